@@ -0,0 +1,55 @@
+//! Compare deserializing API responses with serde_json and with simd-json.
+//!
+//! Run with `cargo bench --features simd-json`.
+
+use criterion::{criterion_group, criterion_main, Criterion, Throughput};
+use dropbox_sdk::files::ListFolderResult;
+
+/// A `files/list_folder` response with the given number of file entries.
+fn list_folder_response(entries: usize) -> String {
+    let entries = (0 .. entries)
+        .map(|i| format!(r#"{{
+            ".tag": "file",
+            "name": "file{i}.txt",
+            "id": "id:a4ayc_80_OEAAAAAAAA{i:04}",
+            "client_modified": "2015-05-12T15:50:38Z",
+            "server_modified": "2015-05-12T15:50:38Z",
+            "rev": "a1c10ce0dd78",
+            "size": {i},
+            "path_lower": "/homework/math/file{i}.txt",
+            "path_display": "/Homework/math/file{i}.txt",
+            "is_downloadable": true,
+            "content_hash": "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855"
+        }}"#))
+        .collect::<Vec<_>>()
+        .join(",");
+    format!(r#"{{"entries": [{entries}], "cursor": "ZtkX9_EHj3x7PMkVuFIhwKYXEpwpLwyxp9vMKomUhllil9q7eWiAu", "has_more": false}}"#)
+}
+
+fn deserialize(c: &mut Criterion) {
+    for entries in [10, 1000] {
+        let json = list_folder_response(entries);
+
+        // Make sure both paths agree before timing them.
+        let expected: ListFolderResult = serde_json::from_str(&json).unwrap();
+        let mut bytes = json.clone().into_bytes();
+        assert_eq!(expected, simd_json::serde::from_slice::<ListFolderResult>(&mut bytes).unwrap());
+
+        let mut group = c.benchmark_group(format!("list_folder/{entries}"));
+        group.throughput(Throughput::Bytes(json.len() as u64));
+        group.bench_function("serde_json", |b| {
+            b.iter(|| serde_json::from_str::<ListFolderResult>(&json).unwrap())
+        });
+        group.bench_function("simd_json", |b| {
+            // This includes the copy the SDK makes, since simd-json parses in place.
+            b.iter(|| {
+                let mut bytes = json.as_bytes().to_vec();
+                simd_json::serde::from_slice::<ListFolderResult>(&mut bytes).unwrap()
+            })
+        });
+        group.finish();
+    }
+}
+
+criterion_group!(benches, deserialize);
+criterion_main!(benches);
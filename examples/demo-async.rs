@@ -3,7 +3,6 @@
 //! This example illustrates a few basic Dropbox API operations: getting an OAuth2 token, listing
 //! the contents of a folder recursively, and fetching a file given its path.
 
-use tokio_util::compat::FuturesAsyncReadCompatExt;
 use dropbox_sdk::default_async_client::{NoauthDefaultClient, UserAuthDefaultClient};
 use dropbox_sdk::async_routes::files;
 
@@ -82,10 +81,9 @@ async fn main() {
             eprintln!();
 
             match files::download(&client, &files::DownloadArg::new(path), None, None).await {
-                Ok(result) => {
+                Ok(mut result) => {
                     match tokio::io::copy(
-                        &mut result.body.expect("there must be a response body")
-                            .compat(),
+                        &mut result.take_tokio_body().expect("there must be a response body"),
                         &mut tokio::io::stdout(),
                     ).await {
                         Ok(n) => {
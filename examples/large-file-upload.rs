@@ -4,9 +4,11 @@
 //! files that would not fit in a single HTTP request, including allowing the user to resume
 //! interrupted uploads, and uploading blocks in parallel.
 
-use dropbox_sdk::Error::Api;
 use dropbox_sdk::files;
+use dropbox_sdk::files_helpers;
+use dropbox_sdk::users_helpers::format_bytes;
 use dropbox_sdk::default_client::UserAuthDefaultClient;
+use futures::FutureExt;
 use std::collections::HashMap;
 use std::fs::File;
 use std::path::{Path, PathBuf};
@@ -121,15 +123,16 @@ fn get_destination_path(client: &UserAuthDefaultClient, given_path: &str, source
         return Ok(path);
     }
 
-    let meta_result = files::get_metadata(
-        client, &files::GetMetadataArg::new(given_path.to_owned()));
+    let meta_result = files_helpers::metadata_opt(client, given_path)
+        .now_or_never()
+        .unwrap();
 
     match meta_result {
-        Ok(files::Metadata::File(_)) => {
+        Ok(Some(files::Metadata::File(_))) => {
             // We're not going to allow overwriting existing files.
             Err(format!("Path {} already exists in Dropbox", given_path))
         }
-        Ok(files::Metadata::Folder(_)) => {
+        Ok(Some(files::Metadata::Folder(_))) => {
             // Given destination path points to a folder, so append the source path's filename and
             // use that as the actual destination.
 
@@ -139,8 +142,8 @@ fn get_destination_path(client: &UserAuthDefaultClient, given_path: &str, source
 
             Ok(path)
         }
-        Ok(files::Metadata::Deleted(_)) => panic!("unexpected deleted metadata received"),
-        Err(Api(files::GetMetadataError::Path(files::LookupError::NotFound))) => {
+        Ok(Some(files::Metadata::Deleted(_))) => panic!("unexpected deleted metadata received"),
+        Ok(None) => {
             // Given destination path doesn't exist, which is just fine. Use the given path as-is.
             // Note that it's fine if the path's parents don't exist either; folders will be
             // automatically created as needed.
@@ -210,7 +213,7 @@ impl UploadSession {
                 self.session_id.clone(),
                 self.file_size),
             files::CommitInfo::new(dest_path)
-                .with_client_modified(iso8601(source_mtime)))
+                .with_client_modified(dropbox_sdk::timestamp::from_system_time(source_mtime)))
     }
 
     /// Mark a block as uploaded.
@@ -275,7 +278,7 @@ fn get_file_mtime_and_size(f: &File) -> Result<(SystemTime, u64), String> {
 
 /// This function does it all.
 fn upload_file(
-    client: Arc<UserAuthDefaultClient>,
+    client: UserAuthDefaultClient,
     mut source_file: File,
     dest_path: String,
     resume: Option<Resume>,
@@ -288,7 +291,7 @@ fn upload_file(
             .map_err(|e| format!("Seek error: {}", e))?;
         UploadSession::resume(resume.clone(), source_len)
     } else {
-        UploadSession::new(client.as_ref(), source_len)?
+        UploadSession::new(&client, source_len)?
     });
 
     eprintln!("upload session ID is {}", session.session_id);
@@ -320,7 +323,7 @@ fn upload_file(
                     return Ok(());
                 }
                 let result = upload_block_with_retry(
-                    client.as_ref(),
+                    &client,
                     &append_arg,
                     data,
                     start_time,
@@ -345,7 +348,7 @@ fn upload_file(
     let mut arg = session.append_arg(last_block_offset);
     arg.close = true;
     if let Err(e) = upload_block_with_retry(
-        client.as_ref(), &arg, &last_block_data, start_time, session.as_ref(), resume.as_ref())
+        &client, &arg, &last_block_data, start_time, session.as_ref(), resume.as_ref())
     {
         eprintln!("failed to close session: {}", e);
         // But don't error out; try committing anyway. It could be we're resuming a file where we
@@ -357,7 +360,7 @@ fn upload_file(
 
     let mut retry = 0;
     while retry < 3 {
-        match files::upload_session_finish(client.as_ref(), &finish, &[]) {
+        match files::upload_session_finish(&client, &finish, &[]) {
             Ok(file_metadata) => {
                 println!("Upload succeeded!");
                 println!("{:#?}", file_metadata);
@@ -425,45 +428,16 @@ fn upload_block_with_retry(
 
     let overall_rate = bytes_sofar as f64 / overall_dur.as_secs_f64();
 
-    eprintln!("{:.01}%: {}Bytes uploaded, {}Bytes per second, {}Bytes per second average",
+    eprintln!("{:.01}%: {} uploaded, {} per second, {} per second average",
         percent,
-        human_number(bytes_sofar),
-        human_number(block_rate as u64),
-        human_number(overall_rate as u64),
+        format_bytes(bytes_sofar),
+        format_bytes(block_rate as u64),
+        format_bytes(overall_rate as u64),
         );
 
     Ok(())
 }
 
-fn human_number(n: u64) -> String {
-    let mut f = n as f64;
-    let prefixes = ['k','M','G','T','E'];
-    let mut mag = 0;
-    while mag < prefixes.len() {
-        if f < 1000. {
-            break;
-        }
-        f /= 1000.;
-        mag += 1;
-    }
-    if mag == 0 {
-        format!("{} ", n)
-    } else {
-        format!("{:.02} {}", f, prefixes[mag - 1])
-    }
-}
-
-fn iso8601(t: SystemTime) -> String {
-    let timestamp: i64 = match t.duration_since(SystemTime::UNIX_EPOCH) {
-        Ok(duration) => duration.as_secs() as i64,
-        Err(e) => -(e.duration().as_secs() as i64),
-    };
-
-    chrono::DateTime::from_timestamp(timestamp, 0 /* nsecs */)
-        .expect("invalid or out-of-range timestamp")
-        .format("%Y-%m-%dT%H:%M:%SZ").to_string()
-}
-
 fn unwrap_arcmutex<T: std::fmt::Debug>(x: Arc<Mutex<T>>) -> T {
     Arc::try_unwrap(x)
         .expect("failed to unwrap Arc")
@@ -488,9 +462,9 @@ fn main() {
         });
 
     let auth = dropbox_sdk::oauth2::get_auth_from_env_or_prompt();
-    let client = Arc::new(UserAuthDefaultClient::new(auth));
+    let client = UserAuthDefaultClient::new(auth);
 
-    let dest_path = get_destination_path(client.as_ref(), &args.dest_path, &args.source_path)
+    let dest_path = get_destination_path(&client, &args.dest_path, &args.source_path)
         .unwrap_or_else(|e| {
             fatal!("Error: {}", e);
         });
@@ -0,0 +1,72 @@
+#![deny(rust_2018_idioms)]
+
+//! This example shows how to preview a shared link using only the app's key and secret, without
+//! any user's authorization: it prints the link's metadata, and optionally saves a thumbnail.
+
+use dropbox_sdk::default_client::AppAuthDefaultClient;
+use dropbox_sdk::files;
+use dropbox_sdk::sharing;
+use dropbox_sdk::sharing_helpers;
+use futures::FutureExt;
+
+fn usage() -> ! {
+    eprintln!("usage: {} <shared link URL> [<thumbnail output path>]",
+        std::env::args().next().unwrap());
+    eprintln!();
+    eprintln!("    The app key and secret must be given in the environment variables");
+    eprintln!("    DBX_APP_KEY and DBX_APP_SECRET.");
+    std::process::exit(1);
+}
+
+fn main() {
+    env_logger::init();
+
+    let mut args = std::env::args().skip(1);
+    let url = match args.next() {
+        Some(url) if url.starts_with("https://") => url,
+        _ => usage(),
+    };
+    let thumbnail_path = args.next();
+
+    let (app_key, app_secret) = match (std::env::var("DBX_APP_KEY"), std::env::var("DBX_APP_SECRET")) {
+        (Ok(key), Ok(secret)) => (key, secret),
+        _ => usage(),
+    };
+    let client = AppAuthDefaultClient::new(&app_key, &app_secret);
+    let link = files::SharedLinkFileInfo::new(url);
+
+    // The helpers are async, but with a sync client they complete immediately.
+    match sharing_helpers::shared_link_metadata(&client, &link).now_or_never().unwrap() {
+        Ok(sharing::SharedLinkMetadata::File(file)) => {
+            println!("File: {} ({} bytes, modified {})", file.name, file.size, file.client_modified);
+        }
+        Ok(sharing::SharedLinkMetadata::Folder(folder)) => {
+            println!("Folder: {}", folder.name);
+        }
+        Ok(other) => {
+            println!("Other: {other:?}");
+        }
+        Err(e) => {
+            eprintln!("Error from sharing/get_shared_link_metadata: {e}");
+            std::process::exit(2);
+        }
+    }
+
+    if let Some(path) = thumbnail_path {
+        match sharing_helpers::shared_link_thumbnail(
+            &client,
+            &link,
+            files::ThumbnailSize::W256h256,
+            files::ThumbnailFormat::Png,
+        ).now_or_never().unwrap() {
+            Ok((_metadata, image)) => {
+                std::fs::write(&path, &image).expect("failed to write thumbnail");
+                eprintln!("Wrote {} byte thumbnail to {path}", image.len());
+            }
+            Err(e) => {
+                eprintln!("Error getting thumbnail: {e}");
+                std::process::exit(2);
+            }
+        }
+    }
+}
@@ -36,7 +36,7 @@ def spec_files(spec_root: str) -> List[str]:
     return specs
 
 
-def generate_code(spec_root: str, gen_rust: bool, gen_test: bool):
+def generate_code(spec_root: str, gen_rust: bool, gen_test: bool, field_aliases: str):
     """
     This is basically stone/stone/cli.py stripped down and customized to our needs.
     """
@@ -75,7 +75,12 @@ def generate_code(spec_root: str, gen_rust: bool, gen_test: bool):
 
         rmtree(destination, ignore_errors=True)
 
-        c = Compiler(api, backend_module, [], destination)
+        backend_args = {
+            "rust": ["--field-aliases", field_aliases] if field_aliases else [],
+            "test": [],
+        }[target]
+
+        c = Compiler(api, backend_module, backend_args, destination)
         try:
             c.build()
         except BackendException as e:
@@ -100,6 +105,9 @@ def main():
                         help="Path to the API spec submodule.")
     parser.add_argument("--gen-rust", action="store_true")
     parser.add_argument("--gen-test", action="store_true")
+    parser.add_argument("--field-aliases", type=str, default=join("generator", "field_aliases.json"),
+                        help="Path to the table of alternate field names to accept when"
+                             " deserializing, or an empty string for none.")
 
     args = parser.parse_args()
     if not args.gen_rust and not args.gen_test:
@@ -109,7 +117,7 @@ def main():
     logging.basicConfig(level=logging.INFO)
 
     try:
-        generate_code(args.spec_path, args.gen_rust, args.gen_test)
+        generate_code(args.spec_path, args.gen_rust, args.gen_test, args.field_aliases)
     except CodegenFailed:
         exit(2)
 
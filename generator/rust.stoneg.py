@@ -1,4 +1,6 @@
+import argparse
 import contextlib
+import json
 from contextlib import contextmanager
 from typing import Iterator, Optional, Sequence
 
@@ -14,11 +16,35 @@ def fmt_shouting_snake(name: str) -> str:
     return '_'.join([word.upper() for word in split_words(name)])
 
 
+def fmt_camel(name: str) -> str:
+    first, *rest = name.split('_')
+    return first + ''.join(word.capitalize() for word in rest)
+
+
+_cmdline_parser = argparse.ArgumentParser(prog='rust-backend')
+_cmdline_parser.add_argument(
+    '--field-aliases',
+    type=str,
+    help='JSON file of alternate names to accept when deserializing struct fields. It has a '
+         '"camel_case_namespaces" list of namespaces whose fields are also accepted in camelCase, '
+         'and a "fields" object mapping "namespace.Type.field" to a list of other names.')
+
+
 class RustBackend(RustHelperBackend):
+    cmdline_parser = _cmdline_parser
+
     def __init__(self, target_folder_path: str, args: Optional[Sequence[str]]) -> None:
         super().__init__(target_folder_path, args)
         self.preserve_aliases = True
 
+        self._camel_case_namespaces: set[str] = set()
+        self._field_aliases: dict[str, list[str]] = dict()
+        if self.args is not None and self.args.field_aliases:
+            with open(self.args.field_aliases) as f:
+                table = json.load(f)
+            self._camel_case_namespaces = set(table.get('camel_case_namespaces', []))
+            self._field_aliases = table.get('fields', {})
+
         self._all_types: dict[str, dict[str, ir.UserDefined]] = dict()
         self._current_namespace: str = ''
         self._error_types: set[Optional[ir.DataType]] = set()
@@ -51,6 +77,9 @@ class RustBackend(RustHelperBackend):
         for d in ['async_routes', 'sync_routes', 'types']:
             self._generate_mod_file(f'{d}/mod.rs')
 
+        with self.output_to_relative_path('route_table.rs'):
+            self._emit_route_table(api)
+
         with self.output_to_relative_path('mod.rs'):
             self._emit_header()
             self.emit('pub mod types;')
@@ -58,15 +87,42 @@ class RustBackend(RustHelperBackend):
             with self.block('if_feature! { "async_routes",', delim=(None, '}')):
                 self.emit('pub mod async_routes;')
             self.emit()
+            self.emit('// The async routes are always built because the helper modules use them, but they\'re only')
+            self.emit('// public if the feature is enabled.')
+            self.emit('#[cfg(not(feature = "async_routes"))]')
+            self.emit('#[allow(dead_code)]')
+            self.emit('pub(crate) mod async_routes;')
+            self.emit()
             with self.block('if_feature! { "sync_routes",', delim=(None, '}')):
                 self.emit('pub mod sync_routes;')
             self.emit()
+            self.emit('#[cfg(feature = "dispatch")]')
+            self.emit('pub(crate) mod route_table;')
+            self.emit()
+            self._emit_deprecated_routes(api)
+            self.emit()
             with self.block('pub(crate) fn eat_json_fields<\'de, V>(map: &mut V)'
                             ' -> Result<(), V::Error>'
                             ' where V: ::serde::de::MapAccess<\'de>'):
                 with self.block('while map.next_entry::<&str, ::serde_json::Value>()?.is_some()'):
                     self.emit('/* ignore */')
                 self.emit('Ok(())')
+            self.emit()
+            # These are shared by all the struct deserializers, to keep the generated code small.
+            with self.block('pub(crate) fn eat_json_field<\'de, V>(map: &mut V)'
+                            ' -> Result<(), V::Error>'
+                            ' where V: ::serde::de::MapAccess<\'de>'):
+                self.emit('map.next_value::<::serde_json::Value>()?;')
+                self.emit('Ok(())')
+            self.emit()
+            with self.block('pub(crate) fn deserialize_field<\'de, V, T>('
+                            'map: &mut V, field: &mut Option<T>, name: &\'static str)'
+                            ' -> Result<(), V::Error>'
+                            ' where V: ::serde::de::MapAccess<\'de>, T: ::serde::de::Deserialize<\'de>'):
+                with self.block('if field.is_some()'):
+                    self.emit('return Err(::serde::de::Error::duplicate_field(name));')
+                self.emit('*field = Some(map.next_value()?);')
+                self.emit('Ok(())')
 
     def _generate_mod_file(self, path: str) -> None:
         with self.output_to_relative_path(path):
@@ -81,6 +137,58 @@ class RustBackend(RustHelperBackend):
                     self.emit(f'if_feature! {{ "dbx_{module}", pub mod {ns}; }}')
                 self.emit()
 
+    def _emit_route_table(self, api: ir.Api) -> None:
+        self._emit_header()
+        self.emit('use crate::client_trait_common::{Endpoint, Style};')
+        self.emit('use crate::dispatch::RouteInfo;')
+        self.emit()
+        routes = sorted(
+            ((namespace.name, fn) for namespace in api.namespaces.values() for fn in namespace.routes),
+            key=lambda pair: self._route_path(pair[0], pair[1]))
+        self.emit('/// Every route, sorted by name.')
+        with self.block('pub(crate) static ROUTES: &[RouteInfo] = &[', delim=(None, '];')):
+            for ns, fn in routes:
+                assert fn.attrs is not None
+                if fn.attrs.get('is_preview'):
+                    self.emit('#[cfg(feature = "unstable")]')
+                if ns not in REQUIRED_NAMESPACES:
+                    self.emit(f'#[cfg(feature = "dbx_{ns}")]')
+                auths = sorted(s.strip() for s in fn.attrs.get('auth', 'user').split(','))
+                auths_list = ', '.join(f'"{auth}"' for auth in auths)
+                with self.block('RouteInfo', delim=('{', '},')):
+                    self.emit(f'name: "{self._route_path(ns, fn)}",')
+                    self.emit(f'endpoint: Endpoint::{fn.attrs.get("host", "api").capitalize()},')
+                    self.emit(f'style: Style::{fn.attrs.get("style", "rpc").capitalize()},')
+                    self.emit(f'auth: &[{auths_list}],')
+                    self.emit(f'preview: {"true" if fn.attrs.get("is_preview") else "false"},')
+                    self.emit(f'deprecated: {"true" if fn.deprecated else "false"},')
+                    if fn.deprecated and fn.deprecated.by:
+                        self.emit(f'replaced_by: Some("{self._route_path(ns, fn.deprecated.by)}"),')
+                    else:
+                        self.emit('replaced_by: None,')
+
+    def _emit_deprecated_routes(self, api: ir.Api) -> None:
+        routes = sorted(
+            (self._route_path(namespace.name, fn),
+             self._route_path(namespace.name, fn.deprecated.by) if fn.deprecated.by else None)
+            for namespace in api.namespaces.values()
+            for fn in namespace.routes
+            if fn.deprecated)
+        self.emit('/// The deprecated routes, sorted by name, and the routes which replace them.')
+        with self.block('pub(crate) const DEPRECATED_ROUTES: &[(&str, Option<&str>)] = &[',
+                        delim=(None, '];')):
+            for name, replacement in routes:
+                replacement_str = f'Some("{replacement}")' if replacement else 'None'
+                self.emit(f'("{name}", {replacement_str}),')
+
+    def _route_path(self, ns: str, fn: ir.ApiRoute) -> str:
+        # This is the path of the HTTP route. Almost the same as the 'route_name', but without any
+        # mangling to avoid Rust keywords and such.
+        if fn.version > 1:
+            return f'{ns}/{fn.name}_v{fn.version}'
+        else:
+            return f'{ns}/{fn.name}'
+
     # Type Emitters
 
     def _emit_namespace(self, namespace: ir.ApiNamespace) -> None:
@@ -99,6 +207,9 @@ class RustBackend(RustHelperBackend):
             if namespace.aliases:
                 self.emit()
 
+            if namespace.routes:
+                self._emit_route_names(namespace)
+
             for typ in namespace.data_types:
                 self._current_type = typ
                 if isinstance(typ, ir.Struct):
@@ -116,6 +227,7 @@ class RustBackend(RustHelperBackend):
             self.emit('#[allow(unused_imports)]')
             self.emit(f'pub use crate::generated::types::{ns}::*;')
             self.emit()
+            self._emit_prelude(ns, 'client_trait')
             for fn in namespace.routes:
                 self._emit_route(ns, fn)
 
@@ -124,11 +236,35 @@ class RustBackend(RustHelperBackend):
             self.emit('#[allow(unused_imports)]')
             self.emit(f'pub use crate::generated::types::{ns}::*;')
             self.emit()
+            self._emit_prelude(ns, 'async_client_trait')
             for fn in namespace.routes:
                 self._emit_route(ns, fn, as_async=True)
 
         self._modules.append(namespace.name)
 
+    def _emit_route_names(self, namespace: ir.ApiNamespace) -> None:
+        self.emit('/// The names of this namespace\'s routes, as they appear in request URLs after the')
+        self.emit('/// [`Endpoint`](crate::client_trait_common::Endpoint) base URL, for referring to them in logs and')
+        self.emit('/// metrics without spelling them out.')
+        with self.block('pub mod routes'):
+            for fn in namespace.routes:
+                path = self._route_path(namespace.name, fn)
+                const = path.split('/', 1)[1].replace('/', '_').upper()
+                if fn.attrs.get('is_preview'):
+                    self.emit('#[cfg(feature = "unstable")]')
+                self.emit(f'pub const {const}: &str = "{path}";')
+        self.emit()
+
+    def _emit_prelude(self, ns: str, trait_mod: str) -> None:
+        self.emit('/// The types of this namespace, and the client traits its routes take, for importing all at')
+        self.emit(f'/// once with `use dropbox_sdk::{ns}::prelude::*`. Unlike a glob import of this module, this')
+        self.emit('/// leaves out the route functions, whose names are often too general to import unqualified.')
+        self.emit('#[allow(unused_imports)]')
+        with self.block('pub mod prelude'):
+            self.emit(f'pub use crate::generated::types::{ns}::*;')
+            self.emit(f'pub use crate::{trait_mod}::{{AppAuthClient, NoauthClient, TeamAuthClient, UserAuthClient}};')
+        self.emit()
+
     def _emit_header(self) -> None:
         self.emit('// DO NOT EDIT')
         self.emit('// This file was @generated by Stone')
@@ -222,7 +358,7 @@ class RustBackend(RustHelperBackend):
                 else:
                     self.emit(f'{variant_name}({self._rust_type(field.data_type)}),')
             if not union.closed:
-                self._emit_other_variant()
+                self._emit_other_variant(unknown_variant=True)
         self.emit()
 
         self._impl_serde_for_union(union)
@@ -378,13 +514,23 @@ class RustBackend(RustHelperBackend):
         assert isinstance(alias.data_type, ir.DataType)
         self.emit(f'pub type {alias_name} = {self._rust_type(alias.data_type)};')
 
-    def _emit_other_variant(self) -> None:
-        self.emit_wrapped_text(
-                'Catch-all used for unrecognized values returned from the server.'
-                ' Encountering this value typically indicates that this SDK version is'
-                ' out of date.',
-                prefix='/// ', width=100)
-        self.emit('Other,')
+    def _emit_other_variant(self, unknown_variant: bool = False) -> None:
+        if unknown_variant:
+            # Unions keep what was received, so it can be serialized back unchanged.
+            self.emit_wrapped_text(
+                    'Catch-all used for unrecognized values returned from the server.'
+                    ' Encountering this value typically indicates that this SDK version is'
+                    ' out of date. It holds the value as it was received, and serializes back'
+                    ' to it unchanged.',
+                    prefix='/// ', width=100)
+            self.emit('Other(crate::UnknownVariant),')
+        else:
+            self.emit_wrapped_text(
+                    'Catch-all used for unrecognized values returned from the server.'
+                    ' Encountering this value typically indicates that this SDK version is'
+                    ' out of date.',
+                    prefix='/// ', width=100)
+            self.emit('Other,')
 
     # Serialization
 
@@ -451,14 +597,11 @@ class RustBackend(RustHelperBackend):
                         with self.block('match key'):
                             for field in struct.all_fields:
                                 field_name = self.field_name(field)
-                                with self.block(f'"{field.name}" =>'):
-                                    with self.block(f'if field_{field_name}.is_some()'):
-                                        self.emit('return Err(::serde::de::Error::duplicate_field('
-                                                  f'"{field.name}"));')
-                                    self.emit(f'field_{field_name} = Some(map.next_value()?);')
-                            with self.block('_ =>'):
-                                self.emit('// unknown field allowed and ignored')
-                                self.emit('map.next_value::<::serde_json::Value>()?;')
+                                keys = ' | '.join(f'"{key}"' for key in self._field_keys(struct, field))
+                                self.emit(f'{keys} => crate::deserialize_field('
+                                          f'&mut map, &mut field_{field_name}, "{field.name}")?,')
+                            # unknown fields are allowed and ignored
+                            self.emit('_ => crate::eat_json_field(&mut map)?,')
                     if optional:
                         with self.block('if optional && nothing'):
                             self.emit('return Ok(None);')
@@ -554,6 +697,22 @@ class RustBackend(RustHelperBackend):
                 self.emit('s.end()')
         self.emit()
 
+    def _field_keys(self, struct: ir.Struct, field: ir.StructField) -> list[str]:
+        """
+        The names a struct field is accepted under when deserializing: its own name, then any
+        aliases from the table given with --field-aliases. Aliases are only ever accepted, never
+        serialized, and ones which clash with another field's name are left out.
+        """
+        keys = [field.name]
+        aliases = list(self._field_aliases.get(f'{self._current_namespace}.{struct.name}.{field.name}', []))
+        if self._current_namespace in self._camel_case_namespaces:
+            aliases.append(fmt_camel(field.name))
+        other_names = set(f.name for f in struct.all_fields)
+        for alias in aliases:
+            if alias not in keys and alias not in other_names:
+                keys.append(alias)
+        return keys
+
     def _impl_serde_for_polymorphic_struct(self, struct: ir.Struct) -> None:
         type_name = self.enum_name(struct)
         with self._impl_deserialize(type_name):
@@ -644,9 +803,8 @@ class RustBackend(RustHelperBackend):
                         self.emit('_ => return Err(de::Error::missing_field(".tag"))')
                     if len(union.all_fields) == 1 and union.all_fields[0].catch_all:
                         self.emit('// open enum with no defined variants')
-                        self.emit('let _ = tag;') # hax
-                        self.emit('crate::eat_json_fields(&mut map)?;')
-                        self.emit(f'Ok({type_name}::Other)')
+                        self.emit(f'Ok({type_name}::Other('
+                                  'crate::UnknownVariant::deserialize_fields(tag, &mut map)?))')
                     else:
                         with self.block('let value = match tag', after=';'):
                             for field in union.all_fields:
@@ -689,7 +847,8 @@ class RustBackend(RustHelperBackend):
                                             self.emit('_ => return Err(de::Error::unknown_field('
                                                       'tag, VARIANTS))')
                             if not union.closed:
-                                self.emit(f'_ => {type_name}::Other,')
+                                self.emit(f'_ => {type_name}::Other('
+                                          'crate::UnknownVariant::deserialize_fields(tag, &mut map)?),')
                             else:
                                 self.emit('_ => return Err(de::Error::unknown_variant(tag, VARIANTS))')
                         self.emit('crate::eat_json_fields(&mut map)?;')
@@ -705,9 +864,9 @@ class RustBackend(RustHelperBackend):
             self.emit('// union serializer')
             if len(union.all_fields) == 1 and union.all_fields[0].catch_all:
                 # special case: an open union with no variants defined.
-                self.emit('#![allow(unused_variables)]')
-                self.emit('Err(::serde::ser::Error::custom("cannot serialize an open union with '
-                          'no defined variants"))')
+                self.emit('// catch-all')
+                self.emit(f'let {type_name}::Other(ref x) = *self;')
+                self.emit('::serde::ser::Serialize::serialize(x, serializer)')
             else:
                 self.emit('use serde::ser::SerializeStruct;')
                 with self.block('match *self'):
@@ -767,8 +926,10 @@ class RustBackend(RustHelperBackend):
                                     self.emit(f's.serialize_field("{field.name}", x)?;')
                                     self.emit('s.end()')
                     if not union.closed:
-                        self.emit(f'{type_name}::Other => Err(::serde::ser::Error::custom('
-                                  '"cannot serialize \'Other\' variant"))')
+                        # Unknown variants are serialized as they were received.
+                        with self.block(f'{type_name}::Other(ref x) =>'):
+                            self.emit('// catch-all')
+                            self.emit('::serde::ser::Serialize::serialize(x, serializer)')
         self.emit()
 
     # "extends" for structs means the subtype adds additional fields to the supertype, so we can
@@ -813,7 +974,7 @@ class RustBackend(RustHelperBackend):
                 with self.block(f'match parent'):
                     for field in parent.all_fields:
                         variant_name = self.enum_variant_name(field)
-                        x = "" if isinstance(field.data_type, ir.Void) else "(x)"
+                        x = "" if isinstance(field.data_type, ir.Void) and not field.catch_all else "(x)"
                         self.emit(f'{supertype}::{variant_name}{x} => {subtype}::{variant_name}{x},')
 
     # Helpers
@@ -1117,8 +1278,15 @@ class RustBackend(RustHelperBackend):
 
                     inner_fmt = ''
                     if self._is_error_type(variant.data_type):
-                        # include the Display representation of the inner error.
+                        # include the Display representation of the inner error, which includes
+                        # any errors nested inside it in turn.
                         inner_fmt = '{}'
+
+                        if not msg:
+                            # Prefix it with the name of the variant, so that the whole chain is
+                            # shown, like the API's error summaries, e.g. "path: There is nothing
+                            # at the given path."
+                            msg = variant.name
                     elif not ir.is_void_type(variant.data_type):
                         # Include the Debug representation of the inner value.
                         inner_fmt = '{:?}'
@@ -2,9 +2,10 @@
 
 use std::future::{Future, ready};
 use std::sync::Arc;
+use std::time::Duration;
 use bytes::Bytes;
 use futures::AsyncRead;
-use crate::client_trait_common::{HttpRequest, TeamSelect};
+use crate::client_trait_common::{HttpRequest, TeamSelect, CLOSE_DRAIN_LIMIT};
 use crate::Error;
 
 /// The base HTTP asynchronous client trait.
@@ -48,6 +49,38 @@ pub trait HttpClient: Sync {
         None
     }
 
+    /// The locale to request user-facing strings in, if any, as an IETF language tag such as
+    /// `"en-GB"`. This is sent in the `Dropbox-API-User-Locale` header.
+    fn locale(&self) -> Option<&str> {
+        None
+    }
+
+    /// Extra headers to send with every request, as `(name, value)` pairs, such as ones which opt
+    /// in to beta behavior of the API. These are sent in addition to the headers the SDK sets
+    /// itself, so they shouldn't include any of those, such as `Authorization`.
+    fn extra_headers(&self) -> &[(String, String)] {
+        &[]
+    }
+
+    /// The longest one call of a route may take in total, including refreshing the access token,
+    /// retrying with the new one, and reading the body of a download, or `None` for no limit. Each
+    /// request is given the time remaining as its timeout. The call fails with
+    /// [`DeadlineExceeded`](crate::client_trait_common::DeadlineExceeded) if it runs out before the
+    /// response arrives, and reading the body fails with the client's timeout error if it runs out
+    /// after, so this should allow for the largest download expected.
+    fn total_timeout(&self) -> Option<Duration> {
+        None
+    }
+
+    /// Compress JSON request bodies larger than this many bytes with gzip, sending them with
+    /// `Content-Encoding: gzip`, or `None` to never compress them. This only applies to the
+    /// arguments of RPC-style routes, which can be large for batch operations; upload and download
+    /// arguments are sent in a header, and file contents are never compressed.
+    #[cfg(feature = "gzip")]
+    fn gzip_requests_over(&self) -> Option<usize> {
+        None
+    }
+
     /// This should only be implemented by (or called on) the blanket impl for sync HTTP clients
     /// implemented in this module.
     ///
@@ -71,9 +104,28 @@ pub trait HttpClient: Sync {
         #[allow(unreachable_code)] // otherwise it complains that `()` is not a future.
         async move { unimplemented!() }
     }
+
+    /// Like `execute_borrowed_body`, this should only be implemented by the blanket impl for sync
+    /// HTTP clients. It's used by the sync-only helpers which stream request bodies from a reader.
+    /// For other clients, this reads the body into memory and calls [`execute`](Self::execute).
+    #[doc(hidden)]
+    #[cfg(feature = "sync_routes")]
+    fn execute_streaming_body(
+        &self,
+        request: Self::Request,
+        body: &mut (dyn std::io::Read + Send),
+        content_length: u64,
+    ) -> impl Future<Output = Result<HttpRequestResultRaw, Error>> + Send {
+        let body = crate::client_trait_common::read_body(body, content_length);
+        async move { self.execute(request, Bytes::from(body?)).await }
+    }
 }
 
 /// The raw response from the server, including an async streaming response body.
+///
+/// More headers may be added to this in the future, so make one with [`new`](Self::new) and then
+/// set the fields for any headers present.
+#[non_exhaustive]
 pub struct HttpRequestResultRaw {
     /// HTTP response code.
     pub status: u16,
@@ -84,25 +136,118 @@ pub struct HttpRequestResultRaw {
     /// The value of the `Content-Length` header, if present.
     pub content_length: Option<u64>,
 
+    /// The value of the `Retry-After` header, if present.
+    pub retry_after: Option<String>,
+
+    /// The value of the `Content-Range` header, if present.
+    pub content_range: Option<String>,
+
+    /// The value of the `Content-Disposition` header, if present.
+    pub content_disposition: Option<String>,
+
+    /// The value of the `X-Dropbox-Request-Id` header, if present, which identifies the request to
+    /// Dropbox support.
+    pub request_id: Option<String>,
+
     /// The response body stream.
+    ///
+    /// Dropping this before reading all of it must not leave the connection in a state where it's
+    /// reused with the unread data still pending: clients should either close the connection, or
+    /// read the rest of the body first.
     pub body: Box<dyn AsyncRead + Send + Unpin>,
 }
 
+impl HttpRequestResultRaw {
+    /// A response with the given status code and body, and none of the headers the SDK looks at.
+    pub fn new(status: u16, body: Box<dyn AsyncRead + Send + Unpin>) -> Self {
+        Self {
+            status,
+            result_header: None,
+            content_length: None,
+            retry_after: None,
+            content_range: None,
+            content_disposition: None,
+            request_id: None,
+            body,
+        }
+    }
+}
+
 /// The response from the server, parsed into a given type, including a body stream if it is from
 /// a Download style request.
+///
+/// More response details may be added to this in the future, so make one with
+/// [`new`](Self::new) and then set the fields for any others.
+#[non_exhaustive]
 pub struct HttpRequestResult<T> {
     /// The API result, parsed into the given type.
     pub result: T,
 
     /// The value of the `Content-Length` header in the response, if any. Only expected to not be
-    /// `None` if `body` is also not `None`.
+    /// `None` if `body` is also not `None`. For a partial response to a request for a range, this
+    /// is the length of the part returned, not of the whole file.
     pub content_length: Option<u64>,
 
+    /// The part of the file returned, if this is a partial response to a request for a range.
+    pub content_range: Option<crate::client_trait_common::ContentRange>,
+
+    /// The file name given by the `Content-Disposition` header in the response, if any, such as
+    /// for downloads from shared links. See
+    /// [`content_disposition_filename`](crate::client_trait_common::content_disposition_filename).
+    pub filename: Option<String>,
+
     /// The response body stream, if any. Only expected to not be `None` for
     /// [`Style::Download`](crate::client_trait_common::Style::Download) endpoints.
     pub body: Option<Box<dyn AsyncRead + Unpin + Send>>,
 }
 
+impl<T> HttpRequestResult<T> {
+    /// A response with the given result and body, and no other details.
+    pub fn new(result: T, body: Option<Box<dyn AsyncRead + Unpin + Send>>) -> Self {
+        Self {
+            result,
+            content_length: None,
+            content_range: None,
+            filename: None,
+            body,
+        }
+    }
+
+    /// Drop the response body without reading any more of it.
+    ///
+    /// The connection it was being read from is closed instead of being reused, so this is the
+    /// quickest way to stop a download partway through. Dropping the whole result does the same.
+    pub fn abort(&mut self) {
+        self.body = None;
+    }
+
+    /// Finish with the response body, reading and discarding the rest of it if there's no more
+    /// than [`CLOSE_DRAIN_LIMIT`] bytes left, so that the connection can be reused for another
+    /// request. If there's more left than that, the body is [aborted](Self::abort) instead.
+    pub async fn close(&mut self) -> std::io::Result<()> {
+        use futures::AsyncReadExt;
+        if let Some(body) = self.body.take() {
+            let drained = futures::io::copy(body.take(CLOSE_DRAIN_LIMIT + 1), &mut futures::io::sink())
+                .await?;
+            if drained > CLOSE_DRAIN_LIMIT {
+                debug!("aborting a response body with more than {CLOSE_DRAIN_LIMIT} bytes left");
+            }
+        }
+        Ok(())
+    }
+}
+
+if_feature! { "tokio",
+    impl<T> HttpRequestResult<T> {
+        /// Take the response body, if any, as a [`tokio::io::AsyncRead`] instead of a
+        /// [`futures::AsyncRead`].
+        pub fn take_tokio_body(&mut self) -> Option<impl tokio::io::AsyncRead + Send + Unpin> {
+            use tokio_util::compat::FuturesAsyncReadCompatExt;
+            self.body.take().map(|body| body.compat())
+        }
+    }
+}
+
 /// Blanket implementation of the async interface for all sync clients.
 /// This is necessary because all the machinery is actually implemented in terms of the async
 /// client.
@@ -119,14 +264,16 @@ impl<T: crate::client_trait::HttpClient + Sync> HttpClient for T {
     async fn execute_borrowed_body(&self, request: Self::Request, body_slice: &[u8])
         -> Result<HttpRequestResultRaw, Error>
     {
-        self.execute(request, body_slice).map(|r| {
-            HttpRequestResultRaw {
-                status: r.status,
-                result_header: r.result_header,
-                content_length: r.content_length,
-                body: Box::new(SyncReadAdapter { inner: r.body }),
-            }
-        })
+        self.execute(request, body_slice).map(sync_result_to_async)
+    }
+
+    async fn execute_streaming_body(
+        &self,
+        request: Self::Request,
+        body: &mut (dyn std::io::Read + Send),
+        content_length: u64,
+    ) -> Result<HttpRequestResultRaw, Error> {
+        self.execute_streaming(request, body, content_length).map(sync_result_to_async)
     }
 
     fn new_request(&self, url: &str) -> Self::Request {
@@ -150,9 +297,32 @@ impl<T: crate::client_trait::HttpClient + Sync> HttpClient for T {
     fn team_select(&self) -> Option<&TeamSelect> {
         self.team_select()
     }
+
+    fn locale(&self) -> Option<&str> {
+        self.locale()
+    }
+
+    fn extra_headers(&self) -> &[(String, String)] {
+        self.extra_headers()
+    }
+
+    fn total_timeout(&self) -> Option<Duration> {
+        self.total_timeout()
+    }
+
+    #[cfg(feature = "gzip")]
+    fn gzip_requests_over(&self) -> Option<usize> {
+        self.gzip_requests_over()
+    }
 }
 
 /// Marker trait to indicate that a HTTP client supports unauthenticated routes.
+///
+/// Each route function requires its client to implement the marker trait for one of the kinds of
+/// authentication the route accepts, so calling a route with the wrong kind of client is a compile
+/// error. When implementing these for your own client, only implement the ones for the
+/// authentication it actually performs; a client implementing more than that can call routes
+/// which will fail at runtime instead.
 pub trait NoauthClient: HttpClient {}
 
 /// Marker trait to indicate that a HTTP client supports User authentication.
@@ -180,12 +350,45 @@ impl<T: crate::client_trait::TeamAuthClient + Sync> TeamAuthClient for T {}
 impl<T: crate::client_trait::AppAuthClient + Sync> AppAuthClient for T {}
 
 #[cfg(feature = "sync_routes")]
-pub(crate) struct SyncReadAdapter {
-    pub inner: Box<dyn std::io::Read + Send>,
+fn sync_result_to_async(r: crate::client_trait::HttpRequestResultRaw) -> HttpRequestResultRaw {
+    HttpRequestResultRaw {
+        status: r.status,
+        result_header: r.result_header,
+        content_length: r.content_length,
+        retry_after: r.retry_after,
+        content_range: r.content_range,
+        content_disposition: r.content_disposition,
+        request_id: r.request_id,
+        body: Box::new(SyncReadAdapter::new(r.body)),
+    }
+}
+
+/// Adapts a sync [`Read`](std::io::Read) to an [`AsyncRead`] which reads from it in place,
+/// blocking the task until it returns.
+///
+/// This is how the response bodies of sync HTTP clients are given to the async routes. It's only
+/// suitable for readers which don't block for long, or for futures which are polled outside of an
+/// async runtime, as the sync routes do.
+#[cfg(feature = "sync_routes")]
+pub struct SyncReadAdapter<R = Box<dyn std::io::Read + Send>> {
+    inner: R,
+}
+
+#[cfg(feature = "sync_routes")]
+impl<R: std::io::Read> SyncReadAdapter<R> {
+    /// Wrap a sync reader.
+    pub fn new(inner: R) -> Self {
+        Self { inner }
+    }
+
+    /// Unwrap the sync reader.
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
 }
 
 #[cfg(feature = "sync_routes")]
-impl AsyncRead for SyncReadAdapter {
+impl<R: std::io::Read + Unpin> AsyncRead for SyncReadAdapter<R> {
     fn poll_read(
         mut self: std::pin::Pin<&mut Self>,
         _cx: &mut std::task::Context<'_>,
@@ -202,3 +405,53 @@ impl AsyncRead for SyncReadAdapter {
         std::task::Poll::Ready(std::io::Read::read_vectored(&mut self.inner, bufs))
     }
 }
+
+/// Adapts an [`AsyncRead`] which is always ready, such as a [`SyncReadAdapter`], back to a sync
+/// [`Read`](std::io::Read).
+///
+/// Each read polls the inner reader once. If it isn't ready, the read fails with
+/// [`WouldBlock`](std::io::ErrorKind::WouldBlock) rather than blocking, as there is no runtime to
+/// wait on.
+#[cfg(feature = "sync_routes")]
+pub struct ReadyReadAdapter<R> {
+    inner: R,
+}
+
+#[cfg(feature = "sync_routes")]
+impl<R: AsyncRead + Unpin> ReadyReadAdapter<R> {
+    /// Wrap an async reader.
+    pub fn new(inner: R) -> Self {
+        Self { inner }
+    }
+
+    /// Unwrap the async reader.
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+
+    fn poll<T>(
+        &mut self,
+        f: impl FnOnce(std::pin::Pin<&mut R>, &mut std::task::Context<'_>)
+            -> std::task::Poll<std::io::Result<T>>,
+    ) -> std::io::Result<T> {
+        let mut cx = std::task::Context::from_waker(futures::task::noop_waker_ref());
+        match f(std::pin::Pin::new(&mut self.inner), &mut cx) {
+            std::task::Poll::Ready(result) => result,
+            std::task::Poll::Pending => Err(std::io::Error::new(
+                std::io::ErrorKind::WouldBlock,
+                "async reader is not ready",
+            )),
+        }
+    }
+}
+
+#[cfg(feature = "sync_routes")]
+impl<R: AsyncRead + Unpin> std::io::Read for ReadyReadAdapter<R> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        self.poll(|r, cx| r.poll_read(cx, buf))
+    }
+
+    fn read_vectored(&mut self, bufs: &mut [std::io::IoSliceMut<'_>]) -> std::io::Result<usize> {
+        self.poll(|r, cx| r.poll_read_vectored(cx, bufs))
+    }
+}
@@ -0,0 +1,21 @@
+//! Polling of long-running server-side jobs, used by the helper modules.
+
+use std::future::Future;
+use std::time::Duration;
+
+/// Repeatedly call `check` until it returns a result, waiting `interval` between attempts.
+///
+/// `check` should return `Ok(None)` while the job is still in progress.
+pub(crate) async fn poll_until_complete<T, E, F, Fut>(interval: Duration, mut check: F)
+    -> Result<T, E>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<Option<T>, E>>,
+{
+    loop {
+        if let Some(result) = check().await? {
+            return Ok(result);
+        }
+        futures_timer::Delay::new(interval).await;
+    }
+}
@@ -0,0 +1,11 @@
+//! The async routes and client traits, at paths which don't depend on which routes are in the
+//! crate root.
+//!
+//! Whether `dropbox_sdk::files` is the sync or async version of the routes depends on the
+//! `sync_routes_in_root` feature, so code importing from there breaks when another crate in the
+//! build turns the feature on or off. The routes here, such as `dropbox_sdk::asynch::files`, are
+//! always the async ones, and exist whenever the `async_routes` feature is on. (The module isn't
+//! called `async`, as that's a keyword.)
+
+pub use crate::generated::async_routes::*;
+pub use crate::async_client_trait::{AppAuthClient, NoauthClient, TeamAuthClient, UserAuthClient};
@@ -0,0 +1,65 @@
+//! Running the async helpers from sync code.
+//!
+//! Parts of the SDK are only async, such as the helper modules and the streams they return for
+//! watching folders or following pages of results. Sync clients implement the async client
+//! traits, so those can be used with them, but something still has to drive the futures and
+//! streams. These functions do that on the current thread, blocking it until the result is ready,
+//! so that mostly-sync programs don't need an async runtime, or glue code of their own.
+//!
+//! With a sync client, requests complete straight away, so only waiting takes any time, such as
+//! between retries or while longpolling. The timers used for that work without any runtime.
+//!
+//! Don't call these from within an async runtime: blocking one of its threads can stall other
+//! tasks, or deadlock. They can't be nested either: calling one from within a future or stream
+//! that another is running, such as in a closure given to `then`, panics. Sync clients don't need
+//! them for anything they do themselves, such as refreshing their token, so those work fine
+//! inside them.
+//!
+//! This is only built with the `blocking` Cargo feature.
+
+use std::future::Future;
+use std::pin::Pin;
+use futures::{Stream, StreamExt};
+
+/// Run a future to completion on the current thread, blocking until it's done, and return its
+/// result.
+///
+/// For example, `block_on(files_helpers::ensure_folder(&client, "/a/b"))`.
+pub fn block_on<F: Future>(future: F) -> F::Output {
+    futures::executor::block_on(future)
+}
+
+/// Turn a stream into an iterator, which blocks the current thread until each item is ready.
+///
+/// For example, `iter(files_helpers::watch_changes(&client, cursor, &options))` iterates over
+/// changes to a folder as they happen. Each call to `next` only does as much as is needed to get
+/// the next item.
+pub fn iter<S: Stream>(stream: S) -> BlockingIter<S> {
+    BlockingIter {
+        stream: Box::pin(stream),
+    }
+}
+
+/// An iterator over a stream, from [`iter`].
+#[must_use = "iterators do nothing unless iterated over"]
+pub struct BlockingIter<S> {
+    stream: Pin<Box<S>>,
+}
+
+impl<S: Stream> Iterator for BlockingIter<S> {
+    type Item = S::Item;
+
+    fn next(&mut self) -> Option<S::Item> {
+        block_on(self.stream.next())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.stream.size_hint()
+    }
+}
+
+impl<S> std::fmt::Debug for BlockingIter<S> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("BlockingIter").finish_non_exhaustive()
+    }
+}
@@ -0,0 +1,432 @@
+//! Logging the JSON sent to and received from the API, for debugging.
+//!
+//! Wrapping a client in a [`LoggingClient`] (or an [`AsyncLoggingClient`] for async clients) logs
+//! the arguments of each request and the JSON of each response, pretty-printed, at the `debug`
+//! level. This is meant for investigating problems such as a response the SDK can't deserialize:
+//! the log shows exactly what the server sent, which is what's needed to report it.
+//!
+//! Values which are likely to be private, such as paths and email addresses, are redacted from
+//! the log by default, as configured by [`BodyLogOptions`]. Access and refresh tokens are always
+//! redacted. File contents, form-encoded bodies (which are only used for OAuth2 requests), and
+//! other bodies which aren't JSON are never logged.
+//!
+//! Responses without a `Dropbox-API-Result` header are read into memory to be logged, but only
+//! when debug logging is enabled for this module; otherwise the wrapper makes no difference.
+
+use std::io::Read;
+use std::sync::Arc;
+use std::time::Duration;
+use bytes::Bytes;
+use futures::{AsyncReadExt, Future};
+use serde_json::Value;
+use crate::client_trait_common::{Endpoint, HttpRequest, TeamSelect};
+use crate::{async_client_trait, client_trait, Error};
+
+/// What's replaced by [`REDACTED`] regardless of the options: the fields of OAuth2 token
+/// responses which hold credentials.
+const ALWAYS_REDACTED: &[&str] = &["access_token", "refresh_token", "id_token"];
+
+/// What redacted values are replaced with in the log.
+pub const REDACTED: &str = "<redacted>";
+
+/// Options for logging bodies.
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub struct BodyLogOptions {
+    /// Redact the values of fields which usually hold paths or file names: `path`, `paths`,
+    /// `name`, and ones which start with `path_` or end with `_path`. Defaults to true.
+    pub redact_paths: bool,
+
+    /// Redact strings which look like email addresses, wherever they are. Defaults to true.
+    pub redact_emails: bool,
+
+    /// Names of other fields whose values should be redacted, at any depth. Defaults to none.
+    pub redact_fields: Vec<String>,
+
+    /// Truncate each logged body to this many bytes. Defaults to 4096.
+    pub max_len: usize,
+}
+
+impl Default for BodyLogOptions {
+    fn default() -> Self {
+        Self {
+            redact_paths: true,
+            redact_emails: true,
+            redact_fields: vec![],
+            max_len: 4096,
+        }
+    }
+}
+
+impl BodyLogOptions {
+    /// Set whether to redact paths and file names.
+    pub fn with_redact_paths(mut self, value: bool) -> Self {
+        self.redact_paths = value;
+        self
+    }
+
+    /// Set whether to redact email addresses.
+    pub fn with_redact_emails(mut self, value: bool) -> Self {
+        self.redact_emails = value;
+        self
+    }
+
+    /// Also redact the values of fields with the given name.
+    pub fn with_redact_field(mut self, name: impl Into<String>) -> Self {
+        self.redact_fields.push(name.into());
+        self
+    }
+
+    /// Set the length to truncate logged bodies to.
+    pub fn with_max_len(mut self, value: usize) -> Self {
+        self.max_len = value;
+        self
+    }
+
+    /// Pretty-print a JSON body with redactions, or if it isn't JSON, show it as text.
+    fn format(&self, body: &[u8]) -> String {
+        let mut text = match serde_json::from_slice::<Value>(body) {
+            Ok(mut value) => {
+                self.redact(&mut value, None);
+                serde_json::to_string_pretty(&value).expect("failed to serialize JSON value")
+            }
+            Err(_) => String::from_utf8_lossy(body).into_owned(),
+        };
+        if text.len() > self.max_len {
+            let mut end = self.max_len;
+            while !text.is_char_boundary(end) {
+                end -= 1;
+            }
+            let total = text.len();
+            text.truncate(end);
+            text.push_str(&format!("... ({total} bytes in total)"));
+        }
+        text
+    }
+
+    fn redact(&self, value: &mut Value, field: Option<&str>) {
+        match value {
+            Value::Object(map) => {
+                for (name, value) in map.iter_mut() {
+                    if self.is_redacted_field(name) {
+                        *value = Value::String(REDACTED.to_owned());
+                    } else {
+                        self.redact(value, Some(name));
+                    }
+                }
+            }
+            Value::Array(values) => {
+                for value in values {
+                    self.redact(value, field);
+                }
+            }
+            Value::String(s) => {
+                let path = self.redact_paths && field.map_or(false, is_path_field);
+                if path || (self.redact_emails && looks_like_email(s)) {
+                    *s = REDACTED.to_owned();
+                }
+            }
+            _ => (),
+        }
+    }
+
+    fn is_redacted_field(&self, name: &str) -> bool {
+        ALWAYS_REDACTED.contains(&name) || self.redact_fields.iter().any(|field| field == name)
+    }
+
+    fn log_request<R>(&self, request: &LoggedRequest<R>, body: &[u8]) {
+        if let Some(arg) = &request.arg {
+            debug!("request to {}:\n{}", request.route, self.format(arg.as_bytes()));
+        } else if request.gzip {
+            debug!("request to {}: {} bytes of gzip-compressed JSON", request.route, body.len());
+        } else if request.json && !body.is_empty() {
+            debug!("request to {}:\n{}", request.route, self.format(body));
+        } else {
+            debug!("request to {}", request.route);
+        }
+    }
+
+    fn log_response(&self, route: &str, status: u16, result_header: Option<&str>, body: &[u8]) {
+        match result_header {
+            Some(result) => {
+                debug!("response from {route} ({status}):\n{}", self.format(result.as_bytes()));
+            }
+            None => debug!("response from {route} ({status}):\n{}", self.format(body)),
+        }
+    }
+}
+
+fn is_path_field(name: &str) -> bool {
+    matches!(name, "path" | "paths" | "name")
+        || name.starts_with("path_")
+        || name.ends_with("_path")
+}
+
+fn looks_like_email(s: &str) -> bool {
+    match s.split_once('@') {
+        Some((user, domain)) => {
+            !user.is_empty()
+                && domain.contains('.')
+                && !domain.starts_with('.')
+                && !domain.ends_with('.')
+                && !s.contains(char::is_whitespace)
+        }
+        None => false,
+    }
+}
+
+fn io_error(e: std::io::Error) -> Error {
+    Error::HttpClient(Box::new(e))
+}
+
+/// A request made with a [`LoggingClient`] or [`AsyncLoggingClient`], which keeps track of what
+/// to log about it.
+#[derive(Debug)]
+pub struct LoggedRequest<R> {
+    inner: R,
+    route: String,
+    arg: Option<String>,
+    json: bool,
+    gzip: bool,
+}
+
+impl<R> LoggedRequest<R> {
+    fn new(inner: R, url: &str) -> Self {
+        let route = Endpoint::route_from_url(url).map_or(url, |(_, route)| route);
+        Self {
+            inner,
+            route: route.to_owned(),
+            arg: None,
+            json: false,
+            gzip: false,
+        }
+    }
+}
+
+impl<R: HttpRequest> HttpRequest for LoggedRequest<R> {
+    fn set_header(mut self, name: &str, value: &str) -> Self {
+        if name.eq_ignore_ascii_case("Dropbox-API-Arg") {
+            self.arg = Some(value.to_owned());
+        } else if name.eq_ignore_ascii_case("Content-Type") {
+            self.json = value == "application/json";
+        } else if name.eq_ignore_ascii_case("Content-Encoding") {
+            self.gzip = value == "gzip";
+        }
+        self.inner = self.inner.set_header(name, value);
+        self
+    }
+
+    fn set_timeout(mut self, timeout: Duration) -> Self {
+        self.inner = self.inner.set_timeout(timeout);
+        self
+    }
+}
+
+/// A sync client which logs the bodies of the requests made with another sync client, and of
+/// their responses.
+#[derive(Debug)]
+pub struct LoggingClient<C> {
+    inner: C,
+    options: Arc<BodyLogOptions>,
+}
+
+impl<C> LoggingClient<C> {
+    /// Log the requests made with the given client.
+    pub fn new(inner: C, options: BodyLogOptions) -> Self {
+        Self {
+            inner,
+            options: Arc::new(options),
+        }
+    }
+
+    /// The wrapped client.
+    pub fn inner(&self) -> &C {
+        &self.inner
+    }
+
+    fn finish(&self, route: &str, result: Result<client_trait::HttpRequestResultRaw, Error>)
+        -> Result<client_trait::HttpRequestResultRaw, Error>
+    {
+        let mut response = result?;
+        if !log_enabled!(log::Level::Debug) {
+            return Ok(response);
+        }
+        let mut body = vec![];
+        if response.result_header.is_none() {
+            response.body.read_to_end(&mut body).map_err(io_error)?;
+        }
+        self.options.log_response(
+            route, response.status, response.result_header.as_deref(), &body);
+        if response.result_header.is_none() {
+            response.body = Box::new(std::io::Cursor::new(body));
+        }
+        Ok(response)
+    }
+}
+
+impl<C: client_trait::HttpClient> client_trait::HttpClient for LoggingClient<C> {
+    type Request = LoggedRequest<C::Request>;
+
+    fn execute(&self, request: Self::Request, body: &[u8])
+        -> Result<client_trait::HttpRequestResultRaw, Error>
+    {
+        if log_enabled!(log::Level::Debug) {
+            self.options.log_request(&request, body);
+        }
+        let result = self.inner.execute(request.inner, body);
+        self.finish(&request.route, result)
+    }
+
+    fn execute_streaming(
+        &self,
+        request: Self::Request,
+        body: &mut (dyn Read + Send),
+        content_length: u64,
+    ) -> Result<client_trait::HttpRequestResultRaw, Error> {
+        if log_enabled!(log::Level::Debug) {
+            self.options.log_request(&request, &[]);
+        }
+        let result = self.inner.execute_streaming(request.inner, body, content_length);
+        self.finish(&request.route, result)
+    }
+
+    fn new_request(&self, url: &str) -> Self::Request {
+        LoggedRequest::new(self.inner.new_request(url), url)
+    }
+
+    fn update_token(&self, old_token: Arc<String>) -> Result<bool, Error> {
+        self.inner.update_token(old_token)
+    }
+
+    fn token(&self) -> Option<Arc<String>> {
+        self.inner.token()
+    }
+
+    fn path_root(&self) -> Option<&str> {
+        self.inner.path_root()
+    }
+
+    fn team_select(&self) -> Option<&TeamSelect> {
+        self.inner.team_select()
+    }
+
+    fn locale(&self) -> Option<&str> {
+        self.inner.locale()
+    }
+
+    fn extra_headers(&self) -> &[(String, String)] {
+        self.inner.extra_headers()
+    }
+
+    fn total_timeout(&self) -> Option<Duration> {
+        self.inner.total_timeout()
+    }
+
+    #[cfg(feature = "gzip")]
+    fn gzip_requests_over(&self) -> Option<usize> {
+        self.inner.gzip_requests_over()
+    }
+}
+
+impl<C: client_trait::NoauthClient> client_trait::NoauthClient for LoggingClient<C> {}
+impl<C: client_trait::UserAuthClient> client_trait::UserAuthClient for LoggingClient<C> {}
+impl<C: client_trait::TeamAuthClient> client_trait::TeamAuthClient for LoggingClient<C> {}
+impl<C: client_trait::AppAuthClient> client_trait::AppAuthClient for LoggingClient<C> {}
+
+/// An async client which logs the bodies of the requests made with another async client, and of
+/// their responses.
+#[derive(Debug)]
+pub struct AsyncLoggingClient<C> {
+    inner: C,
+    options: Arc<BodyLogOptions>,
+}
+
+impl<C> AsyncLoggingClient<C> {
+    /// Log the requests made with the given client.
+    pub fn new(inner: C, options: BodyLogOptions) -> Self {
+        Self {
+            inner,
+            options: Arc::new(options),
+        }
+    }
+
+    /// The wrapped client.
+    pub fn inner(&self) -> &C {
+        &self.inner
+    }
+}
+
+impl<C: async_client_trait::HttpClient> async_client_trait::HttpClient for AsyncLoggingClient<C> {
+    type Request = LoggedRequest<C::Request>;
+
+    async fn execute(&self, request: Self::Request, body: Bytes)
+        -> Result<async_client_trait::HttpRequestResultRaw, Error>
+    {
+        let enabled = log_enabled!(log::Level::Debug);
+        if enabled {
+            self.options.log_request(&request, &body);
+        }
+        let mut response = self.inner.execute(request.inner, body).await?;
+        if !enabled {
+            return Ok(response);
+        }
+        let mut body = vec![];
+        if response.result_header.is_none() {
+            response.body.read_to_end(&mut body).await.map_err(io_error)?;
+        }
+        self.options.log_response(
+            &request.route, response.status, response.result_header.as_deref(), &body);
+        if response.result_header.is_none() {
+            response.body = Box::new(futures::io::Cursor::new(body));
+        }
+        Ok(response)
+    }
+
+    fn new_request(&self, url: &str) -> Self::Request {
+        LoggedRequest::new(self.inner.new_request(url), url)
+    }
+
+    fn update_token(&self, old_token: Arc<String>)
+        -> impl Future<Output = Result<bool, Error>> + Send
+    {
+        self.inner.update_token(old_token)
+    }
+
+    fn token(&self) -> Option<Arc<String>> {
+        self.inner.token()
+    }
+
+    fn path_root(&self) -> Option<&str> {
+        self.inner.path_root()
+    }
+
+    fn team_select(&self) -> Option<&TeamSelect> {
+        self.inner.team_select()
+    }
+
+    fn locale(&self) -> Option<&str> {
+        self.inner.locale()
+    }
+
+    fn extra_headers(&self) -> &[(String, String)] {
+        self.inner.extra_headers()
+    }
+
+    fn total_timeout(&self) -> Option<Duration> {
+        self.inner.total_timeout()
+    }
+
+    #[cfg(feature = "gzip")]
+    fn gzip_requests_over(&self) -> Option<usize> {
+        self.inner.gzip_requests_over()
+    }
+}
+
+impl<C: async_client_trait::NoauthClient> async_client_trait::NoauthClient
+    for AsyncLoggingClient<C> {}
+impl<C: async_client_trait::UserAuthClient> async_client_trait::UserAuthClient
+    for AsyncLoggingClient<C> {}
+impl<C: async_client_trait::TeamAuthClient> async_client_trait::TeamAuthClient
+    for AsyncLoggingClient<C> {}
+impl<C: async_client_trait::AppAuthClient> async_client_trait::AppAuthClient
+    for AsyncLoggingClient<C> {}
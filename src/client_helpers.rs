@@ -1,16 +1,24 @@
 // Copyright (c) 2019-2021 Dropbox, Inc.
 
+use std::borrow::Cow;
+use std::cell::RefCell;
 use std::error::Error as StdError;
+use std::fmt::Write;
 use std::io::ErrorKind;
 use std::sync::Arc;
-use bytes::Bytes;
+use std::time::{Duration, Instant};
+use bytes::{BufMut, Bytes, BytesMut};
+use futures::future::Either;
 use futures::{AsyncRead, AsyncReadExt};
 use serde::{Deserialize};
 use serde::de::DeserializeOwned;
 use serde::ser::Serialize;
 use crate::Error;
 use crate::async_client_trait::{HttpClient, HttpRequestResult, HttpRequestResultRaw};
-use crate::client_trait_common::{Endpoint, HttpRequest, ParamsType, Style, TeamSelect};
+use crate::client_trait_common::{
+    content_disposition_filename, ContentRange, DeadlineExceeded, Endpoint, HttpRequest,
+    ParamsType, Style, TeamSelect, NOTIFY_TIMEOUT,
+};
 use crate::types::auth::{AccessError, AuthError, RateLimitReason};
 
 /// When Dropbox returns an error with HTTP 409 or 429, it uses an implicit JSON object with the
@@ -35,24 +43,70 @@ struct RateLimitedError {
     pub retry_after: u32,
 }
 
+/// How much space to make sure is available in the parameter buffer before serializing into it.
+/// Parameters larger than this are fine; the buffer just grows.
+const PARAMS_BUF_RESERVE: usize = 4096;
+
+/// The largest parameters whose allocation the parameter buffer keeps for reuse. After serializing
+/// larger ones, such as a big batch of paths, the buffer starts over, so that the thread doesn't
+/// hold on to the memory once the request is done.
+const PARAMS_BUF_MAX: usize = 64 * 1024;
+
+/// The `User-Agent` header sent with every request.
+pub(crate) const USER_AGENT: &str = concat!("Dropbox-SDK-Rust/", env!("CARGO_PKG_VERSION"));
+
+thread_local! {
+    /// A buffer for serializing request parameters into, reused across requests made on the same
+    /// thread to avoid allocating for each one.
+    ///
+    /// Each request's parameters are split off of the buffer as a [`Bytes`], which shares its
+    /// allocation. Once those are dropped after the request is done, the next `reserve` reclaims
+    /// the space instead of allocating more. Parameters over [`PARAMS_BUF_MAX`] get the allocation
+    /// to themselves, and it's freed along with them.
+    static PARAMS_BUF: RefCell<BytesMut> = RefCell::new(BytesMut::new());
+}
+
+/// Serialize request parameters as JSON, using the thread's parameter buffer.
+fn serialize_params<P: Serialize>(params: &P) -> Result<Bytes, serde_json::Error> {
+    PARAMS_BUF.with(|buf| {
+        let mut buf = buf.borrow_mut();
+        buf.reserve(PARAMS_BUF_RESERVE);
+        let result = serde_json::to_writer((&mut *buf).writer(), params);
+        // Split it off even on error, so that the next request starts with an empty buffer.
+        let json = buf.split().freeze();
+        if json.len() > PARAMS_BUF_MAX {
+            *buf = BytesMut::new();
+        }
+        result.map(|()| json)
+    })
+}
+
 #[allow(clippy::too_many_arguments)]
 pub(crate) fn prepare_request<T: HttpClient>(
     client: &T,
     endpoint: Endpoint,
     style: Style,
     function: &str,
-    params: String,
+    params: Bytes,
     params_type: ParamsType,
     range_start: Option<u64>,
     range_end: Option<u64>,
     token: Option<&str>,
     path_root: Option<&str>,
     team_select: Option<&TeamSelect>,
+    locale: Option<&str>,
 ) -> (T::Request, Option<Bytes>) {
     let url = endpoint.url().to_owned() + function;
 
     let mut req = client.new_request(&url);
-    req = req.set_header("User-Agent", concat!("Dropbox-SDK-Rust/", env!("CARGO_PKG_VERSION")));
+    req = req.set_header("User-Agent", USER_AGENT);
+    for (name, value) in client.extra_headers() {
+        req = req.set_header(name, value);
+    }
+
+    if endpoint == Endpoint::Notify {
+        req = req.set_timeout(NOTIFY_TIMEOUT);
+    }
 
     if let Some(token) = token {
         req = req.set_header("Authorization", &format!("Bearer {token}"));
@@ -69,6 +123,10 @@ pub(crate) fn prepare_request<T: HttpClient>(
         };
     }
 
+    if let Some(locale) = locale {
+        req = req.set_header("Dropbox-API-User-Locale", locale);
+    }
+
     req = match (range_start, range_end) {
         (Some(start), Some(end)) => req.set_header("Range", &format!("bytes={start}-{end}")),
         (Some(start), None) => req.set_header("Range", &format!("bytes={start}-")),
@@ -82,16 +140,26 @@ pub(crate) fn prepare_request<T: HttpClient>(
             Style::Rpc => {
                 // Send params in the body.
                 req = req.set_header("Content-Type", params_type.content_type());
-                params_body = Some(Bytes::from(params));
+                #[cfg(feature = "gzip")]
+                let params = match client.gzip_requests_over() {
+                    Some(threshold)
+                        if params_type == ParamsType::Json && params.len() > threshold =>
+                    {
+                        req = req.set_header("Content-Encoding", "gzip");
+                        gzip(&params)
+                    }
+                    _ => params,
+                };
+                params_body = Some(params);
             }
             Style::Upload => {
                 // Send params in a header.
-                req = req.set_header("Dropbox-API-Arg", &params);
+                req = req.set_header("Dropbox-API-Arg", &json_escape_header(params_str(&params)));
                 req = req.set_header("Content-Type", "application/octet-stream");
             }
             Style::Download => {
                 // Send params in a header.
-                req = req.set_header("Dropbox-API-Arg", &params);
+                req = req.set_header("Dropbox-API-Arg", &json_escape_header(params_str(&params)));
             }
         }
     };
@@ -99,6 +167,59 @@ pub(crate) fn prepare_request<T: HttpClient>(
     (req, params_body)
 }
 
+/// Compress request parameters with gzip.
+#[cfg(feature = "gzip")]
+fn gzip(params: &[u8]) -> Bytes {
+    use std::io::Write as _;
+    let mut encoder = flate2::write::GzEncoder::new(
+        Vec::with_capacity(params.len() / 4),
+        flate2::Compression::fast(),
+    );
+    // Writing to a Vec can't fail.
+    encoder.write_all(params).expect("gzip compression failed");
+    Bytes::from(encoder.finish().expect("gzip compression failed"))
+}
+
+fn params_str(params: &[u8]) -> &str {
+    // Parameters are always serialized as JSON or form-encoded, both of which are UTF-8.
+    std::str::from_utf8(params).expect("request parameters are not UTF-8")
+}
+
+/// Escape a JSON string for use as a HTTP header value, by replacing any non-ASCII characters (and
+/// 0x7F) with JSON-style `\uXXXX` escape sequences. Characters outside the Basic Multilingual Plane
+/// are escaped as a UTF-16 surrogate pair, as JSON requires.
+///
+/// HTTP header values can only contain visible ASCII characters, so this is needed for the
+/// `Dropbox-API-Arg` header whenever the arguments contain something like a non-ASCII file name.
+/// Only string contents can contain such characters, and the escape sequences are valid there, so
+/// the result is still the same JSON value. Otherwise, the string is returned unmodified without
+/// any additional allocation or copying.
+fn json_escape_header(s: &str) -> Cow<'_, str> {
+    // Unfortunately, the HTTP spec requires escaping ASCII DEL (0x7F), so we can't use the quicker
+    // bit pattern check done in str::is_ascii() to skip this for the common case of all ASCII. :(
+
+    let mut out = Cow::Borrowed(s);
+    for (i, c) in s.char_indices() {
+        if !c.is_ascii() || c == '\x7f' {
+            let mstr = match out {
+                Cow::Borrowed(_) => {
+                    // If we're still borrowed, we must have had ascii up until this point.
+                    // Clone the string up until here, and from now on we'll be pushing chars to it.
+                    out = Cow::Owned(s[0..i].to_owned());
+                    out.to_mut()
+                }
+                Cow::Owned(ref mut m) => m,
+            };
+            for unit in c.encode_utf16(&mut [0; 2]) {
+                write!(mstr, "\\u{:04x}", unit).unwrap();
+            }
+        } else if let Cow::Owned(ref mut o) = out {
+            o.push(c);
+        }
+    }
+    out
+}
+
 pub(crate) async fn body_to_string(body: &mut (dyn AsyncRead + Send + Unpin)) -> Result<String, Error> {
     let mut s = String::new();
     match body.read_to_string(&mut s).await {
@@ -117,6 +238,10 @@ pub(crate) async fn body_to_string(body: &mut (dyn AsyncRead + Send + Unpin)) ->
 /// went wrong in the process of making the request (I/O errors, parse errors, server 500 errors,
 /// etc.). The inner result has an error if the server returned one for the request, otherwise it
 /// has the deserialized JSON response and the body stream (if any).
+///
+/// If the client has a [`total_timeout`](HttpClient::total_timeout), this fails with
+/// [`DeadlineExceeded`] if it takes longer than that in total. The body stream keeps the request's
+/// timeout, so reading it fails once that runs out.
 #[allow(clippy::too_many_arguments)]
 pub async fn request_with_body<'a, T, E, P, C>(
     client: &C,
@@ -133,18 +258,69 @@ pub async fn request_with_body<'a, T, E, P, C>(
     P: Serialize,
     C: HttpClient,
 {
+    let Some(timeout) = client.total_timeout() else {
+        return request_before_deadline(
+            client, endpoint, style, function, params, body, range_start, range_end, None).await;
+    };
+    let deadline = Instant::now() + timeout;
+    let request = request_before_deadline(
+        client, endpoint, style, function, params, body, range_start, range_end, Some(deadline));
+    futures::pin_mut!(request);
+    // Sync clients complete the request when it's first polled, so they never wait on the timer;
+    // their requests are bounded by the timeouts set on them instead.
+    match futures::future::select(request, futures_timer::Delay::new(timeout)).await {
+        Either::Left((result, _)) => result,
+        Either::Right(_) => Err(DeadlineExceeded.into()),
+    }
+}
+
+/// The time left before a deadline, if there is one, or an error if it has passed.
+fn time_left(deadline: Option<Instant>) -> Result<Option<Duration>, DeadlineExceeded> {
+    match deadline.map(|deadline| deadline.saturating_duration_since(Instant::now())) {
+        Some(left) if left.is_zero() => Err(DeadlineExceeded),
+        left => Ok(left),
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+async fn request_before_deadline<'a, T, E, P, C>(
+    client: &C,
+    endpoint: Endpoint,
+    style: Style,
+    function: &str,
+    params: &P,
+    body: Option<Body<'a>>,
+    range_start: Option<u64>,
+    range_end: Option<u64>,
+    deadline: Option<Instant>,
+) -> Result<HttpRequestResult<T>, Error<E>> where
+    T: DeserializeOwned,
+    E: DeserializeOwned + StdError,
+    P: Serialize,
+    C: HttpClient,
+{
+    crate::deprecation::warn_if_deprecated(function);
+    if let (Some(start), Some(end)) = (range_start, range_end) {
+        if end < start {
+            return Err(Error::BadRequest(format!("invalid range {start}-{end}: the end of a range \
+                can't be before its start")));
+        }
+    }
+    // The notify endpoint doesn't accept authentication, even from clients which have it.
+    let use_auth = endpoint != Endpoint::Notify;
     let mut retried = false;
     'auth_retry: loop {
-        let params_json = serde_json::to_string(params)?;
-        let token = client.token();
-        if token.is_none()
+        let params_json = serialize_params(params)?;
+        let token = if use_auth { client.token() } else { None };
+        if use_auth
+            && token.is_none()
             && !retried
             && client.update_token(Arc::new(String::new())).await.map_err(Error::typed)?
         {
             retried = true;
             continue 'auth_retry;
         }
-        let (req, params_body) = prepare_request(
+        let (mut req, params_body) = prepare_request(
             client,
             endpoint,
             style,
@@ -156,21 +332,41 @@ pub async fn request_with_body<'a, T, E, P, C>(
             token.as_ref().map(|t| t.as_str()),
             client.path_root(),
             client.team_select(),
+            client.locale(),
         );
+        if let Some(left) = time_left(deadline)? {
+            req = req.set_timeout(if endpoint == Endpoint::Notify {
+                left.min(NOTIFY_TIMEOUT)
+            } else {
+                left
+            });
+        }
         let result = match (params_body, body.clone()) {
             (None, None) => client.execute(req, Bytes::new()).await,
             (Some(params_body), _) => client.execute(req, params_body).await,
 
-            #[cfg(feature = "async_routes")]
             (None, Some(Body::Owned((body_bytes, ..)))) => client.execute(req, body_bytes).await,
 
             #[cfg(feature = "sync_routes")]
             (None, Some(Body::Borrowed(body_slice))) => client.execute_borrowed_body(req, body_slice).await,
+
+            #[cfg(feature = "sync_routes")]
+            (None, Some(Body::Stream(stream))) => match (stream.open)() {
+                Ok(mut reader) => client.execute_streaming_body(req, &mut *reader, stream.len).await,
+                Err(e) => Err(Error::HttpClient(Box::new(e))),
+            },
         };
         return match result {
             Ok(raw_resp) => {
+                #[cfg(feature = "miette")]
+                crate::diagnostic::record(
+                    function, raw_resp.status, raw_resp.request_id.as_deref());
                 let status = raw_resp.status;
-                let (json, content_length, body) = match parse_response(raw_resp, style).await {
+                let content_range = raw_resp.content_range.clone();
+                let filename = raw_resp.content_disposition.as_deref()
+                    .and_then(content_disposition_filename);
+                let parsed = parse_response(raw_resp, style, function).await;
+                let (json, content_length, body) = match parsed {
                     Ok(x) => x,
                     Err(e @ Error::Authentication(AuthError::ExpiredAccessToken)) if !retried => {
                         let old_token = token.unwrap_or_else(|| Arc::new(String::new()));
@@ -182,7 +378,13 @@ pub async fn request_with_body<'a, T, E, P, C>(
                         }
                     }
                     Err(e) => {
-                        error!("HTTP {status}: {e}");
+                        if let Some(scope) = e.missing_scope() {
+                            error!("{function}: the access token is missing the required scope \
+                                {scope:?}; enable it in the App Console and re-authorize the app \
+                                requesting it");
+                        } else {
+                            error!("HTTP {status}: {e}");
+                        }
                         return Err(e.typed())
                     },
                 };
@@ -190,21 +392,30 @@ pub async fn request_with_body<'a, T, E, P, C>(
                 if status == 409 {
                     // Response should be JSON-deseraializable into the strongly-typed
                     // error specified by type parameter E.
-                    return match serde_json::from_str::<TopLevelError<E>>(&json) {
+                    return match parse_json::<TopLevelError<E>>(&json) {
                         Ok(deserialized) => {
                             error!("API error: {}", deserialized.error);
                             Err(Error::Api(deserialized.error))
                         },
                         Err(de_error) => {
                             error!("Failed to deserialize JSON from API error: {}", de_error);
-                            Err(Error::Json(de_error))
+                            Err(deserialize_error(function, de_error, &json))
                         }
                     };
                 }
 
+                let result = parse_json(&json).map_err(|de_error| {
+                    error!("Failed to deserialize JSON from API response: {}", de_error);
+                    deserialize_error(function, de_error, &json)
+                })?;
+                let content_range = check_content_range(
+                    status, content_range.as_deref(), range_start, range_end)
+                    .map_err(Error::typed)?;
                 Ok(HttpRequestResult {
-                    result: serde_json::from_str(&json)?,
+                    result,
                     content_length,
+                    content_range,
+                    filename,
                     body,
                 })
             }
@@ -213,13 +424,139 @@ pub async fn request_with_body<'a, T, E, P, C>(
     }
 }
 
-pub(crate) async fn parse_response(raw_resp: HttpRequestResultRaw, style: Style)
+/// Parse the `Content-Range` header of a partial response, and check that it's for the range
+/// which was asked for.
+fn check_content_range(
+    status: u16,
+    header: Option<&str>,
+    range_start: Option<u64>,
+    range_end: Option<u64>,
+) -> Result<Option<ContentRange>, Error> {
+    if status != 206 {
+        return Ok(None);
+    }
+    let header = header.ok_or_else(|| {
+        Error::UnexpectedResponse("partial response without a Content-Range header".to_owned())
+    })?;
+    let range = ContentRange::parse(header).ok_or_else(|| {
+        Error::UnexpectedResponse(format!("invalid Content-Range header {header:?}"))
+    })?;
+    if !range.satisfies(range_start, range_end) {
+        return Err(Error::UnexpectedResponse(format!(
+            "partial response for {header:?} doesn't match the range requested")));
+    }
+    Ok(Some(range))
+}
+
+/// Makes a request with the same authentication handling and error mapping as
+/// [`request_with_body`], but returns the raw response if it succeeded, without parsing it.
+#[cfg(any(feature = "dispatch", feature = "dbx_files"))]
+pub(crate) async fn request_raw<E, C>(
+    client: &C,
+    endpoint: Endpoint,
+    style: Style,
+    function: &str,
+    params: Bytes,
+    params_type: ParamsType,
+    body: Bytes,
+) -> Result<HttpRequestResultRaw, Error<E>> where
+    E: DeserializeOwned + StdError,
+    C: HttpClient,
+{
+    crate::deprecation::warn_if_deprecated(function);
+    // The notify endpoint doesn't accept authentication, even from clients which have it.
+    let use_auth = endpoint != Endpoint::Notify;
+    let mut retried = false;
+    loop {
+        let token = if use_auth { client.token() } else { None };
+        if use_auth
+            && token.is_none()
+            && !retried
+            && client.update_token(Arc::new(String::new())).await.map_err(Error::typed)?
+        {
+            retried = true;
+            continue;
+        }
+        let (req, params_body) = prepare_request(
+            client,
+            endpoint,
+            style,
+            function,
+            params.clone(),
+            params_type,
+            None,
+            None,
+            token.as_ref().map(|t| t.as_str()),
+            client.path_root(),
+            client.team_select(),
+            client.locale(),
+        );
+        let raw_resp = client.execute(req, params_body.unwrap_or_else(|| body.clone())).await
+            .map_err(Error::typed)?;
+        #[cfg(feature = "miette")]
+        crate::diagnostic::record(function, raw_resp.status, raw_resp.request_id.as_deref());
+        if (200..300).contains(&raw_resp.status) {
+            return Ok(raw_resp);
+        }
+        return match parse_response(raw_resp, style, function).await {
+            Err(e @ Error::Authentication(AuthError::ExpiredAccessToken)) if !retried => {
+                let old_token = token.unwrap_or_else(|| Arc::new(String::new()));
+                if client.update_token(old_token).await.map_err(Error::typed)? {
+                    retried = true;
+                    continue;
+                }
+                Err(e.typed())
+            }
+            Err(e) => Err(e.typed()),
+            // Only a 409 gets here, with the route's error.
+            Ok((json, ..)) => match parse_json::<TopLevelError<E>>(&json) {
+                Ok(deserialized) => Err(Error::Api(deserialized.error)),
+                Err(de_error) => Err(deserialize_error(function, de_error, &json)),
+            },
+        };
+    }
+}
+
+/// Deserialize a JSON response.
+///
+/// With the `simd-json` feature, this uses simd-json, and only falls back to serde_json if that
+/// fails, so that errors are still reported as [`serde_json::Error`].
+fn parse_json<T: DeserializeOwned>(json: &str) -> Result<T, serde_json::Error> {
+    #[cfg(feature = "simd-json")]
+    {
+        // simd-json parses in place, so it needs its own copy.
+        let mut bytes = json.as_bytes().to_vec();
+        if let Ok(value) = simd_json::serde::from_slice(&mut bytes) {
+            return Ok(value);
+        }
+    }
+    serde_json::from_str(json)
+}
+
+/// How much of a response to include in an [`Error::Deserialize`].
+const BODY_SNIPPET_LEN: usize = 1024;
+
+pub(crate) fn deserialize_error<E>(route: &str, source: serde_json::Error, body: &str) -> Error<E> {
+    let mut end = body.len().min(BODY_SNIPPET_LEN);
+    while !body.is_char_boundary(end) {
+        end -= 1;
+    }
+    Error::Deserialize {
+        route: route.to_owned(),
+        source,
+        body_snippet: body[..end].to_owned(),
+    }
+}
+
+pub(crate) async fn parse_response(raw_resp: HttpRequestResultRaw, style: Style, route: &str)
     -> Result<(String, Option<u64>, Option<Box<dyn AsyncRead + Send + Unpin>>), Error> {
     let HttpRequestResultRaw {
         status,
         result_header,
         content_length,
-        mut body
+        retry_after,
+        mut body,
+        ..
     } = raw_resp;
     if (200..300).contains(&status) {
         Ok(match style {
@@ -254,7 +591,7 @@ pub(crate) async fn parse_response(raw_resp: HttpRequestResultRaw, style: Style)
                     }
                     Err(de_error) => {
                         error!("Failed to deserialize JSON from API error: {response}");
-                        Err(Error::Json(de_error))
+                        Err(deserialize_error(route, de_error, &response))
                     }
                 }
             },
@@ -265,7 +602,7 @@ pub(crate) async fn parse_response(raw_resp: HttpRequestResultRaw, style: Style)
                     }
                     Err(de_error) => {
                         error!("Failed to deserialize JSON from API error: {response}");
-                        Err(Error::Json(de_error))
+                        Err(deserialize_error(route, de_error, &response))
                     }
                 }
             }
@@ -284,10 +621,16 @@ pub(crate) async fn parse_response(raw_resp: HttpRequestResultRaw, style: Style)
                     }
                     Err(de_error) => {
                         error!("Failed to deserialize JSON from API error: {response}");
-                        Err(Error::Json(de_error))
+                        Err(deserialize_error(route, de_error, &response))
                     }
                 }
             },
+            503 => match retry_after.as_deref().and_then(parse_retry_after) {
+                Some(retry_after_seconds) => {
+                    Err(Error::ServiceUnavailable { retry_after_seconds, response })
+                }
+                None => Err(Error::ServerError(response)),
+            },
             500..=599 => {
                 Err(Error::ServerError(response))
             },
@@ -296,17 +639,43 @@ pub(crate) async fn parse_response(raw_resp: HttpRequestResultRaw, style: Style)
     }
 }
 
+/// Parse a `Retry-After` header given in seconds. The other form, an HTTP date, isn't supported,
+/// as Dropbox doesn't use it.
+fn parse_retry_after(value: &str) -> Option<u32> {
+    value.trim().parse().ok()
+}
+
 #[derive(Debug, Clone)]
 pub(crate) enum Body<'a> {
     #[cfg(feature = "sync_routes")]
     Borrowed(&'a [u8]),
 
-    #[cfg(feature = "async_routes")]
+    #[cfg(feature = "sync_routes")]
+    Stream(StreamBody<'a>),
+
     // PhantomData because otherwise if sync_routes is turned off, nothing uses the 'a lifetime
     Owned((Bytes, std::marker::PhantomData<&'a ()>)),
 }
 
-#[cfg(feature = "async_routes")]
+/// A request body which is streamed from a reader. Only sync clients support this.
+#[cfg(feature = "sync_routes")]
+#[derive(Clone, Copy)]
+pub(crate) struct StreamBody<'a> {
+    /// Opens a reader for the body. This is called again for each attempt, so that requests can be
+    /// retried.
+    pub open: &'a (dyn Fn() -> std::io::Result<Box<dyn std::io::Read + Send>> + Sync),
+
+    /// How many bytes the reader gives.
+    pub len: u64,
+}
+
+#[cfg(feature = "sync_routes")]
+impl std::fmt::Debug for StreamBody<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("StreamBody").field("len", &self.len).finish_non_exhaustive()
+    }
+}
+
 impl From<Bytes> for Body<'_> {
     fn from(value: Bytes) -> Self {
         Body::Owned((value, std::marker::PhantomData))
@@ -336,41 +705,24 @@ pub async fn request<T: DeserializeOwned, E: DeserializeOwned + StdError, P: Ser
 #[cfg(feature = "sync_routes")]
 mod sync_helpers {
     use std::future::Future;
-    use futures::{AsyncRead, FutureExt};
-    use crate::async_client_trait::{HttpRequestResult, SyncReadAdapter};
+    use std::io::Read;
+    use futures::FutureExt;
+    use crate::async_client_trait::{HttpRequestResult, ReadyReadAdapter};
     use crate::client_trait as sync;
     use crate::Error;
 
     /// Given an async HttpRequestResult which was created from a *sync* HttpClient, convert it to the
-    /// sync HttpRequestResult by cracking open the SyncReadAdapter in the body.
-    ///
-    /// This is ONLY safe if the result was created by a sync HttpClient, so we require it as an
-    /// argument just to be extra careful.
+    /// sync HttpRequestResult by adapting the body back to a sync reader. The body is then always
+    /// ready, as it reads from the sync client's body.
     #[cfg(feature = "sync_routes")]
     #[inline]
-    pub(crate) fn unwrap_async_result<T>(
-        r: HttpRequestResult<T>,
-        _client: &impl sync::HttpClient,
-    ) -> sync::HttpRequestResult<T> {
-        match r.body {
-            Some(async_read) => {
-                let p: *mut dyn AsyncRead = Box::into_raw(async_read);
-                // SAFETY: the only body value an async HttpRequestResult created for a sync client
-                // can be is a SyncReadAdapter.
-                let adapter = unsafe {
-                    Box::<SyncReadAdapter>::from_raw(p as *mut SyncReadAdapter)
-                };
-                sync::HttpRequestResult {
-                    result: r.result,
-                    content_length: r.content_length,
-                    body: Some(adapter.inner),
-                }
-            }
-            None => sync::HttpRequestResult {
-                result: r.result,
-                content_length: r.content_length,
-                body: None,
-            }
+    pub(crate) fn unwrap_async_result<T>(r: HttpRequestResult<T>) -> sync::HttpRequestResult<T> {
+        sync::HttpRequestResult {
+            result: r.result,
+            content_length: r.content_length,
+            content_range: r.content_range,
+            filename: r.filename,
+            body: r.body.map(|body| Box::new(ReadyReadAdapter::new(body)) as Box<dyn Read>),
         }
     }
 
@@ -378,11 +730,11 @@ mod sync_helpers {
     #[inline]
     pub(crate) fn unwrap_async_body<T, E>(
         f: impl Future<Output = Result<HttpRequestResult<T>, Error<E>>>,
-        client: &impl sync::HttpClient,
+        _client: &impl sync::HttpClient,
     ) -> Result<sync::HttpRequestResult<T>, Error<E>> {
         let r = f.now_or_never().expect("sync future should resolve immediately");
         match r {
-            Ok(v) => Ok(unwrap_async_result(v, client)),
+            Ok(v) => Ok(unwrap_async_result(v)),
             Err(e) => Err(e),
         }
     }
@@ -398,3 +750,38 @@ mod sync_helpers {
 
 #[cfg(feature = "sync_routes")]
 pub(crate) use sync_helpers::*;
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_json_escape() {
+        assert_eq!(Cow::Borrowed("foobar"), json_escape_header("foobar"));
+        assert_eq!(
+            Cow::<'_, str>::Owned("tro\\u0161kovi".to_owned()),
+            json_escape_header("troškovi"));
+        assert_eq!(
+            Cow::<'_, str>::Owned(
+                r#"{"field": "some_\u00fc\u00f1\u00eec\u00f8d\u00e9_and_\u007f"}"#.to_owned()),
+            json_escape_header("{\"field\": \"some_üñîcødé_and_\x7f\"}"));
+        assert_eq!(
+            Cow::<'_, str>::Owned("almost,\\u007f but not quite".to_owned()),
+            json_escape_header("almost,\x7f but not quite"));
+        assert_eq!(
+            Cow::<'_, str>::Owned("\\u6587\\u4ef6 \\ud83d\\ude00.txt".to_owned()),
+            json_escape_header("文件 😀.txt"));
+    }
+
+    #[test]
+    fn test_params_buf_shrinks() {
+        let capacity = || PARAMS_BUF.with(|buf| buf.borrow().capacity());
+        let small = serialize_params(&"a".repeat(100)).unwrap();
+        assert_eq!(102, small.len());
+        assert!(capacity() >= PARAMS_BUF_RESERVE - small.len());
+
+        let big = serialize_params(&"a".repeat(PARAMS_BUF_MAX)).unwrap();
+        assert_eq!(PARAMS_BUF_MAX + 2, big.len());
+        assert_eq!(0, capacity());
+    }
+}
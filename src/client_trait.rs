@@ -4,7 +4,8 @@
 
 use std::io::Read;
 use std::sync::Arc;
-use crate::client_trait_common::{HttpRequest, TeamSelect};
+use std::time::Duration;
+use crate::client_trait_common::{read_body, HttpRequest, TeamSelect, CLOSE_DRAIN_LIMIT};
 use crate::Error;
 
 /// The base HTTP synchronous client trait.
@@ -19,6 +20,21 @@ pub trait HttpClient: Sync {
         body: &[u8],
     ) -> Result<HttpRequestResultRaw, Error>;
 
+    /// Make a HTTP request, streaming the body from a reader instead of taking it all at once.
+    ///
+    /// The body is exactly `content_length` bytes long. The default implementation reads it into
+    /// memory and calls [`execute`](Self::execute); clients which can send the body as they read
+    /// it should override this.
+    fn execute_streaming(
+        &self,
+        request: Self::Request,
+        body: &mut (dyn Read + Send),
+        content_length: u64,
+    ) -> Result<HttpRequestResultRaw, Error> {
+        let buf = read_body(body, content_length)?;
+        self.execute(request, &buf)
+    }
+
     /// Create a new request instance for the given URL. It should be a POST request.
     fn new_request(&self, url: &str) -> Self::Request;
 
@@ -44,9 +60,47 @@ pub trait HttpClient: Sync {
     fn team_select(&self) -> Option<&TeamSelect> {
         None
     }
+
+    /// The locale to request user-facing strings in, if any, as an IETF language tag such as
+    /// `"en-GB"`. This is sent in the `Dropbox-API-User-Locale` header.
+    fn locale(&self) -> Option<&str> {
+        None
+    }
+
+    /// Extra headers to send with every request, as `(name, value)` pairs, such as ones which opt
+    /// in to beta behavior of the API. These are sent in addition to the headers the SDK sets
+    /// itself, so they shouldn't include any of those, such as `Authorization`.
+    fn extra_headers(&self) -> &[(String, String)] {
+        &[]
+    }
+
+    /// The longest one call of a route may take in total, including refreshing the access token,
+    /// retrying with the new one, and reading the body of a download, or `None` for no limit. Each
+    /// request is given the time remaining as its timeout. The call fails with
+    /// [`DeadlineExceeded`](crate::client_trait_common::DeadlineExceeded) if it runs out before the
+    /// response arrives, and reading the body fails with the client's timeout error if it runs out
+    /// after, so this should allow for the largest download expected.
+    fn total_timeout(&self) -> Option<Duration> {
+        None
+    }
+
+    /// Compress JSON request bodies larger than this many bytes with gzip, sending them with
+    /// `Content-Encoding: gzip`, or `None` to never compress them. This only applies to the
+    /// arguments of RPC-style routes, which can be large for batch operations; upload and download
+    /// arguments are sent in a header, and file contents are never compressed.
+    #[cfg(feature = "gzip")]
+    fn gzip_requests_over(&self) -> Option<usize> {
+        None
+    }
 }
 
 /// Marker trait to indicate that a HTTP client supports unauthenticated routes.
+///
+/// Each route function requires its client to implement the marker trait for one of the kinds of
+/// authentication the route accepts, so calling a route with the wrong kind of client is a compile
+/// error. When implementing these for your own client, only implement the ones for the
+/// authentication it actually performs; a client implementing more than that can call routes
+/// which will fail at runtime instead.
 pub trait NoauthClient: HttpClient {}
 
 /// Marker trait to indicate that a HTTP client supports User authentication.
@@ -64,6 +118,10 @@ pub trait TeamAuthClient: HttpClient {}
 pub trait AppAuthClient: HttpClient {}
 
 /// The raw response from the server, including a sync streaming response body.
+///
+/// More headers may be added to this in the future, so make one with [`new`](Self::new) and then
+/// set the fields for any headers present.
+#[non_exhaustive]
 pub struct HttpRequestResultRaw {
     /// HTTP response code.
     pub status: u16,
@@ -74,22 +132,101 @@ pub struct HttpRequestResultRaw {
     /// The value of the `Content-Length` header in the response, if present.
     pub content_length: Option<u64>,
 
+    /// The value of the `Retry-After` header in the response, if present.
+    pub retry_after: Option<String>,
+
+    /// The value of the `Content-Range` header in the response, if present.
+    pub content_range: Option<String>,
+
+    /// The value of the `Content-Disposition` header in the response, if present.
+    pub content_disposition: Option<String>,
+
+    /// The value of the `X-Dropbox-Request-Id` header in the response, if present, which
+    /// identifies the request to Dropbox support.
+    pub request_id: Option<String>,
+
     /// The response body stream.
+    ///
+    /// Dropping this before reading all of it must not leave the connection in a state where it's
+    /// reused with the unread data still pending: clients should either close the connection, or
+    /// read the rest of the body first.
     pub body: Box<dyn Read + Send>,
 }
 
+impl HttpRequestResultRaw {
+    /// A response with the given status code and body, and none of the headers the SDK looks at.
+    pub fn new(status: u16, body: Box<dyn Read + Send>) -> Self {
+        Self {
+            status,
+            result_header: None,
+            content_length: None,
+            retry_after: None,
+            content_range: None,
+            content_disposition: None,
+            request_id: None,
+            body,
+        }
+    }
+}
+
 /// The response from the server, parsed into a given type, including a body stream if it is from
 /// a Download style request.
+///
+/// More response details may be added to this in the future, so make one with
+/// [`new`](Self::new) and then set the fields for any others.
+#[non_exhaustive]
 pub struct HttpRequestResult<T> {
     /// The API result, parsed into the given type.
     pub result: T,
 
     /// The value of the `Content-Length` header in the response, if any. Only expected to not be
-    /// `None` if `body` is also not `None`.
+    /// `None` if `body` is also not `None`. For a partial response to a request for a range, this
+    /// is the length of the part returned, not of the whole file.
     pub content_length: Option<u64>,
 
+    /// The part of the file returned, if this is a partial response to a request for a range.
+    pub content_range: Option<crate::client_trait_common::ContentRange>,
+
+    /// The file name given by the `Content-Disposition` header in the response, if any, such as
+    /// for downloads from shared links. See
+    /// [`content_disposition_filename`](crate::client_trait_common::content_disposition_filename).
+    pub filename: Option<String>,
+
     /// The response body stream, if any. Only expected to not be `None` for
     /// [`Style::Download`](crate::client_trait_common::Style::Download) endpoints.
     pub body: Option<Box<dyn Read>>,
 }
 
+impl<T> HttpRequestResult<T> {
+    /// A response with the given result and body, and no other details.
+    pub fn new(result: T, body: Option<Box<dyn Read>>) -> Self {
+        Self {
+            result,
+            content_length: None,
+            content_range: None,
+            filename: None,
+            body,
+        }
+    }
+
+    /// Drop the response body without reading any more of it.
+    ///
+    /// The connection it was being read from is closed instead of being reused, so this is the
+    /// quickest way to stop a download partway through. Dropping the whole result does the same.
+    pub fn abort(&mut self) {
+        self.body = None;
+    }
+
+    /// Finish with the response body, reading and discarding the rest of it if there's no more
+    /// than [`CLOSE_DRAIN_LIMIT`] bytes left, so that the connection can be reused for another
+    /// request. If there's more left than that, the body is [aborted](Self::abort) instead.
+    pub fn close(&mut self) -> std::io::Result<()> {
+        if let Some(body) = self.body.take() {
+            let drained = std::io::copy(&mut body.take(CLOSE_DRAIN_LIMIT + 1), &mut std::io::sink())?;
+            if drained > CLOSE_DRAIN_LIMIT {
+                debug!("aborting a response body with more than {CLOSE_DRAIN_LIMIT} bytes left");
+            }
+        }
+        Ok(())
+    }
+}
@@ -1,9 +1,20 @@
 //! Types common to the sync and async HTTP clients.
 
+use std::time::Duration;
+
 /// A builder for a HTTP request.
 pub trait HttpRequest {
     /// Set a HTTP header.
     fn set_header(self, name: &str, value: &str) -> Self;
+
+    /// Set how long to wait for the whole request to complete, including reading the response
+    /// body, overriding any shorter timeout the client has by default.
+    ///
+    /// This is used for requests which are expected to take a long time, such as longpolling
+    /// requests to the [`Endpoint::Notify`] endpoint. The default implementation does nothing.
+    fn set_timeout(self, _timeout: Duration) -> Self where Self: Sized {
+        self
+    }
 }
 
 /// The API base endpoint for a request. Determines which hostname the request should go to.
@@ -16,6 +27,9 @@ pub enum Endpoint {
     Content,
 
     /// The endpoint primarily used for longpolling calls.
+    ///
+    /// Requests to this endpoint are sent without authentication, and with a timeout of
+    /// [`NOTIFY_TIMEOUT`].
     Notify,
 
     /// The endpoint used for OAuth2 token requests.
@@ -23,15 +37,127 @@ pub enum Endpoint {
 }
 
 impl Endpoint {
+    /// The base URL of the [`Api`](Self::Api) endpoint.
+    pub const API_URL: &'static str = "https://api.dropboxapi.com/2/";
+
+    /// The base URL of the [`Content`](Self::Content) endpoint.
+    pub const CONTENT_URL: &'static str = "https://content.dropboxapi.com/2/";
+
+    /// The base URL of the [`Notify`](Self::Notify) endpoint.
+    pub const NOTIFY_URL: &'static str = "https://notify.dropboxapi.com/2/";
+
+    /// The base URL of the [`OAuth2`](Self::OAuth2) endpoint. Note that unlike the others, this
+    /// doesn't end in `2/`.
+    pub const OAUTH2_URL: &'static str = "https://api.dropboxapi.com/";
+
     /// The base URL for API calls using the given endpoint.
     pub fn url(self) -> &'static str {
         match self {
-            Endpoint::Api => "https://api.dropboxapi.com/2/",
-            Endpoint::Content => "https://content.dropboxapi.com/2/",
-            Endpoint::Notify => "https://notify.dropboxapi.com/2/",
-            Endpoint::OAuth2 => "https://api.dropboxapi.com/", // note no '2/'
+            Endpoint::Api => Self::API_URL,
+            Endpoint::Content => Self::CONTENT_URL,
+            Endpoint::Notify => Self::NOTIFY_URL,
+            Endpoint::OAuth2 => Self::OAUTH2_URL,
         }
     }
+
+    /// Split a request URL into the endpoint it's for and the name of the route it calls, such as
+    /// `files/list_folder`, which can be compared with the constants in each namespace's `routes`
+    /// module.
+    ///
+    /// This only recognizes the default base URLs; it returns `None` for URLs which don't start
+    /// with one of them, including any made by clients which override the base URLs.
+    pub fn route_from_url(url: &str) -> Option<(Endpoint, &str)> {
+        let endpoint = Self::for_url(url).filter(|&endpoint| endpoint != Endpoint::OAuth2)?;
+        let route = &url[endpoint.url().len()..];
+        Some((endpoint, route.split(['?', '#']).next().unwrap_or(route)))
+    }
+
+    /// Which endpoint a request URL is for, if any.
+    pub(crate) fn for_url(url: &str) -> Option<Endpoint> {
+        // The OAuth2 URL is a prefix of the API one, so it has to be checked last.
+        [Endpoint::Api, Endpoint::Content, Endpoint::Notify, Endpoint::OAuth2]
+            .iter()
+            .copied()
+            .find(|endpoint| url.starts_with(endpoint.url()))
+    }
+}
+
+/// The timeout used for requests to the [`Endpoint::Notify`] endpoint. Longpoll requests can
+/// block for up to 480 seconds, plus up to 90 seconds of random jitter, so this is a bit longer than
+/// that.
+pub const NOTIFY_TIMEOUT: Duration = Duration::from_secs(600);
+
+/// A response body stopped making progress: no data arrived for longer than the client's stall
+/// timeout, so reading it was aborted.
+///
+/// Reading the body fails with an I/O error of kind
+/// [`TimedOut`](std::io::ErrorKind::TimedOut) which wraps this; use [`Stalled::from_io_error`] to
+/// check for it. The connection is likely dead, so the transfer should be retried, or resumed from
+/// where it stopped, with a new request.
+#[derive(thiserror::Error, Debug, Clone, Copy, PartialEq, Eq)]
+#[error("transfer stalled: no data received for {timeout:?}")]
+pub struct Stalled {
+    /// How long the body went without receiving any data.
+    pub timeout: Duration,
+}
+
+impl Stalled {
+    /// The stall which caused the given I/O error, if it was caused by one.
+    pub fn from_io_error(e: &std::io::Error) -> Option<&Stalled> {
+        e.get_ref()?.downcast_ref()
+    }
+}
+
+impl From<Stalled> for std::io::Error {
+    fn from(e: Stalled) -> Self {
+        std::io::Error::new(std::io::ErrorKind::TimedOut, e)
+    }
+}
+
+/// An operation ran out of time: its deadline passed before it completed, counting all of its
+/// requests, retries, and token refreshes.
+///
+/// This is returned as an [`Error::HttpClient`](crate::Error::HttpClient) which wraps it; use
+/// [`DeadlineExceeded::from_error`] to check for it. The deadline is set by
+/// [`RetryBudget::with_deadline`](crate::retry::RetryBudget::with_deadline) for the helpers, or by
+/// the client's `total_timeout` for each call of a route.
+#[derive(thiserror::Error, Debug, Clone, Copy, PartialEq, Eq)]
+#[error("deadline exceeded before the operation completed")]
+pub struct DeadlineExceeded;
+
+impl DeadlineExceeded {
+    /// The deadline which caused the given error, if it was caused by one.
+    pub fn from_error<E>(e: &crate::Error<E>) -> Option<&DeadlineExceeded> {
+        match e {
+            crate::Error::HttpClient(e) => e.downcast_ref(),
+            _ => None,
+        }
+    }
+}
+
+impl<E> From<DeadlineExceeded> for crate::Error<E> {
+    fn from(e: DeadlineExceeded) -> Self {
+        crate::Error::HttpClient(Box::new(e))
+    }
+}
+
+/// The most that closing a response body (with `HttpRequestResult::close`) reads of the rest of
+/// it, so that the connection can be reused. If there's more left than this, the body is aborted
+/// instead, which closes the connection.
+pub const CLOSE_DRAIN_LIMIT: u64 = 64 * 1024;
+
+/// Read a request body into memory, for clients which can't stream it.
+///
+/// The body's length is given by the caller rather than known to be right, so at most 1 MiB of
+/// it is allocated up front, and the buffer grows as the rest of it is actually read.
+pub(crate) fn read_body(
+    body: &mut (dyn std::io::Read + Send),
+    content_length: u64,
+) -> Result<Vec<u8>, crate::Error> {
+    const PREALLOCATE_LIMIT: u64 = 1024 * 1024;
+    let mut buf = Vec::with_capacity(content_length.min(PREALLOCATE_LIMIT) as usize);
+    body.read_to_end(&mut buf).map_err(|e| crate::Error::HttpClient(Box::new(e)))?;
+    Ok(buf)
 }
 
 /// The style of a request, which determines how arguments are passed, and whether there is a
@@ -57,7 +183,8 @@ pub enum ParamsType {
     /// JSON.
     Json,
 
-    /// WWW Form URL-encoded. Only used for OAuth2 requests.
+    /// WWW Form URL-encoded. Used for OAuth2 requests, and by
+    /// [`dispatch::call_form`](crate::dispatch::call_form) when the `dispatch` feature is enabled.
     Form,
 }
 
@@ -72,7 +199,7 @@ impl ParamsType {
 }
 
 /// Used with Team Authentication to select a user context within that team.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum TeamSelect {
     /// A team member's user ID.
     User(String),
@@ -90,3 +217,144 @@ impl TeamSelect {
         }
     }
 }
+
+/// The part of a file which a partial (206) response contains, from its `Content-Range` header.
+///
+/// Download routes return only part of a file when they are given a range to download. The
+/// response's `Content-Length` is then the length of the part, not of the whole file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ContentRange {
+    /// The offset of the first byte of the part.
+    pub start: u64,
+
+    /// The offset of the last byte of the part. Unlike a Rust range, this is inclusive.
+    pub end: u64,
+
+    /// The length of the whole file, if the server said.
+    pub total: Option<u64>,
+}
+
+impl ContentRange {
+    /// Parse a `Content-Range` header value of the form `bytes 0-99/1000` (or `bytes 0-99/*` when
+    /// the total length isn't known). Returns `None` if it isn't in that form, or describes an
+    /// impossible range.
+    pub fn parse(value: &str) -> Option<Self> {
+        let (range, total) = value.trim().strip_prefix("bytes ")?.split_once('/')?;
+        let (start, end) = range.split_once('-')?;
+        let start = start.trim().parse().ok()?;
+        let end = end.trim().parse().ok()?;
+        let total = match total.trim() {
+            "*" => None,
+            total => Some(total.parse().ok()?),
+        };
+        if end < start || total.map_or(false, |total| end >= total) {
+            return None;
+        }
+        Some(Self { start, end, total })
+    }
+
+    /// The number of bytes in the part.
+    pub fn len(&self) -> u64 {
+        (self.end + 1).saturating_sub(self.start)
+    }
+
+    /// Whether the part is empty. Ranges parsed from a header never are.
+    pub fn is_empty(&self) -> bool {
+        self.end < self.start
+    }
+
+    /// Whether this is a valid response to a request for the given range, as passed to a download
+    /// route. A start with no end asks for everything from the start onwards; an end with no start
+    /// asks for that many bytes from the end of the file.
+    pub fn satisfies(&self, range_start: Option<u64>, range_end: Option<u64>) -> bool {
+        match (range_start, range_end) {
+            (Some(start), Some(end)) => self.start == start && self.end <= end,
+            (Some(start), None) => self.start == start,
+            (None, Some(suffix)) => self.len() <= suffix,
+            (None, None) => true,
+        }
+    }
+}
+
+/// Get the file name from a `Content-Disposition` header value, such as
+/// `attachment; filename="a.txt"; filename*=UTF-8''%C3%A9.txt`.
+///
+/// The `filename*` parameter, encoded as in RFC 5987 with the `UTF-8` or `ISO-8859-1` character
+/// set, is preferred over the plain `filename` one where it can be decoded, as RFC 6266 says. Any
+/// directories in the name are removed, leaving only the last component, so that it's safe to use
+/// as the name of a local file. Returns `None` if there's no usable file name.
+pub fn content_disposition_filename(value: &str) -> Option<String> {
+    let mut plain = None;
+    let mut extended = None;
+    // Skip the disposition type, such as `attachment`.
+    let (_, mut rest) = value.split_once(';')?;
+    loop {
+        rest = rest.trim_start_matches(|c: char| c == ';' || c.is_whitespace());
+        let Some((name, after)) = rest.split_once('=') else {
+            break;
+        };
+        if let Some(end) = name.find(';') {
+            // A parameter without a value.
+            rest = &rest[end + 1..];
+            continue;
+        }
+        let after = after.trim_start();
+        let (param, remaining) = match after.strip_prefix('"') {
+            Some(quoted) => unquote(quoted)?,
+            None => match after.split_once(';') {
+                Some((param, remaining)) => (param.trim_end().to_owned(), remaining),
+                None => (after.trim_end().to_owned(), ""),
+            },
+        };
+        match name.trim().to_ascii_lowercase().as_str() {
+            "filename" => plain = Some(param),
+            "filename*" => extended = decode_ext_value(&param),
+            _ => (),
+        }
+        rest = remaining;
+    }
+    let name = extended.or(plain)?;
+    let name = name.rsplit(['/', '\\']).next().unwrap_or_default();
+    if name.is_empty() || name == "." || name == ".." {
+        return None;
+    }
+    Some(name.to_owned())
+}
+
+/// Take a quoted string, without its opening quote, up to its closing quote, returning it with
+/// escapes removed, and what follows it. Returns `None` if it isn't closed.
+fn unquote(s: &str) -> Option<(String, &str)> {
+    let mut value = String::new();
+    let mut chars = s.char_indices();
+    while let Some((i, c)) = chars.next() {
+        match c {
+            '"' => return Some((value, &s[i + 1..])),
+            '\\' => value.push(chars.next()?.1),
+            c => value.push(c),
+        }
+    }
+    None
+}
+
+/// Decode an RFC 5987 `ext-value`, of the form `charset'language'percent-encoded`.
+fn decode_ext_value(value: &str) -> Option<String> {
+    let mut parts = value.splitn(3, '\'');
+    let (charset, _language, encoded) = (parts.next()?, parts.next()?, parts.next()?);
+    let mut bytes = Vec::with_capacity(encoded.len());
+    let mut iter = encoded.bytes();
+    while let Some(b) = iter.next() {
+        if b == b'%' {
+            let hex = [iter.next()?, iter.next()?];
+            bytes.push(u8::from_str_radix(std::str::from_utf8(&hex).ok()?, 16).ok()?);
+        } else {
+            bytes.push(b);
+        }
+    }
+    if charset.eq_ignore_ascii_case("UTF-8") {
+        String::from_utf8(bytes).ok()
+    } else if charset.eq_ignore_ascii_case("ISO-8859-1") {
+        Some(bytes.into_iter().map(char::from).collect())
+    } else {
+        None
+    }
+}
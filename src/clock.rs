@@ -0,0 +1,99 @@
+//! Telling the time and waiting, in a way which can be swapped out in tests.
+//!
+//! Parts of the SDK which wait between attempts, such as
+//! [`RetryBudget`](crate::retry::RetryBudget), use a [`Clock`] to do so. By default this is the
+//! [`SystemClock`], but giving them a [`FakeClock`] instead makes them run without actually
+//! waiting, and records how long they would have waited, so that their behavior can be tested
+//! quickly and deterministically. The same can be done for your own code which waits, by having it
+//! take a [`Clock`] too.
+
+use std::fmt::Debug;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+use futures::future::BoxFuture;
+use futures::FutureExt;
+
+/// A source of the current time, and of waiting for time to pass.
+pub trait Clock: Debug + Send + Sync {
+    /// The current time.
+    fn now(&self) -> Instant;
+
+    /// Wait for the given amount of time.
+    fn sleep(&self, duration: Duration) -> BoxFuture<'static, ()>;
+}
+
+/// The real clock, which waits using a timer.
+///
+/// Waiting needs an executor which supports timers.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> Instant {
+        Instant::now()
+    }
+
+    fn sleep(&self, duration: Duration) -> BoxFuture<'static, ()> {
+        futures_timer::Delay::new(duration).boxed()
+    }
+}
+
+/// A clock for tests, which only moves forward when told to, or when something sleeps on it.
+///
+/// Sleeping on this clock returns immediately, after advancing the time by the length of the
+/// sleep.
+#[derive(Debug)]
+pub struct FakeClock {
+    start: Instant,
+    state: Mutex<FakeClockState>,
+}
+
+#[derive(Debug, Default)]
+struct FakeClockState {
+    elapsed: Duration,
+    sleeps: Vec<Duration>,
+}
+
+impl FakeClock {
+    /// Create a new fake clock, starting at the current time.
+    pub fn new() -> Self {
+        Self {
+            start: Instant::now(),
+            state: Mutex::new(FakeClockState::default()),
+        }
+    }
+
+    /// Move the time forward.
+    pub fn advance(&self, duration: Duration) {
+        self.state.lock().unwrap().elapsed += duration;
+    }
+
+    /// How much time has passed since the clock was created.
+    pub fn elapsed(&self) -> Duration {
+        self.state.lock().unwrap().elapsed
+    }
+
+    /// The lengths of all the sleeps on this clock so far, in order.
+    pub fn sleeps(&self) -> Vec<Duration> {
+        self.state.lock().unwrap().sleeps.clone()
+    }
+}
+
+impl Default for FakeClock {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Clock for FakeClock {
+    fn now(&self) -> Instant {
+        self.start + self.elapsed()
+    }
+
+    fn sleep(&self, duration: Duration) -> BoxFuture<'static, ()> {
+        let mut state = self.state.lock().unwrap();
+        state.elapsed += duration;
+        state.sleeps.push(duration);
+        futures::future::ready(()).boxed()
+    }
+}
@@ -0,0 +1,380 @@
+//! Compact JSON representations of the API types, for storing them.
+//!
+//! The API represents unions (Rust enums) as objects with a `.tag` field naming the variant, and
+//! the variant's value either in a field with the same name, or for structs, inline:
+//!
+//! ```json
+//! {".tag": "path", "path": {".tag": "not_found"}}
+//! ```
+//!
+//! This module converts the API types to and from representations which are more compact, and
+//! more like what [`serde`] derives, for applications which store them in their own databases.
+//! With [`UnionRepr::External`], the above is stored as:
+//!
+//! ```json
+//! {"path": "not_found"}
+//! ```
+//!
+//! and with [`UnionRepr::Adjacent`] as:
+//!
+//! ```json
+//! {"tag": "path", "content": "not_found"}
+//! ```
+//!
+//! Structs are stored as they are, except for any unions in them. Use [`to_value`] and
+//! [`from_value`] to convert directly, or the [`external`] and [`adjacent`] modules with
+//! `#[serde(with = "...")]` on fields of API types in your own serializable types.
+//!
+//! The stored representation can only be read back into the same type it was made from, and like
+//! the API's own, it's only meant for JSON.
+
+use serde::de::{self, DeserializeOwned, DeserializeSeed, MapAccess, SeqAccess, Visitor};
+use serde::Serialize;
+use serde_json::{Map, Value};
+
+/// How to represent unions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum UnionRepr {
+    /// As an object with a single field, named for the variant, holding the variant's value, like
+    /// serde's default representation of enums. Variants without a value are just the name, as a
+    /// string.
+    External,
+
+    /// As an object with the variant's name in one field, and its value, if it has one, in
+    /// another.
+    Adjacent {
+        /// The name of the field holding the variant's name.
+        tag: &'static str,
+
+        /// The name of the field holding the variant's value.
+        content: &'static str,
+    },
+}
+
+impl UnionRepr {
+    /// [`UnionRepr::Adjacent`] with fields named `tag` and `content`.
+    pub const ADJACENT: UnionRepr = UnionRepr::Adjacent { tag: "tag", content: "content" };
+
+    /// Make a union's tag and value into this representation.
+    fn union(self, tag: String, value: Option<Value>) -> Value {
+        match (self, value) {
+            (UnionRepr::External, None) => Value::String(tag),
+            (UnionRepr::External, Some(value)) => {
+                let mut map = Map::new();
+                map.insert(tag, value);
+                Value::Object(map)
+            }
+            (UnionRepr::Adjacent { tag: tag_field, content }, value) => {
+                let mut map = Map::new();
+                map.insert(tag_field.to_owned(), Value::String(tag));
+                if let Some(value) = value {
+                    map.insert(content.to_owned(), value);
+                }
+                Value::Object(map)
+            }
+        }
+    }
+
+    /// If an object could be a union in this representation, split it into its tag and value.
+    fn split(self, map: &Map<String, Value>) -> Option<(&str, Option<&Value>)> {
+        match self {
+            UnionRepr::External if map.len() == 1 => {
+                map.iter().next().map(|(tag, value)| (tag.as_str(), Some(value)))
+            }
+            UnionRepr::External => None,
+            UnionRepr::Adjacent { tag, content } => {
+                let tag = map.get(tag)?.as_str()?;
+                let value = map.get(content);
+                (map.len() == 1 + usize::from(value.is_some())).then(|| (tag, value))
+            }
+        }
+    }
+}
+
+/// Convert a value of an API type into JSON, representing unions in it as given.
+pub fn to_value<T: Serialize + ?Sized>(value: &T, repr: UnionRepr)
+    -> Result<Value, serde_json::Error>
+{
+    Ok(compact(serde_json::to_value(value)?, repr))
+}
+
+/// Convert JSON made by [`to_value`] with the same representation back into a value of the API
+/// type.
+pub fn from_value<T: DeserializeOwned>(value: &Value, repr: UnionRepr)
+    -> Result<T, serde_json::Error>
+{
+    T::deserialize(Expand { value, repr })
+}
+
+fn compact(value: Value, repr: UnionRepr) -> Value {
+    match value {
+        Value::Object(mut map) => {
+            let Some(Value::String(tag)) = map.remove(".tag") else {
+                return Value::Object(
+                    map.into_iter().map(|(k, v)| (k, compact(v, repr))).collect());
+            };
+            let value = if map.is_empty() {
+                None
+            } else if map.len() == 1 && map.contains_key(&tag) {
+                // The value is in a field named for the tag.
+                map.remove(&tag)
+            } else {
+                // The value is a struct, with its fields inline.
+                Some(Value::Object(map))
+            };
+            repr.union(tag, value.map(|value| compact(value, repr)))
+        }
+        Value::Array(values) => {
+            Value::Array(values.into_iter().map(|value| compact(value, repr)).collect())
+        }
+        other => other,
+    }
+}
+
+/// Deserializes a value from the compact representation, by presenting unions to the API types'
+/// deserializers the way the API represents them.
+struct Expand<'de> {
+    value: &'de Value,
+    repr: UnionRepr,
+}
+
+impl<'de> de::Deserializer<'de> for Expand<'de> {
+    type Error = serde_json::Error;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        match self.value {
+            Value::Object(map) => visitor.visit_map(UnionMap::plain(map, self.repr)),
+            Value::Array(values) => visitor.visit_seq(Seq { values: values.iter(), repr: self.repr }),
+            other => de::Deserializer::deserialize_any(other, visitor),
+        }
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        match self.value {
+            Value::Null => visitor.visit_none(),
+            _ => visitor.visit_some(self),
+        }
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(self, _name: &'static str, visitor: V)
+        -> Result<V::Value, Self::Error>
+    {
+        visitor.visit_newtype_struct(self)
+    }
+
+    // The API types deserialize both structs and unions this way, so either could be wanted here.
+    fn deserialize_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        match self.value {
+            Value::String(tag) => visitor.visit_map(UnionMap::union(tag, None, None, self.repr)),
+            Value::Object(map) => match self.repr.split(map) {
+                Some((tag, value)) => {
+                    visitor.visit_map(UnionMap::union(tag, value, Some(map), self.repr))
+                }
+                None => visitor.visit_map(UnionMap::plain(map, self.repr)),
+            },
+            _ => self.deserialize_any(visitor),
+        }
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        name: &'static str,
+        variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        de::Deserializer::deserialize_enum(self.value, name, variants, visitor)
+    }
+
+    serde::forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string bytes byte_buf unit
+        unit_struct seq tuple tuple_struct map identifier ignored_any
+    }
+}
+
+struct Seq<'de> {
+    values: std::slice::Iter<'de, Value>,
+    repr: UnionRepr,
+}
+
+impl<'de> SeqAccess<'de> for Seq<'de> {
+    type Error = serde_json::Error;
+
+    fn next_element_seed<T: DeserializeSeed<'de>>(&mut self, seed: T)
+        -> Result<Option<T::Value>, Self::Error>
+    {
+        self.values.next()
+            .map(|value| seed.deserialize(Expand { value, repr: self.repr }))
+            .transpose()
+    }
+}
+
+/// The fields of an object, or of something which may be a union.
+///
+/// Something which may be a union starts with a `.tag` field. If it's read as a string, this is a
+/// union, and the rest of the fields are the value in a field named for the tag, followed by the
+/// value's fields, in case it's a struct whose fields the API puts inline. (Union deserializers
+/// ignore fields after the one they want, and struct deserializers ignore fields they don't
+/// know.) Otherwise, `.tag` is being skipped by a struct deserializer, and the rest of the fields
+/// are the object's own.
+struct UnionMap<'de> {
+    state: State<'de>,
+    /// The value for the next call to `next_value_seed`.
+    pending: Option<&'de Value>,
+    repr: UnionRepr,
+}
+
+enum State<'de> {
+    Tag {
+        tag: &'de str,
+        value: Option<&'de Value>,
+        plain: Option<&'de Map<String, Value>>,
+    },
+    Value {
+        tag: &'de str,
+        value: Option<&'de Value>,
+        plain: Option<&'de Map<String, Value>>,
+        is_union: bool,
+    },
+    Fields(Option<serde_json::map::Iter<'de>>),
+}
+
+impl<'de> UnionMap<'de> {
+    fn plain(map: &'de Map<String, Value>, repr: UnionRepr) -> Self {
+        Self { state: State::Fields(Some(map.iter())), pending: None, repr }
+    }
+
+    fn union(
+        tag: &'de str,
+        value: Option<&'de Value>,
+        plain: Option<&'de Map<String, Value>>,
+        repr: UnionRepr,
+    ) -> Self {
+        Self { state: State::Tag { tag, value, plain }, pending: None, repr }
+    }
+}
+
+impl<'de> MapAccess<'de> for UnionMap<'de> {
+    type Error = serde_json::Error;
+
+    fn next_key_seed<K: DeserializeSeed<'de>>(&mut self, seed: K)
+        -> Result<Option<K::Value>, Self::Error>
+    {
+        let key = match std::mem::replace(&mut self.state, State::Fields(None)) {
+            State::Tag { tag, value, plain } => {
+                self.state = State::Value { tag, value, plain, is_union: false };
+                ".tag"
+            }
+            State::Value { tag, value, is_union: true, .. } => {
+                let Some(value) = value else {
+                    return Ok(None);
+                };
+                self.state = State::Fields(value.as_object().map(|map| map.iter()));
+                self.pending = Some(value);
+                tag
+            }
+            State::Value { plain, is_union: false, .. } => {
+                self.state = State::Fields(plain.map(|map| map.iter()));
+                return self.next_key_seed(seed);
+            }
+            State::Fields(mut fields) => {
+                let Some((key, value)) = fields.as_mut().and_then(Iterator::next) else {
+                    return Ok(None);
+                };
+                self.state = State::Fields(fields);
+                self.pending = Some(value);
+                key
+            }
+        };
+        seed.deserialize(de::value::BorrowedStrDeserializer::new(key)).map(Some)
+    }
+
+    fn next_value_seed<V: DeserializeSeed<'de>>(&mut self, seed: V)
+        -> Result<V::Value, Self::Error>
+    {
+        if let State::Value { tag, is_union, .. } = &mut self.state {
+            return seed.deserialize(Tag { tag, is_union });
+        }
+        let value = self.pending.take().ok_or_else(|| de::Error::custom("value without a key"))?;
+        seed.deserialize(Expand { value, repr: self.repr })
+    }
+}
+
+/// Deserializes a union's tag, noting whether it was read as a string, as unions do, or skipped.
+struct Tag<'a, 'de> {
+    tag: &'de str,
+    is_union: &'a mut bool,
+}
+
+impl<'de> de::Deserializer<'de> for Tag<'_, 'de> {
+    type Error = serde_json::Error;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        visitor.visit_borrowed_str(self.tag)
+    }
+
+    fn deserialize_str<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        *self.is_union = true;
+        visitor.visit_borrowed_str(self.tag)
+    }
+
+    fn deserialize_string<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        self.deserialize_str(visitor)
+    }
+
+    serde::forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char bytes byte_buf option unit
+        unit_struct newtype_struct seq tuple tuple_struct map struct enum identifier ignored_any
+    }
+}
+
+/// Serialize and deserialize a field of an API type with unions represented as
+/// [`UnionRepr::External`], with `#[serde(with = "dropbox_sdk::compact_serde::external")]`.
+pub mod external {
+    use super::*;
+
+    /// Serialize a value with unions represented as [`UnionRepr::External`].
+    pub fn serialize<T: Serialize, S: serde::Serializer>(value: &T, serializer: S)
+        -> Result<S::Ok, S::Error>
+    {
+        to_value(value, UnionRepr::External)
+            .map_err(serde::ser::Error::custom)?
+            .serialize(serializer)
+    }
+
+    /// Deserialize a value with unions represented as [`UnionRepr::External`].
+    pub fn deserialize<'de, T: DeserializeOwned, D: serde::Deserializer<'de>>(deserializer: D)
+        -> Result<T, D::Error>
+    {
+        let value = <Value as serde::Deserialize>::deserialize(deserializer)?;
+        from_value(&value, UnionRepr::External).map_err(de::Error::custom)
+    }
+}
+
+/// Serialize and deserialize a field of an API type with unions represented as
+/// [`UnionRepr::ADJACENT`], with `#[serde(with = "dropbox_sdk::compact_serde::adjacent")]`.
+pub mod adjacent {
+    use super::*;
+
+    /// Serialize a value with unions represented as [`UnionRepr::ADJACENT`].
+    pub fn serialize<T: Serialize, S: serde::Serializer>(value: &T, serializer: S)
+        -> Result<S::Ok, S::Error>
+    {
+        to_value(value, UnionRepr::ADJACENT)
+            .map_err(serde::ser::Error::custom)?
+            .serialize(serializer)
+    }
+
+    /// Deserialize a value with unions represented as [`UnionRepr::ADJACENT`].
+    pub fn deserialize<'de, T: DeserializeOwned, D: serde::Deserializer<'de>>(deserializer: D)
+        -> Result<T, D::Error>
+    {
+        let value = <Value as serde::Deserialize>::deserialize(deserializer)?;
+        from_value(&value, UnionRepr::ADJACENT).map_err(de::Error::custom)
+    }
+}
@@ -0,0 +1,111 @@
+//! Computing the content hash of local data, for comparing with the
+//! [`content_hash`](crate::types::files::FileMetadata::content_hash) of a file in Dropbox.
+//!
+//! The content hash is computed by splitting the data into blocks of 4 MiB (the last block may be
+//! shorter), taking the SHA-256 hash of each block, and then taking the SHA-256 hash of all the
+//! block hashes concatenated together. It is represented as a lowercase hexadecimal string. See
+//! <https://www.dropbox.com/developers/reference/content-hash> for details.
+
+use std::io::{self, Read};
+use std::path::Path;
+use ring::digest::{Context, SHA256};
+
+/// The size of the blocks which the data is split into.
+pub const BLOCK_SIZE: usize = 4 * 1024 * 1024;
+
+/// Computes a content hash incrementally, from data given in pieces of any size.
+///
+/// This also implements [`std::io::Write`], so data can be copied into it with
+/// [`std::io::copy`].
+#[derive(Clone)]
+pub struct ContentHasher {
+    overall: Context,
+    block: Context,
+    block_len: usize,
+}
+
+impl ContentHasher {
+    /// Start hashing some data.
+    pub fn new() -> Self {
+        Self {
+            overall: Context::new(&SHA256),
+            block: Context::new(&SHA256),
+            block_len: 0,
+        }
+    }
+
+    /// Add more data to the hash.
+    pub fn update(&mut self, mut data: &[u8]) {
+        while !data.is_empty() {
+            if self.block_len == BLOCK_SIZE {
+                self.finish_block();
+            }
+            let n = data.len().min(BLOCK_SIZE - self.block_len);
+            self.block.update(&data[..n]);
+            self.block_len += n;
+            data = &data[n..];
+        }
+    }
+
+    /// Finish hashing, and return the content hash as a hexadecimal string.
+    pub fn finish(mut self) -> String {
+        if self.block_len > 0 {
+            self.finish_block();
+        }
+        let digest = self.overall.finish();
+        let mut hex = String::with_capacity(digest.as_ref().len() * 2);
+        for byte in digest.as_ref() {
+            hex.push(char::from_digit(u32::from(byte >> 4), 16).unwrap());
+            hex.push(char::from_digit(u32::from(byte & 0xf), 16).unwrap());
+        }
+        hex
+    }
+
+    fn finish_block(&mut self) {
+        let block = std::mem::replace(&mut self.block, Context::new(&SHA256));
+        self.overall.update(block.finish().as_ref());
+        self.block_len = 0;
+    }
+}
+
+impl Default for ContentHasher {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl std::fmt::Debug for ContentHasher {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ContentHasher").finish_non_exhaustive()
+    }
+}
+
+impl io::Write for ContentHasher {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.update(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+/// Compute the content hash of some data.
+pub fn content_hash(data: &[u8]) -> String {
+    let mut hasher = ContentHasher::new();
+    hasher.update(data);
+    hasher.finish()
+}
+
+/// Compute the content hash of all the data read from a reader.
+pub fn content_hash_reader(mut reader: impl Read) -> io::Result<String> {
+    let mut hasher = ContentHasher::new();
+    io::copy(&mut reader, &mut hasher)?;
+    Ok(hasher.finish())
+}
+
+/// Compute the content hash of a local file.
+pub fn file_content_hash(path: impl AsRef<Path>) -> io::Result<String> {
+    content_hash_reader(std::fs::File::open(path)?)
+}
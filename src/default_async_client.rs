@@ -7,20 +7,43 @@
 //! some HTTP client crate, you probably want to have this Dropbox SDK crate use it as well. To do
 //! that, you should implement the traits in `crate::client_trait` for it and use it instead.
 //!
+//! The clients are cheap to clone: clones share the same connection pool and [`TokenCache`], so
+//! a clone can be passed to each thread or task instead of wrapping the client in an `Arc`.
+//!
+//! Response bodies can be dropped at any point. If a body is dropped before all of it has been read,
+//! its connection is closed rather than returned to the pool, so no request is ever sent on a
+//! connection with unread data left on it. To keep the connection instead, when only a little of
+//! the body is left, use
+//! [`HttpRequestResult::close`](crate::async_client_trait::HttpRequestResult::close).
+//!
 //! This code (and its dependencies) are only built if you use the `default_async_client` Cargo
 //! feature.
 
-use std::future::{Future, ready};
+use std::future::Future;
+#[cfg(unix)]
+use std::path::PathBuf;
+use std::pin::Pin;
 use std::str::FromStr;
+use std::net::ToSocketAddrs;
 use std::sync::Arc;
+use std::task::{Context, Poll};
+use std::time::Duration;
 use bytes::Bytes;
-use futures::{FutureExt, TryFutureExt, TryStreamExt};
-use crate::async_client_trait::{HttpClient, HttpRequestResultRaw, NoauthClient, TeamAuthClient, UserAuthClient};
-use crate::client_trait_common::{HttpRequest, TeamSelect};
-use crate::default_client_common::impl_set_path_root;
+use futures::{AsyncRead, FutureExt, TryStreamExt};
+use futures::future::BoxFuture;
+use tokio::sync::{OwnedSemaphorePermit, Semaphore};
+use crate::async_client_trait::{HttpClient, AppAuthClient, HttpRequestResultRaw, NoauthClient, TeamAuthClient, UserAuthClient};
+use crate::client_trait_common::{Endpoint, HttpRequest, Stalled, TeamSelect};
+use crate::default_client_common::{app_auth_header, impl_set_locale,
+    impl_set_path_root, impl_team_select, impl_with_concurrency_limit, impl_with_stall_timeout,
+    impl_with_request_compression, impl_gzip_requests_over, impl_with_extra_header,
+    impl_extra_headers, impl_with_total_timeout, impl_total_timeout, impl_connection_stats,
+    EndpointLimits, StatsCounters};
 use crate::Error;
 use crate::oauth2::{Authorization, TokenCache};
 
+pub use crate::default_client_common::{ConnectionEvent, ConnectionEvents, ConnectionStats};
+
 macro_rules! impl_update_token {
     ($self:ident) => {
         fn update_token(&$self, old_token: Arc<String>)
@@ -43,11 +66,50 @@ macro_rules! impl_update_token {
     };
 }
 
+macro_rules! impl_with_connector {
+    () => {
+        /// Use the given transport for connecting to the Dropbox API servers, instead of the
+        /// default of connecting directly over TCP.
+        pub fn with_connector(mut self, connector: Connector) -> Self {
+            self.inner = ReqwestClient {
+                limits: self.inner.limits,
+                stall_timeout: self.inner.stall_timeout,
+                #[cfg(feature = "gzip")]
+                gzip_threshold: self.inner.gzip_threshold,
+                extra_headers: self.inner.extra_headers,
+                total_timeout: self.inner.total_timeout,
+                ..ReqwestClient::new(&connector, self.inner.stats)
+            };
+            self
+        }
+    };
+}
+
+/// How the client connects to the Dropbox API servers.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Connector {
+    /// Connect directly over TCP. This is the default.
+    ///
+    /// Note that HTTP proxies given in the standard environment variables (such as `HTTPS_PROXY`)
+    /// are used when connecting this way.
+    #[default]
+    Tcp,
+
+    /// Make all connections over the Unix-domain socket at the given path, such as one provided by
+    /// a local egress proxy in a sandboxed environment. TLS is still used over the socket, and no
+    /// DNS resolution is done.
+    #[cfg(unix)]
+    UnixSocket(PathBuf),
+}
+
 /// Default HTTP client using User authorization.
+#[derive(Clone)]
 pub struct UserAuthDefaultClient {
     inner: ReqwestClient,
     tokens: Arc<TokenCache>,
     path_root: Option<String>, // a serialized PathRoot enum
+    locale: Option<String>,
 }
 
 impl UserAuthDefaultClient {
@@ -57,16 +119,25 @@ impl UserAuthDefaultClient {
     }
 
     /// Create a new client from a [`TokenCache`], which lets you share the same tokens between
-    /// multiple clients.
+    /// multiple clients, including a mix of sync and async ones.
     pub fn from_token_cache(tokens: Arc<TokenCache>) -> Self {
         Self {
             inner: Default::default(),
             tokens,
             path_root: None,
+            locale: None,
         }
     }
 
+    impl_with_connector!();
     impl_set_path_root!(self);
+    impl_set_locale!(self);
+    impl_with_concurrency_limit!();
+    impl_with_stall_timeout!();
+    impl_with_request_compression!();
+    impl_with_extra_header!();
+    impl_with_total_timeout!();
+    impl_connection_stats!();
 }
 
 impl HttpClient for UserAuthDefaultClient {
@@ -93,16 +164,26 @@ impl HttpClient for UserAuthDefaultClient {
     fn path_root(&self) -> Option<&str> {
         self.path_root.as_deref()
     }
+
+    fn locale(&self) -> Option<&str> {
+        self.locale.as_deref()
+    }
+
+    impl_gzip_requests_over!();
+    impl_extra_headers!();
+    impl_total_timeout!();
 }
 
 impl UserAuthClient for UserAuthDefaultClient {}
 
 /// Default HTTP client using Team authorization.
+#[derive(Clone)]
 pub struct TeamAuthDefaultClient {
     inner: ReqwestClient,
     tokens: Arc<TokenCache>,
     path_root: Option<String>, // a serialized PathRoot enum
     team_select: Option<TeamSelect>,
+    locale: Option<String>,
 }
 
 impl TeamAuthDefaultClient {
@@ -113,6 +194,7 @@ impl TeamAuthDefaultClient {
             tokens: tokens.into(),
             path_root: None,
             team_select: None,
+            locale: None,
         }
     }
 
@@ -121,7 +203,17 @@ impl TeamAuthDefaultClient {
         self.team_select = team_select;
     }
 
+    impl_team_select!();
+
+    impl_with_connector!();
     impl_set_path_root!(self);
+    impl_set_locale!(self);
+    impl_with_concurrency_limit!();
+    impl_with_stall_timeout!();
+    impl_with_request_compression!();
+    impl_with_extra_header!();
+    impl_with_total_timeout!();
+    impl_connection_stats!();
 }
 
 impl HttpClient for TeamAuthDefaultClient {
@@ -149,6 +241,14 @@ impl HttpClient for TeamAuthDefaultClient {
         self.path_root.as_deref()
     }
 
+    fn locale(&self) -> Option<&str> {
+        self.locale.as_deref()
+    }
+
+    impl_gzip_requests_over!();
+    impl_extra_headers!();
+    impl_total_timeout!();
+
     fn team_select(&self) -> Option<&TeamSelect> {
         self.team_select.as_ref()
     }
@@ -157,14 +257,23 @@ impl HttpClient for TeamAuthDefaultClient {
 impl TeamAuthClient for TeamAuthDefaultClient {}
 
 /// Default HTTP client for unauthenticated API calls.
-#[derive(Debug, Default)]
+#[derive(Debug, Clone, Default)]
 pub struct NoauthDefaultClient {
     inner: ReqwestClient,
     path_root: Option<String>,
+    locale: Option<String>,
 }
 
 impl NoauthDefaultClient {
+    impl_with_connector!();
     impl_set_path_root!(self);
+    impl_set_locale!(self);
+    impl_with_concurrency_limit!();
+    impl_with_stall_timeout!();
+    impl_with_request_compression!();
+    impl_with_extra_header!();
+    impl_with_total_timeout!();
+    impl_connection_stats!();
 }
 
 impl HttpClient for NoauthDefaultClient {
@@ -185,10 +294,84 @@ impl HttpClient for NoauthDefaultClient {
     fn path_root(&self) -> Option<&str> {
         self.path_root.as_deref()
     }
+
+    fn locale(&self) -> Option<&str> {
+        self.locale.as_deref()
+    }
+
+    impl_gzip_requests_over!();
+    impl_extra_headers!();
+    impl_total_timeout!();
 }
 
 impl NoauthClient for NoauthDefaultClient {}
 
+/// Default HTTP client using App authorization, which identifies the app by its key and secret
+/// rather than acting on behalf of any user.
+///
+/// Only a few routes support this, such as getting the metadata or a thumbnail of a shared link.
+#[derive(Clone)]
+pub struct AppAuthDefaultClient {
+    inner: ReqwestClient,
+    authorization: String, // the full Authorization header value
+    path_root: Option<String>, // a serialized PathRoot enum
+    locale: Option<String>,
+}
+
+impl AppAuthDefaultClient {
+    /// Create a new client using the given app key and secret, from the App Console.
+    pub fn new(app_key: &str, app_secret: &str) -> Self {
+        Self {
+            inner: ReqwestClient::default(),
+            authorization: app_auth_header(app_key, app_secret),
+            path_root: None,
+            locale: None,
+        }
+    }
+
+    impl_with_connector!();
+    impl_set_path_root!(self);
+    impl_set_locale!(self);
+    impl_with_concurrency_limit!();
+    impl_with_stall_timeout!();
+    impl_with_request_compression!();
+    impl_with_extra_header!();
+    impl_with_total_timeout!();
+    impl_connection_stats!();
+}
+
+impl HttpClient for AppAuthDefaultClient {
+    type Request = ReqwestRequest;
+
+    fn execute(
+        &self,
+        request: Self::Request,
+        body: Bytes,
+    ) -> impl Future<Output=Result<HttpRequestResultRaw, Error>> + Send {
+        self.inner.execute(request, body)
+    }
+
+    fn new_request(&self, url: &str) -> Self::Request {
+        // App auth doesn't use a token, so set the header here instead.
+        self.inner.new_request(url)
+            .set_header("Authorization", &self.authorization)
+    }
+
+    fn path_root(&self) -> Option<&str> {
+        self.path_root.as_deref()
+    }
+
+    fn locale(&self) -> Option<&str> {
+        self.locale.as_deref()
+    }
+
+    impl_gzip_requests_over!();
+    impl_extra_headers!();
+    impl_total_timeout!();
+}
+
+impl AppAuthClient for AppAuthDefaultClient {}
+
 /// Same as NoauthDefaultClient but with inner by reference and no path_root.
 /// Only used for updating authorization tokens.
 struct TokenUpdateClient<'a> {
@@ -213,21 +396,109 @@ impl<'a> HttpClient for TokenUpdateClient<'a> {
 
 impl NoauthClient for TokenUpdateClient<'_> {}
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 struct ReqwestClient {
     inner: reqwest::Client,
+    limits: EndpointLimits<Semaphore>,
+    stats: Arc<StatsCounters>,
+    stall_timeout: Option<Duration>,
+    #[cfg(feature = "gzip")]
+    gzip_threshold: Option<usize>,
+    extra_headers: Vec<(String, String)>,
+    total_timeout: Option<Duration>,
 }
 
-impl Default for ReqwestClient {
-    fn default() -> Self {
+impl ReqwestClient {
+    fn new(connector: &Connector, stats: Arc<StatsCounters>) -> Self {
+        let builder = reqwest::Client::builder()
+            .https_only(true)
+            .http2_prior_knowledge()
+            .dns_resolver(Arc::new(CountingResolver(Arc::clone(&stats))))
+            .connector_layer(CountingLayer(Arc::clone(&stats)));
+        let builder = match connector {
+            Connector::Tcp => builder,
+            #[cfg(unix)]
+            Connector::UnixSocket(path) => builder.unix_socket(path.as_path()),
+        };
         Self {
-            inner: reqwest::Client::builder()
-                .https_only(true)
-                .http2_prior_knowledge()
-                .build()
-                .unwrap()
+            inner: builder.build().unwrap(),
+            limits: EndpointLimits::default(),
+            stats,
+            stall_timeout: None,
+            #[cfg(feature = "gzip")]
+            gzip_threshold: None,
+            extra_headers: Vec::new(),
+            total_timeout: None,
         }
     }
+
+    fn set_stall_timeout(&mut self, timeout: Duration) {
+        self.stall_timeout = Some(timeout);
+    }
+}
+
+/// Looks up host names the way reqwest does by default, counting the lookups.
+#[derive(Debug)]
+struct CountingResolver(Arc<StatsCounters>);
+
+impl reqwest::dns::Resolve for CountingResolver {
+    fn resolve(&self, name: reqwest::dns::Name) -> reqwest::dns::Resolving {
+        self.0.record(ConnectionEvent::DnsLookup);
+        let host = name.as_str().to_owned();
+        Box::pin(async move {
+            // The port is replaced with the one from the URL.
+            let addrs = tokio::task::spawn_blocking(move || (host.as_str(), 0).to_socket_addrs())
+                .await??;
+            Ok(Box::new(addrs) as reqwest::dns::Addrs)
+        })
+    }
+}
+
+/// Counts the new connections reqwest makes, including through a Unix socket, and the TLS
+/// handshakes completed for them. reqwest doesn't expose the handshake itself, but as only HTTPS is
+/// used, each connection it makes successfully has completed one.
+#[derive(Debug, Clone)]
+struct CountingLayer(Arc<StatsCounters>);
+
+impl<S> tower_layer::Layer<S> for CountingLayer {
+    type Service = CountingConnector<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        CountingConnector(inner, Arc::clone(&self.0))
+    }
+}
+
+#[derive(Debug, Clone)]
+struct CountingConnector<S>(S, Arc<StatsCounters>);
+
+impl<S, R> tower_service::Service<R> for CountingConnector<S>
+where
+    S: tower_service::Service<R>,
+    S::Future: Send + 'static,
+{
+    type Response = S::Response;
+    type Error = S::Error;
+    type Future = BoxFuture<'static, Result<S::Response, S::Error>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.0.poll_ready(cx)
+    }
+
+    fn call(&mut self, req: R) -> Self::Future {
+        self.1.record(ConnectionEvent::NewConnection);
+        let stats = Arc::clone(&self.1);
+        self.0.call(req)
+            .inspect(move |result| if result.is_ok() {
+                stats.record(ConnectionEvent::TlsHandshake);
+            })
+            .boxed()
+    }
+}
+
+impl Default for ReqwestClient {
+    fn default() -> Self {
+        Self::new(&Connector::Tcp, Arc::new(StatsCounters::default()))
+    }
 }
 
 fn unexpected<T: std::error::Error + Send + Sync>(e: T, msg: &str) -> Error {
@@ -242,61 +513,149 @@ impl HttpClient for ReqwestClient {
         request: Self::Request,
         body: Bytes,
     ) -> impl Future<Output = Result<HttpRequestResultRaw, Error>> + Send {
-        let mut req = match request.req.build() {
-            Ok(req) => req,
-            Err(e) => {
-                return ready(Err(Error::HttpClient(Box::new(e)))).boxed();
+        let semaphore = self.limits.get(request.endpoint).cloned();
+        let stall_timeout = self.stall_timeout;
+        self.stats.record(ConnectionEvent::Request);
+        async move {
+            let mut req = request.req.build().map_err(|e| Error::HttpClient(Box::new(e)))?;
+            debug!("request for {}", req.url());
+            if !body.is_empty() {
+                *req.body_mut() = Some(reqwest::Body::from(body));
             }
-        };
-        debug!("request for {}", req.url());
-        if !body.is_empty() {
-            *req.body_mut() = Some(reqwest::Body::from(body));
-        }
-        self.inner.execute(req)
-            .map_ok_or_else(
-                |e| Err(Error::HttpClient(Box::new(e))),
-                |resp| {
-                    let status = resp.status().as_u16();
-
-                    let result_header = resp
-                        .headers()
-                        .get("Dropbox-API-Result")
-                        .map(|v| v.to_str())
-                        .transpose()
-                        .map_err(|e| unexpected(e, "invalid Dropbox-API-Result header"))?
-                        .map(ToOwned::to_owned);
-
-                    let content_length = resp
-                        .headers()
-                        .get("Content-Length")
-                        .map(|v| {
-                            v.to_str()
+            let permit = match semaphore {
+                // The semaphore is never closed, so this can't fail.
+                Some(semaphore) => Some(semaphore.acquire_owned().await.unwrap()),
+                None => None,
+            };
+            let resp = self.inner.execute(req).await.map_err(|e| Error::HttpClient(Box::new(e)))?;
+            let status = resp.status().as_u16();
+
+            let result_header = resp
+                .headers()
+                .get("Dropbox-API-Result")
+                .map(|v| v.to_str())
+                .transpose()
+                .map_err(|e| unexpected(e, "invalid Dropbox-API-Result header"))?
+                .map(ToOwned::to_owned);
+
+            let retry_after = resp
+                .headers()
+                .get("Retry-After")
+                .and_then(|v| v.to_str().ok())
+                .map(ToOwned::to_owned);
+
+            let content_range = resp
+                .headers()
+                .get("Content-Range")
+                .and_then(|v| v.to_str().ok())
+                .map(ToOwned::to_owned);
+
+            let content_disposition = resp
+                .headers()
+                .get("Content-Disposition")
+                .and_then(|v| v.to_str().ok())
+                .map(ToOwned::to_owned);
+
+            let request_id = resp
+                .headers()
+                .get("X-Dropbox-Request-Id")
+                .and_then(|v| v.to_str().ok())
+                .map(ToOwned::to_owned);
+
+            let content_length = resp
+                .headers()
+                .get("Content-Length")
+                .map(|v| {
+                    v.to_str()
+                        .map_err(|e| unexpected(e, "invalid Content-Length"))
+                        .and_then(|s| {
+                            u64::from_str(s)
                                 .map_err(|e| unexpected(e, "invalid Content-Length"))
-                                .and_then(|s| {
-                                    u64::from_str(s)
-                                        .map_err(|e| unexpected(e, "invalid Content-Length"))
-                                })
                         })
-                        .transpose()?;
-
-                    let body = resp.bytes_stream()
-                        .map_err(|e| futures::io::Error::new(futures::io::ErrorKind::Other, e))
-                        .into_async_read();
-
-                    Ok(HttpRequestResultRaw {
-                        status,
-                        result_header,
-                        content_length,
-                        body: Box::new(body),
-                    })
-                }
-            )
-            .boxed()
+                })
+                .transpose()?;
+
+            let body = resp.bytes_stream()
+                .map_err(|e| futures::io::Error::new(futures::io::ErrorKind::Other, e))
+                .into_async_read();
+            let body = StallBody::new(body, stall_timeout);
+
+            Ok(HttpRequestResultRaw {
+                status,
+                result_header,
+                content_length,
+                retry_after,
+                content_range,
+                content_disposition,
+                request_id,
+                body: match permit {
+                    Some(permit) => Box::new(LimitedBody { inner: body, _permit: permit }),
+                    None => Box::new(body),
+                },
+            })
+        }
     }
 
     fn new_request(&self, url: &str) -> Self::Request {
         ReqwestRequest {
             req: self.inner.post(url),
+            endpoint: Endpoint::for_url(url),
+        }
+    }
+}
+
+/// A response body which holds onto a concurrency limit permit until it is dropped.
+struct LimitedBody<R> {
+    inner: R,
+    _permit: OwnedSemaphorePermit,
+}
+
+impl<R: AsyncRead + Unpin> AsyncRead for LimitedBody<R> {
+    fn poll_read(mut self: Pin<&mut Self>, cx: &mut Context<'_>, buf: &mut [u8])
+        -> Poll<std::io::Result<usize>>
+    {
+        Pin::new(&mut self.inner).poll_read(cx, buf)
+    }
+}
+
+/// A response body which fails with a [`Stalled`] error if reading it makes no progress for the
+/// stall timeout, if there is one.
+struct StallBody<R> {
+    inner: R,
+    timeout: Option<Duration>,
+    /// Started when a read has to wait, and stopped when data arrives.
+    timer: Option<futures_timer::Delay>,
+}
+
+impl<R> StallBody<R> {
+    fn new(inner: R, timeout: Option<Duration>) -> Self {
+        Self { inner, timeout, timer: None }
+    }
+}
+
+impl<R: AsyncRead + Unpin> AsyncRead for StallBody<R> {
+    fn poll_read(mut self: Pin<&mut Self>, cx: &mut Context<'_>, buf: &mut [u8])
+        -> Poll<std::io::Result<usize>>
+    {
+        let this = &mut *self;
+        match Pin::new(&mut this.inner).poll_read(cx, buf) {
+            Poll::Pending => {
+                let Some(timeout) = this.timeout else {
+                    return Poll::Pending;
+                };
+                let timer = this.timer.get_or_insert_with(|| futures_timer::Delay::new(timeout));
+                match Pin::new(timer).poll(cx) {
+                    Poll::Ready(()) => {
+                        this.timer = None;
+                        Poll::Ready(Err(Stalled { timeout }.into()))
+                    }
+                    Poll::Pending => Poll::Pending,
+                }
+            }
+            ready => {
+                this.timer = None;
+                ready
+            }
         }
     }
 }
@@ -304,6 +663,7 @@ impl HttpClient for ReqwestClient {
 /// This is an implementation detail of the HTTP client.
 pub struct ReqwestRequest {
     req: reqwest::RequestBuilder,
+    endpoint: Option<Endpoint>,
 }
 
 impl HttpRequest for ReqwestRequest {
@@ -311,4 +671,80 @@ impl HttpRequest for ReqwestRequest {
         self.req = self.req.header(name, value);
         self
     }
+
+    fn set_timeout(mut self, timeout: Duration) -> Self {
+        self.req = self.req.timeout(timeout);
+        self
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::sync::Mutex;
+    use futures::AsyncReadExt;
+
+    /// A body which sends its data, and then never sends any more or finishes.
+    struct Hang(std::io::Cursor<Vec<u8>>);
+
+    impl AsyncRead for Hang {
+        fn poll_read(mut self: Pin<&mut Self>, _cx: &mut Context<'_>, buf: &mut [u8])
+            -> Poll<std::io::Result<usize>>
+        {
+            match std::io::Read::read(&mut self.0, buf) {
+                Ok(0) => Poll::Pending,
+                result => Poll::Ready(result),
+            }
+        }
+    }
+
+    #[test]
+    fn test_stall_body() {
+        let timeout = Duration::from_millis(50);
+        let mut body = StallBody::new(Hang(std::io::Cursor::new(b"hello".to_vec())), Some(timeout));
+        let mut buf = [0; 16];
+        let n = futures::executor::block_on(body.read(&mut buf)).unwrap();
+        assert_eq!(b"hello", &buf[..n]);
+
+        let e = futures::executor::block_on(body.read(&mut buf)).unwrap_err();
+        assert_eq!(Some(&Stalled { timeout }), Stalled::from_io_error(&e));
+
+        // Without a timeout, it keeps waiting.
+        let mut body = StallBody::new(Hang(std::io::Cursor::new(vec![])), None);
+        assert!(body.read(&mut buf).now_or_never().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_connection_stats() {
+        // A server which hangs up straight away, so requests fail after connecting.
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let port = listener.local_addr().unwrap().port();
+        let url = format!("https://localhost:{port}/2/check/app");
+        std::thread::spawn(move || listener.incoming().for_each(drop));
+
+        let events = Arc::new(Mutex::new(Vec::new()));
+        let client = NoauthDefaultClient::default().with_connection_events({
+            let events = Arc::clone(&events);
+            Arc::new(move |event: &ConnectionEvent| events.lock().unwrap().push(*event))
+        });
+        let clone = client.clone();
+        for client in [&client, &clone] {
+            let request = client.new_request(&url);
+            assert!(client.execute(request, Bytes::new()).await.is_err());
+        }
+        let stats = client.connection_stats();
+        assert_eq!(2, stats.requests);
+        assert_eq!(2, stats.new_connections);
+        assert_eq!(0, stats.reused_connections());
+        assert_eq!(2, stats.dns_lookups);
+        assert_eq!(0, stats.tls_handshakes);
+        assert_eq!(stats, clone.connection_stats());
+
+        let events = events.lock().unwrap();
+        let count = |event| events.iter().filter(|&&e| e == event).count();
+        assert_eq!(2, count(ConnectionEvent::Request));
+        assert_eq!(2, count(ConnectionEvent::NewConnection));
+        assert_eq!(2, count(ConnectionEvent::DnsLookup));
+        assert_eq!(6, events.len());
+    }
 }
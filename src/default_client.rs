@@ -9,27 +9,41 @@
 //! some HTTP client crate, you probably want to have this Dropbox SDK crate use it as well. To do
 //! that, you should implement the traits in `crate::client_trait` for it and use it instead.
 //!
+//! The clients are cheap to clone: clones share the same connection pool and [`TokenCache`], so
+//! a clone can be passed to each thread or task instead of wrapping the client in an `Arc`.
+//!
+//! Response bodies can be dropped at any point. If a body is dropped before all of it has been read,
+//! its connection is closed rather than returned to the pool, so no request is ever sent on a
+//! connection with unread data left on it. To keep the connection instead, when only a little of
+//! the body is left, use [`HttpRequestResult::close`](crate::client_trait::HttpRequestResult::close).
+//!
 //! This code (and its dependencies) are only built if you use the `default_client` Cargo feature.
 
 use crate::Error;
 use crate::oauth2::{Authorization, TokenCache};
-use std::borrow::Cow;
-use std::fmt::Write;
+use std::io::Read;
+use std::net::{SocketAddr, ToSocketAddrs};
 use std::str::FromStr;
-use std::sync::Arc;
-use futures::FutureExt;
-use crate::client_trait::{HttpClient, HttpRequestResultRaw, NoauthClient, TeamAuthClient, UserAuthClient};
-use crate::client_trait_common::{HttpRequest, TeamSelect};
-use crate::default_client_common::impl_set_path_root;
+use std::sync::{Arc, Condvar, Mutex};
+use std::time::Duration;
+use crate::client_trait::{HttpClient, AppAuthClient, HttpRequestResultRaw, NoauthClient, TeamAuthClient, UserAuthClient};
+use crate::client_trait_common::{Endpoint, HttpRequest, Stalled, TeamSelect};
+use crate::default_client_common::{app_auth_header, impl_set_locale,
+    impl_set_path_root, impl_team_select, impl_with_concurrency_limit, impl_with_stall_timeout,
+    impl_with_request_compression, impl_gzip_requests_over, impl_with_extra_header,
+    impl_extra_headers, impl_with_total_timeout, impl_total_timeout, impl_connection_stats,
+    EndpointLimits, StatsCounters};
+
+pub use crate::default_client_common::{ConnectionEvent, ConnectionEvents, ConnectionStats};
 
 macro_rules! impl_update_token {
     ($self:ident) => {
         fn update_token(&$self, old_token: Arc<String>) -> Result<bool, Error> {
             info!("refreshing auth token");
-            match $self.tokens.update_token(
+            match $self.tokens.update_token_blocking(
                 TokenUpdateClient { inner: &$self.inner },
                 old_token,
-            ).now_or_never().unwrap() {
+            ) {
                 Ok(_) => Ok(true),
                 Err(e) => {
                     error!("failed to update auth token: {e}");
@@ -41,10 +55,12 @@ macro_rules! impl_update_token {
 }
 
 /// Default HTTP client using User authorization.
+#[derive(Clone)]
 pub struct UserAuthDefaultClient {
     inner: UreqClient,
     tokens: Arc<TokenCache>,
     path_root: Option<String>, // a serialized PathRoot enum
+    locale: Option<String>,
 }
 
 impl UserAuthDefaultClient {
@@ -54,16 +70,24 @@ impl UserAuthDefaultClient {
     }
 
     /// Create a new client from a [`TokenCache`], which lets you share the same tokens between
-    /// multiple clients.
+    /// multiple clients, including a mix of sync and async ones.
     pub fn from_token_cache(tokens: Arc<TokenCache>) -> Self {
         Self {
             inner: UreqClient::default(),
             tokens,
             path_root: None,
+            locale: None,
         }
     }
 
     impl_set_path_root!(self);
+    impl_set_locale!(self);
+    impl_with_concurrency_limit!();
+    impl_with_stall_timeout!();
+    impl_with_request_compression!();
+    impl_with_extra_header!();
+    impl_with_total_timeout!();
+    impl_connection_stats!();
 }
 
 impl HttpClient for UserAuthDefaultClient {
@@ -86,16 +110,26 @@ impl HttpClient for UserAuthDefaultClient {
     fn path_root(&self) -> Option<&str> {
         self.path_root.as_deref()
     }
+
+    fn locale(&self) -> Option<&str> {
+        self.locale.as_deref()
+    }
+
+    impl_gzip_requests_over!();
+    impl_extra_headers!();
+    impl_total_timeout!();
 }
 
 impl UserAuthClient for UserAuthDefaultClient {}
 
 /// Default HTTP client using Team authorization.
+#[derive(Clone)]
 pub struct TeamAuthDefaultClient {
     inner: UreqClient,
     tokens: Arc<TokenCache>,
     path_root: Option<String>, // a serialized PathRoot enum
     team_select: Option<TeamSelect>,
+    locale: Option<String>,
 }
 
 impl TeamAuthDefaultClient {
@@ -106,6 +140,7 @@ impl TeamAuthDefaultClient {
             tokens: tokens.into(),
             path_root: None,
             team_select: None,
+            locale: None,
         }
     }
 
@@ -114,7 +149,16 @@ impl TeamAuthDefaultClient {
         self.team_select = team_select;
     }
 
+    impl_team_select!();
+
     impl_set_path_root!(self);
+    impl_set_locale!(self);
+    impl_with_concurrency_limit!();
+    impl_with_stall_timeout!();
+    impl_with_request_compression!();
+    impl_with_extra_header!();
+    impl_with_total_timeout!();
+    impl_connection_stats!();
 }
 
 impl HttpClient for TeamAuthDefaultClient {
@@ -138,6 +182,14 @@ impl HttpClient for TeamAuthDefaultClient {
         self.path_root.as_deref()
     }
 
+    fn locale(&self) -> Option<&str> {
+        self.locale.as_deref()
+    }
+
+    impl_gzip_requests_over!();
+    impl_extra_headers!();
+    impl_total_timeout!();
+
     fn team_select(&self) -> Option<&TeamSelect> {
         self.team_select.as_ref()
     }
@@ -146,14 +198,22 @@ impl HttpClient for TeamAuthDefaultClient {
 impl TeamAuthClient for TeamAuthDefaultClient {}
 
 /// Default HTTP client for unauthenticated API calls.
-#[derive(Debug, Default)]
+#[derive(Debug, Clone, Default)]
 pub struct NoauthDefaultClient {
     inner: UreqClient,
     path_root: Option<String>,
+    locale: Option<String>,
 }
 
 impl NoauthDefaultClient {
     impl_set_path_root!(self);
+    impl_set_locale!(self);
+    impl_with_concurrency_limit!();
+    impl_with_stall_timeout!();
+    impl_with_request_compression!();
+    impl_with_extra_header!();
+    impl_with_total_timeout!();
+    impl_connection_stats!();
 }
 
 impl HttpClient for NoauthDefaultClient {
@@ -170,10 +230,79 @@ impl HttpClient for NoauthDefaultClient {
     fn path_root(&self) -> Option<&str> {
         self.path_root.as_deref()
     }
+
+    fn locale(&self) -> Option<&str> {
+        self.locale.as_deref()
+    }
+
+    impl_gzip_requests_over!();
+    impl_extra_headers!();
+    impl_total_timeout!();
 }
 
 impl NoauthClient for NoauthDefaultClient {}
 
+/// Default HTTP client using App authorization, which identifies the app by its key and secret
+/// rather than acting on behalf of any user.
+///
+/// Only a few routes support this, such as getting the metadata or a thumbnail of a shared link.
+#[derive(Clone)]
+pub struct AppAuthDefaultClient {
+    inner: UreqClient,
+    authorization: String, // the full Authorization header value
+    path_root: Option<String>, // a serialized PathRoot enum
+    locale: Option<String>,
+}
+
+impl AppAuthDefaultClient {
+    /// Create a new client using the given app key and secret, from the App Console.
+    pub fn new(app_key: &str, app_secret: &str) -> Self {
+        Self {
+            inner: UreqClient::default(),
+            authorization: app_auth_header(app_key, app_secret),
+            path_root: None,
+            locale: None,
+        }
+    }
+
+    impl_set_path_root!(self);
+    impl_set_locale!(self);
+    impl_with_concurrency_limit!();
+    impl_with_stall_timeout!();
+    impl_with_request_compression!();
+    impl_with_extra_header!();
+    impl_with_total_timeout!();
+    impl_connection_stats!();
+}
+
+impl HttpClient for AppAuthDefaultClient {
+    type Request = UreqRequest;
+
+    fn execute(&self, request: Self::Request, body: &[u8]) -> Result<HttpRequestResultRaw, Error> {
+        self.inner.execute(request, body)
+    }
+
+    fn new_request(&self, url: &str) -> Self::Request {
+        // App auth doesn't use a token, so set the header here instead.
+        self.inner.new_request(url)
+            .set_header("Authorization", &self.authorization)
+    }
+
+    fn path_root(&self) -> Option<&str> {
+        self.path_root.as_deref()
+    }
+
+    fn locale(&self) -> Option<&str> {
+        self.locale.as_deref()
+    }
+
+    impl_gzip_requests_over!();
+    impl_extra_headers!();
+    impl_total_timeout!();
+}
+
+impl AppAuthClient for AppAuthDefaultClient {}
+
 /// Same as NoauthDefaultClient but with inner by reference and no path_root.
 /// Only used for updating authorization tokens.
 struct TokenUpdateClient<'a> {
@@ -192,17 +321,32 @@ impl HttpClient for TokenUpdateClient<'_> {
     }
 }
 
-impl crate::async_client_trait::NoauthClient for TokenUpdateClient<'_> {}
+impl crate::client_trait::NoauthClient for TokenUpdateClient<'_> {}
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 struct UreqClient {
     agent: ureq::Agent,
+    limits: EndpointLimits<Semaphore>,
+    stats: Arc<StatsCounters>,
+    stall_timeout: Option<Duration>,
+    #[cfg(feature = "gzip")]
+    gzip_threshold: Option<usize>,
+    extra_headers: Vec<(String, String)>,
+    total_timeout: Option<Duration>,
 }
 
 impl Default for UreqClient {
     fn default() -> Self {
+        let stats = Arc::new(StatsCounters::default());
         Self {
-            agent: ureq::Agent::new(),
+            agent: Self::agent(&stats, None),
+            limits: EndpointLimits::default(),
+            stats,
+            stall_timeout: None,
+            #[cfg(feature = "gzip")]
+            gzip_threshold: None,
+            extra_headers: Vec::new(),
+            total_timeout: None,
         }
     }
 }
@@ -211,12 +355,75 @@ impl HttpClient for UreqClient {
     type Request = UreqRequest;
 
     fn execute(&self, request: Self::Request, body: &[u8]) -> Result<HttpRequestResultRaw, Error> {
+        let permit = self.limits.get(request.endpoint).map(Semaphore::acquire);
+        self.stats.record(ConnectionEvent::Request);
         let resp = if body.is_empty() {
             request.req.call()
         } else {
             request.req.send_bytes(body)
         };
+        self.finish(resp, permit)
+    }
+
+    fn execute_streaming(
+        &self,
+        request: Self::Request,
+        body: &mut (dyn Read + Send),
+        content_length: u64,
+    ) -> Result<HttpRequestResultRaw, Error> {
+        let permit = self.limits.get(request.endpoint).map(Semaphore::acquire);
+        self.stats.record(ConnectionEvent::Request);
+        let req = request.req.set("Content-Length", &content_length.to_string());
+        self.finish(req.send(body), permit)
+    }
+
+    fn new_request(&self, url: &str) -> Self::Request {
+        UreqRequest {
+            req: self.agent.post(url),
+            endpoint: Endpoint::for_url(url),
+        }
+    }
+}
+
+impl UreqClient {
+    fn agent(stats: &Arc<StatsCounters>, stall_timeout: Option<Duration>) -> ureq::Agent {
+        // ureq resolves the host name each time it makes a new connection, and only then, so
+        // resolving it is where new connections are counted.
+        let resolver_stats = Arc::clone(stats);
+        let mut builder = ureq::AgentBuilder::new()
+            .resolver(move |netloc: &str| -> std::io::Result<Vec<SocketAddr>> {
+                resolver_stats.record(ConnectionEvent::NewConnection);
+                if netloc.parse::<SocketAddr>().is_err() {
+                    resolver_stats.record(ConnectionEvent::DnsLookup);
+                }
+                netloc.to_socket_addrs().map(Iterator::collect)
+            });
+        let mut tls = tls_config();
+        if let Some(timeout) = stall_timeout {
+            // This applies to each read from the socket, except for requests with their own
+            // timeout, which ureq applies to the whole request instead. ureq also applies it to
+            // reading the response headers of new connections, which would fail requests that the
+            // server takes a while to respond to, so the connector clears it until then.
+            builder = builder.timeout_read(timeout);
+            tls = Arc::new(BodyTimeoutConnector(tls));
+        }
+        builder
+            .tls_connector(Arc::new(CountingConnector(tls, Arc::clone(stats))))
+            .build()
+    }
 
+    /// Set the stall timeout. The agent's socket settings can't be changed, so this makes a new
+    /// agent, with a new connection pool.
+    fn set_stall_timeout(&mut self, timeout: Duration) {
+        self.agent = Self::agent(&self.stats, Some(timeout));
+        self.stall_timeout = Some(timeout);
+    }
+
+    /// Turn a response into a result. If the request counts against a concurrency limit, the
+    /// permit is held until the body is dropped.
+    fn finish(&self, resp: Result<ureq::Response, ureq::Error>, permit: Option<Permit>)
+        -> Result<HttpRequestResultRaw, Error>
+    {
         let (status, resp) = match resp {
             Ok(resp) => {
                 (resp.status(), resp)
@@ -230,6 +437,10 @@ impl HttpClient for UreqClient {
         };
 
         let result_header = resp.header("Dropbox-API-Result").map(String::from);
+        let retry_after = resp.header("Retry-After").map(String::from);
+        let content_range = resp.header("Content-Range").map(String::from);
+        let content_disposition = resp.header("Content-Disposition").map(String::from);
+        let request_id = resp.header("X-Dropbox-Request-Id").map(String::from);
 
         let content_length = resp.header("Content-Length")
             .map(|s| {
@@ -239,35 +450,152 @@ impl HttpClient for UreqClient {
             })
             .transpose()?;
 
+        let mut body: Box<dyn Read + Send> = match permit {
+            Some(permit) => Box::new(LimitedBody { inner: resp.into_reader(), _permit: permit }),
+            None => resp.into_reader(),
+        };
+        if let Some(timeout) = self.stall_timeout {
+            body = Box::new(StallBody { inner: body, timeout });
+        }
+
         Ok(HttpRequestResultRaw {
             status,
             result_header,
             content_length,
-            body: resp.into_reader(),
+            retry_after,
+            content_range,
+            content_disposition,
+            request_id,
+            body,
         })
     }
+}
 
-    fn new_request(&self, url: &str) -> Self::Request {
-        UreqRequest {
-            req: self.agent.post(url),
+/// A counting semaphore, for [`EndpointLimits`].
+#[derive(Debug)]
+struct Semaphore {
+    available: Mutex<usize>,
+    released: Condvar,
+}
+
+impl Semaphore {
+    fn new(permits: usize) -> Self {
+        Self {
+            available: Mutex::new(permits),
+            released: Condvar::new(),
         }
     }
+
+    /// Wait until a permit is available, and take it.
+    fn acquire(self: &Arc<Self>) -> Permit {
+        let mut available = self.released
+            .wait_while(self.available.lock().unwrap(), |available| *available == 0)
+            .unwrap();
+        *available -= 1;
+        Permit(Arc::clone(self))
+    }
+}
+
+/// A permit taken from a [`Semaphore`], which is given back when this is dropped.
+struct Permit(Arc<Semaphore>);
+
+impl Drop for Permit {
+    fn drop(&mut self) {
+        *self.0.available.lock().unwrap() += 1;
+        self.0.released.notify_one();
+    }
+}
+
+/// A response body which holds onto a [`Permit`] until it is dropped.
+struct LimitedBody {
+    inner: Box<dyn Read + Send>,
+    _permit: Permit,
+}
+
+impl Read for LimitedBody {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        self.inner.read(buf)
+    }
+}
+
+/// A response body which reports read timeouts as [`Stalled`] errors.
+struct StallBody {
+    inner: Box<dyn Read + Send>,
+    timeout: Duration,
+}
+
+impl Read for StallBody {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        self.inner.read(buf).map_err(|e| match e.kind() {
+            std::io::ErrorKind::TimedOut | std::io::ErrorKind::WouldBlock => {
+                Stalled { timeout: self.timeout }.into()
+            }
+            _ => e,
+        })
+    }
+}
+
+/// The same TLS configuration ureq uses by default, which it doesn't expose.
+fn tls_config() -> Arc<dyn ureq::TlsConnector> {
+    use ureq::rustls;
+    let config = rustls::ClientConfig::builder_with_provider(
+        rustls::crypto::ring::default_provider().into())
+        .with_protocol_versions(&[&rustls::version::TLS12, &rustls::version::TLS13])
+        .expect("the ring provider supports TLS 1.2 and 1.3")
+        .with_root_certificates(rustls::RootCertStore {
+            roots: webpki_roots::TLS_SERVER_ROOTS.to_vec(),
+        })
+        .with_no_client_auth();
+    Arc::new(Arc::new(config))
+}
+
+/// A TLS connector which removes the socket's read timeout once the connection is set up.
+///
+/// ureq sets the socket's read timeout when connecting, and only sets it again when it starts
+/// reading a response body. Removing it in between means the stall timeout only applies to
+/// response bodies, and not to waiting for the server to respond. Connections taken from the pool
+/// have no read timeout until then either.
+struct BodyTimeoutConnector(Arc<dyn ureq::TlsConnector>);
+
+impl ureq::TlsConnector for BodyTimeoutConnector {
+    fn connect(&self, dns_name: &str, io: Box<dyn ureq::ReadWrite>)
+        -> Result<Box<dyn ureq::ReadWrite>, ureq::Error>
+    {
+        let stream = self.0.connect(dns_name, io)?;
+        if let Some(socket) = stream.socket() {
+            socket.set_read_timeout(None)?;
+        }
+        Ok(stream)
+    }
+}
+
+/// A TLS connector which counts the handshakes it completes.
+struct CountingConnector(Arc<dyn ureq::TlsConnector>, Arc<StatsCounters>);
+
+impl ureq::TlsConnector for CountingConnector {
+    fn connect(&self, dns_name: &str, io: Box<dyn ureq::ReadWrite>)
+        -> Result<Box<dyn ureq::ReadWrite>, ureq::Error>
+    {
+        let stream = self.0.connect(dns_name, io)?;
+        self.1.record(ConnectionEvent::TlsHandshake);
+        Ok(stream)
+    }
 }
 
 /// This is an implementation detail of the HTTP client.
 pub struct UreqRequest {
     req: ureq::Request,
+    endpoint: Option<Endpoint>,
 }
 
 impl HttpRequest for UreqRequest {
     fn set_header(mut self, name: &str, value: &str) -> Self {
-        if name.eq_ignore_ascii_case("dropbox-api-arg") {
-            // Non-ASCII and 0x7F in a header need to be escaped per the HTTP spec, and ureq doesn't
-            // do this for us. This is only an issue for this particular header.
-            self.req = self.req.set(name, json_escape_header(value).as_ref());
-        } else {
-            self.req = self.req.set(name, value);
-        }
+        self.req = self.req.set(name, value);
+        self
+    }
+
+    fn set_timeout(mut self, timeout: Duration) -> Self {
+        self.req = self.req.timeout(timeout);
         self
     }
 }
@@ -325,53 +653,67 @@ impl std::fmt::Debug for RequestError {
 }
 
 impl std::error::Error for RequestError {
-    fn cause(&self) -> Option<&dyn std::error::Error> {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
         Some(&self.inner)
     }
 }
 
-/// Replaces any non-ASCII characters (and 0x7f) with JSON-style '\uXXXX' sequence. Otherwise,
-/// returns it unmodified without any additional allocation or copying.
-fn json_escape_header(s: &str) -> Cow<'_, str> {
-    // Unfortunately, the HTTP spec requires escaping ASCII DEL (0x7F), so we can't use the quicker
-    // bit pattern check done in str::is_ascii() to skip this for the common case of all ASCII. :(
+#[cfg(test)]
+mod test {
+    use super::*;
 
-    let mut out = Cow::Borrowed(s);
-    for (i, c) in s.char_indices() {
-        if !c.is_ascii() || c == '\x7f' {
-            let mstr = match out {
-                Cow::Borrowed(_) => {
-                    // If we're still borrowed, we must have had ascii up until this point.
-                    // Clone the string up until here, and from now on we'll be pushing chars to it.
-                    out = Cow::Owned(s[0..i].to_owned());
-                    out.to_mut()
-                }
-                Cow::Owned(ref mut m) => m,
-            };
-            write!(mstr, "\\u{:04x}", c as u32).unwrap();
-        } else if let Cow::Owned(ref mut o) = out {
-            o.push(c);
+    /// Connects without TLS.
+    struct Plain;
+
+    impl ureq::TlsConnector for Plain {
+        fn connect(&self, _dns_name: &str, io: Box<dyn ureq::ReadWrite>)
+            -> Result<Box<dyn ureq::ReadWrite>, ureq::Error>
+        {
+            Ok(io)
         }
     }
-    out
-}
 
-#[cfg(test)]
-mod test {
-    use super::*;
+    #[test]
+    fn test_body_timeout_connector() {
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let socket = std::net::TcpStream::connect(listener.local_addr().unwrap()).unwrap();
+        socket.set_read_timeout(Some(Duration::from_secs(1))).unwrap();
+
+        let connector = BodyTimeoutConnector(Arc::new(Plain));
+        let stream = ureq::TlsConnector::connect(&connector, "localhost", Box::new(socket)).unwrap();
+        assert_eq!(None, stream.socket().unwrap().read_timeout().unwrap());
+    }
 
     #[test]
-    fn test_json_escape() {
-        assert_eq!(Cow::Borrowed("foobar"), json_escape_header("foobar"));
-        assert_eq!(
-            Cow::<'_, str>::Owned("tro\\u0161kovi".to_owned()),
-            json_escape_header("troškovi"));
-        assert_eq!(
-            Cow::<'_, str>::Owned(
-                r#"{"field": "some_\u00fc\u00f1\u00eec\u00f8d\u00e9_and_\u007f"}"#.to_owned()),
-            json_escape_header("{\"field\": \"some_üñîcødé_and_\x7f\"}"));
-        assert_eq!(
-            Cow::<'_, str>::Owned("almost,\\u007f but not quite".to_owned()),
-            json_escape_header("almost,\x7f but not quite"));
+    fn test_connection_stats() {
+        // A server which hangs up straight away, so requests fail after connecting.
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let port = listener.local_addr().unwrap().port();
+        let url = format!("https://localhost:{port}/2/check/app");
+        std::thread::spawn(move || listener.incoming().for_each(drop));
+
+        let events = Arc::new(Mutex::new(Vec::new()));
+        let client = NoauthDefaultClient::default().with_connection_events({
+            let events = Arc::clone(&events);
+            Arc::new(move |event: &ConnectionEvent| events.lock().unwrap().push(*event))
+        });
+        let clone = client.clone();
+        for client in [&client, &clone] {
+            assert!(client.execute(client.new_request(&url), b"").is_err());
+        }
+        let stats = client.connection_stats();
+        assert_eq!(2, stats.requests);
+        assert_eq!(2, stats.new_connections);
+        assert_eq!(0, stats.reused_connections());
+        assert_eq!(2, stats.dns_lookups);
+        assert_eq!(0, stats.tls_handshakes);
+        assert_eq!(stats, clone.connection_stats());
+
+        let events = events.lock().unwrap();
+        let count = |event| events.iter().filter(|&&e| e == event).count();
+        assert_eq!(2, count(ConnectionEvent::Request));
+        assert_eq!(2, count(ConnectionEvent::NewConnection));
+        assert_eq!(2, count(ConnectionEvent::DnsLookup));
+        assert_eq!(6, events.len());
     }
 }
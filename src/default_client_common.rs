@@ -1,3 +1,14 @@
+use std::sync::{Arc, RwLock};
+use std::sync::atomic::{AtomicU64, Ordering};
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD;
+use crate::client_trait_common::Endpoint;
+
+/// The value of the `Authorization` header used for App authentication.
+pub(crate) fn app_auth_header(app_key: &str, app_secret: &str) -> String {
+    format!("Basic {}", STANDARD.encode(format!("{app_key}:{app_secret}")))
+}
+
 macro_rules! impl_set_path_root {
     ($self:ident) => {
         /// Set a root which all subsequent paths are evaluated relative to.
@@ -9,10 +20,372 @@ macro_rules! impl_set_path_root {
         /// information.
         #[cfg(feature = "dbx_common")]
         pub fn set_path_root(&mut $self, path_root: &crate::types::common::PathRoot) {
-            // Only way this can fail is if PathRoot::Other was specified, which is a programmer
-            // error, so panic if that happens.
+            // Serializing a PathRoot can't fail, even for PathRoot::Other, which is serialized as
+            // it was received.
             $self.path_root = Some(serde_json::to_string(path_root).expect("invalid path root"));
         }
     }
 }
 pub(crate) use impl_set_path_root;
+
+macro_rules! impl_set_locale {
+    ($self:ident) => {
+        /// Set the locale that user-facing strings returned by the server, such as the
+        /// `user_message` of API errors, should be localized in.
+        ///
+        /// The locale is given as an IETF language tag, such as `"en-GB"` or `"ja"`. The default,
+        /// if this function is not called, is the locale of the user's Dropbox account.
+        pub fn set_locale(&mut $self, locale: Option<String>) {
+            $self.locale = locale;
+        }
+    }
+}
+pub(crate) use impl_set_locale;
+
+macro_rules! impl_team_select {
+    () => {
+        /// Create a new client using the given cache of the team's tokens, which acts as the given
+        /// team member.
+        pub fn for_member(tokens: impl Into<Arc<TokenCache>>, member_id: impl Into<String>) -> Self {
+            let mut client = Self::new(tokens);
+            client.select(Some(TeamSelect::User(member_id.into())));
+            client
+        }
+
+        /// Create a new client using the given cache of the team's tokens, which acts as the given
+        /// team admin, with the additional access that grants.
+        pub fn for_admin(tokens: impl Into<Arc<TokenCache>>, admin_id: impl Into<String>) -> Self {
+            let mut client = Self::new(tokens);
+            client.select(Some(TeamSelect::Admin(admin_id.into())));
+            client
+        }
+
+        /// Get a copy of this client which acts as the given team member.
+        ///
+        /// The copy shares this client's tokens, connections, and settings, so it's cheap to make
+        /// one for each member being worked on, and use them at the same time.
+        pub fn as_member(&self, member_id: impl Into<String>) -> Self {
+            let mut client = self.clone();
+            client.select(Some(TeamSelect::User(member_id.into())));
+            client
+        }
+
+        /// Get a copy of this client which acts as the given team admin, sharing this client's
+        /// tokens, connections, and settings like [`as_member`](Self::as_member).
+        pub fn as_admin(&self, admin_id: impl Into<String>) -> Self {
+            let mut client = self.clone();
+            client.select(Some(TeamSelect::Admin(admin_id.into())));
+            client
+        }
+    };
+}
+pub(crate) use impl_team_select;
+
+/// Semaphores limiting how many requests to each endpoint can be in flight at once, for those
+/// endpoints which have a limit.
+#[derive(Debug)]
+pub(crate) struct EndpointLimits<S> {
+    api: Option<Arc<S>>,
+    content: Option<Arc<S>>,
+    notify: Option<Arc<S>>,
+    oauth2: Option<Arc<S>>,
+}
+
+impl<S> EndpointLimits<S> {
+    pub fn get(&self, endpoint: Option<Endpoint>) -> Option<&Arc<S>> {
+        match endpoint? {
+            Endpoint::Api => self.api.as_ref(),
+            Endpoint::Content => self.content.as_ref(),
+            Endpoint::Notify => self.notify.as_ref(),
+            Endpoint::OAuth2 => self.oauth2.as_ref(),
+        }
+    }
+
+    pub fn set(&mut self, endpoint: Endpoint, semaphore: S) {
+        let slot = match endpoint {
+            Endpoint::Api => &mut self.api,
+            Endpoint::Content => &mut self.content,
+            Endpoint::Notify => &mut self.notify,
+            Endpoint::OAuth2 => &mut self.oauth2,
+        };
+        *slot = Some(Arc::new(semaphore));
+    }
+}
+
+// Not derived, as that would require `S: Clone` and `S: Default`.
+impl<S> Clone for EndpointLimits<S> {
+    fn clone(&self) -> Self {
+        Self {
+            api: self.api.clone(),
+            content: self.content.clone(),
+            notify: self.notify.clone(),
+            oauth2: self.oauth2.clone(),
+        }
+    }
+}
+
+impl<S> Default for EndpointLimits<S> {
+    fn default() -> Self {
+        Self {
+            api: None,
+            content: None,
+            notify: None,
+            oauth2: None,
+        }
+    }
+}
+
+macro_rules! impl_connection_stats {
+    () => {
+        /// Get counts of the requests and connections made by this client so far, including those
+        /// made by its clones, which share its connection pool.
+        pub fn connection_stats(&self) -> ConnectionStats {
+            self.inner.stats.snapshot()
+        }
+
+        /// Report each [`ConnectionEvent`] to the given [`ConnectionEvents`] as it happens, such as
+        /// to export them to a metrics system. This replaces any previously given, and applies to
+        /// the clones of this client too, as they share its connection pool.
+        pub fn with_connection_events(self, events: Arc<dyn ConnectionEvents>) -> Self {
+            *self.inner.stats.events.write().unwrap() = Some(events);
+            self
+        }
+    };
+}
+pub(crate) use impl_connection_stats;
+
+/// Counts of the requests and connections made by a default client, for diagnosing throughput
+/// problems.
+///
+/// Each new connection needs a TLS handshake, and usually a DNS lookup, which take several round
+/// trips, so if requests are often not reusing connections, they are slower than they need to be.
+/// This can happen when more requests are made at once than there are idle connections in the
+/// pool.
+///
+/// To get these as they change, rather than polling for them, give the client
+/// [`ConnectionEvents`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct ConnectionStats {
+    /// The number of requests made.
+    pub requests: u64,
+
+    /// The number of new connections attempted. The rest of the requests reused an existing
+    /// connection.
+    pub new_connections: u64,
+
+    /// The number of DNS lookups made for new connections. This is fewer than the new connections
+    /// when connecting to an IP address, or through a Unix socket.
+    pub dns_lookups: u64,
+
+    /// The number of TLS handshakes completed for new connections. This is fewer than the new
+    /// connections when some fail to connect.
+    pub tls_handshakes: u64,
+}
+
+impl ConnectionStats {
+    /// The number of requests which reused an existing connection.
+    pub fn reused_connections(&self) -> u64 {
+        self.requests.saturating_sub(self.new_connections)
+    }
+}
+
+/// Something a default client did, reported to its [`ConnectionEvents`]. Each of these adds one
+/// to the corresponding count in [`ConnectionStats`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ConnectionEvent {
+    /// A request is being sent.
+    Request,
+
+    /// A new connection is being made, as no idle one could be reused.
+    NewConnection,
+
+    /// A host name is being looked up for a new connection.
+    DnsLookup,
+
+    /// A new connection completed its TLS handshake.
+    TlsHandshake,
+}
+
+/// A hook for exporting a default client's [`ConnectionEvent`]s, given to it with
+/// `with_connection_events`.
+///
+/// This is called on the thread making the request or connection, so it should return quickly. It
+/// is implemented for closures taking a `&ConnectionEvent`.
+pub trait ConnectionEvents: Send + Sync {
+    /// Handle an event.
+    fn on_event(&self, event: &ConnectionEvent);
+}
+
+impl<F: Fn(&ConnectionEvent) + Send + Sync> ConnectionEvents for F {
+    fn on_event(&self, event: &ConnectionEvent) {
+        self(event)
+    }
+}
+
+#[derive(Default)]
+pub(crate) struct StatsCounters {
+    requests: AtomicU64,
+    new_connections: AtomicU64,
+    dns_lookups: AtomicU64,
+    tls_handshakes: AtomicU64,
+    pub events: RwLock<Option<Arc<dyn ConnectionEvents>>>,
+}
+
+impl StatsCounters {
+    pub fn record(&self, event: ConnectionEvent) {
+        let counter = match event {
+            ConnectionEvent::Request => &self.requests,
+            ConnectionEvent::NewConnection => &self.new_connections,
+            ConnectionEvent::DnsLookup => &self.dns_lookups,
+            ConnectionEvent::TlsHandshake => &self.tls_handshakes,
+        };
+        counter.fetch_add(1, Ordering::Relaxed);
+        if let Some(events) = self.events.read().unwrap().as_ref() {
+            events.on_event(&event);
+        }
+    }
+
+    pub fn snapshot(&self) -> ConnectionStats {
+        ConnectionStats {
+            requests: self.requests.load(Ordering::Relaxed),
+            new_connections: self.new_connections.load(Ordering::Relaxed),
+            dns_lookups: self.dns_lookups.load(Ordering::Relaxed),
+            tls_handshakes: self.tls_handshakes.load(Ordering::Relaxed),
+        }
+    }
+}
+
+// Not derived, as the events hook isn't Debug.
+impl std::fmt::Debug for StatsCounters {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("StatsCounters")
+            .field("stats", &self.snapshot())
+            .finish_non_exhaustive()
+    }
+}
+
+macro_rules! impl_with_concurrency_limit {
+    () => {
+        /// Allow at most `limit` requests to the given endpoint to be in flight at once, counting
+        /// from when each request is sent until its response body has been read or dropped.
+        /// Further requests to the endpoint wait until one of these finishes.
+        ///
+        /// For example, limiting [`Endpoint::Content`](crate::client_trait_common::Endpoint::Content)
+        /// requests keeps many concurrent uploads and downloads from causing rate-limiting, while
+        /// leaving other calls unconstrained. Requests to endpoints without a limit are not
+        /// limited. The limit is shared with clones of the client made after it is set.
+        ///
+        /// # Panics
+        ///
+        /// Panics if `limit` is zero.
+        pub fn with_concurrency_limit(
+            mut self,
+            endpoint: crate::client_trait_common::Endpoint,
+            limit: usize,
+        ) -> Self {
+            assert!(limit > 0, "concurrency limit must be at least 1");
+            self.inner.limits.set(endpoint, Semaphore::new(limit));
+            self
+        }
+    };
+}
+pub(crate) use impl_with_concurrency_limit;
+
+macro_rules! impl_with_stall_timeout {
+    () => {
+        /// Abort reading a response body if no data arrives for the given time, failing the read
+        /// with a [`Stalled`](crate::client_trait_common::Stalled) error instead of waiting
+        /// forever on a dead connection. By default there is no stall timeout. Waiting for the
+        /// server to start responding isn't limited by this; use
+        /// [`with_total_timeout`](Self::with_total_timeout) for that.
+        ///
+        /// This is shared with clones of the client made after it is set.
+        pub fn with_stall_timeout(mut self, timeout: std::time::Duration) -> Self {
+            self.inner.set_stall_timeout(timeout);
+            self
+        }
+    };
+}
+pub(crate) use impl_with_stall_timeout;
+
+macro_rules! impl_with_extra_header {
+    () => {
+        /// Send an extra header with every request, such as one which opts in to beta behavior of
+        /// the API. This can be called more than once to send several headers. Headers which the
+        /// SDK sets itself, such as `Authorization`, shouldn't be given here.
+        ///
+        /// This is shared with clones of the client made after it is set.
+        pub fn with_extra_header(mut self, name: impl Into<String>, value: impl Into<String>)
+            -> Self
+        {
+            self.inner.extra_headers.push((name.into(), value.into()));
+            self
+        }
+    };
+}
+pub(crate) use impl_with_extra_header;
+
+macro_rules! impl_extra_headers {
+    () => {
+        fn extra_headers(&self) -> &[(String, String)] {
+            &self.inner.extra_headers
+        }
+    };
+}
+pub(crate) use impl_extra_headers;
+
+macro_rules! impl_with_total_timeout {
+    () => {
+        /// Limit how long each call of a route may take in total, including refreshing the access
+        /// token, retrying with the new one, and reading the body of a download. Each request is
+        /// given the time remaining as its timeout, overriding the client's own timeouts. The call
+        /// fails with [`DeadlineExceeded`](crate::client_trait_common::DeadlineExceeded) if it
+        /// runs out before the response arrives, and reading the body fails with a timeout error
+        /// if it runs out after.
+        ///
+        /// For a limit across all the requests of a helper, such as a chunked upload, use
+        /// [`RetryBudget::with_deadline`](crate::retry::RetryBudget::with_deadline).
+        pub fn with_total_timeout(mut self, timeout: Duration) -> Self {
+            self.inner.total_timeout = Some(timeout);
+            self
+        }
+    };
+}
+pub(crate) use impl_with_total_timeout;
+
+macro_rules! impl_total_timeout {
+    () => {
+        fn total_timeout(&self) -> Option<Duration> {
+            self.inner.total_timeout
+        }
+    };
+}
+pub(crate) use impl_total_timeout;
+
+macro_rules! impl_with_request_compression {
+    () => {
+        /// Compress the JSON arguments of RPC-style requests with gzip when they're larger than
+        /// `threshold` bytes, such as for big batch operations, to reduce the amount of data sent.
+        /// By default nothing is compressed. File contents uploaded are never compressed.
+        ///
+        /// This is shared with clones of the client made after it is set.
+        #[cfg(feature = "gzip")]
+        pub fn with_request_compression(mut self, threshold: usize) -> Self {
+            self.inner.gzip_threshold = Some(threshold);
+            self
+        }
+    };
+}
+pub(crate) use impl_with_request_compression;
+
+macro_rules! impl_gzip_requests_over {
+    () => {
+        #[cfg(feature = "gzip")]
+        fn gzip_requests_over(&self) -> Option<usize> {
+            self.inner.gzip_threshold
+        }
+    };
+}
+pub(crate) use impl_gzip_requests_over;
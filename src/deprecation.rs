@@ -0,0 +1,62 @@
+//! Warnings about calls to deprecated API routes.
+//!
+//! The first time each deprecated route is called, a warning is logged using the `log` crate,
+//! naming the route and the one which replaces it, if any. This makes use of routes which are going
+//! to be removed noticeable in production logs, even when the call comes from code which was built
+//! without looking at compiler warnings. The warnings can be turned off with [`set_warnings`].
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use crate::generated::DEPRECATED_ROUTES;
+
+static WARNINGS_ENABLED: AtomicBool = AtomicBool::new(true);
+
+// Only used to initialize the array below, which is how to repeat a value which isn't Copy.
+#[allow(clippy::declare_interior_mutable_const)]
+const NOT_WARNED: AtomicBool = AtomicBool::new(false);
+
+/// Whether each deprecated route has been warned about yet.
+static WARNED: [AtomicBool; DEPRECATED_ROUTES.len()] = [NOT_WARNED; DEPRECATED_ROUTES.len()];
+
+/// Turn the warnings on or off, for the whole program. They are on by default.
+pub fn set_warnings(enabled: bool) {
+    WARNINGS_ENABLED.store(enabled, Ordering::Relaxed);
+}
+
+/// Whether the route with the given name, such as `"files/copy"`, is deprecated.
+pub fn is_deprecated(route: &str) -> bool {
+    lookup(route).is_some()
+}
+
+/// The name of the route which replaces the given deprecated route, if it is deprecated and has a
+/// replacement.
+pub fn replaced_by(route: &str) -> Option<&'static str> {
+    lookup(route).and_then(|(_, replacement)| replacement)
+}
+
+fn lookup(route: &str) -> Option<(&'static str, Option<&'static str>)> {
+    position(route).map(|idx| DEPRECATED_ROUTES[idx])
+}
+
+fn position(route: &str) -> Option<usize> {
+    DEPRECATED_ROUTES.binary_search_by(|(name, _)| name.cmp(&route)).ok()
+}
+
+/// Log a warning if the given route is deprecated, and hasn't been warned about before.
+pub(crate) fn warn_if_deprecated(route: &str) {
+    if !WARNINGS_ENABLED.load(Ordering::Relaxed) {
+        return;
+    }
+    let idx = match position(route) {
+        Some(idx) => idx,
+        None => return,
+    };
+    if WARNED[idx].swap(true, Ordering::Relaxed) {
+        return;
+    }
+    match DEPRECATED_ROUTES[idx] {
+        (name, Some(replacement)) => {
+            warn!("called deprecated route {name}; use {replacement} instead")
+        }
+        (name, None) => warn!("called deprecated route {name}, which is going to be removed"),
+    }
+}
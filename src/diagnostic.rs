@@ -0,0 +1,298 @@
+//! Rich error reports for [`Error`], using `miette`.
+//!
+//! Each report has a code identifying the kind of error, such as `dropbox::api::path::not_found`
+//! or `dropbox::rate_limited`, and advice on what to do about it where there is some. Errors with
+//! a boxed API error get codes which don't go into the API error's details, as its type isn't
+//! known.
+//!
+//! An [`Error`] doesn't say which request it came from. To report that too, make the call inside
+//! [`capture`] (or [`capture_async`] for async routes), which returns a [`Report`] with the route,
+//! HTTP status, and request ID of the last response the call received:
+//!
+//! ```ignore
+//! let metadata = diagnostic::capture(|| files::get_metadata(&client, &arg))?;
+//! ```
+//!
+//! This is only built with the `miette` Cargo feature.
+
+use std::cell::RefCell;
+use std::fmt::Display;
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use std::time::Duration;
+use miette::Diagnostic;
+use crate::types::auth::AuthError;
+use crate::{BoxedApiError, Error, PolicyError};
+
+/// The request an error came from: the details of the last response received while making a call
+/// inside [`capture`] or [`capture_async`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct RequestContext {
+    /// The route called, such as `files/get_metadata`.
+    pub route: String,
+
+    /// The HTTP status of the response.
+    pub status: u16,
+
+    /// The request ID Dropbox gave the response, if any, which identifies the request to Dropbox
+    /// support.
+    pub request_id: Option<String>,
+}
+
+/// An [`Error`] along with the request it came from, if it came from a response.
+///
+/// Its message says which route failed, with what HTTP status and request ID, and the error is
+/// its source. Its code and advice are the error's.
+#[derive(Debug)]
+pub struct Report<E> {
+    error: Error<E>,
+    context: Option<RequestContext>,
+}
+
+impl<E> Report<E> {
+    /// The error.
+    pub fn error(&self) -> &Error<E> {
+        &self.error
+    }
+
+    /// The request the error came from, or `None` if the call failed without receiving a
+    /// response, such as when it couldn't connect.
+    pub fn context(&self) -> Option<&RequestContext> {
+        self.context.as_ref()
+    }
+
+    /// Get the error back.
+    pub fn into_error(self) -> Error<E> {
+        self.error
+    }
+}
+
+impl<E> std::fmt::Display for Report<E>
+where
+    Error<E>: std::fmt::Display,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let Some(context) = &self.context else {
+            return self.error.fmt(f);
+        };
+        write!(f, "{} returned HTTP {}", context.route, context.status)?;
+        if let Some(request_id) = &context.request_id {
+            write!(f, " (request ID {request_id})")?;
+        }
+        Ok(())
+    }
+}
+
+impl<E: std::fmt::Debug> std::error::Error for Report<E>
+where
+    Error<E>: std::error::Error + 'static,
+{
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self.context {
+            Some(_) => Some(&self.error),
+            None => self.error.source(),
+        }
+    }
+}
+
+impl<E: std::fmt::Debug> Diagnostic for Report<E>
+where
+    Error<E>: Diagnostic + 'static,
+{
+    fn code<'a>(&'a self) -> Option<Box<dyn Display + 'a>> {
+        self.error.code()
+    }
+
+    fn help<'a>(&'a self) -> Option<Box<dyn Display + 'a>> {
+        self.error.help()
+    }
+}
+
+thread_local! {
+    /// The last response received by each call being captured on this thread, innermost last.
+    static CAPTURED: RefCell<Vec<Option<RequestContext>>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Run `f` with a slot for recording responses in, which starts off holding `context`, and return
+/// what it ended up holding.
+fn with_slot<T>(context: Option<RequestContext>, f: impl FnOnce() -> T)
+    -> (T, Option<RequestContext>)
+{
+    /// Removes the slot even if `f` panics.
+    struct Slot;
+
+    impl Drop for Slot {
+        fn drop(&mut self) {
+            CAPTURED.with(|captured| captured.borrow_mut().pop());
+        }
+    }
+
+    CAPTURED.with(|captured| captured.borrow_mut().push(context));
+    let slot = Slot;
+    let result = f();
+    let context = CAPTURED.with(|captured| captured.borrow_mut().last_mut().and_then(Option::take));
+    drop(slot);
+    (result, context)
+}
+
+/// Record a response for the innermost call being captured on this thread, if there is one.
+pub(crate) fn record(route: &str, status: u16, request_id: Option<&str>) {
+    CAPTURED.with(|captured| {
+        if let Some(slot) = captured.borrow_mut().last_mut() {
+            *slot = Some(RequestContext {
+                route: route.to_owned(),
+                status,
+                request_id: request_id.map(ToOwned::to_owned),
+            });
+        }
+    });
+}
+
+/// Make a call with a sync client, such as of a route, and if it fails, report the request the
+/// error came from along with it.
+///
+/// The request recorded is the last one the call made on this thread, so this doesn't work with
+/// calls which hand their requests off to other threads.
+pub fn capture<T, E>(f: impl FnOnce() -> Result<T, Error<E>>) -> Result<T, Report<E>> {
+    let (result, context) = with_slot(None, f);
+    result.map_err(|error| Report { error, context })
+}
+
+/// Make a call with an async client, such as of a route, and if it fails, report the request the
+/// error came from along with it.
+///
+/// The request recorded is the last one the future made while being polled, so this doesn't work
+/// with futures which spawn their requests as separate tasks.
+pub async fn capture_async<T, E>(future: impl Future<Output = Result<T, Error<E>>>)
+    -> Result<T, Report<E>>
+{
+    let mut capture = Capture { future: Box::pin(future), context: None };
+    let result = (&mut capture).await;
+    result.map_err(|error| Report { error, context: capture.context })
+}
+
+/// Polls a future with a slot for recording responses in, keeping what it holds between polls.
+struct Capture<F> {
+    future: Pin<Box<F>>,
+    context: Option<RequestContext>,
+}
+
+impl<F: Future> Future for Capture<F> {
+    type Output = F::Output;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = &mut *self;
+        let (poll, context) = with_slot(this.context.take(), || this.future.as_mut().poll(cx));
+        this.context = context;
+        poll
+    }
+}
+
+impl<E> Diagnostic for Error<E>
+where
+    E: std::error::Error + serde::Serialize + 'static,
+{
+    fn code<'a>(&'a self) -> Option<Box<dyn Display + 'a>> {
+        let api_tags = match self {
+            Error::Api(e) => tags(e),
+            _ => None,
+        };
+        Some(Box::new(code(self, api_tags)))
+    }
+
+    fn help<'a>(&'a self) -> Option<Box<dyn Display + 'a>> {
+        help(self, self.retry_after(), self.policy_error())
+    }
+}
+
+impl Diagnostic for Error<BoxedApiError> {
+    fn code<'a>(&'a self) -> Option<Box<dyn Display + 'a>> {
+        Some(Box::new(code(self, None)))
+    }
+
+    fn help<'a>(&'a self) -> Option<Box<dyn Display + 'a>> {
+        help(self, self.server_retry_after(), None)
+    }
+}
+
+/// The code for an error, given the tags of its API error, if it has one and they are known.
+fn code<E>(error: &Error<E>, api_tags: Option<Vec<String>>) -> String {
+    let (kind, details) = match error {
+        Error::Api(_) => ("api", api_tags),
+        Error::HttpClient(_) => ("http_client", None),
+        Error::Json(_) => ("json", None),
+        Error::Deserialize { .. } => ("deserialize", None),
+        Error::UnexpectedResponse(_) => ("unexpected_response", None),
+        Error::BadRequest(_) => ("bad_request", None),
+        Error::Authentication(e) => ("authentication", tags(e)),
+        Error::RateLimited { .. } => ("rate_limited", None),
+        Error::AccessDenied(e) => ("access_denied", tags(e)),
+        Error::ServerError(_) => ("server_error", None),
+        Error::ServiceUnavailable { .. } => ("service_unavailable", None),
+        Error::UnexpectedHttpError { code, .. } => ("http", Some(vec![code.to_string()])),
+    };
+    let mut code = format!("dropbox::{kind}");
+    for part in details.into_iter().flatten() {
+        code.push_str("::");
+        code.push_str(&part);
+    }
+    code
+}
+
+/// The tags of a union and the unions nested within it, outermost first.
+///
+/// Unions are serialized with the tag of the variant in `.tag`, and the variant's value, if it is
+/// a union too, in a field named after the tag, such as
+/// `{".tag": "path", "path": {".tag": "not_found"}}`.
+fn tags(e: &impl serde::Serialize) -> Option<Vec<String>> {
+    let root = serde_json::to_value(e).ok()?;
+    let mut value = &root;
+    let mut tags = vec![];
+    while let Some(tag) = value.get(".tag").and_then(serde_json::Value::as_str) {
+        tags.push(tag.to_owned());
+        match value.get(tag) {
+            Some(inner) => value = inner,
+            None => break,
+        }
+    }
+    Some(tags)
+}
+
+/// Advice on what to do about an error, if there is any.
+fn help<'a, E>(error: &'a Error<E>, retry_after: Option<Duration>, policy: Option<PolicyError>)
+    -> Option<Box<dyn Display + 'a>>
+{
+    if let Some(scope) = error.missing_scope() {
+        return Some(Box::new(format!(
+            "the app needs the `{scope}` scope: enable it in the App Console, then have the user \
+            authorize the app again, requesting the scope")));
+    }
+    if let Some(wait) = retry_after {
+        return Some(Box::new(format!(
+            "this is temporary; retry after {}s", wait.as_secs_f32())));
+    }
+    if let Some(policy) = policy {
+        return Some(Box::new(format!("{policy}, so retrying won't help")));
+    }
+    Some(Box::new(match error {
+        Error::Authentication(AuthError::ExpiredAccessToken) => {
+            "the access token has expired: refresh it, or have the user authorize the app again"
+        }
+        Error::Authentication(AuthError::InvalidAccessToken | AuthError::UserSuspended) => {
+            "have the user authorize the app again"
+        }
+        Error::HttpClient(_) | Error::ServerError(_) => {
+            "this is probably temporary; retry later"
+        }
+        Error::Deserialize { .. } | Error::UnexpectedResponse(_) => {
+            "this usually means the SDK is out of date or has a bug; please report it, including \
+            the details of the error"
+        }
+        Error::BadRequest(_) => {
+            "check the arguments to the route; if they are valid, the SDK may be out of date"
+        }
+        _ => return None,
+    }))
+}
@@ -0,0 +1,275 @@
+//! A local cache of downloaded files, keyed by their content hash.
+//!
+//! Files with the same contents have the same [content hash](crate::content_hash), no matter their
+//! path or revision, so a [`DiskCache`] lets apps which fetch the same files repeatedly (such as
+//! preview servers) download each distinct file only once. [`DiskCache::get_or_download`] looks up
+//! a file's content hash with `files/get_metadata`, and only downloads it if it isn't cached yet.
+//!
+//! The cache can be limited in size, in which case the least recently used files are evicted to
+//! make room for new ones. Which files were used recently is only tracked while the cache is open;
+//! when it is opened again, files are considered used when they were added.
+//!
+//! This is only built with the `disk_cache` Cargo feature.
+
+use std::collections::HashMap;
+use std::io::{self, Read, Write};
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+use std::time::SystemTime;
+use futures::AsyncReadExt;
+use crate::async_client_trait::UserAuthClient;
+use crate::content_hash::ContentHasher;
+use crate::generated::async_routes::files;
+use crate::Error;
+
+/// Prefix of the names of files being written, before they are moved into place.
+const TEMP_PREFIX: &str = "tmp-";
+
+/// An error from [`DiskCache::get_or_download`].
+#[derive(thiserror::Error, Debug)]
+pub enum DiskCacheError {
+    /// The file's metadata couldn't be retrieved.
+    #[error("error getting the file's metadata: {0}")]
+    Metadata(#[source] Error<files::GetMetadataError>),
+
+    /// The path is a folder, not a file.
+    #[error("{0} is not a file")]
+    NotAFile(String),
+
+    /// The file couldn't be downloaded.
+    #[error("error downloading the file: {0}")]
+    Download(#[source] Error<files::DownloadError>),
+
+    /// An I/O error reading the download or writing to the cache directory.
+    #[error("I/O error: {0}")]
+    Io(#[from] io::Error),
+}
+
+#[derive(Debug)]
+struct Entry {
+    size: u64,
+    last_used: SystemTime,
+}
+
+/// A directory of cached files, each named by its content hash.
+#[derive(Debug)]
+pub struct DiskCache {
+    dir: PathBuf,
+    max_size: Option<u64>,
+    entries: Mutex<HashMap<String, Entry>>,
+    temp_counter: AtomicU64,
+}
+
+impl DiskCache {
+    /// Open a cache in the given directory, creating it if it doesn't exist yet.
+    ///
+    /// Files in the directory which aren't cache entries are ignored, except for leftovers of
+    /// interrupted insertions, which are removed.
+    pub fn open(dir: impl Into<PathBuf>) -> io::Result<Self> {
+        let dir = dir.into();
+        std::fs::create_dir_all(&dir)?;
+        let mut entries = HashMap::new();
+        for entry in std::fs::read_dir(&dir)? {
+            let entry = entry?;
+            let Some(name) = entry.file_name().to_str().map(str::to_owned) else {
+                continue;
+            };
+            if name.starts_with(TEMP_PREFIX) {
+                debug!("removing leftover {}", entry.path().display());
+                std::fs::remove_file(entry.path())?;
+            } else if is_content_hash(&name) {
+                let metadata = entry.metadata()?;
+                entries.insert(name, Entry {
+                    size: metadata.len(),
+                    last_used: metadata.modified()?,
+                });
+            }
+        }
+        Ok(Self {
+            dir,
+            max_size: None,
+            entries: Mutex::new(entries),
+            temp_counter: AtomicU64::new(0),
+        })
+    }
+
+    /// Limit the total size of the cached files, in bytes. By default there is no limit.
+    ///
+    /// The limit is enforced whenever a file is inserted.
+    pub fn with_max_size(mut self, bytes: u64) -> Self {
+        self.max_size = Some(bytes);
+        self
+    }
+
+    /// The total size of the cached files, in bytes.
+    pub fn size(&self) -> u64 {
+        self.entries.lock().unwrap().values().map(|e| e.size).sum()
+    }
+
+    /// Get the path of the cached file with the given content hash, if there is one.
+    pub fn get(&self, content_hash: &str) -> Option<PathBuf> {
+        let mut entries = self.entries.lock().unwrap();
+        let entry = entries.get_mut(content_hash)?;
+        entry.last_used = SystemTime::now();
+        Some(self.dir.join(content_hash))
+    }
+
+    /// Add a file to the cache, with the data read from the given reader, and return its path.
+    ///
+    /// The data is checked against the content hash; if it doesn't match, nothing is added and an
+    /// error of kind [`io::ErrorKind::InvalidData`] is returned.
+    pub fn insert(&self, content_hash: &str, mut data: impl Read) -> io::Result<PathBuf> {
+        let mut writer = self.start_insert()?;
+        io::copy(&mut data, &mut writer)?;
+        self.finish_insert(writer, content_hash)
+    }
+
+    /// Evict the least recently used files, until the cache is within its maximum size.
+    pub fn evict(&self) -> io::Result<()> {
+        self.evict_except(None)
+    }
+
+    /// Get the path of the cached copy of the file at the given Dropbox path, downloading it into
+    /// the cache first if necessary.
+    ///
+    /// This calls `files/get_metadata` each time, to find the file's current content hash. It
+    /// does blocking I/O when writing the downloaded file.
+    pub async fn get_or_download(
+        &self,
+        client: &impl UserAuthClient,
+        path: &str,
+    ) -> Result<PathBuf, DiskCacheError> {
+        let arg = files::GetMetadataArg::new(path.to_owned());
+        let content_hash = match files::get_metadata(client, &arg).await {
+            Ok(files::Metadata::File(file)) => file.content_hash,
+            Ok(_) => return Err(DiskCacheError::NotAFile(path.to_owned())),
+            Err(e) => return Err(DiskCacheError::Metadata(e)),
+        };
+        if let Some(hash) = &content_hash {
+            if let Some(cached) = self.get(hash) {
+                trace!("{path} is cached as {hash}");
+                return Ok(cached);
+            }
+        }
+
+        debug!("downloading {path} into the cache");
+        let arg = files::DownloadArg::new(path.to_owned());
+        let result = files::download(client, &arg, None, None)
+            .await
+            .map_err(DiskCacheError::Download)?;
+        // The file may have changed since getting its metadata, so use the downloaded content's
+        // own hash.
+        let content_hash = result.result.content_hash.or(content_hash).ok_or_else(|| {
+            io::Error::new(io::ErrorKind::InvalidData, "file has no content hash")
+        })?;
+        let mut body = result.body.ok_or_else(|| {
+            io::Error::new(io::ErrorKind::UnexpectedEof, "download has no body")
+        })?;
+
+        let mut writer = self.start_insert()?;
+        let mut buf = vec![0; 64 * 1024];
+        loop {
+            let n = body.read(&mut buf).await?;
+            if n == 0 {
+                break;
+            }
+            writer.write_all(&buf[..n])?;
+        }
+        Ok(self.finish_insert(writer, &content_hash)?)
+    }
+
+    fn start_insert(&self) -> io::Result<TempWriter> {
+        let n = self.temp_counter.fetch_add(1, Ordering::SeqCst);
+        let path = self.dir.join(format!("{TEMP_PREFIX}{}-{n}", std::process::id()));
+        let file = std::fs::File::create(&path)?;
+        Ok(TempWriter {
+            path: Some(path),
+            file,
+            hasher: ContentHasher::new(),
+            size: 0,
+        })
+    }
+
+    fn finish_insert(&self, mut writer: TempWriter, content_hash: &str) -> io::Result<PathBuf> {
+        writer.file.flush()?;
+        let hasher = std::mem::take(&mut writer.hasher);
+        if hasher.finish() != content_hash {
+            return Err(io::Error::new(io::ErrorKind::InvalidData,
+                format!("data doesn't match the content hash {content_hash}")));
+        }
+        let path = self.dir.join(content_hash);
+        std::fs::rename(writer.path.as_ref().unwrap(), &path)?;
+        writer.path = None; // nothing to clean up
+        self.entries.lock().unwrap().insert(content_hash.to_owned(), Entry {
+            size: writer.size,
+            last_used: SystemTime::now(),
+        });
+        self.evict_except(Some(content_hash))?;
+        Ok(path)
+    }
+
+    /// Evict files until the cache is within its maximum size, keeping the given one even if it is
+    /// over the size by itself.
+    fn evict_except(&self, keep: Option<&str>) -> io::Result<()> {
+        let Some(max_size) = self.max_size else {
+            return Ok(());
+        };
+        let mut entries = self.entries.lock().unwrap();
+        let mut size = entries.values().map(|e| e.size).sum::<u64>();
+        if size <= max_size {
+            return Ok(());
+        }
+        let mut by_age = entries.iter()
+            .filter(|(hash, _)| Some(hash.as_str()) != keep)
+            .map(|(hash, entry)| (entry.last_used, hash.clone()))
+            .collect::<Vec<_>>();
+        by_age.sort();
+        for (_, hash) in by_age {
+            if size <= max_size {
+                break;
+            }
+            debug!("evicting {hash}");
+            match std::fs::remove_file(self.dir.join(&hash)) {
+                Ok(()) => (),
+                Err(e) if e.kind() == io::ErrorKind::NotFound => (),
+                Err(e) => return Err(e),
+            }
+            size -= entries.remove(&hash).map_or(0, |e| e.size);
+        }
+        Ok(())
+    }
+}
+
+/// A file being inserted into the cache, which is removed if it isn't finished.
+struct TempWriter {
+    path: Option<PathBuf>,
+    file: std::fs::File,
+    hasher: ContentHasher,
+    size: u64,
+}
+
+impl Write for TempWriter {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let n = self.file.write(buf)?;
+        self.hasher.update(&buf[..n]);
+        self.size += n as u64;
+        Ok(n)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.file.flush()
+    }
+}
+
+impl Drop for TempWriter {
+    fn drop(&mut self) {
+        if let Some(path) = &self.path {
+            let _ = std::fs::remove_file(path);
+        }
+    }
+}
+
+fn is_content_hash(name: &str) -> bool {
+    name.len() == 64 && name.bytes().all(|c| matches!(c, b'0'..=b'9' | b'a'..=b'f'))
+}
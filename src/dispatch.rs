@@ -0,0 +1,258 @@
+//! Calling API routes by name, with JSON arguments and results.
+//!
+//! This is meant for programs like API gateways and proxies, which need to forward arbitrary
+//! Dropbox API calls without handling each route specifically. Other programs should use the
+//! typed route functions instead.
+//!
+//! The client used with [`dispatch`] needs to use a kind of authentication that the route
+//! supports; [`RouteInfo::auth`] lists them. Clients say which kind they use by implementing
+//! [`DispatchClient`], and routes which don't support it fail without making a request.
+//!
+//! Routes which the SDK doesn't know about, such as new beta routes, can be called with
+//! [`call_raw`] instead.
+
+use bytes::Bytes;
+use serde::{Deserialize, Serialize};
+use crate::async_client_trait::{HttpClient, HttpRequestResult, HttpRequestResultRaw};
+use crate::client_helpers::{request_raw, request_with_body, Body};
+use crate::client_trait_common::{Endpoint, ParamsType, Style};
+use crate::generated::route_table::ROUTES;
+use crate::Error;
+
+/// Information about an API route.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct RouteInfo {
+    /// The route's name as it appears in its URL, including the namespace and version, such as
+    /// `"files/list_folder"` or `"files/delete_v2"`.
+    pub name: &'static str,
+
+    /// The API endpoint the route is on.
+    pub endpoint: Endpoint,
+
+    /// How the route takes its arguments and returns its result.
+    pub style: Style,
+
+    /// The kinds of authentication the route supports: one or more of `"user"`, `"team"`, `"app"`,
+    /// and `"noauth"`.
+    pub auth: &'static [&'static str],
+
+    /// Whether the route is a preview, which may change or disappear without notice. These are
+    /// only included with the `unstable` feature.
+    pub preview: bool,
+
+    /// Whether the route is deprecated, and going to be removed.
+    pub deprecated: bool,
+
+    /// The name of the route which replaces this one, if it is deprecated and has a replacement.
+    pub replaced_by: Option<&'static str>,
+}
+
+/// All the routes in the namespaces enabled by Cargo features, sorted by name.
+pub fn routes() -> &'static [RouteInfo] {
+    ROUTES
+}
+
+/// Look up a route by name.
+pub fn route(name: &str) -> Option<&'static RouteInfo> {
+    ROUTES.binary_search_by(|route| route.name.cmp(name))
+        .ok()
+        .map(|idx| &ROUTES[idx])
+}
+
+/// An error returned by a route called by name: the JSON value of the `error` field of the
+/// server's response.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(transparent)]
+pub struct RawApiError(pub serde_json::Value);
+
+impl std::fmt::Display for RawApiError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl std::error::Error for RawApiError {}
+
+/// A client which can call routes by name with [`dispatch`], because it says which kind of
+/// authentication it uses.
+///
+/// This is implemented for the default clients, and the wrappers around clients in this crate.
+pub trait DispatchClient: HttpClient {
+    /// The kind of authentication the client uses, as in [`RouteInfo::auth`]: one of `"user"`,
+    /// `"team"`, `"app"`, and `"noauth"`.
+    ///
+    /// A team client with a member or admin [selected](HttpClient::team_select) can also call
+    /// user routes.
+    fn auth(&self) -> &'static str;
+}
+
+macro_rules! impl_dispatch_client {
+    ($($client:ty => $auth:literal),* $(,)?) => {
+        $(
+            impl DispatchClient for $client {
+                fn auth(&self) -> &'static str {
+                    $auth
+                }
+            }
+        )*
+    };
+}
+
+#[cfg(feature = "default_client")]
+impl_dispatch_client! {
+    crate::default_client::UserAuthDefaultClient => "user",
+    crate::default_client::TeamAuthDefaultClient => "team",
+    crate::default_client::AppAuthDefaultClient => "app",
+    crate::default_client::NoauthDefaultClient => "noauth",
+}
+
+#[cfg(feature = "default_async_client")]
+impl_dispatch_client! {
+    crate::default_async_client::UserAuthDefaultClient => "user",
+    crate::default_async_client::TeamAuthDefaultClient => "team",
+    crate::default_async_client::AppAuthDefaultClient => "app",
+    crate::default_async_client::NoauthDefaultClient => "noauth",
+}
+
+#[cfg(feature = "fake")]
+impl_dispatch_client! {
+    crate::fake::FakeDropbox => "user",
+}
+
+#[cfg(all(feature = "body_logging", feature = "sync_routes"))]
+impl<C: DispatchClient + crate::client_trait::HttpClient> DispatchClient
+    for crate::body_logging::LoggingClient<C>
+{
+    fn auth(&self) -> &'static str {
+        self.inner().auth()
+    }
+}
+
+#[cfg(feature = "body_logging")]
+impl<C: DispatchClient> DispatchClient for crate::body_logging::AsyncLoggingClient<C> {
+    fn auth(&self) -> &'static str {
+        self.inner().auth()
+    }
+}
+
+/// An error from [`dispatch`].
+#[derive(thiserror::Error, Debug)]
+pub enum DispatchError {
+    /// There's no route with the given name, or its namespace isn't enabled.
+    #[error("unknown route {0:?}")]
+    UnknownRoute(String),
+
+    /// The route doesn't support the kind of authentication the client uses, so it wasn't called.
+    #[error("route {route} doesn't support {auth} authentication")]
+    WrongAuth {
+        /// The route's name.
+        route: &'static str,
+
+        /// The kind of authentication the client uses.
+        auth: &'static str,
+    },
+
+    /// The route was called, and returned an error.
+    #[error(transparent)]
+    Call(#[from] Error<RawApiError>),
+}
+
+/// Call a route by name, with the given JSON arguments, and return its JSON result.
+///
+/// Routes which take no arguments should be given `serde_json::Value::Null`. For upload-style
+/// routes, `body` is the content to upload; it's ignored for other routes. For download-style
+/// routes, the result includes the response body.
+///
+/// If the route doesn't support the client's kind of authentication, this fails with
+/// [`DispatchError::WrongAuth`] without making a request.
+pub async fn dispatch(
+    client: &impl DispatchClient,
+    route_name: &str,
+    params: &serde_json::Value,
+    body: Option<Bytes>,
+) -> Result<HttpRequestResult<serde_json::Value>, DispatchError> {
+    let route = route(route_name)
+        .ok_or_else(|| DispatchError::UnknownRoute(route_name.to_owned()))?;
+    let auth = client.auth();
+    let as_user = auth == "team" && client.team_select().is_some();
+    let supported = route.auth.contains(&auth) || (as_user && route.auth.contains(&"user"));
+    if !supported {
+        return Err(DispatchError::WrongAuth { route: route.name, auth });
+    }
+    let body = match route.style {
+        Style::Upload => Some(Body::from(body.unwrap_or_default())),
+        Style::Rpc | Style::Download => None,
+    };
+    debug!("dispatching {} to {:?}", route.name, route.endpoint);
+    Ok(request_with_body(client, route.endpoint, route.style, route.name, params, body, None, None)
+        .await?)
+}
+
+/// Call any function on the given endpoint, with the given JSON arguments, and return the raw
+/// response.
+///
+/// This is an escape hatch for calling routes which aren't in the SDK yet, such as beta routes,
+/// while still using the client's authentication, including refreshing its token when it expires.
+/// As with [`dispatch`], `body` is the content to send for upload-style calls.
+///
+/// Only successful responses are returned; error responses are turned into an [`Error`], with the
+/// `error` field of an HTTP 409 response as an [`Error::Api`]. For RPC and upload-style calls,
+/// the JSON result is left unread in the response body, and for download-style ones it is in the
+/// [`result_header`](HttpRequestResultRaw::result_header).
+pub async fn call_raw(
+    client: &impl HttpClient,
+    endpoint: Endpoint,
+    style: Style,
+    function: &str,
+    params: &serde_json::Value,
+    body: Option<Bytes>,
+) -> Result<HttpRequestResultRaw, Error<RawApiError>> {
+    let params = Bytes::from(serde_json::to_vec(params)?);
+    debug!("calling {function} on {endpoint:?}");
+    let body = body.unwrap_or_default();
+    request_raw(client, endpoint, style, function, params, ParamsType::Json, body).await
+}
+
+/// Call an RPC-style function on the given endpoint with its arguments sent as a form-encoded
+/// (`application/x-www-form-urlencoded`) body instead of as JSON, and return the raw response.
+///
+/// This is like [`call_raw`], for functions which take form bodies, as `oauth2/token` does. The
+/// arguments can be of any type which serializes to a JSON object whose values are strings,
+/// numbers, or booleans; fields which are null are left out. Anything else, such as a nested
+/// object, is an [`Error::Json`].
+pub async fn call_form<P: Serialize>(
+    client: &impl HttpClient,
+    endpoint: Endpoint,
+    function: &str,
+    params: &P,
+) -> Result<HttpRequestResultRaw, Error<RawApiError>> {
+    let params = form_encode(params)?;
+    debug!("calling {function} on {endpoint:?} with form arguments");
+    request_raw(client, endpoint, Style::Rpc, function, params, ParamsType::Form, Bytes::new())
+        .await
+}
+
+fn form_encode<P: Serialize>(params: &P) -> Result<Bytes, serde_json::Error> {
+    use serde::ser::Error as _;
+    let serde_json::Value::Object(fields) = serde_json::to_value(params)? else {
+        return Err(serde_json::Error::custom("form arguments must be an object"));
+    };
+    let mut form = url::form_urlencoded::Serializer::new(String::new());
+    for (name, value) in fields {
+        match value {
+            serde_json::Value::Null => (),
+            serde_json::Value::String(s) => {
+                form.append_pair(&name, &s);
+            }
+            serde_json::Value::Number(_) | serde_json::Value::Bool(_) => {
+                form.append_pair(&name, &value.to_string());
+            }
+            serde_json::Value::Array(_) | serde_json::Value::Object(_) => {
+                return Err(serde_json::Error::custom(
+                    format!("form argument {name:?} must be a string, number, or boolean")));
+            }
+        }
+    }
+    Ok(Bytes::from(form.finish()))
+}
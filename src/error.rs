@@ -1,8 +1,19 @@
+use std::time::Duration;
 use crate::types;
 
+/// How long [`Error::retry_after`] suggests waiting before retrying a request which failed because
+/// of too many write operations, when the server doesn't say.
+pub const WRITE_CONTENTION_RETRY_AFTER: Duration = Duration::from_secs(1);
+
 /// An error occurred in the process of making an API call.
 /// This is different from the case where your call succeeded, but the operation returned an error.
+///
+/// With the `miette` feature enabled, this implements `miette::Diagnostic`, so it can be converted
+/// with `?` into a `miette::Report` which describes what kind of error it was and what to do
+/// about it, such as how long to wait before retrying. To report which request it came from too,
+/// see [`diagnostic`](crate::diagnostic).
 #[derive(thiserror::Error, Debug)]
+#[non_exhaustive]
 pub enum Error<E = NoError> {
     /// An error returned by the API. Its type depends on the endpoint being called.
     #[error("Dropbox API endpoint returned an error: {0}")]
@@ -13,9 +24,26 @@ pub enum Error<E = NoError> {
     HttpClient(#[source] Box<dyn std::error::Error + Send + Sync + 'static>),
 
     /// Something went wrong in the process of transforming your arguments into a JSON string.
+    ///
+    /// Failures to parse responses are [`Error::Deserialize`] instead.
     #[error("JSON serialization error: {0}")]
     Json(#[from] serde_json::Error),
 
+    /// The response from the Dropbox API couldn't be deserialized into the expected type. This
+    /// usually means the SDK is out of date or has a bug; the details here are helpful for
+    /// reporting it.
+    #[error("failed to deserialize the response from {route}: {source}")]
+    Deserialize {
+        /// The route that was called, such as `"files/list_folder"`.
+        route: String,
+
+        /// The deserialization error.
+        source: serde_json::Error,
+
+        /// The start of the response which failed to deserialize, truncated if it was long.
+        body_snippet: String,
+    },
+
     /// The Dropbox API response was unexpected or malformed in some way.
     #[error("Dropbox API returned something unexpected: {0}")]
     UnexpectedResponse(String),
@@ -47,6 +75,21 @@ pub enum Error<E = NoError> {
     #[error("Dropbox API had an internal server error: {0}")]
     ServerError(String),
 
+    /// The Dropbox API is temporarily unavailable, such as during planned maintenance, and said
+    /// when to try again. Long-running jobs can pause until then.
+    ///
+    /// Service unavailable responses which don't say when to try again are reported as
+    /// [`Error::ServerError`].
+    #[error("Dropbox API is temporarily unavailable, retry after {retry_after_seconds}s: \
+        {response}")]
+    ServiceUnavailable {
+        /// You can retry this request after this many seconds.
+        retry_after_seconds: u32,
+
+        /// The response body.
+        response: String,
+    },
+
     /// The Dropbox API returned an unexpected HTTP response code.
     #[error("Dropbox API returned HTTP {code} - {response}")]
     UnexpectedHttpError {
@@ -67,7 +110,313 @@ pub enum Error<E = NoError> {
 /// or [`Error::downcast_ref_inner`] if desired.
 ///
 /// See [`Error::boxed`] for how to convert a concretely-typed version of [`Error`] into this.
-pub type BoxedError = Error<Box<dyn std::error::Error + Send + Sync>>;
+///
+/// Like all the SDK's errors, this is `Send + Sync + 'static`, so it can be returned from spawned
+/// tasks and threads, and converted into `Box<dyn std::error::Error + Send + Sync>` (or anything
+/// else which accepts such errors) with `?`.
+///
+/// [`Error::retry_after`], [`Error::policy_error`], and [`Error::is_file_locked`] look into the
+/// API error's JSON form, which needs its concrete type, so they can't be called on a
+/// `BoxedError`. Call them before boxing the error, or on the error downcast back to its concrete
+/// type.
+pub type BoxedError = Error<BoxedApiError>;
+
+/// The API error contained in a [`BoxedError`].
+///
+/// A `Box<dyn std::error::Error + Send + Sync>` doesn't itself implement
+/// [`std::error::Error`], which would prevent [`BoxedError`] from implementing it too, so it is
+/// wrapped in this type instead. It is transparent: it displays as the boxed error does, and
+/// dereferences to it, so the concrete error can be recovered with `downcast_ref`.
+#[derive(thiserror::Error, Debug)]
+#[error(transparent)]
+pub struct BoxedApiError(pub Box<dyn std::error::Error + Send + Sync>);
+
+impl std::ops::Deref for BoxedApiError {
+    type Target = dyn std::error::Error + Send + Sync;
+
+    fn deref(&self) -> &Self::Target {
+        &*self.0
+    }
+}
+
+impl<E> Error<E> {
+    /// If the request failed because the access token doesn't have a scope required by the route,
+    /// return the name of that scope.
+    ///
+    /// To fix this, enable the scope for your app in the App Console, and then have the user
+    /// authorize the app again, requesting the scope (see
+    /// [`AuthorizeUrlBuilder::scope`](crate::oauth2::AuthorizeUrlBuilder::scope)). Existing tokens
+    /// are not updated with newly-enabled scopes.
+    pub fn missing_scope(&self) -> Option<&str> {
+        match self {
+            Error::Authentication(types::auth::AuthError::MissingScope(e)) => {
+                Some(&e.required_scope)
+            }
+            _ => None,
+        }
+    }
+}
+
+impl<E: serde::Serialize> Error<E> {
+    /// If the request failed because of rate-limiting or write contention, or the service being
+    /// temporarily unavailable, return how long to wait before retrying it.
+    ///
+    /// Besides [`Error::RateLimited`] and [`Error::ServiceUnavailable`], this recognizes API errors which contain
+    /// `too_many_write_operations`. Dropbox returns these with HTTP 409 rather than 429 when there
+    /// are too many concurrent writes to the same namespace, so they come back as [`Error::Api`],
+    /// usually nested within the route's error type. Neither kind of contention error says how
+    /// long to wait, so [`WRITE_CONTENTION_RETRY_AFTER`] is returned for them.
+    pub fn retry_after(&self) -> Option<Duration> {
+        match self {
+            Error::Api(e) if is_too_many_write_operations(e) => Some(WRITE_CONTENTION_RETRY_AFTER),
+            _ => self.server_retry_after(),
+        }
+    }
+
+    /// If the request failed because of rate-limiting or write contention, return which kind, to
+    /// decide which other requests should back off along with it.
+    ///
+    /// Like [`Error::retry_after`], this recognizes `too_many_write_operations` nested within API
+    /// errors, as well as [`Error::RateLimited`]. Rate limits with a reason this SDK doesn't know
+    /// are treated as [`RateLimitCategory::TooManyRequests`], which backs off the most.
+    pub fn rate_limit_category(&self) -> Option<RateLimitCategory> {
+        match self {
+            Error::RateLimited { reason, .. } => Some(match reason {
+                types::auth::RateLimitReason::TooManyWriteOperations => {
+                    RateLimitCategory::TooManyWriteOperations
+                }
+                _ => RateLimitCategory::TooManyRequests,
+            }),
+            Error::Api(e) if is_too_many_write_operations(e) => {
+                Some(RateLimitCategory::TooManyWriteOperations)
+            }
+            _ => None,
+        }
+    }
+
+    /// Like [`Error::retry_after`], but with a random amount of time up to `max_jitter` added, so
+    /// that many clients rate-limited at once don't all retry at the same moment. Pass
+    /// [`Duration::ZERO`] for no jitter.
+    pub fn retry_delay(&self, max_jitter: Duration) -> Option<Duration> {
+        self.retry_after().map(|wait| wait + jitter(max_jitter))
+    }
+}
+
+/// The kind of rate limit a request ran into, from [`Error::rate_limit_category`].
+///
+/// The kinds differ in which other requests should back off:
+/// [`backoff_scope`](Self::backoff_scope) says which.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum RateLimitCategory {
+    /// The app or user made too many requests. All requests made on their behalf should back off.
+    TooManyRequests,
+
+    /// Too many writes were happening at once in the user's Dropbox. Other writes of the same kind
+    /// should back off, but unrelated requests can carry on.
+    TooManyWriteOperations,
+}
+
+impl RateLimitCategory {
+    /// Which requests should back off after a request to the given route, such as
+    /// `"files/upload"`, ran into this kind of rate limit.
+    ///
+    /// Requests being rate limited back off globally, while write contention only backs off
+    /// requests to routes in the same namespace as the failed one, such as `files`.
+    pub fn backoff_scope(self, route: &str) -> BackoffScope {
+        match self {
+            RateLimitCategory::TooManyRequests => BackoffScope::Global,
+            RateLimitCategory::TooManyWriteOperations => {
+                let namespace = route.split('/').next().unwrap_or(route);
+                BackoffScope::Namespace(namespace.to_owned())
+            }
+        }
+    }
+}
+
+/// Which requests should back off after one was rate limited, from
+/// [`RateLimitCategory::backoff_scope`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum BackoffScope {
+    /// Every request.
+    Global,
+
+    /// Only requests to routes in the given namespace, such as `"files"`.
+    Namespace(String),
+}
+
+impl BackoffScope {
+    /// Whether a request to the given route, such as `"files/upload"`, should back off.
+    pub fn applies_to(&self, route: &str) -> bool {
+        match self {
+            BackoffScope::Global => true,
+            BackoffScope::Namespace(namespace) => {
+                route.split('/').next().map_or(false, |n| n == namespace)
+            }
+        }
+    }
+}
+
+/// A random duration between zero and `max`.
+pub(crate) fn jitter(max: Duration) -> Duration {
+    use ring::rand::{SecureRandom, SystemRandom};
+    if max.is_zero() {
+        return Duration::ZERO;
+    }
+    let mut bytes = [0; 8];
+    if SystemRandom::new().fill(&mut bytes).is_err() {
+        // Jitter is only an optimization, so do without it rather than failing.
+        return Duration::ZERO;
+    }
+    max.mul_f64(u64::from_le_bytes(bytes) as f64 / (u64::MAX as f64 + 1.0))
+}
+
+impl<E> Error<E> {
+    /// Like [`Error::retry_after`], but only for [`Error::RateLimited`] and
+    /// [`Error::ServiceUnavailable`], which don't need to look into the API error.
+    pub(crate) fn server_retry_after(&self) -> Option<Duration> {
+        match self {
+            Error::RateLimited {
+                reason: types::auth::RateLimitReason::TooManyWriteOperations,
+                retry_after_seconds: 0,
+            } => Some(WRITE_CONTENTION_RETRY_AFTER),
+            Error::RateLimited { retry_after_seconds, .. }
+                | Error::ServiceUnavailable { retry_after_seconds, .. } => {
+                Some(Duration::from_secs(u64::from(*retry_after_seconds)))
+            }
+            _ => None,
+        }
+    }
+}
+
+impl<E: serde::Serialize> Error<E> {
+    /// Whether the request failed because a file involved is locked, or the request conflicts
+    /// with a lock on it.
+    ///
+    /// Like [`Error::retry_after`], this looks for the `locked` and `lock_conflict` tags at any
+    /// depth within API errors, as different routes report locked files in different places.
+    pub fn is_file_locked(&self) -> bool {
+        match self {
+            Error::Api(e) => {
+                find_tag(e, |tag| matches!(tag, "locked" | "lock_conflict").then(|| ())).is_some()
+            }
+            _ => false,
+        }
+    }
+}
+
+/// Look for a `too_many_write_operations` tag anywhere within an API error.
+///
+/// This goes through the error's JSON form because the tag can appear at different depths in many
+/// different error types, such as `UploadError::Path(UploadWriteFailed { reason:
+/// WriteError::TooManyWriteOperations, .. })`.
+fn is_too_many_write_operations(e: &impl serde::Serialize) -> bool {
+    find_tag(e, |tag| (tag == "too_many_write_operations").then(|| ())).is_some()
+}
+
+/// Find the first `.tag` anywhere within an API error's JSON form which matches the predicate.
+fn find_tag<T>(e: &impl serde::Serialize, f: impl Fn(&str) -> Option<T>) -> Option<T> {
+    fn search<T>(value: &serde_json::Value, f: &dyn Fn(&str) -> Option<T>) -> Option<T> {
+        match value {
+            serde_json::Value::Object(map) => map.iter().find_map(|(k, v)| match v {
+                serde_json::Value::String(tag) if k == ".tag" => f(tag),
+                _ => search(v, f),
+            }),
+            serde_json::Value::Array(values) => values.iter().find_map(|v| search(v, f)),
+            _ => None,
+        }
+    }
+    // Unknown variants are serialized as they were received, so tags within them are found too.
+    // Errors which fail to serialize can't contain any of the tags being looked for.
+    serde_json::to_value(e).ok().and_then(|value| search(&value, &f))
+}
+
+/// A request was refused because of the account's type, or a policy set by its team or the owner
+/// of the content, rather than because of a problem with the request itself.
+///
+/// Each route reports these in its own way, some as [`Error::AccessDenied`] and others nested
+/// somewhere within the route's own error type. [`Error::policy_error`] finds them wherever they
+/// are, so that they can be handled, or explained to the user, in one place.
+#[derive(thiserror::Error, Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum PolicyError {
+    /// The account's type or plan doesn't allow this, such as a Basic account trying to use a
+    /// feature of paid plans. Upgrading the account may help.
+    #[error("the account's type or plan doesn't allow this")]
+    AccountType,
+
+    /// Paper is disabled for the account's team.
+    #[error("Paper is disabled for this team")]
+    PaperDisabled,
+
+    /// The user hasn't used Paper yet, and needs to do so before Paper can be accessed through the
+    /// API.
+    #[error("the user has not used Paper yet")]
+    NotPaperUser,
+
+    /// The account's team has a sharing policy which doesn't allow this, such as one preventing
+    /// sharing or shared links outside the team. A team admin can change the policy.
+    #[error("a team sharing policy doesn't allow this")]
+    TeamPolicy,
+
+    /// This can't be done to a team folder. Team folders are managed by team admins, with the
+    /// `team/team_folder/*` routes.
+    #[error("this can't be done to a team folder")]
+    TeamFolder,
+
+    /// The owner of the content, or of a folder containing it, doesn't allow this.
+    #[error("the owner of the content doesn't allow this")]
+    OwnerPolicy,
+
+    /// The content is restricted, for example because of legal requirements.
+    #[error("the content is restricted")]
+    RestrictedContent,
+
+    /// The account's email address needs to be verified first.
+    #[error("the account's email address is not verified")]
+    EmailUnverified,
+}
+
+impl PolicyError {
+    fn from_tag(tag: &str) -> Option<Self> {
+        Some(match tag {
+            "invalid_account_type" | "user_account_type" | "insufficient_plan" => Self::AccountType,
+            "paper_disabled" => Self::PaperDisabled,
+            "not_paper_user" => Self::NotPaperUser,
+            "cant_share_outside_team" | "restricted_by_team" | "team_policy_disallows_member_policy"
+                | "disallowed_shared_link_policy" => Self::TeamPolicy,
+            "team_folder" | "folder_is_limited_team_folder" => Self::TeamFolder,
+            "user_not_allowed_by_owner" | "restricted_by_parent_folder"
+                | "restricted_by_shared_folder" => Self::OwnerPolicy,
+            "restricted_content" => Self::RestrictedContent,
+            "email_unverified" | "email_not_verified" => Self::EmailUnverified,
+            _ => return None,
+        })
+    }
+}
+
+impl<E: serde::Serialize> Error<E> {
+    /// If the request was refused because of the account's type, or a team or owner policy,
+    /// return which kind of policy it was.
+    ///
+    /// This covers [`Error::AccessDenied`], as well as API errors which contain one of the
+    /// policy-related tags listed in [`PolicyError`]'s variants, at any depth.
+    pub fn policy_error(&self) -> Option<PolicyError> {
+        match self {
+            Error::AccessDenied(types::auth::AccessError::InvalidAccountType(_)) => {
+                Some(PolicyError::AccountType)
+            }
+            Error::AccessDenied(types::auth::AccessError::PaperAccessDenied(e)) => match e {
+                types::auth::PaperAccessError::PaperDisabled => Some(PolicyError::PaperDisabled),
+                types::auth::PaperAccessError::NotPaperUser => Some(PolicyError::NotPaperUser),
+                _ => None,
+            },
+            Error::Api(e) => find_tag(e, PolicyError::from_tag),
+            _ => None,
+        }
+    }
+}
 
 impl<E: std::error::Error + 'static> Error<E> {
     /// Look for an inner error of the given type anywhere within this error, by walking the chain
@@ -78,6 +427,11 @@ impl<E: std::error::Error + 'static> Error<E> {
             if let Some(e) = e.downcast_ref() {
                 return Some(e);
             }
+            // A boxed API error is transparent, so its source is that of the boxed error, skipping
+            // over the boxed error itself.
+            if let Some(e) = e.downcast_ref::<BoxedApiError>().and_then(|e| e.downcast_ref()) {
+                return Some(e);
+            }
             inner = e.source();
         }
         None
@@ -93,19 +447,21 @@ impl<E: std::error::Error + Send + Sync + 'static> Error<E> {
     /// if desired.
     pub fn boxed(self) -> BoxedError {
         match self {
-            Error::Api(e) => Error::Api(Box::new(e)),
+            Error::Api(e) => Error::Api(BoxedApiError(Box::new(e))),
 
             // Other variants unchanged.
             // These have to be actually re-stated, because the (unstated) generic type of `Error`
             // is different on the left vs the right.
             Error::HttpClient(e) => Error::HttpClient(e),
             Error::Json(e) => Error::Json(e),
+            Error::Deserialize { route, source, body_snippet } => Error::Deserialize { route, source, body_snippet },
             Error::UnexpectedResponse(e) => Error::UnexpectedResponse(e),
             Error::BadRequest(e) => Error::BadRequest(e),
             Error::Authentication(e) => Error::Authentication(e),
             Error::RateLimited { reason, retry_after_seconds } => Error::RateLimited { reason, retry_after_seconds },
             Error::AccessDenied(e) => Error::AccessDenied(e),
             Error::ServerError(e) => Error::ServerError(e),
+            Error::ServiceUnavailable { retry_after_seconds, response } => Error::ServiceUnavailable { retry_after_seconds, response },
             Error::UnexpectedHttpError { code, response } => Error::UnexpectedHttpError { code, response },
         }
     }
@@ -122,12 +478,14 @@ impl Error<NoError> {
             Error::Api(x) => unreachable(x),
             Error::HttpClient(e) => Error::HttpClient(e),
             Error::Json(e) => Error::Json(e),
+            Error::Deserialize { route, source, body_snippet } => Error::Deserialize { route, source, body_snippet },
             Error::UnexpectedResponse(e) => Error::UnexpectedResponse(e),
             Error::BadRequest(e) => Error::BadRequest(e),
             Error::Authentication(e) => Error::Authentication(e),
             Error::RateLimited { reason, retry_after_seconds } => Error::RateLimited { reason, retry_after_seconds },
             Error::AccessDenied(e) => Error::AccessDenied(e),
             Error::ServerError(e) => Error::ServerError(e),
+            Error::ServiceUnavailable { retry_after_seconds, response } => Error::ServiceUnavailable { retry_after_seconds, response },
             Error::UnexpectedHttpError { code, response } => Error::UnexpectedHttpError { code, response },
         }
     }
@@ -171,6 +529,12 @@ impl std::fmt::Display for NoError {
     }
 }
 
+impl serde::Serialize for NoError {
+    fn serialize<S: serde::Serializer>(&self, _: S) -> Result<S::Ok, S::Error> {
+        unreachable(*self)
+    }
+}
+
 // This is the reason we can't just use the otherwise-identical `void` crate's Void type: we need
 // to implement this trait.
 impl<'de> serde::de::Deserialize<'de> for NoError {
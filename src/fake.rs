@@ -0,0 +1,616 @@
+//! An in-memory fake of Dropbox, for testing code which uses the SDK without a network connection
+//! or a Dropbox account.
+//!
+//! [`FakeDropbox`] is a HTTP client which, instead of sending requests, answers them itself from a
+//! tree of files and folders kept in memory. It implements the client traits, so it can be given
+//! to the routes and helpers in place of a real client, and the code under test goes through the
+//! same request and response handling as it would with Dropbox.
+//!
+//! It simulates the `files` routes needed for uploading, downloading, listing, deleting, and moving
+//! files, including with the helpers in [`files_helpers`](crate::files_helpers):
+//!
+//! * `files/upload`, and `files/upload_session/start`, `append_v2`, and `finish`
+//! * `files/download`
+//! * `files/get_metadata`
+//! * `files/list_folder` and `files/list_folder/continue`
+//! * `files/create_folder_v2`
+//! * `files/delete_v2`
+//! * `files/move_v2`
+//!
+//! Other routes fail with [`Error::BadRequest`](crate::Error::BadRequest). The common API errors
+//! are returned as Dropbox would, such as [`LookupError::NotFound`](files::LookupError::NotFound)
+//! for a path which doesn't exist. IDs and revisions are numbered in order, and all files have the
+//! same server modified time, so tests get the same results each time they run.
+//!
+//! Some things are simpler than in Dropbox: paths are case-insensitive, but aren't Unicode
+//! normalized; `autorename` is ignored; and listing cursors only keep the position in the listing,
+//! so continuing a listing after it's done doesn't report any changes made since.
+//!
+//! This is only built with the `fake` Cargo feature.
+
+use std::collections::{BTreeMap, HashMap};
+use std::io::Cursor;
+use std::sync::Mutex;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use crate::client_trait::{HttpClient, HttpRequestResultRaw, UserAuthClient};
+use crate::client_trait_common::{Endpoint, HttpRequest};
+use crate::content_hash::content_hash;
+use crate::types::files::{self, routes};
+use crate::Error;
+
+/// The server modified time of all files, and the client modified time of files which were
+/// uploaded without one.
+pub const FAKE_TIMESTAMP: &str = "2020-01-01T00:00:00Z";
+
+/// The most entries in a page of a listing, if the request doesn't give a limit.
+const DEFAULT_LIST_LIMIT: usize = 2000;
+
+/// A fake Dropbox account, kept in memory. See the [module documentation](self).
+#[derive(Debug, Default)]
+pub struct FakeDropbox {
+    state: Mutex<State>,
+}
+
+impl FakeDropbox {
+    /// Create an empty fake Dropbox.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a file, replacing any file already at the path, and creating the folders it's in as
+    /// needed.
+    ///
+    /// Panics if the path isn't valid, or is inside a file.
+    pub fn with_file(self, path: &str, contents: impl Into<Vec<u8>>) -> Self {
+        self.state.lock().unwrap()
+            .write_file(path, contents.into(), &files::WriteMode::Overwrite, None)
+            .unwrap_or_else(|e| panic!("can't add file {:?}: {}", path, e));
+        self
+    }
+
+    /// Add a folder, creating the folders it's in as needed.
+    ///
+    /// Panics if the path isn't valid, or is inside a file, or there's a file there.
+    pub fn with_folder(self, path: &str) -> Self {
+        self.state.lock().unwrap()
+            .create_folder(path)
+            .unwrap_or_else(|e| panic!("can't add folder {:?}: {}", path, e));
+        self
+    }
+
+    /// The contents of the file at the given path, if there is one.
+    pub fn contents(&self, path: &str) -> Option<Vec<u8>> {
+        let state = self.state.lock().unwrap();
+        let key = state.lookup(path).ok()?;
+        state.entries.get(&key)?.file.as_ref().map(|file| file.data.clone())
+    }
+
+    /// The metadata of the file or folder at the given path, if there is one.
+    pub fn metadata(&self, path: &str) -> Option<files::Metadata> {
+        let state = self.state.lock().unwrap();
+        state.lookup(path).ok().map(|key| state.metadata(&key))
+    }
+
+    /// The paths of all the files and folders, as they were given when each was created, in
+    /// order.
+    pub fn paths(&self) -> Vec<String> {
+        self.state.lock().unwrap()
+            .entries
+            .values()
+            .map(|entry| entry.path_display.clone())
+            .collect()
+    }
+
+    /// The routes called so far, in order, such as `"files/upload"`.
+    pub fn calls(&self) -> Vec<String> {
+        self.state.lock().unwrap().calls.clone()
+    }
+}
+
+/// A request to a [`FakeDropbox`].
+#[derive(Debug)]
+pub struct FakeRequest {
+    url: String,
+    arg: Option<String>,
+}
+
+impl HttpRequest for FakeRequest {
+    fn set_header(mut self, name: &str, value: &str) -> Self {
+        if name.eq_ignore_ascii_case("Dropbox-API-Arg") {
+            self.arg = Some(value.to_owned());
+        }
+        self
+    }
+}
+
+impl HttpClient for FakeDropbox {
+    type Request = FakeRequest;
+
+    fn execute(&self, request: Self::Request, body: &[u8]) -> Result<HttpRequestResultRaw, Error> {
+        let reply = match Endpoint::route_from_url(&request.url) {
+            Some((_, route)) => {
+                let arg = request.arg.as_ref().map_or(body, |arg| arg.as_bytes());
+                let mut state = self.state.lock().unwrap();
+                state.calls.push(route.to_owned());
+                state.handle(route, arg, body)
+            }
+            None => Reply::BadRequest(format!("unknown URL {:?}", request.url)),
+        };
+        Ok(reply.into_response())
+    }
+
+    fn new_request(&self, url: &str) -> Self::Request {
+        FakeRequest { url: url.to_owned(), arg: None }
+    }
+}
+
+impl UserAuthClient for FakeDropbox {}
+
+/// The contents of the fake account.
+#[derive(Debug, Default)]
+struct State {
+    /// Every file and folder, by lowercased path. The root folder isn't included.
+    entries: BTreeMap<String, Entry>,
+
+    /// The data uploaded so far to each upload session in progress, by session ID.
+    sessions: HashMap<String, Vec<u8>>,
+
+    /// The number used for the last ID, revision, or upload session ID.
+    counter: u64,
+
+    calls: Vec<String>,
+}
+
+#[derive(Debug, Clone)]
+struct Entry {
+    path_display: String,
+    id: String,
+    /// `None` for a folder.
+    file: Option<FileContents>,
+}
+
+#[derive(Debug, Clone)]
+struct FileContents {
+    data: Vec<u8>,
+    rev: String,
+    client_modified: String,
+}
+
+/// The response to a request.
+enum Reply {
+    Ok { result: serde_json::Value, body: Option<Vec<u8>> },
+    ApiError(serde_json::Value),
+    BadRequest(String),
+}
+
+impl Reply {
+    fn into_response(self) -> HttpRequestResultRaw {
+        let (status, result_header, body) = match self {
+            Reply::Ok { result, body: None } => (200, None, result.to_string().into_bytes()),
+            Reply::Ok { result, body: Some(body) } => (200, Some(result.to_string()), body),
+            Reply::ApiError(error) => {
+                let body = serde_json::json!({
+                    "error_summary": error_summary(&error),
+                    "error": error,
+                });
+                (409, None, body.to_string().into_bytes())
+            }
+            Reply::BadRequest(message) => (400, None, message.into_bytes()),
+        };
+        HttpRequestResultRaw {
+            status,
+            result_header,
+            content_length: Some(body.len() as u64),
+            retry_after: None,
+            content_range: None,
+            content_disposition: None,
+            request_id: None,
+            body: Box::new(Cursor::new(body)),
+        }
+    }
+}
+
+/// The error summary Dropbox would give for an error: its tags, from the outside in, each
+/// followed by a slash, such as `"path/not_found/"`.
+fn error_summary(error: &serde_json::Value) -> String {
+    let mut summary = String::new();
+    let mut value = error;
+    while let Some(tag) = value.get(".tag").and_then(|tag| tag.as_str()) {
+        summary.push_str(tag);
+        summary.push('/');
+        match value.get(tag) {
+            Some(inner) => value = inner,
+            None => break,
+        }
+    }
+    summary
+}
+
+/// Deserialize a route's argument, or reply that it's invalid.
+fn parse<A: DeserializeOwned>(arg: &[u8]) -> Result<A, Reply> {
+    serde_json::from_slice(arg)
+        .map_err(|e| Reply::BadRequest(format!("could not decode input as JSON: {e}")))
+}
+
+/// Reply with a route's result or error.
+fn reply<R: Serialize, E: Serialize>(result: Result<R, E>) -> Reply {
+    match result {
+        Ok(result) => Reply::Ok { result: to_value(&result), body: None },
+        Err(e) => Reply::ApiError(to_value(&e)),
+    }
+}
+
+fn to_value(value: &impl Serialize) -> serde_json::Value {
+    serde_json::to_value(value).expect("failed to serialize a reply")
+}
+
+/// Split a listing cursor into the offset of the next page, the page size, whether the listing
+/// is recursive, and the path of the folder.
+fn parse_cursor(cursor: &str) -> Option<(usize, usize, bool, &str)> {
+    let mut parts = cursor.splitn(4, ':');
+    let offset = parts.next()?.parse().ok()?;
+    let limit = parts.next()?.parse().ok()?;
+    let recursive = parts.next()? == "1";
+    Some((offset, limit, recursive, parts.next()?))
+}
+
+fn malformed(message: &str) -> files::MalformedPathError {
+    Some(message.to_owned())
+}
+
+/// Check that a path is valid, and return its lowercased form. The root is the empty string.
+fn normalize(path: &str) -> Result<String, files::MalformedPathError> {
+    if path.is_empty() {
+        Ok(String::new())
+    } else if !path.starts_with('/') {
+        Err(malformed("path must start with a slash"))
+    } else if path.ends_with('/') || path.contains("//") {
+        Err(malformed("path must not have an empty component"))
+    } else {
+        Ok(path.to_lowercase())
+    }
+}
+
+/// The lowercased path of the folder containing the given one.
+fn parent(key: &str) -> &str {
+    &key[..key.rfind('/').unwrap_or(0)]
+}
+
+/// Whether `key` is the path of something inside the folder `folder`.
+fn is_inside(key: &str, folder: &str) -> bool {
+    key.len() > folder.len() && key.starts_with(folder) && key.as_bytes()[folder.len()] == b'/'
+}
+
+impl State {
+    fn handle(&mut self, route: &str, arg: &[u8], body: &[u8]) -> Reply {
+        match self.handle_route(route, arg, body) {
+            Ok(reply) | Err(reply) => reply,
+        }
+    }
+
+    fn handle_route(&mut self, route: &str, arg: &[u8], body: &[u8]) -> Result<Reply, Reply> {
+        Ok(match route {
+            routes::UPLOAD => {
+                let arg: files::UploadArg = parse(arg)?;
+                reply(self.write_file(&arg.path, body.to_vec(), &arg.mode, arg.client_modified)
+                    .map_err(|e| files::UploadError::Path(
+                        files::UploadWriteFailed::new(e, String::new()))))
+            }
+            routes::UPLOAD_SESSION_START => {
+                let _: files::UploadSessionStartArg = parse(arg)?;
+                let session_id = format!("session{}", self.next());
+                self.sessions.insert(session_id.clone(), body.to_vec());
+                reply(Ok::<_, files::UploadSessionStartError>(
+                    files::UploadSessionStartResult::new(session_id)))
+            }
+            routes::UPLOAD_SESSION_APPEND_V2 => {
+                let arg: files::UploadSessionAppendArg = parse(arg)?;
+                reply(self.session(&arg.cursor).map(|data| data.extend_from_slice(body)))
+            }
+            routes::UPLOAD_SESSION_FINISH => {
+                let arg: files::UploadSessionFinishArg = parse(arg)?;
+                let mut data = match self.session(&arg.cursor) {
+                    Ok(data) => std::mem::take(data),
+                    Err(e) => return Ok(reply::<(), _>(
+                        Err(files::UploadSessionFinishError::LookupFailed(e)))),
+                };
+                data.extend_from_slice(body);
+                self.sessions.remove(&arg.cursor.session_id);
+                let commit = arg.commit;
+                reply(self.write_file(&commit.path, data, &commit.mode, commit.client_modified)
+                    .map_err(files::UploadSessionFinishError::Path))
+            }
+            routes::DOWNLOAD => {
+                let arg: files::DownloadArg = parse(arg)?;
+                match self.download(&arg) {
+                    Ok((metadata, data)) => Reply::Ok {
+                        result: to_value(&metadata),
+                        body: Some(data),
+                    },
+                    Err(e) => reply::<(), _>(Err(e)),
+                }
+            }
+            routes::GET_METADATA => {
+                let arg: files::GetMetadataArg = parse(arg)?;
+                reply(self.lookup(&arg.path)
+                    .map(|key| self.metadata(&key))
+                    .map_err(files::GetMetadataError::Path))
+            }
+            routes::LIST_FOLDER => {
+                let arg: files::ListFolderArg = parse(arg)?;
+                let limit = arg.limit.map_or(DEFAULT_LIST_LIMIT, |limit| limit.max(1) as usize);
+                reply(self.list_page(&arg.path, arg.recursive, 0, limit)
+                    .map_err(files::ListFolderError::Path))
+            }
+            routes::LIST_FOLDER_CONTINUE => {
+                let arg: files::ListFolderContinueArg = parse(arg)?;
+                let (offset, limit, recursive, path) = parse_cursor(&arg.cursor)
+                    .ok_or_else(|| Reply::BadRequest("invalid cursor".to_owned()))?;
+                reply(self.list_page(path, recursive, offset, limit)
+                    .map_err(files::ListFolderContinueError::Path))
+            }
+            routes::CREATE_FOLDER_V2 => {
+                let arg: files::CreateFolderArg = parse(arg)?;
+                reply(self.create_folder(&arg.path)
+                    .map(files::CreateFolderResult::new)
+                    .map_err(files::CreateFolderError::Path))
+            }
+            routes::DELETE_V2 => {
+                let arg: files::DeleteArg = parse(arg)?;
+                reply(self.delete(&arg.path).map(files::DeleteResult::new))
+            }
+            routes::MOVE_V2 => {
+                let arg: files::RelocationArg = parse(arg)?;
+                reply(self.relocate(&arg.from_path, &arg.to_path).map(files::RelocationResult::new))
+            }
+            _ => Reply::BadRequest(format!("{route} is not supported by FakeDropbox")),
+        })
+    }
+
+    fn next(&mut self) -> u64 {
+        self.counter += 1;
+        self.counter
+    }
+
+    /// The lowercased path of an existing file or folder, given its path or ID.
+    fn lookup(&self, path: &str) -> Result<String, files::LookupError> {
+        if path.starts_with("id:") {
+            return self.entries.iter()
+                .find(|(_, entry)| entry.id == path)
+                .map(|(key, _)| key.clone())
+                .ok_or(files::LookupError::NotFound);
+        }
+        match normalize(path).map_err(files::LookupError::MalformedPath)? {
+            key if key.is_empty() => Err(files::LookupError::MalformedPath(
+                malformed("the root folder isn't supported here"))),
+            key if self.entries.contains_key(&key) => Ok(key),
+            _ => Err(files::LookupError::NotFound),
+        }
+    }
+
+    /// The lowercased and display forms of a path to write to, given its path or ID.
+    fn write_target(&self, path: &str) -> Result<(String, String), files::WriteError> {
+        if path.starts_with("id:") {
+            let key = self.lookup(path)
+                .map_err(|_| files::WriteError::MalformedPath(malformed("no such ID")))?;
+            let display = self.entries[&key].path_display.clone();
+            return Ok((key, display));
+        }
+        match normalize(path).map_err(files::WriteError::MalformedPath)? {
+            key if key.is_empty() => Err(files::WriteError::MalformedPath(
+                malformed("can't write to the root folder"))),
+            key => Ok((key, path.to_owned())),
+        }
+    }
+
+    /// Create any missing folders containing the given path.
+    fn create_parents(&mut self, key: &str, display: &str) -> Result<(), files::WriteError> {
+        let ancestors = display.match_indices('/')
+            .skip(1)
+            .map(|(i, _)| (key[..i].to_owned(), &display[..i]))
+            .collect::<Vec<_>>();
+        for (ancestor, _) in &ancestors {
+            if self.entries.get(ancestor).map_or(false, |entry| entry.file.is_some()) {
+                return Err(files::WriteError::Conflict(files::WriteConflictError::FileAncestor));
+            }
+        }
+        for (ancestor, ancestor_display) in ancestors {
+            if !self.entries.contains_key(&ancestor) {
+                let id = format!("id:fake{}", self.next());
+                self.entries.insert(ancestor, Entry {
+                    path_display: ancestor_display.to_owned(),
+                    id,
+                    file: None,
+                });
+            }
+        }
+        Ok(())
+    }
+
+    fn write_file(
+        &mut self,
+        path: &str,
+        data: Vec<u8>,
+        mode: &files::WriteMode,
+        client_modified: Option<String>,
+    ) -> Result<files::FileMetadata, files::WriteError> {
+        let (key, mut display) = self.write_target(path)?;
+        let conflict = |kind| Err(files::WriteError::Conflict(kind));
+        let mut id = None;
+        if let Some(existing) = self.entries.get(&key) {
+            let Some(file) = &existing.file else {
+                return conflict(files::WriteConflictError::Folder);
+            };
+            match mode {
+                files::WriteMode::Add if file.data == data => return Ok(self.file_metadata(&key)),
+                files::WriteMode::Update(rev) if *rev != file.rev => {
+                    return conflict(files::WriteConflictError::File);
+                }
+                files::WriteMode::Add => return conflict(files::WriteConflictError::File),
+                _ => (),
+            }
+            id = Some(existing.id.clone());
+            display = existing.path_display.clone();
+        } else if matches!(mode, files::WriteMode::Update(_)) {
+            return conflict(files::WriteConflictError::File);
+        }
+        self.create_parents(&key, &display)?;
+        let id = id.unwrap_or_else(|| format!("id:fake{}", self.next()));
+        let rev = format!("{:09x}", self.next());
+        self.entries.insert(key.clone(), Entry {
+            path_display: display,
+            id,
+            file: Some(FileContents {
+                data,
+                rev,
+                client_modified: client_modified.unwrap_or_else(|| FAKE_TIMESTAMP.to_owned()),
+            }),
+        });
+        Ok(self.file_metadata(&key))
+    }
+
+    fn download(&self, arg: &files::DownloadArg)
+        -> Result<(files::FileMetadata, Vec<u8>), files::DownloadError>
+    {
+        let key = self.lookup(&arg.path).map_err(files::DownloadError::Path)?;
+        match &self.entries[&key].file {
+            Some(file) if arg.rev.as_ref().map_or(true, |rev| *rev == file.rev) => {
+                Ok((self.file_metadata(&key), file.data.clone()))
+            }
+            Some(_) => Err(files::DownloadError::Path(files::LookupError::NotFound)),
+            None => Err(files::DownloadError::Path(files::LookupError::NotFile)),
+        }
+    }
+
+    fn create_folder(&mut self, path: &str) -> Result<files::FolderMetadata, files::WriteError> {
+        let (key, display) = self.write_target(path)?;
+        if let Some(existing) = self.entries.get(&key) {
+            return Err(files::WriteError::Conflict(match existing.file {
+                Some(_) => files::WriteConflictError::File,
+                None => files::WriteConflictError::Folder,
+            }));
+        }
+        self.create_parents(&key, &display)?;
+        let id = format!("id:fake{}", self.next());
+        self.entries.insert(key.clone(), Entry { path_display: display, id, file: None });
+        match self.metadata(&key) {
+            files::Metadata::Folder(folder) => Ok(folder),
+            _ => unreachable!("created something other than a folder"),
+        }
+    }
+
+    fn delete(&mut self, path: &str) -> Result<files::Metadata, files::DeleteError> {
+        let key = self.lookup(path).map_err(files::DeleteError::PathLookup)?;
+        let metadata = self.metadata(&key);
+        self.entries.retain(|k, _| *k != key && !is_inside(k, &key));
+        Ok(metadata)
+    }
+
+    fn relocate(&mut self, from: &str, to: &str) -> Result<files::Metadata, files::RelocationError> {
+        let from_key = self.lookup(from).map_err(files::RelocationError::FromLookup)?;
+        let (to_key, to_display) = self.write_target(to).map_err(files::RelocationError::To)?;
+        if is_inside(&to_key, &from_key) {
+            return Err(files::RelocationError::CantMoveFolderIntoItself);
+        }
+        // Moving to the same path with a different case renames it.
+        if to_key != from_key {
+            if let Some(existing) = self.entries.get(&to_key) {
+                return Err(files::RelocationError::To(files::WriteError::Conflict(
+                    match existing.file {
+                        Some(_) => files::WriteConflictError::File,
+                        None => files::WriteConflictError::Folder,
+                    })));
+            }
+        }
+        self.create_parents(&to_key, &to_display).map_err(files::RelocationError::To)?;
+        let from_display_len = self.entries[&from_key].path_display.len();
+        let moved = self.entries.keys()
+            .filter(|k| **k == from_key || is_inside(k, &from_key))
+            .cloned()
+            .collect::<Vec<_>>();
+        for key in moved {
+            let mut entry = self.entries.remove(&key).expect("entry went missing");
+            entry.path_display = format!("{to_display}{}", &entry.path_display[from_display_len..]);
+            self.entries.insert(format!("{to_key}{}", &key[from_key.len()..]), entry);
+        }
+        Ok(self.metadata(&to_key))
+    }
+
+    /// A page of the entries in a folder.
+    fn list_page(&self, path: &str, recursive: bool, offset: usize, limit: usize)
+        -> Result<files::ListFolderResult, files::LookupError>
+    {
+        let folder = match path {
+            "" => String::new(),
+            path => {
+                let key = self.lookup(path)?;
+                if self.entries[&key].file.is_some() {
+                    return Err(files::LookupError::NotFolder);
+                }
+                key
+            }
+        };
+        let keys = self.entries.keys()
+            .filter(|key| {
+                if recursive {
+                    // Recursive listings include the folder itself.
+                    **key == folder || is_inside(key, &folder)
+                } else {
+                    parent(key) == folder
+                }
+            })
+            .collect::<Vec<_>>();
+        let end = keys.len().min(offset.saturating_add(limit));
+        let entries = keys.get(offset..end)
+            .unwrap_or_default()
+            .iter()
+            .map(|key| self.metadata(key))
+            .collect();
+        let cursor = format!("{end}:{limit}:{}:{path}", u8::from(recursive));
+        Ok(files::ListFolderResult::new(entries, cursor, end < keys.len()))
+    }
+
+    fn file_metadata(&self, key: &str) -> files::FileMetadata {
+        match self.metadata(key) {
+            files::Metadata::File(file) => file,
+            _ => unreachable!("not a file: {}", key),
+        }
+    }
+
+    fn metadata(&self, key: &str) -> files::Metadata {
+        let entry = &self.entries[key];
+        let name = entry.path_display.rsplit('/').next().unwrap_or_default().to_owned();
+        match &entry.file {
+            Some(file) => files::Metadata::File(
+                files::FileMetadata::new(
+                    name,
+                    entry.id.clone(),
+                    file.client_modified.clone(),
+                    FAKE_TIMESTAMP.to_owned(),
+                    file.rev.clone(),
+                    file.data.len() as u64,
+                )
+                .with_path_lower(key.to_owned())
+                .with_path_display(entry.path_display.clone())
+                .with_content_hash(content_hash(&file.data))),
+            None => files::Metadata::Folder(
+                files::FolderMetadata::new(name, entry.id.clone())
+                    .with_path_lower(key.to_owned())
+                    .with_path_display(entry.path_display.clone())),
+        }
+    }
+
+    /// The data uploaded so far to an upload session, checking that the cursor is at its end.
+    fn session(&mut self, cursor: &files::UploadSessionCursor)
+        -> Result<&mut Vec<u8>, files::UploadSessionLookupError>
+    {
+        let data = self.sessions.get_mut(&cursor.session_id)
+            .ok_or(files::UploadSessionLookupError::NotFound)?;
+        if data.len() as u64 != cursor.offset {
+            return Err(files::UploadSessionLookupError::IncorrectOffset(
+                files::UploadSessionOffsetError::new(data.len() as u64)));
+        }
+        Ok(data)
+    }
+}
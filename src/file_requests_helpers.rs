@@ -0,0 +1,215 @@
+//! Helpers for managing many file requests at once, from the `file_requests` namespace.
+//!
+//! [`list_all_file_requests`] follows the cursor through all of the user's file requests, and
+//! [`update_file_requests`] applies a change to each of them which needs one, retrying when rate
+//! limited. [`close_expired_file_requests`] and [`extend_file_request_deadlines`] use these for the
+//! most common changes. Accessors on [`FileRequest`](file_requests::FileRequest) tell when a
+//! request's deadline is, and whether it's passed or coming up soon, such as for sending
+//! reminders before it.
+//!
+//! Like the [`files_helpers`](crate::files_helpers), these are async functions, which can also be
+//! used with sync clients by driving them with any executor. Retrying needs an executor which
+//! supports timers, unless the [`RetryBudget`] has a clock which doesn't need one.
+
+use std::borrow::Borrow;
+use std::time::{Duration, SystemTime};
+use futures::{Stream, TryStreamExt};
+use crate::async_client_trait::UserAuthClient;
+use crate::generated::async_routes::file_requests;
+use crate::retry::{RetryBudget, RetryScope};
+use crate::{timestamp, BoxedError, Error};
+
+/// List all the file requests owned by the user, as a stream, following the cursor through all
+/// the pages of `file_requests/list_v2` and `file_requests/list/continue`.
+///
+/// The stream ends after the first error.
+pub fn list_all_file_requests(client: &impl UserAuthClient)
+    -> impl Stream<Item = Result<file_requests::FileRequest, BoxedError>> + Send + '_
+{
+    list_with_retries(client, RetryBudget::new(0).start())
+}
+
+/// Like [`list_all_file_requests`], but retrying within the given scope, which may be owned or
+/// borrowed.
+fn list_with_retries<'a, S>(client: &'a impl UserAuthClient, scope: S)
+    -> impl Stream<Item = Result<file_requests::FileRequest, BoxedError>> + Send + 'a
+where
+    S: Borrow<RetryScope> + Send + Sync + 'a,
+{
+    struct State<'a, C, S> {
+        client: &'a C,
+        scope: S,
+        started: bool,
+        cursor: Option<String>,
+        requests: std::vec::IntoIter<file_requests::FileRequest>,
+    }
+
+    let state = State {
+        client,
+        scope,
+        started: false,
+        cursor: None,
+        requests: vec![].into_iter(),
+    };
+
+    futures::stream::unfold(state, |mut state| async move {
+        loop {
+            if let Some(request) = state.requests.next() {
+                return Some((Ok(request), state));
+            }
+
+            let page = if !state.started {
+                state.started = true;
+                let arg = file_requests::ListFileRequestsArg::default();
+                state.scope.borrow().call(|| file_requests::list_v2(state.client, &arg)).await
+                    .map_err(Error::boxed)
+            } else if let Some(cursor) = state.cursor.take() {
+                let arg = file_requests::ListFileRequestsContinueArg::new(cursor);
+                state.scope.borrow().call(|| file_requests::list_continue(state.client, &arg)).await
+                    .map_err(Error::boxed)
+            } else {
+                return None;
+            };
+            match page {
+                Ok(page) => {
+                    state.requests = page.file_requests.into_iter();
+                    if page.has_more {
+                        state.cursor = Some(page.cursor);
+                    }
+                }
+                Err(e) => return Some((Err(e), state)),
+            }
+        }
+    })
+}
+
+/// What [`update_file_requests`] did.
+#[derive(Debug, Default)]
+#[non_exhaustive]
+pub struct BulkUpdateReport {
+    /// The file requests which were updated, as they are after the update.
+    pub updated: Vec<file_requests::FileRequest>,
+
+    /// The IDs of the file requests which couldn't be updated, with the errors from updating
+    /// them.
+    pub failed: Vec<(String, Error<file_requests::UpdateFileRequestError>)>,
+}
+
+/// Go through all the user's file requests, and update each one that `change` returns an update
+/// for.
+///
+/// The requests listing them and updating them are retried if they fail with a transient error,
+/// such as being rate limited, within the limits of the `retry_budget`, which is shared by all
+/// of them. An error updating one file request doesn't stop the others being updated; it's
+/// recorded in the report instead. An error listing them stops everything, and is returned.
+pub async fn update_file_requests<F>(
+    client: &impl UserAuthClient,
+    retry_budget: &RetryBudget,
+    mut change: F,
+) -> Result<BulkUpdateReport, BoxedError>
+where
+    F: FnMut(&file_requests::FileRequest) -> Option<file_requests::UpdateFileRequestArgs>,
+{
+    // Everything is listed before updating anything, so the updates can't affect the listing.
+    let scope = retry_budget.start();
+    let to_update = list_with_retries(client, &scope)
+        .try_filter_map(|request| futures::future::ok(change(&request)))
+        .try_collect::<Vec<_>>()
+        .await?;
+
+    let mut report = BulkUpdateReport::default();
+    for arg in to_update {
+        match scope.call(|| file_requests::update(client, &arg)).await {
+            Ok(request) => report.updated.push(request),
+            Err(e) => {
+                warn!("failed to update file request {}: {e}", arg.id);
+                report.failed.push((arg.id, e));
+            }
+        }
+    }
+    Ok(report)
+}
+
+/// Close all the user's open file requests whose deadline, including any grace period for late
+/// uploads, was before `now`.
+pub async fn close_expired_file_requests(
+    client: &impl UserAuthClient,
+    now: SystemTime,
+    retry_budget: &RetryBudget,
+) -> Result<BulkUpdateReport, BoxedError> {
+    update_file_requests(client, retry_budget, |request| {
+        request.is_expired(now)
+            .then(|| file_requests::UpdateFileRequestArgs::new(request.id.clone()).with_open(false))
+    })
+    .await
+}
+
+/// Move the deadline of all the user's open file requests which have one before `deadline` to
+/// `deadline`, keeping their grace periods.
+///
+/// Requests without a deadline are left without one.
+pub async fn extend_file_request_deadlines(
+    client: &impl UserAuthClient,
+    deadline: SystemTime,
+    retry_budget: &RetryBudget,
+) -> Result<BulkUpdateReport, BoxedError> {
+    update_file_requests(client, retry_budget, |request| {
+        let current = request.deadline.as_ref()?;
+        if !request.is_open || request.deadline_time()? >= deadline {
+            return None;
+        }
+        let new = file_requests::FileRequestDeadline {
+            deadline: timestamp::from_system_time(deadline),
+            allow_late_uploads: current.allow_late_uploads.clone(),
+        };
+        Some(file_requests::UpdateFileRequestArgs::new(request.id.clone())
+            .with_deadline(file_requests::UpdateFileRequestDeadline::Update(Some(new))))
+    })
+    .await
+}
+
+impl crate::types::file_requests::GracePeriod {
+    /// How long after the deadline uploads are still allowed, or `None` if they're always
+    /// allowed, or if it's a grace period this version of the SDK doesn't know.
+    pub fn duration(&self) -> Option<Duration> {
+        const DAY: Duration = Duration::from_secs(24 * 60 * 60);
+        match self {
+            Self::OneDay => Some(DAY),
+            Self::TwoDays => Some(DAY * 2),
+            Self::SevenDays => Some(DAY * 7),
+            Self::ThirtyDays => Some(DAY * 30),
+            Self::Always | Self::Other(_) => None,
+        }
+    }
+}
+
+impl crate::types::file_requests::FileRequest {
+    /// The file request's deadline, if it has one and it could be parsed.
+    pub fn deadline_time(&self) -> Option<SystemTime> {
+        timestamp::to_system_time(&self.deadline.as_ref()?.deadline).ok()
+    }
+
+    /// When the file request stops accepting uploads: its deadline, plus the grace period for
+    /// late uploads if it has one. `None` if it has no deadline, or late uploads are always
+    /// allowed.
+    pub fn closes_at(&self) -> Option<SystemTime> {
+        let deadline = self.deadline.as_ref()?;
+        let time = self.deadline_time()?;
+        match &deadline.allow_late_uploads {
+            None => Some(time),
+            Some(grace) => Some(time + grace.duration()?),
+        }
+    }
+
+    /// Whether the file request is open, but has stopped accepting uploads as of `now`.
+    pub fn is_expired(&self, now: SystemTime) -> bool {
+        self.is_open && self.closes_at().map_or(false, |closes| closes <= now)
+    }
+
+    /// Whether the file request is open, and its deadline is after `now` but within `window` of
+    /// it, such as for reminding people to upload before it.
+    pub fn is_due_within(&self, now: SystemTime, window: Duration) -> bool {
+        self.is_open && self.deadline_time()
+            .map_or(false, |deadline| deadline > now && deadline <= now + window)
+    }
+}
@@ -0,0 +1,2859 @@
+//! Higher-level helpers for common operations in the `files` namespace.
+//!
+//! These are built on top of the async routes and work with any client implementing the async
+//! client traits. The sync clients implement those too, and with a sync client the futures returned
+//! here always complete immediately, so they can be used from sync code by driving them with any
+//! executor, such as `futures::executor::block_on`.
+
+use std::collections::BTreeMap;
+use std::path::Path;
+use std::sync::Arc;
+use std::time::{Duration, SystemTime};
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD;
+use bytes::Bytes;
+use futures::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt, Sink, SinkExt, Stream,
+    StreamExt, TryStreamExt};
+use serde::de::DeserializeOwned;
+use serde_json::value::RawValue;
+use crate::async_client_trait::{NoauthClient, UserAuthClient};
+use crate::client_helpers::{parse_response, request, request_raw, USER_AGENT};
+use crate::client_trait_common::{Endpoint, HttpRequest, ParamsType, Stalled, Style};
+use crate::clock::{Clock, SystemClock};
+use crate::content_hash::ContentHasher;
+use crate::generated::async_routes::files;
+use crate::pagination;
+use crate::retry::{RetryBudget, RetryScope};
+#[cfg(feature = "sync_routes")]
+use crate::client_helpers::{Body, StreamBody};
+use crate::{BoxedError, Error, NoError};
+
+/// Uploads smaller than this are done in a single request, and larger ones use an upload session
+/// with requests of this size. Upload session requests must be a multiple of 4 MiB.
+pub const UPLOAD_CHUNK_SIZE: usize = 8 * 1024 * 1024;
+
+/// An error from one of the transfer helpers.
+#[derive(thiserror::Error, Debug)]
+#[non_exhaustive]
+pub enum TransferError {
+    /// An error reading or writing local data.
+    #[error("I/O error: {0}")]
+    Io(std::io::Error),
+
+    /// The response body stopped receiving data for longer than the client's stall timeout, so
+    /// the transfer was aborted. It can be retried, or resumed from where it stopped.
+    #[error("{0}")]
+    Stalled(Stalled),
+
+    /// An error returned by one of the API calls made by the helper.
+    #[error("{0}")]
+    Dropbox(BoxedError),
+
+    /// The file at the given path is locked by someone else, so it can't be changed.
+    #[error("{path} is locked{}", .lock_holder.as_ref()
+        .and_then(|h| h.name.as_deref()).map(|name| format!(" by {name}")).unwrap_or_default())]
+    Locked {
+        /// The path of the locked file.
+        path: String,
+
+        /// Who holds the lock, if it could be found out.
+        lock_holder: Option<LockHolder>,
+    },
+
+    /// The source has more data than can be uploaded this way, so nothing was uploaded.
+    #[error("too much data to upload: the limit is {limit} bytes")]
+    TooLarge {
+        /// The most which can be uploaded, in bytes.
+        limit: u64,
+    },
+
+    /// The [content hash](crate::content_hash) of the data transferred didn't match the one
+    /// Dropbox has for the file, so the data was corrupted on the way.
+    #[error("content hash mismatch: transferred {local}, but Dropbox has {remote}")]
+    ContentHashMismatch {
+        /// The content hash of the data sent or received.
+        local: String,
+
+        /// The content hash Dropbox returned for the file.
+        remote: String,
+    },
+}
+
+impl From<std::io::Error> for TransferError {
+    fn from(e: std::io::Error) -> Self {
+        match Stalled::from_io_error(&e) {
+            Some(stalled) => TransferError::Stalled(*stalled),
+            None => TransferError::Io(e),
+        }
+    }
+}
+
+impl TransferError {
+    /// Convert an error from a request involving the given path, looking up who holds the lock if
+    /// it failed because the file is locked.
+    async fn from_locked<E>(client: &impl UserAuthClient, path: &str, e: Error<E>) -> Self
+    where
+        E: std::error::Error + serde::Serialize + Send + Sync + 'static,
+    {
+        if !e.is_file_locked() {
+            return e.into();
+        }
+        let lock_holder = match get_file_locks(client, [path]).await {
+            Ok(mut locks) => locks.pop().and_then(Result::ok).flatten(),
+            Err(e) => {
+                debug!("failed to look up the lock on {path}: {e}");
+                None
+            }
+        };
+        TransferError::Locked {
+            path: path.to_owned(),
+            lock_holder,
+        }
+    }
+}
+
+/// The holder of a lock on a file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct LockHolder {
+    /// The account ID of the lock holder.
+    pub account_id: String,
+
+    /// The lock holder's display name, if known.
+    pub name: Option<String>,
+
+    /// The ID of the lock holder's team, if they're on one.
+    pub team_id: Option<String>,
+
+    /// When the lock was created.
+    pub created: String,
+}
+
+/// Find out who, if anyone, holds the lock on each of the given files, using
+/// `files/get_file_lock_batch`.
+///
+/// The results are in the same order as the paths. Each is `None` if the file isn't locked, or an
+/// error if its lock couldn't be looked up, such as if it doesn't exist.
+pub async fn get_file_locks(
+    client: &impl UserAuthClient,
+    paths: impl IntoIterator<Item = impl Into<String>>,
+) -> Result<Vec<Result<Option<LockHolder>, files::LockFileError>>, Error<files::LockFileError>> {
+    let entries = paths.into_iter().map(|path| files::LockFileArg::new(path.into())).collect();
+    let result = files::get_file_lock_batch(client, &files::LockFileBatchArg::new(entries)).await?;
+    Ok(result.entries.into_iter().map(|entry| match entry {
+        files::LockFileResultEntry::Success(result) => {
+            let files::FileLockContent::SingleUser(lock) = result.lock.content else {
+                return Ok(None);
+            };
+            let name = match result.metadata {
+                files::Metadata::File(file) => file.file_lock_info.and_then(|info| info.lockholder_name),
+                _ => None,
+            };
+            Ok(Some(LockHolder {
+                account_id: lock.lock_holder_account_id,
+                name,
+                team_id: lock.lock_holder_team_id,
+                created: lock.created,
+            }))
+        }
+        files::LockFileResultEntry::Failure(e) => Err(e),
+    }).collect())
+}
+
+/// Move a file or folder, with `files/move_v2`.
+///
+/// If the source or destination is locked, this fails with [`TransferError::Locked`], saying who
+/// holds the lock.
+pub async fn move_path(
+    client: &impl UserAuthClient,
+    arg: &files::RelocationArg,
+) -> Result<files::Metadata, TransferError> {
+    match files::move_v2(client, arg).await {
+        Ok(result) => Ok(result.metadata),
+        Err(e @ Error::Api(files::RelocationError::FromLookup(_) | files::RelocationError::FromWrite(_))) => {
+            Err(TransferError::from_locked(client, &arg.from_path, e).await)
+        }
+        Err(e) => Err(TransferError::from_locked(client, &arg.to_path, e).await),
+    }
+}
+
+impl<E: std::error::Error + Send + Sync + 'static> From<Error<E>> for TransferError {
+    fn from(e: Error<E>) -> Self {
+        TransferError::Dropbox(e.boxed())
+    }
+}
+
+/// Options for the upload helpers.
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub struct UploadOptions {
+    /// When uploading a local file, set the `client_modified` time of the uploaded file to the
+    /// local file's modification time, unless the [`CommitInfo`](files::CommitInfo) already
+    /// specifies one. Defaults to true.
+    pub preserve_mtime: bool,
+
+    /// Limits on retrying the requests of one upload which fail with transient errors, in total.
+    /// Defaults to no retries.
+    ///
+    /// Retrying waits between attempts, so if this allows any retries, the upload needs an
+    /// executor which supports timers, even with a sync client.
+    pub retry_budget: RetryBudget,
+
+    /// Convert the path uploaded to into Unicode NFC form, logging a warning if this changed it,
+    /// so that names of local files from macOS match the same names created elsewhere. See
+    /// [`unicode_paths`](crate::unicode_paths). Defaults to false.
+    #[cfg(feature = "unicode-normalization")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "unicode-normalization")))]
+    pub normalize_paths: bool,
+}
+
+impl Default for UploadOptions {
+    fn default() -> Self {
+        Self {
+            preserve_mtime: true,
+            retry_budget: RetryBudget::new(0),
+            #[cfg(feature = "unicode-normalization")]
+            normalize_paths: false,
+        }
+    }
+}
+
+impl UploadOptions {
+    /// Set whether to preserve local file modification times.
+    pub fn with_preserve_mtime(mut self, value: bool) -> Self {
+        self.preserve_mtime = value;
+        self
+    }
+
+    /// Set the limits on retrying failed requests.
+    pub fn with_retry_budget(mut self, value: RetryBudget) -> Self {
+        self.retry_budget = value;
+        self
+    }
+
+    /// Set whether to normalize the path uploaded to.
+    #[cfg(feature = "unicode-normalization")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "unicode-normalization")))]
+    pub fn with_normalize_paths(mut self, value: bool) -> Self {
+        self.normalize_paths = value;
+        self
+    }
+
+    /// The path to upload to, normalized if the options say to.
+    fn upload_path(&self, path: String) -> String {
+        #[cfg(feature = "unicode-normalization")]
+        if self.normalize_paths {
+            return crate::unicode_paths::normalize_path(&path).into_owned();
+        }
+        path
+    }
+}
+
+impl crate::types::files::FileMetadata {
+    /// The photo or video metadata of the file, if it has any.
+    ///
+    /// This is only present if `include_media_info` was set in the request, and the server has
+    /// finished extracting it.
+    pub fn media_metadata(&self) -> Option<&files::MediaMetadata> {
+        match self.media_info.as_ref()? {
+            files::MediaInfo::Metadata(metadata) => Some(metadata),
+            files::MediaInfo::Pending => None,
+        }
+    }
+
+    /// The dimensions of a photo or video.
+    pub fn image_dimensions(&self) -> Option<&files::Dimensions> {
+        match self.media_metadata()? {
+            files::MediaMetadata::Photo(photo) => photo.dimensions.as_ref(),
+            files::MediaMetadata::Video(video) => video.dimensions.as_ref(),
+        }
+    }
+
+    /// When a photo or video was taken, as a timestamp string (see [`crate::timestamp`]).
+    pub fn time_taken(&self) -> Option<&str> {
+        match self.media_metadata()? {
+            files::MediaMetadata::Photo(photo) => photo.time_taken.as_deref(),
+            files::MediaMetadata::Video(video) => video.time_taken.as_deref(),
+        }
+    }
+
+    /// Where a photo or video was taken.
+    pub fn gps(&self) -> Option<&files::GpsCoordinates> {
+        match self.media_metadata()? {
+            files::MediaMetadata::Photo(photo) => photo.location.as_ref(),
+            files::MediaMetadata::Video(video) => video.location.as_ref(),
+        }
+    }
+}
+
+/// Get the metadata for a file or folder, or `None` if nothing exists at the path.
+///
+/// Any error other than the path not being found is returned.
+pub async fn metadata_opt(
+    client: &impl UserAuthClient,
+    path: impl Into<String>,
+) -> Result<Option<files::Metadata>, Error<files::GetMetadataError>> {
+    let arg = files::GetMetadataArg::new(path.into());
+    match files::get_metadata(client, &arg).await {
+        Ok(metadata) => Ok(Some(metadata)),
+        Err(Error::Api(files::GetMetadataError::Path(files::LookupError::NotFound))) => Ok(None),
+        Err(e) => Err(e),
+    }
+}
+
+/// Check whether a file or folder exists at the path.
+///
+/// Any error other than the path not being found is returned.
+pub async fn exists(
+    client: &impl UserAuthClient,
+    path: impl Into<String>,
+) -> Result<bool, Error<files::GetMetadataError>> {
+    Ok(metadata_opt(client, path).await?.is_some())
+}
+
+/// A string isn't a valid [`FileId`] or [`Rev`].
+#[derive(thiserror::Error, Debug, Clone, PartialEq, Eq)]
+pub enum InvalidAddress {
+    /// Not a valid file ID.
+    #[error("invalid file ID {0:?}: expected \"id:\" followed by letters, digits, '_' or '-'")]
+    Id(String),
+
+    /// Not a valid revision.
+    #[error("invalid revision {0:?}: expected at least 9 lowercase hex digits")]
+    Rev(String),
+}
+
+/// The ID of a file or folder, as in [`files::FileMetadata::id`], which stays the same when it's
+/// moved or renamed.
+///
+/// The API addresses files and folders by ID by passing `id:<ID>` in place of a path; this
+/// checks the ID's format up front and builds that string.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct FileId(String);
+
+impl FileId {
+    /// Check that the ID is well-formed. It can be given with or without the `id:` prefix.
+    pub fn new(id: &str) -> Result<Self, InvalidAddress> {
+        let bare = id.strip_prefix("id:").unwrap_or(id);
+        if bare.is_empty()
+            || !bare.bytes().all(|b| b.is_ascii_alphanumeric() || b == b'_' || b == b'-')
+        {
+            return Err(InvalidAddress::Id(id.to_owned()));
+        }
+        Ok(Self(format!("id:{}", bare)))
+    }
+
+    /// The ID, including the `id:` prefix. This is what the API accepts in place of a path.
+    pub fn as_path(&self) -> &str {
+        &self.0
+    }
+}
+
+/// A revision of a file, as in [`files::FileMetadata::rev`].
+///
+/// The API addresses a specific revision of a file by passing `rev:<rev>` in place of a path;
+/// this checks the revision's format up front and builds that string.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Rev(String);
+
+impl Rev {
+    /// Check that the revision is well-formed. It can be given with or without the `rev:` prefix.
+    pub fn new(rev: &str) -> Result<Self, InvalidAddress> {
+        let bare = rev.strip_prefix("rev:").unwrap_or(rev);
+        if bare.len() < 9 || !bare.bytes().all(|b| matches!(b, b'0'..=b'9' | b'a'..=b'f')) {
+            return Err(InvalidAddress::Rev(rev.to_owned()));
+        }
+        Ok(Self(format!("rev:{}", bare)))
+    }
+
+    /// The revision without the `rev:` prefix, as used by `files/restore` and
+    /// [`WriteMode::Update`](files::WriteMode::Update).
+    pub fn as_str(&self) -> &str {
+        &self.0["rev:".len()..]
+    }
+
+    /// The revision with the `rev:` prefix. This is what the API accepts in place of a path.
+    pub fn as_path(&self) -> &str {
+        &self.0
+    }
+}
+
+/// Get the metadata for a file or folder by its ID, wherever it is now.
+pub async fn get_metadata_by_id(
+    client: &impl UserAuthClient,
+    id: &FileId,
+) -> Result<files::Metadata, Error<files::GetMetadataError>> {
+    let arg = files::GetMetadataArg::new(id.as_path().to_owned());
+    files::get_metadata(client, &arg).await
+}
+
+/// Get the metadata for a specific revision of a file, which may since have been modified,
+/// moved, or deleted.
+pub async fn get_by_rev(
+    client: &impl UserAuthClient,
+    rev: &Rev,
+) -> Result<files::FileMetadata, Error<files::GetMetadataError>> {
+    let arg = files::GetMetadataArg::new(rev.as_path().to_owned());
+    match files::get_metadata(client, &arg).await? {
+        files::Metadata::File(file) => Ok(file),
+        other => Err(Error::UnexpectedResponse(
+            format!("expected file metadata for {}, got {:?}", rev.as_path(), other))),
+    }
+}
+
+/// The format of a file's preview, from [`get_preview`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PreviewFormat {
+    /// A PDF document, for documents and presentations.
+    Pdf,
+
+    /// An HTML page, for spreadsheets.
+    Html,
+}
+
+impl PreviewFormat {
+    /// The format that the preview of a file with the given name or path would be in, going by
+    /// its extension, or `None` if Dropbox can't generate previews of files like it.
+    pub fn for_name(name: &str) -> Option<Self> {
+        let name = name.rsplit('/').next().unwrap_or(name);
+        let (_, extension) = name.rsplit_once('.')?;
+        match extension.to_ascii_lowercase().as_str() {
+            "ai" | "doc" | "docm" | "docx" | "eps" | "gdoc" | "gslides" | "odp" | "odt" | "pps"
+                | "ppsm" | "ppsx" | "ppt" | "pptm" | "pptx" | "rtf" => Some(Self::Pdf),
+            "csv" | "ods" | "xls" | "xlsm" | "gsheet" | "xlsx" => Some(Self::Html),
+            _ => None,
+        }
+    }
+
+    /// The MIME type of previews in this format.
+    pub fn content_type(self) -> &'static str {
+        match self {
+            Self::Pdf => "application/pdf",
+            Self::Html => "text/html",
+        }
+    }
+}
+
+/// A file's preview, from [`get_preview`].
+pub struct Preview {
+    /// The metadata of the file which the preview is of.
+    pub metadata: files::FileMetadata,
+
+    /// The format of the preview, worked out from the file's extension. This is `None` if the
+    /// extension is one which the SDK doesn't know Dropbox can preview.
+    pub format: Option<PreviewFormat>,
+
+    /// The size of the preview, if the server said.
+    pub content_length: Option<u64>,
+
+    /// The preview's contents.
+    pub body: Box<dyn AsyncRead + Unpin + Send>,
+}
+
+/// Get a preview of a document or spreadsheet, using `files/get_preview`, along with what format
+/// it is in.
+///
+/// If the path ends in a file name, and Dropbox can't preview files with its extension, this
+/// fails with [`files::PreviewError::UnsupportedExtension`] without making a request. Otherwise
+/// (such as for IDs and revisions) the format is worked out from the file's name once it is
+/// known.
+pub async fn get_preview(
+    client: &impl UserAuthClient,
+    path: impl Into<String>,
+) -> Result<Preview, Error<files::PreviewError>> {
+    let path = path.into();
+    if path.contains('/') && PreviewFormat::for_name(&path).is_none() {
+        return Err(Error::Api(files::PreviewError::UnsupportedExtension));
+    }
+    let arg = files::PreviewArg::new(path);
+    let result = files::get_preview(client, &arg, None, None).await?;
+    let body = result.body.ok_or_else(|| {
+        Error::UnexpectedResponse("no response body for a preview".to_owned())
+    })?;
+    Ok(Preview {
+        format: PreviewFormat::for_name(&result.result.name),
+        metadata: result.result,
+        content_length: result.content_length,
+        body,
+    })
+}
+
+/// Options for [`get_thumbnail`] and [`get_thumbnails`].
+///
+/// The defaults are the same as the API's: a 64 by 64 pixel JPEG, scaled to fit exactly.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct ThumbnailOptions {
+    /// The image format. JPEG is smaller, PNG supports transparency.
+    pub format: files::ThumbnailFormat,
+
+    /// The size of the image.
+    pub size: files::ThumbnailSize,
+
+    /// How to fit the file's image to the size.
+    pub mode: files::ThumbnailMode,
+}
+
+impl Default for ThumbnailOptions {
+    fn default() -> Self {
+        Self {
+            format: files::ThumbnailFormat::Jpeg,
+            size: files::ThumbnailSize::W64h64,
+            mode: files::ThumbnailMode::Strict,
+        }
+    }
+}
+
+impl ThumbnailOptions {
+    /// Set the image format.
+    pub fn with_format(mut self, value: files::ThumbnailFormat) -> Self {
+        self.format = value;
+        self
+    }
+
+    /// Set the size of the image.
+    pub fn with_size(mut self, value: files::ThumbnailSize) -> Self {
+        self.size = value;
+        self
+    }
+
+    /// Set how to fit the file's image to the size.
+    pub fn with_mode(mut self, value: files::ThumbnailMode) -> Self {
+        self.mode = value;
+        self
+    }
+}
+
+impl crate::types::files::ThumbnailFormat {
+    /// The MIME type of images in this format.
+    pub fn content_type(&self) -> &'static str {
+        match self {
+            Self::Jpeg => "image/jpeg",
+            Self::Png => "image/png",
+        }
+    }
+}
+
+/// A file's thumbnail, being downloaded, from [`get_thumbnail`].
+pub struct ThumbnailStream {
+    /// The metadata of the file which the thumbnail is of.
+    pub metadata: files::FileMetadata,
+
+    /// The format of the image.
+    pub format: files::ThumbnailFormat,
+
+    /// The size of the image data, if the server said.
+    pub content_length: Option<u64>,
+
+    /// The image data.
+    pub body: Box<dyn AsyncRead + Unpin + Send>,
+}
+
+impl ThumbnailStream {
+    /// Read the whole image.
+    pub async fn read_all(mut self) -> std::io::Result<Thumbnail> {
+        let mut data = Vec::with_capacity(self.content_length.unwrap_or(0) as usize);
+        self.body.read_to_end(&mut data).await?;
+        Ok(Thumbnail {
+            metadata: self.metadata,
+            format: self.format,
+            data,
+        })
+    }
+}
+
+/// A file's thumbnail, from [`get_thumbnails`] or [`ThumbnailStream::read_all`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Thumbnail {
+    /// The metadata of the file which the thumbnail is of.
+    pub metadata: files::FileMetadata,
+
+    /// The format of the image.
+    pub format: files::ThumbnailFormat,
+
+    /// The image data.
+    pub data: Vec<u8>,
+}
+
+/// Get a thumbnail of an image or video file, given by path or ID, using `files/get_thumbnail_v2`.
+///
+/// The image is streamed, so this suits large thumbnails, or showing one file at a time. To get
+/// small thumbnails of many files, such as for a grid of images, [`get_thumbnails`] makes fewer
+/// requests.
+pub async fn get_thumbnail(
+    client: &impl UserAuthClient,
+    path: impl Into<String>,
+    options: &ThumbnailOptions,
+) -> Result<ThumbnailStream, Error<files::ThumbnailV2Error>> {
+    let arg = files::ThumbnailV2Arg::new(files::PathOrLink::Path(path.into()))
+        .with_format(options.format.clone())
+        .with_size(options.size.clone())
+        .with_mode(options.mode.clone());
+    let result = files::get_thumbnail_v2(client, &arg, None, None).await?;
+    let metadata = result.result.file_metadata.ok_or_else(|| {
+        Error::UnexpectedResponse("thumbnail response is missing file metadata".to_owned())
+    })?;
+    let body = result.body.ok_or_else(|| {
+        Error::UnexpectedResponse("no response body for a thumbnail".to_owned())
+    })?;
+    Ok(ThumbnailStream {
+        metadata,
+        format: options.format.clone(),
+        content_length: result.content_length,
+        body,
+    })
+}
+
+/// The most files `files/get_thumbnail_batch` accepts in one request.
+const THUMBNAIL_BATCH_SIZE: usize = 25;
+
+/// Get thumbnails of many image or video files, each given by path or ID.
+///
+/// The thumbnails are fetched 25 at a time with `files/get_thumbnail_batch`. Its images come
+/// base64-encoded in a JSON response, so this suits small thumbnails; use [`get_thumbnail`] for
+/// large ones. If the server rejects a batch, its files are fetched one at a time with
+/// `files/get_thumbnail_v2` instead.
+///
+/// The result has each file's thumbnail, or the error getting it, in the order the files were
+/// given. Errors making a request, such as network errors, stop everything, and are returned.
+pub async fn get_thumbnails(
+    client: &impl UserAuthClient,
+    paths: impl IntoIterator<Item = impl Into<String>>,
+    options: &ThumbnailOptions,
+) -> Result<Vec<Result<Thumbnail, Error<files::ThumbnailV2Error>>>, BoxedError> {
+    let paths = paths.into_iter().map(Into::into).collect::<Vec<String>>();
+    let mut results = Vec::with_capacity(paths.len());
+    for chunk in paths.chunks(THUMBNAIL_BATCH_SIZE) {
+        let entries = chunk.iter()
+            .map(|path| files::ThumbnailArg::new(path.clone())
+                .with_format(options.format.clone())
+                .with_size(options.size.clone())
+                .with_mode(options.mode.clone()))
+            .collect();
+        let arg = files::GetThumbnailBatchArg::new(entries);
+        match files::get_thumbnail_batch(client, &arg).await {
+            Ok(batch) => {
+                if batch.entries.len() != chunk.len() {
+                    return Err(Error::<NoError>::UnexpectedResponse(format!(
+                        "thumbnail batch of {} files has {} results",
+                        chunk.len(), batch.entries.len())).boxed());
+                }
+                for entry in batch.entries {
+                    results.push(thumbnail_from_batch(entry, &options.format));
+                }
+            }
+            Err(Error::Api(e)) => {
+                debug!("thumbnail batch failed ({e}); getting thumbnails one at a time");
+                for path in chunk {
+                    results.push(match get_thumbnail(client, path.clone(), options).await {
+                        Ok(stream) => Ok(stream.read_all().await
+                            .map_err(|e| Error::<NoError>::HttpClient(Box::new(e)).boxed())?),
+                        Err(e @ Error::Api(_)) => Err(e),
+                        Err(e) => return Err(e.boxed()),
+                    });
+                }
+            }
+            Err(e) => return Err(e.boxed()),
+        }
+    }
+    Ok(results)
+}
+
+fn thumbnail_from_batch(
+    entry: files::GetThumbnailBatchResultEntry,
+    format: &files::ThumbnailFormat,
+) -> Result<Thumbnail, Error<files::ThumbnailV2Error>> {
+    use files::ThumbnailV2Error as E;
+    match entry {
+        files::GetThumbnailBatchResultEntry::Success(data) => {
+            let data_bytes = STANDARD.decode(&data.thumbnail).map_err(|e| {
+                Error::UnexpectedResponse(format!("thumbnail is not valid base64: {e}"))
+            })?;
+            Ok(Thumbnail {
+                metadata: data.metadata,
+                format: format.clone(),
+                data: data_bytes,
+            })
+        }
+        files::GetThumbnailBatchResultEntry::Failure(e) => Err(Error::Api(match e {
+            files::ThumbnailError::Path(e) => E::Path(e),
+            files::ThumbnailError::UnsupportedExtension => E::UnsupportedExtension,
+            files::ThumbnailError::UnsupportedImage => E::UnsupportedImage,
+            files::ThumbnailError::ConversionError => E::ConversionError,
+        })),
+        files::GetThumbnailBatchResultEntry::Other(x) => Err(Error::Api(E::Other(x))),
+    }
+}
+
+/// Options for [`list_folder`].
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub struct ListFolderOptions {
+    /// If the cursor is invalidated partway through the listing (which `files/list_folder/continue`
+    /// reports as [`Reset`](files::ListFolderContinueError::Reset)), start the listing again from
+    /// the beginning instead of returning the error. Defaults to true.
+    ///
+    /// Entries from before the restart may be listed again, so each entry says how many times the
+    /// listing was restarted before it was returned. The listing is restarted at most
+    /// [`LIST_FOLDER_MAX_RESTARTS`] times; after that, the error is returned.
+    pub restart_on_reset: bool,
+
+    /// Parse each page of entries incrementally as the response arrives, instead of reading and
+    /// deserializing the whole page before returning any of it. Defaults to false.
+    ///
+    /// Only one entry, and a small buffer of the response, is held in memory at a time, which
+    /// lowers peak memory use when listing folders with very many entries. The catch is that a
+    /// malformed response is only noticed partway through a page, after some of its entries have
+    /// already been returned.
+    pub stream_entries: bool,
+
+    /// How many entries to ask for in each page. Defaults to the argument's
+    /// [`limit`](files::ListFolderArg::limit) if it has one, or else the most `files/list_folder`
+    /// allows, which is 2000; see [`pagination`].
+    pub page_size: Option<u32>,
+
+    /// If the first page fails with a server error or times out, which large pages of
+    /// complicated folders can cause, ask for it again with half as many entries, down to 100,
+    /// before returning the error. Defaults to true.
+    ///
+    /// The following pages have the same size as the first one, as the cursor keeps it.
+    pub adaptive_page_size: bool,
+
+    /// Which entries to return. Defaults to all of them.
+    pub filter: EntryFilter,
+
+    /// Limits on retrying the requests for pages which fail with transient errors, in total.
+    /// Defaults to no retries.
+    ///
+    /// Retrying waits between attempts, so if this allows any retries, the listing needs an
+    /// executor which supports timers, even with a sync client.
+    pub retry_budget: RetryBudget,
+}
+
+impl Default for ListFolderOptions {
+    fn default() -> Self {
+        Self {
+            restart_on_reset: true,
+            stream_entries: false,
+            page_size: None,
+            adaptive_page_size: true,
+            filter: EntryFilter::default(),
+            retry_budget: RetryBudget::new(0),
+        }
+    }
+}
+
+impl ListFolderOptions {
+    /// Set whether to restart the listing if the cursor is invalidated.
+    pub fn with_restart_on_reset(mut self, value: bool) -> Self {
+        self.restart_on_reset = value;
+        self
+    }
+
+    /// Set whether to parse entries incrementally as each page arrives.
+    pub fn with_stream_entries(mut self, value: bool) -> Self {
+        self.stream_entries = value;
+        self
+    }
+
+    /// Set how many entries to ask for in each page.
+    pub fn with_page_size(mut self, value: u32) -> Self {
+        self.page_size = Some(value);
+        self
+    }
+
+    /// Set whether to ask for smaller pages if the first one fails.
+    pub fn with_adaptive_page_size(mut self, value: bool) -> Self {
+        self.adaptive_page_size = value;
+        self
+    }
+
+    /// Set which entries to return.
+    pub fn with_filter(mut self, value: EntryFilter) -> Self {
+        self.filter = value;
+        self
+    }
+
+    /// Set the limits on retrying failed requests.
+    pub fn with_retry_budget(mut self, value: RetryBudget) -> Self {
+        self.retry_budget = value;
+        self
+    }
+}
+
+/// A kind of entry in a folder listing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EntryKind {
+    /// A file.
+    File,
+
+    /// A folder.
+    Folder,
+
+    /// A deleted file or folder.
+    Deleted,
+}
+
+/// Which entries [`list_folder`] and [`list_folder_raw`] return. By default, they return all of
+/// them.
+///
+/// Entries are checked as they're listed. Where the server can do some of the filtering itself,
+/// the listing's argument is adjusted so that it does: asking for deleted entries includes them
+/// in the listing, and asking for folders leaves out files which can't be downloaded.
+#[derive(Clone, Default)]
+#[non_exhaustive]
+pub struct EntryFilter {
+    /// Only return entries of this kind.
+    pub kind: Option<EntryKind>,
+
+    /// Only return files, or deleted entries, whose names have one of these extensions, such as
+    /// `"jpg"`, compared case-insensitively. Folders are never returned when this is set.
+    pub extensions: Option<Vec<String>>,
+
+    file_predicate: Option<Arc<FilePredicate>>,
+}
+
+type FilePredicate = dyn Fn(&files::FileMetadata) -> bool + Send + Sync;
+
+impl EntryFilter {
+    /// Only return entries of the given kind.
+    pub fn with_kind(mut self, value: EntryKind) -> Self {
+        self.kind = Some(value);
+        self
+    }
+
+    /// Only return entries whose names have one of the given extensions.
+    pub fn with_extensions(mut self, value: Vec<String>) -> Self {
+        self.extensions = Some(value);
+        self
+    }
+
+    /// Only return files which the predicate returns true for. Folders and deleted entries are
+    /// never returned when this is set.
+    ///
+    /// With [`list_folder_raw`], this fully deserializes each file's metadata to check it. Files
+    /// whose metadata can't be deserialized are returned, so that the error is seen when parsing
+    /// them.
+    pub fn with_file_predicate(
+        mut self,
+        predicate: impl Fn(&files::FileMetadata) -> bool + Send + Sync + 'static,
+    ) -> Self {
+        self.file_predicate = Some(Arc::new(predicate));
+        self
+    }
+
+    /// Adjust a listing's argument to have the server do what filtering it can.
+    fn adjust(&self, arg: &mut files::ListFolderArg) {
+        match self.kind {
+            Some(EntryKind::Deleted) => arg.include_deleted = true,
+            Some(EntryKind::Folder) => arg.include_non_downloadable_files = false,
+            Some(EntryKind::File) | None => (),
+        }
+    }
+
+    fn matches<M: ListedMetadata>(&self, metadata: &M) -> bool {
+        let kind = metadata.kind();
+        if self.kind.map_or(false, |k| k != kind) {
+            return false;
+        }
+        if let Some(extensions) = &self.extensions {
+            let extension = metadata.name().rsplit_once('.').map(|(_, ext)| ext);
+            let matched = kind != EntryKind::Folder
+                && extension.map_or(false,
+                    |ext| extensions.iter().any(|e| e.eq_ignore_ascii_case(ext)));
+            if !matched {
+                return false;
+            }
+        }
+        match &self.file_predicate {
+            Some(predicate) => kind == EntryKind::File && metadata.file_matches(&**predicate),
+            None => true,
+        }
+    }
+}
+
+impl std::fmt::Debug for EntryFilter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("EntryFilter")
+            .field("kind", &self.kind)
+            .field("extensions", &self.extensions)
+            .field("file_predicate", &self.file_predicate.as_ref().map(|_| "<fn>"))
+            .finish()
+    }
+}
+
+/// The metadata of an entry in a listing, as [`EntryFilter`] checks it.
+trait ListedMetadata {
+    fn kind(&self) -> EntryKind;
+    fn name(&self) -> &str;
+    fn file_matches(&self, predicate: &dyn Fn(&files::FileMetadata) -> bool) -> bool;
+}
+
+impl ListedMetadata for files::Metadata {
+    fn kind(&self) -> EntryKind {
+        match self {
+            files::Metadata::File(_) => EntryKind::File,
+            files::Metadata::Folder(_) => EntryKind::Folder,
+            files::Metadata::Deleted(_) => EntryKind::Deleted,
+        }
+    }
+
+    fn name(&self) -> &str {
+        match self {
+            files::Metadata::File(file) => &file.name,
+            files::Metadata::Folder(folder) => &folder.name,
+            files::Metadata::Deleted(deleted) => &deleted.name,
+        }
+    }
+
+    fn file_matches(&self, predicate: &dyn Fn(&files::FileMetadata) -> bool) -> bool {
+        matches!(self, files::Metadata::File(file) if predicate(file))
+    }
+}
+
+impl ListedMetadata for RawMetadata {
+    fn kind(&self) -> EntryKind {
+        if self.is_file() {
+            EntryKind::File
+        } else if self.is_folder() {
+            EntryKind::Folder
+        } else {
+            EntryKind::Deleted
+        }
+    }
+
+    fn name(&self) -> &str {
+        RawMetadata::name(self)
+    }
+
+    fn file_matches(&self, predicate: &dyn Fn(&files::FileMetadata) -> bool) -> bool {
+        match self.parse() {
+            Ok(metadata) => metadata.file_matches(predicate),
+            Err(_) => true,
+        }
+    }
+}
+
+/// An entry returned by [`list_folder`], or by [`list_folder_raw`] with [`RawMetadata`].
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub struct ListFolderEntry<M = files::Metadata> {
+    /// The entry's metadata.
+    pub metadata: M,
+
+    /// How many times the listing had been restarted because its cursor was invalidated, when
+    /// this entry was returned. When this changes, the listing has started over, so anything
+    /// built up from the earlier entries should be discarded.
+    pub resets: u32,
+}
+
+/// A [`files::Metadata`] which is only fully deserialized on demand, with [`parse`](Self::parse).
+///
+/// Only the tag, name, and paths are deserialized up front; the rest is kept as raw JSON. This
+/// saves work when listing large folders where most entries are filtered out by name or path.
+#[derive(Debug, Clone)]
+pub struct RawMetadata {
+    json: Box<RawValue>,
+    header: RawMetadataHeader,
+}
+
+#[derive(Debug, Clone, serde::Deserialize)]
+struct RawMetadataHeader {
+    #[serde(rename = ".tag")]
+    tag: String,
+    name: String,
+    path_lower: Option<String>,
+    path_display: Option<String>,
+}
+
+impl RawMetadata {
+    /// The last component of the path, including the extension.
+    pub fn name(&self) -> &str {
+        &self.header.name
+    }
+
+    /// The lowercased full path, if available.
+    pub fn path_lower(&self) -> Option<&str> {
+        self.header.path_lower.as_deref()
+    }
+
+    /// The cased full path, if available.
+    pub fn path_display(&self) -> Option<&str> {
+        self.header.path_display.as_deref()
+    }
+
+    /// Whether this is a file.
+    pub fn is_file(&self) -> bool {
+        self.header.tag == "file"
+    }
+
+    /// Whether this is a folder.
+    pub fn is_folder(&self) -> bool {
+        self.header.tag == "folder"
+    }
+
+    /// Whether this is a deleted file or folder.
+    pub fn is_deleted(&self) -> bool {
+        self.header.tag == "deleted"
+    }
+
+    /// The raw JSON of the metadata.
+    pub fn json(&self) -> &str {
+        self.json.get()
+    }
+
+    /// Deserialize the full metadata.
+    pub fn parse(&self) -> Result<files::Metadata, serde_json::Error> {
+        serde_json::from_str(self.json.get())
+    }
+}
+
+impl PartialEq for RawMetadata {
+    fn eq(&self, other: &Self) -> bool {
+        self.json.get() == other.json.get()
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for RawMetadata {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let json = Box::<RawValue>::deserialize(deserializer)?;
+        let header = serde_json::from_str(json.get()).map_err(serde::de::Error::custom)?;
+        Ok(Self { json, header })
+    }
+}
+
+/// List the contents of a folder as a stream of entries, following the cursor through all the
+/// pages of `files/list_folder` and `files/list_folder/continue`.
+///
+/// The stream ends after the first error.
+pub fn list_folder<'a>(
+    client: &'a impl UserAuthClient,
+    arg: files::ListFolderArg,
+    options: &ListFolderOptions,
+) -> impl Stream<Item = Result<ListFolderEntry, BoxedError>> + Send + 'a {
+    list_folder_impl(client, arg, options)
+}
+
+/// Like [`list_folder`], but the entries are [`RawMetadata`], which is only fully deserialized
+/// when needed.
+pub fn list_folder_raw<'a>(
+    client: &'a impl UserAuthClient,
+    arg: files::ListFolderArg,
+    options: &ListFolderOptions,
+) -> impl Stream<Item = Result<ListFolderEntry<RawMetadata>, BoxedError>> + Send + 'a {
+    list_folder_impl(client, arg, options)
+}
+
+/// The total size and number of entries in a folder and all its subfolders, from
+/// [`folder_stats`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct FolderStats {
+    /// The total size of all the files, in bytes.
+    pub total_bytes: u64,
+
+    /// The number of files.
+    pub files: u64,
+
+    /// The number of folders, not counting the folder itself.
+    pub folders: u64,
+}
+
+/// Options for [`folder_stats`].
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub struct FolderStatsOptions {
+    /// How many folders to list at once. Defaults to 4.
+    pub concurrency: usize,
+}
+
+impl Default for FolderStatsOptions {
+    fn default() -> Self {
+        Self {
+            concurrency: 4,
+        }
+    }
+}
+
+impl FolderStatsOptions {
+    /// Set how many folders to list at once.
+    pub fn with_concurrency(mut self, value: usize) -> Self {
+        self.concurrency = value;
+        self
+    }
+}
+
+/// Add up the sizes of all the files in a folder and its subfolders, and count them and the
+/// subfolders.
+///
+/// Each folder is listed separately, with up to [`concurrency`](FolderStatsOptions::concurrency)
+/// of them being listed at once, so large trees with many subfolders are walked faster than with
+/// a single recursive [`list_folder`]. If a folder's listing has to be restarted because its
+/// cursor was reset, the counting of that folder starts again too.
+pub async fn folder_stats(
+    client: &impl UserAuthClient,
+    path: &str,
+    options: &FolderStatsOptions,
+) -> Result<FolderStats, BoxedError> {
+    let mut stats = FolderStats::default();
+    let mut pending = vec![path.to_owned()];
+    let mut listing = futures::stream::FuturesUnordered::new();
+    loop {
+        while listing.len() < options.concurrency.max(1) {
+            let Some(folder) = pending.pop() else {
+                break;
+            };
+            listing.push(folder_contents(client, folder));
+        }
+        let Some(contents) = listing.next().await else {
+            break;
+        };
+        let (contents, subfolders) = contents?;
+        stats.total_bytes += contents.total_bytes;
+        stats.files += contents.files;
+        stats.folders += subfolders.len() as u64;
+        pending.extend(subfolders);
+    }
+    Ok(stats)
+}
+
+/// List one folder for [`folder_stats`], returning the sizes and number of the files directly in
+/// it, and the IDs of its subfolders.
+async fn folder_contents(client: &impl UserAuthClient, path: String)
+    -> Result<(FolderStats, Vec<String>), BoxedError>
+{
+    let path_lower = path.to_lowercase();
+    let arg = files::ListFolderArg::new(path.clone());
+    let mut stats = FolderStats::default();
+    let mut subfolders = Vec::new();
+    let mut resets = 0;
+    let mut entries = Box::pin(list_folder(client, arg, &ListFolderOptions::default()));
+    while let Some(entry) = entries.try_next().await? {
+        if entry.resets != resets {
+            resets = entry.resets;
+            stats = FolderStats::default();
+            subfolders.clear();
+        }
+        match entry.metadata {
+            files::Metadata::File(file) => {
+                stats.files += 1;
+                stats.total_bytes += file.size;
+            }
+            // Skip the folder itself, in case it's listed.
+            files::Metadata::Folder(folder)
+                if folder.id == path || folder.path_lower.as_deref() == Some(&path_lower) => {}
+            files::Metadata::Folder(folder) => subfolders.push(folder.id),
+            files::Metadata::Deleted(_) => (),
+        }
+    }
+    Ok((stats, subfolders))
+}
+
+/// Files in a folder tree which all have the same contents, from [`find_duplicates`].
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub struct DuplicateGroup {
+    /// The files' [`content_hash`](files::FileMetadata::content_hash).
+    pub content_hash: String,
+
+    /// The size of each of the files, in bytes.
+    pub size: u64,
+
+    /// The files, in the order they were listed. There are always at least two.
+    pub files: Vec<files::FileMetadata>,
+}
+
+impl DuplicateGroup {
+    /// How much space would be freed by keeping only one of the files.
+    pub fn redundant_bytes(&self) -> u64 {
+        self.size * (self.files.len() as u64 - 1)
+    }
+}
+
+/// Find files in a folder and its subfolders which have the same contents, going by their content
+/// hash and size, as a stream of groups of them.
+///
+/// This uses a single recursive [`list_folder`] with the given options, so a filter can narrow
+/// down which files are considered. Empty files, and files without a content hash, are left out.
+/// The groups can only be known once the whole tree has been listed, so the first one is yielded
+/// after the last page, and the rest follow in the order their first file was listed. If the
+/// listing has to be restarted because its cursor was reset, the grouping starts again too.
+///
+/// The stream ends after the first error.
+pub fn find_duplicates<'a>(
+    client: &'a impl UserAuthClient,
+    path: &str,
+    options: &ListFolderOptions,
+) -> impl Stream<Item = Result<DuplicateGroup, BoxedError>> + Send + 'a {
+    let arg = files::ListFolderArg::new(path.to_owned()).with_recursive(true);
+    let mut entries = Box::pin(list_folder(client, arg, options));
+    futures::stream::once(async move {
+        let mut groups = Vec::<DuplicateGroup>::new();
+        let mut index = std::collections::HashMap::<(String, u64), usize>::new();
+        let mut resets = 0;
+        while let Some(entry) = entries.try_next().await? {
+            if entry.resets != resets {
+                resets = entry.resets;
+                groups.clear();
+                index.clear();
+            }
+            let files::Metadata::File(file) = entry.metadata else {
+                continue;
+            };
+            let Some(hash) = file.content_hash.clone().filter(|_| file.size > 0) else {
+                continue;
+            };
+            let i = *index.entry((hash.clone(), file.size)).or_insert_with(|| {
+                groups.push(DuplicateGroup { content_hash: hash, size: file.size, files: vec![] });
+                groups.len() - 1
+            });
+            groups[i].files.push(file);
+        }
+        groups.retain(|group| group.files.len() > 1);
+        Ok::<_, BoxedError>(futures::stream::iter(groups.into_iter().map(Ok)))
+    })
+    .try_flatten()
+}
+
+/// Options for [`search_files`].
+#[derive(Debug, Clone, Default)]
+#[non_exhaustive]
+pub struct SearchFilesOptions {
+    /// Match the query against the contents of files, as well as their names. Defaults to false.
+    ///
+    /// Searching file contents is only available on some plans; on others, only names are
+    /// matched either way.
+    pub match_contents: bool,
+
+    /// Search for deleted files and folders, instead of ones which currently exist. Defaults to
+    /// false.
+    pub deleted: bool,
+
+    /// Only return files with one of these extensions, such as `"jpg"`. Defaults to any.
+    ///
+    /// This can't be used together with [`deleted`](Self::deleted).
+    pub extensions: Option<Vec<String>>,
+
+    /// Only return files and folders in one of these categories. Defaults to any.
+    ///
+    /// This can't be used together with [`deleted`](Self::deleted).
+    pub categories: Option<Vec<files::FileCategory>>,
+
+    /// How many results to ask for in each page. Defaults to the most `files/search_v2` allows;
+    /// see [`pagination`].
+    pub page_size: Option<u32>,
+}
+
+impl SearchFilesOptions {
+    /// Set whether to match the query against the contents of files.
+    pub fn with_match_contents(mut self, value: bool) -> Self {
+        self.match_contents = value;
+        self
+    }
+
+    /// Set whether to search for deleted files and folders.
+    pub fn with_deleted(mut self, value: bool) -> Self {
+        self.deleted = value;
+        self
+    }
+
+    /// Only return files with one of the given extensions.
+    pub fn with_extensions(mut self, value: Vec<String>) -> Self {
+        self.extensions = Some(value);
+        self
+    }
+
+    /// Only return files and folders in one of the given categories.
+    pub fn with_categories(mut self, value: Vec<files::FileCategory>) -> Self {
+        self.categories = Some(value);
+        self
+    }
+
+    /// Set how many results to ask for in each page.
+    pub fn with_page_size(mut self, value: u32) -> Self {
+        self.page_size = Some(value);
+        self
+    }
+}
+
+/// Search for files and folders whose names match the query, as a stream of their metadata,
+/// following the cursor through all the pages of `files/search_v2` and
+/// `files/search/continue_v2`.
+///
+/// Only the folder given by `path_scope` and its subfolders are searched, or the whole Dropbox if
+/// it's `None`. Only files and folders which currently exist are returned; use [`search_files`]
+/// for more options. Results are in order of relevance.
+///
+/// The stream ends after the first error.
+pub fn search_filenames<'a>(
+    client: &'a impl UserAuthClient,
+    query: impl Into<String>,
+    path_scope: Option<&str>,
+) -> impl Stream<Item = Result<files::Metadata, BoxedError>> + Send + 'a {
+    search_files(client, query, path_scope, &SearchFilesOptions::default())
+}
+
+/// Like [`search_filenames`], but with options for matching file contents, searching deleted
+/// files, and filtering the results by type.
+pub fn search_files<'a>(
+    client: &'a impl UserAuthClient,
+    query: impl Into<String>,
+    path_scope: Option<&str>,
+    options: &SearchFilesOptions,
+) -> impl Stream<Item = Result<files::Metadata, BoxedError>> + Send + 'a {
+    struct State<'a, C> {
+        client: &'a C,
+        arg: files::SearchV2Arg,
+        cursor: Option<String>,
+        has_more: bool,
+        matches: std::vec::IntoIter<files::SearchMatchV2>,
+        done: bool,
+    }
+
+    let mut search_options = files::SearchOptions::default()
+        .with_max_results(u64::from(pagination::page_size("files/search_v2", options.page_size)))
+        .with_filename_only(!options.match_contents)
+        .with_file_status(if options.deleted {
+            files::FileStatus::Deleted
+        } else {
+            files::FileStatus::Active
+        });
+    search_options.path = path_scope.map(ToOwned::to_owned);
+    search_options.file_extensions = options.extensions.clone();
+    search_options.file_categories = options.categories.clone();
+
+    let state = State {
+        client,
+        arg: files::SearchV2Arg::new(query.into()).with_options(search_options),
+        cursor: None,
+        has_more: true,
+        matches: vec![].into_iter(),
+        done: false,
+    };
+
+    futures::stream::unfold(state, |mut state| async move {
+        loop {
+            if state.done {
+                return None;
+            }
+            for search_match in state.matches.by_ref() {
+                match search_match.metadata {
+                    files::MetadataV2::Metadata(metadata) => return Some((Ok(metadata), state)),
+                    files::MetadataV2::Other(x) => {
+                        warn!("skipping a search match of a kind unknown to this SDK version: {:?}",
+                            x.tag());
+                    }
+                }
+            }
+            if !state.has_more {
+                return None;
+            }
+
+            let page = match state.cursor.take() {
+                None => files::search_v2(state.client, &state.arg).await,
+                Some(cursor) => {
+                    let arg = files::SearchV2ContinueArg::new(cursor);
+                    files::search_continue_v2(state.client, &arg).await
+                }
+            };
+            match page {
+                Ok(page) => {
+                    state.matches = page.matches.into_iter();
+                    state.has_more = page.has_more && page.cursor.is_some();
+                    state.cursor = page.cursor;
+                }
+                Err(e) => {
+                    state.done = true;
+                    return Some((Err(e.boxed()), state));
+                }
+            }
+        }
+    })
+}
+
+/// A page of `files/list_folder` results, with the entries as `M`.
+#[derive(serde::Deserialize)]
+struct ListFolderPage<M> {
+    entries: Vec<M>,
+    cursor: String,
+    has_more: bool,
+}
+
+fn list_folder_impl<'a, M: DeserializeOwned + ListedMetadata + Send + 'a>(
+    client: &'a impl UserAuthClient,
+    mut arg: files::ListFolderArg,
+    options: &ListFolderOptions,
+) -> impl Stream<Item = Result<ListFolderEntry<M>, BoxedError>> + Send + 'a {
+    struct State<'a, C, M> {
+        client: &'a C,
+        arg: files::ListFolderArg,
+        restart_on_reset: bool,
+        stream_entries: bool,
+        adaptive_page_size: bool,
+        cursor: Option<String>,
+        has_more: bool,
+        entries: std::vec::IntoIter<M>,
+        reader: Option<PageReader>,
+        resets: u32,
+        retry: RetryScope,
+        done: bool,
+    }
+
+    arg.limit = Some(pagination::page_size("files/list_folder", options.page_size.or(arg.limit)));
+    options.filter.adjust(&mut arg);
+    let filter = options.filter.clone();
+    let state = State {
+        client,
+        arg,
+        restart_on_reset: options.restart_on_reset,
+        stream_entries: options.stream_entries,
+        adaptive_page_size: options.adaptive_page_size,
+        cursor: None,
+        has_more: true,
+        entries: vec![].into_iter(),
+        reader: None,
+        resets: 0,
+        retry: options.retry_budget.start(),
+        done: false,
+    };
+
+    futures::stream::unfold(state, |mut state| async move {
+        loop {
+            if state.done {
+                return None;
+            }
+            if let Some(metadata) = state.entries.next() {
+                let entry = ListFolderEntry { metadata, resets: state.resets };
+                return Some((Ok(entry), state));
+            }
+            if let Some(reader) = &mut state.reader {
+                match reader.next_entry().await {
+                    Ok(Some(metadata)) => {
+                        let entry = ListFolderEntry { metadata, resets: state.resets };
+                        return Some((Ok(entry), state));
+                    }
+                    Ok(None) => match reader.finish() {
+                        Ok((cursor, has_more)) => {
+                            state.cursor = Some(cursor);
+                            state.has_more = has_more;
+                            state.reader = None;
+                        }
+                        Err(e) => {
+                            state.done = true;
+                            return Some((Err(e.boxed()), state));
+                        }
+                    },
+                    Err(e) => {
+                        state.done = true;
+                        return Some((Err(e.boxed()), state));
+                    }
+                }
+            }
+            if !state.has_more {
+                return None;
+            }
+
+            // Borrow the fields separately, as the state as a whole isn't `Sync`.
+            let (client, list_arg, stream_entries) =
+                (state.client, &state.arg, state.stream_entries);
+            let page = match &state.cursor {
+                None => match state.retry.call(|| list_folder_page::<_, files::ListFolderError, _>(
+                    client, "files/list_folder", list_arg, stream_entries)).await
+                {
+                    Err(e) if state.adaptive_page_size
+                        && state.arg.limit > Some(MIN_ADAPTIVE_PAGE_SIZE)
+                        && page_too_big(&e) =>
+                    {
+                        let limit = (state.arg.limit.unwrap() / 2).max(MIN_ADAPTIVE_PAGE_SIZE);
+                        warn!("list_folder failed; asking for pages of {} entries instead", limit);
+                        state.arg.limit = Some(limit);
+                        continue;
+                    }
+                    result => result.map_err(Error::boxed),
+                },
+                Some(cursor) => {
+                    let arg = files::ListFolderContinueArg::new(cursor.clone());
+                    match state.retry.call(|| list_folder_page(client,
+                        "files/list_folder/continue", &arg, stream_entries)).await
+                    {
+                        Err(Error::Api(files::ListFolderContinueError::Reset))
+                            if state.restart_on_reset
+                                && state.resets < LIST_FOLDER_MAX_RESTARTS =>
+                        {
+                            warn!("list_folder cursor was reset; restarting the listing");
+                            state.cursor = None;
+                            state.resets += 1;
+                            continue;
+                        }
+                        result => result.map_err(Error::boxed),
+                    }
+                }
+            };
+            match page {
+                Ok(PageResponse::Parsed(page)) => {
+                    state.entries = page.entries.into_iter();
+                    state.cursor = Some(page.cursor);
+                    state.has_more = page.has_more;
+                }
+                Ok(PageResponse::Streaming(reader)) => state.reader = Some(reader),
+                Err(e) => {
+                    state.done = true;
+                    return Some((Err(e), state));
+                }
+            }
+        }
+    })
+    .try_filter(move |entry| futures::future::ready(filter.matches(&entry.metadata)))
+}
+
+/// The most times [`list_folder`] restarts a listing whose cursor was reset, with
+/// [`ListFolderOptions::restart_on_reset`], before giving up and returning the error. A server
+/// which keeps resetting the cursor would otherwise keep the listing going forever.
+pub const LIST_FOLDER_MAX_RESTARTS: u32 = 5;
+
+/// The smallest page size which [`ListFolderOptions::adaptive_page_size`] shrinks pages to.
+const MIN_ADAPTIVE_PAGE_SIZE: u32 = 100;
+
+/// Whether a failed page may have been too big for the server to list in time: a server error or
+/// a timeout. Other errors, such as failing to connect, aren't helped by asking for less.
+fn page_too_big<E>(e: &Error<E>) -> bool {
+    match e {
+        Error::ServerError(_) => true,
+        Error::UnexpectedHttpError { code, .. } => *code >= 500,
+        Error::HttpClient(e) => {
+            let mut source: Option<&(dyn std::error::Error + 'static)> = Some(e.as_ref());
+            while let Some(e) = source {
+                if e.downcast_ref::<std::io::Error>()
+                    .map_or(false, |e| e.kind() == std::io::ErrorKind::TimedOut)
+                {
+                    return true;
+                }
+                #[cfg(feature = "default_async_client")]
+                if e.downcast_ref::<reqwest::Error>().map_or(false, reqwest::Error::is_timeout) {
+                    return true;
+                }
+                source = e.source();
+            }
+            false
+        }
+        _ => false,
+    }
+}
+
+enum PageResponse<M> {
+    Parsed(ListFolderPage<M>),
+    Streaming(PageReader),
+}
+
+/// Request a page of `files/list_folder` results, either parsed in full or ready to be parsed
+/// incrementally.
+async fn list_folder_page<M, E, P>(
+    client: &impl UserAuthClient,
+    route: &str,
+    arg: &P,
+    stream_entries: bool,
+) -> Result<PageResponse<M>, Error<E>>
+where
+    M: DeserializeOwned,
+    E: DeserializeOwned + std::error::Error + Send + Sync + 'static,
+    P: serde::Serialize,
+{
+    if !stream_entries {
+        return request(client, Endpoint::Api, Style::Rpc, route, arg, None).await
+            .map(PageResponse::Parsed);
+    }
+    let params = Bytes::from(serde_json::to_vec(arg)?);
+    let response = request_raw(client, Endpoint::Api, Style::Rpc, route, params, ParamsType::Json,
+        Bytes::new()).await?;
+    Ok(PageResponse::Streaming(PageReader::new(route, response.body)))
+}
+
+/// How many bytes of a page to read from the response at a time, when streaming entries.
+const PAGE_READ_SIZE: usize = 64 * 1024;
+
+/// Where a [`PageReader`] is in the page's JSON object.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum PageState {
+    Start,
+    Key,
+    Entries { first: bool },
+    Done,
+}
+
+/// Parses a page of `files/list_folder` results from a response body one entry at a time, keeping
+/// only the part of the body which hasn't been parsed yet in memory.
+///
+/// The page's object is walked by hand, and each value in it, including each entry of the
+/// `entries` array, is deserialized with a [`serde_json::StreamDeserializer`] over the buffered
+/// part of the body, reading more of it whenever a value is incomplete.
+struct PageReader {
+    route: String,
+    body: Box<dyn AsyncRead + Send + Unpin>,
+    buf: Vec<u8>,
+    pos: usize,
+    eof: bool,
+    state: PageState,
+    cursor: Option<String>,
+    has_more: Option<bool>,
+}
+
+impl PageReader {
+    fn new(route: &str, body: Box<dyn AsyncRead + Send + Unpin>) -> Self {
+        Self {
+            route: route.to_owned(),
+            body,
+            buf: Vec::new(),
+            pos: 0,
+            eof: false,
+            state: PageState::Start,
+            cursor: None,
+            has_more: None,
+        }
+    }
+
+    /// Parse the next entry of the page, or return `None` once the whole page has been parsed.
+    async fn next_entry<M: DeserializeOwned>(&mut self) -> Result<Option<M>, Error> {
+        loop {
+            match self.state {
+                PageState::Start => {
+                    self.expect(b'{').await?;
+                    if self.peek().await? == Some(b'}') {
+                        self.pos += 1;
+                        self.state = PageState::Done;
+                    } else {
+                        self.state = PageState::Key;
+                    }
+                }
+                PageState::Key => {
+                    let key: String = self.value().await?;
+                    self.expect(b':').await?;
+                    match key.as_str() {
+                        "entries" => {
+                            self.expect(b'[').await?;
+                            self.state = PageState::Entries { first: true };
+                            continue;
+                        }
+                        "cursor" => self.cursor = Some(self.value().await?),
+                        "has_more" => self.has_more = Some(self.value().await?),
+                        _ => {
+                            self.value::<serde::de::IgnoredAny>().await?;
+                        }
+                    }
+                    self.end_field().await?;
+                }
+                PageState::Entries { first } => {
+                    if self.peek().await? == Some(b']') {
+                        self.pos += 1;
+                        self.end_field().await?;
+                        continue;
+                    }
+                    if !first {
+                        self.expect(b',').await?;
+                    }
+                    let entry = self.value().await?;
+                    self.state = PageState::Entries { first: false };
+                    return Ok(Some(entry));
+                }
+                PageState::Done => return Ok(None),
+            }
+        }
+    }
+
+    /// The page's cursor and `has_more` flag, once all its entries have been parsed.
+    fn finish(&self) -> Result<(String, bool), Error> {
+        match (&self.cursor, self.has_more) {
+            (Some(cursor), Some(has_more)) => Ok((cursor.clone(), has_more)),
+            _ => Err(Error::UnexpectedResponse(format!(
+                "{} response is missing its cursor or has_more", self.route))),
+        }
+    }
+
+    /// After a field's value, move on to the next field, or to the end of the object.
+    async fn end_field(&mut self) -> Result<(), Error> {
+        match self.peek().await? {
+            Some(b',') => self.state = PageState::Key,
+            Some(b'}') => self.state = PageState::Done,
+            other => return Err(self.unexpected(other)),
+        }
+        self.pos += 1;
+        Ok(())
+    }
+
+    async fn expect(&mut self, expected: u8) -> Result<(), Error> {
+        match self.peek().await? {
+            Some(c) if c == expected => {
+                self.pos += 1;
+                Ok(())
+            }
+            other => Err(self.unexpected(other)),
+        }
+    }
+
+    /// Skip whitespace, and return the next character without consuming it, or `None` at the end
+    /// of the body.
+    async fn peek(&mut self) -> Result<Option<u8>, Error> {
+        loop {
+            while let Some(&c) = self.buf.get(self.pos) {
+                if !matches!(c, b' ' | b'\t' | b'\n' | b'\r') {
+                    return Ok(Some(c));
+                }
+                self.pos += 1;
+            }
+            if self.eof {
+                return Ok(None);
+            }
+            self.fill().await?;
+        }
+    }
+
+    /// Deserialize the next value, reading more of the body until it's all there.
+    async fn value<T: DeserializeOwned>(&mut self) -> Result<T, Error> {
+        loop {
+            self.peek().await?;
+            let mut values = serde_json::Deserializer::from_slice(&self.buf[self.pos..])
+                .into_iter::<T>();
+            let result = values.next();
+            let end = self.pos + values.byte_offset();
+            match result {
+                // A number at the end of the buffer might continue in the part not read yet.
+                Some(Ok(value)) if end < self.buf.len() || self.eof => {
+                    self.pos = end;
+                    return Ok(value);
+                }
+                Some(Err(e)) if !e.is_eof() || self.eof => {
+                    let snippet = String::from_utf8_lossy(&self.buf[self.pos..]);
+                    return Err(crate::client_helpers::deserialize_error(&self.route, e, &snippet));
+                }
+                None if self.eof => return Err(self.unexpected(None)),
+                _ => self.fill().await?,
+            }
+        }
+    }
+
+    /// Read more of the body into the buffer, dropping the part which has been parsed already.
+    async fn fill(&mut self) -> Result<(), Error> {
+        self.buf.drain(..self.pos);
+        self.pos = 0;
+        let len = self.buf.len();
+        self.buf.resize(len + PAGE_READ_SIZE, 0);
+        let result = self.body.read(&mut self.buf[len..]).await;
+        let read = match result {
+            Ok(read) => read,
+            Err(e) => {
+                self.buf.truncate(len);
+                return Err(Error::HttpClient(Box::new(e)));
+            }
+        };
+        self.buf.truncate(len + read);
+        self.eof = read == 0;
+        Ok(())
+    }
+
+    fn unexpected(&self, found: Option<u8>) -> Error {
+        Error::UnexpectedResponse(match found {
+            Some(c) => format!("unexpected {:?} in {} response", char::from(c), self.route),
+            None => format!("{} response ended early", self.route),
+        })
+    }
+}
+
+/// Wait for changes in a folder listing, using `files/list_folder/longpoll`.
+///
+/// This returns once there are changes since the given cursor, or after roughly the given timeout;
+/// if [`changes`](files::ListFolderLongpollResult::changes) is set, get them with
+/// `files/list_folder/continue`. The timeout is clamped to the 30 to 480 seconds the API allows,
+/// and the server adds up to 90 seconds of random jitter to it. If the result has a
+/// [`backoff`](files::ListFolderLongpollResult::backoff), wait that many seconds before calling
+/// this again.
+///
+/// Longpoll requests go to the [notify endpoint](crate::client_trait_common::Endpoint::Notify),
+/// which doesn't take authentication, so any client can be used, and no access token is sent even
+/// if the client has one. These requests are made with a timeout of
+/// [`NOTIFY_TIMEOUT`](crate::client_trait_common::NOTIFY_TIMEOUT) instead of the client's usual
+/// one; custom clients need to implement
+/// [`HttpRequest::set_timeout`](crate::client_trait_common::HttpRequest::set_timeout) for that.
+pub async fn longpoll(
+    client: &impl NoauthClient,
+    cursor: impl Into<String>,
+    timeout: Duration,
+) -> Result<files::ListFolderLongpollResult, Error<files::ListFolderLongpollError>> {
+    let arg = files::ListFolderLongpollArg::new(cursor.into())
+        .with_timeout(timeout.as_secs().clamp(30, 480));
+    files::list_folder_longpoll(client, &arg).await
+}
+
+/// Options for [`watch_changes`] and [`forward_changes`].
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub struct WatchOptions {
+    /// How long each longpoll request waits for changes before being made again. Defaults to the
+    /// API's maximum of 480 seconds; see [`longpoll`].
+    pub longpoll_timeout: Duration,
+
+    /// The clock used to wait out any backoff the server asks for. Defaults to the
+    /// [`SystemClock`](crate::clock::SystemClock).
+    pub clock: Arc<dyn Clock>,
+}
+
+impl Default for WatchOptions {
+    fn default() -> Self {
+        Self {
+            longpoll_timeout: Duration::from_secs(480),
+            clock: Arc::new(SystemClock),
+        }
+    }
+}
+
+impl WatchOptions {
+    /// Set how long each longpoll request waits for changes.
+    pub fn with_longpoll_timeout(mut self, timeout: Duration) -> Self {
+        self.longpoll_timeout = timeout;
+        self
+    }
+
+    /// Set the clock used to wait out backoffs.
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+}
+
+/// A change in a folder, from [`watch_changes`] or [`forward_changes`].
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub struct FolderChange {
+    /// The changed entry. Deleted files and folders are [`files::Metadata::Deleted`].
+    pub metadata: files::Metadata,
+
+    /// A cursor to resume watching from once this change has been handled.
+    ///
+    /// Cursors only move forward a page of changes at a time, so this is the cursor from before
+    /// this change's page, except for the last change in a page, which has the cursor from after
+    /// it. Resuming from a saved cursor may deliver some changes again, but never skips any.
+    pub cursor: String,
+}
+
+/// Watch a folder for changes, as a stream of the entries which changed since the given cursor.
+///
+/// The cursor comes from `files/list_folder` or `files/list_folder/get_latest_cursor`. When there
+/// are no more changes, this waits for some with [`longpoll`], and then gets them with
+/// `files/list_folder/continue`, forever. Nothing more is requested from the server until the
+/// changes already fetched have been taken from the stream.
+///
+/// The stream ends after the first error. If the cursor has expired, the error is
+/// [`Reset`](files::ListFolderContinueError::Reset), and the folder needs to be listed again from
+/// scratch to get a new one.
+pub fn watch_changes<'a>(
+    client: &'a impl UserAuthClient,
+    cursor: impl Into<String>,
+    options: &WatchOptions,
+) -> impl Stream<Item = Result<FolderChange, BoxedError>> + Send + 'a {
+    struct State<'a, C> {
+        client: &'a C,
+        timeout: Duration,
+        clock: Arc<dyn Clock>,
+        // The cursor from before the current page, and the one from after it.
+        cursor: String,
+        next_cursor: String,
+        has_more: bool,
+        entries: std::iter::Peekable<std::vec::IntoIter<files::Metadata>>,
+        backoff: Option<Duration>,
+        done: bool,
+    }
+
+    let cursor = cursor.into();
+    let state = State {
+        client,
+        timeout: options.longpoll_timeout,
+        clock: options.clock.clone(),
+        cursor: cursor.clone(),
+        next_cursor: cursor,
+        has_more: false,
+        entries: vec![].into_iter().peekable(),
+        backoff: None,
+        done: false,
+    };
+
+    futures::stream::unfold(state, |mut state| async move {
+        loop {
+            if state.done {
+                return None;
+            }
+            if let Some(metadata) = state.entries.next() {
+                let cursor = if state.entries.peek().is_none() {
+                    state.next_cursor.clone()
+                } else {
+                    state.cursor.clone()
+                };
+                return Some((Ok(FolderChange { metadata, cursor }), state));
+            }
+            state.cursor = state.next_cursor.clone();
+
+            if !state.has_more {
+                if let Some(backoff) = state.backoff.take() {
+                    state.clock.sleep(backoff).await;
+                }
+                // Longpolling goes to the notify endpoint, which doesn't use the client's
+                // authentication, so a user client can make the request too.
+                let arg = files::ListFolderLongpollArg::new(state.cursor.clone())
+                    .with_timeout(state.timeout.as_secs().clamp(30, 480));
+                let result = request::<files::ListFolderLongpollResult,
+                    files::ListFolderLongpollError, _>(
+                    state.client, Endpoint::Notify, Style::Rpc, "files/list_folder/longpoll", &arg,
+                    None).await;
+                match result {
+                    Ok(result) => {
+                        state.backoff = result.backoff.map(Duration::from_secs);
+                        if !result.changes {
+                            continue;
+                        }
+                    }
+                    Err(e) => {
+                        state.done = true;
+                        return Some((Err(e.boxed()), state));
+                    }
+                }
+            }
+
+            let arg = files::ListFolderContinueArg::new(state.cursor.clone());
+            match files::list_folder_continue(state.client, &arg).await {
+                Ok(page) => {
+                    state.next_cursor = page.cursor;
+                    state.has_more = page.has_more;
+                    state.entries = page.entries.into_iter().peekable();
+                }
+                Err(e) => {
+                    state.done = true;
+                    return Some((Err(e.boxed()), state));
+                }
+            }
+        }
+    })
+}
+
+/// An error which stopped [`forward_changes`].
+#[derive(thiserror::Error, Debug)]
+pub enum ForwardError<E> {
+    /// Watching for changes failed.
+    #[error("error watching for changes: {0}")]
+    Watch(#[source] BoxedError),
+
+    /// The sink failed to accept a change.
+    #[error("error sending a change to the sink: {0}")]
+    Sink(#[source] E),
+}
+
+/// Send the changes in a folder since the given cursor into a [`Sink`], as they happen.
+///
+/// This is [`watch_changes`] forwarded into the sink, which is flushed whenever there are no more
+/// changes ready. It runs until either side fails. The sink can be anything implementing
+/// [`Sink`], such as a [`futures::channel::mpsc::Sender`] or a wrapper around a message queue's
+/// producer. New changes are only fetched once the sink has room for them, so a slow consumer
+/// holds back the watcher rather than letting changes pile up in memory.
+///
+/// To resume after a restart, save the [`cursor`](FolderChange::cursor) of the changes once the
+/// consumer has handled them, and pass the last one saved here.
+pub async fn forward_changes<S>(
+    client: &impl UserAuthClient,
+    cursor: impl Into<String>,
+    options: &WatchOptions,
+    sink: S,
+) -> Result<(), ForwardError<S::Error>>
+where
+    S: Sink<FolderChange> + Unpin,
+{
+    let changes = watch_changes(client, cursor, options).map_err(ForwardError::Watch);
+    let mut sink = sink.sink_map_err(ForwardError::Sink);
+    futures::pin_mut!(changes);
+    sink.send_all(&mut changes).await
+}
+
+/// Create a folder if it doesn't already exist, returning its metadata either way.
+///
+/// Like `files/create_folder_v2`, this creates any missing parent folders. Unlike it, a folder
+/// already existing at the path is not an error; anything else in the way still is.
+pub async fn ensure_folder(
+    client: &impl UserAuthClient,
+    path: impl Into<String>,
+) -> Result<files::FolderMetadata, BoxedError> {
+    let path = path.into();
+    let arg = files::CreateFolderArg::new(path.clone());
+    let conflict = match files::create_folder_v2(client, &arg).await {
+        Ok(result) => return Ok(result.metadata),
+        Err(e @ Error::Api(files::CreateFolderError::Path(
+            files::WriteError::Conflict(files::WriteConflictError::Folder)))) => e,
+        Err(e) => return Err(e.boxed()),
+    };
+    match files::get_metadata(client, &files::GetMetadataArg::new(path)).await {
+        Ok(files::Metadata::Folder(metadata)) => Ok(metadata),
+        // Something else replaced the folder in the meantime.
+        Ok(_) | Err(Error::Api(files::GetMetadataError::Path(files::LookupError::NotFound))) => {
+            Err(conflict.boxed())
+        }
+        Err(e) => Err(e.boxed()),
+    }
+}
+
+/// Make sure every folder along a path exists, creating any which don't, and return the metadata
+/// of each one, starting from the top.
+///
+/// For example, for `"/a/b/c"` this returns the metadata of `/a`, `/a/b` and `/a/b/c`. If a file
+/// is in the way at any level, the error is for that level. The path has to be a plain path
+/// starting with `/`, not an ID or namespace-relative path.
+pub async fn ensure_folders(
+    client: &impl UserAuthClient,
+    path: &str,
+) -> Result<Vec<files::FolderMetadata>, BoxedError> {
+    let mut folders = vec![];
+    let mut prefix = String::new();
+    for component in path.split('/').filter(|c| !c.is_empty()) {
+        prefix.push('/');
+        prefix.push_str(component);
+        folders.push(ensure_folder(client, prefix.clone()).await?);
+    }
+    Ok(folders)
+}
+
+/// Upload the contents of a local file.
+///
+/// Unless disabled in the options, the file's modification time is used as the `client_modified`
+/// time of the uploaded file.
+///
+/// Note that the file is read using blocking I/O.
+pub async fn upload_file(
+    client: &impl UserAuthClient,
+    local_path: impl AsRef<Path>,
+    mut commit: files::CommitInfo,
+    options: &UploadOptions,
+) -> Result<files::FileMetadata, TransferError> {
+    let file = std::fs::File::open(local_path)?;
+    if options.preserve_mtime && commit.client_modified.is_none() {
+        let mtime = file.metadata()?.modified()?;
+        commit.client_modified = Some(crate::timestamp::from_system_time(mtime));
+    }
+    upload(client, commit, futures::io::AllowStdIo::new(file), options).await
+}
+
+/// The result of [`upload_file_if_changed`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum UploadOutcome {
+    /// The file was uploaded.
+    Uploaded(files::FileMetadata),
+
+    /// A file with the same contents was already at the destination path, so nothing was
+    /// uploaded. This is its metadata.
+    AlreadyUpToDate(files::FileMetadata),
+}
+
+impl UploadOutcome {
+    /// The metadata of the file at the destination path, whether or not it was uploaded.
+    pub fn metadata(&self) -> &files::FileMetadata {
+        match self {
+            UploadOutcome::Uploaded(metadata) | UploadOutcome::AlreadyUpToDate(metadata) => metadata,
+        }
+    }
+}
+
+/// Upload the contents of a local file, unless the file at the destination path already has the
+/// same contents.
+///
+/// This first computes the local file's [content hash](crate::content_hash), and compares it with
+/// the destination's, using `files/get_metadata`. If they match, nothing is uploaded, even if
+/// other details such as the modification time differ. Otherwise, this is the same as
+/// [`upload_file`].
+///
+/// Note that the file is read using blocking I/O, once to hash it and again to upload it.
+pub async fn upload_file_if_changed(
+    client: &impl UserAuthClient,
+    local_path: impl AsRef<Path>,
+    mut commit: files::CommitInfo,
+    options: &UploadOptions,
+) -> Result<UploadOutcome, TransferError> {
+    commit.path = options.upload_path(commit.path);
+    let local_path = local_path.as_ref();
+    let local_hash = crate::content_hash::file_content_hash(local_path)?;
+    if let Some(files::Metadata::File(remote)) = metadata_opt(client, commit.path.as_str()).await? {
+        if remote.content_hash.as_deref() == Some(local_hash.as_str()) {
+            debug!("{} is already up to date", commit.path);
+            return Ok(UploadOutcome::AlreadyUpToDate(remote));
+        }
+    }
+    upload_file(client, local_path, commit, options).await.map(UploadOutcome::Uploaded)
+}
+
+/// Upload everything read from the given source.
+///
+/// If the source is smaller than [`UPLOAD_CHUNK_SIZE`], it is uploaded with a single request;
+/// otherwise it is uploaded in chunks using an upload session. The source is not read into memory
+/// all at once.
+pub async fn upload(
+    client: &impl UserAuthClient,
+    mut commit: files::CommitInfo,
+    source: impl AsyncRead + Unpin,
+    options: &UploadOptions,
+) -> Result<files::FileMetadata, TransferError> {
+    commit.path = options.upload_path(commit.path);
+    let retry = options.retry_budget.start();
+    upload_chunks(client, commit, source, &retry, None).await.map(|(metadata, _)| metadata)
+}
+
+/// The result of [`upload_with_backup`].
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub struct BackedUpUpload {
+    /// The metadata of the uploaded file.
+    pub metadata: files::FileMetadata,
+
+    /// The revision of the file which was overwritten, or `None` if there wasn't a file at the
+    /// path before.
+    pub previous_rev: Option<String>,
+}
+
+impl BackedUpUpload {
+    /// The arguments for `files/restore` to put back the file which was overwritten, undoing the
+    /// upload, or `None` if nothing was overwritten.
+    pub fn restore_arg(&self) -> Option<files::RestoreArg> {
+        let path = self.metadata.path_lower.clone()?;
+        Some(files::RestoreArg::new(path, self.previous_rev.clone()?))
+    }
+}
+
+/// Upload the given data to a path, overwriting any file already there, and return the revision
+/// of the file it overwrote along with the new file's metadata, so that the overwrite can be
+/// undone by restoring it.
+///
+/// The existing file's revision is looked up first, and the upload only overwrites that
+/// revision. If the file changes in between, or one is created where there wasn't one, the upload
+/// fails with a conflict rather than losing the other change. If there's a folder at the path,
+/// the upload fails too.
+pub async fn upload_with_backup(
+    client: &impl UserAuthClient,
+    path: impl Into<String>,
+    data: &[u8],
+    options: &UploadOptions,
+) -> Result<BackedUpUpload, TransferError> {
+    let path = options.upload_path(path.into());
+    let previous_rev = match metadata_opt(client, path.as_str()).await? {
+        Some(files::Metadata::File(file)) => Some(file.rev),
+        _ => None,
+    };
+    let mode = match &previous_rev {
+        Some(rev) => files::WriteMode::Update(rev.clone()),
+        None => files::WriteMode::Add,
+    };
+    let commit = files::CommitInfo::new(path).with_mode(mode);
+    let metadata = upload(client, commit, data, options).await?;
+    Ok(BackedUpUpload { metadata, previous_rev })
+}
+
+/// The most files [`upload_batch`] uploads with one batch of upload sessions, which is the most
+/// that `files/upload_session/finish_batch_v2` can commit at once.
+pub const UPLOAD_BATCH_MAX_FILES: usize = 1000;
+
+/// Options for [`upload_batch`].
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub struct BatchUploadOptions {
+    /// How many files to upload at once. Defaults to 4.
+    pub concurrency: usize,
+
+    /// Limits on retrying the requests of the whole batch upload which fail with transient errors,
+    /// in total. Defaults to no retries.
+    ///
+    /// Retrying waits between attempts, so if this allows any retries, the upload needs an
+    /// executor which supports timers, even with a sync client.
+    pub retry_budget: RetryBudget,
+}
+
+impl Default for BatchUploadOptions {
+    fn default() -> Self {
+        Self {
+            concurrency: 4,
+            retry_budget: RetryBudget::new(0),
+        }
+    }
+}
+
+impl BatchUploadOptions {
+    /// Set how many files to upload at once.
+    pub fn with_concurrency(mut self, value: usize) -> Self {
+        self.concurrency = value;
+        self
+    }
+
+    /// Set the limits on retrying failed requests.
+    pub fn with_retry_budget(mut self, value: RetryBudget) -> Self {
+        self.retry_budget = value;
+        self
+    }
+}
+
+/// Upload many files, each read from its own source, with far fewer requests than uploading them
+/// one at a time.
+///
+/// The files are uploaded in batches of up to [`UPLOAD_BATCH_MAX_FILES`]. For each batch, one
+/// `files/upload_session/start_batch` request starts an upload session for every file, the
+/// contents are sent to the sessions with `files/upload_session/append_v2`, several files at once,
+/// and one `files/upload_session/finish_batch_v2` request commits them all. Small files only need
+/// one request each, plus the two for the batch.
+///
+/// Committing a file can fail without failing the others, such as when there's a conflict at its
+/// path, so this returns the result of committing each file, in the order they were given. Any
+/// other error, such as failing to read a source or to send its contents, stops the upload; files
+/// in batches already finished stay uploaded.
+pub async fn upload_batch<S>(
+    client: &impl UserAuthClient,
+    uploads: impl IntoIterator<Item = (files::CommitInfo, S)>,
+    options: &BatchUploadOptions,
+) -> Result<Vec<Result<files::FileMetadata, files::UploadSessionFinishError>>, TransferError>
+where
+    S: AsyncRead + Unpin + Send,
+{
+    let retry = options.retry_budget.start();
+    let mut uploads = uploads.into_iter().collect::<Vec<_>>();
+    let mut results = Vec::with_capacity(uploads.len());
+    while !uploads.is_empty() {
+        let rest = uploads.split_off(uploads.len().min(UPLOAD_BATCH_MAX_FILES));
+        let batch = std::mem::replace(&mut uploads, rest);
+        let count = batch.len();
+
+        let start = files::UploadSessionStartBatchArg::new(count as u64);
+        let session_ids = retry.call(|| files::upload_session_start_batch(client, &start))
+            .await?
+            .session_ids;
+        if session_ids.len() != count {
+            return Err(Error::<NoError>::UnexpectedResponse(format!(
+                "asked for {count} upload sessions, got {}", session_ids.len())).into());
+        }
+        debug!("uploading {count} files with a batch of upload sessions");
+
+        let retry = &retry;
+        let entries = futures::stream::iter(session_ids.into_iter().zip(batch))
+            .map(|(session_id, (commit, source))| async move {
+                let len = append_to_session(client, &session_id, source, retry).await?;
+                let cursor = files::UploadSessionCursor::new(session_id, len);
+                Ok::<_, TransferError>(files::UploadSessionFinishArg::new(cursor, commit))
+            })
+            .buffered(options.concurrency.max(1))
+            .try_collect::<Vec<_>>()
+            .await?;
+
+        let finish = files::UploadSessionFinishBatchArg::new(entries);
+        let finished = retry.call(|| files::upload_session_finish_batch_v2(client, &finish))
+            .await?
+            .entries;
+        if finished.len() != count {
+            return Err(Error::<NoError>::UnexpectedResponse(format!(
+                "committed {count} files, got {} results", finished.len())).into());
+        }
+        results.extend(finished.into_iter().map(|entry| match entry {
+            files::UploadSessionFinishBatchResultEntry::Success(metadata) => Ok(metadata),
+            files::UploadSessionFinishBatchResultEntry::Failure(e) => Err(e),
+        }));
+    }
+    Ok(results)
+}
+
+/// Send everything read from the source to an upload session, closing it with the last chunk, and
+/// return the number of bytes sent.
+async fn append_to_session(
+    client: &impl UserAuthClient,
+    session_id: &str,
+    mut source: impl AsyncRead + Unpin,
+    retry: &crate::retry::RetryScope,
+) -> Result<u64, TransferError> {
+    let mut offset = 0;
+    loop {
+        let chunk = read_chunk(&mut source).await?;
+        let last = chunk.len() < UPLOAD_CHUNK_SIZE;
+        let cursor = files::UploadSessionCursor::new(session_id.to_owned(), offset);
+        let append = files::UploadSessionAppendArg::new(cursor).with_close(last);
+        retry.call(|| files::upload_session_append_v2(client, &append, chunk.clone())).await?;
+        offset += chunk.len() as u64;
+        if last {
+            return Ok(offset);
+        }
+    }
+}
+
+/// Options for [`list_deleted`].
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub struct ListDeletedOptions {
+    /// Include deleted files in subfolders, and in deleted subfolders. Defaults to true.
+    pub recursive: bool,
+
+    /// Only include files deleted at or after this time. Defaults to no limit.
+    pub deleted_after: Option<SystemTime>,
+
+    /// Only include files deleted before this time. Defaults to no limit.
+    pub deleted_before: Option<SystemTime>,
+
+    /// How many deleted files to look up the revisions of at once. Defaults to 4.
+    pub concurrency: usize,
+}
+
+impl Default for ListDeletedOptions {
+    fn default() -> Self {
+        Self {
+            recursive: true,
+            deleted_after: None,
+            deleted_before: None,
+            concurrency: 4,
+        }
+    }
+}
+
+impl ListDeletedOptions {
+    /// Set whether to include deleted files in subfolders.
+    pub fn with_recursive(mut self, value: bool) -> Self {
+        self.recursive = value;
+        self
+    }
+
+    /// Only include files deleted at or after the given time.
+    pub fn with_deleted_after(mut self, value: SystemTime) -> Self {
+        self.deleted_after = Some(value);
+        self
+    }
+
+    /// Only include files deleted before the given time.
+    pub fn with_deleted_before(mut self, value: SystemTime) -> Self {
+        self.deleted_before = Some(value);
+        self
+    }
+
+    /// Set how many deleted files to look up at once.
+    pub fn with_concurrency(mut self, value: usize) -> Self {
+        self.concurrency = value;
+        self
+    }
+}
+
+/// A deleted file, from [`list_deleted`], which can be brought back with [`restore_deleted`].
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub struct DeletedFile {
+    /// The path the file was deleted from.
+    pub path: String,
+
+    /// When the file was deleted, if the server said.
+    pub deleted_at: Option<SystemTime>,
+
+    /// The file as it was when it was deleted: its last revision.
+    pub last_revision: files::FileMetadata,
+}
+
+impl DeletedFile {
+    /// The argument for `files/restore` to restore the file's last revision to where it was.
+    pub fn restore_arg(&self) -> files::RestoreArg {
+        files::RestoreArg::new(self.path.clone(), self.last_revision.rev.clone())
+    }
+}
+
+/// List the files which have been deleted from a folder, and not restored or recreated since,
+/// with when they were deleted and their last revision.
+///
+/// This lists the folder with deleted entries included, and then looks up the revisions of each
+/// one with `files/list_revisions`, several at a time. Deleted folders are left out, as only
+/// files can be restored; restoring the files in a folder recreates it. Files whose deletion time
+/// the server doesn't give are left out when [`deleted_after`](ListDeletedOptions::deleted_after)
+/// or [`deleted_before`](ListDeletedOptions::deleted_before) is set. The files are in order of
+/// their path.
+pub async fn list_deleted(
+    client: &impl UserAuthClient,
+    path: &str,
+    options: &ListDeletedOptions,
+) -> Result<Vec<DeletedFile>, BoxedError> {
+    let arg = files::ListFolderArg::new(path.to_owned())
+        .with_recursive(options.recursive)
+        .with_include_deleted(true);
+    // Keyed by lower-case path, so entries listed twice because the listing restarted are only
+    // looked up once.
+    let mut paths = BTreeMap::new();
+    let mut entries = Box::pin(list_folder(client, arg, &ListFolderOptions::default()));
+    while let Some(entry) = entries.try_next().await? {
+        if let files::Metadata::Deleted(deleted) = entry.metadata {
+            if let Some(path) = deleted.path_display.or(deleted.path_lower) {
+                paths.insert(path.to_lowercase(), path);
+            }
+        }
+    }
+
+    let in_window = |deleted_at: Option<SystemTime>| match deleted_at {
+        Some(t) => options.deleted_after.map_or(true, |after| t >= after)
+            && options.deleted_before.map_or(true, |before| t < before),
+        None => options.deleted_after.is_none() && options.deleted_before.is_none(),
+    };
+    let mut lookups = futures::stream::iter(paths.into_values())
+        .map(|path| async move {
+            let arg = files::ListRevisionsArg::new(path.clone()).with_limit(1);
+            match files::list_revisions(client, &arg).await {
+                Ok(result) => Ok(Some((path, result))),
+                // Deleted folders don't have revisions, and entries which have since been
+                // permanently deleted are gone.
+                Err(Error::Api(files::ListRevisionsError::Path(
+                    files::LookupError::NotFile | files::LookupError::NotFound))) => Ok(None),
+                Err(e) => Err(e.boxed()),
+            }
+        })
+        .buffered(options.concurrency.max(1));
+    let mut deleted = vec![];
+    while let Some(lookup) = lookups.try_next().await? {
+        let Some((path, mut result)) = lookup else {
+            continue;
+        };
+        if !result.is_deleted || result.entries.is_empty() {
+            continue;
+        }
+        let deleted_at = result.server_deleted
+            .and_then(|t| crate::timestamp::to_system_time(&t).ok());
+        if in_window(deleted_at) {
+            deleted.push(DeletedFile {
+                path,
+                deleted_at,
+                last_revision: result.entries.swap_remove(0),
+            });
+        }
+    }
+    Ok(deleted)
+}
+
+/// What [`restore_deleted`] did.
+#[derive(Debug, Default)]
+#[non_exhaustive]
+pub struct RestoreReport {
+    /// The files which were restored, as they are now.
+    pub restored: Vec<files::FileMetadata>,
+
+    /// The paths of the files which couldn't be restored, with the errors from restoring them.
+    pub failed: Vec<(String, Error<files::RestoreError>)>,
+}
+
+/// Restore deleted files from [`list_deleted`] to where they were, up to `concurrency` at a time,
+/// calling `progress` with how many have been done so far and how many there are in total after
+/// each one.
+///
+/// An error restoring one file doesn't stop the others being restored; it's recorded in the
+/// report instead. Restoring is a write, and writes to the same Dropbox at the same time contend
+/// with each other, so a low concurrency works best.
+pub async fn restore_deleted(
+    client: &impl UserAuthClient,
+    deleted: &[DeletedFile],
+    concurrency: usize,
+    mut progress: impl FnMut(usize, usize),
+) -> RestoreReport {
+    let mut restores = futures::stream::iter(deleted)
+        .map(|file| async move { (file, files::restore(client, &file.restore_arg()).await) })
+        .buffer_unordered(concurrency.max(1));
+    let mut report = RestoreReport::default();
+    while let Some((file, result)) = restores.next().await {
+        match result {
+            Ok(metadata) => report.restored.push(metadata),
+            Err(e) => {
+                warn!("failed to restore {}: {e}", file.path);
+                report.failed.push((file.path.clone(), e));
+            }
+        }
+        progress(report.restored.len() + report.failed.len(), deleted.len());
+    }
+    report
+}
+
+/// Measurements of a finished transfer, from [`upload_with_report`] or
+/// [`download_with_report`], for logging.
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub struct IntegrityReport {
+    /// The number of bytes of file contents transferred, not counting retries.
+    pub bytes: u64,
+
+    /// How long the whole transfer took, including waiting between retries.
+    pub duration: Duration,
+
+    /// How many requests were retried.
+    pub retries: u32,
+
+    /// Whether the [content hash](crate::content_hash) of the data transferred was checked against
+    /// the one Dropbox returned for the file. This is false if Dropbox didn't return one; if it
+    /// did, and they didn't match, the transfer fails with
+    /// [`TransferError::ContentHashMismatch`] instead.
+    pub content_hash_verified: bool,
+}
+
+impl IntegrityReport {
+    fn new(bytes: u64, retry: &crate::retry::RetryScope, content_hash_verified: bool) -> Self {
+        Self {
+            bytes,
+            duration: retry.elapsed(),
+            retries: retry.retries(),
+            content_hash_verified,
+        }
+    }
+
+    /// The average transfer rate, in bytes per second, or `None` if no time passed.
+    pub fn average_rate(&self) -> Option<f64> {
+        let secs = self.duration.as_secs_f64();
+        if secs > 0. {
+            Some(self.bytes as f64 / secs)
+        } else {
+            None
+        }
+    }
+}
+
+/// Like [`upload`], but also return measurements of the upload.
+///
+/// The source's content hash is computed as it is uploaded, and checked against the one Dropbox
+/// computed for the uploaded file.
+pub async fn upload_with_report(
+    client: &impl UserAuthClient,
+    mut commit: files::CommitInfo,
+    source: impl AsyncRead + Unpin,
+    options: &UploadOptions,
+) -> Result<(files::FileMetadata, IntegrityReport), TransferError> {
+    commit.path = options.upload_path(commit.path);
+    let retry = options.retry_budget.start();
+    let mut hasher = ContentHasher::new();
+    let (metadata, bytes) = upload_chunks(client, commit, source, &retry, Some(&mut hasher)).await?;
+    let verified = check_content_hash(hasher.finish(), metadata.content_hash.as_deref())?;
+    let report = IntegrityReport::new(bytes, &retry, verified);
+    Ok((metadata, report))
+}
+
+/// Download a file, writing its contents to the given destination, and return its metadata along
+/// with measurements of the download.
+///
+/// The contents' content hash is computed as they are written, and checked against the one
+/// Dropbox returned for the file. Starting the download is retried using the budget. If the
+/// download [stalls](TransferError::Stalled), it's resumed from where it stopped, from the same
+/// revision of the file, using up one of the retries. Other errors once any of the contents have
+/// been written are returned as they are.
+pub async fn download_with_report(
+    client: &impl UserAuthClient,
+    arg: &files::DownloadArg,
+    mut dest: impl AsyncWrite + Unpin,
+    retry_budget: &RetryBudget,
+) -> Result<(files::FileMetadata, IntegrityReport), TransferError> {
+    let retry = retry_budget.start();
+    let response = retry.call(|| files::download(client, arg, None, None)).await?;
+    let metadata = response.result;
+    let mut body = download_body(response.body)?;
+    let mut hasher = ContentHasher::new();
+    let mut buf = vec![0; 64 * 1024];
+    let mut bytes = 0;
+    loop {
+        let read = match body.read(&mut buf).await.map_err(TransferError::from) {
+            Ok(read) => read,
+            Err(TransferError::Stalled(stalled)) if bytes == metadata.size => {
+                // Everything has arrived already.
+                debug!("ignoring a stall at the end of a download: {stalled}");
+                0
+            }
+            Err(TransferError::Stalled(stalled)) if retry.retry_stalled(&stalled) => {
+                let resume = files::DownloadArg::new(format!("rev:{}", metadata.rev));
+                let response = retry.call(|| files::download(client, &resume, Some(bytes), None))
+                    .await?;
+                body = download_body(response.body)?;
+                continue;
+            }
+            Err(e) => return Err(e),
+        };
+        if read == 0 {
+            break;
+        }
+        hasher.update(&buf[..read]);
+        dest.write_all(&buf[..read]).await?;
+        bytes += read as u64;
+    }
+    dest.flush().await?;
+    let verified = check_content_hash(hasher.finish(), metadata.content_hash.as_deref())?;
+    let report = IntegrityReport::new(bytes, &retry, verified);
+    Ok((metadata, report))
+}
+
+/// The body of a download response, which should always have one.
+fn download_body<B>(body: Option<B>) -> Result<B, TransferError> {
+    body.ok_or_else(|| {
+        TransferError::Dropbox(Error::UnexpectedResponse(
+            "no response body for a download".to_owned()))
+    })
+}
+
+/// Check a local content hash against the one Dropbox returned, if it returned one.
+fn check_content_hash(local: String, remote: Option<&str>) -> Result<bool, TransferError> {
+    match remote {
+        None => Ok(false),
+        Some(remote) if remote == local => Ok(true),
+        Some(remote) => Err(TransferError::ContentHashMismatch {
+            local,
+            remote: remote.to_owned(),
+        }),
+    }
+}
+
+/// Upload everything read from the source, as for [`upload`], adding the data to the hasher if
+/// there is one, and return the file's metadata and the number of bytes uploaded.
+async fn upload_chunks(
+    client: &impl UserAuthClient,
+    commit: files::CommitInfo,
+    mut source: impl AsyncRead + Unpin,
+    retry: &crate::retry::RetryScope,
+    mut hasher: Option<&mut ContentHasher>,
+) -> Result<(files::FileMetadata, u64), TransferError> {
+    let mut read = |chunk: std::io::Result<Bytes>| {
+        if let (Ok(chunk), Some(hasher)) = (&chunk, hasher.as_deref_mut()) {
+            hasher.update(chunk);
+        }
+        chunk
+    };
+    let mut chunk = read(read_chunk(&mut source).await)?;
+    if chunk.len() < UPLOAD_CHUNK_SIZE {
+        debug!("uploading {} bytes to {} in a single request", chunk.len(), commit.path);
+        let arg = upload_arg(commit);
+        return match retry.call(|| files::upload(client, &arg, chunk.clone())).await {
+            Ok(metadata) => Ok((metadata, chunk.len() as u64)),
+            Err(e) => Err(TransferError::from_locked(client, &arg.path, e).await),
+        };
+    }
+
+    let start = files::UploadSessionStartArg::default();
+    let session_id = retry.call(|| files::upload_session_start(client, &start, chunk.clone()))
+        .await?
+        .session_id;
+    debug!("uploading to {} with upload session {session_id}", commit.path);
+
+    let mut offset = 0;
+    loop {
+        offset += chunk.len() as u64;
+        chunk = read(read_chunk(&mut source).await)?;
+        let cursor = files::UploadSessionCursor::new(session_id.clone(), offset);
+        if chunk.len() < UPLOAD_CHUNK_SIZE {
+            // This is the last chunk (possibly empty); upload it and commit the file.
+            let len = offset + chunk.len() as u64;
+            let finish = files::UploadSessionFinishArg::new(cursor, commit);
+            return match retry.call(|| files::upload_session_finish(client, &finish, chunk.clone()))
+                .await
+            {
+                Ok(metadata) => Ok((metadata, len)),
+                Err(e) => Err(TransferError::from_locked(client, &finish.commit.path, e).await),
+            };
+        }
+        let append = files::UploadSessionAppendArg::new(cursor);
+        retry.call(|| files::upload_session_append_v2(client, &append, chunk.clone())).await?;
+    }
+}
+
+/// The most which can be uploaded to a temporary upload link, in bytes.
+pub const TEMPORARY_UPLOAD_LINK_MAX_SIZE: u64 = 150 * 1024 * 1024;
+
+/// Upload everything read from the given source to a temporary upload link, from
+/// `files/get_temporary_upload_link`.
+///
+/// The link itself authorizes the upload, so this can be done by a worker which doesn't have the
+/// user's access token, using any client. The file is committed to the path, and with the options,
+/// given when the link was made. A link can only be used once, and expires after four hours.
+///
+/// At most [`TEMPORARY_UPLOAD_LINK_MAX_SIZE`] bytes can be uploaded this way; for a larger source,
+/// this fails with [`TransferError::TooLarge`] without uploading anything. The source is read into
+/// memory first, so that the upload can be retried using the options' retry budget; the other
+/// options don't apply.
+pub async fn upload_to_temporary_link(
+    client: &impl NoauthClient,
+    link: &files::GetTemporaryUploadLinkResult,
+    source: impl AsyncRead + Unpin,
+    options: &UploadOptions,
+) -> Result<(), TransferError> {
+    let limit = TEMPORARY_UPLOAD_LINK_MAX_SIZE;
+    let mut buf = Vec::new();
+    source.take(limit + 1).read_to_end(&mut buf).await?;
+    if buf.len() as u64 > limit {
+        return Err(TransferError::TooLarge { limit });
+    }
+    let body = Bytes::from(buf);
+    debug!("uploading {} bytes to a temporary upload link", body.len());
+    options.retry_budget.start()
+        .call(|| upload_to_link(client, &link.link, body.clone()))
+        .await?;
+    Ok(())
+}
+
+async fn upload_to_link(client: &impl NoauthClient, link: &str, body: Bytes) -> Result<(), Error> {
+    let request = client.new_request(link)
+        .set_header("User-Agent", USER_AGENT)
+        .set_header("Content-Type", "application/octet-stream");
+    let response = client.execute(request, body).await?;
+    if (200..300).contains(&response.status) {
+        return Ok(());
+    }
+    match parse_response(response, Style::Upload, "temporary upload link").await {
+        // Only a 409 gets here, which means the link has already been used, or has expired.
+        Ok((response, ..)) => Err(Error::UnexpectedHttpError { code: 409, response }),
+        Err(e) => Err(e),
+    }
+}
+
+if_feature! { "sync_routes",
+    /// Upload the contents of a local file using a sync client, streaming it from the file instead
+    /// of reading it into memory.
+    ///
+    /// This is like [`upload_file`], except that it doesn't read each request's worth of the file
+    /// into memory first. This needs the client to override
+    /// [`HttpClient::execute_streaming`](crate::client_trait::HttpClient::execute_streaming), as
+    /// the default client does. The file shouldn't be changed while it's being uploaded.
+    pub fn upload_file_sync(
+        client: &impl crate::client_trait::UserAuthClient,
+        local_path: impl AsRef<Path>,
+        mut commit: files::CommitInfo,
+        options: &UploadOptions,
+    ) -> Result<files::FileMetadata, TransferError> {
+        use futures::executor::block_on;
+
+        commit.path = options.upload_path(commit.path);
+        let local_path = local_path.as_ref();
+        let metadata = std::fs::metadata(local_path)?;
+        if options.preserve_mtime && commit.client_modified.is_none() {
+            commit.client_modified = Some(crate::timestamp::from_system_time(metadata.modified()?));
+        }
+        let len = metadata.len();
+        // Retries (which need the timer in block_on) and token refreshes re-open the file, so
+        // that each attempt starts reading from the right place.
+        let open_range = |offset: u64, len: u64| {
+            move || -> std::io::Result<Box<dyn std::io::Read + Send>> {
+                use std::io::{Read, Seek, SeekFrom};
+                let mut file = std::fs::File::open(local_path)?;
+                file.seek(SeekFrom::Start(offset))?;
+                Ok(Box::new(file.take(len)))
+            }
+        };
+        let retry = options.retry_budget.start();
+        let chunk_size = UPLOAD_CHUNK_SIZE as u64;
+
+        if len < chunk_size {
+            debug!("uploading {len} bytes to {} in a single request", commit.path);
+            let arg = upload_arg(commit);
+            let open = open_range(0, len);
+            return block_on(async {
+                match retry.call(|| {
+                    upload_request::<_, files::UploadError, _>(
+                        client, "files/upload", &arg, StreamBody { open: &open, len })
+                }).await {
+                    Ok(metadata) => Ok(metadata),
+                    Err(e) => Err(TransferError::from_locked(client, &arg.path, e).await),
+                }
+            });
+        }
+
+        let start = files::UploadSessionStartArg::default();
+        let open = open_range(0, chunk_size);
+        let session_id = block_on(retry.call(|| {
+            upload_request::<files::UploadSessionStartResult, files::UploadSessionStartError, _>(
+                client, "files/upload_session/start", &start,
+                StreamBody { open: &open, len: chunk_size })
+        }))?.session_id;
+        debug!("uploading to {} with upload session {session_id}", commit.path);
+
+        let mut offset = chunk_size;
+        loop {
+            let cursor = files::UploadSessionCursor::new(session_id.clone(), offset);
+            let remaining = len - offset;
+            if remaining < chunk_size {
+                // This is the last chunk (possibly empty); upload it and commit the file.
+                let finish = files::UploadSessionFinishArg::new(cursor, commit);
+                let open = open_range(offset, remaining);
+                return block_on(async {
+                    match retry.call(|| {
+                        upload_request::<_, files::UploadSessionFinishError, _>(
+                            client, "files/upload_session/finish", &finish,
+                            StreamBody { open: &open, len: remaining })
+                    }).await {
+                        Ok(metadata) => Ok(metadata),
+                        Err(e) => Err(TransferError::from_locked(client, &finish.commit.path, e).await),
+                    }
+                });
+            }
+            let append = files::UploadSessionAppendArg::new(cursor);
+            let open = open_range(offset, chunk_size);
+            block_on(retry.call(|| {
+                upload_request::<(), files::UploadSessionAppendError, _>(
+                    client, "files/upload_session/append_v2", &append,
+                    StreamBody { open: &open, len: chunk_size })
+            }))?;
+            offset += chunk_size;
+        }
+    }
+}
+
+#[cfg(feature = "sync_routes")]
+async fn upload_request<T, E, P>(
+    client: &impl crate::client_trait::UserAuthClient,
+    function: &str,
+    arg: &P,
+    body: StreamBody<'_>,
+) -> Result<T, Error<E>>
+where
+    T: serde::de::DeserializeOwned,
+    E: serde::de::DeserializeOwned + std::error::Error,
+    P: serde::Serialize,
+{
+    crate::client_helpers::request(
+        client,
+        crate::client_trait_common::Endpoint::Content,
+        crate::client_trait_common::Style::Upload,
+        function,
+        arg,
+        Some(Body::Stream(body)),
+    ).await
+}
+
+if_feature! { "tokio",
+    /// Upload the contents of a [`tokio::fs::File`], starting from its current position.
+    ///
+    /// This is like [`upload_file`], but doesn't do blocking I/O. It needs to be run within a
+    /// tokio runtime.
+    pub async fn upload_tokio_file(
+        client: &impl UserAuthClient,
+        file: tokio::fs::File,
+        mut commit: files::CommitInfo,
+        options: &UploadOptions,
+    ) -> Result<files::FileMetadata, TransferError> {
+        if options.preserve_mtime && commit.client_modified.is_none() {
+            let mtime = file.metadata().await?.modified()?;
+            commit.client_modified = Some(crate::timestamp::from_system_time(mtime));
+        }
+        upload_tokio(client, commit, file, options).await
+    }
+
+    /// Upload everything read from the given [`tokio::io::AsyncRead`] source.
+    ///
+    /// This is like [`upload`], but for tokio sources.
+    pub async fn upload_tokio(
+        client: &impl UserAuthClient,
+        commit: files::CommitInfo,
+        source: impl tokio::io::AsyncRead + Unpin,
+        options: &UploadOptions,
+    ) -> Result<files::FileMetadata, TransferError> {
+        use tokio_util::compat::TokioAsyncReadCompatExt;
+        upload(client, commit, source.compat(), options).await
+    }
+}
+
+/// Read up to [`UPLOAD_CHUNK_SIZE`] bytes, stopping short only at the end of the source.
+async fn read_chunk(source: &mut (impl AsyncRead + Unpin)) -> std::io::Result<Bytes> {
+    let mut buf = Vec::with_capacity(UPLOAD_CHUNK_SIZE);
+    source.take(UPLOAD_CHUNK_SIZE as u64).read_to_end(&mut buf).await?;
+    Ok(Bytes::from(buf))
+}
+
+fn upload_arg(commit: files::CommitInfo) -> files::UploadArg {
+    files::UploadArg {
+        path: commit.path,
+        mode: commit.mode,
+        autorename: commit.autorename,
+        client_modified: commit.client_modified,
+        mute: commit.mute,
+        property_groups: commit.property_groups,
+        strict_conflict: commit.strict_conflict,
+        content_hash: None,
+    }
+}
@@ -11,6 +11,15 @@
 #[allow(unused_imports)]
 pub use crate::generated::types::auth::*;
 
+/// The types of this namespace, and the client traits its routes take, for importing all at
+/// once with `use dropbox_sdk::auth::prelude::*`. Unlike a glob import of this module, this
+/// leaves out the route functions, whose names are often too general to import unqualified.
+#[allow(unused_imports)]
+pub mod prelude {
+    pub use crate::generated::types::auth::*;
+    pub use crate::async_client_trait::{AppAuthClient, NoauthClient, TeamAuthClient, UserAuthClient};
+}
+
 /// Creates an OAuth 2.0 access token from the supplied OAuth 1.0 access token.
 #[deprecated]
 pub fn token_from_oauth1<'a>(
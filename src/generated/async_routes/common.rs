@@ -11,3 +11,12 @@
 #[allow(unused_imports)]
 pub use crate::generated::types::common::*;
 
+/// The types of this namespace, and the client traits its routes take, for importing all at
+/// once with `use dropbox_sdk::common::prelude::*`. Unlike a glob import of this module, this
+/// leaves out the route functions, whose names are often too general to import unqualified.
+#[allow(unused_imports)]
+pub mod prelude {
+    pub use crate::generated::types::common::*;
+    pub use crate::async_client_trait::{AppAuthClient, NoauthClient, TeamAuthClient, UserAuthClient};
+}
+
@@ -11,6 +11,15 @@
 #[allow(unused_imports)]
 pub use crate::generated::types::contacts::*;
 
+/// The types of this namespace, and the client traits its routes take, for importing all at
+/// once with `use dropbox_sdk::contacts::prelude::*`. Unlike a glob import of this module, this
+/// leaves out the route functions, whose names are often too general to import unqualified.
+#[allow(unused_imports)]
+pub mod prelude {
+    pub use crate::generated::types::contacts::*;
+    pub use crate::async_client_trait::{AppAuthClient, NoauthClient, TeamAuthClient, UserAuthClient};
+}
+
 /// Removes all manually added contacts. You'll still keep contacts who are on your team or who you
 /// imported. New contacts will be added when you share.
 pub fn delete_manual_contacts(
@@ -11,6 +11,15 @@
 #[allow(unused_imports)]
 pub use crate::generated::types::file_properties::*;
 
+/// The types of this namespace, and the client traits its routes take, for importing all at
+/// once with `use dropbox_sdk::file_properties::prelude::*`. Unlike a glob import of this module, this
+/// leaves out the route functions, whose names are often too general to import unqualified.
+#[allow(unused_imports)]
+pub mod prelude {
+    pub use crate::generated::types::file_properties::*;
+    pub use crate::async_client_trait::{AppAuthClient, NoauthClient, TeamAuthClient, UserAuthClient};
+}
+
 /// Add property groups to a Dropbox file. See
 /// [`templates_add_for_user()`](crate::file_properties::templates_add_for_user) or
 /// [`templates_add_for_team()`](crate::file_properties::templates_add_for_team) to create new
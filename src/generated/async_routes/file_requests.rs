@@ -11,6 +11,15 @@
 #[allow(unused_imports)]
 pub use crate::generated::types::file_requests::*;
 
+/// The types of this namespace, and the client traits its routes take, for importing all at
+/// once with `use dropbox_sdk::file_requests::prelude::*`. Unlike a glob import of this module, this
+/// leaves out the route functions, whose names are often too general to import unqualified.
+#[allow(unused_imports)]
+pub mod prelude {
+    pub use crate::generated::types::file_requests::*;
+    pub use crate::async_client_trait::{AppAuthClient, NoauthClient, TeamAuthClient, UserAuthClient};
+}
+
 /// Returns the total number of file requests owned by this user. Includes both open and closed file
 /// requests.
 pub fn count(
@@ -11,6 +11,15 @@
 #[allow(unused_imports)]
 pub use crate::generated::types::team::*;
 
+/// The types of this namespace, and the client traits its routes take, for importing all at
+/// once with `use dropbox_sdk::team::prelude::*`. Unlike a glob import of this module, this
+/// leaves out the route functions, whose names are often too general to import unqualified.
+#[allow(unused_imports)]
+pub mod prelude {
+    pub use crate::generated::types::team::*;
+    pub use crate::async_client_trait::{AppAuthClient, NoauthClient, TeamAuthClient, UserAuthClient};
+}
+
 /// List all device sessions of a team's member.
 pub fn devices_list_member_devices<'a>(
     client: &'a impl crate::async_client_trait::TeamAuthClient,
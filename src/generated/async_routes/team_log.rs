@@ -11,6 +11,15 @@
 #[allow(unused_imports)]
 pub use crate::generated::types::team_log::*;
 
+/// The types of this namespace, and the client traits its routes take, for importing all at
+/// once with `use dropbox_sdk::team_log::prelude::*`. Unlike a glob import of this module, this
+/// leaves out the route functions, whose names are often too general to import unqualified.
+#[allow(unused_imports)]
+pub mod prelude {
+    pub use crate::generated::types::team_log::*;
+    pub use crate::async_client_trait::{AppAuthClient, NoauthClient, TeamAuthClient, UserAuthClient};
+}
+
 /// Retrieves team events. If the result's [`GetTeamEventsResult::has_more`](GetTeamEventsResult)
 /// field is `true`, call [`get_events_continue()`](crate::team_log::get_events_continue) with the
 /// returned cursor to retrieve more entries. If end_time is not specified in your request, you may
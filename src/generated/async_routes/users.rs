@@ -11,6 +11,15 @@
 #[allow(unused_imports)]
 pub use crate::generated::types::users::*;
 
+/// The types of this namespace, and the client traits its routes take, for importing all at
+/// once with `use dropbox_sdk::users::prelude::*`. Unlike a glob import of this module, this
+/// leaves out the route functions, whose names are often too general to import unqualified.
+#[allow(unused_imports)]
+pub mod prelude {
+    pub use crate::generated::types::users::*;
+    pub use crate::async_client_trait::{AppAuthClient, NoauthClient, TeamAuthClient, UserAuthClient};
+}
+
 /// Get a list of feature values that may be configured for the current account.
 pub fn features_get_values<'a>(
     client: &'a impl crate::async_client_trait::UserAuthClient,
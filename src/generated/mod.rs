@@ -14,13 +14,90 @@ if_feature! { "async_routes",
     pub mod async_routes;
 }
 
+// The async routes are always built because the helper modules use them, but they're only
+// public if the feature is enabled.
+#[cfg(not(feature = "async_routes"))]
+#[allow(dead_code)]
+pub(crate) mod async_routes;
+
 if_feature! { "sync_routes",
     pub mod sync_routes;
 }
 
+#[cfg(feature = "dispatch")]
+pub(crate) mod route_table;
+
+/// The deprecated routes, sorted by name, and the routes which replace them.
+pub(crate) const DEPRECATED_ROUTES: &[(&str, Option<&str>)] = &[
+    ("auth/token/from_oauth1", None),
+    ("files/alpha/get_metadata", Some("files/get_metadata")),
+    ("files/alpha/upload", Some("files/upload")),
+    ("files/copy", Some("files/copy_v2")),
+    ("files/copy_batch", Some("files/copy_batch_v2")),
+    ("files/copy_batch/check", Some("files/copy_batch/check_v2")),
+    ("files/create_folder", Some("files/create_folder_v2")),
+    ("files/delete", Some("files/delete_v2")),
+    ("files/move", Some("files/move_v2")),
+    ("files/move_batch", Some("files/move_batch_v2")),
+    ("files/move_batch/check", Some("files/move_batch/check_v2")),
+    ("files/properties/add", None),
+    ("files/properties/overwrite", None),
+    ("files/properties/remove", None),
+    ("files/properties/template/get", None),
+    ("files/properties/template/list", None),
+    ("files/properties/update", None),
+    ("files/search", Some("files/search_v2")),
+    ("files/upload_session/append", Some("files/upload_session/append_v2")),
+    ("files/upload_session/finish_batch", Some("files/upload_session/finish_batch_v2")),
+    ("paper/docs/archive", None),
+    ("paper/docs/create", None),
+    ("paper/docs/download", None),
+    ("paper/docs/folder_users/list", None),
+    ("paper/docs/folder_users/list/continue", None),
+    ("paper/docs/get_folder_info", None),
+    ("paper/docs/list", None),
+    ("paper/docs/list/continue", None),
+    ("paper/docs/permanently_delete", None),
+    ("paper/docs/sharing_policy/get", None),
+    ("paper/docs/sharing_policy/set", None),
+    ("paper/docs/update", None),
+    ("paper/docs/users/add", None),
+    ("paper/docs/users/list", None),
+    ("paper/docs/users/list/continue", None),
+    ("paper/docs/users/remove", None),
+    ("paper/folders/create", None),
+    ("sharing/create_shared_link", Some("sharing/create_shared_link_with_settings")),
+    ("sharing/get_shared_links", Some("sharing/list_shared_links")),
+    ("sharing/remove_file_member", Some("sharing/remove_file_member_2")),
+    ("team/devices/list_team_devices", Some("team/devices/list_members_devices")),
+    ("team/linked_apps/list_team_linked_apps", Some("team/linked_apps/list_members_linked_apps")),
+    ("team/properties/template/add", None),
+    ("team/properties/template/get", None),
+    ("team/properties/template/list", None),
+    ("team/properties/template/update", None),
+    ("team/reports/get_activity", None),
+    ("team/reports/get_devices", None),
+    ("team/reports/get_membership", None),
+    ("team/reports/get_storage", None),
+];
+
 pub(crate) fn eat_json_fields<'de, V>(map: &mut V) -> Result<(), V::Error> where V: ::serde::de::MapAccess<'de> {
     while map.next_entry::<&str, ::serde_json::Value>()?.is_some() {
         /* ignore */
     }
     Ok(())
 }
+
+// These are shared by all the struct deserializers, to keep the generated code small.
+pub(crate) fn eat_json_field<'de, V>(map: &mut V) -> Result<(), V::Error> where V: ::serde::de::MapAccess<'de> {
+    map.next_value::<::serde_json::Value>()?;
+    Ok(())
+}
+
+pub(crate) fn deserialize_field<'de, V, T>(map: &mut V, field: &mut Option<T>, name: &'static str) -> Result<(), V::Error> where V: ::serde::de::MapAccess<'de>, T: ::serde::de::Deserialize<'de> {
+    if field.is_some() {
+        return Err(::serde::de::Error::duplicate_field(name));
+    }
+    *field = Some(map.next_value()?);
+    Ok(())
+}
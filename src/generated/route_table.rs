@@ -0,0 +1,2639 @@
+// DO NOT EDIT
+// This file was @generated by Stone
+
+#![allow(
+    clippy::too_many_arguments,
+    clippy::large_enum_variant,
+    clippy::result_large_err,
+    clippy::doc_markdown,
+)]
+
+use crate::client_trait_common::{Endpoint, Style};
+use crate::dispatch::RouteInfo;
+
+/// Every route, sorted by name.
+pub(crate) static ROUTES: &[RouteInfo] = &[
+    #[cfg(feature = "dbx_account")]
+    RouteInfo {
+        name: "account/set_profile_photo",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["user"],
+        preview: false,
+        deprecated: false,
+        replaced_by: None,
+    },
+    RouteInfo {
+        name: "auth/token/from_oauth1",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["app"],
+        preview: false,
+        deprecated: true,
+        replaced_by: None,
+    },
+    RouteInfo {
+        name: "auth/token/revoke",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["user"],
+        preview: false,
+        deprecated: false,
+        replaced_by: None,
+    },
+    #[cfg(feature = "unstable")]
+    #[cfg(feature = "dbx_check")]
+    RouteInfo {
+        name: "check/app",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["app"],
+        preview: true,
+        deprecated: false,
+        replaced_by: None,
+    },
+    #[cfg(feature = "unstable")]
+    #[cfg(feature = "dbx_check")]
+    RouteInfo {
+        name: "check/user",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["user"],
+        preview: true,
+        deprecated: false,
+        replaced_by: None,
+    },
+    #[cfg(feature = "dbx_contacts")]
+    RouteInfo {
+        name: "contacts/delete_manual_contacts",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["user"],
+        preview: false,
+        deprecated: false,
+        replaced_by: None,
+    },
+    #[cfg(feature = "dbx_contacts")]
+    RouteInfo {
+        name: "contacts/delete_manual_contacts_batch",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["user"],
+        preview: false,
+        deprecated: false,
+        replaced_by: None,
+    },
+    #[cfg(feature = "dbx_file_properties")]
+    RouteInfo {
+        name: "file_properties/properties/add",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["user"],
+        preview: false,
+        deprecated: false,
+        replaced_by: None,
+    },
+    #[cfg(feature = "dbx_file_properties")]
+    RouteInfo {
+        name: "file_properties/properties/overwrite",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["user"],
+        preview: false,
+        deprecated: false,
+        replaced_by: None,
+    },
+    #[cfg(feature = "dbx_file_properties")]
+    RouteInfo {
+        name: "file_properties/properties/remove",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["user"],
+        preview: false,
+        deprecated: false,
+        replaced_by: None,
+    },
+    #[cfg(feature = "dbx_file_properties")]
+    RouteInfo {
+        name: "file_properties/properties/search",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["user"],
+        preview: false,
+        deprecated: false,
+        replaced_by: None,
+    },
+    #[cfg(feature = "dbx_file_properties")]
+    RouteInfo {
+        name: "file_properties/properties/search/continue",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["user"],
+        preview: false,
+        deprecated: false,
+        replaced_by: None,
+    },
+    #[cfg(feature = "dbx_file_properties")]
+    RouteInfo {
+        name: "file_properties/properties/update",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["user"],
+        preview: false,
+        deprecated: false,
+        replaced_by: None,
+    },
+    #[cfg(feature = "dbx_file_properties")]
+    RouteInfo {
+        name: "file_properties/templates/add_for_team",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["team"],
+        preview: false,
+        deprecated: false,
+        replaced_by: None,
+    },
+    #[cfg(feature = "dbx_file_properties")]
+    RouteInfo {
+        name: "file_properties/templates/add_for_user",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["user"],
+        preview: false,
+        deprecated: false,
+        replaced_by: None,
+    },
+    #[cfg(feature = "dbx_file_properties")]
+    RouteInfo {
+        name: "file_properties/templates/get_for_team",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["team"],
+        preview: false,
+        deprecated: false,
+        replaced_by: None,
+    },
+    #[cfg(feature = "dbx_file_properties")]
+    RouteInfo {
+        name: "file_properties/templates/get_for_user",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["user"],
+        preview: false,
+        deprecated: false,
+        replaced_by: None,
+    },
+    #[cfg(feature = "dbx_file_properties")]
+    RouteInfo {
+        name: "file_properties/templates/list_for_team",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["team"],
+        preview: false,
+        deprecated: false,
+        replaced_by: None,
+    },
+    #[cfg(feature = "dbx_file_properties")]
+    RouteInfo {
+        name: "file_properties/templates/list_for_user",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["user"],
+        preview: false,
+        deprecated: false,
+        replaced_by: None,
+    },
+    #[cfg(feature = "dbx_file_properties")]
+    RouteInfo {
+        name: "file_properties/templates/remove_for_team",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["team"],
+        preview: false,
+        deprecated: false,
+        replaced_by: None,
+    },
+    #[cfg(feature = "dbx_file_properties")]
+    RouteInfo {
+        name: "file_properties/templates/remove_for_user",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["user"],
+        preview: false,
+        deprecated: false,
+        replaced_by: None,
+    },
+    #[cfg(feature = "dbx_file_properties")]
+    RouteInfo {
+        name: "file_properties/templates/update_for_team",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["team"],
+        preview: false,
+        deprecated: false,
+        replaced_by: None,
+    },
+    #[cfg(feature = "dbx_file_properties")]
+    RouteInfo {
+        name: "file_properties/templates/update_for_user",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["user"],
+        preview: false,
+        deprecated: false,
+        replaced_by: None,
+    },
+    #[cfg(feature = "dbx_file_requests")]
+    RouteInfo {
+        name: "file_requests/count",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["user"],
+        preview: false,
+        deprecated: false,
+        replaced_by: None,
+    },
+    #[cfg(feature = "dbx_file_requests")]
+    RouteInfo {
+        name: "file_requests/create",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["user"],
+        preview: false,
+        deprecated: false,
+        replaced_by: None,
+    },
+    #[cfg(feature = "dbx_file_requests")]
+    RouteInfo {
+        name: "file_requests/delete",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["user"],
+        preview: false,
+        deprecated: false,
+        replaced_by: None,
+    },
+    #[cfg(feature = "dbx_file_requests")]
+    RouteInfo {
+        name: "file_requests/delete_all_closed",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["user"],
+        preview: false,
+        deprecated: false,
+        replaced_by: None,
+    },
+    #[cfg(feature = "dbx_file_requests")]
+    RouteInfo {
+        name: "file_requests/get",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["user"],
+        preview: false,
+        deprecated: false,
+        replaced_by: None,
+    },
+    #[cfg(feature = "dbx_file_requests")]
+    RouteInfo {
+        name: "file_requests/list",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["user"],
+        preview: false,
+        deprecated: false,
+        replaced_by: None,
+    },
+    #[cfg(feature = "dbx_file_requests")]
+    RouteInfo {
+        name: "file_requests/list/continue",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["user"],
+        preview: false,
+        deprecated: false,
+        replaced_by: None,
+    },
+    #[cfg(feature = "dbx_file_requests")]
+    RouteInfo {
+        name: "file_requests/list_v2",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["user"],
+        preview: false,
+        deprecated: false,
+        replaced_by: None,
+    },
+    #[cfg(feature = "dbx_file_requests")]
+    RouteInfo {
+        name: "file_requests/update",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["user"],
+        preview: false,
+        deprecated: false,
+        replaced_by: None,
+    },
+    #[cfg(feature = "unstable")]
+    #[cfg(feature = "dbx_files")]
+    RouteInfo {
+        name: "files/alpha/get_metadata",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["user"],
+        preview: true,
+        deprecated: true,
+        replaced_by: Some("files/get_metadata"),
+    },
+    #[cfg(feature = "unstable")]
+    #[cfg(feature = "dbx_files")]
+    RouteInfo {
+        name: "files/alpha/upload",
+        endpoint: Endpoint::Content,
+        style: Style::Upload,
+        auth: &["user"],
+        preview: true,
+        deprecated: true,
+        replaced_by: Some("files/upload"),
+    },
+    #[cfg(feature = "dbx_files")]
+    RouteInfo {
+        name: "files/copy",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["user"],
+        preview: false,
+        deprecated: true,
+        replaced_by: Some("files/copy_v2"),
+    },
+    #[cfg(feature = "dbx_files")]
+    RouteInfo {
+        name: "files/copy_batch",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["user"],
+        preview: false,
+        deprecated: true,
+        replaced_by: Some("files/copy_batch_v2"),
+    },
+    #[cfg(feature = "dbx_files")]
+    RouteInfo {
+        name: "files/copy_batch/check",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["user"],
+        preview: false,
+        deprecated: true,
+        replaced_by: Some("files/copy_batch/check_v2"),
+    },
+    #[cfg(feature = "dbx_files")]
+    RouteInfo {
+        name: "files/copy_batch/check_v2",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["user"],
+        preview: false,
+        deprecated: false,
+        replaced_by: None,
+    },
+    #[cfg(feature = "dbx_files")]
+    RouteInfo {
+        name: "files/copy_batch_v2",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["user"],
+        preview: false,
+        deprecated: false,
+        replaced_by: None,
+    },
+    #[cfg(feature = "dbx_files")]
+    RouteInfo {
+        name: "files/copy_reference/get",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["user"],
+        preview: false,
+        deprecated: false,
+        replaced_by: None,
+    },
+    #[cfg(feature = "dbx_files")]
+    RouteInfo {
+        name: "files/copy_reference/save",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["user"],
+        preview: false,
+        deprecated: false,
+        replaced_by: None,
+    },
+    #[cfg(feature = "dbx_files")]
+    RouteInfo {
+        name: "files/copy_v2",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["user"],
+        preview: false,
+        deprecated: false,
+        replaced_by: None,
+    },
+    #[cfg(feature = "dbx_files")]
+    RouteInfo {
+        name: "files/create_folder",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["user"],
+        preview: false,
+        deprecated: true,
+        replaced_by: Some("files/create_folder_v2"),
+    },
+    #[cfg(feature = "dbx_files")]
+    RouteInfo {
+        name: "files/create_folder_batch",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["user"],
+        preview: false,
+        deprecated: false,
+        replaced_by: None,
+    },
+    #[cfg(feature = "dbx_files")]
+    RouteInfo {
+        name: "files/create_folder_batch/check",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["user"],
+        preview: false,
+        deprecated: false,
+        replaced_by: None,
+    },
+    #[cfg(feature = "dbx_files")]
+    RouteInfo {
+        name: "files/create_folder_v2",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["user"],
+        preview: false,
+        deprecated: false,
+        replaced_by: None,
+    },
+    #[cfg(feature = "dbx_files")]
+    RouteInfo {
+        name: "files/delete",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["user"],
+        preview: false,
+        deprecated: true,
+        replaced_by: Some("files/delete_v2"),
+    },
+    #[cfg(feature = "dbx_files")]
+    RouteInfo {
+        name: "files/delete_batch",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["user"],
+        preview: false,
+        deprecated: false,
+        replaced_by: None,
+    },
+    #[cfg(feature = "dbx_files")]
+    RouteInfo {
+        name: "files/delete_batch/check",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["user"],
+        preview: false,
+        deprecated: false,
+        replaced_by: None,
+    },
+    #[cfg(feature = "dbx_files")]
+    RouteInfo {
+        name: "files/delete_v2",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["user"],
+        preview: false,
+        deprecated: false,
+        replaced_by: None,
+    },
+    #[cfg(feature = "dbx_files")]
+    RouteInfo {
+        name: "files/download",
+        endpoint: Endpoint::Content,
+        style: Style::Download,
+        auth: &["user"],
+        preview: false,
+        deprecated: false,
+        replaced_by: None,
+    },
+    #[cfg(feature = "dbx_files")]
+    RouteInfo {
+        name: "files/download_zip",
+        endpoint: Endpoint::Content,
+        style: Style::Download,
+        auth: &["user"],
+        preview: false,
+        deprecated: false,
+        replaced_by: None,
+    },
+    #[cfg(feature = "unstable")]
+    #[cfg(feature = "dbx_files")]
+    RouteInfo {
+        name: "files/export",
+        endpoint: Endpoint::Content,
+        style: Style::Download,
+        auth: &["user"],
+        preview: true,
+        deprecated: false,
+        replaced_by: None,
+    },
+    #[cfg(feature = "dbx_files")]
+    RouteInfo {
+        name: "files/get_file_lock_batch",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["user"],
+        preview: false,
+        deprecated: false,
+        replaced_by: None,
+    },
+    #[cfg(feature = "dbx_files")]
+    RouteInfo {
+        name: "files/get_metadata",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["user"],
+        preview: false,
+        deprecated: false,
+        replaced_by: None,
+    },
+    #[cfg(feature = "dbx_files")]
+    RouteInfo {
+        name: "files/get_preview",
+        endpoint: Endpoint::Content,
+        style: Style::Download,
+        auth: &["user"],
+        preview: false,
+        deprecated: false,
+        replaced_by: None,
+    },
+    #[cfg(feature = "dbx_files")]
+    RouteInfo {
+        name: "files/get_temporary_link",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["user"],
+        preview: false,
+        deprecated: false,
+        replaced_by: None,
+    },
+    #[cfg(feature = "dbx_files")]
+    RouteInfo {
+        name: "files/get_temporary_upload_link",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["user"],
+        preview: false,
+        deprecated: false,
+        replaced_by: None,
+    },
+    #[cfg(feature = "dbx_files")]
+    RouteInfo {
+        name: "files/get_thumbnail",
+        endpoint: Endpoint::Content,
+        style: Style::Download,
+        auth: &["user"],
+        preview: false,
+        deprecated: false,
+        replaced_by: None,
+    },
+    #[cfg(feature = "dbx_files")]
+    RouteInfo {
+        name: "files/get_thumbnail_batch",
+        endpoint: Endpoint::Content,
+        style: Style::Rpc,
+        auth: &["user"],
+        preview: false,
+        deprecated: false,
+        replaced_by: None,
+    },
+    #[cfg(feature = "dbx_files")]
+    RouteInfo {
+        name: "files/get_thumbnail_v2",
+        endpoint: Endpoint::Content,
+        style: Style::Download,
+        auth: &["app", "user"],
+        preview: false,
+        deprecated: false,
+        replaced_by: None,
+    },
+    #[cfg(feature = "dbx_files")]
+    RouteInfo {
+        name: "files/list_folder",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["app", "user"],
+        preview: false,
+        deprecated: false,
+        replaced_by: None,
+    },
+    #[cfg(feature = "dbx_files")]
+    RouteInfo {
+        name: "files/list_folder/continue",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["app", "user"],
+        preview: false,
+        deprecated: false,
+        replaced_by: None,
+    },
+    #[cfg(feature = "dbx_files")]
+    RouteInfo {
+        name: "files/list_folder/get_latest_cursor",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["user"],
+        preview: false,
+        deprecated: false,
+        replaced_by: None,
+    },
+    #[cfg(feature = "dbx_files")]
+    RouteInfo {
+        name: "files/list_folder/longpoll",
+        endpoint: Endpoint::Notify,
+        style: Style::Rpc,
+        auth: &["noauth"],
+        preview: false,
+        deprecated: false,
+        replaced_by: None,
+    },
+    #[cfg(feature = "dbx_files")]
+    RouteInfo {
+        name: "files/list_revisions",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["user"],
+        preview: false,
+        deprecated: false,
+        replaced_by: None,
+    },
+    #[cfg(feature = "dbx_files")]
+    RouteInfo {
+        name: "files/lock_file_batch",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["user"],
+        preview: false,
+        deprecated: false,
+        replaced_by: None,
+    },
+    #[cfg(feature = "dbx_files")]
+    RouteInfo {
+        name: "files/move",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["user"],
+        preview: false,
+        deprecated: true,
+        replaced_by: Some("files/move_v2"),
+    },
+    #[cfg(feature = "dbx_files")]
+    RouteInfo {
+        name: "files/move_batch",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["user"],
+        preview: false,
+        deprecated: true,
+        replaced_by: Some("files/move_batch_v2"),
+    },
+    #[cfg(feature = "dbx_files")]
+    RouteInfo {
+        name: "files/move_batch/check",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["user"],
+        preview: false,
+        deprecated: true,
+        replaced_by: Some("files/move_batch/check_v2"),
+    },
+    #[cfg(feature = "dbx_files")]
+    RouteInfo {
+        name: "files/move_batch/check_v2",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["user"],
+        preview: false,
+        deprecated: false,
+        replaced_by: None,
+    },
+    #[cfg(feature = "dbx_files")]
+    RouteInfo {
+        name: "files/move_batch_v2",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["user"],
+        preview: false,
+        deprecated: false,
+        replaced_by: None,
+    },
+    #[cfg(feature = "dbx_files")]
+    RouteInfo {
+        name: "files/move_v2",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["user"],
+        preview: false,
+        deprecated: false,
+        replaced_by: None,
+    },
+    #[cfg(feature = "unstable")]
+    #[cfg(feature = "dbx_files")]
+    RouteInfo {
+        name: "files/paper/create",
+        endpoint: Endpoint::Api,
+        style: Style::Upload,
+        auth: &["user"],
+        preview: true,
+        deprecated: false,
+        replaced_by: None,
+    },
+    #[cfg(feature = "unstable")]
+    #[cfg(feature = "dbx_files")]
+    RouteInfo {
+        name: "files/paper/update",
+        endpoint: Endpoint::Api,
+        style: Style::Upload,
+        auth: &["user"],
+        preview: true,
+        deprecated: false,
+        replaced_by: None,
+    },
+    #[cfg(feature = "dbx_files")]
+    RouteInfo {
+        name: "files/permanently_delete",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["user"],
+        preview: false,
+        deprecated: false,
+        replaced_by: None,
+    },
+    #[cfg(feature = "dbx_files")]
+    RouteInfo {
+        name: "files/properties/add",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["user"],
+        preview: false,
+        deprecated: true,
+        replaced_by: None,
+    },
+    #[cfg(feature = "dbx_files")]
+    RouteInfo {
+        name: "files/properties/overwrite",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["user"],
+        preview: false,
+        deprecated: true,
+        replaced_by: None,
+    },
+    #[cfg(feature = "dbx_files")]
+    RouteInfo {
+        name: "files/properties/remove",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["user"],
+        preview: false,
+        deprecated: true,
+        replaced_by: None,
+    },
+    #[cfg(feature = "dbx_files")]
+    RouteInfo {
+        name: "files/properties/template/get",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["user"],
+        preview: false,
+        deprecated: true,
+        replaced_by: None,
+    },
+    #[cfg(feature = "dbx_files")]
+    RouteInfo {
+        name: "files/properties/template/list",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["user"],
+        preview: false,
+        deprecated: true,
+        replaced_by: None,
+    },
+    #[cfg(feature = "dbx_files")]
+    RouteInfo {
+        name: "files/properties/update",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["user"],
+        preview: false,
+        deprecated: true,
+        replaced_by: None,
+    },
+    #[cfg(feature = "dbx_files")]
+    RouteInfo {
+        name: "files/restore",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["user"],
+        preview: false,
+        deprecated: false,
+        replaced_by: None,
+    },
+    #[cfg(feature = "dbx_files")]
+    RouteInfo {
+        name: "files/save_url",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["user"],
+        preview: false,
+        deprecated: false,
+        replaced_by: None,
+    },
+    #[cfg(feature = "dbx_files")]
+    RouteInfo {
+        name: "files/save_url/check_job_status",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["user"],
+        preview: false,
+        deprecated: false,
+        replaced_by: None,
+    },
+    #[cfg(feature = "dbx_files")]
+    RouteInfo {
+        name: "files/search",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["user"],
+        preview: false,
+        deprecated: true,
+        replaced_by: Some("files/search_v2"),
+    },
+    #[cfg(feature = "dbx_files")]
+    RouteInfo {
+        name: "files/search/continue_v2",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["user"],
+        preview: false,
+        deprecated: false,
+        replaced_by: None,
+    },
+    #[cfg(feature = "dbx_files")]
+    RouteInfo {
+        name: "files/search_v2",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["user"],
+        preview: false,
+        deprecated: false,
+        replaced_by: None,
+    },
+    #[cfg(feature = "unstable")]
+    #[cfg(feature = "dbx_files")]
+    RouteInfo {
+        name: "files/tags/add",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["user"],
+        preview: true,
+        deprecated: false,
+        replaced_by: None,
+    },
+    #[cfg(feature = "unstable")]
+    #[cfg(feature = "dbx_files")]
+    RouteInfo {
+        name: "files/tags/get",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["user"],
+        preview: true,
+        deprecated: false,
+        replaced_by: None,
+    },
+    #[cfg(feature = "unstable")]
+    #[cfg(feature = "dbx_files")]
+    RouteInfo {
+        name: "files/tags/remove",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["user"],
+        preview: true,
+        deprecated: false,
+        replaced_by: None,
+    },
+    #[cfg(feature = "dbx_files")]
+    RouteInfo {
+        name: "files/unlock_file_batch",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["user"],
+        preview: false,
+        deprecated: false,
+        replaced_by: None,
+    },
+    #[cfg(feature = "dbx_files")]
+    RouteInfo {
+        name: "files/upload",
+        endpoint: Endpoint::Content,
+        style: Style::Upload,
+        auth: &["user"],
+        preview: false,
+        deprecated: false,
+        replaced_by: None,
+    },
+    #[cfg(feature = "dbx_files")]
+    RouteInfo {
+        name: "files/upload_session/append",
+        endpoint: Endpoint::Content,
+        style: Style::Upload,
+        auth: &["user"],
+        preview: false,
+        deprecated: true,
+        replaced_by: Some("files/upload_session/append_v2"),
+    },
+    #[cfg(feature = "dbx_files")]
+    RouteInfo {
+        name: "files/upload_session/append_v2",
+        endpoint: Endpoint::Content,
+        style: Style::Upload,
+        auth: &["user"],
+        preview: false,
+        deprecated: false,
+        replaced_by: None,
+    },
+    #[cfg(feature = "dbx_files")]
+    RouteInfo {
+        name: "files/upload_session/finish",
+        endpoint: Endpoint::Content,
+        style: Style::Upload,
+        auth: &["user"],
+        preview: false,
+        deprecated: false,
+        replaced_by: None,
+    },
+    #[cfg(feature = "dbx_files")]
+    RouteInfo {
+        name: "files/upload_session/finish_batch",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["user"],
+        preview: false,
+        deprecated: true,
+        replaced_by: Some("files/upload_session/finish_batch_v2"),
+    },
+    #[cfg(feature = "dbx_files")]
+    RouteInfo {
+        name: "files/upload_session/finish_batch/check",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["user"],
+        preview: false,
+        deprecated: false,
+        replaced_by: None,
+    },
+    #[cfg(feature = "dbx_files")]
+    RouteInfo {
+        name: "files/upload_session/finish_batch_v2",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["user"],
+        preview: false,
+        deprecated: false,
+        replaced_by: None,
+    },
+    #[cfg(feature = "dbx_files")]
+    RouteInfo {
+        name: "files/upload_session/start",
+        endpoint: Endpoint::Content,
+        style: Style::Upload,
+        auth: &["user"],
+        preview: false,
+        deprecated: false,
+        replaced_by: None,
+    },
+    #[cfg(feature = "dbx_files")]
+    RouteInfo {
+        name: "files/upload_session/start_batch",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["user"],
+        preview: false,
+        deprecated: false,
+        replaced_by: None,
+    },
+    #[cfg(feature = "unstable")]
+    #[cfg(feature = "dbx_openid")]
+    RouteInfo {
+        name: "openid/userinfo",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["user"],
+        preview: true,
+        deprecated: false,
+        replaced_by: None,
+    },
+    #[cfg(feature = "dbx_paper")]
+    RouteInfo {
+        name: "paper/docs/archive",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["user"],
+        preview: false,
+        deprecated: true,
+        replaced_by: None,
+    },
+    #[cfg(feature = "dbx_paper")]
+    RouteInfo {
+        name: "paper/docs/create",
+        endpoint: Endpoint::Api,
+        style: Style::Upload,
+        auth: &["user"],
+        preview: false,
+        deprecated: true,
+        replaced_by: None,
+    },
+    #[cfg(feature = "dbx_paper")]
+    RouteInfo {
+        name: "paper/docs/download",
+        endpoint: Endpoint::Api,
+        style: Style::Download,
+        auth: &["user"],
+        preview: false,
+        deprecated: true,
+        replaced_by: None,
+    },
+    #[cfg(feature = "dbx_paper")]
+    RouteInfo {
+        name: "paper/docs/folder_users/list",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["user"],
+        preview: false,
+        deprecated: true,
+        replaced_by: None,
+    },
+    #[cfg(feature = "dbx_paper")]
+    RouteInfo {
+        name: "paper/docs/folder_users/list/continue",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["user"],
+        preview: false,
+        deprecated: true,
+        replaced_by: None,
+    },
+    #[cfg(feature = "dbx_paper")]
+    RouteInfo {
+        name: "paper/docs/get_folder_info",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["user"],
+        preview: false,
+        deprecated: true,
+        replaced_by: None,
+    },
+    #[cfg(feature = "dbx_paper")]
+    RouteInfo {
+        name: "paper/docs/list",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["user"],
+        preview: false,
+        deprecated: true,
+        replaced_by: None,
+    },
+    #[cfg(feature = "dbx_paper")]
+    RouteInfo {
+        name: "paper/docs/list/continue",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["user"],
+        preview: false,
+        deprecated: true,
+        replaced_by: None,
+    },
+    #[cfg(feature = "dbx_paper")]
+    RouteInfo {
+        name: "paper/docs/permanently_delete",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["user"],
+        preview: false,
+        deprecated: true,
+        replaced_by: None,
+    },
+    #[cfg(feature = "dbx_paper")]
+    RouteInfo {
+        name: "paper/docs/sharing_policy/get",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["user"],
+        preview: false,
+        deprecated: true,
+        replaced_by: None,
+    },
+    #[cfg(feature = "dbx_paper")]
+    RouteInfo {
+        name: "paper/docs/sharing_policy/set",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["user"],
+        preview: false,
+        deprecated: true,
+        replaced_by: None,
+    },
+    #[cfg(feature = "dbx_paper")]
+    RouteInfo {
+        name: "paper/docs/update",
+        endpoint: Endpoint::Api,
+        style: Style::Upload,
+        auth: &["user"],
+        preview: false,
+        deprecated: true,
+        replaced_by: None,
+    },
+    #[cfg(feature = "dbx_paper")]
+    RouteInfo {
+        name: "paper/docs/users/add",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["user"],
+        preview: false,
+        deprecated: true,
+        replaced_by: None,
+    },
+    #[cfg(feature = "dbx_paper")]
+    RouteInfo {
+        name: "paper/docs/users/list",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["user"],
+        preview: false,
+        deprecated: true,
+        replaced_by: None,
+    },
+    #[cfg(feature = "dbx_paper")]
+    RouteInfo {
+        name: "paper/docs/users/list/continue",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["user"],
+        preview: false,
+        deprecated: true,
+        replaced_by: None,
+    },
+    #[cfg(feature = "dbx_paper")]
+    RouteInfo {
+        name: "paper/docs/users/remove",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["user"],
+        preview: false,
+        deprecated: true,
+        replaced_by: None,
+    },
+    #[cfg(feature = "dbx_paper")]
+    RouteInfo {
+        name: "paper/folders/create",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["user"],
+        preview: false,
+        deprecated: true,
+        replaced_by: None,
+    },
+    #[cfg(feature = "dbx_sharing")]
+    RouteInfo {
+        name: "sharing/add_file_member",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["user"],
+        preview: false,
+        deprecated: false,
+        replaced_by: None,
+    },
+    #[cfg(feature = "dbx_sharing")]
+    RouteInfo {
+        name: "sharing/add_folder_member",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["user"],
+        preview: false,
+        deprecated: false,
+        replaced_by: None,
+    },
+    #[cfg(feature = "dbx_sharing")]
+    RouteInfo {
+        name: "sharing/check_job_status",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["user"],
+        preview: false,
+        deprecated: false,
+        replaced_by: None,
+    },
+    #[cfg(feature = "dbx_sharing")]
+    RouteInfo {
+        name: "sharing/check_remove_member_job_status",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["user"],
+        preview: false,
+        deprecated: false,
+        replaced_by: None,
+    },
+    #[cfg(feature = "dbx_sharing")]
+    RouteInfo {
+        name: "sharing/check_share_job_status",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["user"],
+        preview: false,
+        deprecated: false,
+        replaced_by: None,
+    },
+    #[cfg(feature = "dbx_sharing")]
+    RouteInfo {
+        name: "sharing/create_shared_link",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["user"],
+        preview: false,
+        deprecated: true,
+        replaced_by: Some("sharing/create_shared_link_with_settings"),
+    },
+    #[cfg(feature = "dbx_sharing")]
+    RouteInfo {
+        name: "sharing/create_shared_link_with_settings",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["user"],
+        preview: false,
+        deprecated: false,
+        replaced_by: None,
+    },
+    #[cfg(feature = "dbx_sharing")]
+    RouteInfo {
+        name: "sharing/get_file_metadata",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["user"],
+        preview: false,
+        deprecated: false,
+        replaced_by: None,
+    },
+    #[cfg(feature = "dbx_sharing")]
+    RouteInfo {
+        name: "sharing/get_file_metadata/batch",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["user"],
+        preview: false,
+        deprecated: false,
+        replaced_by: None,
+    },
+    #[cfg(feature = "dbx_sharing")]
+    RouteInfo {
+        name: "sharing/get_folder_metadata",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["user"],
+        preview: false,
+        deprecated: false,
+        replaced_by: None,
+    },
+    #[cfg(feature = "dbx_sharing")]
+    RouteInfo {
+        name: "sharing/get_shared_link_file",
+        endpoint: Endpoint::Content,
+        style: Style::Download,
+        auth: &["user"],
+        preview: false,
+        deprecated: false,
+        replaced_by: None,
+    },
+    #[cfg(feature = "dbx_sharing")]
+    RouteInfo {
+        name: "sharing/get_shared_link_metadata",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["app", "user"],
+        preview: false,
+        deprecated: false,
+        replaced_by: None,
+    },
+    #[cfg(feature = "dbx_sharing")]
+    RouteInfo {
+        name: "sharing/get_shared_links",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["user"],
+        preview: false,
+        deprecated: true,
+        replaced_by: Some("sharing/list_shared_links"),
+    },
+    #[cfg(feature = "dbx_sharing")]
+    RouteInfo {
+        name: "sharing/list_file_members",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["user"],
+        preview: false,
+        deprecated: false,
+        replaced_by: None,
+    },
+    #[cfg(feature = "dbx_sharing")]
+    RouteInfo {
+        name: "sharing/list_file_members/batch",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["user"],
+        preview: false,
+        deprecated: false,
+        replaced_by: None,
+    },
+    #[cfg(feature = "dbx_sharing")]
+    RouteInfo {
+        name: "sharing/list_file_members/continue",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["user"],
+        preview: false,
+        deprecated: false,
+        replaced_by: None,
+    },
+    #[cfg(feature = "dbx_sharing")]
+    RouteInfo {
+        name: "sharing/list_folder_members",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["user"],
+        preview: false,
+        deprecated: false,
+        replaced_by: None,
+    },
+    #[cfg(feature = "dbx_sharing")]
+    RouteInfo {
+        name: "sharing/list_folder_members/continue",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["user"],
+        preview: false,
+        deprecated: false,
+        replaced_by: None,
+    },
+    #[cfg(feature = "dbx_sharing")]
+    RouteInfo {
+        name: "sharing/list_folders",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["user"],
+        preview: false,
+        deprecated: false,
+        replaced_by: None,
+    },
+    #[cfg(feature = "dbx_sharing")]
+    RouteInfo {
+        name: "sharing/list_folders/continue",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["user"],
+        preview: false,
+        deprecated: false,
+        replaced_by: None,
+    },
+    #[cfg(feature = "dbx_sharing")]
+    RouteInfo {
+        name: "sharing/list_mountable_folders",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["user"],
+        preview: false,
+        deprecated: false,
+        replaced_by: None,
+    },
+    #[cfg(feature = "dbx_sharing")]
+    RouteInfo {
+        name: "sharing/list_mountable_folders/continue",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["user"],
+        preview: false,
+        deprecated: false,
+        replaced_by: None,
+    },
+    #[cfg(feature = "dbx_sharing")]
+    RouteInfo {
+        name: "sharing/list_received_files",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["user"],
+        preview: false,
+        deprecated: false,
+        replaced_by: None,
+    },
+    #[cfg(feature = "dbx_sharing")]
+    RouteInfo {
+        name: "sharing/list_received_files/continue",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["user"],
+        preview: false,
+        deprecated: false,
+        replaced_by: None,
+    },
+    #[cfg(feature = "dbx_sharing")]
+    RouteInfo {
+        name: "sharing/list_shared_links",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["user"],
+        preview: false,
+        deprecated: false,
+        replaced_by: None,
+    },
+    #[cfg(feature = "dbx_sharing")]
+    RouteInfo {
+        name: "sharing/modify_shared_link_settings",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["user"],
+        preview: false,
+        deprecated: false,
+        replaced_by: None,
+    },
+    #[cfg(feature = "dbx_sharing")]
+    RouteInfo {
+        name: "sharing/mount_folder",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["user"],
+        preview: false,
+        deprecated: false,
+        replaced_by: None,
+    },
+    #[cfg(feature = "dbx_sharing")]
+    RouteInfo {
+        name: "sharing/relinquish_file_membership",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["user"],
+        preview: false,
+        deprecated: false,
+        replaced_by: None,
+    },
+    #[cfg(feature = "dbx_sharing")]
+    RouteInfo {
+        name: "sharing/relinquish_folder_membership",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["user"],
+        preview: false,
+        deprecated: false,
+        replaced_by: None,
+    },
+    #[cfg(feature = "dbx_sharing")]
+    RouteInfo {
+        name: "sharing/remove_file_member",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["user"],
+        preview: false,
+        deprecated: true,
+        replaced_by: Some("sharing/remove_file_member_2"),
+    },
+    #[cfg(feature = "dbx_sharing")]
+    RouteInfo {
+        name: "sharing/remove_file_member_2",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["user"],
+        preview: false,
+        deprecated: false,
+        replaced_by: None,
+    },
+    #[cfg(feature = "dbx_sharing")]
+    RouteInfo {
+        name: "sharing/remove_folder_member",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["user"],
+        preview: false,
+        deprecated: false,
+        replaced_by: None,
+    },
+    #[cfg(feature = "dbx_sharing")]
+    RouteInfo {
+        name: "sharing/revoke_shared_link",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["user"],
+        preview: false,
+        deprecated: false,
+        replaced_by: None,
+    },
+    #[cfg(feature = "dbx_sharing")]
+    RouteInfo {
+        name: "sharing/set_access_inheritance",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["user"],
+        preview: false,
+        deprecated: false,
+        replaced_by: None,
+    },
+    #[cfg(feature = "dbx_sharing")]
+    RouteInfo {
+        name: "sharing/share_folder",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["user"],
+        preview: false,
+        deprecated: false,
+        replaced_by: None,
+    },
+    #[cfg(feature = "dbx_sharing")]
+    RouteInfo {
+        name: "sharing/transfer_folder",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["user"],
+        preview: false,
+        deprecated: false,
+        replaced_by: None,
+    },
+    #[cfg(feature = "dbx_sharing")]
+    RouteInfo {
+        name: "sharing/unmount_folder",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["user"],
+        preview: false,
+        deprecated: false,
+        replaced_by: None,
+    },
+    #[cfg(feature = "dbx_sharing")]
+    RouteInfo {
+        name: "sharing/unshare_file",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["user"],
+        preview: false,
+        deprecated: false,
+        replaced_by: None,
+    },
+    #[cfg(feature = "dbx_sharing")]
+    RouteInfo {
+        name: "sharing/unshare_folder",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["user"],
+        preview: false,
+        deprecated: false,
+        replaced_by: None,
+    },
+    #[cfg(feature = "dbx_sharing")]
+    RouteInfo {
+        name: "sharing/update_file_member",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["user"],
+        preview: false,
+        deprecated: false,
+        replaced_by: None,
+    },
+    #[cfg(feature = "dbx_sharing")]
+    RouteInfo {
+        name: "sharing/update_folder_member",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["user"],
+        preview: false,
+        deprecated: false,
+        replaced_by: None,
+    },
+    #[cfg(feature = "dbx_sharing")]
+    RouteInfo {
+        name: "sharing/update_folder_policy",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["user"],
+        preview: false,
+        deprecated: false,
+        replaced_by: None,
+    },
+    #[cfg(feature = "dbx_team")]
+    RouteInfo {
+        name: "team/devices/list_member_devices",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["team"],
+        preview: false,
+        deprecated: false,
+        replaced_by: None,
+    },
+    #[cfg(feature = "dbx_team")]
+    RouteInfo {
+        name: "team/devices/list_members_devices",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["team"],
+        preview: false,
+        deprecated: false,
+        replaced_by: None,
+    },
+    #[cfg(feature = "dbx_team")]
+    RouteInfo {
+        name: "team/devices/list_team_devices",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["team"],
+        preview: false,
+        deprecated: true,
+        replaced_by: Some("team/devices/list_members_devices"),
+    },
+    #[cfg(feature = "dbx_team")]
+    RouteInfo {
+        name: "team/devices/revoke_device_session",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["team"],
+        preview: false,
+        deprecated: false,
+        replaced_by: None,
+    },
+    #[cfg(feature = "dbx_team")]
+    RouteInfo {
+        name: "team/devices/revoke_device_session_batch",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["team"],
+        preview: false,
+        deprecated: false,
+        replaced_by: None,
+    },
+    #[cfg(feature = "dbx_team")]
+    RouteInfo {
+        name: "team/features/get_values",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["team"],
+        preview: false,
+        deprecated: false,
+        replaced_by: None,
+    },
+    #[cfg(feature = "dbx_team")]
+    RouteInfo {
+        name: "team/get_info",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["team"],
+        preview: false,
+        deprecated: false,
+        replaced_by: None,
+    },
+    #[cfg(feature = "dbx_team")]
+    RouteInfo {
+        name: "team/groups/create",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["team"],
+        preview: false,
+        deprecated: false,
+        replaced_by: None,
+    },
+    #[cfg(feature = "dbx_team")]
+    RouteInfo {
+        name: "team/groups/delete",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["team"],
+        preview: false,
+        deprecated: false,
+        replaced_by: None,
+    },
+    #[cfg(feature = "dbx_team")]
+    RouteInfo {
+        name: "team/groups/get_info",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["team"],
+        preview: false,
+        deprecated: false,
+        replaced_by: None,
+    },
+    #[cfg(feature = "dbx_team")]
+    RouteInfo {
+        name: "team/groups/job_status/get",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["team"],
+        preview: false,
+        deprecated: false,
+        replaced_by: None,
+    },
+    #[cfg(feature = "dbx_team")]
+    RouteInfo {
+        name: "team/groups/list",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["team"],
+        preview: false,
+        deprecated: false,
+        replaced_by: None,
+    },
+    #[cfg(feature = "dbx_team")]
+    RouteInfo {
+        name: "team/groups/list/continue",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["team"],
+        preview: false,
+        deprecated: false,
+        replaced_by: None,
+    },
+    #[cfg(feature = "dbx_team")]
+    RouteInfo {
+        name: "team/groups/members/add",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["team"],
+        preview: false,
+        deprecated: false,
+        replaced_by: None,
+    },
+    #[cfg(feature = "dbx_team")]
+    RouteInfo {
+        name: "team/groups/members/list",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["team"],
+        preview: false,
+        deprecated: false,
+        replaced_by: None,
+    },
+    #[cfg(feature = "dbx_team")]
+    RouteInfo {
+        name: "team/groups/members/list/continue",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["team"],
+        preview: false,
+        deprecated: false,
+        replaced_by: None,
+    },
+    #[cfg(feature = "dbx_team")]
+    RouteInfo {
+        name: "team/groups/members/remove",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["team"],
+        preview: false,
+        deprecated: false,
+        replaced_by: None,
+    },
+    #[cfg(feature = "dbx_team")]
+    RouteInfo {
+        name: "team/groups/members/set_access_type",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["team"],
+        preview: false,
+        deprecated: false,
+        replaced_by: None,
+    },
+    #[cfg(feature = "dbx_team")]
+    RouteInfo {
+        name: "team/groups/update",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["team"],
+        preview: false,
+        deprecated: false,
+        replaced_by: None,
+    },
+    #[cfg(feature = "dbx_team")]
+    RouteInfo {
+        name: "team/legal_holds/create_policy",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["team"],
+        preview: false,
+        deprecated: false,
+        replaced_by: None,
+    },
+    #[cfg(feature = "dbx_team")]
+    RouteInfo {
+        name: "team/legal_holds/get_policy",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["team"],
+        preview: false,
+        deprecated: false,
+        replaced_by: None,
+    },
+    #[cfg(feature = "dbx_team")]
+    RouteInfo {
+        name: "team/legal_holds/list_held_revisions",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["team"],
+        preview: false,
+        deprecated: false,
+        replaced_by: None,
+    },
+    #[cfg(feature = "dbx_team")]
+    RouteInfo {
+        name: "team/legal_holds/list_held_revisions_continue",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["team"],
+        preview: false,
+        deprecated: false,
+        replaced_by: None,
+    },
+    #[cfg(feature = "dbx_team")]
+    RouteInfo {
+        name: "team/legal_holds/list_policies",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["team"],
+        preview: false,
+        deprecated: false,
+        replaced_by: None,
+    },
+    #[cfg(feature = "dbx_team")]
+    RouteInfo {
+        name: "team/legal_holds/release_policy",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["team"],
+        preview: false,
+        deprecated: false,
+        replaced_by: None,
+    },
+    #[cfg(feature = "dbx_team")]
+    RouteInfo {
+        name: "team/legal_holds/update_policy",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["team"],
+        preview: false,
+        deprecated: false,
+        replaced_by: None,
+    },
+    #[cfg(feature = "dbx_team")]
+    RouteInfo {
+        name: "team/linked_apps/list_member_linked_apps",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["team"],
+        preview: false,
+        deprecated: false,
+        replaced_by: None,
+    },
+    #[cfg(feature = "dbx_team")]
+    RouteInfo {
+        name: "team/linked_apps/list_members_linked_apps",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["team"],
+        preview: false,
+        deprecated: false,
+        replaced_by: None,
+    },
+    #[cfg(feature = "dbx_team")]
+    RouteInfo {
+        name: "team/linked_apps/list_team_linked_apps",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["team"],
+        preview: false,
+        deprecated: true,
+        replaced_by: Some("team/linked_apps/list_members_linked_apps"),
+    },
+    #[cfg(feature = "dbx_team")]
+    RouteInfo {
+        name: "team/linked_apps/revoke_linked_app",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["team"],
+        preview: false,
+        deprecated: false,
+        replaced_by: None,
+    },
+    #[cfg(feature = "dbx_team")]
+    RouteInfo {
+        name: "team/linked_apps/revoke_linked_app_batch",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["team"],
+        preview: false,
+        deprecated: false,
+        replaced_by: None,
+    },
+    #[cfg(feature = "dbx_team")]
+    RouteInfo {
+        name: "team/member_space_limits/excluded_users/add",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["team"],
+        preview: false,
+        deprecated: false,
+        replaced_by: None,
+    },
+    #[cfg(feature = "dbx_team")]
+    RouteInfo {
+        name: "team/member_space_limits/excluded_users/list",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["team"],
+        preview: false,
+        deprecated: false,
+        replaced_by: None,
+    },
+    #[cfg(feature = "dbx_team")]
+    RouteInfo {
+        name: "team/member_space_limits/excluded_users/list/continue",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["team"],
+        preview: false,
+        deprecated: false,
+        replaced_by: None,
+    },
+    #[cfg(feature = "dbx_team")]
+    RouteInfo {
+        name: "team/member_space_limits/excluded_users/remove",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["team"],
+        preview: false,
+        deprecated: false,
+        replaced_by: None,
+    },
+    #[cfg(feature = "dbx_team")]
+    RouteInfo {
+        name: "team/member_space_limits/get_custom_quota",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["team"],
+        preview: false,
+        deprecated: false,
+        replaced_by: None,
+    },
+    #[cfg(feature = "dbx_team")]
+    RouteInfo {
+        name: "team/member_space_limits/remove_custom_quota",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["team"],
+        preview: false,
+        deprecated: false,
+        replaced_by: None,
+    },
+    #[cfg(feature = "dbx_team")]
+    RouteInfo {
+        name: "team/member_space_limits/set_custom_quota",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["team"],
+        preview: false,
+        deprecated: false,
+        replaced_by: None,
+    },
+    #[cfg(feature = "dbx_team")]
+    RouteInfo {
+        name: "team/members/add",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["team"],
+        preview: false,
+        deprecated: false,
+        replaced_by: None,
+    },
+    #[cfg(feature = "dbx_team")]
+    RouteInfo {
+        name: "team/members/add/job_status/get",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["team"],
+        preview: false,
+        deprecated: false,
+        replaced_by: None,
+    },
+    #[cfg(feature = "dbx_team")]
+    RouteInfo {
+        name: "team/members/add/job_status/get_v2",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["team"],
+        preview: false,
+        deprecated: false,
+        replaced_by: None,
+    },
+    #[cfg(feature = "dbx_team")]
+    RouteInfo {
+        name: "team/members/add_v2",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["team"],
+        preview: false,
+        deprecated: false,
+        replaced_by: None,
+    },
+    #[cfg(feature = "dbx_team")]
+    RouteInfo {
+        name: "team/members/delete_profile_photo",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["team"],
+        preview: false,
+        deprecated: false,
+        replaced_by: None,
+    },
+    #[cfg(feature = "dbx_team")]
+    RouteInfo {
+        name: "team/members/delete_profile_photo_v2",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["team"],
+        preview: false,
+        deprecated: false,
+        replaced_by: None,
+    },
+    #[cfg(feature = "dbx_team")]
+    RouteInfo {
+        name: "team/members/get_available_team_member_roles",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["team"],
+        preview: false,
+        deprecated: false,
+        replaced_by: None,
+    },
+    #[cfg(feature = "dbx_team")]
+    RouteInfo {
+        name: "team/members/get_info",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["team"],
+        preview: false,
+        deprecated: false,
+        replaced_by: None,
+    },
+    #[cfg(feature = "dbx_team")]
+    RouteInfo {
+        name: "team/members/get_info_v2",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["team"],
+        preview: false,
+        deprecated: false,
+        replaced_by: None,
+    },
+    #[cfg(feature = "dbx_team")]
+    RouteInfo {
+        name: "team/members/list",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["team"],
+        preview: false,
+        deprecated: false,
+        replaced_by: None,
+    },
+    #[cfg(feature = "dbx_team")]
+    RouteInfo {
+        name: "team/members/list/continue",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["team"],
+        preview: false,
+        deprecated: false,
+        replaced_by: None,
+    },
+    #[cfg(feature = "dbx_team")]
+    RouteInfo {
+        name: "team/members/list/continue_v2",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["team"],
+        preview: false,
+        deprecated: false,
+        replaced_by: None,
+    },
+    #[cfg(feature = "dbx_team")]
+    RouteInfo {
+        name: "team/members/list_v2",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["team"],
+        preview: false,
+        deprecated: false,
+        replaced_by: None,
+    },
+    #[cfg(feature = "dbx_team")]
+    RouteInfo {
+        name: "team/members/move_former_member_files",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["team"],
+        preview: false,
+        deprecated: false,
+        replaced_by: None,
+    },
+    #[cfg(feature = "dbx_team")]
+    RouteInfo {
+        name: "team/members/move_former_member_files/job_status/check",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["team"],
+        preview: false,
+        deprecated: false,
+        replaced_by: None,
+    },
+    #[cfg(feature = "dbx_team")]
+    RouteInfo {
+        name: "team/members/recover",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["team"],
+        preview: false,
+        deprecated: false,
+        replaced_by: None,
+    },
+    #[cfg(feature = "dbx_team")]
+    RouteInfo {
+        name: "team/members/remove",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["team"],
+        preview: false,
+        deprecated: false,
+        replaced_by: None,
+    },
+    #[cfg(feature = "dbx_team")]
+    RouteInfo {
+        name: "team/members/remove/job_status/get",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["team"],
+        preview: false,
+        deprecated: false,
+        replaced_by: None,
+    },
+    #[cfg(feature = "dbx_team")]
+    RouteInfo {
+        name: "team/members/secondary_emails/add",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["team"],
+        preview: false,
+        deprecated: false,
+        replaced_by: None,
+    },
+    #[cfg(feature = "dbx_team")]
+    RouteInfo {
+        name: "team/members/secondary_emails/delete",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["team"],
+        preview: false,
+        deprecated: false,
+        replaced_by: None,
+    },
+    #[cfg(feature = "dbx_team")]
+    RouteInfo {
+        name: "team/members/secondary_emails/resend_verification_emails",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["team"],
+        preview: false,
+        deprecated: false,
+        replaced_by: None,
+    },
+    #[cfg(feature = "dbx_team")]
+    RouteInfo {
+        name: "team/members/send_welcome_email",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["team"],
+        preview: false,
+        deprecated: false,
+        replaced_by: None,
+    },
+    #[cfg(feature = "dbx_team")]
+    RouteInfo {
+        name: "team/members/set_admin_permissions",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["team"],
+        preview: false,
+        deprecated: false,
+        replaced_by: None,
+    },
+    #[cfg(feature = "dbx_team")]
+    RouteInfo {
+        name: "team/members/set_admin_permissions_v2",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["team"],
+        preview: false,
+        deprecated: false,
+        replaced_by: None,
+    },
+    #[cfg(feature = "dbx_team")]
+    RouteInfo {
+        name: "team/members/set_profile",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["team"],
+        preview: false,
+        deprecated: false,
+        replaced_by: None,
+    },
+    #[cfg(feature = "dbx_team")]
+    RouteInfo {
+        name: "team/members/set_profile_photo",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["team"],
+        preview: false,
+        deprecated: false,
+        replaced_by: None,
+    },
+    #[cfg(feature = "dbx_team")]
+    RouteInfo {
+        name: "team/members/set_profile_photo_v2",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["team"],
+        preview: false,
+        deprecated: false,
+        replaced_by: None,
+    },
+    #[cfg(feature = "dbx_team")]
+    RouteInfo {
+        name: "team/members/set_profile_v2",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["team"],
+        preview: false,
+        deprecated: false,
+        replaced_by: None,
+    },
+    #[cfg(feature = "dbx_team")]
+    RouteInfo {
+        name: "team/members/suspend",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["team"],
+        preview: false,
+        deprecated: false,
+        replaced_by: None,
+    },
+    #[cfg(feature = "dbx_team")]
+    RouteInfo {
+        name: "team/members/unsuspend",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["team"],
+        preview: false,
+        deprecated: false,
+        replaced_by: None,
+    },
+    #[cfg(feature = "dbx_team")]
+    RouteInfo {
+        name: "team/namespaces/list",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["team"],
+        preview: false,
+        deprecated: false,
+        replaced_by: None,
+    },
+    #[cfg(feature = "dbx_team")]
+    RouteInfo {
+        name: "team/namespaces/list/continue",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["team"],
+        preview: false,
+        deprecated: false,
+        replaced_by: None,
+    },
+    #[cfg(feature = "dbx_team")]
+    RouteInfo {
+        name: "team/properties/template/add",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["team"],
+        preview: false,
+        deprecated: true,
+        replaced_by: None,
+    },
+    #[cfg(feature = "dbx_team")]
+    RouteInfo {
+        name: "team/properties/template/get",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["team"],
+        preview: false,
+        deprecated: true,
+        replaced_by: None,
+    },
+    #[cfg(feature = "dbx_team")]
+    RouteInfo {
+        name: "team/properties/template/list",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["team"],
+        preview: false,
+        deprecated: true,
+        replaced_by: None,
+    },
+    #[cfg(feature = "dbx_team")]
+    RouteInfo {
+        name: "team/properties/template/update",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["team"],
+        preview: false,
+        deprecated: true,
+        replaced_by: None,
+    },
+    #[cfg(feature = "dbx_team")]
+    RouteInfo {
+        name: "team/reports/get_activity",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["team"],
+        preview: false,
+        deprecated: true,
+        replaced_by: None,
+    },
+    #[cfg(feature = "dbx_team")]
+    RouteInfo {
+        name: "team/reports/get_devices",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["team"],
+        preview: false,
+        deprecated: true,
+        replaced_by: None,
+    },
+    #[cfg(feature = "dbx_team")]
+    RouteInfo {
+        name: "team/reports/get_membership",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["team"],
+        preview: false,
+        deprecated: true,
+        replaced_by: None,
+    },
+    #[cfg(feature = "dbx_team")]
+    RouteInfo {
+        name: "team/reports/get_storage",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["team"],
+        preview: false,
+        deprecated: true,
+        replaced_by: None,
+    },
+    #[cfg(feature = "unstable")]
+    #[cfg(feature = "dbx_team")]
+    RouteInfo {
+        name: "team/sharing_allowlist/add",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["team"],
+        preview: true,
+        deprecated: false,
+        replaced_by: None,
+    },
+    #[cfg(feature = "unstable")]
+    #[cfg(feature = "dbx_team")]
+    RouteInfo {
+        name: "team/sharing_allowlist/list",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["team"],
+        preview: true,
+        deprecated: false,
+        replaced_by: None,
+    },
+    #[cfg(feature = "unstable")]
+    #[cfg(feature = "dbx_team")]
+    RouteInfo {
+        name: "team/sharing_allowlist/list/continue",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["team"],
+        preview: true,
+        deprecated: false,
+        replaced_by: None,
+    },
+    #[cfg(feature = "unstable")]
+    #[cfg(feature = "dbx_team")]
+    RouteInfo {
+        name: "team/sharing_allowlist/remove",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["team"],
+        preview: true,
+        deprecated: false,
+        replaced_by: None,
+    },
+    #[cfg(feature = "dbx_team")]
+    RouteInfo {
+        name: "team/team_folder/activate",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["team"],
+        preview: false,
+        deprecated: false,
+        replaced_by: None,
+    },
+    #[cfg(feature = "dbx_team")]
+    RouteInfo {
+        name: "team/team_folder/archive",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["team"],
+        preview: false,
+        deprecated: false,
+        replaced_by: None,
+    },
+    #[cfg(feature = "dbx_team")]
+    RouteInfo {
+        name: "team/team_folder/archive/check",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["team"],
+        preview: false,
+        deprecated: false,
+        replaced_by: None,
+    },
+    #[cfg(feature = "dbx_team")]
+    RouteInfo {
+        name: "team/team_folder/create",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["team"],
+        preview: false,
+        deprecated: false,
+        replaced_by: None,
+    },
+    #[cfg(feature = "dbx_team")]
+    RouteInfo {
+        name: "team/team_folder/get_info",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["team"],
+        preview: false,
+        deprecated: false,
+        replaced_by: None,
+    },
+    #[cfg(feature = "dbx_team")]
+    RouteInfo {
+        name: "team/team_folder/list",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["team"],
+        preview: false,
+        deprecated: false,
+        replaced_by: None,
+    },
+    #[cfg(feature = "dbx_team")]
+    RouteInfo {
+        name: "team/team_folder/list/continue",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["team"],
+        preview: false,
+        deprecated: false,
+        replaced_by: None,
+    },
+    #[cfg(feature = "dbx_team")]
+    RouteInfo {
+        name: "team/team_folder/permanently_delete",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["team"],
+        preview: false,
+        deprecated: false,
+        replaced_by: None,
+    },
+    #[cfg(feature = "dbx_team")]
+    RouteInfo {
+        name: "team/team_folder/rename",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["team"],
+        preview: false,
+        deprecated: false,
+        replaced_by: None,
+    },
+    #[cfg(feature = "dbx_team")]
+    RouteInfo {
+        name: "team/team_folder/update_sync_settings",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["team"],
+        preview: false,
+        deprecated: false,
+        replaced_by: None,
+    },
+    #[cfg(feature = "dbx_team")]
+    RouteInfo {
+        name: "team/token/get_authenticated_admin",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["team"],
+        preview: false,
+        deprecated: false,
+        replaced_by: None,
+    },
+    #[cfg(feature = "dbx_team_log")]
+    RouteInfo {
+        name: "team_log/get_events",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["team"],
+        preview: false,
+        deprecated: false,
+        replaced_by: None,
+    },
+    #[cfg(feature = "dbx_team_log")]
+    RouteInfo {
+        name: "team_log/get_events/continue",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["team"],
+        preview: false,
+        deprecated: false,
+        replaced_by: None,
+    },
+    #[cfg(feature = "dbx_users")]
+    RouteInfo {
+        name: "users/features/get_values",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["user"],
+        preview: false,
+        deprecated: false,
+        replaced_by: None,
+    },
+    #[cfg(feature = "dbx_users")]
+    RouteInfo {
+        name: "users/get_account",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["user"],
+        preview: false,
+        deprecated: false,
+        replaced_by: None,
+    },
+    #[cfg(feature = "dbx_users")]
+    RouteInfo {
+        name: "users/get_account_batch",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["user"],
+        preview: false,
+        deprecated: false,
+        replaced_by: None,
+    },
+    #[cfg(feature = "dbx_users")]
+    RouteInfo {
+        name: "users/get_current_account",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["user"],
+        preview: false,
+        deprecated: false,
+        replaced_by: None,
+    },
+    #[cfg(feature = "dbx_users")]
+    RouteInfo {
+        name: "users/get_space_usage",
+        endpoint: Endpoint::Api,
+        style: Style::Rpc,
+        auth: &["user"],
+        preview: false,
+        deprecated: false,
+        replaced_by: None,
+    },
+];
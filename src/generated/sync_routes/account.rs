@@ -11,6 +11,15 @@
 #[allow(unused_imports)]
 pub use crate::generated::types::account::*;
 
+/// The types of this namespace, and the client traits its routes take, for importing all at
+/// once with `use dropbox_sdk::account::prelude::*`. Unlike a glob import of this module, this
+/// leaves out the route functions, whose names are often too general to import unqualified.
+#[allow(unused_imports)]
+pub mod prelude {
+    pub use crate::generated::types::account::*;
+    pub use crate::client_trait::{AppAuthClient, NoauthClient, TeamAuthClient, UserAuthClient};
+}
+
 /// Sets a user's profile photo.
 pub fn set_profile_photo(
     client: &impl crate::client_trait::UserAuthClient,
@@ -11,6 +11,15 @@
 #[allow(unused_imports)]
 pub use crate::generated::types::check::*;
 
+/// The types of this namespace, and the client traits its routes take, for importing all at
+/// once with `use dropbox_sdk::check::prelude::*`. Unlike a glob import of this module, this
+/// leaves out the route functions, whose names are often too general to import unqualified.
+#[allow(unused_imports)]
+pub mod prelude {
+    pub use crate::generated::types::check::*;
+    pub use crate::client_trait::{AppAuthClient, NoauthClient, TeamAuthClient, UserAuthClient};
+}
+
 /// This endpoint performs App Authentication, validating the supplied app key and secret, and
 /// returns the supplied string, to allow you to test your code and connection to the Dropbox API.
 /// It has no other effect. If you receive an HTTP 200 response with the supplied query, it
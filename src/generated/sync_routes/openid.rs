@@ -11,6 +11,15 @@
 #[allow(unused_imports)]
 pub use crate::generated::types::openid::*;
 
+/// The types of this namespace, and the client traits its routes take, for importing all at
+/// once with `use dropbox_sdk::openid::prelude::*`. Unlike a glob import of this module, this
+/// leaves out the route functions, whose names are often too general to import unqualified.
+#[allow(unused_imports)]
+pub mod prelude {
+    pub use crate::generated::types::openid::*;
+    pub use crate::client_trait::{AppAuthClient, NoauthClient, TeamAuthClient, UserAuthClient};
+}
+
 /// This route is used for refreshing the info that is found in the id_token during the OIDC flow.
 /// This route doesn't require any arguments and will use the scopes approved for the given access
 /// token.
@@ -11,6 +11,15 @@
 #[allow(unused_imports)]
 pub use crate::generated::types::paper::*;
 
+/// The types of this namespace, and the client traits its routes take, for importing all at
+/// once with `use dropbox_sdk::paper::prelude::*`. Unlike a glob import of this module, this
+/// leaves out the route functions, whose names are often too general to import unqualified.
+#[allow(unused_imports)]
+pub mod prelude {
+    pub use crate::generated::types::paper::*;
+    pub use crate::client_trait::{AppAuthClient, NoauthClient, TeamAuthClient, UserAuthClient};
+}
+
 /// Marks the given Paper doc as archived. This action can be performed or undone by anyone with
 /// edit permissions to the doc. Note that this endpoint will continue to work for content created
 /// by users on the older version of Paper. To check which version of Paper a user is on, use
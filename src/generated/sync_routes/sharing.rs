@@ -11,6 +11,15 @@
 #[allow(unused_imports)]
 pub use crate::generated::types::sharing::*;
 
+/// The types of this namespace, and the client traits its routes take, for importing all at
+/// once with `use dropbox_sdk::sharing::prelude::*`. Unlike a glob import of this module, this
+/// leaves out the route functions, whose names are often too general to import unqualified.
+#[allow(unused_imports)]
+pub mod prelude {
+    pub use crate::generated::types::sharing::*;
+    pub use crate::client_trait::{AppAuthClient, NoauthClient, TeamAuthClient, UserAuthClient};
+}
+
 /// Adds specified members to a file.
 pub fn add_file_member(
     client: &impl crate::client_trait::UserAuthClient,
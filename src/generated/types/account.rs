@@ -8,14 +8,22 @@
     clippy::doc_markdown,
 )]
 
+/// The names of this namespace's routes, as they appear in request URLs after the
+/// [`Endpoint`](crate::client_trait_common::Endpoint) base URL, for referring to them in logs and
+/// metrics without spelling them out.
+pub mod routes {
+    pub const SET_PROFILE_PHOTO: &str = "account/set_profile_photo";
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 #[non_exhaustive] // variants may be added in the future
 pub enum PhotoSourceArg {
     /// Image data in base64-encoded bytes.
     Base64Data(String),
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for PhotoSourceArg {
@@ -41,7 +49,7 @@ impl<'de> ::serde::de::Deserialize<'de> for PhotoSourceArg {
                             _ => return Err(de::Error::unknown_field(tag, VARIANTS))
                         }
                     }
-                    _ => PhotoSourceArg::Other,
+                    _ => PhotoSourceArg::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -65,7 +73,10 @@ impl ::serde::ser::Serialize for PhotoSourceArg {
                 s.serialize_field("base64_data", x)?;
                 s.end()
             }
-            PhotoSourceArg::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            PhotoSourceArg::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -102,16 +113,8 @@ impl SetProfilePhotoArg {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "photo" => {
-                    if field_photo.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("photo"));
-                    }
-                    field_photo = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "photo" => crate::deserialize_field(&mut map, &mut field_photo, "photo")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -175,8 +178,9 @@ pub enum SetProfilePhotoError {
     /// Temporary infrastructure failure, please retry.
     TransientError,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for SetProfilePhotoError {
@@ -200,7 +204,7 @@ impl<'de> ::serde::de::Deserialize<'de> for SetProfilePhotoError {
                     "dimension_error" => SetProfilePhotoError::DimensionError,
                     "thumbnail_error" => SetProfilePhotoError::ThumbnailError,
                     "transient_error" => SetProfilePhotoError::TransientError,
-                    _ => SetProfilePhotoError::Other,
+                    _ => SetProfilePhotoError::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -251,7 +255,10 @@ impl ::serde::ser::Serialize for SetProfilePhotoError {
                 s.serialize_field(".tag", "transient_error")?;
                 s.end()
             }
-            SetProfilePhotoError::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            SetProfilePhotoError::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -304,16 +311,8 @@ impl SetProfilePhotoResult {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "profile_photo_url" => {
-                    if field_profile_photo_url.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("profile_photo_url"));
-                    }
-                    field_profile_photo_url = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "profile_photo_url" => crate::deserialize_field(&mut map, &mut field_profile_photo_url, "profile_photo_url")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
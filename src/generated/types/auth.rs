@@ -8,6 +8,14 @@
     clippy::doc_markdown,
 )]
 
+/// The names of this namespace's routes, as they appear in request URLs after the
+/// [`Endpoint`](crate::client_trait_common::Endpoint) base URL, for referring to them in logs and
+/// metrics without spelling them out.
+pub mod routes {
+    pub const TOKEN_FROM_OAUTH1: &str = "auth/token/from_oauth1";
+    pub const TOKEN_REVOKE: &str = "auth/token/revoke";
+}
+
 /// Error occurred because the account doesn't have permission to access the resource.
 #[derive(Debug, Clone, PartialEq, Eq)]
 #[non_exhaustive] // variants may be added in the future
@@ -17,8 +25,9 @@ pub enum AccessError {
     /// Current account cannot access Paper.
     PaperAccessDenied(PaperAccessError),
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for AccessError {
@@ -51,7 +60,7 @@ impl<'de> ::serde::de::Deserialize<'de> for AccessError {
                             _ => return Err(de::Error::unknown_field(tag, VARIANTS))
                         }
                     }
-                    _ => AccessError::Other,
+                    _ => AccessError::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -83,7 +92,10 @@ impl ::serde::ser::Serialize for AccessError {
                 s.serialize_field("paper_access_denied", x)?;
                 s.end()
             }
-            AccessError::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            AccessError::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -127,8 +139,9 @@ pub enum AuthError {
     /// The route is not available to public.
     RouteAccessDenied,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for AuthError {
@@ -154,7 +167,7 @@ impl<'de> ::serde::de::Deserialize<'de> for AuthError {
                     "expired_access_token" => AuthError::ExpiredAccessToken,
                     "missing_scope" => AuthError::MissingScope(TokenScopeError::internal_deserialize(&mut map)?),
                     "route_access_denied" => AuthError::RouteAccessDenied,
-                    _ => AuthError::Other,
+                    _ => AuthError::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -220,7 +233,10 @@ impl ::serde::ser::Serialize for AuthError {
                 s.serialize_field(".tag", "route_access_denied")?;
                 s.end()
             }
-            AuthError::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            AuthError::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -251,8 +267,9 @@ pub enum InvalidAccountTypeError {
     /// Current account type doesn't have permission to access this feature.
     Feature,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for InvalidAccountTypeError {
@@ -273,7 +290,7 @@ impl<'de> ::serde::de::Deserialize<'de> for InvalidAccountTypeError {
                 let value = match tag {
                     "endpoint" => InvalidAccountTypeError::Endpoint,
                     "feature" => InvalidAccountTypeError::Feature,
-                    _ => InvalidAccountTypeError::Other,
+                    _ => InvalidAccountTypeError::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -303,7 +320,10 @@ impl ::serde::ser::Serialize for InvalidAccountTypeError {
                 s.serialize_field(".tag", "feature")?;
                 s.end()
             }
-            InvalidAccountTypeError::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            InvalidAccountTypeError::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -329,8 +349,9 @@ pub enum PaperAccessError {
     /// The provided user has not used Paper yet.
     NotPaperUser,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for PaperAccessError {
@@ -351,7 +372,7 @@ impl<'de> ::serde::de::Deserialize<'de> for PaperAccessError {
                 let value = match tag {
                     "paper_disabled" => PaperAccessError::PaperDisabled,
                     "not_paper_user" => PaperAccessError::NotPaperUser,
-                    _ => PaperAccessError::Other,
+                    _ => PaperAccessError::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -381,7 +402,10 @@ impl ::serde::ser::Serialize for PaperAccessError {
                 s.serialize_field(".tag", "not_paper_user")?;
                 s.end()
             }
-            PaperAccessError::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            PaperAccessError::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -442,22 +466,9 @@ impl RateLimitError {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "reason" => {
-                    if field_reason.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("reason"));
-                    }
-                    field_reason = Some(map.next_value()?);
-                }
-                "retry_after" => {
-                    if field_retry_after.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("retry_after"));
-                    }
-                    field_retry_after = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "reason" => crate::deserialize_field(&mut map, &mut field_reason, "reason")?,
+                "retry_after" => crate::deserialize_field(&mut map, &mut field_retry_after, "retry_after")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -519,8 +530,9 @@ pub enum RateLimitReason {
     /// There are currently too many write operations happening in the user's Dropbox.
     TooManyWriteOperations,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for RateLimitReason {
@@ -541,7 +553,7 @@ impl<'de> ::serde::de::Deserialize<'de> for RateLimitReason {
                 let value = match tag {
                     "too_many_requests" => RateLimitReason::TooManyRequests,
                     "too_many_write_operations" => RateLimitReason::TooManyWriteOperations,
-                    _ => RateLimitReason::Other,
+                    _ => RateLimitReason::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -571,7 +583,10 @@ impl ::serde::ser::Serialize for RateLimitReason {
                 s.serialize_field(".tag", "too_many_write_operations")?;
                 s.end()
             }
-            RateLimitReason::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            RateLimitReason::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -623,22 +638,9 @@ impl TokenFromOAuth1Arg {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "oauth1_token" => {
-                    if field_oauth1_token.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("oauth1_token"));
-                    }
-                    field_oauth1_token = Some(map.next_value()?);
-                }
-                "oauth1_token_secret" => {
-                    if field_oauth1_token_secret.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("oauth1_token_secret"));
-                    }
-                    field_oauth1_token_secret = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "oauth1_token" => crate::deserialize_field(&mut map, &mut field_oauth1_token, "oauth1_token")?,
+                "oauth1_token_secret" => crate::deserialize_field(&mut map, &mut field_oauth1_token_secret, "oauth1_token_secret")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -698,8 +700,9 @@ pub enum TokenFromOAuth1Error {
     /// The authorized app does not match the app associated with the supplied access token.
     AppIdMismatch,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for TokenFromOAuth1Error {
@@ -720,7 +723,7 @@ impl<'de> ::serde::de::Deserialize<'de> for TokenFromOAuth1Error {
                 let value = match tag {
                     "invalid_oauth1_token_info" => TokenFromOAuth1Error::InvalidOauth1TokenInfo,
                     "app_id_mismatch" => TokenFromOAuth1Error::AppIdMismatch,
-                    _ => TokenFromOAuth1Error::Other,
+                    _ => TokenFromOAuth1Error::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -750,7 +753,10 @@ impl ::serde::ser::Serialize for TokenFromOAuth1Error {
                 s.serialize_field(".tag", "app_id_mismatch")?;
                 s.end()
             }
-            TokenFromOAuth1Error::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            TokenFromOAuth1Error::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -800,16 +806,8 @@ impl TokenFromOAuth1Result {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "oauth2_token" => {
-                    if field_oauth2_token.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("oauth2_token"));
-                    }
-                    field_oauth2_token = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "oauth2_token" => crate::deserialize_field(&mut map, &mut field_oauth2_token, "oauth2_token")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -891,16 +889,8 @@ impl TokenScopeError {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "required_scope" => {
-                    if field_required_scope.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("required_scope"));
-                    }
-                    field_required_scope = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "required_scope" => crate::deserialize_field(&mut map, &mut field_required_scope, "required_scope")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
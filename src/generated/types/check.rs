@@ -8,6 +8,16 @@
     clippy::doc_markdown,
 )]
 
+/// The names of this namespace's routes, as they appear in request URLs after the
+/// [`Endpoint`](crate::client_trait_common::Endpoint) base URL, for referring to them in logs and
+/// metrics without spelling them out.
+pub mod routes {
+    #[cfg(feature = "unstable")]
+    pub const APP: &str = "check/app";
+    #[cfg(feature = "unstable")]
+    pub const USER: &str = "check/user";
+}
+
 /// Contains the arguments to be sent to the Dropbox servers.
 #[derive(Debug, Clone, PartialEq, Eq, Default)]
 #[non_exhaustive] // structs may have more fields added in the future.
@@ -32,16 +42,8 @@ impl EchoArg {
         let mut field_query = None;
         while let Some(key) = map.next_key::<&str>()? {
             match key {
-                "query" => {
-                    if field_query.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("query"));
-                    }
-                    field_query = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "query" => crate::deserialize_field(&mut map, &mut field_query, "query")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         let result = EchoArg {
@@ -114,16 +116,8 @@ impl EchoResult {
         let mut field_result = None;
         while let Some(key) = map.next_key::<&str>()? {
             match key {
-                "result" => {
-                    if field_result.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("result"));
-                    }
-                    field_result = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "result" => crate::deserialize_field(&mut map, &mut field_result, "result")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         let result = EchoResult {
@@ -35,8 +35,9 @@ pub enum PathRoot {
     /// this namespace.).
     NamespaceId(NamespaceId),
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for PathRoot {
@@ -70,7 +71,7 @@ impl<'de> ::serde::de::Deserialize<'de> for PathRoot {
                             _ => return Err(de::Error::unknown_field(tag, VARIANTS))
                         }
                     }
-                    _ => PathRoot::Other,
+                    _ => PathRoot::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -109,7 +110,10 @@ impl ::serde::ser::Serialize for PathRoot {
                 s.serialize_field("namespace_id", x)?;
                 s.end()
             }
-            PathRoot::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            PathRoot::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -123,8 +127,9 @@ pub enum PathRootError {
     /// You don't have permission to access the namespace id in Dropbox-API-Path-Root  header.
     NoPermission,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for PathRootError {
@@ -151,7 +156,7 @@ impl<'de> ::serde::de::Deserialize<'de> for PathRootError {
                         }
                     }
                     "no_permission" => PathRootError::NoPermission,
-                    _ => PathRootError::Other,
+                    _ => PathRootError::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -182,7 +187,10 @@ impl ::serde::ser::Serialize for PathRootError {
                 s.serialize_field(".tag", "no_permission")?;
                 s.end()
             }
-            PathRootError::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            PathRootError::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -313,28 +321,10 @@ impl TeamRootInfo {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "root_namespace_id" => {
-                    if field_root_namespace_id.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("root_namespace_id"));
-                    }
-                    field_root_namespace_id = Some(map.next_value()?);
-                }
-                "home_namespace_id" => {
-                    if field_home_namespace_id.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("home_namespace_id"));
-                    }
-                    field_home_namespace_id = Some(map.next_value()?);
-                }
-                "home_path" => {
-                    if field_home_path.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("home_path"));
-                    }
-                    field_home_path = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "root_namespace_id" => crate::deserialize_field(&mut map, &mut field_root_namespace_id, "root_namespace_id")?,
+                "home_namespace_id" => crate::deserialize_field(&mut map, &mut field_home_namespace_id, "home_namespace_id")?,
+                "home_path" => crate::deserialize_field(&mut map, &mut field_home_path, "home_path")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -435,22 +425,9 @@ impl UserRootInfo {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "root_namespace_id" => {
-                    if field_root_namespace_id.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("root_namespace_id"));
-                    }
-                    field_root_namespace_id = Some(map.next_value()?);
-                }
-                "home_namespace_id" => {
-                    if field_home_namespace_id.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("home_namespace_id"));
-                    }
-                    field_home_namespace_id = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "root_namespace_id" => crate::deserialize_field(&mut map, &mut field_root_namespace_id, "root_namespace_id")?,
+                "home_namespace_id" => crate::deserialize_field(&mut map, &mut field_home_namespace_id, "home_namespace_id")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -8,6 +8,14 @@
     clippy::doc_markdown,
 )]
 
+/// The names of this namespace's routes, as they appear in request URLs after the
+/// [`Endpoint`](crate::client_trait_common::Endpoint) base URL, for referring to them in logs and
+/// metrics without spelling them out.
+pub mod routes {
+    pub const DELETE_MANUAL_CONTACTS: &str = "contacts/delete_manual_contacts";
+    pub const DELETE_MANUAL_CONTACTS_BATCH: &str = "contacts/delete_manual_contacts_batch";
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 #[non_exhaustive] // structs may have more fields added in the future.
 pub struct DeleteManualContactsArg {
@@ -40,16 +48,8 @@ impl DeleteManualContactsArg {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "email_addresses" => {
-                    if field_email_addresses.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("email_addresses"));
-                    }
-                    field_email_addresses = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "email_addresses" => crate::deserialize_field(&mut map, &mut field_email_addresses, "email_addresses")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -106,8 +106,9 @@ pub enum DeleteManualContactsError {
     /// The deletion was cancelled.
     ContactsNotFound(Vec<crate::types::common::EmailAddress>),
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for DeleteManualContactsError {
@@ -133,7 +134,7 @@ impl<'de> ::serde::de::Deserialize<'de> for DeleteManualContactsError {
                             _ => return Err(de::Error::unknown_field(tag, VARIANTS))
                         }
                     }
-                    _ => DeleteManualContactsError::Other,
+                    _ => DeleteManualContactsError::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -157,7 +158,10 @@ impl ::serde::ser::Serialize for DeleteManualContactsError {
                 s.serialize_field("contacts_not_found", x)?;
                 s.end()
             }
-            DeleteManualContactsError::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            DeleteManualContactsError::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
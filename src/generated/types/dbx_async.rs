@@ -182,16 +182,8 @@ impl PollArg {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "async_job_id" => {
-                    if field_async_job_id.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("async_job_id"));
-                    }
-                    field_async_job_id = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "async_job_id" => crate::deserialize_field(&mut map, &mut field_async_job_id, "async_job_id")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -320,8 +312,9 @@ pub enum PollError {
     /// you were taking succeeded, and if not, try again. This should happen very rarely.
     InternalError,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for PollError {
@@ -342,7 +335,7 @@ impl<'de> ::serde::de::Deserialize<'de> for PollError {
                 let value = match tag {
                     "invalid_async_job_id" => PollError::InvalidAsyncJobId,
                     "internal_error" => PollError::InternalError,
-                    _ => PollError::Other,
+                    _ => PollError::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -372,7 +365,10 @@ impl ::serde::ser::Serialize for PollError {
                 s.serialize_field(".tag", "internal_error")?;
                 s.end()
             }
-            PollError::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            PollError::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -45,6 +45,28 @@ pub type PathOrId = String;
 pub type PropertiesSearchCursor = String;
 pub type TemplateId = String;
 
+/// The names of this namespace's routes, as they appear in request URLs after the
+/// [`Endpoint`](crate::client_trait_common::Endpoint) base URL, for referring to them in logs and
+/// metrics without spelling them out.
+pub mod routes {
+    pub const PROPERTIES_ADD: &str = "file_properties/properties/add";
+    pub const PROPERTIES_OVERWRITE: &str = "file_properties/properties/overwrite";
+    pub const PROPERTIES_REMOVE: &str = "file_properties/properties/remove";
+    pub const PROPERTIES_SEARCH: &str = "file_properties/properties/search";
+    pub const PROPERTIES_SEARCH_CONTINUE: &str = "file_properties/properties/search/continue";
+    pub const PROPERTIES_UPDATE: &str = "file_properties/properties/update";
+    pub const TEMPLATES_ADD_FOR_TEAM: &str = "file_properties/templates/add_for_team";
+    pub const TEMPLATES_ADD_FOR_USER: &str = "file_properties/templates/add_for_user";
+    pub const TEMPLATES_GET_FOR_TEAM: &str = "file_properties/templates/get_for_team";
+    pub const TEMPLATES_GET_FOR_USER: &str = "file_properties/templates/get_for_user";
+    pub const TEMPLATES_LIST_FOR_TEAM: &str = "file_properties/templates/list_for_team";
+    pub const TEMPLATES_LIST_FOR_USER: &str = "file_properties/templates/list_for_user";
+    pub const TEMPLATES_REMOVE_FOR_TEAM: &str = "file_properties/templates/remove_for_team";
+    pub const TEMPLATES_REMOVE_FOR_USER: &str = "file_properties/templates/remove_for_user";
+    pub const TEMPLATES_UPDATE_FOR_TEAM: &str = "file_properties/templates/update_for_team";
+    pub const TEMPLATES_UPDATE_FOR_USER: &str = "file_properties/templates/update_for_user";
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 #[non_exhaustive] // structs may have more fields added in the future.
 pub struct AddPropertiesArg {
@@ -83,22 +105,9 @@ impl AddPropertiesArg {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "path" => {
-                    if field_path.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("path"));
-                    }
-                    field_path = Some(map.next_value()?);
-                }
-                "property_groups" => {
-                    if field_property_groups.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("property_groups"));
-                    }
-                    field_property_groups = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "path" => crate::deserialize_field(&mut map, &mut field_path, "path")?,
+                "property_groups" => crate::deserialize_field(&mut map, &mut field_property_groups, "property_groups")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -169,8 +178,9 @@ pub enum AddPropertiesError {
     /// A property group associated with this template and file already exists.
     PropertyGroupAlreadyExists,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for AddPropertiesError {
@@ -209,7 +219,7 @@ impl<'de> ::serde::de::Deserialize<'de> for AddPropertiesError {
                     "does_not_fit_template" => AddPropertiesError::DoesNotFitTemplate,
                     "duplicate_property_groups" => AddPropertiesError::DuplicatePropertyGroups,
                     "property_group_already_exists" => AddPropertiesError::PropertyGroupAlreadyExists,
-                    _ => AddPropertiesError::Other,
+                    _ => AddPropertiesError::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -283,7 +293,10 @@ impl ::serde::ser::Serialize for AddPropertiesError {
                 s.serialize_field(".tag", "property_group_already_exists")?;
                 s.end()
             }
-            AddPropertiesError::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            AddPropertiesError::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -302,7 +315,7 @@ impl ::std::fmt::Display for AddPropertiesError {
         match self {
             AddPropertiesError::TemplateNotFound(inner) => write!(f, "Template does not exist for the given identifier: {:?}", inner),
             AddPropertiesError::RestrictedContent => f.write_str("You do not have permission to modify this template."),
-            AddPropertiesError::Path(inner) => write!(f, "{}", inner),
+            AddPropertiesError::Path(inner) => write!(f, "path: {}", inner),
             AddPropertiesError::UnsupportedFolder => f.write_str("This folder cannot be tagged. Tagging folders is not supported for team-owned templates."),
             AddPropertiesError::PropertyFieldTooLarge => f.write_str("One or more of the supplied property field values is too large."),
             AddPropertiesError::DoesNotFitTemplate => f.write_str("One or more of the supplied property fields does not conform to the template specifications."),
@@ -319,7 +332,7 @@ impl From<InvalidPropertyGroupError> for AddPropertiesError {
         match parent {
             InvalidPropertyGroupError::TemplateNotFound(x) => AddPropertiesError::TemplateNotFound(x),
             InvalidPropertyGroupError::RestrictedContent => AddPropertiesError::RestrictedContent,
-            InvalidPropertyGroupError::Other => AddPropertiesError::Other,
+            InvalidPropertyGroupError::Other(x) => AddPropertiesError::Other(x),
             InvalidPropertyGroupError::Path(x) => AddPropertiesError::Path(x),
             InvalidPropertyGroupError::UnsupportedFolder => AddPropertiesError::UnsupportedFolder,
             InvalidPropertyGroupError::PropertyFieldTooLarge => AddPropertiesError::PropertyFieldTooLarge,
@@ -371,28 +384,10 @@ impl AddTemplateArg {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "name" => {
-                    if field_name.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("name"));
-                    }
-                    field_name = Some(map.next_value()?);
-                }
-                "description" => {
-                    if field_description.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("description"));
-                    }
-                    field_description = Some(map.next_value()?);
-                }
-                "fields" => {
-                    if field_fields.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("fields"));
-                    }
-                    field_fields = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "name" => crate::deserialize_field(&mut map, &mut field_name, "name")?,
+                "description" => crate::deserialize_field(&mut map, &mut field_description, "description")?,
+                "fields" => crate::deserialize_field(&mut map, &mut field_fields, "fields")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -490,16 +485,8 @@ impl AddTemplateResult {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "template_id" => {
-                    if field_template_id.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("template_id"));
-                    }
-                    field_template_id = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "template_id" => crate::deserialize_field(&mut map, &mut field_template_id, "template_id")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -583,16 +570,8 @@ impl GetTemplateArg {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "template_id" => {
-                    if field_template_id.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("template_id"));
-                    }
-                    field_template_id = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "template_id" => crate::deserialize_field(&mut map, &mut field_template_id, "template_id")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -685,28 +664,10 @@ impl GetTemplateResult {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "name" => {
-                    if field_name.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("name"));
-                    }
-                    field_name = Some(map.next_value()?);
-                }
-                "description" => {
-                    if field_description.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("description"));
-                    }
-                    field_description = Some(map.next_value()?);
-                }
-                "fields" => {
-                    if field_fields.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("fields"));
-                    }
-                    field_fields = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "name" => crate::deserialize_field(&mut map, &mut field_name, "name")?,
+                "description" => crate::deserialize_field(&mut map, &mut field_description, "description")?,
+                "fields" => crate::deserialize_field(&mut map, &mut field_fields, "fields")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -787,8 +748,9 @@ pub enum InvalidPropertyGroupError {
     /// There are 2 or more property groups referring to the same templates in the input.
     DuplicatePropertyGroups,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for InvalidPropertyGroupError {
@@ -826,7 +788,7 @@ impl<'de> ::serde::de::Deserialize<'de> for InvalidPropertyGroupError {
                     "property_field_too_large" => InvalidPropertyGroupError::PropertyFieldTooLarge,
                     "does_not_fit_template" => InvalidPropertyGroupError::DoesNotFitTemplate,
                     "duplicate_property_groups" => InvalidPropertyGroupError::DuplicatePropertyGroups,
-                    _ => InvalidPropertyGroupError::Other,
+                    _ => InvalidPropertyGroupError::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -893,7 +855,10 @@ impl ::serde::ser::Serialize for InvalidPropertyGroupError {
                 s.serialize_field(".tag", "duplicate_property_groups")?;
                 s.end()
             }
-            InvalidPropertyGroupError::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            InvalidPropertyGroupError::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -912,7 +877,7 @@ impl ::std::fmt::Display for InvalidPropertyGroupError {
         match self {
             InvalidPropertyGroupError::TemplateNotFound(inner) => write!(f, "Template does not exist for the given identifier: {:?}", inner),
             InvalidPropertyGroupError::RestrictedContent => f.write_str("You do not have permission to modify this template."),
-            InvalidPropertyGroupError::Path(inner) => write!(f, "{}", inner),
+            InvalidPropertyGroupError::Path(inner) => write!(f, "path: {}", inner),
             InvalidPropertyGroupError::UnsupportedFolder => f.write_str("This folder cannot be tagged. Tagging folders is not supported for team-owned templates."),
             InvalidPropertyGroupError::PropertyFieldTooLarge => f.write_str("One or more of the supplied property field values is too large."),
             InvalidPropertyGroupError::DoesNotFitTemplate => f.write_str("One or more of the supplied property fields does not conform to the template specifications."),
@@ -928,7 +893,7 @@ impl From<PropertiesError> for InvalidPropertyGroupError {
         match parent {
             PropertiesError::TemplateNotFound(x) => InvalidPropertyGroupError::TemplateNotFound(x),
             PropertiesError::RestrictedContent => InvalidPropertyGroupError::RestrictedContent,
-            PropertiesError::Other => InvalidPropertyGroupError::Other,
+            PropertiesError::Other(x) => InvalidPropertyGroupError::Other(x),
             PropertiesError::Path(x) => InvalidPropertyGroupError::Path(x),
             PropertiesError::UnsupportedFolder => InvalidPropertyGroupError::UnsupportedFolder,
         }
@@ -968,16 +933,8 @@ impl ListTemplateResult {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "template_ids" => {
-                    if field_template_ids.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("template_ids"));
-                    }
-                    field_template_ids = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "template_ids" => crate::deserialize_field(&mut map, &mut field_template_ids, "template_ids")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -1034,8 +991,9 @@ pub enum LogicalOperator {
     /// Append a query with an "or" operator.
     OrOperator,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for LogicalOperator {
@@ -1055,7 +1013,7 @@ impl<'de> ::serde::de::Deserialize<'de> for LogicalOperator {
                 };
                 let value = match tag {
                     "or_operator" => LogicalOperator::OrOperator,
-                    _ => LogicalOperator::Other,
+                    _ => LogicalOperator::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -1078,7 +1036,10 @@ impl ::serde::ser::Serialize for LogicalOperator {
                 s.serialize_field(".tag", "or_operator")?;
                 s.end()
             }
-            LogicalOperator::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            LogicalOperator::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -1089,8 +1050,9 @@ pub enum LookUpPropertiesError {
     /// No property group was found.
     PropertyGroupNotFound,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for LookUpPropertiesError {
@@ -1110,7 +1072,7 @@ impl<'de> ::serde::de::Deserialize<'de> for LookUpPropertiesError {
                 };
                 let value = match tag {
                     "property_group_not_found" => LookUpPropertiesError::PropertyGroupNotFound,
-                    _ => LookUpPropertiesError::Other,
+                    _ => LookUpPropertiesError::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -1133,7 +1095,10 @@ impl ::serde::ser::Serialize for LookUpPropertiesError {
                 s.serialize_field(".tag", "property_group_not_found")?;
                 s.end()
             }
-            LookUpPropertiesError::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            LookUpPropertiesError::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -1164,8 +1129,9 @@ pub enum LookupError {
     /// restrict a file due to legal requirements.
     RestrictedContent,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for LookupError {
@@ -1195,7 +1161,7 @@ impl<'de> ::serde::de::Deserialize<'de> for LookupError {
                     "not_file" => LookupError::NotFile,
                     "not_folder" => LookupError::NotFolder,
                     "restricted_content" => LookupError::RestrictedContent,
-                    _ => LookupError::Other,
+                    _ => LookupError::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -1247,7 +1213,10 @@ impl ::serde::ser::Serialize for LookupError {
                 s.serialize_field(".tag", "restricted_content")?;
                 s.end()
             }
-            LookupError::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            LookupError::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -1285,8 +1254,9 @@ pub enum ModifyTemplateError {
     /// The template name, description or one or more of the property field keys is too large.
     TemplateAttributeTooLarge,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for ModifyTemplateError {
@@ -1317,7 +1287,7 @@ impl<'de> ::serde::de::Deserialize<'de> for ModifyTemplateError {
                     "too_many_properties" => ModifyTemplateError::TooManyProperties,
                     "too_many_templates" => ModifyTemplateError::TooManyTemplates,
                     "template_attribute_too_large" => ModifyTemplateError::TemplateAttributeTooLarge,
-                    _ => ModifyTemplateError::Other,
+                    _ => ModifyTemplateError::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -1376,7 +1346,10 @@ impl ::serde::ser::Serialize for ModifyTemplateError {
                 s.serialize_field(".tag", "template_attribute_too_large")?;
                 s.end()
             }
-            ModifyTemplateError::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            ModifyTemplateError::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -1404,7 +1377,7 @@ impl From<TemplateError> for ModifyTemplateError {
         match parent {
             TemplateError::TemplateNotFound(x) => ModifyTemplateError::TemplateNotFound(x),
             TemplateError::RestrictedContent => ModifyTemplateError::RestrictedContent,
-            TemplateError::Other => ModifyTemplateError::Other,
+            TemplateError::Other(x) => ModifyTemplateError::Other(x),
         }
     }
 }
@@ -1446,22 +1419,9 @@ impl OverwritePropertyGroupArg {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "path" => {
-                    if field_path.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("path"));
-                    }
-                    field_path = Some(map.next_value()?);
-                }
-                "property_groups" => {
-                    if field_property_groups.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("property_groups"));
-                    }
-                    field_property_groups = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "path" => crate::deserialize_field(&mut map, &mut field_path, "path")?,
+                "property_groups" => crate::deserialize_field(&mut map, &mut field_property_groups, "property_groups")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -1524,8 +1484,9 @@ pub enum PropertiesError {
     /// This folder cannot be tagged. Tagging folders is not supported for team-owned templates.
     UnsupportedFolder,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for PropertiesError {
@@ -1560,7 +1521,7 @@ impl<'de> ::serde::de::Deserialize<'de> for PropertiesError {
                         }
                     }
                     "unsupported_folder" => PropertiesError::UnsupportedFolder,
-                    _ => PropertiesError::Other,
+                    _ => PropertiesError::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -1606,7 +1567,10 @@ impl ::serde::ser::Serialize for PropertiesError {
                 s.serialize_field(".tag", "unsupported_folder")?;
                 s.end()
             }
-            PropertiesError::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            PropertiesError::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -1625,7 +1589,7 @@ impl ::std::fmt::Display for PropertiesError {
         match self {
             PropertiesError::TemplateNotFound(inner) => write!(f, "Template does not exist for the given identifier: {:?}", inner),
             PropertiesError::RestrictedContent => f.write_str("You do not have permission to modify this template."),
-            PropertiesError::Path(inner) => write!(f, "{}", inner),
+            PropertiesError::Path(inner) => write!(f, "path: {}", inner),
             PropertiesError::UnsupportedFolder => f.write_str("This folder cannot be tagged. Tagging folders is not supported for team-owned templates."),
             _ => write!(f, "{:?}", *self),
         }
@@ -1638,7 +1602,7 @@ impl From<TemplateError> for PropertiesError {
         match parent {
             TemplateError::TemplateNotFound(x) => PropertiesError::TemplateNotFound(x),
             TemplateError::RestrictedContent => PropertiesError::RestrictedContent,
-            TemplateError::Other => PropertiesError::Other,
+            TemplateError::Other(x) => PropertiesError::Other(x),
         }
     }
 }
@@ -1684,22 +1648,9 @@ impl PropertiesSearchArg {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "queries" => {
-                    if field_queries.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("queries"));
-                    }
-                    field_queries = Some(map.next_value()?);
-                }
-                "template_filter" => {
-                    if field_template_filter.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("template_filter"));
-                    }
-                    field_template_filter = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "queries" => crate::deserialize_field(&mut map, &mut field_queries, "queries")?,
+                "template_filter" => crate::deserialize_field(&mut map, &mut field_template_filter, "template_filter")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -1787,16 +1738,8 @@ impl PropertiesSearchContinueArg {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "cursor" => {
-                    if field_cursor.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("cursor"));
-                    }
-                    field_cursor = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "cursor" => crate::deserialize_field(&mut map, &mut field_cursor, "cursor")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -1853,8 +1796,9 @@ pub enum PropertiesSearchContinueError {
     /// [`properties_search()`](crate::file_properties::properties_search) to obtain a new cursor.
     Reset,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for PropertiesSearchContinueError {
@@ -1874,7 +1818,7 @@ impl<'de> ::serde::de::Deserialize<'de> for PropertiesSearchContinueError {
                 };
                 let value = match tag {
                     "reset" => PropertiesSearchContinueError::Reset,
-                    _ => PropertiesSearchContinueError::Other,
+                    _ => PropertiesSearchContinueError::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -1897,7 +1841,10 @@ impl ::serde::ser::Serialize for PropertiesSearchContinueError {
                 s.serialize_field(".tag", "reset")?;
                 s.end()
             }
-            PropertiesSearchContinueError::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            PropertiesSearchContinueError::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -1916,8 +1863,9 @@ impl ::std::fmt::Display for PropertiesSearchContinueError {
 pub enum PropertiesSearchError {
     PropertyGroupLookup(LookUpPropertiesError),
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for PropertiesSearchError {
@@ -1943,7 +1891,7 @@ impl<'de> ::serde::de::Deserialize<'de> for PropertiesSearchError {
                             _ => return Err(de::Error::unknown_field(tag, VARIANTS))
                         }
                     }
-                    _ => PropertiesSearchError::Other,
+                    _ => PropertiesSearchError::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -1967,7 +1915,10 @@ impl ::serde::ser::Serialize for PropertiesSearchError {
                 s.serialize_field("property_group_lookup", x)?;
                 s.end()
             }
-            PropertiesSearchError::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            PropertiesSearchError::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -1984,7 +1935,7 @@ impl ::std::error::Error for PropertiesSearchError {
 impl ::std::fmt::Display for PropertiesSearchError {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         match self {
-            PropertiesSearchError::PropertyGroupLookup(inner) => write!(f, "{}", inner),
+            PropertiesSearchError::PropertyGroupLookup(inner) => write!(f, "property_group_lookup: {}", inner),
             _ => write!(f, "{:?}", *self),
         }
     }
@@ -2042,34 +1993,11 @@ impl PropertiesSearchMatch {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "id" => {
-                    if field_id.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("id"));
-                    }
-                    field_id = Some(map.next_value()?);
-                }
-                "path" => {
-                    if field_path.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("path"));
-                    }
-                    field_path = Some(map.next_value()?);
-                }
-                "is_deleted" => {
-                    if field_is_deleted.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("is_deleted"));
-                    }
-                    field_is_deleted = Some(map.next_value()?);
-                }
-                "property_groups" => {
-                    if field_property_groups.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("property_groups"));
-                    }
-                    field_property_groups = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "id" => crate::deserialize_field(&mut map, &mut field_id, "id")?,
+                "path" => crate::deserialize_field(&mut map, &mut field_path, "path")?,
+                "is_deleted" => crate::deserialize_field(&mut map, &mut field_is_deleted, "is_deleted")?,
+                "property_groups" => crate::deserialize_field(&mut map, &mut field_property_groups, "property_groups")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -2131,8 +2059,9 @@ pub enum PropertiesSearchMode {
     /// Search for a value associated with this field name.
     FieldName(String),
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for PropertiesSearchMode {
@@ -2158,7 +2087,7 @@ impl<'de> ::serde::de::Deserialize<'de> for PropertiesSearchMode {
                             _ => return Err(de::Error::unknown_field(tag, VARIANTS))
                         }
                     }
-                    _ => PropertiesSearchMode::Other,
+                    _ => PropertiesSearchMode::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -2182,7 +2111,10 @@ impl ::serde::ser::Serialize for PropertiesSearchMode {
                 s.serialize_field("field_name", x)?;
                 s.end()
             }
-            PropertiesSearchMode::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            PropertiesSearchMode::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -2234,28 +2166,10 @@ impl PropertiesSearchQuery {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "query" => {
-                    if field_query.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("query"));
-                    }
-                    field_query = Some(map.next_value()?);
-                }
-                "mode" => {
-                    if field_mode.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("mode"));
-                    }
-                    field_mode = Some(map.next_value()?);
-                }
-                "logical_operator" => {
-                    if field_logical_operator.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("logical_operator"));
-                    }
-                    field_logical_operator = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "query" => crate::deserialize_field(&mut map, &mut field_query, "query")?,
+                "mode" => crate::deserialize_field(&mut map, &mut field_mode, "mode")?,
+                "logical_operator" => crate::deserialize_field(&mut map, &mut field_logical_operator, "logical_operator")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -2355,22 +2269,9 @@ impl PropertiesSearchResult {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "matches" => {
-                    if field_matches.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("matches"));
-                    }
-                    field_matches = Some(map.next_value()?);
-                }
-                "cursor" => {
-                    if field_cursor.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("cursor"));
-                    }
-                    field_cursor = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "matches" => crate::deserialize_field(&mut map, &mut field_matches, "matches")?,
+                "cursor" => crate::deserialize_field(&mut map, &mut field_cursor, "cursor")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -2464,22 +2365,9 @@ impl PropertyField {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "name" => {
-                    if field_name.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("name"));
-                    }
-                    field_name = Some(map.next_value()?);
-                }
-                "value" => {
-                    if field_value.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("value"));
-                    }
-                    field_value = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "name" => crate::deserialize_field(&mut map, &mut field_name, "name")?,
+                "value" => crate::deserialize_field(&mut map, &mut field_value, "value")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -2576,28 +2464,10 @@ impl PropertyFieldTemplate {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "name" => {
-                    if field_name.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("name"));
-                    }
-                    field_name = Some(map.next_value()?);
-                }
-                "description" => {
-                    if field_description.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("description"));
-                    }
-                    field_description = Some(map.next_value()?);
-                }
-                "type" => {
-                    if field_type_field.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("type"));
-                    }
-                    field_type_field = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "name" => crate::deserialize_field(&mut map, &mut field_name, "name")?,
+                "description" => crate::deserialize_field(&mut map, &mut field_description, "description")?,
+                "type" => crate::deserialize_field(&mut map, &mut field_type_field, "type")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -2693,22 +2563,9 @@ impl PropertyGroup {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "template_id" => {
-                    if field_template_id.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("template_id"));
-                    }
-                    field_template_id = Some(map.next_value()?);
-                }
-                "fields" => {
-                    if field_fields.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("fields"));
-                    }
-                    field_fields = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "template_id" => crate::deserialize_field(&mut map, &mut field_template_id, "template_id")?,
+                "fields" => crate::deserialize_field(&mut map, &mut field_fields, "fields")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -2804,28 +2661,10 @@ impl PropertyGroupTemplate {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "name" => {
-                    if field_name.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("name"));
-                    }
-                    field_name = Some(map.next_value()?);
-                }
-                "description" => {
-                    if field_description.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("description"));
-                    }
-                    field_description = Some(map.next_value()?);
-                }
-                "fields" => {
-                    if field_fields.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("fields"));
-                    }
-                    field_fields = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "name" => crate::deserialize_field(&mut map, &mut field_name, "name")?,
+                "description" => crate::deserialize_field(&mut map, &mut field_description, "description")?,
+                "fields" => crate::deserialize_field(&mut map, &mut field_fields, "fields")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -2932,28 +2771,10 @@ impl PropertyGroupUpdate {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "template_id" => {
-                    if field_template_id.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("template_id"));
-                    }
-                    field_template_id = Some(map.next_value()?);
-                }
-                "add_or_update_fields" => {
-                    if field_add_or_update_fields.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("add_or_update_fields"));
-                    }
-                    field_add_or_update_fields = Some(map.next_value()?);
-                }
-                "remove_fields" => {
-                    if field_remove_fields.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("remove_fields"));
-                    }
-                    field_remove_fields = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "template_id" => crate::deserialize_field(&mut map, &mut field_template_id, "template_id")?,
+                "add_or_update_fields" => crate::deserialize_field(&mut map, &mut field_add_or_update_fields, "add_or_update_fields")?,
+                "remove_fields" => crate::deserialize_field(&mut map, &mut field_remove_fields, "remove_fields")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -3018,8 +2839,9 @@ pub enum PropertyType {
     /// The associated property field will be of type string. Unicode is supported.
     String,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for PropertyType {
@@ -3039,7 +2861,7 @@ impl<'de> ::serde::de::Deserialize<'de> for PropertyType {
                 };
                 let value = match tag {
                     "string" => PropertyType::String,
-                    _ => PropertyType::Other,
+                    _ => PropertyType::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -3062,7 +2884,10 @@ impl ::serde::ser::Serialize for PropertyType {
                 s.serialize_field(".tag", "string")?;
                 s.end()
             }
-            PropertyType::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            PropertyType::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -3106,22 +2931,9 @@ impl RemovePropertiesArg {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "path" => {
-                    if field_path.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("path"));
-                    }
-                    field_path = Some(map.next_value()?);
-                }
-                "property_template_ids" => {
-                    if field_property_template_ids.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("property_template_ids"));
-                    }
-                    field_property_template_ids = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "path" => crate::deserialize_field(&mut map, &mut field_path, "path")?,
+                "property_template_ids" => crate::deserialize_field(&mut map, &mut field_property_template_ids, "property_template_ids")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -3185,8 +2997,9 @@ pub enum RemovePropertiesError {
     UnsupportedFolder,
     PropertyGroupLookup(LookUpPropertiesError),
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for RemovePropertiesError {
@@ -3228,7 +3041,7 @@ impl<'de> ::serde::de::Deserialize<'de> for RemovePropertiesError {
                             _ => return Err(de::Error::unknown_field(tag, VARIANTS))
                         }
                     }
-                    _ => RemovePropertiesError::Other,
+                    _ => RemovePropertiesError::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -3282,7 +3095,10 @@ impl ::serde::ser::Serialize for RemovePropertiesError {
                 s.serialize_field("property_group_lookup", x)?;
                 s.end()
             }
-            RemovePropertiesError::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            RemovePropertiesError::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -3302,9 +3118,9 @@ impl ::std::fmt::Display for RemovePropertiesError {
         match self {
             RemovePropertiesError::TemplateNotFound(inner) => write!(f, "Template does not exist for the given identifier: {:?}", inner),
             RemovePropertiesError::RestrictedContent => f.write_str("You do not have permission to modify this template."),
-            RemovePropertiesError::Path(inner) => write!(f, "{}", inner),
+            RemovePropertiesError::Path(inner) => write!(f, "path: {}", inner),
             RemovePropertiesError::UnsupportedFolder => f.write_str("This folder cannot be tagged. Tagging folders is not supported for team-owned templates."),
-            RemovePropertiesError::PropertyGroupLookup(inner) => write!(f, "{}", inner),
+            RemovePropertiesError::PropertyGroupLookup(inner) => write!(f, "property_group_lookup: {}", inner),
             _ => write!(f, "{:?}", *self),
         }
     }
@@ -3316,7 +3132,7 @@ impl From<PropertiesError> for RemovePropertiesError {
         match parent {
             PropertiesError::TemplateNotFound(x) => RemovePropertiesError::TemplateNotFound(x),
             PropertiesError::RestrictedContent => RemovePropertiesError::RestrictedContent,
-            PropertiesError::Other => RemovePropertiesError::Other,
+            PropertiesError::Other(x) => RemovePropertiesError::Other(x),
             PropertiesError::Path(x) => RemovePropertiesError::Path(x),
             PropertiesError::UnsupportedFolder => RemovePropertiesError::UnsupportedFolder,
         }
@@ -3356,16 +3172,8 @@ impl RemoveTemplateArg {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "template_id" => {
-                    if field_template_id.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("template_id"));
-                    }
-                    field_template_id = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "template_id" => crate::deserialize_field(&mut map, &mut field_template_id, "template_id")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -3423,8 +3231,9 @@ pub enum TemplateError {
     /// You do not have permission to modify this template.
     RestrictedContent,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for TemplateError {
@@ -3451,7 +3260,7 @@ impl<'de> ::serde::de::Deserialize<'de> for TemplateError {
                         }
                     }
                     "restricted_content" => TemplateError::RestrictedContent,
-                    _ => TemplateError::Other,
+                    _ => TemplateError::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -3482,7 +3291,10 @@ impl ::serde::ser::Serialize for TemplateError {
                 s.serialize_field(".tag", "restricted_content")?;
                 s.end()
             }
-            TemplateError::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            TemplateError::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -3509,8 +3321,9 @@ pub enum TemplateFilter {
     /// No templates will be filtered from the result (all templates will be returned).
     FilterNone,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for TemplateFilter {
@@ -3537,7 +3350,7 @@ impl<'de> ::serde::de::Deserialize<'de> for TemplateFilter {
                         }
                     }
                     "filter_none" => TemplateFilter::FilterNone,
-                    _ => TemplateFilter::Other,
+                    _ => TemplateFilter::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -3568,7 +3381,10 @@ impl ::serde::ser::Serialize for TemplateFilter {
                 s.serialize_field(".tag", "filter_none")?;
                 s.end()
             }
-            TemplateFilter::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            TemplateFilter::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -3578,7 +3394,7 @@ impl From<TemplateFilterBase> for TemplateFilter {
     fn from(parent: TemplateFilterBase) -> Self {
         match parent {
             TemplateFilterBase::FilterSome(x) => TemplateFilter::FilterSome(x),
-            TemplateFilterBase::Other => TemplateFilter::Other,
+            TemplateFilterBase::Other(x) => TemplateFilter::Other(x),
         }
     }
 }
@@ -3589,8 +3405,9 @@ pub enum TemplateFilterBase {
     /// be returned).
     FilterSome(Vec<TemplateId>),
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for TemplateFilterBase {
@@ -3616,7 +3433,7 @@ impl<'de> ::serde::de::Deserialize<'de> for TemplateFilterBase {
                             _ => return Err(de::Error::unknown_field(tag, VARIANTS))
                         }
                     }
-                    _ => TemplateFilterBase::Other,
+                    _ => TemplateFilterBase::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -3640,7 +3457,10 @@ impl ::serde::ser::Serialize for TemplateFilterBase {
                 s.serialize_field("filter_some", x)?;
                 s.end()
             }
-            TemplateFilterBase::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            TemplateFilterBase::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -3653,8 +3473,9 @@ pub enum TemplateOwnerType {
     /// Template will be associated with a team.
     Team,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for TemplateOwnerType {
@@ -3675,7 +3496,7 @@ impl<'de> ::serde::de::Deserialize<'de> for TemplateOwnerType {
                 let value = match tag {
                     "user" => TemplateOwnerType::User,
                     "team" => TemplateOwnerType::Team,
-                    _ => TemplateOwnerType::Other,
+                    _ => TemplateOwnerType::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -3705,7 +3526,10 @@ impl ::serde::ser::Serialize for TemplateOwnerType {
                 s.serialize_field(".tag", "team")?;
                 s.end()
             }
-            TemplateOwnerType::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            TemplateOwnerType::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -3747,22 +3571,9 @@ impl UpdatePropertiesArg {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "path" => {
-                    if field_path.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("path"));
-                    }
-                    field_path = Some(map.next_value()?);
-                }
-                "update_property_groups" => {
-                    if field_update_property_groups.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("update_property_groups"));
-                    }
-                    field_update_property_groups = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "path" => crate::deserialize_field(&mut map, &mut field_path, "path")?,
+                "update_property_groups" => crate::deserialize_field(&mut map, &mut field_update_property_groups, "update_property_groups")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -3832,8 +3643,9 @@ pub enum UpdatePropertiesError {
     DuplicatePropertyGroups,
     PropertyGroupLookup(LookUpPropertiesError),
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for UpdatePropertiesError {
@@ -3878,7 +3690,7 @@ impl<'de> ::serde::de::Deserialize<'de> for UpdatePropertiesError {
                             _ => return Err(de::Error::unknown_field(tag, VARIANTS))
                         }
                     }
-                    _ => UpdatePropertiesError::Other,
+                    _ => UpdatePropertiesError::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -3953,7 +3765,10 @@ impl ::serde::ser::Serialize for UpdatePropertiesError {
                 s.serialize_field("property_group_lookup", x)?;
                 s.end()
             }
-            UpdatePropertiesError::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            UpdatePropertiesError::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -3973,12 +3788,12 @@ impl ::std::fmt::Display for UpdatePropertiesError {
         match self {
             UpdatePropertiesError::TemplateNotFound(inner) => write!(f, "Template does not exist for the given identifier: {:?}", inner),
             UpdatePropertiesError::RestrictedContent => f.write_str("You do not have permission to modify this template."),
-            UpdatePropertiesError::Path(inner) => write!(f, "{}", inner),
+            UpdatePropertiesError::Path(inner) => write!(f, "path: {}", inner),
             UpdatePropertiesError::UnsupportedFolder => f.write_str("This folder cannot be tagged. Tagging folders is not supported for team-owned templates."),
             UpdatePropertiesError::PropertyFieldTooLarge => f.write_str("One or more of the supplied property field values is too large."),
             UpdatePropertiesError::DoesNotFitTemplate => f.write_str("One or more of the supplied property fields does not conform to the template specifications."),
             UpdatePropertiesError::DuplicatePropertyGroups => f.write_str("There are 2 or more property groups referring to the same templates in the input."),
-            UpdatePropertiesError::PropertyGroupLookup(inner) => write!(f, "{}", inner),
+            UpdatePropertiesError::PropertyGroupLookup(inner) => write!(f, "property_group_lookup: {}", inner),
             _ => write!(f, "{:?}", *self),
         }
     }
@@ -3990,7 +3805,7 @@ impl From<InvalidPropertyGroupError> for UpdatePropertiesError {
         match parent {
             InvalidPropertyGroupError::TemplateNotFound(x) => UpdatePropertiesError::TemplateNotFound(x),
             InvalidPropertyGroupError::RestrictedContent => UpdatePropertiesError::RestrictedContent,
-            InvalidPropertyGroupError::Other => UpdatePropertiesError::Other,
+            InvalidPropertyGroupError::Other(x) => UpdatePropertiesError::Other(x),
             InvalidPropertyGroupError::Path(x) => UpdatePropertiesError::Path(x),
             InvalidPropertyGroupError::UnsupportedFolder => UpdatePropertiesError::UnsupportedFolder,
             InvalidPropertyGroupError::PropertyFieldTooLarge => UpdatePropertiesError::PropertyFieldTooLarge,
@@ -4064,34 +3879,11 @@ impl UpdateTemplateArg {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "template_id" => {
-                    if field_template_id.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("template_id"));
-                    }
-                    field_template_id = Some(map.next_value()?);
-                }
-                "name" => {
-                    if field_name.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("name"));
-                    }
-                    field_name = Some(map.next_value()?);
-                }
-                "description" => {
-                    if field_description.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("description"));
-                    }
-                    field_description = Some(map.next_value()?);
-                }
-                "add_fields" => {
-                    if field_add_fields.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("add_fields"));
-                    }
-                    field_add_fields = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "template_id" => crate::deserialize_field(&mut map, &mut field_template_id, "template_id")?,
+                "name" => crate::deserialize_field(&mut map, &mut field_name, "name")?,
+                "description" => crate::deserialize_field(&mut map, &mut field_description, "description")?,
+                "add_fields" => crate::deserialize_field(&mut map, &mut field_add_fields, "add_fields")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -4187,16 +3979,8 @@ impl UpdateTemplateResult {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "template_id" => {
-                    if field_template_id.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("template_id"));
-                    }
-                    field_template_id = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "template_id" => crate::deserialize_field(&mut map, &mut field_template_id, "template_id")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -13,6 +13,21 @@
 pub type FileRequestId = String;
 pub type FileRequestValidationError = Option<String>;
 
+/// The names of this namespace's routes, as they appear in request URLs after the
+/// [`Endpoint`](crate::client_trait_common::Endpoint) base URL, for referring to them in logs and
+/// metrics without spelling them out.
+pub mod routes {
+    pub const COUNT: &str = "file_requests/count";
+    pub const CREATE: &str = "file_requests/create";
+    pub const DELETE: &str = "file_requests/delete";
+    pub const DELETE_ALL_CLOSED: &str = "file_requests/delete_all_closed";
+    pub const GET: &str = "file_requests/get";
+    pub const LIST_V2: &str = "file_requests/list_v2";
+    pub const LIST: &str = "file_requests/list";
+    pub const LIST_CONTINUE: &str = "file_requests/list/continue";
+    pub const UPDATE: &str = "file_requests/update";
+}
+
 /// There was an error counting the file requests.
 #[derive(Debug, Clone, PartialEq, Eq)]
 #[non_exhaustive] // variants may be added in the future
@@ -20,8 +35,9 @@ pub enum CountFileRequestsError {
     /// This user's Dropbox Business team doesn't allow file requests.
     DisabledForTeam,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for CountFileRequestsError {
@@ -41,7 +57,7 @@ impl<'de> ::serde::de::Deserialize<'de> for CountFileRequestsError {
                 };
                 let value = match tag {
                     "disabled_for_team" => CountFileRequestsError::DisabledForTeam,
-                    _ => CountFileRequestsError::Other,
+                    _ => CountFileRequestsError::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -64,7 +80,10 @@ impl ::serde::ser::Serialize for CountFileRequestsError {
                 s.serialize_field(".tag", "disabled_for_team")?;
                 s.end()
             }
-            CountFileRequestsError::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            CountFileRequestsError::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -86,7 +105,7 @@ impl From<GeneralFileRequestsError> for CountFileRequestsError {
     fn from(parent: GeneralFileRequestsError) -> Self {
         match parent {
             GeneralFileRequestsError::DisabledForTeam => CountFileRequestsError::DisabledForTeam,
-            GeneralFileRequestsError::Other => CountFileRequestsError::Other,
+            GeneralFileRequestsError::Other(x) => CountFileRequestsError::Other(x),
         }
     }
 }
@@ -123,16 +142,8 @@ impl CountFileRequestsResult {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "file_request_count" => {
-                    if field_file_request_count.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("file_request_count"));
-                    }
-                    field_file_request_count = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "file_request_count" => crate::deserialize_field(&mut map, &mut field_file_request_count, "file_request_count")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -253,40 +264,12 @@ impl CreateFileRequestArgs {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "title" => {
-                    if field_title.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("title"));
-                    }
-                    field_title = Some(map.next_value()?);
-                }
-                "destination" => {
-                    if field_destination.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("destination"));
-                    }
-                    field_destination = Some(map.next_value()?);
-                }
-                "deadline" => {
-                    if field_deadline.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("deadline"));
-                    }
-                    field_deadline = Some(map.next_value()?);
-                }
-                "open" => {
-                    if field_open.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("open"));
-                    }
-                    field_open = Some(map.next_value()?);
-                }
-                "description" => {
-                    if field_description.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("description"));
-                    }
-                    field_description = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "title" => crate::deserialize_field(&mut map, &mut field_title, "title")?,
+                "destination" => crate::deserialize_field(&mut map, &mut field_destination, "destination")?,
+                "deadline" => crate::deserialize_field(&mut map, &mut field_deadline, "deadline")?,
+                "open" => crate::deserialize_field(&mut map, &mut field_open, "open")?,
+                "description" => crate::deserialize_field(&mut map, &mut field_description, "description")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -378,8 +361,9 @@ pub enum CreateFileRequestError {
     /// file requests total.
     RateLimit,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for CreateFileRequestError {
@@ -407,7 +391,7 @@ impl<'de> ::serde::de::Deserialize<'de> for CreateFileRequestError {
                     "validation_error" => CreateFileRequestError::ValidationError,
                     "invalid_location" => CreateFileRequestError::InvalidLocation,
                     "rate_limit" => CreateFileRequestError::RateLimit,
-                    _ => CreateFileRequestError::Other,
+                    _ => CreateFileRequestError::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -486,7 +470,10 @@ impl ::serde::ser::Serialize for CreateFileRequestError {
                 s.serialize_field(".tag", "rate_limit")?;
                 s.end()
             }
-            CreateFileRequestError::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            CreateFileRequestError::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -515,7 +502,7 @@ impl From<FileRequestError> for CreateFileRequestError {
     fn from(parent: FileRequestError) -> Self {
         match parent {
             FileRequestError::DisabledForTeam => CreateFileRequestError::DisabledForTeam,
-            FileRequestError::Other => CreateFileRequestError::Other,
+            FileRequestError::Other(x) => CreateFileRequestError::Other(x),
             FileRequestError::NotFound => CreateFileRequestError::NotFound,
             FileRequestError::NotAFolder => CreateFileRequestError::NotAFolder,
             FileRequestError::AppLacksAccess => CreateFileRequestError::AppLacksAccess,
@@ -548,8 +535,9 @@ pub enum DeleteAllClosedFileRequestsError {
     /// disallowed characters in the destination path.
     ValidationError,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for DeleteAllClosedFileRequestsError {
@@ -575,7 +563,7 @@ impl<'de> ::serde::de::Deserialize<'de> for DeleteAllClosedFileRequestsError {
                     "no_permission" => DeleteAllClosedFileRequestsError::NoPermission,
                     "email_unverified" => DeleteAllClosedFileRequestsError::EmailUnverified,
                     "validation_error" => DeleteAllClosedFileRequestsError::ValidationError,
-                    _ => DeleteAllClosedFileRequestsError::Other,
+                    _ => DeleteAllClosedFileRequestsError::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -640,7 +628,10 @@ impl ::serde::ser::Serialize for DeleteAllClosedFileRequestsError {
                 s.serialize_field(".tag", "validation_error")?;
                 s.end()
             }
-            DeleteAllClosedFileRequestsError::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            DeleteAllClosedFileRequestsError::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -667,7 +658,7 @@ impl From<FileRequestError> for DeleteAllClosedFileRequestsError {
     fn from(parent: FileRequestError) -> Self {
         match parent {
             FileRequestError::DisabledForTeam => DeleteAllClosedFileRequestsError::DisabledForTeam,
-            FileRequestError::Other => DeleteAllClosedFileRequestsError::Other,
+            FileRequestError::Other(x) => DeleteAllClosedFileRequestsError::Other(x),
             FileRequestError::NotFound => DeleteAllClosedFileRequestsError::NotFound,
             FileRequestError::NotAFolder => DeleteAllClosedFileRequestsError::NotAFolder,
             FileRequestError::AppLacksAccess => DeleteAllClosedFileRequestsError::AppLacksAccess,
@@ -710,16 +701,8 @@ impl DeleteAllClosedFileRequestsResult {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "file_requests" => {
-                    if field_file_requests.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("file_requests"));
-                    }
-                    field_file_requests = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "file_requests" => crate::deserialize_field(&mut map, &mut field_file_requests, "file_requests")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -802,16 +785,8 @@ impl DeleteFileRequestArgs {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "ids" => {
-                    if field_ids.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("ids"));
-                    }
-                    field_ids = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "ids" => crate::deserialize_field(&mut map, &mut field_ids, "ids")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -886,8 +861,9 @@ pub enum DeleteFileRequestError {
     /// One or more file requests currently open.
     FileRequestOpen,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for DeleteFileRequestError {
@@ -914,7 +890,7 @@ impl<'de> ::serde::de::Deserialize<'de> for DeleteFileRequestError {
                     "email_unverified" => DeleteFileRequestError::EmailUnverified,
                     "validation_error" => DeleteFileRequestError::ValidationError,
                     "file_request_open" => DeleteFileRequestError::FileRequestOpen,
-                    _ => DeleteFileRequestError::Other,
+                    _ => DeleteFileRequestError::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -986,7 +962,10 @@ impl ::serde::ser::Serialize for DeleteFileRequestError {
                 s.serialize_field(".tag", "file_request_open")?;
                 s.end()
             }
-            DeleteFileRequestError::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            DeleteFileRequestError::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -1014,7 +993,7 @@ impl From<FileRequestError> for DeleteFileRequestError {
     fn from(parent: FileRequestError) -> Self {
         match parent {
             FileRequestError::DisabledForTeam => DeleteFileRequestError::DisabledForTeam,
-            FileRequestError::Other => DeleteFileRequestError::Other,
+            FileRequestError::Other(x) => DeleteFileRequestError::Other(x),
             FileRequestError::NotFound => DeleteFileRequestError::NotFound,
             FileRequestError::NotAFolder => DeleteFileRequestError::NotAFolder,
             FileRequestError::AppLacksAccess => DeleteFileRequestError::AppLacksAccess,
@@ -1057,16 +1036,8 @@ impl DeleteFileRequestsResult {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "file_requests" => {
-                    if field_file_requests.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("file_requests"));
-                    }
-                    field_file_requests = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "file_requests" => crate::deserialize_field(&mut map, &mut field_file_requests, "file_requests")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -1215,64 +1186,16 @@ impl FileRequest {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "id" => {
-                    if field_id.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("id"));
-                    }
-                    field_id = Some(map.next_value()?);
-                }
-                "url" => {
-                    if field_url.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("url"));
-                    }
-                    field_url = Some(map.next_value()?);
-                }
-                "title" => {
-                    if field_title.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("title"));
-                    }
-                    field_title = Some(map.next_value()?);
-                }
-                "created" => {
-                    if field_created.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("created"));
-                    }
-                    field_created = Some(map.next_value()?);
-                }
-                "is_open" => {
-                    if field_is_open.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("is_open"));
-                    }
-                    field_is_open = Some(map.next_value()?);
-                }
-                "file_count" => {
-                    if field_file_count.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("file_count"));
-                    }
-                    field_file_count = Some(map.next_value()?);
-                }
-                "destination" => {
-                    if field_destination.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("destination"));
-                    }
-                    field_destination = Some(map.next_value()?);
-                }
-                "deadline" => {
-                    if field_deadline.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("deadline"));
-                    }
-                    field_deadline = Some(map.next_value()?);
-                }
-                "description" => {
-                    if field_description.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("description"));
-                    }
-                    field_description = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "id" => crate::deserialize_field(&mut map, &mut field_id, "id")?,
+                "url" => crate::deserialize_field(&mut map, &mut field_url, "url")?,
+                "title" => crate::deserialize_field(&mut map, &mut field_title, "title")?,
+                "created" => crate::deserialize_field(&mut map, &mut field_created, "created")?,
+                "is_open" => crate::deserialize_field(&mut map, &mut field_is_open, "is_open")?,
+                "file_count" => crate::deserialize_field(&mut map, &mut field_file_count, "file_count")?,
+                "destination" => crate::deserialize_field(&mut map, &mut field_destination, "destination")?,
+                "deadline" => crate::deserialize_field(&mut map, &mut field_deadline, "deadline")?,
+                "description" => crate::deserialize_field(&mut map, &mut field_description, "description")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -1387,22 +1310,9 @@ impl FileRequestDeadline {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "deadline" => {
-                    if field_deadline.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("deadline"));
-                    }
-                    field_deadline = Some(map.next_value()?);
-                }
-                "allow_late_uploads" => {
-                    if field_allow_late_uploads.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("allow_late_uploads"));
-                    }
-                    field_allow_late_uploads = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "deadline" => crate::deserialize_field(&mut map, &mut field_deadline, "deadline")?,
+                "allow_late_uploads" => crate::deserialize_field(&mut map, &mut field_allow_late_uploads, "allow_late_uploads")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -1479,8 +1389,9 @@ pub enum FileRequestError {
     /// disallowed characters in the destination path.
     ValidationError,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for FileRequestError {
@@ -1506,7 +1417,7 @@ impl<'de> ::serde::de::Deserialize<'de> for FileRequestError {
                     "no_permission" => FileRequestError::NoPermission,
                     "email_unverified" => FileRequestError::EmailUnverified,
                     "validation_error" => FileRequestError::ValidationError,
-                    _ => FileRequestError::Other,
+                    _ => FileRequestError::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -1571,7 +1482,10 @@ impl ::serde::ser::Serialize for FileRequestError {
                 s.serialize_field(".tag", "validation_error")?;
                 s.end()
             }
-            FileRequestError::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            FileRequestError::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -1598,7 +1512,7 @@ impl From<GeneralFileRequestsError> for FileRequestError {
     fn from(parent: GeneralFileRequestsError) -> Self {
         match parent {
             GeneralFileRequestsError::DisabledForTeam => FileRequestError::DisabledForTeam,
-            GeneralFileRequestsError::Other => FileRequestError::Other,
+            GeneralFileRequestsError::Other(x) => FileRequestError::Other(x),
         }
     }
 }
@@ -1609,8 +1523,9 @@ pub enum GeneralFileRequestsError {
     /// This user's Dropbox Business team doesn't allow file requests.
     DisabledForTeam,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for GeneralFileRequestsError {
@@ -1630,7 +1545,7 @@ impl<'de> ::serde::de::Deserialize<'de> for GeneralFileRequestsError {
                 };
                 let value = match tag {
                     "disabled_for_team" => GeneralFileRequestsError::DisabledForTeam,
-                    _ => GeneralFileRequestsError::Other,
+                    _ => GeneralFileRequestsError::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -1653,7 +1568,10 @@ impl ::serde::ser::Serialize for GeneralFileRequestsError {
                 s.serialize_field(".tag", "disabled_for_team")?;
                 s.end()
             }
-            GeneralFileRequestsError::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            GeneralFileRequestsError::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -1703,16 +1621,8 @@ impl GetFileRequestArgs {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "id" => {
-                    if field_id.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("id"));
-                    }
-                    field_id = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "id" => crate::deserialize_field(&mut map, &mut field_id, "id")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -1785,8 +1695,9 @@ pub enum GetFileRequestError {
     /// disallowed characters in the destination path.
     ValidationError,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for GetFileRequestError {
@@ -1812,7 +1723,7 @@ impl<'de> ::serde::de::Deserialize<'de> for GetFileRequestError {
                     "no_permission" => GetFileRequestError::NoPermission,
                     "email_unverified" => GetFileRequestError::EmailUnverified,
                     "validation_error" => GetFileRequestError::ValidationError,
-                    _ => GetFileRequestError::Other,
+                    _ => GetFileRequestError::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -1877,7 +1788,10 @@ impl ::serde::ser::Serialize for GetFileRequestError {
                 s.serialize_field(".tag", "validation_error")?;
                 s.end()
             }
-            GetFileRequestError::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            GetFileRequestError::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -1904,7 +1818,7 @@ impl From<FileRequestError> for GetFileRequestError {
     fn from(parent: FileRequestError) -> Self {
         match parent {
             FileRequestError::DisabledForTeam => GetFileRequestError::DisabledForTeam,
-            FileRequestError::Other => GetFileRequestError::Other,
+            FileRequestError::Other(x) => GetFileRequestError::Other(x),
             FileRequestError::NotFound => GetFileRequestError::NotFound,
             FileRequestError::NotAFolder => GetFileRequestError::NotAFolder,
             FileRequestError::AppLacksAccess => GetFileRequestError::AppLacksAccess,
@@ -1923,8 +1837,9 @@ pub enum GracePeriod {
     ThirtyDays,
     Always,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for GracePeriod {
@@ -1948,7 +1863,7 @@ impl<'de> ::serde::de::Deserialize<'de> for GracePeriod {
                     "seven_days" => GracePeriod::SevenDays,
                     "thirty_days" => GracePeriod::ThirtyDays,
                     "always" => GracePeriod::Always,
-                    _ => GracePeriod::Other,
+                    _ => GracePeriod::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -1999,7 +1914,10 @@ impl ::serde::ser::Serialize for GracePeriod {
                 s.serialize_field(".tag", "always")?;
                 s.end()
             }
-            GracePeriod::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            GracePeriod::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -2036,16 +1954,8 @@ impl ListFileRequestsArg {
         let mut field_limit = None;
         while let Some(key) = map.next_key::<&str>()? {
             match key {
-                "limit" => {
-                    if field_limit.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("limit"));
-                    }
-                    field_limit = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "limit" => crate::deserialize_field(&mut map, &mut field_limit, "limit")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         let result = ListFileRequestsArg {
@@ -2126,16 +2036,8 @@ impl ListFileRequestsContinueArg {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "cursor" => {
-                    if field_cursor.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("cursor"));
-                    }
-                    field_cursor = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "cursor" => crate::deserialize_field(&mut map, &mut field_cursor, "cursor")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -2194,8 +2096,9 @@ pub enum ListFileRequestsContinueError {
     /// The cursor is invalid.
     InvalidCursor,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for ListFileRequestsContinueError {
@@ -2216,7 +2119,7 @@ impl<'de> ::serde::de::Deserialize<'de> for ListFileRequestsContinueError {
                 let value = match tag {
                     "disabled_for_team" => ListFileRequestsContinueError::DisabledForTeam,
                     "invalid_cursor" => ListFileRequestsContinueError::InvalidCursor,
-                    _ => ListFileRequestsContinueError::Other,
+                    _ => ListFileRequestsContinueError::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -2246,7 +2149,10 @@ impl ::serde::ser::Serialize for ListFileRequestsContinueError {
                 s.serialize_field(".tag", "invalid_cursor")?;
                 s.end()
             }
-            ListFileRequestsContinueError::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            ListFileRequestsContinueError::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -2269,7 +2175,7 @@ impl From<GeneralFileRequestsError> for ListFileRequestsContinueError {
     fn from(parent: GeneralFileRequestsError) -> Self {
         match parent {
             GeneralFileRequestsError::DisabledForTeam => ListFileRequestsContinueError::DisabledForTeam,
-            GeneralFileRequestsError::Other => ListFileRequestsContinueError::Other,
+            GeneralFileRequestsError::Other(x) => ListFileRequestsContinueError::Other(x),
         }
     }
 }
@@ -2280,8 +2186,9 @@ pub enum ListFileRequestsError {
     /// This user's Dropbox Business team doesn't allow file requests.
     DisabledForTeam,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for ListFileRequestsError {
@@ -2301,7 +2208,7 @@ impl<'de> ::serde::de::Deserialize<'de> for ListFileRequestsError {
                 };
                 let value = match tag {
                     "disabled_for_team" => ListFileRequestsError::DisabledForTeam,
-                    _ => ListFileRequestsError::Other,
+                    _ => ListFileRequestsError::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -2324,7 +2231,10 @@ impl ::serde::ser::Serialize for ListFileRequestsError {
                 s.serialize_field(".tag", "disabled_for_team")?;
                 s.end()
             }
-            ListFileRequestsError::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            ListFileRequestsError::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -2346,7 +2256,7 @@ impl From<GeneralFileRequestsError> for ListFileRequestsError {
     fn from(parent: GeneralFileRequestsError) -> Self {
         match parent {
             GeneralFileRequestsError::DisabledForTeam => ListFileRequestsError::DisabledForTeam,
-            GeneralFileRequestsError::Other => ListFileRequestsError::Other,
+            GeneralFileRequestsError::Other(x) => ListFileRequestsError::Other(x),
         }
     }
 }
@@ -2384,16 +2294,8 @@ impl ListFileRequestsResult {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "file_requests" => {
-                    if field_file_requests.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("file_requests"));
-                    }
-                    field_file_requests = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "file_requests" => crate::deserialize_field(&mut map, &mut field_file_requests, "file_requests")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -2490,28 +2392,10 @@ impl ListFileRequestsV2Result {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "file_requests" => {
-                    if field_file_requests.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("file_requests"));
-                    }
-                    field_file_requests = Some(map.next_value()?);
-                }
-                "cursor" => {
-                    if field_cursor.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("cursor"));
-                    }
-                    field_cursor = Some(map.next_value()?);
-                }
-                "has_more" => {
-                    if field_has_more.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("has_more"));
-                    }
-                    field_has_more = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "file_requests" => crate::deserialize_field(&mut map, &mut field_file_requests, "file_requests")?,
+                "cursor" => crate::deserialize_field(&mut map, &mut field_cursor, "cursor")?,
+                "has_more" => crate::deserialize_field(&mut map, &mut field_has_more, "has_more")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -2650,46 +2534,13 @@ impl UpdateFileRequestArgs {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "id" => {
-                    if field_id.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("id"));
-                    }
-                    field_id = Some(map.next_value()?);
-                }
-                "title" => {
-                    if field_title.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("title"));
-                    }
-                    field_title = Some(map.next_value()?);
-                }
-                "destination" => {
-                    if field_destination.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("destination"));
-                    }
-                    field_destination = Some(map.next_value()?);
-                }
-                "deadline" => {
-                    if field_deadline.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("deadline"));
-                    }
-                    field_deadline = Some(map.next_value()?);
-                }
-                "open" => {
-                    if field_open.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("open"));
-                    }
-                    field_open = Some(map.next_value()?);
-                }
-                "description" => {
-                    if field_description.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("description"));
-                    }
-                    field_description = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "id" => crate::deserialize_field(&mut map, &mut field_id, "id")?,
+                "title" => crate::deserialize_field(&mut map, &mut field_title, "title")?,
+                "destination" => crate::deserialize_field(&mut map, &mut field_destination, "destination")?,
+                "deadline" => crate::deserialize_field(&mut map, &mut field_deadline, "deadline")?,
+                "open" => crate::deserialize_field(&mut map, &mut field_open, "open")?,
+                "description" => crate::deserialize_field(&mut map, &mut field_description, "description")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -2767,8 +2618,9 @@ pub enum UpdateFileRequestDeadline {
     /// If `None`, the file request's deadline is cleared.
     Update(Option<FileRequestDeadline>),
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for UpdateFileRequestDeadline {
@@ -2789,7 +2641,7 @@ impl<'de> ::serde::de::Deserialize<'de> for UpdateFileRequestDeadline {
                 let value = match tag {
                     "no_update" => UpdateFileRequestDeadline::NoUpdate,
                     "update" => UpdateFileRequestDeadline::Update(FileRequestDeadline::internal_deserialize_opt(&mut map, true)?),
-                    _ => UpdateFileRequestDeadline::Other,
+                    _ => UpdateFileRequestDeadline::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -2823,7 +2675,10 @@ impl ::serde::ser::Serialize for UpdateFileRequestDeadline {
                 }
                 s.end()
             }
-            UpdateFileRequestDeadline::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            UpdateFileRequestDeadline::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -2851,8 +2706,9 @@ pub enum UpdateFileRequestError {
     /// disallowed characters in the destination path.
     ValidationError,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for UpdateFileRequestError {
@@ -2878,7 +2734,7 @@ impl<'de> ::serde::de::Deserialize<'de> for UpdateFileRequestError {
                     "no_permission" => UpdateFileRequestError::NoPermission,
                     "email_unverified" => UpdateFileRequestError::EmailUnverified,
                     "validation_error" => UpdateFileRequestError::ValidationError,
-                    _ => UpdateFileRequestError::Other,
+                    _ => UpdateFileRequestError::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -2943,7 +2799,10 @@ impl ::serde::ser::Serialize for UpdateFileRequestError {
                 s.serialize_field(".tag", "validation_error")?;
                 s.end()
             }
-            UpdateFileRequestError::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            UpdateFileRequestError::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -2970,7 +2829,7 @@ impl From<FileRequestError> for UpdateFileRequestError {
     fn from(parent: FileRequestError) -> Self {
         match parent {
             FileRequestError::DisabledForTeam => UpdateFileRequestError::DisabledForTeam,
-            FileRequestError::Other => UpdateFileRequestError::Other,
+            FileRequestError::Other(x) => UpdateFileRequestError::Other(x),
             FileRequestError::NotFound => UpdateFileRequestError::NotFound,
             FileRequestError::NotAFolder => UpdateFileRequestError::NotAFolder,
             FileRequestError::AppLacksAccess => UpdateFileRequestError::AppLacksAccess,
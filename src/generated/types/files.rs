@@ -28,6 +28,89 @@ pub type TagText = String;
 pub type WritePath = String;
 pub type WritePathOrId = String;
 
+/// The names of this namespace's routes, as they appear in request URLs after the
+/// [`Endpoint`](crate::client_trait_common::Endpoint) base URL, for referring to them in logs and
+/// metrics without spelling them out.
+pub mod routes {
+    #[cfg(feature = "unstable")]
+    pub const ALPHA_GET_METADATA: &str = "files/alpha/get_metadata";
+    #[cfg(feature = "unstable")]
+    pub const ALPHA_UPLOAD: &str = "files/alpha/upload";
+    pub const COPY_V2: &str = "files/copy_v2";
+    pub const COPY: &str = "files/copy";
+    pub const COPY_BATCH_V2: &str = "files/copy_batch_v2";
+    pub const COPY_BATCH: &str = "files/copy_batch";
+    pub const COPY_BATCH_CHECK_V2: &str = "files/copy_batch/check_v2";
+    pub const COPY_BATCH_CHECK: &str = "files/copy_batch/check";
+    pub const COPY_REFERENCE_GET: &str = "files/copy_reference/get";
+    pub const COPY_REFERENCE_SAVE: &str = "files/copy_reference/save";
+    pub const CREATE_FOLDER_V2: &str = "files/create_folder_v2";
+    pub const CREATE_FOLDER: &str = "files/create_folder";
+    pub const CREATE_FOLDER_BATCH: &str = "files/create_folder_batch";
+    pub const CREATE_FOLDER_BATCH_CHECK: &str = "files/create_folder_batch/check";
+    pub const DELETE_V2: &str = "files/delete_v2";
+    pub const DELETE: &str = "files/delete";
+    pub const DELETE_BATCH: &str = "files/delete_batch";
+    pub const DELETE_BATCH_CHECK: &str = "files/delete_batch/check";
+    pub const DOWNLOAD: &str = "files/download";
+    pub const DOWNLOAD_ZIP: &str = "files/download_zip";
+    #[cfg(feature = "unstable")]
+    pub const EXPORT: &str = "files/export";
+    pub const GET_FILE_LOCK_BATCH: &str = "files/get_file_lock_batch";
+    pub const GET_METADATA: &str = "files/get_metadata";
+    pub const GET_PREVIEW: &str = "files/get_preview";
+    pub const GET_TEMPORARY_LINK: &str = "files/get_temporary_link";
+    pub const GET_TEMPORARY_UPLOAD_LINK: &str = "files/get_temporary_upload_link";
+    pub const GET_THUMBNAIL: &str = "files/get_thumbnail";
+    pub const GET_THUMBNAIL_V2: &str = "files/get_thumbnail_v2";
+    pub const GET_THUMBNAIL_BATCH: &str = "files/get_thumbnail_batch";
+    pub const LIST_FOLDER: &str = "files/list_folder";
+    pub const LIST_FOLDER_CONTINUE: &str = "files/list_folder/continue";
+    pub const LIST_FOLDER_GET_LATEST_CURSOR: &str = "files/list_folder/get_latest_cursor";
+    pub const LIST_FOLDER_LONGPOLL: &str = "files/list_folder/longpoll";
+    pub const LIST_REVISIONS: &str = "files/list_revisions";
+    pub const LOCK_FILE_BATCH: &str = "files/lock_file_batch";
+    pub const MOVE_V2: &str = "files/move_v2";
+    pub const MOVE: &str = "files/move";
+    pub const MOVE_BATCH_V2: &str = "files/move_batch_v2";
+    pub const MOVE_BATCH: &str = "files/move_batch";
+    pub const MOVE_BATCH_CHECK_V2: &str = "files/move_batch/check_v2";
+    pub const MOVE_BATCH_CHECK: &str = "files/move_batch/check";
+    #[cfg(feature = "unstable")]
+    pub const PAPER_CREATE: &str = "files/paper/create";
+    #[cfg(feature = "unstable")]
+    pub const PAPER_UPDATE: &str = "files/paper/update";
+    pub const PERMANENTLY_DELETE: &str = "files/permanently_delete";
+    pub const PROPERTIES_ADD: &str = "files/properties/add";
+    pub const PROPERTIES_OVERWRITE: &str = "files/properties/overwrite";
+    pub const PROPERTIES_REMOVE: &str = "files/properties/remove";
+    pub const PROPERTIES_TEMPLATE_GET: &str = "files/properties/template/get";
+    pub const PROPERTIES_TEMPLATE_LIST: &str = "files/properties/template/list";
+    pub const PROPERTIES_UPDATE: &str = "files/properties/update";
+    pub const RESTORE: &str = "files/restore";
+    pub const SAVE_URL: &str = "files/save_url";
+    pub const SAVE_URL_CHECK_JOB_STATUS: &str = "files/save_url/check_job_status";
+    pub const SEARCH: &str = "files/search";
+    pub const SEARCH_V2: &str = "files/search_v2";
+    pub const SEARCH_CONTINUE_V2: &str = "files/search/continue_v2";
+    #[cfg(feature = "unstable")]
+    pub const TAGS_ADD: &str = "files/tags/add";
+    #[cfg(feature = "unstable")]
+    pub const TAGS_GET: &str = "files/tags/get";
+    #[cfg(feature = "unstable")]
+    pub const TAGS_REMOVE: &str = "files/tags/remove";
+    pub const UNLOCK_FILE_BATCH: &str = "files/unlock_file_batch";
+    pub const UPLOAD: &str = "files/upload";
+    pub const UPLOAD_SESSION_APPEND_V2: &str = "files/upload_session/append_v2";
+    pub const UPLOAD_SESSION_APPEND: &str = "files/upload_session/append";
+    pub const UPLOAD_SESSION_FINISH: &str = "files/upload_session/finish";
+    pub const UPLOAD_SESSION_FINISH_BATCH: &str = "files/upload_session/finish_batch";
+    pub const UPLOAD_SESSION_FINISH_BATCH_V2: &str = "files/upload_session/finish_batch_v2";
+    pub const UPLOAD_SESSION_FINISH_BATCH_CHECK: &str = "files/upload_session/finish_batch/check";
+    pub const UPLOAD_SESSION_START: &str = "files/upload_session/start";
+    pub const UPLOAD_SESSION_START_BATCH: &str = "files/upload_session/start_batch";
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 #[non_exhaustive] // structs may have more fields added in the future.
 pub struct AddTagArg {
@@ -65,22 +148,9 @@ impl AddTagArg {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "path" => {
-                    if field_path.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("path"));
-                    }
-                    field_path = Some(map.next_value()?);
-                }
-                "tag_text" => {
-                    if field_tag_text.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("tag_text"));
-                    }
-                    field_tag_text = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "path" => crate::deserialize_field(&mut map, &mut field_path, "path")?,
+                "tag_text" => crate::deserialize_field(&mut map, &mut field_tag_text, "tag_text")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -139,8 +209,9 @@ pub enum AddTagError {
     /// The item already has the maximum supported number of tags.
     TooManyTags,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for AddTagError {
@@ -167,7 +238,7 @@ impl<'de> ::serde::de::Deserialize<'de> for AddTagError {
                         }
                     }
                     "too_many_tags" => AddTagError::TooManyTags,
-                    _ => AddTagError::Other,
+                    _ => AddTagError::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -198,7 +269,10 @@ impl ::serde::ser::Serialize for AddTagError {
                 s.serialize_field(".tag", "too_many_tags")?;
                 s.end()
             }
-            AddTagError::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            AddTagError::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -215,7 +289,7 @@ impl ::std::error::Error for AddTagError {
 impl ::std::fmt::Display for AddTagError {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         match self {
-            AddTagError::Path(inner) => write!(f, "{}", inner),
+            AddTagError::Path(inner) => write!(f, "path: {}", inner),
             AddTagError::TooManyTags => f.write_str("The item already has the maximum supported number of tags."),
             _ => write!(f, "{:?}", *self),
         }
@@ -227,7 +301,7 @@ impl From<BaseTagError> for AddTagError {
     fn from(parent: BaseTagError) -> Self {
         match parent {
             BaseTagError::Path(x) => AddTagError::Path(x),
-            BaseTagError::Other => AddTagError::Other,
+            BaseTagError::Other(x) => AddTagError::Other(x),
         }
     }
 }
@@ -323,46 +397,13 @@ impl AlphaGetMetadataArg {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "path" => {
-                    if field_path.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("path"));
-                    }
-                    field_path = Some(map.next_value()?);
-                }
-                "include_media_info" => {
-                    if field_include_media_info.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("include_media_info"));
-                    }
-                    field_include_media_info = Some(map.next_value()?);
-                }
-                "include_deleted" => {
-                    if field_include_deleted.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("include_deleted"));
-                    }
-                    field_include_deleted = Some(map.next_value()?);
-                }
-                "include_has_explicit_shared_members" => {
-                    if field_include_has_explicit_shared_members.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("include_has_explicit_shared_members"));
-                    }
-                    field_include_has_explicit_shared_members = Some(map.next_value()?);
-                }
-                "include_property_groups" => {
-                    if field_include_property_groups.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("include_property_groups"));
-                    }
-                    field_include_property_groups = Some(map.next_value()?);
-                }
-                "include_property_templates" => {
-                    if field_include_property_templates.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("include_property_templates"));
-                    }
-                    field_include_property_templates = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "path" => crate::deserialize_field(&mut map, &mut field_path, "path")?,
+                "include_media_info" => crate::deserialize_field(&mut map, &mut field_include_media_info, "include_media_info")?,
+                "include_deleted" => crate::deserialize_field(&mut map, &mut field_include_deleted, "include_deleted")?,
+                "include_has_explicit_shared_members" => crate::deserialize_field(&mut map, &mut field_include_has_explicit_shared_members, "include_has_explicit_shared_members")?,
+                "include_property_groups" => crate::deserialize_field(&mut map, &mut field_include_property_groups, "include_property_groups")?,
+                "include_property_templates" => crate::deserialize_field(&mut map, &mut field_include_property_templates, "include_property_templates")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -527,8 +568,8 @@ impl ::std::error::Error for AlphaGetMetadataError {
 impl ::std::fmt::Display for AlphaGetMetadataError {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         match self {
-            AlphaGetMetadataError::Path(inner) => write!(f, "{}", inner),
-            AlphaGetMetadataError::PropertiesError(inner) => write!(f, "{}", inner),
+            AlphaGetMetadataError::Path(inner) => write!(f, "path: {}", inner),
+            AlphaGetMetadataError::PropertiesError(inner) => write!(f, "properties_error: {}", inner),
         }
     }
 }
@@ -546,8 +587,9 @@ impl From<GetMetadataError> for AlphaGetMetadataError {
 pub enum BaseTagError {
     Path(LookupError),
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for BaseTagError {
@@ -573,7 +615,7 @@ impl<'de> ::serde::de::Deserialize<'de> for BaseTagError {
                             _ => return Err(de::Error::unknown_field(tag, VARIANTS))
                         }
                     }
-                    _ => BaseTagError::Other,
+                    _ => BaseTagError::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -597,7 +639,10 @@ impl ::serde::ser::Serialize for BaseTagError {
                 s.serialize_field("path", x)?;
                 s.end()
             }
-            BaseTagError::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            BaseTagError::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -614,7 +659,7 @@ impl ::std::error::Error for BaseTagError {
 impl ::std::fmt::Display for BaseTagError {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         match self {
-            BaseTagError::Path(inner) => write!(f, "{}", inner),
+            BaseTagError::Path(inner) => write!(f, "path: {}", inner),
             _ => write!(f, "{:?}", *self),
         }
     }
@@ -725,52 +770,14 @@ impl CommitInfo {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "path" => {
-                    if field_path.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("path"));
-                    }
-                    field_path = Some(map.next_value()?);
-                }
-                "mode" => {
-                    if field_mode.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("mode"));
-                    }
-                    field_mode = Some(map.next_value()?);
-                }
-                "autorename" => {
-                    if field_autorename.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("autorename"));
-                    }
-                    field_autorename = Some(map.next_value()?);
-                }
-                "client_modified" => {
-                    if field_client_modified.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("client_modified"));
-                    }
-                    field_client_modified = Some(map.next_value()?);
-                }
-                "mute" => {
-                    if field_mute.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("mute"));
-                    }
-                    field_mute = Some(map.next_value()?);
-                }
-                "property_groups" => {
-                    if field_property_groups.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("property_groups"));
-                    }
-                    field_property_groups = Some(map.next_value()?);
-                }
-                "strict_conflict" => {
-                    if field_strict_conflict.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("strict_conflict"));
-                    }
-                    field_strict_conflict = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "path" => crate::deserialize_field(&mut map, &mut field_path, "path")?,
+                "mode" => crate::deserialize_field(&mut map, &mut field_mode, "mode")?,
+                "autorename" => crate::deserialize_field(&mut map, &mut field_autorename, "autorename")?,
+                "client_modified" => crate::deserialize_field(&mut map, &mut field_client_modified, "client_modified")?,
+                "mute" => crate::deserialize_field(&mut map, &mut field_mute, "mute")?,
+                "property_groups" => crate::deserialize_field(&mut map, &mut field_property_groups, "property_groups")?,
+                "strict_conflict" => crate::deserialize_field(&mut map, &mut field_strict_conflict, "strict_conflict")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -881,22 +888,9 @@ impl ContentSyncSetting {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "id" => {
-                    if field_id.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("id"));
-                    }
-                    field_id = Some(map.next_value()?);
-                }
-                "sync_setting" => {
-                    if field_sync_setting.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("sync_setting"));
-                    }
-                    field_sync_setting = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "id" => crate::deserialize_field(&mut map, &mut field_id, "id")?,
+                "sync_setting" => crate::deserialize_field(&mut map, &mut field_sync_setting, "sync_setting")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -985,22 +979,9 @@ impl ContentSyncSettingArg {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "id" => {
-                    if field_id.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("id"));
-                    }
-                    field_id = Some(map.next_value()?);
-                }
-                "sync_setting" => {
-                    if field_sync_setting.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("sync_setting"));
-                    }
-                    field_sync_setting = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "id" => crate::deserialize_field(&mut map, &mut field_id, "id")?,
+                "sync_setting" => crate::deserialize_field(&mut map, &mut field_sync_setting, "sync_setting")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -1095,22 +1076,9 @@ impl CreateFolderArg {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "path" => {
-                    if field_path.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("path"));
-                    }
-                    field_path = Some(map.next_value()?);
-                }
-                "autorename" => {
-                    if field_autorename.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("autorename"));
-                    }
-                    field_autorename = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "path" => crate::deserialize_field(&mut map, &mut field_path, "path")?,
+                "autorename" => crate::deserialize_field(&mut map, &mut field_autorename, "autorename")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -1218,28 +1186,10 @@ impl CreateFolderBatchArg {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "paths" => {
-                    if field_paths.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("paths"));
-                    }
-                    field_paths = Some(map.next_value()?);
-                }
-                "autorename" => {
-                    if field_autorename.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("autorename"));
-                    }
-                    field_autorename = Some(map.next_value()?);
-                }
-                "force_async" => {
-                    if field_force_async.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("force_async"));
-                    }
-                    field_force_async = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "paths" => crate::deserialize_field(&mut map, &mut field_paths, "paths")?,
+                "autorename" => crate::deserialize_field(&mut map, &mut field_autorename, "autorename")?,
+                "force_async" => crate::deserialize_field(&mut map, &mut field_force_async, "force_async")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -1303,8 +1253,9 @@ pub enum CreateFolderBatchError {
     /// The operation would involve too many files or folders.
     TooManyFiles,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for CreateFolderBatchError {
@@ -1324,7 +1275,7 @@ impl<'de> ::serde::de::Deserialize<'de> for CreateFolderBatchError {
                 };
                 let value = match tag {
                     "too_many_files" => CreateFolderBatchError::TooManyFiles,
-                    _ => CreateFolderBatchError::Other,
+                    _ => CreateFolderBatchError::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -1347,7 +1298,10 @@ impl ::serde::ser::Serialize for CreateFolderBatchError {
                 s.serialize_field(".tag", "too_many_files")?;
                 s.end()
             }
-            CreateFolderBatchError::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            CreateFolderBatchError::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -1374,8 +1328,9 @@ pub enum CreateFolderBatchJobStatus {
     /// The batch create folder has failed.
     Failed(CreateFolderBatchError),
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for CreateFolderBatchJobStatus {
@@ -1403,7 +1358,7 @@ impl<'de> ::serde::de::Deserialize<'de> for CreateFolderBatchJobStatus {
                             _ => return Err(de::Error::unknown_field(tag, VARIANTS))
                         }
                     }
-                    _ => CreateFolderBatchJobStatus::Other,
+                    _ => CreateFolderBatchJobStatus::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -1442,7 +1397,10 @@ impl ::serde::ser::Serialize for CreateFolderBatchJobStatus {
                 s.serialize_field("failed", x)?;
                 s.end()
             }
-            CreateFolderBatchJobStatus::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            CreateFolderBatchJobStatus::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -1465,8 +1423,9 @@ pub enum CreateFolderBatchLaunch {
     AsyncJobId(crate::types::dbx_async::AsyncJobId),
     Complete(CreateFolderBatchResult),
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for CreateFolderBatchLaunch {
@@ -1493,7 +1452,7 @@ impl<'de> ::serde::de::Deserialize<'de> for CreateFolderBatchLaunch {
                         }
                     }
                     "complete" => CreateFolderBatchLaunch::Complete(CreateFolderBatchResult::internal_deserialize(&mut map)?),
-                    _ => CreateFolderBatchLaunch::Other,
+                    _ => CreateFolderBatchLaunch::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -1525,7 +1484,10 @@ impl ::serde::ser::Serialize for CreateFolderBatchLaunch {
                 x.internal_serialize::<S>(&mut s)?;
                 s.end()
             }
-            CreateFolderBatchLaunch::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            CreateFolderBatchLaunch::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -1571,16 +1533,8 @@ impl CreateFolderBatchResult {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "entries" => {
-                    if field_entries.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("entries"));
-                    }
-                    field_entries = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "entries" => crate::deserialize_field(&mut map, &mut field_entries, "entries")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -1706,8 +1660,9 @@ impl ::serde::ser::Serialize for CreateFolderBatchResultEntry {
 pub enum CreateFolderEntryError {
     Path(WriteError),
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for CreateFolderEntryError {
@@ -1733,7 +1688,7 @@ impl<'de> ::serde::de::Deserialize<'de> for CreateFolderEntryError {
                             _ => return Err(de::Error::unknown_field(tag, VARIANTS))
                         }
                     }
-                    _ => CreateFolderEntryError::Other,
+                    _ => CreateFolderEntryError::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -1757,7 +1712,10 @@ impl ::serde::ser::Serialize for CreateFolderEntryError {
                 s.serialize_field("path", x)?;
                 s.end()
             }
-            CreateFolderEntryError::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            CreateFolderEntryError::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -1774,7 +1732,7 @@ impl ::std::error::Error for CreateFolderEntryError {
 impl ::std::fmt::Display for CreateFolderEntryError {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         match self {
-            CreateFolderEntryError::Path(inner) => write!(f, "{}", inner),
+            CreateFolderEntryError::Path(inner) => write!(f, "path: {}", inner),
             _ => write!(f, "{:?}", *self),
         }
     }
@@ -1812,16 +1770,8 @@ impl CreateFolderEntryResult {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "metadata" => {
-                    if field_metadata.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("metadata"));
-                    }
-                    field_metadata = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "metadata" => crate::deserialize_field(&mut map, &mut field_metadata, "metadata")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -1937,7 +1887,7 @@ impl ::std::error::Error for CreateFolderError {
 impl ::std::fmt::Display for CreateFolderError {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         match self {
-            CreateFolderError::Path(inner) => write!(f, "{}", inner),
+            CreateFolderError::Path(inner) => write!(f, "path: {}", inner),
         }
     }
 }
@@ -1974,16 +1924,8 @@ impl CreateFolderResult {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "metadata" => {
-                    if field_metadata.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("metadata"));
-                    }
-                    field_metadata = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "metadata" => crate::deserialize_field(&mut map, &mut field_metadata, "metadata")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -2082,22 +2024,9 @@ impl DeleteArg {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "path" => {
-                    if field_path.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("path"));
-                    }
-                    field_path = Some(map.next_value()?);
-                }
-                "parent_rev" => {
-                    if field_parent_rev.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("parent_rev"));
-                    }
-                    field_parent_rev = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "path" => crate::deserialize_field(&mut map, &mut field_path, "path")?,
+                "parent_rev" => crate::deserialize_field(&mut map, &mut field_parent_rev, "parent_rev")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -2182,16 +2111,8 @@ impl DeleteBatchArg {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "entries" => {
-                    if field_entries.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("entries"));
-                    }
-                    field_entries = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "entries" => crate::deserialize_field(&mut map, &mut field_entries, "entries")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -2249,8 +2170,9 @@ pub enum DeleteBatchError {
     /// entry has failed because of this.
     TooManyWriteOperations,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for DeleteBatchError {
@@ -2270,7 +2192,7 @@ impl<'de> ::serde::de::Deserialize<'de> for DeleteBatchError {
                 };
                 let value = match tag {
                     "too_many_write_operations" => DeleteBatchError::TooManyWriteOperations,
-                    _ => DeleteBatchError::Other,
+                    _ => DeleteBatchError::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -2293,7 +2215,10 @@ impl ::serde::ser::Serialize for DeleteBatchError {
                 s.serialize_field(".tag", "too_many_write_operations")?;
                 s.end()
             }
-            DeleteBatchError::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            DeleteBatchError::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -2317,8 +2242,9 @@ pub enum DeleteBatchJobStatus {
     /// The batch delete has failed.
     Failed(DeleteBatchError),
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for DeleteBatchJobStatus {
@@ -2346,7 +2272,7 @@ impl<'de> ::serde::de::Deserialize<'de> for DeleteBatchJobStatus {
                             _ => return Err(de::Error::unknown_field(tag, VARIANTS))
                         }
                     }
-                    _ => DeleteBatchJobStatus::Other,
+                    _ => DeleteBatchJobStatus::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -2385,7 +2311,10 @@ impl ::serde::ser::Serialize for DeleteBatchJobStatus {
                 s.serialize_field("failed", x)?;
                 s.end()
             }
-            DeleteBatchJobStatus::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            DeleteBatchJobStatus::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -2408,8 +2337,9 @@ pub enum DeleteBatchLaunch {
     AsyncJobId(crate::types::dbx_async::AsyncJobId),
     Complete(DeleteBatchResult),
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for DeleteBatchLaunch {
@@ -2436,7 +2366,7 @@ impl<'de> ::serde::de::Deserialize<'de> for DeleteBatchLaunch {
                         }
                     }
                     "complete" => DeleteBatchLaunch::Complete(DeleteBatchResult::internal_deserialize(&mut map)?),
-                    _ => DeleteBatchLaunch::Other,
+                    _ => DeleteBatchLaunch::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -2468,7 +2398,10 @@ impl ::serde::ser::Serialize for DeleteBatchLaunch {
                 x.internal_serialize::<S>(&mut s)?;
                 s.end()
             }
-            DeleteBatchLaunch::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            DeleteBatchLaunch::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -2514,16 +2447,8 @@ impl DeleteBatchResult {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "entries" => {
-                    if field_entries.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("entries"));
-                    }
-                    field_entries = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "entries" => crate::deserialize_field(&mut map, &mut field_entries, "entries")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -2611,16 +2536,8 @@ impl DeleteBatchResultData {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "metadata" => {
-                    if field_metadata.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("metadata"));
-                    }
-                    field_metadata = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "metadata" => crate::deserialize_field(&mut map, &mut field_metadata, "metadata")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -2745,8 +2662,9 @@ pub enum DeleteError {
     /// There are too many files in one request. Please retry with fewer files.
     TooManyFiles,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for DeleteError {
@@ -2781,7 +2699,7 @@ impl<'de> ::serde::de::Deserialize<'de> for DeleteError {
                     }
                     "too_many_write_operations" => DeleteError::TooManyWriteOperations,
                     "too_many_files" => DeleteError::TooManyFiles,
-                    _ => DeleteError::Other,
+                    _ => DeleteError::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -2827,7 +2745,10 @@ impl ::serde::ser::Serialize for DeleteError {
                 s.serialize_field(".tag", "too_many_files")?;
                 s.end()
             }
-            DeleteError::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            DeleteError::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -2845,8 +2766,8 @@ impl ::std::error::Error for DeleteError {
 impl ::std::fmt::Display for DeleteError {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         match self {
-            DeleteError::PathLookup(inner) => write!(f, "{}", inner),
-            DeleteError::PathWrite(inner) => write!(f, "{}", inner),
+            DeleteError::PathLookup(inner) => write!(f, "path_lookup: {}", inner),
+            DeleteError::PathWrite(inner) => write!(f, "path_write: {}", inner),
             DeleteError::TooManyWriteOperations => f.write_str("There are too many write operations in user's Dropbox. Please retry this request."),
             DeleteError::TooManyFiles => f.write_str("There are too many files in one request. Please retry with fewer files."),
             _ => write!(f, "{:?}", *self),
@@ -2886,16 +2807,8 @@ impl DeleteResult {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "metadata" => {
-                    if field_metadata.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("metadata"));
-                    }
-                    field_metadata = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "metadata" => crate::deserialize_field(&mut map, &mut field_metadata, "metadata")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -3034,40 +2947,12 @@ impl DeletedMetadata {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "name" => {
-                    if field_name.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("name"));
-                    }
-                    field_name = Some(map.next_value()?);
-                }
-                "path_lower" => {
-                    if field_path_lower.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("path_lower"));
-                    }
-                    field_path_lower = Some(map.next_value()?);
-                }
-                "path_display" => {
-                    if field_path_display.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("path_display"));
-                    }
-                    field_path_display = Some(map.next_value()?);
-                }
-                "parent_shared_folder_id" => {
-                    if field_parent_shared_folder_id.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("parent_shared_folder_id"));
-                    }
-                    field_parent_shared_folder_id = Some(map.next_value()?);
-                }
-                "preview_url" => {
-                    if field_preview_url.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("preview_url"));
-                    }
-                    field_preview_url = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "name" => crate::deserialize_field(&mut map, &mut field_name, "name")?,
+                "path_lower" => crate::deserialize_field(&mut map, &mut field_path_lower, "path_lower")?,
+                "path_display" => crate::deserialize_field(&mut map, &mut field_path_display, "path_display")?,
+                "parent_shared_folder_id" => crate::deserialize_field(&mut map, &mut field_parent_shared_folder_id, "parent_shared_folder_id")?,
+                "preview_url" => crate::deserialize_field(&mut map, &mut field_preview_url, "preview_url")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -3177,22 +3062,9 @@ impl Dimensions {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "height" => {
-                    if field_height.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("height"));
-                    }
-                    field_height = Some(map.next_value()?);
-                }
-                "width" => {
-                    if field_width.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("width"));
-                    }
-                    field_width = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "height" => crate::deserialize_field(&mut map, &mut field_height, "height")?,
+                "width" => crate::deserialize_field(&mut map, &mut field_width, "width")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -3286,22 +3158,9 @@ impl DownloadArg {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "path" => {
-                    if field_path.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("path"));
-                    }
-                    field_path = Some(map.next_value()?);
-                }
-                "rev" => {
-                    if field_rev.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("rev"));
-                    }
-                    field_rev = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "path" => crate::deserialize_field(&mut map, &mut field_path, "path")?,
+                "rev" => crate::deserialize_field(&mut map, &mut field_rev, "rev")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -3363,8 +3222,9 @@ pub enum DownloadError {
     /// instead.
     UnsupportedFile,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for DownloadError {
@@ -3391,7 +3251,7 @@ impl<'de> ::serde::de::Deserialize<'de> for DownloadError {
                         }
                     }
                     "unsupported_file" => DownloadError::UnsupportedFile,
-                    _ => DownloadError::Other,
+                    _ => DownloadError::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -3422,7 +3282,10 @@ impl ::serde::ser::Serialize for DownloadError {
                 s.serialize_field(".tag", "unsupported_file")?;
                 s.end()
             }
-            DownloadError::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            DownloadError::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -3439,7 +3302,7 @@ impl ::std::error::Error for DownloadError {
 impl ::std::fmt::Display for DownloadError {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         match self {
-            DownloadError::Path(inner) => write!(f, "{}", inner),
+            DownloadError::Path(inner) => write!(f, "path: {}", inner),
             _ => write!(f, "{:?}", *self),
         }
     }
@@ -3477,16 +3340,8 @@ impl DownloadZipArg {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "path" => {
-                    if field_path.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("path"));
-                    }
-                    field_path = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "path" => crate::deserialize_field(&mut map, &mut field_path, "path")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -3545,8 +3400,9 @@ pub enum DownloadZipError {
     /// The folder has too many files to download.
     TooManyFiles,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for DownloadZipError {
@@ -3574,7 +3430,7 @@ impl<'de> ::serde::de::Deserialize<'de> for DownloadZipError {
                     }
                     "too_large" => DownloadZipError::TooLarge,
                     "too_many_files" => DownloadZipError::TooManyFiles,
-                    _ => DownloadZipError::Other,
+                    _ => DownloadZipError::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -3612,7 +3468,10 @@ impl ::serde::ser::Serialize for DownloadZipError {
                 s.serialize_field(".tag", "too_many_files")?;
                 s.end()
             }
-            DownloadZipError::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            DownloadZipError::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -3629,7 +3488,7 @@ impl ::std::error::Error for DownloadZipError {
 impl ::std::fmt::Display for DownloadZipError {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         match self {
-            DownloadZipError::Path(inner) => write!(f, "{}", inner),
+            DownloadZipError::Path(inner) => write!(f, "path: {}", inner),
             DownloadZipError::TooLarge => f.write_str("The folder or a file is too large to download."),
             DownloadZipError::TooManyFiles => f.write_str("The folder has too many files to download."),
             _ => write!(f, "{:?}", *self),
@@ -3668,16 +3527,8 @@ impl DownloadZipResult {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "metadata" => {
-                    if field_metadata.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("metadata"));
-                    }
-                    field_metadata = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "metadata" => crate::deserialize_field(&mut map, &mut field_metadata, "metadata")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -3772,22 +3623,9 @@ impl ExportArg {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "path" => {
-                    if field_path.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("path"));
-                    }
-                    field_path = Some(map.next_value()?);
-                }
-                "export_format" => {
-                    if field_export_format.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("export_format"));
-                    }
-                    field_export_format = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "path" => crate::deserialize_field(&mut map, &mut field_path, "path")?,
+                "export_format" => crate::deserialize_field(&mut map, &mut field_export_format, "export_format")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -3852,8 +3690,9 @@ pub enum ExportError {
     /// The exportable content is not yet available. Please retry later.
     RetryError,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for ExportError {
@@ -3882,7 +3721,7 @@ impl<'de> ::serde::de::Deserialize<'de> for ExportError {
                     "non_exportable" => ExportError::NonExportable,
                     "invalid_export_format" => ExportError::InvalidExportFormat,
                     "retry_error" => ExportError::RetryError,
-                    _ => ExportError::Other,
+                    _ => ExportError::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -3927,7 +3766,10 @@ impl ::serde::ser::Serialize for ExportError {
                 s.serialize_field(".tag", "retry_error")?;
                 s.end()
             }
-            ExportError::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            ExportError::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -3944,7 +3786,7 @@ impl ::std::error::Error for ExportError {
 impl ::std::fmt::Display for ExportError {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         match self {
-            ExportError::Path(inner) => write!(f, "{}", inner),
+            ExportError::Path(inner) => write!(f, "path: {}", inner),
             ExportError::InvalidExportFormat => f.write_str("The specified export format is not a valid option for this file type."),
             ExportError::RetryError => f.write_str("The exportable content is not yet available. Please retry later."),
             _ => write!(f, "{:?}", *self),
@@ -3986,22 +3828,9 @@ impl ExportInfo {
         let mut field_export_options = None;
         while let Some(key) = map.next_key::<&str>()? {
             match key {
-                "export_as" => {
-                    if field_export_as.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("export_as"));
-                    }
-                    field_export_as = Some(map.next_value()?);
-                }
-                "export_options" => {
-                    if field_export_options.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("export_options"));
-                    }
-                    field_export_options = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "export_as" => crate::deserialize_field(&mut map, &mut field_export_as, "export_as")?,
+                "export_options" => crate::deserialize_field(&mut map, &mut field_export_options, "export_options")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         let result = ExportInfo {
@@ -4114,34 +3943,11 @@ impl ExportMetadata {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "name" => {
-                    if field_name.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("name"));
-                    }
-                    field_name = Some(map.next_value()?);
-                }
-                "size" => {
-                    if field_size.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("size"));
-                    }
-                    field_size = Some(map.next_value()?);
-                }
-                "export_hash" => {
-                    if field_export_hash.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("export_hash"));
-                    }
-                    field_export_hash = Some(map.next_value()?);
-                }
-                "paper_revision" => {
-                    if field_paper_revision.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("paper_revision"));
-                    }
-                    field_paper_revision = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "name" => crate::deserialize_field(&mut map, &mut field_name, "name")?,
+                "size" => crate::deserialize_field(&mut map, &mut field_size, "size")?,
+                "export_hash" => crate::deserialize_field(&mut map, &mut field_export_hash, "export_hash")?,
+                "paper_revision" => crate::deserialize_field(&mut map, &mut field_paper_revision, "paper_revision")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -4238,22 +4044,9 @@ impl ExportResult {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "export_metadata" => {
-                    if field_export_metadata.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("export_metadata"));
-                    }
-                    field_export_metadata = Some(map.next_value()?);
-                }
-                "file_metadata" => {
-                    if field_file_metadata.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("file_metadata"));
-                    }
-                    field_file_metadata = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "export_metadata" => crate::deserialize_field(&mut map, &mut field_export_metadata, "export_metadata")?,
+                "file_metadata" => crate::deserialize_field(&mut map, &mut field_file_metadata, "file_metadata")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -4329,8 +4122,9 @@ pub enum FileCategory {
     /// any file not in one of the categories above.
     Others,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for FileCategory {
@@ -4359,7 +4153,7 @@ impl<'de> ::serde::de::Deserialize<'de> for FileCategory {
                     "folder" => FileCategory::Folder,
                     "paper" => FileCategory::Paper,
                     "others" => FileCategory::Others,
-                    _ => FileCategory::Other,
+                    _ => FileCategory::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -4445,7 +4239,10 @@ impl ::serde::ser::Serialize for FileCategory {
                 s.serialize_field(".tag", "others")?;
                 s.end()
             }
-            FileCategory::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            FileCategory::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -4482,16 +4279,8 @@ impl FileLock {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "content" => {
-                    if field_content.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("content"));
-                    }
-                    field_content = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "content" => crate::deserialize_field(&mut map, &mut field_content, "content")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -4549,8 +4338,9 @@ pub enum FileLockContent {
     /// A lock held by a single user.
     SingleUser(SingleUserLock),
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for FileLockContent {
@@ -4571,7 +4361,7 @@ impl<'de> ::serde::de::Deserialize<'de> for FileLockContent {
                 let value = match tag {
                     "unlocked" => FileLockContent::Unlocked,
                     "single_user" => FileLockContent::SingleUser(SingleUserLock::internal_deserialize(&mut map)?),
-                    _ => FileLockContent::Other,
+                    _ => FileLockContent::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -4602,7 +4392,10 @@ impl ::serde::ser::Serialize for FileLockContent {
                 x.internal_serialize::<S>(&mut s)?;
                 s.end()
             }
-            FileLockContent::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            FileLockContent::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -4660,34 +4453,11 @@ impl FileLockMetadata {
         let mut field_created = None;
         while let Some(key) = map.next_key::<&str>()? {
             match key {
-                "is_lockholder" => {
-                    if field_is_lockholder.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("is_lockholder"));
-                    }
-                    field_is_lockholder = Some(map.next_value()?);
-                }
-                "lockholder_name" => {
-                    if field_lockholder_name.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("lockholder_name"));
-                    }
-                    field_lockholder_name = Some(map.next_value()?);
-                }
-                "lockholder_account_id" => {
-                    if field_lockholder_account_id.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("lockholder_account_id"));
-                    }
-                    field_lockholder_account_id = Some(map.next_value()?);
-                }
-                "created" => {
-                    if field_created.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("created"));
-                    }
-                    field_created = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "is_lockholder" => crate::deserialize_field(&mut map, &mut field_is_lockholder, "is_lockholder")?,
+                "lockholder_name" => crate::deserialize_field(&mut map, &mut field_lockholder_name, "lockholder_name")?,
+                "lockholder_account_id" => crate::deserialize_field(&mut map, &mut field_lockholder_account_id, "lockholder_account_id")?,
+                "created" => crate::deserialize_field(&mut map, &mut field_created, "created")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         let result = FileLockMetadata {
@@ -4971,124 +4741,26 @@ impl FileMetadata {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "name" => {
-                    if field_name.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("name"));
-                    }
-                    field_name = Some(map.next_value()?);
-                }
-                "id" => {
-                    if field_id.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("id"));
-                    }
-                    field_id = Some(map.next_value()?);
-                }
-                "client_modified" => {
-                    if field_client_modified.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("client_modified"));
-                    }
-                    field_client_modified = Some(map.next_value()?);
-                }
-                "server_modified" => {
-                    if field_server_modified.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("server_modified"));
-                    }
-                    field_server_modified = Some(map.next_value()?);
-                }
-                "rev" => {
-                    if field_rev.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("rev"));
-                    }
-                    field_rev = Some(map.next_value()?);
-                }
-                "size" => {
-                    if field_size.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("size"));
-                    }
-                    field_size = Some(map.next_value()?);
-                }
-                "path_lower" => {
-                    if field_path_lower.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("path_lower"));
-                    }
-                    field_path_lower = Some(map.next_value()?);
-                }
-                "path_display" => {
-                    if field_path_display.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("path_display"));
-                    }
-                    field_path_display = Some(map.next_value()?);
-                }
-                "parent_shared_folder_id" => {
-                    if field_parent_shared_folder_id.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("parent_shared_folder_id"));
-                    }
-                    field_parent_shared_folder_id = Some(map.next_value()?);
-                }
-                "preview_url" => {
-                    if field_preview_url.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("preview_url"));
-                    }
-                    field_preview_url = Some(map.next_value()?);
-                }
-                "media_info" => {
-                    if field_media_info.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("media_info"));
-                    }
-                    field_media_info = Some(map.next_value()?);
-                }
-                "symlink_info" => {
-                    if field_symlink_info.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("symlink_info"));
-                    }
-                    field_symlink_info = Some(map.next_value()?);
-                }
-                "sharing_info" => {
-                    if field_sharing_info.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("sharing_info"));
-                    }
-                    field_sharing_info = Some(map.next_value()?);
-                }
-                "is_downloadable" => {
-                    if field_is_downloadable.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("is_downloadable"));
-                    }
-                    field_is_downloadable = Some(map.next_value()?);
-                }
-                "export_info" => {
-                    if field_export_info.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("export_info"));
-                    }
-                    field_export_info = Some(map.next_value()?);
-                }
-                "property_groups" => {
-                    if field_property_groups.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("property_groups"));
-                    }
-                    field_property_groups = Some(map.next_value()?);
-                }
-                "has_explicit_shared_members" => {
-                    if field_has_explicit_shared_members.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("has_explicit_shared_members"));
-                    }
-                    field_has_explicit_shared_members = Some(map.next_value()?);
-                }
-                "content_hash" => {
-                    if field_content_hash.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("content_hash"));
-                    }
-                    field_content_hash = Some(map.next_value()?);
-                }
-                "file_lock_info" => {
-                    if field_file_lock_info.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("file_lock_info"));
-                    }
-                    field_file_lock_info = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "name" => crate::deserialize_field(&mut map, &mut field_name, "name")?,
+                "id" => crate::deserialize_field(&mut map, &mut field_id, "id")?,
+                "client_modified" => crate::deserialize_field(&mut map, &mut field_client_modified, "client_modified")?,
+                "server_modified" => crate::deserialize_field(&mut map, &mut field_server_modified, "server_modified")?,
+                "rev" => crate::deserialize_field(&mut map, &mut field_rev, "rev")?,
+                "size" => crate::deserialize_field(&mut map, &mut field_size, "size")?,
+                "path_lower" => crate::deserialize_field(&mut map, &mut field_path_lower, "path_lower")?,
+                "path_display" => crate::deserialize_field(&mut map, &mut field_path_display, "path_display")?,
+                "parent_shared_folder_id" => crate::deserialize_field(&mut map, &mut field_parent_shared_folder_id, "parent_shared_folder_id")?,
+                "preview_url" => crate::deserialize_field(&mut map, &mut field_preview_url, "preview_url")?,
+                "media_info" => crate::deserialize_field(&mut map, &mut field_media_info, "media_info")?,
+                "symlink_info" => crate::deserialize_field(&mut map, &mut field_symlink_info, "symlink_info")?,
+                "sharing_info" => crate::deserialize_field(&mut map, &mut field_sharing_info, "sharing_info")?,
+                "is_downloadable" => crate::deserialize_field(&mut map, &mut field_is_downloadable, "is_downloadable")?,
+                "export_info" => crate::deserialize_field(&mut map, &mut field_export_info, "export_info")?,
+                "property_groups" => crate::deserialize_field(&mut map, &mut field_property_groups, "property_groups")?,
+                "has_explicit_shared_members" => crate::deserialize_field(&mut map, &mut field_has_explicit_shared_members, "has_explicit_shared_members")?,
+                "content_hash" => crate::deserialize_field(&mut map, &mut field_content_hash, "content_hash")?,
+                "file_lock_info" => crate::deserialize_field(&mut map, &mut field_file_lock_info, "file_lock_info")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -5301,28 +4973,10 @@ impl FileSharingInfo {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "read_only" => {
-                    if field_read_only.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("read_only"));
-                    }
-                    field_read_only = Some(map.next_value()?);
-                }
-                "parent_shared_folder_id" => {
-                    if field_parent_shared_folder_id.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("parent_shared_folder_id"));
-                    }
-                    field_parent_shared_folder_id = Some(map.next_value()?);
-                }
-                "modified_by" => {
-                    if field_modified_by.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("modified_by"));
-                    }
-                    field_modified_by = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "read_only" => crate::deserialize_field(&mut map, &mut field_read_only, "read_only")?,
+                "parent_shared_folder_id" => crate::deserialize_field(&mut map, &mut field_parent_shared_folder_id, "parent_shared_folder_id")?,
+                "modified_by" => crate::deserialize_field(&mut map, &mut field_modified_by, "modified_by")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -5392,8 +5046,9 @@ pub enum FileStatus {
     Active,
     Deleted,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for FileStatus {
@@ -5414,7 +5069,7 @@ impl<'de> ::serde::de::Deserialize<'de> for FileStatus {
                 let value = match tag {
                     "active" => FileStatus::Active,
                     "deleted" => FileStatus::Deleted,
-                    _ => FileStatus::Other,
+                    _ => FileStatus::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -5444,7 +5099,10 @@ impl ::serde::ser::Serialize for FileStatus {
                 s.serialize_field(".tag", "deleted")?;
                 s.end()
             }
-            FileStatus::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            FileStatus::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -5571,64 +5229,16 @@ impl FolderMetadata {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "name" => {
-                    if field_name.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("name"));
-                    }
-                    field_name = Some(map.next_value()?);
-                }
-                "id" => {
-                    if field_id.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("id"));
-                    }
-                    field_id = Some(map.next_value()?);
-                }
-                "path_lower" => {
-                    if field_path_lower.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("path_lower"));
-                    }
-                    field_path_lower = Some(map.next_value()?);
-                }
-                "path_display" => {
-                    if field_path_display.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("path_display"));
-                    }
-                    field_path_display = Some(map.next_value()?);
-                }
-                "parent_shared_folder_id" => {
-                    if field_parent_shared_folder_id.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("parent_shared_folder_id"));
-                    }
-                    field_parent_shared_folder_id = Some(map.next_value()?);
-                }
-                "preview_url" => {
-                    if field_preview_url.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("preview_url"));
-                    }
-                    field_preview_url = Some(map.next_value()?);
-                }
-                "shared_folder_id" => {
-                    if field_shared_folder_id.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("shared_folder_id"));
-                    }
-                    field_shared_folder_id = Some(map.next_value()?);
-                }
-                "sharing_info" => {
-                    if field_sharing_info.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("sharing_info"));
-                    }
-                    field_sharing_info = Some(map.next_value()?);
-                }
-                "property_groups" => {
-                    if field_property_groups.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("property_groups"));
-                    }
-                    field_property_groups = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "name" => crate::deserialize_field(&mut map, &mut field_name, "name")?,
+                "id" => crate::deserialize_field(&mut map, &mut field_id, "id")?,
+                "path_lower" => crate::deserialize_field(&mut map, &mut field_path_lower, "path_lower")?,
+                "path_display" => crate::deserialize_field(&mut map, &mut field_path_display, "path_display")?,
+                "parent_shared_folder_id" => crate::deserialize_field(&mut map, &mut field_parent_shared_folder_id, "parent_shared_folder_id")?,
+                "preview_url" => crate::deserialize_field(&mut map, &mut field_preview_url, "preview_url")?,
+                "shared_folder_id" => crate::deserialize_field(&mut map, &mut field_shared_folder_id, "shared_folder_id")?,
+                "sharing_info" => crate::deserialize_field(&mut map, &mut field_sharing_info, "sharing_info")?,
+                "property_groups" => crate::deserialize_field(&mut map, &mut field_property_groups, "property_groups")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -5794,40 +5404,12 @@ impl FolderSharingInfo {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "read_only" => {
-                    if field_read_only.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("read_only"));
-                    }
-                    field_read_only = Some(map.next_value()?);
-                }
-                "parent_shared_folder_id" => {
-                    if field_parent_shared_folder_id.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("parent_shared_folder_id"));
-                    }
-                    field_parent_shared_folder_id = Some(map.next_value()?);
-                }
-                "shared_folder_id" => {
-                    if field_shared_folder_id.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("shared_folder_id"));
-                    }
-                    field_shared_folder_id = Some(map.next_value()?);
-                }
-                "traverse_only" => {
-                    if field_traverse_only.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("traverse_only"));
-                    }
-                    field_traverse_only = Some(map.next_value()?);
-                }
-                "no_access" => {
-                    if field_no_access.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("no_access"));
-                    }
-                    field_no_access = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "read_only" => crate::deserialize_field(&mut map, &mut field_read_only, "read_only")?,
+                "parent_shared_folder_id" => crate::deserialize_field(&mut map, &mut field_parent_shared_folder_id, "parent_shared_folder_id")?,
+                "shared_folder_id" => crate::deserialize_field(&mut map, &mut field_shared_folder_id, "shared_folder_id")?,
+                "traverse_only" => crate::deserialize_field(&mut map, &mut field_traverse_only, "traverse_only")?,
+                "no_access" => crate::deserialize_field(&mut map, &mut field_no_access, "no_access")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -5933,16 +5515,8 @@ impl GetCopyReferenceArg {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "path" => {
-                    if field_path.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("path"));
-                    }
-                    field_path = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "path" => crate::deserialize_field(&mut map, &mut field_path, "path")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -5997,8 +5571,9 @@ impl ::serde::ser::Serialize for GetCopyReferenceArg {
 pub enum GetCopyReferenceError {
     Path(LookupError),
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for GetCopyReferenceError {
@@ -6024,7 +5599,7 @@ impl<'de> ::serde::de::Deserialize<'de> for GetCopyReferenceError {
                             _ => return Err(de::Error::unknown_field(tag, VARIANTS))
                         }
                     }
-                    _ => GetCopyReferenceError::Other,
+                    _ => GetCopyReferenceError::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -6048,7 +5623,10 @@ impl ::serde::ser::Serialize for GetCopyReferenceError {
                 s.serialize_field("path", x)?;
                 s.end()
             }
-            GetCopyReferenceError::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            GetCopyReferenceError::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -6065,7 +5643,7 @@ impl ::std::error::Error for GetCopyReferenceError {
 impl ::std::fmt::Display for GetCopyReferenceError {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         match self {
-            GetCopyReferenceError::Path(inner) => write!(f, "{}", inner),
+            GetCopyReferenceError::Path(inner) => write!(f, "path: {}", inner),
             _ => write!(f, "{:?}", *self),
         }
     }
@@ -6118,28 +5696,10 @@ impl GetCopyReferenceResult {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "metadata" => {
-                    if field_metadata.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("metadata"));
-                    }
-                    field_metadata = Some(map.next_value()?);
-                }
-                "copy_reference" => {
-                    if field_copy_reference.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("copy_reference"));
-                    }
-                    field_copy_reference = Some(map.next_value()?);
-                }
-                "expires" => {
-                    if field_expires.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("expires"));
-                    }
-                    field_expires = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "metadata" => crate::deserialize_field(&mut map, &mut field_metadata, "metadata")?,
+                "copy_reference" => crate::deserialize_field(&mut map, &mut field_copy_reference, "copy_reference")?,
+                "expires" => crate::deserialize_field(&mut map, &mut field_expires, "expires")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -6271,40 +5831,12 @@ impl GetMetadataArg {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "path" => {
-                    if field_path.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("path"));
-                    }
-                    field_path = Some(map.next_value()?);
-                }
-                "include_media_info" => {
-                    if field_include_media_info.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("include_media_info"));
-                    }
-                    field_include_media_info = Some(map.next_value()?);
-                }
-                "include_deleted" => {
-                    if field_include_deleted.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("include_deleted"));
-                    }
-                    field_include_deleted = Some(map.next_value()?);
-                }
-                "include_has_explicit_shared_members" => {
-                    if field_include_has_explicit_shared_members.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("include_has_explicit_shared_members"));
-                    }
-                    field_include_has_explicit_shared_members = Some(map.next_value()?);
-                }
-                "include_property_groups" => {
-                    if field_include_property_groups.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("include_property_groups"));
-                    }
-                    field_include_property_groups = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "path" => crate::deserialize_field(&mut map, &mut field_path, "path")?,
+                "include_media_info" => crate::deserialize_field(&mut map, &mut field_include_media_info, "include_media_info")?,
+                "include_deleted" => crate::deserialize_field(&mut map, &mut field_include_deleted, "include_deleted")?,
+                "include_has_explicit_shared_members" => crate::deserialize_field(&mut map, &mut field_include_has_explicit_shared_members, "include_has_explicit_shared_members")?,
+                "include_property_groups" => crate::deserialize_field(&mut map, &mut field_include_property_groups, "include_property_groups")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -6436,7 +5968,7 @@ impl ::std::error::Error for GetMetadataError {
 impl ::std::fmt::Display for GetMetadataError {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         match self {
-            GetMetadataError::Path(inner) => write!(f, "{}", inner),
+            GetMetadataError::Path(inner) => write!(f, "path: {}", inner),
         }
     }
 }
@@ -6473,16 +6005,8 @@ impl GetTagsArg {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "paths" => {
-                    if field_paths.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("paths"));
-                    }
-                    field_paths = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "paths" => crate::deserialize_field(&mut map, &mut field_paths, "paths")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -6564,16 +6088,8 @@ impl GetTagsResult {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "paths_to_tags" => {
-                    if field_paths_to_tags.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("paths_to_tags"));
-                    }
-                    field_paths_to_tags = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "paths_to_tags" => crate::deserialize_field(&mut map, &mut field_paths_to_tags, "paths_to_tags")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -6655,16 +6171,8 @@ impl GetTemporaryLinkArg {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "path" => {
-                    if field_path.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("path"));
-                    }
-                    field_path = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "path" => crate::deserialize_field(&mut map, &mut field_path, "path")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -6729,8 +6237,9 @@ pub enum GetTemporaryLinkError {
     /// [banned](https://help.dropbox.com/files-folders/share/banned-links).
     NotAllowed,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for GetTemporaryLinkError {
@@ -6759,7 +6268,7 @@ impl<'de> ::serde::de::Deserialize<'de> for GetTemporaryLinkError {
                     "email_not_verified" => GetTemporaryLinkError::EmailNotVerified,
                     "unsupported_file" => GetTemporaryLinkError::UnsupportedFile,
                     "not_allowed" => GetTemporaryLinkError::NotAllowed,
-                    _ => GetTemporaryLinkError::Other,
+                    _ => GetTemporaryLinkError::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -6804,7 +6313,10 @@ impl ::serde::ser::Serialize for GetTemporaryLinkError {
                 s.serialize_field(".tag", "not_allowed")?;
                 s.end()
             }
-            GetTemporaryLinkError::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            GetTemporaryLinkError::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -6821,7 +6333,7 @@ impl ::std::error::Error for GetTemporaryLinkError {
 impl ::std::fmt::Display for GetTemporaryLinkError {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         match self {
-            GetTemporaryLinkError::Path(inner) => write!(f, "{}", inner),
+            GetTemporaryLinkError::Path(inner) => write!(f, "path: {}", inner),
             _ => write!(f, "{:?}", *self),
         }
     }
@@ -6864,22 +6376,9 @@ impl GetTemporaryLinkResult {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "metadata" => {
-                    if field_metadata.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("metadata"));
-                    }
-                    field_metadata = Some(map.next_value()?);
-                }
-                "link" => {
-                    if field_link.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("link"));
-                    }
-                    field_link = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "metadata" => crate::deserialize_field(&mut map, &mut field_metadata, "metadata")?,
+                "link" => crate::deserialize_field(&mut map, &mut field_link, "link")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -6975,22 +6474,9 @@ impl GetTemporaryUploadLinkArg {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "commit_info" => {
-                    if field_commit_info.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("commit_info"));
-                    }
-                    field_commit_info = Some(map.next_value()?);
-                }
-                "duration" => {
-                    if field_duration.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("duration"));
-                    }
-                    field_duration = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "commit_info" => crate::deserialize_field(&mut map, &mut field_commit_info, "commit_info")?,
+                "duration" => crate::deserialize_field(&mut map, &mut field_duration, "duration")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -7076,16 +6562,8 @@ impl GetTemporaryUploadLinkResult {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "link" => {
-                    if field_link.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("link"));
-                    }
-                    field_link = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "link" => crate::deserialize_field(&mut map, &mut field_link, "link")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -7168,16 +6646,8 @@ impl GetThumbnailBatchArg {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "entries" => {
-                    if field_entries.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("entries"));
-                    }
-                    field_entries = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "entries" => crate::deserialize_field(&mut map, &mut field_entries, "entries")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -7233,8 +6703,9 @@ pub enum GetThumbnailBatchError {
     /// The operation involves more than 25 files.
     TooManyFiles,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for GetThumbnailBatchError {
@@ -7254,7 +6725,7 @@ impl<'de> ::serde::de::Deserialize<'de> for GetThumbnailBatchError {
                 };
                 let value = match tag {
                     "too_many_files" => GetThumbnailBatchError::TooManyFiles,
-                    _ => GetThumbnailBatchError::Other,
+                    _ => GetThumbnailBatchError::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -7277,7 +6748,10 @@ impl ::serde::ser::Serialize for GetThumbnailBatchError {
                 s.serialize_field(".tag", "too_many_files")?;
                 s.end()
             }
-            GetThumbnailBatchError::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            GetThumbnailBatchError::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -7326,16 +6800,8 @@ impl GetThumbnailBatchResult {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "entries" => {
-                    if field_entries.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("entries"));
-                    }
-                    field_entries = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "entries" => crate::deserialize_field(&mut map, &mut field_entries, "entries")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -7421,22 +6887,9 @@ impl GetThumbnailBatchResultData {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "metadata" => {
-                    if field_metadata.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("metadata"));
-                    }
-                    field_metadata = Some(map.next_value()?);
-                }
-                "thumbnail" => {
-                    if field_thumbnail.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("thumbnail"));
-                    }
-                    field_thumbnail = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "metadata" => crate::deserialize_field(&mut map, &mut field_metadata, "metadata")?,
+                "thumbnail" => crate::deserialize_field(&mut map, &mut field_thumbnail, "thumbnail")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -7495,8 +6948,9 @@ pub enum GetThumbnailBatchResultEntry {
     /// The result for this file if it was an error.
     Failure(ThumbnailError),
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for GetThumbnailBatchResultEntry {
@@ -7523,7 +6977,7 @@ impl<'de> ::serde::de::Deserialize<'de> for GetThumbnailBatchResultEntry {
                             _ => return Err(de::Error::unknown_field(tag, VARIANTS))
                         }
                     }
-                    _ => GetThumbnailBatchResultEntry::Other,
+                    _ => GetThumbnailBatchResultEntry::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -7555,7 +7009,10 @@ impl ::serde::ser::Serialize for GetThumbnailBatchResultEntry {
                 s.serialize_field("failure", x)?;
                 s.end()
             }
-            GetThumbnailBatchResultEntry::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            GetThumbnailBatchResultEntry::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -7598,22 +7055,9 @@ impl GpsCoordinates {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "latitude" => {
-                    if field_latitude.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("latitude"));
-                    }
-                    field_latitude = Some(map.next_value()?);
-                }
-                "longitude" => {
-                    if field_longitude.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("longitude"));
-                    }
-                    field_longitude = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "latitude" => crate::deserialize_field(&mut map, &mut field_latitude, "latitude")?,
+                "longitude" => crate::deserialize_field(&mut map, &mut field_longitude, "longitude")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -7702,22 +7146,9 @@ impl HighlightSpan {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "highlight_str" => {
-                    if field_highlight_str.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("highlight_str"));
-                    }
-                    field_highlight_str = Some(map.next_value()?);
-                }
-                "is_highlighted" => {
-                    if field_is_highlighted.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("is_highlighted"));
-                    }
-                    field_is_highlighted = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "highlight_str" => crate::deserialize_field(&mut map, &mut field_highlight_str, "highlight_str")?,
+                "is_highlighted" => crate::deserialize_field(&mut map, &mut field_is_highlighted, "is_highlighted")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -7780,8 +7211,9 @@ pub enum ImportFormat {
     /// The provided data is interpreted as plain text.
     PlainText,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for ImportFormat {
@@ -7803,7 +7235,7 @@ impl<'de> ::serde::de::Deserialize<'de> for ImportFormat {
                     "html" => ImportFormat::Html,
                     "markdown" => ImportFormat::Markdown,
                     "plain_text" => ImportFormat::PlainText,
-                    _ => ImportFormat::Other,
+                    _ => ImportFormat::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -7840,7 +7272,10 @@ impl ::serde::ser::Serialize for ImportFormat {
                 s.serialize_field(".tag", "plain_text")?;
                 s.end()
             }
-            ImportFormat::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            ImportFormat::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -7979,70 +7414,17 @@ impl ListFolderArg {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "path" => {
-                    if field_path.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("path"));
-                    }
-                    field_path = Some(map.next_value()?);
-                }
-                "recursive" => {
-                    if field_recursive.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("recursive"));
-                    }
-                    field_recursive = Some(map.next_value()?);
-                }
-                "include_media_info" => {
-                    if field_include_media_info.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("include_media_info"));
-                    }
-                    field_include_media_info = Some(map.next_value()?);
-                }
-                "include_deleted" => {
-                    if field_include_deleted.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("include_deleted"));
-                    }
-                    field_include_deleted = Some(map.next_value()?);
-                }
-                "include_has_explicit_shared_members" => {
-                    if field_include_has_explicit_shared_members.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("include_has_explicit_shared_members"));
-                    }
-                    field_include_has_explicit_shared_members = Some(map.next_value()?);
-                }
-                "include_mounted_folders" => {
-                    if field_include_mounted_folders.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("include_mounted_folders"));
-                    }
-                    field_include_mounted_folders = Some(map.next_value()?);
-                }
-                "limit" => {
-                    if field_limit.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("limit"));
-                    }
-                    field_limit = Some(map.next_value()?);
-                }
-                "shared_link" => {
-                    if field_shared_link.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("shared_link"));
-                    }
-                    field_shared_link = Some(map.next_value()?);
-                }
-                "include_property_groups" => {
-                    if field_include_property_groups.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("include_property_groups"));
-                    }
-                    field_include_property_groups = Some(map.next_value()?);
-                }
-                "include_non_downloadable_files" => {
-                    if field_include_non_downloadable_files.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("include_non_downloadable_files"));
-                    }
-                    field_include_non_downloadable_files = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "path" => crate::deserialize_field(&mut map, &mut field_path, "path")?,
+                "recursive" => crate::deserialize_field(&mut map, &mut field_recursive, "recursive")?,
+                "include_media_info" => crate::deserialize_field(&mut map, &mut field_include_media_info, "include_media_info")?,
+                "include_deleted" => crate::deserialize_field(&mut map, &mut field_include_deleted, "include_deleted")?,
+                "include_has_explicit_shared_members" => crate::deserialize_field(&mut map, &mut field_include_has_explicit_shared_members, "include_has_explicit_shared_members")?,
+                "include_mounted_folders" => crate::deserialize_field(&mut map, &mut field_include_mounted_folders, "include_mounted_folders")?,
+                "limit" => crate::deserialize_field(&mut map, &mut field_limit, "limit")?,
+                "shared_link" => crate::deserialize_field(&mut map, &mut field_shared_link, "shared_link")?,
+                "include_property_groups" => crate::deserialize_field(&mut map, &mut field_include_property_groups, "include_property_groups")?,
+                "include_non_downloadable_files" => crate::deserialize_field(&mut map, &mut field_include_non_downloadable_files, "include_non_downloadable_files")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -8161,16 +7543,8 @@ impl ListFolderContinueArg {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "cursor" => {
-                    if field_cursor.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("cursor"));
-                    }
-                    field_cursor = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "cursor" => crate::deserialize_field(&mut map, &mut field_cursor, "cursor")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -8228,8 +7602,9 @@ pub enum ListFolderContinueError {
     /// [`list_folder()`](crate::files::list_folder) to obtain a new cursor.
     Reset,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for ListFolderContinueError {
@@ -8256,7 +7631,7 @@ impl<'de> ::serde::de::Deserialize<'de> for ListFolderContinueError {
                         }
                     }
                     "reset" => ListFolderContinueError::Reset,
-                    _ => ListFolderContinueError::Other,
+                    _ => ListFolderContinueError::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -8287,7 +7662,10 @@ impl ::serde::ser::Serialize for ListFolderContinueError {
                 s.serialize_field(".tag", "reset")?;
                 s.end()
             }
-            ListFolderContinueError::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            ListFolderContinueError::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -8304,7 +7682,7 @@ impl ::std::error::Error for ListFolderContinueError {
 impl ::std::fmt::Display for ListFolderContinueError {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         match self {
-            ListFolderContinueError::Path(inner) => write!(f, "{}", inner),
+            ListFolderContinueError::Path(inner) => write!(f, "path: {}", inner),
             _ => write!(f, "{:?}", *self),
         }
     }
@@ -8316,8 +7694,9 @@ pub enum ListFolderError {
     Path(LookupError),
     TemplateError(crate::types::file_properties::TemplateError),
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for ListFolderError {
@@ -8350,7 +7729,7 @@ impl<'de> ::serde::de::Deserialize<'de> for ListFolderError {
                             _ => return Err(de::Error::unknown_field(tag, VARIANTS))
                         }
                     }
-                    _ => ListFolderError::Other,
+                    _ => ListFolderError::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -8382,7 +7761,10 @@ impl ::serde::ser::Serialize for ListFolderError {
                 s.serialize_field("template_error", x)?;
                 s.end()
             }
-            ListFolderError::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            ListFolderError::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -8400,8 +7782,8 @@ impl ::std::error::Error for ListFolderError {
 impl ::std::fmt::Display for ListFolderError {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         match self {
-            ListFolderError::Path(inner) => write!(f, "{}", inner),
-            ListFolderError::TemplateError(inner) => write!(f, "{}", inner),
+            ListFolderError::Path(inner) => write!(f, "path: {}", inner),
+            ListFolderError::TemplateError(inner) => write!(f, "template_error: {}", inner),
             _ => write!(f, "{:?}", *self),
         }
     }
@@ -8440,16 +7822,8 @@ impl ListFolderGetLatestCursorResult {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "cursor" => {
-                    if field_cursor.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("cursor"));
-                    }
-                    field_cursor = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "cursor" => crate::deserialize_field(&mut map, &mut field_cursor, "cursor")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -8545,22 +7919,9 @@ impl ListFolderLongpollArg {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "cursor" => {
-                    if field_cursor.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("cursor"));
-                    }
-                    field_cursor = Some(map.next_value()?);
-                }
-                "timeout" => {
-                    if field_timeout.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("timeout"));
-                    }
-                    field_timeout = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "cursor" => crate::deserialize_field(&mut map, &mut field_cursor, "cursor")?,
+                "timeout" => crate::deserialize_field(&mut map, &mut field_timeout, "timeout")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -8621,8 +7982,9 @@ pub enum ListFolderLongpollError {
     /// [`list_folder()`](crate::files::list_folder) to obtain a new cursor.
     Reset,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for ListFolderLongpollError {
@@ -8642,7 +8004,7 @@ impl<'de> ::serde::de::Deserialize<'de> for ListFolderLongpollError {
                 };
                 let value = match tag {
                     "reset" => ListFolderLongpollError::Reset,
-                    _ => ListFolderLongpollError::Other,
+                    _ => ListFolderLongpollError::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -8665,7 +8027,10 @@ impl ::serde::ser::Serialize for ListFolderLongpollError {
                 s.serialize_field(".tag", "reset")?;
                 s.end()
             }
-            ListFolderLongpollError::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            ListFolderLongpollError::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -8723,22 +8088,9 @@ impl ListFolderLongpollResult {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "changes" => {
-                    if field_changes.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("changes"));
-                    }
-                    field_changes = Some(map.next_value()?);
-                }
-                "backoff" => {
-                    if field_backoff.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("backoff"));
-                    }
-                    field_backoff = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "changes" => crate::deserialize_field(&mut map, &mut field_changes, "changes")?,
+                "backoff" => crate::deserialize_field(&mut map, &mut field_backoff, "backoff")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -8836,28 +8188,10 @@ impl ListFolderResult {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "entries" => {
-                    if field_entries.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("entries"));
-                    }
-                    field_entries = Some(map.next_value()?);
-                }
-                "cursor" => {
-                    if field_cursor.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("cursor"));
-                    }
-                    field_cursor = Some(map.next_value()?);
-                }
-                "has_more" => {
-                    if field_has_more.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("has_more"));
-                    }
-                    field_has_more = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "entries" => crate::deserialize_field(&mut map, &mut field_entries, "entries")?,
+                "cursor" => crate::deserialize_field(&mut map, &mut field_cursor, "cursor")?,
+                "has_more" => crate::deserialize_field(&mut map, &mut field_has_more, "has_more")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -8963,28 +8297,10 @@ impl ListRevisionsArg {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "path" => {
-                    if field_path.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("path"));
-                    }
-                    field_path = Some(map.next_value()?);
-                }
-                "mode" => {
-                    if field_mode.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("mode"));
-                    }
-                    field_mode = Some(map.next_value()?);
-                }
-                "limit" => {
-                    if field_limit.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("limit"));
-                    }
-                    field_limit = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "path" => crate::deserialize_field(&mut map, &mut field_path, "path")?,
+                "mode" => crate::deserialize_field(&mut map, &mut field_mode, "mode")?,
+                "limit" => crate::deserialize_field(&mut map, &mut field_limit, "limit")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -9047,8 +8363,9 @@ impl ::serde::ser::Serialize for ListRevisionsArg {
 pub enum ListRevisionsError {
     Path(LookupError),
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for ListRevisionsError {
@@ -9074,7 +8391,7 @@ impl<'de> ::serde::de::Deserialize<'de> for ListRevisionsError {
                             _ => return Err(de::Error::unknown_field(tag, VARIANTS))
                         }
                     }
-                    _ => ListRevisionsError::Other,
+                    _ => ListRevisionsError::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -9098,7 +8415,10 @@ impl ::serde::ser::Serialize for ListRevisionsError {
                 s.serialize_field("path", x)?;
                 s.end()
             }
-            ListRevisionsError::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            ListRevisionsError::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -9115,7 +8435,7 @@ impl ::std::error::Error for ListRevisionsError {
 impl ::std::fmt::Display for ListRevisionsError {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         match self {
-            ListRevisionsError::Path(inner) => write!(f, "{}", inner),
+            ListRevisionsError::Path(inner) => write!(f, "path: {}", inner),
             _ => write!(f, "{:?}", *self),
         }
     }
@@ -9131,8 +8451,9 @@ pub enum ListRevisionsMode {
     /// file path or id.
     Id,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for ListRevisionsMode {
@@ -9153,7 +8474,7 @@ impl<'de> ::serde::de::Deserialize<'de> for ListRevisionsMode {
                 let value = match tag {
                     "path" => ListRevisionsMode::Path,
                     "id" => ListRevisionsMode::Id,
-                    _ => ListRevisionsMode::Other,
+                    _ => ListRevisionsMode::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -9183,7 +8504,10 @@ impl ::serde::ser::Serialize for ListRevisionsMode {
                 s.serialize_field(".tag", "id")?;
                 s.end()
             }
-            ListRevisionsMode::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            ListRevisionsMode::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -9235,28 +8559,10 @@ impl ListRevisionsResult {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "is_deleted" => {
-                    if field_is_deleted.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("is_deleted"));
-                    }
-                    field_is_deleted = Some(map.next_value()?);
-                }
-                "entries" => {
-                    if field_entries.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("entries"));
-                    }
-                    field_entries = Some(map.next_value()?);
-                }
-                "server_deleted" => {
-                    if field_server_deleted.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("server_deleted"));
-                    }
-                    field_server_deleted = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "is_deleted" => crate::deserialize_field(&mut map, &mut field_is_deleted, "is_deleted")?,
+                "entries" => crate::deserialize_field(&mut map, &mut field_entries, "entries")?,
+                "server_deleted" => crate::deserialize_field(&mut map, &mut field_server_deleted, "server_deleted")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -9344,16 +8650,8 @@ impl LockConflictError {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "lock" => {
-                    if field_lock.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("lock"));
-                    }
-                    field_lock = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "lock" => crate::deserialize_field(&mut map, &mut field_lock, "lock")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -9435,16 +8733,8 @@ impl LockFileArg {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "path" => {
-                    if field_path.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("path"));
-                    }
-                    field_path = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "path" => crate::deserialize_field(&mut map, &mut field_path, "path")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -9527,16 +8817,8 @@ impl LockFileBatchArg {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "entries" => {
-                    if field_entries.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("entries"));
-                    }
-                    field_entries = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "entries" => crate::deserialize_field(&mut map, &mut field_entries, "entries")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -9619,16 +8901,8 @@ impl LockFileBatchResult {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "entries" => {
-                    if field_entries.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("entries"));
-                    }
-                    field_entries = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "entries" => crate::deserialize_field(&mut map, &mut field_entries, "entries")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -9705,8 +8979,9 @@ pub enum LockFileError {
     /// you were taking succeeded, and if not, try again. This should happen very rarely.
     InternalError,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for LockFileError {
@@ -9739,7 +9014,7 @@ impl<'de> ::serde::de::Deserialize<'de> for LockFileError {
                     "file_not_shared" => LockFileError::FileNotShared,
                     "lock_conflict" => LockFileError::LockConflict(LockConflictError::internal_deserialize(&mut map)?),
                     "internal_error" => LockFileError::InternalError,
-                    _ => LockFileError::Other,
+                    _ => LockFileError::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -9813,7 +9088,10 @@ impl ::serde::ser::Serialize for LockFileError {
                 s.serialize_field(".tag", "internal_error")?;
                 s.end()
             }
-            LockFileError::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            LockFileError::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -9880,22 +9158,9 @@ impl LockFileResult {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "metadata" => {
-                    if field_metadata.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("metadata"));
-                    }
-                    field_metadata = Some(map.next_value()?);
-                }
-                "lock" => {
-                    if field_lock.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("lock"));
-                    }
-                    field_lock = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "metadata" => crate::deserialize_field(&mut map, &mut field_metadata, "metadata")?,
+                "lock" => crate::deserialize_field(&mut map, &mut field_lock, "lock")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -10033,8 +9298,9 @@ pub enum LookupError {
     /// The given path is locked.
     Locked,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for LookupError {
@@ -10066,7 +9332,7 @@ impl<'de> ::serde::de::Deserialize<'de> for LookupError {
                     "restricted_content" => LookupError::RestrictedContent,
                     "unsupported_content_type" => LookupError::UnsupportedContentType,
                     "locked" => LookupError::Locked,
-                    _ => LookupError::Other,
+                    _ => LookupError::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -10135,7 +9401,10 @@ impl ::serde::ser::Serialize for LookupError {
                 s.serialize_field(".tag", "locked")?;
                 s.end()
             }
-            LookupError::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            LookupError::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -10351,8 +9620,9 @@ impl ::serde::ser::Serialize for Metadata {
 pub enum MetadataV2 {
     Metadata(Metadata),
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for MetadataV2 {
@@ -10378,7 +9648,7 @@ impl<'de> ::serde::de::Deserialize<'de> for MetadataV2 {
                             _ => return Err(de::Error::unknown_field(tag, VARIANTS))
                         }
                     }
-                    _ => MetadataV2::Other,
+                    _ => MetadataV2::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -10402,7 +9672,10 @@ impl ::serde::ser::Serialize for MetadataV2 {
                 s.serialize_field("metadata", x)?;
                 s.end()
             }
-            MetadataV2::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            MetadataV2::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -10466,34 +9739,11 @@ impl MinimalFileLinkMetadata {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "url" => {
-                    if field_url.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("url"));
-                    }
-                    field_url = Some(map.next_value()?);
-                }
-                "rev" => {
-                    if field_rev.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("rev"));
-                    }
-                    field_rev = Some(map.next_value()?);
-                }
-                "id" => {
-                    if field_id.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("id"));
-                    }
-                    field_id = Some(map.next_value()?);
-                }
-                "path" => {
-                    if field_path.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("path"));
-                    }
-                    field_path = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "url" => crate::deserialize_field(&mut map, &mut field_url, "url")?,
+                "rev" => crate::deserialize_field(&mut map, &mut field_rev, "rev")?,
+                "id" => crate::deserialize_field(&mut map, &mut field_id, "id")?,
+                "path" => crate::deserialize_field(&mut map, &mut field_path, "path")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -10607,28 +9857,10 @@ impl MoveBatchArg {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "entries" => {
-                    if field_entries.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("entries"));
-                    }
-                    field_entries = Some(map.next_value()?);
-                }
-                "autorename" => {
-                    if field_autorename.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("autorename"));
-                    }
-                    field_autorename = Some(map.next_value()?);
-                }
-                "allow_ownership_transfer" => {
-                    if field_allow_ownership_transfer.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("allow_ownership_transfer"));
-                    }
-                    field_allow_ownership_transfer = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "entries" => crate::deserialize_field(&mut map, &mut field_entries, "entries")?,
+                "autorename" => crate::deserialize_field(&mut map, &mut field_autorename, "autorename")?,
+                "allow_ownership_transfer" => crate::deserialize_field(&mut map, &mut field_allow_ownership_transfer, "allow_ownership_transfer")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -10701,8 +9933,9 @@ pub enum MoveIntoFamilyError {
     /// Moving shared folder into Family Room folder is not allowed.
     IsSharedFolder,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for MoveIntoFamilyError {
@@ -10722,7 +9955,7 @@ impl<'de> ::serde::de::Deserialize<'de> for MoveIntoFamilyError {
                 };
                 let value = match tag {
                     "is_shared_folder" => MoveIntoFamilyError::IsSharedFolder,
-                    _ => MoveIntoFamilyError::Other,
+                    _ => MoveIntoFamilyError::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -10745,7 +9978,10 @@ impl ::serde::ser::Serialize for MoveIntoFamilyError {
                 s.serialize_field(".tag", "is_shared_folder")?;
                 s.end()
             }
-            MoveIntoFamilyError::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            MoveIntoFamilyError::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -10768,8 +10004,9 @@ pub enum MoveIntoVaultError {
     /// Moving shared folder into Vault is not allowed.
     IsSharedFolder,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for MoveIntoVaultError {
@@ -10789,7 +10026,7 @@ impl<'de> ::serde::de::Deserialize<'de> for MoveIntoVaultError {
                 };
                 let value = match tag {
                     "is_shared_folder" => MoveIntoVaultError::IsSharedFolder,
-                    _ => MoveIntoVaultError::Other,
+                    _ => MoveIntoVaultError::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -10812,7 +10049,10 @@ impl ::serde::ser::Serialize for MoveIntoVaultError {
                 s.serialize_field(".tag", "is_shared_folder")?;
                 s.end()
             }
-            MoveIntoVaultError::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            MoveIntoVaultError::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -10842,8 +10082,9 @@ pub enum PaperContentError {
     /// only applies to HTML with data URI.
     ImageSizeExceeded,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for PaperContentError {
@@ -10866,7 +10107,7 @@ impl<'de> ::serde::de::Deserialize<'de> for PaperContentError {
                     "content_malformed" => PaperContentError::ContentMalformed,
                     "doc_length_exceeded" => PaperContentError::DocLengthExceeded,
                     "image_size_exceeded" => PaperContentError::ImageSizeExceeded,
-                    _ => PaperContentError::Other,
+                    _ => PaperContentError::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -10910,7 +10151,10 @@ impl ::serde::ser::Serialize for PaperContentError {
                 s.serialize_field(".tag", "image_size_exceeded")?;
                 s.end()
             }
-            PaperContentError::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            PaperContentError::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -10968,22 +10212,9 @@ impl PaperCreateArg {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "path" => {
-                    if field_path.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("path"));
-                    }
-                    field_path = Some(map.next_value()?);
-                }
-                "import_format" => {
-                    if field_import_format.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("import_format"));
-                    }
-                    field_import_format = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "path" => crate::deserialize_field(&mut map, &mut field_path, "path")?,
+                "import_format" => crate::deserialize_field(&mut map, &mut field_import_format, "import_format")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -11056,8 +10287,9 @@ pub enum PaperCreateError {
     /// Paper is disabled for your team.
     PaperDisabled,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for PaperCreateError {
@@ -11084,7 +10316,7 @@ impl<'de> ::serde::de::Deserialize<'de> for PaperCreateError {
                     "email_unverified" => PaperCreateError::EmailUnverified,
                     "invalid_file_extension" => PaperCreateError::InvalidFileExtension,
                     "paper_disabled" => PaperCreateError::PaperDisabled,
-                    _ => PaperCreateError::Other,
+                    _ => PaperCreateError::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -11156,7 +10388,10 @@ impl ::serde::ser::Serialize for PaperCreateError {
                 s.serialize_field(".tag", "paper_disabled")?;
                 s.end()
             }
-            PaperCreateError::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            PaperCreateError::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -11188,7 +10423,7 @@ impl From<PaperContentError> for PaperCreateError {
             PaperContentError::ContentMalformed => PaperCreateError::ContentMalformed,
             PaperContentError::DocLengthExceeded => PaperCreateError::DocLengthExceeded,
             PaperContentError::ImageSizeExceeded => PaperCreateError::ImageSizeExceeded,
-            PaperContentError::Other => PaperCreateError::Other,
+            PaperContentError::Other(x) => PaperCreateError::Other(x),
         }
     }
 }
@@ -11239,34 +10474,11 @@ impl PaperCreateResult {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "url" => {
-                    if field_url.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("url"));
-                    }
-                    field_url = Some(map.next_value()?);
-                }
-                "result_path" => {
-                    if field_result_path.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("result_path"));
-                    }
-                    field_result_path = Some(map.next_value()?);
-                }
-                "file_id" => {
-                    if field_file_id.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("file_id"));
-                    }
-                    field_file_id = Some(map.next_value()?);
-                }
-                "paper_revision" => {
-                    if field_paper_revision.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("paper_revision"));
-                    }
-                    field_paper_revision = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "url" => crate::deserialize_field(&mut map, &mut field_url, "url")?,
+                "result_path" => crate::deserialize_field(&mut map, &mut field_result_path, "result_path")?,
+                "file_id" => crate::deserialize_field(&mut map, &mut field_file_id, "file_id")?,
+                "paper_revision" => crate::deserialize_field(&mut map, &mut field_paper_revision, "paper_revision")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -11335,8 +10547,9 @@ pub enum PaperDocUpdatePolicy {
     /// Adds the provided content to the end of the doc without checking paper_revision.
     Append,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for PaperDocUpdatePolicy {
@@ -11359,7 +10572,7 @@ impl<'de> ::serde::de::Deserialize<'de> for PaperDocUpdatePolicy {
                     "overwrite" => PaperDocUpdatePolicy::Overwrite,
                     "prepend" => PaperDocUpdatePolicy::Prepend,
                     "append" => PaperDocUpdatePolicy::Append,
-                    _ => PaperDocUpdatePolicy::Other,
+                    _ => PaperDocUpdatePolicy::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -11403,7 +10616,10 @@ impl ::serde::ser::Serialize for PaperDocUpdatePolicy {
                 s.serialize_field(".tag", "append")?;
                 s.end()
             }
-            PaperDocUpdatePolicy::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            PaperDocUpdatePolicy::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -11466,34 +10682,11 @@ impl PaperUpdateArg {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "path" => {
-                    if field_path.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("path"));
-                    }
-                    field_path = Some(map.next_value()?);
-                }
-                "import_format" => {
-                    if field_import_format.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("import_format"));
-                    }
-                    field_import_format = Some(map.next_value()?);
-                }
-                "doc_update_policy" => {
-                    if field_doc_update_policy.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("doc_update_policy"));
-                    }
-                    field_doc_update_policy = Some(map.next_value()?);
-                }
-                "paper_revision" => {
-                    if field_paper_revision.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("paper_revision"));
-                    }
-                    field_paper_revision = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "path" => crate::deserialize_field(&mut map, &mut field_path, "path")?,
+                "import_format" => crate::deserialize_field(&mut map, &mut field_import_format, "import_format")?,
+                "doc_update_policy" => crate::deserialize_field(&mut map, &mut field_doc_update_policy, "doc_update_policy")?,
+                "paper_revision" => crate::deserialize_field(&mut map, &mut field_paper_revision, "paper_revision")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -11571,8 +10764,9 @@ pub enum PaperUpdateError {
     /// This operation is not allowed on deleted Paper docs.
     DocDeleted,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for PaperUpdateError {
@@ -11605,7 +10799,7 @@ impl<'de> ::serde::de::Deserialize<'de> for PaperUpdateError {
                     "revision_mismatch" => PaperUpdateError::RevisionMismatch,
                     "doc_archived" => PaperUpdateError::DocArchived,
                     "doc_deleted" => PaperUpdateError::DocDeleted,
-                    _ => PaperUpdateError::Other,
+                    _ => PaperUpdateError::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -11678,7 +10872,10 @@ impl ::serde::ser::Serialize for PaperUpdateError {
                 s.serialize_field(".tag", "doc_deleted")?;
                 s.end()
             }
-            PaperUpdateError::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            PaperUpdateError::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -11699,7 +10896,7 @@ impl ::std::fmt::Display for PaperUpdateError {
             PaperUpdateError::ContentMalformed => f.write_str("The provided content was malformed and cannot be imported to Paper."),
             PaperUpdateError::DocLengthExceeded => f.write_str("The Paper doc would be too large, split the content into multiple docs."),
             PaperUpdateError::ImageSizeExceeded => f.write_str("The imported document contains an image that is too large. The current limit is 1MB. This only applies to HTML with data URI."),
-            PaperUpdateError::Path(inner) => write!(f, "{}", inner),
+            PaperUpdateError::Path(inner) => write!(f, "path: {}", inner),
             PaperUpdateError::RevisionMismatch => f.write_str("The provided revision does not match the document head."),
             PaperUpdateError::DocArchived => f.write_str("This operation is not allowed on archived Paper docs."),
             PaperUpdateError::DocDeleted => f.write_str("This operation is not allowed on deleted Paper docs."),
@@ -11716,7 +10913,7 @@ impl From<PaperContentError> for PaperUpdateError {
             PaperContentError::ContentMalformed => PaperUpdateError::ContentMalformed,
             PaperContentError::DocLengthExceeded => PaperUpdateError::DocLengthExceeded,
             PaperContentError::ImageSizeExceeded => PaperUpdateError::ImageSizeExceeded,
-            PaperContentError::Other => PaperUpdateError::Other,
+            PaperContentError::Other(x) => PaperUpdateError::Other(x),
         }
     }
 }
@@ -11752,16 +10949,8 @@ impl PaperUpdateResult {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "paper_revision" => {
-                    if field_paper_revision.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("paper_revision"));
-                    }
-                    field_paper_revision = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "paper_revision" => crate::deserialize_field(&mut map, &mut field_paper_revision, "paper_revision")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -11817,8 +11006,9 @@ pub enum PathOrLink {
     Path(ReadPath),
     Link(SharedLinkFileInfo),
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for PathOrLink {
@@ -11845,7 +11035,7 @@ impl<'de> ::serde::de::Deserialize<'de> for PathOrLink {
                         }
                     }
                     "link" => PathOrLink::Link(SharedLinkFileInfo::internal_deserialize(&mut map)?),
-                    _ => PathOrLink::Other,
+                    _ => PathOrLink::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -11877,7 +11067,10 @@ impl ::serde::ser::Serialize for PathOrLink {
                 x.internal_serialize::<S>(&mut s)?;
                 s.end()
             }
-            PathOrLink::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            PathOrLink::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -11919,22 +11112,9 @@ impl PathToTags {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "path" => {
-                    if field_path.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("path"));
-                    }
-                    field_path = Some(map.next_value()?);
-                }
-                "tags" => {
-                    if field_tags.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("tags"));
-                    }
-                    field_tags = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "path" => crate::deserialize_field(&mut map, &mut field_path, "path")?,
+                "tags" => crate::deserialize_field(&mut map, &mut field_tags, "tags")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -12028,28 +11208,10 @@ impl PhotoMetadata {
         let mut field_time_taken = None;
         while let Some(key) = map.next_key::<&str>()? {
             match key {
-                "dimensions" => {
-                    if field_dimensions.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("dimensions"));
-                    }
-                    field_dimensions = Some(map.next_value()?);
-                }
-                "location" => {
-                    if field_location.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("location"));
-                    }
-                    field_location = Some(map.next_value()?);
-                }
-                "time_taken" => {
-                    if field_time_taken.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("time_taken"));
-                    }
-                    field_time_taken = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "dimensions" => crate::deserialize_field(&mut map, &mut field_dimensions, "dimensions")?,
+                "location" => crate::deserialize_field(&mut map, &mut field_location, "location")?,
+                "time_taken" => crate::deserialize_field(&mut map, &mut field_time_taken, "time_taken")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         let result = PhotoMetadata {
@@ -12154,22 +11316,9 @@ impl PreviewArg {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "path" => {
-                    if field_path.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("path"));
-                    }
-                    field_path = Some(map.next_value()?);
-                }
-                "rev" => {
-                    if field_rev.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("rev"));
-                    }
-                    field_rev = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "path" => crate::deserialize_field(&mut map, &mut field_path, "path")?,
+                "rev" => crate::deserialize_field(&mut map, &mut field_rev, "rev")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -12363,22 +11512,9 @@ impl PreviewResult {
         let mut field_link_metadata = None;
         while let Some(key) = map.next_key::<&str>()? {
             match key {
-                "file_metadata" => {
-                    if field_file_metadata.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("file_metadata"));
-                    }
-                    field_file_metadata = Some(map.next_value()?);
-                }
-                "link_metadata" => {
-                    if field_link_metadata.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("link_metadata"));
-                    }
-                    field_link_metadata = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "file_metadata" => crate::deserialize_field(&mut map, &mut field_file_metadata, "file_metadata")?,
+                "link_metadata" => crate::deserialize_field(&mut map, &mut field_link_metadata, "link_metadata")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         let result = PreviewResult {
@@ -12500,40 +11636,12 @@ impl RelocationArg {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "from_path" => {
-                    if field_from_path.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("from_path"));
-                    }
-                    field_from_path = Some(map.next_value()?);
-                }
-                "to_path" => {
-                    if field_to_path.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("to_path"));
-                    }
-                    field_to_path = Some(map.next_value()?);
-                }
-                "allow_shared_folder" => {
-                    if field_allow_shared_folder.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("allow_shared_folder"));
-                    }
-                    field_allow_shared_folder = Some(map.next_value()?);
-                }
-                "autorename" => {
-                    if field_autorename.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("autorename"));
-                    }
-                    field_autorename = Some(map.next_value()?);
-                }
-                "allow_ownership_transfer" => {
-                    if field_allow_ownership_transfer.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("allow_ownership_transfer"));
-                    }
-                    field_allow_ownership_transfer = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "from_path" => crate::deserialize_field(&mut map, &mut field_from_path, "from_path")?,
+                "to_path" => crate::deserialize_field(&mut map, &mut field_to_path, "to_path")?,
+                "allow_shared_folder" => crate::deserialize_field(&mut map, &mut field_allow_shared_folder, "allow_shared_folder")?,
+                "autorename" => crate::deserialize_field(&mut map, &mut field_autorename, "autorename")?,
+                "allow_ownership_transfer" => crate::deserialize_field(&mut map, &mut field_allow_ownership_transfer, "allow_ownership_transfer")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -12670,34 +11778,11 @@ impl RelocationBatchArg {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "entries" => {
-                    if field_entries.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("entries"));
-                    }
-                    field_entries = Some(map.next_value()?);
-                }
-                "autorename" => {
-                    if field_autorename.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("autorename"));
-                    }
-                    field_autorename = Some(map.next_value()?);
-                }
-                "allow_shared_folder" => {
-                    if field_allow_shared_folder.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("allow_shared_folder"));
-                    }
-                    field_allow_shared_folder = Some(map.next_value()?);
-                }
-                "allow_ownership_transfer" => {
-                    if field_allow_ownership_transfer.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("allow_ownership_transfer"));
-                    }
-                    field_allow_ownership_transfer = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "entries" => crate::deserialize_field(&mut map, &mut field_entries, "entries")?,
+                "autorename" => crate::deserialize_field(&mut map, &mut field_autorename, "autorename")?,
+                "allow_shared_folder" => crate::deserialize_field(&mut map, &mut field_allow_shared_folder, "allow_shared_folder")?,
+                "allow_ownership_transfer" => crate::deserialize_field(&mut map, &mut field_allow_ownership_transfer, "allow_ownership_transfer")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -12811,22 +11896,9 @@ impl RelocationBatchArgBase {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "entries" => {
-                    if field_entries.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("entries"));
-                    }
-                    field_entries = Some(map.next_value()?);
-                }
-                "autorename" => {
-                    if field_autorename.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("autorename"));
-                    }
-                    field_autorename = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "entries" => crate::deserialize_field(&mut map, &mut field_entries, "entries")?,
+                "autorename" => crate::deserialize_field(&mut map, &mut field_autorename, "autorename")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -12915,8 +11987,9 @@ pub enum RelocationBatchError {
     /// There are too many write operations in user's Dropbox. Please retry this request.
     TooManyWriteOperations,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for RelocationBatchError {
@@ -12980,7 +12053,7 @@ impl<'de> ::serde::de::Deserialize<'de> for RelocationBatchError {
                         }
                     }
                     "too_many_write_operations" => RelocationBatchError::TooManyWriteOperations,
-                    _ => RelocationBatchError::Other,
+                    _ => RelocationBatchError::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -13106,7 +12179,10 @@ impl ::serde::ser::Serialize for RelocationBatchError {
                 s.serialize_field(".tag", "too_many_write_operations")?;
                 s.end()
             }
-            RelocationBatchError::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            RelocationBatchError::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -13127,9 +12203,9 @@ impl ::std::error::Error for RelocationBatchError {
 impl ::std::fmt::Display for RelocationBatchError {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         match self {
-            RelocationBatchError::FromLookup(inner) => write!(f, "{}", inner),
-            RelocationBatchError::FromWrite(inner) => write!(f, "{}", inner),
-            RelocationBatchError::To(inner) => write!(f, "{}", inner),
+            RelocationBatchError::FromLookup(inner) => write!(f, "from_lookup: {}", inner),
+            RelocationBatchError::FromWrite(inner) => write!(f, "from_write: {}", inner),
+            RelocationBatchError::To(inner) => write!(f, "to: {}", inner),
             RelocationBatchError::CantCopySharedFolder => f.write_str("Shared folders can't be copied."),
             RelocationBatchError::CantNestSharedFolder => f.write_str("Your move operation would result in nested shared folders.  This is not allowed."),
             RelocationBatchError::CantMoveFolderIntoItself => f.write_str("You cannot move a folder into itself."),
@@ -13163,7 +12239,7 @@ impl From<RelocationError> for RelocationBatchError {
             RelocationError::CantMoveSharedFolder => RelocationBatchError::CantMoveSharedFolder,
             RelocationError::CantMoveIntoVault(x) => RelocationBatchError::CantMoveIntoVault(x),
             RelocationError::CantMoveIntoFamily(x) => RelocationBatchError::CantMoveIntoFamily(x),
-            RelocationError::Other => RelocationBatchError::Other,
+            RelocationError::Other(x) => RelocationBatchError::Other(x),
         }
     }
 }
@@ -13178,8 +12254,9 @@ pub enum RelocationBatchErrorEntry {
     /// There are too many write operations in user's Dropbox. Please retry this request.
     TooManyWriteOperations,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for RelocationBatchErrorEntry {
@@ -13207,7 +12284,7 @@ impl<'de> ::serde::de::Deserialize<'de> for RelocationBatchErrorEntry {
                     }
                     "internal_error" => RelocationBatchErrorEntry::InternalError,
                     "too_many_write_operations" => RelocationBatchErrorEntry::TooManyWriteOperations,
-                    _ => RelocationBatchErrorEntry::Other,
+                    _ => RelocationBatchErrorEntry::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -13245,7 +12322,10 @@ impl ::serde::ser::Serialize for RelocationBatchErrorEntry {
                 s.serialize_field(".tag", "too_many_write_operations")?;
                 s.end()
             }
-            RelocationBatchErrorEntry::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            RelocationBatchErrorEntry::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -13346,8 +12426,9 @@ pub enum RelocationBatchLaunch {
     AsyncJobId(crate::types::dbx_async::AsyncJobId),
     Complete(RelocationBatchResult),
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for RelocationBatchLaunch {
@@ -13374,7 +12455,7 @@ impl<'de> ::serde::de::Deserialize<'de> for RelocationBatchLaunch {
                         }
                     }
                     "complete" => RelocationBatchLaunch::Complete(RelocationBatchResult::internal_deserialize(&mut map)?),
-                    _ => RelocationBatchLaunch::Other,
+                    _ => RelocationBatchLaunch::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -13406,7 +12487,10 @@ impl ::serde::ser::Serialize for RelocationBatchLaunch {
                 x.internal_serialize::<S>(&mut s)?;
                 s.end()
             }
-            RelocationBatchLaunch::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            RelocationBatchLaunch::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -13450,16 +12534,8 @@ impl RelocationBatchResult {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "entries" => {
-                    if field_entries.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("entries"));
-                    }
-                    field_entries = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "entries" => crate::deserialize_field(&mut map, &mut field_entries, "entries")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -13546,17 +12622,9 @@ impl RelocationBatchResultData {
         let mut nothing = true;
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
-            match key {
-                "metadata" => {
-                    if field_metadata.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("metadata"));
-                    }
-                    field_metadata = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+            match key {
+                "metadata" => crate::deserialize_field(&mut map, &mut field_metadata, "metadata")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -13612,8 +12680,9 @@ pub enum RelocationBatchResultEntry {
     Success(Metadata),
     Failure(RelocationBatchErrorEntry),
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for RelocationBatchResultEntry {
@@ -13646,7 +12715,7 @@ impl<'de> ::serde::de::Deserialize<'de> for RelocationBatchResultEntry {
                             _ => return Err(de::Error::unknown_field(tag, VARIANTS))
                         }
                     }
-                    _ => RelocationBatchResultEntry::Other,
+                    _ => RelocationBatchResultEntry::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -13678,7 +12747,10 @@ impl ::serde::ser::Serialize for RelocationBatchResultEntry {
                 s.serialize_field("failure", x)?;
                 s.end()
             }
-            RelocationBatchResultEntry::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            RelocationBatchResultEntry::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -13865,16 +12937,8 @@ impl RelocationBatchV2Result {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "entries" => {
-                    if field_entries.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("entries"));
-                    }
-                    field_entries = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "entries" => crate::deserialize_field(&mut map, &mut field_entries, "entries")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -13963,8 +13027,9 @@ pub enum RelocationError {
     /// detailed error.
     CantMoveIntoFamily(MoveIntoFamilyError),
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for RelocationError {
@@ -14027,7 +13092,7 @@ impl<'de> ::serde::de::Deserialize<'de> for RelocationError {
                             _ => return Err(de::Error::unknown_field(tag, VARIANTS))
                         }
                     }
-                    _ => RelocationError::Other,
+                    _ => RelocationError::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -14146,7 +13211,10 @@ impl ::serde::ser::Serialize for RelocationError {
                 s.serialize_field("cant_move_into_family", x)?;
                 s.end()
             }
-            RelocationError::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            RelocationError::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -14167,9 +13235,9 @@ impl ::std::error::Error for RelocationError {
 impl ::std::fmt::Display for RelocationError {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         match self {
-            RelocationError::FromLookup(inner) => write!(f, "{}", inner),
-            RelocationError::FromWrite(inner) => write!(f, "{}", inner),
-            RelocationError::To(inner) => write!(f, "{}", inner),
+            RelocationError::FromLookup(inner) => write!(f, "from_lookup: {}", inner),
+            RelocationError::FromWrite(inner) => write!(f, "from_write: {}", inner),
+            RelocationError::To(inner) => write!(f, "to: {}", inner),
             RelocationError::CantCopySharedFolder => f.write_str("Shared folders can't be copied."),
             RelocationError::CantNestSharedFolder => f.write_str("Your move operation would result in nested shared folders.  This is not allowed."),
             RelocationError::CantMoveFolderIntoItself => f.write_str("You cannot move a folder into itself."),
@@ -14221,22 +13289,9 @@ impl RelocationPath {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "from_path" => {
-                    if field_from_path.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("from_path"));
-                    }
-                    field_from_path = Some(map.next_value()?);
-                }
-                "to_path" => {
-                    if field_to_path.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("to_path"));
-                    }
-                    field_to_path = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "from_path" => crate::deserialize_field(&mut map, &mut field_from_path, "from_path")?,
+                "to_path" => crate::deserialize_field(&mut map, &mut field_to_path, "to_path")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -14320,16 +13375,8 @@ impl RelocationResult {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "metadata" => {
-                    if field_metadata.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("metadata"));
-                    }
-                    field_metadata = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "metadata" => crate::deserialize_field(&mut map, &mut field_metadata, "metadata")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -14422,22 +13469,9 @@ impl RemoveTagArg {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "path" => {
-                    if field_path.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("path"));
-                    }
-                    field_path = Some(map.next_value()?);
-                }
-                "tag_text" => {
-                    if field_tag_text.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("tag_text"));
-                    }
-                    field_tag_text = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "path" => crate::deserialize_field(&mut map, &mut field_path, "path")?,
+                "tag_text" => crate::deserialize_field(&mut map, &mut field_tag_text, "tag_text")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -14496,8 +13530,9 @@ pub enum RemoveTagError {
     /// That tag doesn't exist at this path.
     TagNotPresent,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for RemoveTagError {
@@ -14524,7 +13559,7 @@ impl<'de> ::serde::de::Deserialize<'de> for RemoveTagError {
                         }
                     }
                     "tag_not_present" => RemoveTagError::TagNotPresent,
-                    _ => RemoveTagError::Other,
+                    _ => RemoveTagError::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -14555,7 +13590,10 @@ impl ::serde::ser::Serialize for RemoveTagError {
                 s.serialize_field(".tag", "tag_not_present")?;
                 s.end()
             }
-            RemoveTagError::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            RemoveTagError::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -14572,7 +13610,7 @@ impl ::std::error::Error for RemoveTagError {
 impl ::std::fmt::Display for RemoveTagError {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         match self {
-            RemoveTagError::Path(inner) => write!(f, "{}", inner),
+            RemoveTagError::Path(inner) => write!(f, "path: {}", inner),
             RemoveTagError::TagNotPresent => f.write_str("That tag doesn't exist at this path."),
             _ => write!(f, "{:?}", *self),
         }
@@ -14584,7 +13622,7 @@ impl From<BaseTagError> for RemoveTagError {
     fn from(parent: BaseTagError) -> Self {
         match parent {
             BaseTagError::Path(x) => RemoveTagError::Path(x),
-            BaseTagError::Other => RemoveTagError::Other,
+            BaseTagError::Other(x) => RemoveTagError::Other(x),
         }
     }
 }
@@ -14625,22 +13663,9 @@ impl RestoreArg {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "path" => {
-                    if field_path.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("path"));
-                    }
-                    field_path = Some(map.next_value()?);
-                }
-                "rev" => {
-                    if field_rev.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("rev"));
-                    }
-                    field_rev = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "path" => crate::deserialize_field(&mut map, &mut field_path, "path")?,
+                "rev" => crate::deserialize_field(&mut map, &mut field_rev, "rev")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -14704,8 +13729,9 @@ pub enum RestoreError {
     /// The restore is currently executing, but has not yet completed.
     InProgress,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for RestoreError {
@@ -14740,7 +13766,7 @@ impl<'de> ::serde::de::Deserialize<'de> for RestoreError {
                     }
                     "invalid_revision" => RestoreError::InvalidRevision,
                     "in_progress" => RestoreError::InProgress,
-                    _ => RestoreError::Other,
+                    _ => RestoreError::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -14786,7 +13812,10 @@ impl ::serde::ser::Serialize for RestoreError {
                 s.serialize_field(".tag", "in_progress")?;
                 s.end()
             }
-            RestoreError::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            RestoreError::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -14850,22 +13879,9 @@ impl SaveCopyReferenceArg {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "copy_reference" => {
-                    if field_copy_reference.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("copy_reference"));
-                    }
-                    field_copy_reference = Some(map.next_value()?);
-                }
-                "path" => {
-                    if field_path.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("path"));
-                    }
-                    field_path = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "copy_reference" => crate::deserialize_field(&mut map, &mut field_copy_reference, "copy_reference")?,
+                "path" => crate::deserialize_field(&mut map, &mut field_path, "path")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -14931,8 +13947,9 @@ pub enum SaveCopyReferenceError {
     /// The operation would involve more than 10,000 files and folders.
     TooManyFiles,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for SaveCopyReferenceError {
@@ -14962,7 +13979,7 @@ impl<'de> ::serde::de::Deserialize<'de> for SaveCopyReferenceError {
                     "no_permission" => SaveCopyReferenceError::NoPermission,
                     "not_found" => SaveCopyReferenceError::NotFound,
                     "too_many_files" => SaveCopyReferenceError::TooManyFiles,
-                    _ => SaveCopyReferenceError::Other,
+                    _ => SaveCopyReferenceError::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -15014,7 +14031,10 @@ impl ::serde::ser::Serialize for SaveCopyReferenceError {
                 s.serialize_field(".tag", "too_many_files")?;
                 s.end()
             }
-            SaveCopyReferenceError::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            SaveCopyReferenceError::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -15031,7 +14051,7 @@ impl ::std::error::Error for SaveCopyReferenceError {
 impl ::std::fmt::Display for SaveCopyReferenceError {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         match self {
-            SaveCopyReferenceError::Path(inner) => write!(f, "{}", inner),
+            SaveCopyReferenceError::Path(inner) => write!(f, "path: {}", inner),
             SaveCopyReferenceError::InvalidCopyReference => f.write_str("The copy reference is invalid."),
             SaveCopyReferenceError::NoPermission => f.write_str("You don't have permission to save the given copy reference. Please make sure this app is same app which created the copy reference and the source user is still linked to the app."),
             SaveCopyReferenceError::NotFound => f.write_str("The file referenced by the copy reference cannot be found."),
@@ -15073,16 +14093,8 @@ impl SaveCopyReferenceResult {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "metadata" => {
-                    if field_metadata.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("metadata"));
-                    }
-                    field_metadata = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "metadata" => crate::deserialize_field(&mut map, &mut field_metadata, "metadata")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -15169,22 +14181,9 @@ impl SaveUrlArg {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "path" => {
-                    if field_path.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("path"));
-                    }
-                    field_path = Some(map.next_value()?);
-                }
-                "url" => {
-                    if field_url.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("url"));
-                    }
-                    field_url = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "path" => crate::deserialize_field(&mut map, &mut field_path, "path")?,
+                "url" => crate::deserialize_field(&mut map, &mut field_url, "url")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -15248,8 +14247,9 @@ pub enum SaveUrlError {
     /// The file where the URL is saved to no longer exists.
     NotFound,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for SaveUrlError {
@@ -15278,7 +14278,7 @@ impl<'de> ::serde::de::Deserialize<'de> for SaveUrlError {
                     "download_failed" => SaveUrlError::DownloadFailed,
                     "invalid_url" => SaveUrlError::InvalidUrl,
                     "not_found" => SaveUrlError::NotFound,
-                    _ => SaveUrlError::Other,
+                    _ => SaveUrlError::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -15323,7 +14323,10 @@ impl ::serde::ser::Serialize for SaveUrlError {
                 s.serialize_field(".tag", "not_found")?;
                 s.end()
             }
-            SaveUrlError::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            SaveUrlError::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -15340,7 +14343,7 @@ impl ::std::error::Error for SaveUrlError {
 impl ::std::fmt::Display for SaveUrlError {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         match self {
-            SaveUrlError::Path(inner) => write!(f, "{}", inner),
+            SaveUrlError::Path(inner) => write!(f, "path: {}", inner),
             SaveUrlError::DownloadFailed => f.write_str("Failed downloading the given URL. The URL may be  password-protected and the password provided was incorrect,  or the link may be disabled."),
             SaveUrlError::InvalidUrl => f.write_str("The given URL is invalid."),
             SaveUrlError::NotFound => f.write_str("The file where the URL is saved to no longer exists."),
@@ -15579,40 +14582,12 @@ impl SearchArg {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "path" => {
-                    if field_path.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("path"));
-                    }
-                    field_path = Some(map.next_value()?);
-                }
-                "query" => {
-                    if field_query.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("query"));
-                    }
-                    field_query = Some(map.next_value()?);
-                }
-                "start" => {
-                    if field_start.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("start"));
-                    }
-                    field_start = Some(map.next_value()?);
-                }
-                "max_results" => {
-                    if field_max_results.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("max_results"));
-                    }
-                    field_max_results = Some(map.next_value()?);
-                }
-                "mode" => {
-                    if field_mode.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("mode"));
-                    }
-                    field_mode = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "path" => crate::deserialize_field(&mut map, &mut field_path, "path")?,
+                "query" => crate::deserialize_field(&mut map, &mut field_query, "query")?,
+                "start" => crate::deserialize_field(&mut map, &mut field_start, "start")?,
+                "max_results" => crate::deserialize_field(&mut map, &mut field_max_results, "max_results")?,
+                "mode" => crate::deserialize_field(&mut map, &mut field_mode, "mode")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -15684,8 +14659,9 @@ pub enum SearchError {
     /// Something went wrong, please try again.
     InternalError,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for SearchError {
@@ -15719,7 +14695,7 @@ impl<'de> ::serde::de::Deserialize<'de> for SearchError {
                         }
                     }
                     "internal_error" => SearchError::InternalError,
-                    _ => SearchError::Other,
+                    _ => SearchError::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -15761,7 +14737,10 @@ impl ::serde::ser::Serialize for SearchError {
                 s.serialize_field(".tag", "internal_error")?;
                 s.end()
             }
-            SearchError::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            SearchError::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -15778,7 +14757,7 @@ impl ::std::error::Error for SearchError {
 impl ::std::fmt::Display for SearchError {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         match self {
-            SearchError::Path(inner) => write!(f, "{}", inner),
+            SearchError::Path(inner) => write!(f, "path: {}", inner),
             SearchError::InvalidArgument(None) => f.write_str("invalid_argument"),
             SearchError::InvalidArgument(Some(inner)) => write!(f, "invalid_argument: {:?}", inner),
             SearchError::InternalError => f.write_str("Something went wrong, please try again."),
@@ -15824,22 +14803,9 @@ impl SearchMatch {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "match_type" => {
-                    if field_match_type.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("match_type"));
-                    }
-                    field_match_type = Some(map.next_value()?);
-                }
-                "metadata" => {
-                    if field_metadata.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("metadata"));
-                    }
-                    field_metadata = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "match_type" => crate::deserialize_field(&mut map, &mut field_match_type, "match_type")?,
+                "metadata" => crate::deserialize_field(&mut map, &mut field_metadata, "metadata")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -15914,16 +14880,8 @@ impl SearchMatchFieldOptions {
         let mut field_include_highlights = None;
         while let Some(key) = map.next_key::<&str>()? {
             match key {
-                "include_highlights" => {
-                    if field_include_highlights.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("include_highlights"));
-                    }
-                    field_include_highlights = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "include_highlights" => crate::deserialize_field(&mut map, &mut field_include_highlights, "include_highlights")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         let result = SearchMatchFieldOptions {
@@ -16055,8 +15013,9 @@ pub enum SearchMatchTypeV2 {
     /// This item was matched on image content.
     ImageContent,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for SearchMatchTypeV2 {
@@ -16079,7 +15038,7 @@ impl<'de> ::serde::de::Deserialize<'de> for SearchMatchTypeV2 {
                     "file_content" => SearchMatchTypeV2::FileContent,
                     "filename_and_content" => SearchMatchTypeV2::FilenameAndContent,
                     "image_content" => SearchMatchTypeV2::ImageContent,
-                    _ => SearchMatchTypeV2::Other,
+                    _ => SearchMatchTypeV2::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -16123,7 +15082,10 @@ impl ::serde::ser::Serialize for SearchMatchTypeV2 {
                 s.serialize_field(".tag", "image_content")?;
                 s.end()
             }
-            SearchMatchTypeV2::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            SearchMatchTypeV2::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -16180,28 +15142,10 @@ impl SearchMatchV2 {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "metadata" => {
-                    if field_metadata.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("metadata"));
-                    }
-                    field_metadata = Some(map.next_value()?);
-                }
-                "match_type" => {
-                    if field_match_type.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("match_type"));
-                    }
-                    field_match_type = Some(map.next_value()?);
-                }
-                "highlight_spans" => {
-                    if field_highlight_spans.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("highlight_spans"));
-                    }
-                    field_highlight_spans = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "metadata" => crate::deserialize_field(&mut map, &mut field_metadata, "metadata")?,
+                "match_type" => crate::deserialize_field(&mut map, &mut field_match_type, "match_type")?,
+                "highlight_spans" => crate::deserialize_field(&mut map, &mut field_highlight_spans, "highlight_spans")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -16432,58 +15376,15 @@ impl SearchOptions {
         let mut field_account_id = None;
         while let Some(key) = map.next_key::<&str>()? {
             match key {
-                "path" => {
-                    if field_path.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("path"));
-                    }
-                    field_path = Some(map.next_value()?);
-                }
-                "max_results" => {
-                    if field_max_results.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("max_results"));
-                    }
-                    field_max_results = Some(map.next_value()?);
-                }
-                "order_by" => {
-                    if field_order_by.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("order_by"));
-                    }
-                    field_order_by = Some(map.next_value()?);
-                }
-                "file_status" => {
-                    if field_file_status.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("file_status"));
-                    }
-                    field_file_status = Some(map.next_value()?);
-                }
-                "filename_only" => {
-                    if field_filename_only.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("filename_only"));
-                    }
-                    field_filename_only = Some(map.next_value()?);
-                }
-                "file_extensions" => {
-                    if field_file_extensions.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("file_extensions"));
-                    }
-                    field_file_extensions = Some(map.next_value()?);
-                }
-                "file_categories" => {
-                    if field_file_categories.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("file_categories"));
-                    }
-                    field_file_categories = Some(map.next_value()?);
-                }
-                "account_id" => {
-                    if field_account_id.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("account_id"));
-                    }
-                    field_account_id = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "path" => crate::deserialize_field(&mut map, &mut field_path, "path")?,
+                "max_results" => crate::deserialize_field(&mut map, &mut field_max_results, "max_results")?,
+                "order_by" => crate::deserialize_field(&mut map, &mut field_order_by, "order_by")?,
+                "file_status" => crate::deserialize_field(&mut map, &mut field_file_status, "file_status")?,
+                "filename_only" => crate::deserialize_field(&mut map, &mut field_filename_only, "filename_only")?,
+                "file_extensions" => crate::deserialize_field(&mut map, &mut field_file_extensions, "file_extensions")?,
+                "file_categories" => crate::deserialize_field(&mut map, &mut field_file_categories, "file_categories")?,
+                "account_id" => crate::deserialize_field(&mut map, &mut field_account_id, "account_id")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         let result = SearchOptions {
@@ -16566,8 +15467,9 @@ pub enum SearchOrderBy {
     Relevance,
     LastModifiedTime,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for SearchOrderBy {
@@ -16588,7 +15490,7 @@ impl<'de> ::serde::de::Deserialize<'de> for SearchOrderBy {
                 let value = match tag {
                     "relevance" => SearchOrderBy::Relevance,
                     "last_modified_time" => SearchOrderBy::LastModifiedTime,
-                    _ => SearchOrderBy::Other,
+                    _ => SearchOrderBy::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -16618,7 +15520,10 @@ impl ::serde::ser::Serialize for SearchOrderBy {
                 s.serialize_field(".tag", "last_modified_time")?;
                 s.end()
             }
-            SearchOrderBy::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            SearchOrderBy::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -16667,28 +15572,10 @@ impl SearchResult {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "matches" => {
-                    if field_matches.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("matches"));
-                    }
-                    field_matches = Some(map.next_value()?);
-                }
-                "more" => {
-                    if field_more.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("more"));
-                    }
-                    field_more = Some(map.next_value()?);
-                }
-                "start" => {
-                    if field_start.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("start"));
-                    }
-                    field_start = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "matches" => crate::deserialize_field(&mut map, &mut field_matches, "matches")?,
+                "more" => crate::deserialize_field(&mut map, &mut field_more, "more")?,
+                "start" => crate::deserialize_field(&mut map, &mut field_start, "start")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -16804,34 +15691,11 @@ impl SearchV2Arg {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "query" => {
-                    if field_query.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("query"));
-                    }
-                    field_query = Some(map.next_value()?);
-                }
-                "options" => {
-                    if field_options.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("options"));
-                    }
-                    field_options = Some(map.next_value()?);
-                }
-                "match_field_options" => {
-                    if field_match_field_options.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("match_field_options"));
-                    }
-                    field_match_field_options = Some(map.next_value()?);
-                }
-                "include_highlights" => {
-                    if field_include_highlights.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("include_highlights"));
-                    }
-                    field_include_highlights = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "query" => crate::deserialize_field(&mut map, &mut field_query, "query")?,
+                "options" => crate::deserialize_field(&mut map, &mut field_options, "options")?,
+                "match_field_options" => crate::deserialize_field(&mut map, &mut field_match_field_options, "match_field_options")?,
+                "include_highlights" => crate::deserialize_field(&mut map, &mut field_include_highlights, "include_highlights")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -16926,16 +15790,8 @@ impl SearchV2ContinueArg {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "cursor" => {
-                    if field_cursor.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("cursor"));
-                    }
-                    field_cursor = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "cursor" => crate::deserialize_field(&mut map, &mut field_cursor, "cursor")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -17035,28 +15891,10 @@ impl SearchV2Result {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "matches" => {
-                    if field_matches.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("matches"));
-                    }
-                    field_matches = Some(map.next_value()?);
-                }
-                "has_more" => {
-                    if field_has_more.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("has_more"));
-                    }
-                    field_has_more = Some(map.next_value()?);
-                }
-                "cursor" => {
-                    if field_cursor.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("cursor"));
-                    }
-                    field_cursor = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "matches" => crate::deserialize_field(&mut map, &mut field_matches, "matches")?,
+                "has_more" => crate::deserialize_field(&mut map, &mut field_has_more, "has_more")?,
+                "cursor" => crate::deserialize_field(&mut map, &mut field_cursor, "cursor")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -17154,22 +15992,9 @@ impl SharedLink {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "url" => {
-                    if field_url.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("url"));
-                    }
-                    field_url = Some(map.next_value()?);
-                }
-                "password" => {
-                    if field_password.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("password"));
-                    }
-                    field_password = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "url" => crate::deserialize_field(&mut map, &mut field_url, "url")?,
+                "password" => crate::deserialize_field(&mut map, &mut field_password, "password")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -17279,28 +16104,10 @@ impl SharedLinkFileInfo {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "url" => {
-                    if field_url.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("url"));
-                    }
-                    field_url = Some(map.next_value()?);
-                }
-                "path" => {
-                    if field_path.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("path"));
-                    }
-                    field_path = Some(map.next_value()?);
-                }
-                "password" => {
-                    if field_password.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("password"));
-                    }
-                    field_password = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "url" => crate::deserialize_field(&mut map, &mut field_url, "url")?,
+                "path" => crate::deserialize_field(&mut map, &mut field_path, "path")?,
+                "password" => crate::deserialize_field(&mut map, &mut field_password, "password")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -17391,16 +16198,8 @@ impl SharingInfo {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "read_only" => {
-                    if field_read_only.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("read_only"));
-                    }
-                    field_read_only = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "read_only" => crate::deserialize_field(&mut map, &mut field_read_only, "read_only")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -17500,28 +16299,10 @@ impl SingleUserLock {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "created" => {
-                    if field_created.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("created"));
-                    }
-                    field_created = Some(map.next_value()?);
-                }
-                "lock_holder_account_id" => {
-                    if field_lock_holder_account_id.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("lock_holder_account_id"));
-                    }
-                    field_lock_holder_account_id = Some(map.next_value()?);
-                }
-                "lock_holder_team_id" => {
-                    if field_lock_holder_team_id.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("lock_holder_team_id"));
-                    }
-                    field_lock_holder_team_id = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "created" => crate::deserialize_field(&mut map, &mut field_created, "created")?,
+                "lock_holder_account_id" => crate::deserialize_field(&mut map, &mut field_lock_holder_account_id, "lock_holder_account_id")?,
+                "lock_holder_team_id" => crate::deserialize_field(&mut map, &mut field_lock_holder_team_id, "lock_holder_team_id")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -17609,16 +16390,8 @@ impl SymlinkInfo {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "target" => {
-                    if field_target.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("target"));
-                    }
-                    field_target = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "target" => crate::deserialize_field(&mut map, &mut field_target, "target")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -17681,8 +16454,9 @@ pub enum SyncSetting {
     /// configuration changes. It will follow its parent folder's setting.
     NotSyncedInactive,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for SyncSetting {
@@ -17704,7 +16478,7 @@ impl<'de> ::serde::de::Deserialize<'de> for SyncSetting {
                     "default" => SyncSetting::Default,
                     "not_synced" => SyncSetting::NotSynced,
                     "not_synced_inactive" => SyncSetting::NotSyncedInactive,
-                    _ => SyncSetting::Other,
+                    _ => SyncSetting::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -17741,7 +16515,10 @@ impl ::serde::ser::Serialize for SyncSetting {
                 s.serialize_field(".tag", "not_synced_inactive")?;
                 s.end()
             }
-            SyncSetting::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            SyncSetting::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -17756,8 +16533,9 @@ pub enum SyncSettingArg {
     /// selective sync.
     NotSynced,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for SyncSettingArg {
@@ -17778,7 +16556,7 @@ impl<'de> ::serde::de::Deserialize<'de> for SyncSettingArg {
                 let value = match tag {
                     "default" => SyncSettingArg::Default,
                     "not_synced" => SyncSettingArg::NotSynced,
-                    _ => SyncSettingArg::Other,
+                    _ => SyncSettingArg::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -17808,7 +16586,10 @@ impl ::serde::ser::Serialize for SyncSettingArg {
                 s.serialize_field(".tag", "not_synced")?;
                 s.end()
             }
-            SyncSettingArg::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            SyncSettingArg::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -17822,8 +16603,9 @@ pub enum SyncSettingsError {
     /// The specified configuration is not supported.
     UnsupportedConfiguration,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for SyncSettingsError {
@@ -17851,7 +16633,7 @@ impl<'de> ::serde::de::Deserialize<'de> for SyncSettingsError {
                     }
                     "unsupported_combination" => SyncSettingsError::UnsupportedCombination,
                     "unsupported_configuration" => SyncSettingsError::UnsupportedConfiguration,
-                    _ => SyncSettingsError::Other,
+                    _ => SyncSettingsError::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -17889,7 +16671,10 @@ impl ::serde::ser::Serialize for SyncSettingsError {
                 s.serialize_field(".tag", "unsupported_configuration")?;
                 s.end()
             }
-            SyncSettingsError::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            SyncSettingsError::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -17906,7 +16691,7 @@ impl ::std::error::Error for SyncSettingsError {
 impl ::std::fmt::Display for SyncSettingsError {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         match self {
-            SyncSettingsError::Path(inner) => write!(f, "{}", inner),
+            SyncSettingsError::Path(inner) => write!(f, "path: {}", inner),
             SyncSettingsError::UnsupportedCombination => f.write_str("Setting this combination of sync settings simultaneously is not supported."),
             SyncSettingsError::UnsupportedConfiguration => f.write_str("The specified configuration is not supported."),
             _ => write!(f, "{:?}", *self),
@@ -17921,8 +16706,9 @@ pub enum Tag {
     /// Tag generated by the user.
     UserGeneratedTag(UserGeneratedTag),
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for Tag {
@@ -17942,7 +16728,7 @@ impl<'de> ::serde::de::Deserialize<'de> for Tag {
                 };
                 let value = match tag {
                     "user_generated_tag" => Tag::UserGeneratedTag(UserGeneratedTag::internal_deserialize(&mut map)?),
-                    _ => Tag::Other,
+                    _ => Tag::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -17966,7 +16752,10 @@ impl ::serde::ser::Serialize for Tag {
                 x.internal_serialize::<S>(&mut s)?;
                 s.end()
             }
-            Tag::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            Tag::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -18034,34 +16823,11 @@ impl ThumbnailArg {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "path" => {
-                    if field_path.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("path"));
-                    }
-                    field_path = Some(map.next_value()?);
-                }
-                "format" => {
-                    if field_format.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("format"));
-                    }
-                    field_format = Some(map.next_value()?);
-                }
-                "size" => {
-                    if field_size.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("size"));
-                    }
-                    field_size = Some(map.next_value()?);
-                }
-                "mode" => {
-                    if field_mode.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("mode"));
-                    }
-                    field_mode = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "path" => crate::deserialize_field(&mut map, &mut field_path, "path")?,
+                "format" => crate::deserialize_field(&mut map, &mut field_format, "format")?,
+                "size" => crate::deserialize_field(&mut map, &mut field_size, "size")?,
+                "mode" => crate::deserialize_field(&mut map, &mut field_mode, "mode")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -18548,34 +17314,11 @@ impl ThumbnailV2Arg {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "resource" => {
-                    if field_resource.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("resource"));
-                    }
-                    field_resource = Some(map.next_value()?);
-                }
-                "format" => {
-                    if field_format.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("format"));
-                    }
-                    field_format = Some(map.next_value()?);
-                }
-                "size" => {
-                    if field_size.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("size"));
-                    }
-                    field_size = Some(map.next_value()?);
-                }
-                "mode" => {
-                    if field_mode.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("mode"));
-                    }
-                    field_mode = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "resource" => crate::deserialize_field(&mut map, &mut field_resource, "resource")?,
+                "format" => crate::deserialize_field(&mut map, &mut field_format, "format")?,
+                "size" => crate::deserialize_field(&mut map, &mut field_size, "size")?,
+                "mode" => crate::deserialize_field(&mut map, &mut field_mode, "mode")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -18653,8 +17396,9 @@ pub enum ThumbnailV2Error {
     /// The shared link does not exist.
     NotFound,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for ThumbnailV2Error {
@@ -18685,7 +17429,7 @@ impl<'de> ::serde::de::Deserialize<'de> for ThumbnailV2Error {
                     "conversion_error" => ThumbnailV2Error::ConversionError,
                     "access_denied" => ThumbnailV2Error::AccessDenied,
                     "not_found" => ThumbnailV2Error::NotFound,
-                    _ => ThumbnailV2Error::Other,
+                    _ => ThumbnailV2Error::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -18744,7 +17488,10 @@ impl ::serde::ser::Serialize for ThumbnailV2Error {
                 s.serialize_field(".tag", "not_found")?;
                 s.end()
             }
-            ThumbnailV2Error::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            ThumbnailV2Error::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -18804,16 +17551,8 @@ impl UnlockFileArg {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "path" => {
-                    if field_path.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("path"));
-                    }
-                    field_path = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "path" => crate::deserialize_field(&mut map, &mut field_path, "path")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -18896,16 +17635,8 @@ impl UnlockFileBatchArg {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "entries" => {
-                    if field_entries.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("entries"));
-                    }
-                    field_entries = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "entries" => crate::deserialize_field(&mut map, &mut field_entries, "entries")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -19072,58 +17803,15 @@ impl UploadArg {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "path" => {
-                    if field_path.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("path"));
-                    }
-                    field_path = Some(map.next_value()?);
-                }
-                "mode" => {
-                    if field_mode.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("mode"));
-                    }
-                    field_mode = Some(map.next_value()?);
-                }
-                "autorename" => {
-                    if field_autorename.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("autorename"));
-                    }
-                    field_autorename = Some(map.next_value()?);
-                }
-                "client_modified" => {
-                    if field_client_modified.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("client_modified"));
-                    }
-                    field_client_modified = Some(map.next_value()?);
-                }
-                "mute" => {
-                    if field_mute.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("mute"));
-                    }
-                    field_mute = Some(map.next_value()?);
-                }
-                "property_groups" => {
-                    if field_property_groups.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("property_groups"));
-                    }
-                    field_property_groups = Some(map.next_value()?);
-                }
-                "strict_conflict" => {
-                    if field_strict_conflict.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("strict_conflict"));
-                    }
-                    field_strict_conflict = Some(map.next_value()?);
-                }
-                "content_hash" => {
-                    if field_content_hash.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("content_hash"));
-                    }
-                    field_content_hash = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "path" => crate::deserialize_field(&mut map, &mut field_path, "path")?,
+                "mode" => crate::deserialize_field(&mut map, &mut field_mode, "mode")?,
+                "autorename" => crate::deserialize_field(&mut map, &mut field_autorename, "autorename")?,
+                "client_modified" => crate::deserialize_field(&mut map, &mut field_client_modified, "client_modified")?,
+                "mute" => crate::deserialize_field(&mut map, &mut field_mute, "mute")?,
+                "property_groups" => crate::deserialize_field(&mut map, &mut field_property_groups, "property_groups")?,
+                "strict_conflict" => crate::deserialize_field(&mut map, &mut field_strict_conflict, "strict_conflict")?,
+                "content_hash" => crate::deserialize_field(&mut map, &mut field_content_hash, "content_hash")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -19228,8 +17916,9 @@ pub enum UploadError {
     /// hash.
     ContentHashMismatch,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for UploadError {
@@ -19258,7 +17947,7 @@ impl<'de> ::serde::de::Deserialize<'de> for UploadError {
                     }
                     "payload_too_large" => UploadError::PayloadTooLarge,
                     "content_hash_mismatch" => UploadError::ContentHashMismatch,
-                    _ => UploadError::Other,
+                    _ => UploadError::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -19304,7 +17993,10 @@ impl ::serde::ser::Serialize for UploadError {
                 s.serialize_field(".tag", "content_hash_mismatch")?;
                 s.end()
             }
-            UploadError::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            UploadError::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -19386,28 +18078,10 @@ impl UploadSessionAppendArg {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "cursor" => {
-                    if field_cursor.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("cursor"));
-                    }
-                    field_cursor = Some(map.next_value()?);
-                }
-                "close" => {
-                    if field_close.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("close"));
-                    }
-                    field_close = Some(map.next_value()?);
-                }
-                "content_hash" => {
-                    if field_content_hash.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("content_hash"));
-                    }
-                    field_content_hash = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "cursor" => crate::deserialize_field(&mut map, &mut field_cursor, "cursor")?,
+                "close" => crate::deserialize_field(&mut map, &mut field_close, "close")?,
+                "content_hash" => crate::deserialize_field(&mut map, &mut field_content_hash, "content_hash")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -19493,8 +18167,9 @@ pub enum UploadSessionAppendError {
     /// hash.
     ContentHashMismatch,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for UploadSessionAppendError {
@@ -19522,7 +18197,7 @@ impl<'de> ::serde::de::Deserialize<'de> for UploadSessionAppendError {
                     "concurrent_session_invalid_data_size" => UploadSessionAppendError::ConcurrentSessionInvalidDataSize,
                     "payload_too_large" => UploadSessionAppendError::PayloadTooLarge,
                     "content_hash_mismatch" => UploadSessionAppendError::ContentHashMismatch,
-                    _ => UploadSessionAppendError::Other,
+                    _ => UploadSessionAppendError::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -19602,7 +18277,10 @@ impl ::serde::ser::Serialize for UploadSessionAppendError {
                 s.serialize_field(".tag", "content_hash_mismatch")?;
                 s.end()
             }
-            UploadSessionAppendError::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            UploadSessionAppendError::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -19639,7 +18317,7 @@ impl From<UploadSessionLookupError> for UploadSessionAppendError {
             UploadSessionLookupError::ConcurrentSessionInvalidOffset => UploadSessionAppendError::ConcurrentSessionInvalidOffset,
             UploadSessionLookupError::ConcurrentSessionInvalidDataSize => UploadSessionAppendError::ConcurrentSessionInvalidDataSize,
             UploadSessionLookupError::PayloadTooLarge => UploadSessionAppendError::PayloadTooLarge,
-            UploadSessionLookupError::Other => UploadSessionAppendError::Other,
+            UploadSessionLookupError::Other(x) => UploadSessionAppendError::Other(x),
         }
     }
 }
@@ -19682,22 +18360,9 @@ impl UploadSessionCursor {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "session_id" => {
-                    if field_session_id.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("session_id"));
-                    }
-                    field_session_id = Some(map.next_value()?);
-                }
-                "offset" => {
-                    if field_offset.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("offset"));
-                    }
-                    field_offset = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "session_id" => crate::deserialize_field(&mut map, &mut field_session_id, "session_id")?,
+                "offset" => crate::deserialize_field(&mut map, &mut field_offset, "offset")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -19798,28 +18463,10 @@ impl UploadSessionFinishArg {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "cursor" => {
-                    if field_cursor.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("cursor"));
-                    }
-                    field_cursor = Some(map.next_value()?);
-                }
-                "commit" => {
-                    if field_commit.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("commit"));
-                    }
-                    field_commit = Some(map.next_value()?);
-                }
-                "content_hash" => {
-                    if field_content_hash.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("content_hash"));
-                    }
-                    field_content_hash = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "cursor" => crate::deserialize_field(&mut map, &mut field_cursor, "cursor")?,
+                "commit" => crate::deserialize_field(&mut map, &mut field_commit, "commit")?,
+                "content_hash" => crate::deserialize_field(&mut map, &mut field_content_hash, "content_hash")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -19907,16 +18554,8 @@ impl UploadSessionFinishBatchArg {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "entries" => {
-                    if field_entries.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("entries"));
-                    }
-                    field_entries = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "entries" => crate::deserialize_field(&mut map, &mut field_entries, "entries")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -20045,8 +18684,9 @@ pub enum UploadSessionFinishBatchLaunch {
     AsyncJobId(crate::types::dbx_async::AsyncJobId),
     Complete(UploadSessionFinishBatchResult),
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for UploadSessionFinishBatchLaunch {
@@ -20073,7 +18713,7 @@ impl<'de> ::serde::de::Deserialize<'de> for UploadSessionFinishBatchLaunch {
                         }
                     }
                     "complete" => UploadSessionFinishBatchLaunch::Complete(UploadSessionFinishBatchResult::internal_deserialize(&mut map)?),
-                    _ => UploadSessionFinishBatchLaunch::Other,
+                    _ => UploadSessionFinishBatchLaunch::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -20105,7 +18745,10 @@ impl ::serde::ser::Serialize for UploadSessionFinishBatchLaunch {
                 x.internal_serialize::<S>(&mut s)?;
                 s.end()
             }
-            UploadSessionFinishBatchLaunch::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            UploadSessionFinishBatchLaunch::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -20152,16 +18795,8 @@ impl UploadSessionFinishBatchResult {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "entries" => {
-                    if field_entries.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("entries"));
-                    }
-                    field_entries = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "entries" => crate::deserialize_field(&mut map, &mut field_entries, "entries")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -20304,8 +18939,9 @@ pub enum UploadSessionFinishError {
     /// hash.
     ContentHashMismatch,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for UploadSessionFinishError {
@@ -20352,7 +18988,7 @@ impl<'de> ::serde::de::Deserialize<'de> for UploadSessionFinishError {
                     "concurrent_session_missing_data" => UploadSessionFinishError::ConcurrentSessionMissingData,
                     "payload_too_large" => UploadSessionFinishError::PayloadTooLarge,
                     "content_hash_mismatch" => UploadSessionFinishError::ContentHashMismatch,
-                    _ => UploadSessionFinishError::Other,
+                    _ => UploadSessionFinishError::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -20441,7 +19077,10 @@ impl ::serde::ser::Serialize for UploadSessionFinishError {
                 s.serialize_field(".tag", "content_hash_mismatch")?;
                 s.end()
             }
-            UploadSessionFinishError::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            UploadSessionFinishError::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -20500,8 +19139,9 @@ pub enum UploadSessionLookupError {
     /// The request payload must be at most 150 MB.
     PayloadTooLarge,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for UploadSessionLookupError {
@@ -20528,7 +19168,7 @@ impl<'de> ::serde::de::Deserialize<'de> for UploadSessionLookupError {
                     "concurrent_session_invalid_offset" => UploadSessionLookupError::ConcurrentSessionInvalidOffset,
                     "concurrent_session_invalid_data_size" => UploadSessionLookupError::ConcurrentSessionInvalidDataSize,
                     "payload_too_large" => UploadSessionLookupError::PayloadTooLarge,
-                    _ => UploadSessionLookupError::Other,
+                    _ => UploadSessionLookupError::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -20601,7 +19241,10 @@ impl ::serde::ser::Serialize for UploadSessionLookupError {
                 s.serialize_field(".tag", "payload_too_large")?;
                 s.end()
             }
-            UploadSessionLookupError::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            UploadSessionLookupError::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -20657,16 +19300,8 @@ impl UploadSessionOffsetError {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "correct_offset" => {
-                    if field_correct_offset.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("correct_offset"));
-                    }
-                    field_correct_offset = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "correct_offset" => crate::deserialize_field(&mut map, &mut field_correct_offset, "correct_offset")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -20762,28 +19397,10 @@ impl UploadSessionStartArg {
         let mut field_content_hash = None;
         while let Some(key) = map.next_key::<&str>()? {
             match key {
-                "close" => {
-                    if field_close.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("close"));
-                    }
-                    field_close = Some(map.next_value()?);
-                }
-                "session_type" => {
-                    if field_session_type.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("session_type"));
-                    }
-                    field_session_type = Some(map.next_value()?);
-                }
-                "content_hash" => {
-                    if field_content_hash.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("content_hash"));
-                    }
-                    field_content_hash = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "close" => crate::deserialize_field(&mut map, &mut field_close, "close")?,
+                "session_type" => crate::deserialize_field(&mut map, &mut field_session_type, "session_type")?,
+                "content_hash" => crate::deserialize_field(&mut map, &mut field_content_hash, "content_hash")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         let result = UploadSessionStartArg {
@@ -20883,22 +19500,9 @@ impl UploadSessionStartBatchArg {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "num_sessions" => {
-                    if field_num_sessions.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("num_sessions"));
-                    }
-                    field_num_sessions = Some(map.next_value()?);
-                }
-                "session_type" => {
-                    if field_session_type.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("session_type"));
-                    }
-                    field_session_type = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "num_sessions" => crate::deserialize_field(&mut map, &mut field_num_sessions, "num_sessions")?,
+                "session_type" => crate::deserialize_field(&mut map, &mut field_session_type, "session_type")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -20986,16 +19590,8 @@ impl UploadSessionStartBatchResult {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "session_ids" => {
-                    if field_session_ids.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("session_ids"));
-                    }
-                    field_session_ids = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "session_ids" => crate::deserialize_field(&mut map, &mut field_session_ids, "session_ids")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -21058,8 +19654,9 @@ pub enum UploadSessionStartError {
     /// hash.
     ContentHashMismatch,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for UploadSessionStartError {
@@ -21082,7 +19679,7 @@ impl<'de> ::serde::de::Deserialize<'de> for UploadSessionStartError {
                     "concurrent_session_close_not_allowed" => UploadSessionStartError::ConcurrentSessionCloseNotAllowed,
                     "payload_too_large" => UploadSessionStartError::PayloadTooLarge,
                     "content_hash_mismatch" => UploadSessionStartError::ContentHashMismatch,
-                    _ => UploadSessionStartError::Other,
+                    _ => UploadSessionStartError::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -21126,7 +19723,10 @@ impl ::serde::ser::Serialize for UploadSessionStartError {
                 s.serialize_field(".tag", "content_hash_mismatch")?;
                 s.end()
             }
-            UploadSessionStartError::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            UploadSessionStartError::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -21180,16 +19780,8 @@ impl UploadSessionStartResult {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "session_id" => {
-                    if field_session_id.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("session_id"));
-                    }
-                    field_session_id = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "session_id" => crate::deserialize_field(&mut map, &mut field_session_id, "session_id")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -21247,8 +19839,9 @@ pub enum UploadSessionType {
     /// Pieces of data can be uploaded in concurrent RPCs in any order.
     Concurrent,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for UploadSessionType {
@@ -21269,7 +19862,7 @@ impl<'de> ::serde::de::Deserialize<'de> for UploadSessionType {
                 let value = match tag {
                     "sequential" => UploadSessionType::Sequential,
                     "concurrent" => UploadSessionType::Concurrent,
-                    _ => UploadSessionType::Other,
+                    _ => UploadSessionType::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -21299,7 +19892,10 @@ impl ::serde::ser::Serialize for UploadSessionType {
                 s.serialize_field(".tag", "concurrent")?;
                 s.end()
             }
-            UploadSessionType::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            UploadSessionType::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -21343,22 +19939,9 @@ impl UploadWriteFailed {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "reason" => {
-                    if field_reason.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("reason"));
-                    }
-                    field_reason = Some(map.next_value()?);
-                }
-                "upload_session_id" => {
-                    if field_upload_session_id.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("upload_session_id"));
-                    }
-                    field_upload_session_id = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "reason" => crate::deserialize_field(&mut map, &mut field_reason, "reason")?,
+                "upload_session_id" => crate::deserialize_field(&mut map, &mut field_upload_session_id, "upload_session_id")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -21441,16 +20024,8 @@ impl UserGeneratedTag {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "tag_text" => {
-                    if field_tag_text.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("tag_text"));
-                    }
-                    field_tag_text = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "tag_text" => crate::deserialize_field(&mut map, &mut field_tag_text, "tag_text")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -21551,34 +20126,11 @@ impl VideoMetadata {
         let mut field_duration = None;
         while let Some(key) = map.next_key::<&str>()? {
             match key {
-                "dimensions" => {
-                    if field_dimensions.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("dimensions"));
-                    }
-                    field_dimensions = Some(map.next_value()?);
-                }
-                "location" => {
-                    if field_location.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("location"));
-                    }
-                    field_location = Some(map.next_value()?);
-                }
-                "time_taken" => {
-                    if field_time_taken.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("time_taken"));
-                    }
-                    field_time_taken = Some(map.next_value()?);
-                }
-                "duration" => {
-                    if field_duration.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("duration"));
-                    }
-                    field_duration = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "dimensions" => crate::deserialize_field(&mut map, &mut field_dimensions, "dimensions")?,
+                "location" => crate::deserialize_field(&mut map, &mut field_location, "location")?,
+                "time_taken" => crate::deserialize_field(&mut map, &mut field_time_taken, "time_taken")?,
+                "duration" => crate::deserialize_field(&mut map, &mut field_duration, "duration")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         let result = VideoMetadata {
@@ -21655,8 +20207,9 @@ pub enum WriteConflictError {
     /// There's a file at an ancestor path, so we couldn't create the required parent folders.
     FileAncestor,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for WriteConflictError {
@@ -21678,7 +20231,7 @@ impl<'de> ::serde::de::Deserialize<'de> for WriteConflictError {
                     "file" => WriteConflictError::File,
                     "folder" => WriteConflictError::Folder,
                     "file_ancestor" => WriteConflictError::FileAncestor,
-                    _ => WriteConflictError::Other,
+                    _ => WriteConflictError::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -21715,7 +20268,10 @@ impl ::serde::ser::Serialize for WriteConflictError {
                 s.serialize_field(".tag", "file_ancestor")?;
                 s.end()
             }
-            WriteConflictError::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            WriteConflictError::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -21756,8 +20312,9 @@ pub enum WriteError {
     /// There are too many write operations in user's Dropbox. Please retry this request.
     TooManyWriteOperations,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for WriteError {
@@ -21796,7 +20353,7 @@ impl<'de> ::serde::de::Deserialize<'de> for WriteError {
                     "team_folder" => WriteError::TeamFolder,
                     "operation_suppressed" => WriteError::OperationSuppressed,
                     "too_many_write_operations" => WriteError::TooManyWriteOperations,
-                    _ => WriteError::Other,
+                    _ => WriteError::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -21873,7 +20430,10 @@ impl ::serde::ser::Serialize for WriteError {
                 s.serialize_field(".tag", "too_many_write_operations")?;
                 s.end()
             }
-            WriteError::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            WriteError::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -8,14 +8,23 @@
     clippy::doc_markdown,
 )]
 
+/// The names of this namespace's routes, as they appear in request URLs after the
+/// [`Endpoint`](crate::client_trait_common::Endpoint) base URL, for referring to them in logs and
+/// metrics without spelling them out.
+pub mod routes {
+    #[cfg(feature = "unstable")]
+    pub const USERINFO: &str = "openid/userinfo";
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 #[non_exhaustive] // variants may be added in the future
 pub enum OpenIdError {
     /// Missing openid claims for the associated access token.
     IncorrectOpenidScopes,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for OpenIdError {
@@ -35,7 +44,7 @@ impl<'de> ::serde::de::Deserialize<'de> for OpenIdError {
                 };
                 let value = match tag {
                     "incorrect_openid_scopes" => OpenIdError::IncorrectOpenidScopes,
-                    _ => OpenIdError::Other,
+                    _ => OpenIdError::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -58,7 +67,10 @@ impl ::serde::ser::Serialize for OpenIdError {
                 s.serialize_field(".tag", "incorrect_openid_scopes")?;
                 s.end()
             }
-            OpenIdError::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            OpenIdError::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -124,8 +136,9 @@ impl ::serde::ser::Serialize for UserInfoArgs {
 pub enum UserInfoError {
     OpenidError(OpenIdError),
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for UserInfoError {
@@ -151,7 +164,7 @@ impl<'de> ::serde::de::Deserialize<'de> for UserInfoError {
                             _ => return Err(de::Error::unknown_field(tag, VARIANTS))
                         }
                     }
-                    _ => UserInfoError::Other,
+                    _ => UserInfoError::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -175,7 +188,10 @@ impl ::serde::ser::Serialize for UserInfoError {
                 s.serialize_field("openid_error", x)?;
                 s.end()
             }
-            UserInfoError::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            UserInfoError::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -192,7 +208,7 @@ impl ::std::error::Error for UserInfoError {
 impl ::std::fmt::Display for UserInfoError {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         match self {
-            UserInfoError::OpenidError(inner) => write!(f, "{}", inner),
+            UserInfoError::OpenidError(inner) => write!(f, "openid_error: {}", inner),
             _ => write!(f, "{:?}", *self),
         }
     }
@@ -267,46 +283,13 @@ impl UserInfoResult {
         let mut field_sub = None;
         while let Some(key) = map.next_key::<&str>()? {
             match key {
-                "family_name" => {
-                    if field_family_name.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("family_name"));
-                    }
-                    field_family_name = Some(map.next_value()?);
-                }
-                "given_name" => {
-                    if field_given_name.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("given_name"));
-                    }
-                    field_given_name = Some(map.next_value()?);
-                }
-                "email" => {
-                    if field_email.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("email"));
-                    }
-                    field_email = Some(map.next_value()?);
-                }
-                "email_verified" => {
-                    if field_email_verified.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("email_verified"));
-                    }
-                    field_email_verified = Some(map.next_value()?);
-                }
-                "iss" => {
-                    if field_iss.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("iss"));
-                    }
-                    field_iss = Some(map.next_value()?);
-                }
-                "sub" => {
-                    if field_sub.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("sub"));
-                    }
-                    field_sub = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "family_name" => crate::deserialize_field(&mut map, &mut field_family_name, "family_name")?,
+                "given_name" => crate::deserialize_field(&mut map, &mut field_given_name, "given_name")?,
+                "email" => crate::deserialize_field(&mut map, &mut field_email, "email")?,
+                "email_verified" => crate::deserialize_field(&mut map, &mut field_email_verified, "email_verified")?,
+                "iss" => crate::deserialize_field(&mut map, &mut field_iss, "iss")?,
+                "sub" => crate::deserialize_field(&mut map, &mut field_sub, "sub")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         let result = UserInfoResult {
@@ -16,6 +16,29 @@
 
 pub type PaperDocId = String;
 
+/// The names of this namespace's routes, as they appear in request URLs after the
+/// [`Endpoint`](crate::client_trait_common::Endpoint) base URL, for referring to them in logs and
+/// metrics without spelling them out.
+pub mod routes {
+    pub const DOCS_ARCHIVE: &str = "paper/docs/archive";
+    pub const DOCS_CREATE: &str = "paper/docs/create";
+    pub const DOCS_DOWNLOAD: &str = "paper/docs/download";
+    pub const DOCS_FOLDER_USERS_LIST: &str = "paper/docs/folder_users/list";
+    pub const DOCS_FOLDER_USERS_LIST_CONTINUE: &str = "paper/docs/folder_users/list/continue";
+    pub const DOCS_GET_FOLDER_INFO: &str = "paper/docs/get_folder_info";
+    pub const DOCS_LIST: &str = "paper/docs/list";
+    pub const DOCS_LIST_CONTINUE: &str = "paper/docs/list/continue";
+    pub const DOCS_PERMANENTLY_DELETE: &str = "paper/docs/permanently_delete";
+    pub const DOCS_SHARING_POLICY_GET: &str = "paper/docs/sharing_policy/get";
+    pub const DOCS_SHARING_POLICY_SET: &str = "paper/docs/sharing_policy/set";
+    pub const DOCS_UPDATE: &str = "paper/docs/update";
+    pub const DOCS_USERS_ADD: &str = "paper/docs/users/add";
+    pub const DOCS_USERS_LIST: &str = "paper/docs/users/list";
+    pub const DOCS_USERS_LIST_CONTINUE: &str = "paper/docs/users/list/continue";
+    pub const DOCS_USERS_REMOVE: &str = "paper/docs/users/remove";
+    pub const FOLDERS_CREATE: &str = "paper/folders/create";
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 #[non_exhaustive] // structs may have more fields added in the future.
 pub struct AddMember {
@@ -59,22 +82,9 @@ impl AddMember {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "member" => {
-                    if field_member.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("member"));
-                    }
-                    field_member = Some(map.next_value()?);
-                }
-                "permission_level" => {
-                    if field_permission_level.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("permission_level"));
-                    }
-                    field_permission_level = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "member" => crate::deserialize_field(&mut map, &mut field_member, "member")?,
+                "permission_level" => crate::deserialize_field(&mut map, &mut field_permission_level, "permission_level")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -186,34 +196,11 @@ impl AddPaperDocUser {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "doc_id" => {
-                    if field_doc_id.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("doc_id"));
-                    }
-                    field_doc_id = Some(map.next_value()?);
-                }
-                "members" => {
-                    if field_members.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("members"));
-                    }
-                    field_members = Some(map.next_value()?);
-                }
-                "custom_message" => {
-                    if field_custom_message.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("custom_message"));
-                    }
-                    field_custom_message = Some(map.next_value()?);
-                }
-                "quiet" => {
-                    if field_quiet.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("quiet"));
-                    }
-                    field_quiet = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "doc_id" => crate::deserialize_field(&mut map, &mut field_doc_id, "doc_id")?,
+                "members" => crate::deserialize_field(&mut map, &mut field_members, "members")?,
+                "custom_message" => crate::deserialize_field(&mut map, &mut field_custom_message, "custom_message")?,
+                "quiet" => crate::deserialize_field(&mut map, &mut field_quiet, "quiet")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -322,22 +309,9 @@ impl AddPaperDocUserMemberResult {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "member" => {
-                    if field_member.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("member"));
-                    }
-                    field_member = Some(map.next_value()?);
-                }
-                "result" => {
-                    if field_result.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("result"));
-                    }
-                    field_result = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "member" => crate::deserialize_field(&mut map, &mut field_member, "member")?,
+                "result" => crate::deserialize_field(&mut map, &mut field_result, "result")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -407,8 +381,9 @@ pub enum AddPaperDocUserResult {
     /// This user already has the correct permission to the Paper doc.
     PermissionAlreadyGranted,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for AddPaperDocUserResult {
@@ -434,7 +409,7 @@ impl<'de> ::serde::de::Deserialize<'de> for AddPaperDocUserResult {
                     "user_is_owner" => AddPaperDocUserResult::UserIsOwner,
                     "failed_user_data_retrieval" => AddPaperDocUserResult::FailedUserDataRetrieval,
                     "permission_already_granted" => AddPaperDocUserResult::PermissionAlreadyGranted,
-                    _ => AddPaperDocUserResult::Other,
+                    _ => AddPaperDocUserResult::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -499,7 +474,10 @@ impl ::serde::ser::Serialize for AddPaperDocUserResult {
                 s.serialize_field(".tag", "permission_already_granted")?;
                 s.end()
             }
-            AddPaperDocUserResult::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            AddPaperDocUserResult::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -555,22 +533,9 @@ impl Cursor {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "value" => {
-                    if field_value.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("value"));
-                    }
-                    field_value = Some(map.next_value()?);
-                }
-                "expiration" => {
-                    if field_expiration.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("expiration"));
-                    }
-                    field_expiration = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "value" => crate::deserialize_field(&mut map, &mut field_value, "value")?,
+                "expiration" => crate::deserialize_field(&mut map, &mut field_expiration, "expiration")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -635,8 +600,9 @@ pub enum DocLookupError {
     /// The required doc was not found.
     DocNotFound,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for DocLookupError {
@@ -657,7 +623,7 @@ impl<'de> ::serde::de::Deserialize<'de> for DocLookupError {
                 let value = match tag {
                     "insufficient_permissions" => DocLookupError::InsufficientPermissions,
                     "doc_not_found" => DocLookupError::DocNotFound,
-                    _ => DocLookupError::Other,
+                    _ => DocLookupError::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -687,7 +653,10 @@ impl ::serde::ser::Serialize for DocLookupError {
                 s.serialize_field(".tag", "doc_not_found")?;
                 s.end()
             }
-            DocLookupError::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            DocLookupError::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -709,7 +678,7 @@ impl From<PaperApiBaseError> for DocLookupError {
     fn from(parent: PaperApiBaseError) -> Self {
         match parent {
             PaperApiBaseError::InsufficientPermissions => DocLookupError::InsufficientPermissions,
-            PaperApiBaseError::Other => DocLookupError::Other,
+            PaperApiBaseError::Other(x) => DocLookupError::Other(x),
         }
     }
 }
@@ -802,8 +771,9 @@ pub enum ExportFormat {
     /// The markdown export format.
     Markdown,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for ExportFormat {
@@ -824,7 +794,7 @@ impl<'de> ::serde::de::Deserialize<'de> for ExportFormat {
                 let value = match tag {
                     "html" => ExportFormat::Html,
                     "markdown" => ExportFormat::Markdown,
-                    _ => ExportFormat::Other,
+                    _ => ExportFormat::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -854,7 +824,10 @@ impl ::serde::ser::Serialize for ExportFormat {
                 s.serialize_field(".tag", "markdown")?;
                 s.end()
             }
-            ExportFormat::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            ExportFormat::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -897,22 +870,9 @@ impl Folder {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "id" => {
-                    if field_id.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("id"));
-                    }
-                    field_id = Some(map.next_value()?);
-                }
-                "name" => {
-                    if field_name.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("name"));
-                    }
-                    field_name = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "id" => crate::deserialize_field(&mut map, &mut field_id, "id")?,
+                "name" => crate::deserialize_field(&mut map, &mut field_name, "name")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -1138,22 +1098,9 @@ impl FoldersContainingPaperDoc {
         let mut field_folders = None;
         while let Some(key) = map.next_key::<&str>()? {
             match key {
-                "folder_sharing_policy_type" => {
-                    if field_folder_sharing_policy_type.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("folder_sharing_policy_type"));
-                    }
-                    field_folder_sharing_policy_type = Some(map.next_value()?);
-                }
-                "folders" => {
-                    if field_folders.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("folders"));
-                    }
-                    field_folders = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "folder_sharing_policy_type" => crate::deserialize_field(&mut map, &mut field_folder_sharing_policy_type, "folder_sharing_policy_type")?,
+                "folders" => crate::deserialize_field(&mut map, &mut field_folders, "folders")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         let result = FoldersContainingPaperDoc {
@@ -1219,8 +1166,9 @@ pub enum ImportFormat {
     /// be used as the doc title.
     PlainText,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for ImportFormat {
@@ -1242,7 +1190,7 @@ impl<'de> ::serde::de::Deserialize<'de> for ImportFormat {
                     "html" => ImportFormat::Html,
                     "markdown" => ImportFormat::Markdown,
                     "plain_text" => ImportFormat::PlainText,
-                    _ => ImportFormat::Other,
+                    _ => ImportFormat::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -1279,7 +1227,10 @@ impl ::serde::ser::Serialize for ImportFormat {
                 s.serialize_field(".tag", "plain_text")?;
                 s.end()
             }
-            ImportFormat::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            ImportFormat::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -1324,22 +1275,9 @@ impl InviteeInfoWithPermissionLevel {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "invitee" => {
-                    if field_invitee.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("invitee"));
-                    }
-                    field_invitee = Some(map.next_value()?);
-                }
-                "permission_level" => {
-                    if field_permission_level.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("permission_level"));
-                    }
-                    field_permission_level = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "invitee" => crate::deserialize_field(&mut map, &mut field_invitee, "invitee")?,
+                "permission_level" => crate::deserialize_field(&mut map, &mut field_permission_level, "permission_level")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -1396,8 +1334,9 @@ impl ::serde::ser::Serialize for InviteeInfoWithPermissionLevel {
 pub enum ListDocsCursorError {
     CursorError(PaperApiCursorError),
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for ListDocsCursorError {
@@ -1423,7 +1362,7 @@ impl<'de> ::serde::de::Deserialize<'de> for ListDocsCursorError {
                             _ => return Err(de::Error::unknown_field(tag, VARIANTS))
                         }
                     }
-                    _ => ListDocsCursorError::Other,
+                    _ => ListDocsCursorError::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -1447,7 +1386,10 @@ impl ::serde::ser::Serialize for ListDocsCursorError {
                 s.serialize_field("cursor_error", x)?;
                 s.end()
             }
-            ListDocsCursorError::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            ListDocsCursorError::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -1464,7 +1406,7 @@ impl ::std::error::Error for ListDocsCursorError {
 impl ::std::fmt::Display for ListDocsCursorError {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         match self {
-            ListDocsCursorError::CursorError(inner) => write!(f, "{}", inner),
+            ListDocsCursorError::CursorError(inner) => write!(f, "cursor_error: {}", inner),
             _ => write!(f, "{:?}", *self),
         }
     }
@@ -1532,34 +1474,11 @@ impl ListPaperDocsArgs {
         let mut field_limit = None;
         while let Some(key) = map.next_key::<&str>()? {
             match key {
-                "filter_by" => {
-                    if field_filter_by.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("filter_by"));
-                    }
-                    field_filter_by = Some(map.next_value()?);
-                }
-                "sort_by" => {
-                    if field_sort_by.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("sort_by"));
-                    }
-                    field_sort_by = Some(map.next_value()?);
-                }
-                "sort_order" => {
-                    if field_sort_order.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("sort_order"));
-                    }
-                    field_sort_order = Some(map.next_value()?);
-                }
-                "limit" => {
-                    if field_limit.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("limit"));
-                    }
-                    field_limit = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "filter_by" => crate::deserialize_field(&mut map, &mut field_filter_by, "filter_by")?,
+                "sort_by" => crate::deserialize_field(&mut map, &mut field_sort_by, "sort_by")?,
+                "sort_order" => crate::deserialize_field(&mut map, &mut field_sort_order, "sort_order")?,
+                "limit" => crate::deserialize_field(&mut map, &mut field_limit, "limit")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         let result = ListPaperDocsArgs {
@@ -1653,16 +1572,8 @@ impl ListPaperDocsContinueArgs {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "cursor" => {
-                    if field_cursor.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("cursor"));
-                    }
-                    field_cursor = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "cursor" => crate::deserialize_field(&mut map, &mut field_cursor, "cursor")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -1720,8 +1631,9 @@ pub enum ListPaperDocsFilterBy {
     /// Fetches only the Paper doc IDs that the user has created.
     DocsCreated,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for ListPaperDocsFilterBy {
@@ -1742,7 +1654,7 @@ impl<'de> ::serde::de::Deserialize<'de> for ListPaperDocsFilterBy {
                 let value = match tag {
                     "docs_accessed" => ListPaperDocsFilterBy::DocsAccessed,
                     "docs_created" => ListPaperDocsFilterBy::DocsCreated,
-                    _ => ListPaperDocsFilterBy::Other,
+                    _ => ListPaperDocsFilterBy::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -1772,7 +1684,10 @@ impl ::serde::ser::Serialize for ListPaperDocsFilterBy {
                 s.serialize_field(".tag", "docs_created")?;
                 s.end()
             }
-            ListPaperDocsFilterBy::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            ListPaperDocsFilterBy::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -1826,28 +1741,10 @@ impl ListPaperDocsResponse {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "doc_ids" => {
-                    if field_doc_ids.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("doc_ids"));
-                    }
-                    field_doc_ids = Some(map.next_value()?);
-                }
-                "cursor" => {
-                    if field_cursor.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("cursor"));
-                    }
-                    field_cursor = Some(map.next_value()?);
-                }
-                "has_more" => {
-                    if field_has_more.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("has_more"));
-                    }
-                    field_has_more = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "doc_ids" => crate::deserialize_field(&mut map, &mut field_doc_ids, "doc_ids")?,
+                "cursor" => crate::deserialize_field(&mut map, &mut field_cursor, "cursor")?,
+                "has_more" => crate::deserialize_field(&mut map, &mut field_has_more, "has_more")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -1911,8 +1808,9 @@ pub enum ListPaperDocsSortBy {
     /// Sorts the Paper docs by the creation time.
     Created,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for ListPaperDocsSortBy {
@@ -1934,7 +1832,7 @@ impl<'de> ::serde::de::Deserialize<'de> for ListPaperDocsSortBy {
                     "accessed" => ListPaperDocsSortBy::Accessed,
                     "modified" => ListPaperDocsSortBy::Modified,
                     "created" => ListPaperDocsSortBy::Created,
-                    _ => ListPaperDocsSortBy::Other,
+                    _ => ListPaperDocsSortBy::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -1971,7 +1869,10 @@ impl ::serde::ser::Serialize for ListPaperDocsSortBy {
                 s.serialize_field(".tag", "created")?;
                 s.end()
             }
-            ListPaperDocsSortBy::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            ListPaperDocsSortBy::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -1984,8 +1885,9 @@ pub enum ListPaperDocsSortOrder {
     /// Sorts the search result in descending order.
     Descending,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for ListPaperDocsSortOrder {
@@ -2006,7 +1908,7 @@ impl<'de> ::serde::de::Deserialize<'de> for ListPaperDocsSortOrder {
                 let value = match tag {
                     "ascending" => ListPaperDocsSortOrder::Ascending,
                     "descending" => ListPaperDocsSortOrder::Descending,
-                    _ => ListPaperDocsSortOrder::Other,
+                    _ => ListPaperDocsSortOrder::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -2036,7 +1938,10 @@ impl ::serde::ser::Serialize for ListPaperDocsSortOrder {
                 s.serialize_field(".tag", "descending")?;
                 s.end()
             }
-            ListPaperDocsSortOrder::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            ListPaperDocsSortOrder::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -2053,8 +1958,9 @@ pub enum ListUsersCursorError {
     DocNotFound,
     CursorError(PaperApiCursorError),
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for ListUsersCursorError {
@@ -2082,7 +1988,7 @@ impl<'de> ::serde::de::Deserialize<'de> for ListUsersCursorError {
                             _ => return Err(de::Error::unknown_field(tag, VARIANTS))
                         }
                     }
-                    _ => ListUsersCursorError::Other,
+                    _ => ListUsersCursorError::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -2120,7 +2026,10 @@ impl ::serde::ser::Serialize for ListUsersCursorError {
                 s.serialize_field("cursor_error", x)?;
                 s.end()
             }
-            ListUsersCursorError::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            ListUsersCursorError::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -2138,7 +2047,7 @@ impl ::std::fmt::Display for ListUsersCursorError {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         match self {
             ListUsersCursorError::DocNotFound => f.write_str("The required doc was not found."),
-            ListUsersCursorError::CursorError(inner) => write!(f, "{}", inner),
+            ListUsersCursorError::CursorError(inner) => write!(f, "cursor_error: {}", inner),
             _ => write!(f, "{:?}", *self),
         }
     }
@@ -2149,7 +2058,7 @@ impl From<PaperApiBaseError> for ListUsersCursorError {
     fn from(parent: PaperApiBaseError) -> Self {
         match parent {
             PaperApiBaseError::InsufficientPermissions => ListUsersCursorError::InsufficientPermissions,
-            PaperApiBaseError::Other => ListUsersCursorError::Other,
+            PaperApiBaseError::Other(x) => ListUsersCursorError::Other(x),
         }
     }
 }
@@ -2196,22 +2105,9 @@ impl ListUsersOnFolderArgs {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "doc_id" => {
-                    if field_doc_id.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("doc_id"));
-                    }
-                    field_doc_id = Some(map.next_value()?);
-                }
-                "limit" => {
-                    if field_limit.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("limit"));
-                    }
-                    field_limit = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "doc_id" => crate::deserialize_field(&mut map, &mut field_doc_id, "doc_id")?,
+                "limit" => crate::deserialize_field(&mut map, &mut field_limit, "limit")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -2312,22 +2208,9 @@ impl ListUsersOnFolderContinueArgs {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "doc_id" => {
-                    if field_doc_id.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("doc_id"));
-                    }
-                    field_doc_id = Some(map.next_value()?);
-                }
-                "cursor" => {
-                    if field_cursor.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("cursor"));
-                    }
-                    field_cursor = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "doc_id" => crate::deserialize_field(&mut map, &mut field_doc_id, "doc_id")?,
+                "cursor" => crate::deserialize_field(&mut map, &mut field_cursor, "cursor")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -2446,34 +2329,11 @@ impl ListUsersOnFolderResponse {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "invitees" => {
-                    if field_invitees.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("invitees"));
-                    }
-                    field_invitees = Some(map.next_value()?);
-                }
-                "users" => {
-                    if field_users.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("users"));
-                    }
-                    field_users = Some(map.next_value()?);
-                }
-                "cursor" => {
-                    if field_cursor.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("cursor"));
-                    }
-                    field_cursor = Some(map.next_value()?);
-                }
-                "has_more" => {
-                    if field_has_more.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("has_more"));
-                    }
-                    field_has_more = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "invitees" => crate::deserialize_field(&mut map, &mut field_invitees, "invitees")?,
+                "users" => crate::deserialize_field(&mut map, &mut field_users, "users")?,
+                "cursor" => crate::deserialize_field(&mut map, &mut field_cursor, "cursor")?,
+                "has_more" => crate::deserialize_field(&mut map, &mut field_has_more, "has_more")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -2582,28 +2442,10 @@ impl ListUsersOnPaperDocArgs {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "doc_id" => {
-                    if field_doc_id.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("doc_id"));
-                    }
-                    field_doc_id = Some(map.next_value()?);
-                }
-                "limit" => {
-                    if field_limit.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("limit"));
-                    }
-                    field_limit = Some(map.next_value()?);
-                }
-                "filter_by" => {
-                    if field_filter_by.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("filter_by"));
-                    }
-                    field_filter_by = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "doc_id" => crate::deserialize_field(&mut map, &mut field_doc_id, "doc_id")?,
+                "limit" => crate::deserialize_field(&mut map, &mut field_limit, "limit")?,
+                "filter_by" => crate::deserialize_field(&mut map, &mut field_filter_by, "filter_by")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -2708,22 +2550,9 @@ impl ListUsersOnPaperDocContinueArgs {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "doc_id" => {
-                    if field_doc_id.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("doc_id"));
-                    }
-                    field_doc_id = Some(map.next_value()?);
-                }
-                "cursor" => {
-                    if field_cursor.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("cursor"));
-                    }
-                    field_cursor = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "doc_id" => crate::deserialize_field(&mut map, &mut field_doc_id, "doc_id")?,
+                "cursor" => crate::deserialize_field(&mut map, &mut field_cursor, "cursor")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -2847,40 +2676,12 @@ impl ListUsersOnPaperDocResponse {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "invitees" => {
-                    if field_invitees.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("invitees"));
-                    }
-                    field_invitees = Some(map.next_value()?);
-                }
-                "users" => {
-                    if field_users.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("users"));
-                    }
-                    field_users = Some(map.next_value()?);
-                }
-                "doc_owner" => {
-                    if field_doc_owner.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("doc_owner"));
-                    }
-                    field_doc_owner = Some(map.next_value()?);
-                }
-                "cursor" => {
-                    if field_cursor.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("cursor"));
-                    }
-                    field_cursor = Some(map.next_value()?);
-                }
-                "has_more" => {
-                    if field_has_more.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("has_more"));
-                    }
-                    field_has_more = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "invitees" => crate::deserialize_field(&mut map, &mut field_invitees, "invitees")?,
+                "users" => crate::deserialize_field(&mut map, &mut field_users, "users")?,
+                "doc_owner" => crate::deserialize_field(&mut map, &mut field_doc_owner, "doc_owner")?,
+                "cursor" => crate::deserialize_field(&mut map, &mut field_cursor, "cursor")?,
+                "has_more" => crate::deserialize_field(&mut map, &mut field_has_more, "has_more")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -2947,8 +2748,9 @@ pub enum PaperApiBaseError {
     /// Guide](https://www.dropbox.com/lp/developers/reference/paper-migration-guide).
     InsufficientPermissions,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for PaperApiBaseError {
@@ -2968,7 +2770,7 @@ impl<'de> ::serde::de::Deserialize<'de> for PaperApiBaseError {
                 };
                 let value = match tag {
                     "insufficient_permissions" => PaperApiBaseError::InsufficientPermissions,
-                    _ => PaperApiBaseError::Other,
+                    _ => PaperApiBaseError::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -2991,7 +2793,10 @@ impl ::serde::ser::Serialize for PaperApiBaseError {
                 s.serialize_field(".tag", "insufficient_permissions")?;
                 s.end()
             }
-            PaperApiBaseError::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            PaperApiBaseError::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -3018,8 +2823,9 @@ pub enum PaperApiCursorError {
     /// to obtain a new cursor.
     Reset,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for PaperApiCursorError {
@@ -3042,7 +2848,7 @@ impl<'de> ::serde::de::Deserialize<'de> for PaperApiCursorError {
                     "invalid_cursor" => PaperApiCursorError::InvalidCursor,
                     "wrong_user_in_cursor" => PaperApiCursorError::WrongUserInCursor,
                     "reset" => PaperApiCursorError::Reset,
-                    _ => PaperApiCursorError::Other,
+                    _ => PaperApiCursorError::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -3086,7 +2892,10 @@ impl ::serde::ser::Serialize for PaperApiCursorError {
                 s.serialize_field(".tag", "reset")?;
                 s.end()
             }
-            PaperApiCursorError::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            PaperApiCursorError::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -3149,22 +2958,9 @@ impl PaperDocCreateArgs {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "import_format" => {
-                    if field_import_format.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("import_format"));
-                    }
-                    field_import_format = Some(map.next_value()?);
-                }
-                "parent_folder_id" => {
-                    if field_parent_folder_id.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("parent_folder_id"));
-                    }
-                    field_parent_folder_id = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "import_format" => crate::deserialize_field(&mut map, &mut field_import_format, "import_format")?,
+                "parent_folder_id" => crate::deserialize_field(&mut map, &mut field_parent_folder_id, "parent_folder_id")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -3236,8 +3032,9 @@ pub enum PaperDocCreateError {
     /// only applies to HTML with data URI.
     ImageSizeExceeded,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for PaperDocCreateError {
@@ -3261,7 +3058,7 @@ impl<'de> ::serde::de::Deserialize<'de> for PaperDocCreateError {
                     "folder_not_found" => PaperDocCreateError::FolderNotFound,
                     "doc_length_exceeded" => PaperDocCreateError::DocLengthExceeded,
                     "image_size_exceeded" => PaperDocCreateError::ImageSizeExceeded,
-                    _ => PaperDocCreateError::Other,
+                    _ => PaperDocCreateError::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -3312,7 +3109,10 @@ impl ::serde::ser::Serialize for PaperDocCreateError {
                 s.serialize_field(".tag", "image_size_exceeded")?;
                 s.end()
             }
-            PaperDocCreateError::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            PaperDocCreateError::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -3337,7 +3137,7 @@ impl From<PaperApiBaseError> for PaperDocCreateError {
     fn from(parent: PaperApiBaseError) -> Self {
         match parent {
             PaperApiBaseError::InsufficientPermissions => PaperDocCreateError::InsufficientPermissions,
-            PaperApiBaseError::Other => PaperDocCreateError::Other,
+            PaperApiBaseError::Other(x) => PaperDocCreateError::Other(x),
         }
     }
 }
@@ -3383,28 +3183,10 @@ impl PaperDocCreateUpdateResult {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "doc_id" => {
-                    if field_doc_id.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("doc_id"));
-                    }
-                    field_doc_id = Some(map.next_value()?);
-                }
-                "revision" => {
-                    if field_revision.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("revision"));
-                    }
-                    field_revision = Some(map.next_value()?);
-                }
-                "title" => {
-                    if field_title.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("title"));
-                    }
-                    field_title = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "doc_id" => crate::deserialize_field(&mut map, &mut field_doc_id, "doc_id")?,
+                "revision" => crate::deserialize_field(&mut map, &mut field_revision, "revision")?,
+                "title" => crate::deserialize_field(&mut map, &mut field_title, "title")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -3494,22 +3276,9 @@ impl PaperDocExport {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "doc_id" => {
-                    if field_doc_id.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("doc_id"));
-                    }
-                    field_doc_id = Some(map.next_value()?);
-                }
-                "export_format" => {
-                    if field_export_format.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("export_format"));
-                    }
-                    field_export_format = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "doc_id" => crate::deserialize_field(&mut map, &mut field_doc_id, "doc_id")?,
+                "export_format" => crate::deserialize_field(&mut map, &mut field_export_format, "export_format")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -3617,34 +3386,11 @@ impl PaperDocExportResult {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "owner" => {
-                    if field_owner.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("owner"));
-                    }
-                    field_owner = Some(map.next_value()?);
-                }
-                "title" => {
-                    if field_title.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("title"));
-                    }
-                    field_title = Some(map.next_value()?);
-                }
-                "revision" => {
-                    if field_revision.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("revision"));
-                    }
-                    field_revision = Some(map.next_value()?);
-                }
-                "mime_type" => {
-                    if field_mime_type.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("mime_type"));
-                    }
-                    field_mime_type = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "owner" => crate::deserialize_field(&mut map, &mut field_owner, "owner")?,
+                "title" => crate::deserialize_field(&mut map, &mut field_title, "title")?,
+                "revision" => crate::deserialize_field(&mut map, &mut field_revision, "revision")?,
+                "mime_type" => crate::deserialize_field(&mut map, &mut field_mime_type, "mime_type")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -3708,8 +3454,9 @@ pub enum PaperDocPermissionLevel {
     /// User will be granted view and comment permissions.
     ViewAndComment,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for PaperDocPermissionLevel {
@@ -3730,7 +3477,7 @@ impl<'de> ::serde::de::Deserialize<'de> for PaperDocPermissionLevel {
                 let value = match tag {
                     "edit" => PaperDocPermissionLevel::Edit,
                     "view_and_comment" => PaperDocPermissionLevel::ViewAndComment,
-                    _ => PaperDocPermissionLevel::Other,
+                    _ => PaperDocPermissionLevel::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -3760,7 +3507,10 @@ impl ::serde::ser::Serialize for PaperDocPermissionLevel {
                 s.serialize_field(".tag", "view_and_comment")?;
                 s.end()
             }
-            PaperDocPermissionLevel::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            PaperDocPermissionLevel::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -3802,22 +3552,9 @@ impl PaperDocSharingPolicy {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "doc_id" => {
-                    if field_doc_id.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("doc_id"));
-                    }
-                    field_doc_id = Some(map.next_value()?);
-                }
-                "sharing_policy" => {
-                    if field_sharing_policy.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("sharing_policy"));
-                    }
-                    field_sharing_policy = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "doc_id" => crate::deserialize_field(&mut map, &mut field_doc_id, "doc_id")?,
+                "sharing_policy" => crate::deserialize_field(&mut map, &mut field_sharing_policy, "sharing_policy")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -3930,34 +3667,11 @@ impl PaperDocUpdateArgs {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "doc_id" => {
-                    if field_doc_id.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("doc_id"));
-                    }
-                    field_doc_id = Some(map.next_value()?);
-                }
-                "doc_update_policy" => {
-                    if field_doc_update_policy.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("doc_update_policy"));
-                    }
-                    field_doc_update_policy = Some(map.next_value()?);
-                }
-                "revision" => {
-                    if field_revision.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("revision"));
-                    }
-                    field_revision = Some(map.next_value()?);
-                }
-                "import_format" => {
-                    if field_import_format.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("import_format"));
-                    }
-                    field_import_format = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "doc_id" => crate::deserialize_field(&mut map, &mut field_doc_id, "doc_id")?,
+                "doc_update_policy" => crate::deserialize_field(&mut map, &mut field_doc_update_policy, "doc_update_policy")?,
+                "revision" => crate::deserialize_field(&mut map, &mut field_revision, "revision")?,
+                "import_format" => crate::deserialize_field(&mut map, &mut field_import_format, "import_format")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -4045,8 +3759,9 @@ pub enum PaperDocUpdateError {
     /// This operation is not allowed on deleted Paper docs.
     DocDeleted,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for PaperDocUpdateError {
@@ -4073,7 +3788,7 @@ impl<'de> ::serde::de::Deserialize<'de> for PaperDocUpdateError {
                     "image_size_exceeded" => PaperDocUpdateError::ImageSizeExceeded,
                     "doc_archived" => PaperDocUpdateError::DocArchived,
                     "doc_deleted" => PaperDocUpdateError::DocDeleted,
-                    _ => PaperDocUpdateError::Other,
+                    _ => PaperDocUpdateError::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -4145,7 +3860,10 @@ impl ::serde::ser::Serialize for PaperDocUpdateError {
                 s.serialize_field(".tag", "doc_deleted")?;
                 s.end()
             }
-            PaperDocUpdateError::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            PaperDocUpdateError::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -4173,7 +3891,7 @@ impl From<DocLookupError> for PaperDocUpdateError {
     fn from(parent: DocLookupError) -> Self {
         match parent {
             DocLookupError::InsufficientPermissions => PaperDocUpdateError::InsufficientPermissions,
-            DocLookupError::Other => PaperDocUpdateError::Other,
+            DocLookupError::Other(x) => PaperDocUpdateError::Other(x),
             DocLookupError::DocNotFound => PaperDocUpdateError::DocNotFound,
         }
     }
@@ -4188,8 +3906,9 @@ pub enum PaperDocUpdatePolicy {
     /// The document will be overwitten at the head with the provided content.
     OverwriteAll,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for PaperDocUpdatePolicy {
@@ -4211,7 +3930,7 @@ impl<'de> ::serde::de::Deserialize<'de> for PaperDocUpdatePolicy {
                     "append" => PaperDocUpdatePolicy::Append,
                     "prepend" => PaperDocUpdatePolicy::Prepend,
                     "overwrite_all" => PaperDocUpdatePolicy::OverwriteAll,
-                    _ => PaperDocUpdatePolicy::Other,
+                    _ => PaperDocUpdatePolicy::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -4248,7 +3967,10 @@ impl ::serde::ser::Serialize for PaperDocUpdatePolicy {
                 s.serialize_field(".tag", "overwrite_all")?;
                 s.end()
             }
-            PaperDocUpdatePolicy::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            PaperDocUpdatePolicy::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -4310,28 +4032,10 @@ impl PaperFolderCreateArg {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "name" => {
-                    if field_name.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("name"));
-                    }
-                    field_name = Some(map.next_value()?);
-                }
-                "parent_folder_id" => {
-                    if field_parent_folder_id.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("parent_folder_id"));
-                    }
-                    field_parent_folder_id = Some(map.next_value()?);
-                }
-                "is_team_folder" => {
-                    if field_is_team_folder.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("is_team_folder"));
-                    }
-                    field_is_team_folder = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "name" => crate::deserialize_field(&mut map, &mut field_name, "name")?,
+                "parent_folder_id" => crate::deserialize_field(&mut map, &mut field_parent_folder_id, "parent_folder_id")?,
+                "is_team_folder" => crate::deserialize_field(&mut map, &mut field_is_team_folder, "is_team_folder")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -4402,8 +4106,9 @@ pub enum PaperFolderCreateError {
     /// The folder id cannot be decrypted to valid folder id.
     InvalidFolderId,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for PaperFolderCreateError {
@@ -4425,7 +4130,7 @@ impl<'de> ::serde::de::Deserialize<'de> for PaperFolderCreateError {
                     "insufficient_permissions" => PaperFolderCreateError::InsufficientPermissions,
                     "folder_not_found" => PaperFolderCreateError::FolderNotFound,
                     "invalid_folder_id" => PaperFolderCreateError::InvalidFolderId,
-                    _ => PaperFolderCreateError::Other,
+                    _ => PaperFolderCreateError::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -4462,7 +4167,10 @@ impl ::serde::ser::Serialize for PaperFolderCreateError {
                 s.serialize_field(".tag", "invalid_folder_id")?;
                 s.end()
             }
-            PaperFolderCreateError::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            PaperFolderCreateError::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -4485,7 +4193,7 @@ impl From<PaperApiBaseError> for PaperFolderCreateError {
     fn from(parent: PaperApiBaseError) -> Self {
         match parent {
             PaperApiBaseError::InsufficientPermissions => PaperFolderCreateError::InsufficientPermissions,
-            PaperApiBaseError::Other => PaperFolderCreateError::Other,
+            PaperApiBaseError::Other(x) => PaperFolderCreateError::Other(x),
         }
     }
 }
@@ -4521,16 +4229,8 @@ impl PaperFolderCreateResult {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "folder_id" => {
-                    if field_folder_id.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("folder_id"));
-                    }
-                    field_folder_id = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "folder_id" => crate::deserialize_field(&mut map, &mut field_folder_id, "folder_id")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -4612,16 +4312,8 @@ impl RefPaperDoc {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "doc_id" => {
-                    if field_doc_id.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("doc_id"));
-                    }
-                    field_doc_id = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "doc_id" => crate::deserialize_field(&mut map, &mut field_doc_id, "doc_id")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -4709,22 +4401,9 @@ impl RemovePaperDocUser {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "doc_id" => {
-                    if field_doc_id.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("doc_id"));
-                    }
-                    field_doc_id = Some(map.next_value()?);
-                }
-                "member" => {
-                    if field_member.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("member"));
-                    }
-                    field_member = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "doc_id" => crate::deserialize_field(&mut map, &mut field_doc_id, "doc_id")?,
+                "member" => crate::deserialize_field(&mut map, &mut field_member, "member")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -4817,22 +4496,9 @@ impl SharingPolicy {
         let mut field_team_sharing_policy = None;
         while let Some(key) = map.next_key::<&str>()? {
             match key {
-                "public_sharing_policy" => {
-                    if field_public_sharing_policy.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("public_sharing_policy"));
-                    }
-                    field_public_sharing_policy = Some(map.next_value()?);
-                }
-                "team_sharing_policy" => {
-                    if field_team_sharing_policy.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("team_sharing_policy"));
-                    }
-                    field_team_sharing_policy = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "public_sharing_policy" => crate::deserialize_field(&mut map, &mut field_public_sharing_policy, "public_sharing_policy")?,
+                "team_sharing_policy" => crate::deserialize_field(&mut map, &mut field_team_sharing_policy, "team_sharing_policy")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         let result = SharingPolicy {
@@ -5084,22 +4750,9 @@ impl UserInfoWithPermissionLevel {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "user" => {
-                    if field_user.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("user"));
-                    }
-                    field_user = Some(map.next_value()?);
-                }
-                "permission_level" => {
-                    if field_permission_level.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("permission_level"));
-                    }
-                    field_permission_level = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "user" => crate::deserialize_field(&mut map, &mut field_user, "user")?,
+                "permission_level" => crate::deserialize_field(&mut map, &mut field_permission_level, "permission_level")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -5160,8 +4813,9 @@ pub enum UserOnPaperDocFilter {
     /// doc as well as those who have not.
     Shared,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for UserOnPaperDocFilter {
@@ -5182,7 +4836,7 @@ impl<'de> ::serde::de::Deserialize<'de> for UserOnPaperDocFilter {
                 let value = match tag {
                     "visited" => UserOnPaperDocFilter::Visited,
                     "shared" => UserOnPaperDocFilter::Shared,
-                    _ => UserOnPaperDocFilter::Other,
+                    _ => UserOnPaperDocFilter::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -5212,7 +4866,10 @@ impl ::serde::ser::Serialize for UserOnPaperDocFilter {
                 s.serialize_field(".tag", "shared")?;
                 s.end()
             }
-            UserOnPaperDocFilter::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            UserOnPaperDocFilter::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -45,22 +45,9 @@ impl SecondaryEmail {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "email" => {
-                    if field_email.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("email"));
-                    }
-                    field_email = Some(map.next_value()?);
-                }
-                "is_verified" => {
-                    if field_is_verified.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("is_verified"));
-                    }
-                    field_is_verified = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "email" => crate::deserialize_field(&mut map, &mut field_email, "email")?,
+                "is_verified" => crate::deserialize_field(&mut map, &mut field_is_verified, "is_verified")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
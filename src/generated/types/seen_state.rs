@@ -28,8 +28,9 @@ pub enum PlatformType {
     /// instead.
     Mobile,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for PlatformType {
@@ -55,7 +56,7 @@ impl<'de> ::serde::de::Deserialize<'de> for PlatformType {
                     "api" => PlatformType::Api,
                     "unknown" => PlatformType::Unknown,
                     "mobile" => PlatformType::Mobile,
-                    _ => PlatformType::Other,
+                    _ => PlatformType::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -120,7 +121,10 @@ impl ::serde::ser::Serialize for PlatformType {
                 s.serialize_field(".tag", "mobile")?;
                 s.end()
             }
-            PlatformType::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            PlatformType::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -20,6 +20,54 @@ pub type ReadPath = crate::types::files::ReadPath;
 pub type Rev = crate::types::files::Rev;
 pub type TeamInfo = crate::types::users::Team;
 
+/// The names of this namespace's routes, as they appear in request URLs after the
+/// [`Endpoint`](crate::client_trait_common::Endpoint) base URL, for referring to them in logs and
+/// metrics without spelling them out.
+pub mod routes {
+    pub const ADD_FILE_MEMBER: &str = "sharing/add_file_member";
+    pub const ADD_FOLDER_MEMBER: &str = "sharing/add_folder_member";
+    pub const CHECK_JOB_STATUS: &str = "sharing/check_job_status";
+    pub const CHECK_REMOVE_MEMBER_JOB_STATUS: &str = "sharing/check_remove_member_job_status";
+    pub const CHECK_SHARE_JOB_STATUS: &str = "sharing/check_share_job_status";
+    pub const CREATE_SHARED_LINK: &str = "sharing/create_shared_link";
+    pub const CREATE_SHARED_LINK_WITH_SETTINGS: &str = "sharing/create_shared_link_with_settings";
+    pub const GET_FILE_METADATA: &str = "sharing/get_file_metadata";
+    pub const GET_FILE_METADATA_BATCH: &str = "sharing/get_file_metadata/batch";
+    pub const GET_FOLDER_METADATA: &str = "sharing/get_folder_metadata";
+    pub const GET_SHARED_LINK_FILE: &str = "sharing/get_shared_link_file";
+    pub const GET_SHARED_LINK_METADATA: &str = "sharing/get_shared_link_metadata";
+    pub const GET_SHARED_LINKS: &str = "sharing/get_shared_links";
+    pub const LIST_FILE_MEMBERS: &str = "sharing/list_file_members";
+    pub const LIST_FILE_MEMBERS_BATCH: &str = "sharing/list_file_members/batch";
+    pub const LIST_FILE_MEMBERS_CONTINUE: &str = "sharing/list_file_members/continue";
+    pub const LIST_FOLDER_MEMBERS: &str = "sharing/list_folder_members";
+    pub const LIST_FOLDER_MEMBERS_CONTINUE: &str = "sharing/list_folder_members/continue";
+    pub const LIST_FOLDERS: &str = "sharing/list_folders";
+    pub const LIST_FOLDERS_CONTINUE: &str = "sharing/list_folders/continue";
+    pub const LIST_MOUNTABLE_FOLDERS: &str = "sharing/list_mountable_folders";
+    pub const LIST_MOUNTABLE_FOLDERS_CONTINUE: &str = "sharing/list_mountable_folders/continue";
+    pub const LIST_RECEIVED_FILES: &str = "sharing/list_received_files";
+    pub const LIST_RECEIVED_FILES_CONTINUE: &str = "sharing/list_received_files/continue";
+    pub const LIST_SHARED_LINKS: &str = "sharing/list_shared_links";
+    pub const MODIFY_SHARED_LINK_SETTINGS: &str = "sharing/modify_shared_link_settings";
+    pub const MOUNT_FOLDER: &str = "sharing/mount_folder";
+    pub const RELINQUISH_FILE_MEMBERSHIP: &str = "sharing/relinquish_file_membership";
+    pub const RELINQUISH_FOLDER_MEMBERSHIP: &str = "sharing/relinquish_folder_membership";
+    pub const REMOVE_FILE_MEMBER: &str = "sharing/remove_file_member";
+    pub const REMOVE_FILE_MEMBER_2: &str = "sharing/remove_file_member_2";
+    pub const REMOVE_FOLDER_MEMBER: &str = "sharing/remove_folder_member";
+    pub const REVOKE_SHARED_LINK: &str = "sharing/revoke_shared_link";
+    pub const SET_ACCESS_INHERITANCE: &str = "sharing/set_access_inheritance";
+    pub const SHARE_FOLDER: &str = "sharing/share_folder";
+    pub const TRANSFER_FOLDER: &str = "sharing/transfer_folder";
+    pub const UNMOUNT_FOLDER: &str = "sharing/unmount_folder";
+    pub const UNSHARE_FILE: &str = "sharing/unshare_file";
+    pub const UNSHARE_FOLDER: &str = "sharing/unshare_folder";
+    pub const UPDATE_FILE_MEMBER: &str = "sharing/update_file_member";
+    pub const UPDATE_FOLDER_MEMBER: &str = "sharing/update_folder_member";
+    pub const UPDATE_FOLDER_POLICY: &str = "sharing/update_folder_policy";
+}
+
 /// Information about the inheritance policy of a shared folder.
 #[derive(Debug, Clone, PartialEq, Eq)]
 #[non_exhaustive] // variants may be added in the future
@@ -29,8 +77,9 @@ pub enum AccessInheritance {
     /// The shared folder does not inherit its members from the parent folder.
     NoInherit,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for AccessInheritance {
@@ -51,7 +100,7 @@ impl<'de> ::serde::de::Deserialize<'de> for AccessInheritance {
                 let value = match tag {
                     "inherit" => AccessInheritance::Inherit,
                     "no_inherit" => AccessInheritance::NoInherit,
-                    _ => AccessInheritance::Other,
+                    _ => AccessInheritance::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -81,7 +130,10 @@ impl ::serde::ser::Serialize for AccessInheritance {
                 s.serialize_field(".tag", "no_inherit")?;
                 s.end()
             }
-            AccessInheritance::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            AccessInheritance::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -107,8 +159,9 @@ pub enum AccessLevel {
     /// link access even though the user themselves are not a member on the shared folder yet.
     NoAccess,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for AccessLevel {
@@ -133,7 +186,7 @@ impl<'de> ::serde::de::Deserialize<'de> for AccessLevel {
                     "viewer_no_comment" => AccessLevel::ViewerNoComment,
                     "traverse" => AccessLevel::Traverse,
                     "no_access" => AccessLevel::NoAccess,
-                    _ => AccessLevel::Other,
+                    _ => AccessLevel::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -191,7 +244,10 @@ impl ::serde::ser::Serialize for AccessLevel {
                 s.serialize_field(".tag", "no_access")?;
                 s.end()
             }
-            AccessLevel::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            AccessLevel::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -206,8 +262,9 @@ pub enum AclUpdatePolicy {
     /// Any editor can update the ACL. This may be further restricted to editors on the same team.
     Editors,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for AclUpdatePolicy {
@@ -228,7 +285,7 @@ impl<'de> ::serde::de::Deserialize<'de> for AclUpdatePolicy {
                 let value = match tag {
                     "owner" => AclUpdatePolicy::Owner,
                     "editors" => AclUpdatePolicy::Editors,
-                    _ => AclUpdatePolicy::Other,
+                    _ => AclUpdatePolicy::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -258,7 +315,10 @@ impl ::serde::ser::Serialize for AclUpdatePolicy {
                 s.serialize_field(".tag", "editors")?;
                 s.end()
             }
-            AclUpdatePolicy::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            AclUpdatePolicy::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -343,46 +403,13 @@ impl AddFileMemberArgs {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "file" => {
-                    if field_file.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("file"));
-                    }
-                    field_file = Some(map.next_value()?);
-                }
-                "members" => {
-                    if field_members.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("members"));
-                    }
-                    field_members = Some(map.next_value()?);
-                }
-                "custom_message" => {
-                    if field_custom_message.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("custom_message"));
-                    }
-                    field_custom_message = Some(map.next_value()?);
-                }
-                "quiet" => {
-                    if field_quiet.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("quiet"));
-                    }
-                    field_quiet = Some(map.next_value()?);
-                }
-                "access_level" => {
-                    if field_access_level.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("access_level"));
-                    }
-                    field_access_level = Some(map.next_value()?);
-                }
-                "add_message_as_comment" => {
-                    if field_add_message_as_comment.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("add_message_as_comment"));
-                    }
-                    field_add_message_as_comment = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "file" => crate::deserialize_field(&mut map, &mut field_file, "file")?,
+                "members" => crate::deserialize_field(&mut map, &mut field_members, "members")?,
+                "custom_message" => crate::deserialize_field(&mut map, &mut field_custom_message, "custom_message")?,
+                "quiet" => crate::deserialize_field(&mut map, &mut field_quiet, "quiet")?,
+                "access_level" => crate::deserialize_field(&mut map, &mut field_access_level, "access_level")?,
+                "add_message_as_comment" => crate::deserialize_field(&mut map, &mut field_add_message_as_comment, "add_message_as_comment")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -461,8 +488,9 @@ pub enum AddFileMemberError {
     /// The custom message did not pass comment permissions checks.
     InvalidComment,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for AddFileMemberError {
@@ -497,7 +525,7 @@ impl<'de> ::serde::de::Deserialize<'de> for AddFileMemberError {
                     }
                     "rate_limit" => AddFileMemberError::RateLimit,
                     "invalid_comment" => AddFileMemberError::InvalidComment,
-                    _ => AddFileMemberError::Other,
+                    _ => AddFileMemberError::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -543,7 +571,10 @@ impl ::serde::ser::Serialize for AddFileMemberError {
                 s.serialize_field(".tag", "invalid_comment")?;
                 s.end()
             }
-            AddFileMemberError::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            AddFileMemberError::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -561,8 +592,8 @@ impl ::std::error::Error for AddFileMemberError {
 impl ::std::fmt::Display for AddFileMemberError {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         match self {
-            AddFileMemberError::UserError(inner) => write!(f, "{}", inner),
-            AddFileMemberError::AccessError(inner) => write!(f, "{}", inner),
+            AddFileMemberError::UserError(inner) => write!(f, "user_error: {}", inner),
+            AddFileMemberError::AccessError(inner) => write!(f, "access_error: {}", inner),
             AddFileMemberError::RateLimit => f.write_str("The user has reached the rate limit for invitations."),
             AddFileMemberError::InvalidComment => f.write_str("The custom message did not pass comment permissions checks."),
             _ => write!(f, "{:?}", *self),
@@ -631,34 +662,11 @@ impl AddFolderMemberArg {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "shared_folder_id" => {
-                    if field_shared_folder_id.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("shared_folder_id"));
-                    }
-                    field_shared_folder_id = Some(map.next_value()?);
-                }
-                "members" => {
-                    if field_members.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("members"));
-                    }
-                    field_members = Some(map.next_value()?);
-                }
-                "quiet" => {
-                    if field_quiet.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("quiet"));
-                    }
-                    field_quiet = Some(map.next_value()?);
-                }
-                "custom_message" => {
-                    if field_custom_message.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("custom_message"));
-                    }
-                    field_custom_message = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "shared_folder_id" => crate::deserialize_field(&mut map, &mut field_shared_folder_id, "shared_folder_id")?,
+                "members" => crate::deserialize_field(&mut map, &mut field_members, "members")?,
+                "quiet" => crate::deserialize_field(&mut map, &mut field_quiet, "quiet")?,
+                "custom_message" => crate::deserialize_field(&mut map, &mut field_custom_message, "custom_message")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -752,8 +760,9 @@ pub enum AddFolderMemberError {
     /// Invalid shared folder error will be returned as an access_error.
     InvalidSharedFolder,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for AddFolderMemberError {
@@ -809,7 +818,7 @@ impl<'de> ::serde::de::Deserialize<'de> for AddFolderMemberError {
                     "team_folder" => AddFolderMemberError::TeamFolder,
                     "no_permission" => AddFolderMemberError::NoPermission,
                     "invalid_shared_folder" => AddFolderMemberError::InvalidSharedFolder,
-                    _ => AddFolderMemberError::Other,
+                    _ => AddFolderMemberError::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -920,7 +929,10 @@ impl ::serde::ser::Serialize for AddFolderMemberError {
                 s.serialize_field(".tag", "invalid_shared_folder")?;
                 s.end()
             }
-            AddFolderMemberError::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            AddFolderMemberError::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -940,7 +952,7 @@ impl ::std::fmt::Display for AddFolderMemberError {
         match self {
             AddFolderMemberError::AccessError(inner) => write!(f, "Unable to access shared folder: {}", inner),
             AddFolderMemberError::BannedMember => f.write_str("The current user has been banned."),
-            AddFolderMemberError::BadMember(inner) => write!(f, "{}", inner),
+            AddFolderMemberError::BadMember(inner) => write!(f, "bad_member: {}", inner),
             AddFolderMemberError::CantShareOutsideTeam => f.write_str("Your team policy does not allow sharing outside of the team."),
             AddFolderMemberError::TooManyMembers(inner) => write!(f, "The value is the member limit that was reached: {:?}", inner),
             AddFolderMemberError::TooManyPendingInvites(inner) => write!(f, "The value is the pending invite limit that was reached: {:?}", inner),
@@ -999,22 +1011,9 @@ impl AddMember {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "member" => {
-                    if field_member.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("member"));
-                    }
-                    field_member = Some(map.next_value()?);
-                }
-                "access_level" => {
-                    if field_access_level.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("access_level"));
-                    }
-                    field_access_level = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "member" => crate::deserialize_field(&mut map, &mut field_member, "member")?,
+                "access_level" => crate::deserialize_field(&mut map, &mut field_access_level, "access_level")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -1086,8 +1085,9 @@ pub enum AddMemberSelectorError {
     /// Sharing to a group that is not on the current user's team.
     GroupNotOnTeam,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for AddMemberSelectorError {
@@ -1130,7 +1130,7 @@ impl<'de> ::serde::de::Deserialize<'de> for AddMemberSelectorError {
                     }
                     "group_deleted" => AddMemberSelectorError::GroupDeleted,
                     "group_not_on_team" => AddMemberSelectorError::GroupNotOnTeam,
-                    _ => AddMemberSelectorError::Other,
+                    _ => AddMemberSelectorError::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -1191,7 +1191,10 @@ impl ::serde::ser::Serialize for AddMemberSelectorError {
                 s.serialize_field(".tag", "group_not_on_team")?;
                 s.end()
             }
-            AddMemberSelectorError::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            AddMemberSelectorError::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -1235,8 +1238,9 @@ pub enum AlphaResolvedVisibility {
     /// Only the current user can view this link.
     OnlyYou,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for AlphaResolvedVisibility {
@@ -1262,7 +1266,7 @@ impl<'de> ::serde::de::Deserialize<'de> for AlphaResolvedVisibility {
                     "shared_folder_only" => AlphaResolvedVisibility::SharedFolderOnly,
                     "no_one" => AlphaResolvedVisibility::NoOne,
                     "only_you" => AlphaResolvedVisibility::OnlyYou,
-                    _ => AlphaResolvedVisibility::Other,
+                    _ => AlphaResolvedVisibility::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -1327,7 +1331,10 @@ impl ::serde::ser::Serialize for AlphaResolvedVisibility {
                 s.serialize_field(".tag", "only_you")?;
                 s.end()
             }
-            AlphaResolvedVisibility::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            AlphaResolvedVisibility::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -1343,7 +1350,7 @@ impl From<ResolvedVisibility> for AlphaResolvedVisibility {
             ResolvedVisibility::SharedFolderOnly => AlphaResolvedVisibility::SharedFolderOnly,
             ResolvedVisibility::NoOne => AlphaResolvedVisibility::NoOne,
             ResolvedVisibility::OnlyYou => AlphaResolvedVisibility::OnlyYou,
-            ResolvedVisibility::Other => AlphaResolvedVisibility::Other,
+            ResolvedVisibility::Other(x) => AlphaResolvedVisibility::Other(x),
         }
     }
 }
@@ -1380,16 +1387,8 @@ impl AudienceExceptionContentInfo {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "name" => {
-                    if field_name.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("name"));
-                    }
-                    field_name = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "name" => crate::deserialize_field(&mut map, &mut field_name, "name")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -1479,22 +1478,9 @@ impl AudienceExceptions {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "count" => {
-                    if field_count.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("count"));
-                    }
-                    field_count = Some(map.next_value()?);
-                }
-                "exceptions" => {
-                    if field_exceptions.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("exceptions"));
-                    }
-                    field_exceptions = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "count" => crate::deserialize_field(&mut map, &mut field_count, "count")?,
+                "exceptions" => crate::deserialize_field(&mut map, &mut field_exceptions, "exceptions")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -1594,28 +1580,10 @@ impl AudienceRestrictingSharedFolder {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "shared_folder_id" => {
-                    if field_shared_folder_id.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("shared_folder_id"));
-                    }
-                    field_shared_folder_id = Some(map.next_value()?);
-                }
-                "name" => {
-                    if field_name.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("name"));
-                    }
-                    field_name = Some(map.next_value()?);
-                }
-                "audience" => {
-                    if field_audience.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("audience"));
-                    }
-                    field_audience = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "shared_folder_id" => crate::deserialize_field(&mut map, &mut field_shared_folder_id, "shared_folder_id")?,
+                "name" => crate::deserialize_field(&mut map, &mut field_name, "name")?,
+                "audience" => crate::deserialize_field(&mut map, &mut field_audience, "audience")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -1717,28 +1685,10 @@ impl CollectionLinkMetadata {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "url" => {
-                    if field_url.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("url"));
-                    }
-                    field_url = Some(map.next_value()?);
-                }
-                "visibility" => {
-                    if field_visibility.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("visibility"));
-                    }
-                    field_visibility = Some(map.next_value()?);
-                }
-                "expires" => {
-                    if field_expires.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("expires"));
-                    }
-                    field_expires = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "url" => crate::deserialize_field(&mut map, &mut field_url, "url")?,
+                "visibility" => crate::deserialize_field(&mut map, &mut field_visibility, "visibility")?,
+                "expires" => crate::deserialize_field(&mut map, &mut field_expires, "expires")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -1854,28 +1804,10 @@ impl CreateSharedLinkArg {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "path" => {
-                    if field_path.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("path"));
-                    }
-                    field_path = Some(map.next_value()?);
-                }
-                "short_url" => {
-                    if field_short_url.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("short_url"));
-                    }
-                    field_short_url = Some(map.next_value()?);
-                }
-                "pending_upload" => {
-                    if field_pending_upload.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("pending_upload"));
-                    }
-                    field_pending_upload = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "path" => crate::deserialize_field(&mut map, &mut field_path, "path")?,
+                "short_url" => crate::deserialize_field(&mut map, &mut field_short_url, "short_url")?,
+                "pending_upload" => crate::deserialize_field(&mut map, &mut field_pending_upload, "pending_upload")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -1938,8 +1870,9 @@ impl ::serde::ser::Serialize for CreateSharedLinkArg {
 pub enum CreateSharedLinkError {
     Path(crate::types::files::LookupError),
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for CreateSharedLinkError {
@@ -1965,7 +1898,7 @@ impl<'de> ::serde::de::Deserialize<'de> for CreateSharedLinkError {
                             _ => return Err(de::Error::unknown_field(tag, VARIANTS))
                         }
                     }
-                    _ => CreateSharedLinkError::Other,
+                    _ => CreateSharedLinkError::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -1989,7 +1922,10 @@ impl ::serde::ser::Serialize for CreateSharedLinkError {
                 s.serialize_field("path", x)?;
                 s.end()
             }
-            CreateSharedLinkError::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            CreateSharedLinkError::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -2006,7 +1942,7 @@ impl ::std::error::Error for CreateSharedLinkError {
 impl ::std::fmt::Display for CreateSharedLinkError {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         match self {
-            CreateSharedLinkError::Path(inner) => write!(f, "{}", inner),
+            CreateSharedLinkError::Path(inner) => write!(f, "path: {}", inner),
             _ => write!(f, "{:?}", *self),
         }
     }
@@ -2054,22 +1990,9 @@ impl CreateSharedLinkWithSettingsArg {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "path" => {
-                    if field_path.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("path"));
-                    }
-                    field_path = Some(map.next_value()?);
-                }
-                "settings" => {
-                    if field_settings.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("settings"));
-                    }
-                    field_settings = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "path" => crate::deserialize_field(&mut map, &mut field_path, "path")?,
+                "settings" => crate::deserialize_field(&mut map, &mut field_settings, "settings")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -2251,7 +2174,7 @@ impl ::std::error::Error for CreateSharedLinkWithSettingsError {
 impl ::std::fmt::Display for CreateSharedLinkWithSettingsError {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         match self {
-            CreateSharedLinkWithSettingsError::Path(inner) => write!(f, "{}", inner),
+            CreateSharedLinkWithSettingsError::Path(inner) => write!(f, "path: {}", inner),
             CreateSharedLinkWithSettingsError::SharedLinkAlreadyExists(None) => f.write_str("shared_link_already_exists"),
             CreateSharedLinkWithSettingsError::SharedLinkAlreadyExists(Some(inner)) => write!(f, "shared_link_already_exists: {:?}", inner),
             CreateSharedLinkWithSettingsError::SettingsError(inner) => write!(f, "There is an error with the given settings: {}", inner),
@@ -2351,52 +2274,14 @@ impl ExpectedSharedContentLinkMetadata {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "audience_options" => {
-                    if field_audience_options.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("audience_options"));
-                    }
-                    field_audience_options = Some(map.next_value()?);
-                }
-                "current_audience" => {
-                    if field_current_audience.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("current_audience"));
-                    }
-                    field_current_audience = Some(map.next_value()?);
-                }
-                "link_permissions" => {
-                    if field_link_permissions.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("link_permissions"));
-                    }
-                    field_link_permissions = Some(map.next_value()?);
-                }
-                "password_protected" => {
-                    if field_password_protected.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("password_protected"));
-                    }
-                    field_password_protected = Some(map.next_value()?);
-                }
-                "access_level" => {
-                    if field_access_level.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("access_level"));
-                    }
-                    field_access_level = Some(map.next_value()?);
-                }
-                "audience_restricting_shared_folder" => {
-                    if field_audience_restricting_shared_folder.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("audience_restricting_shared_folder"));
-                    }
-                    field_audience_restricting_shared_folder = Some(map.next_value()?);
-                }
-                "expiry" => {
-                    if field_expiry.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("expiry"));
-                    }
-                    field_expiry = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "audience_options" => crate::deserialize_field(&mut map, &mut field_audience_options, "audience_options")?,
+                "current_audience" => crate::deserialize_field(&mut map, &mut field_current_audience, "current_audience")?,
+                "link_permissions" => crate::deserialize_field(&mut map, &mut field_link_permissions, "link_permissions")?,
+                "password_protected" => crate::deserialize_field(&mut map, &mut field_password_protected, "password_protected")?,
+                "access_level" => crate::deserialize_field(&mut map, &mut field_access_level, "access_level")?,
+                "audience_restricting_shared_folder" => crate::deserialize_field(&mut map, &mut field_audience_restricting_shared_folder, "audience_restricting_shared_folder")?,
+                "expiry" => crate::deserialize_field(&mut map, &mut field_expiry, "expiry")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -2507,8 +2392,9 @@ pub enum FileAction {
     /// Create a shared link to a file that allows users to edit the content.
     CreateEditLink,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for FileAction {
@@ -2539,7 +2425,7 @@ impl<'de> ::serde::de::Deserialize<'de> for FileAction {
                     "create_link" => FileAction::CreateLink,
                     "create_view_link" => FileAction::CreateViewLink,
                     "create_edit_link" => FileAction::CreateEditLink,
-                    _ => FileAction::Other,
+                    _ => FileAction::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -2639,7 +2525,10 @@ impl ::serde::ser::Serialize for FileAction {
                 s.serialize_field(".tag", "create_edit_link")?;
                 s.end()
             }
-            FileAction::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            FileAction::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -2654,8 +2543,9 @@ pub enum FileErrorResult {
     /// User does not have permission to access file specified by file.Id.
     PermissionDeniedError(crate::types::files::Id),
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for FileErrorResult {
@@ -2695,7 +2585,7 @@ impl<'de> ::serde::de::Deserialize<'de> for FileErrorResult {
                             _ => return Err(de::Error::unknown_field(tag, VARIANTS))
                         }
                     }
-                    _ => FileErrorResult::Other,
+                    _ => FileErrorResult::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -2735,7 +2625,10 @@ impl ::serde::ser::Serialize for FileErrorResult {
                 s.serialize_field("permission_denied_error", x)?;
                 s.end()
             }
-            FileErrorResult::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            FileErrorResult::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -2869,82 +2762,19 @@ impl FileLinkMetadata {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "url" => {
-                    if field_url.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("url"));
-                    }
-                    field_url = Some(map.next_value()?);
-                }
-                "name" => {
-                    if field_name.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("name"));
-                    }
-                    field_name = Some(map.next_value()?);
-                }
-                "link_permissions" => {
-                    if field_link_permissions.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("link_permissions"));
-                    }
-                    field_link_permissions = Some(map.next_value()?);
-                }
-                "client_modified" => {
-                    if field_client_modified.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("client_modified"));
-                    }
-                    field_client_modified = Some(map.next_value()?);
-                }
-                "server_modified" => {
-                    if field_server_modified.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("server_modified"));
-                    }
-                    field_server_modified = Some(map.next_value()?);
-                }
-                "rev" => {
-                    if field_rev.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("rev"));
-                    }
-                    field_rev = Some(map.next_value()?);
-                }
-                "size" => {
-                    if field_size.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("size"));
-                    }
-                    field_size = Some(map.next_value()?);
-                }
-                "id" => {
-                    if field_id.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("id"));
-                    }
-                    field_id = Some(map.next_value()?);
-                }
-                "expires" => {
-                    if field_expires.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("expires"));
-                    }
-                    field_expires = Some(map.next_value()?);
-                }
-                "path_lower" => {
-                    if field_path_lower.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("path_lower"));
-                    }
-                    field_path_lower = Some(map.next_value()?);
-                }
-                "team_member_info" => {
-                    if field_team_member_info.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("team_member_info"));
-                    }
-                    field_team_member_info = Some(map.next_value()?);
-                }
-                "content_owner_team_info" => {
-                    if field_content_owner_team_info.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("content_owner_team_info"));
-                    }
-                    field_content_owner_team_info = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "url" => crate::deserialize_field(&mut map, &mut field_url, "url")?,
+                "name" => crate::deserialize_field(&mut map, &mut field_name, "name")?,
+                "link_permissions" => crate::deserialize_field(&mut map, &mut field_link_permissions, "link_permissions")?,
+                "client_modified" => crate::deserialize_field(&mut map, &mut field_client_modified, "client_modified")?,
+                "server_modified" => crate::deserialize_field(&mut map, &mut field_server_modified, "server_modified")?,
+                "rev" => crate::deserialize_field(&mut map, &mut field_rev, "rev")?,
+                "size" => crate::deserialize_field(&mut map, &mut field_size, "size")?,
+                "id" => crate::deserialize_field(&mut map, &mut field_id, "id")?,
+                "expires" => crate::deserialize_field(&mut map, &mut field_expires, "expires")?,
+                "path_lower" => crate::deserialize_field(&mut map, &mut field_path_lower, "path_lower")?,
+                "team_member_info" => crate::deserialize_field(&mut map, &mut field_team_member_info, "team_member_info")?,
+                "content_owner_team_info" => crate::deserialize_field(&mut map, &mut field_content_owner_team_info, "content_owner_team_info")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -3046,8 +2876,9 @@ pub enum FileMemberActionError {
     /// folder.
     NoExplicitAccess(MemberAccessLevelResult),
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for FileMemberActionError {
@@ -3076,7 +2907,7 @@ impl<'de> ::serde::de::Deserialize<'de> for FileMemberActionError {
                         }
                     }
                     "no_explicit_access" => FileMemberActionError::NoExplicitAccess(MemberAccessLevelResult::internal_deserialize(&mut map)?),
-                    _ => FileMemberActionError::Other,
+                    _ => FileMemberActionError::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -3122,7 +2953,10 @@ impl ::serde::ser::Serialize for FileMemberActionError {
                 x.internal_serialize::<S>(&mut s)?;
                 s.end()
             }
-            FileMemberActionError::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            FileMemberActionError::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -3283,34 +3117,11 @@ impl FileMemberActionResult {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "member" => {
-                    if field_member.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("member"));
-                    }
-                    field_member = Some(map.next_value()?);
-                }
-                "result" => {
-                    if field_result.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("result"));
-                    }
-                    field_result = Some(map.next_value()?);
-                }
-                "sckey_sha1" => {
-                    if field_sckey_sha1.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("sckey_sha1"));
-                    }
-                    field_sckey_sha1 = Some(map.next_value()?);
-                }
-                "invitation_signature" => {
-                    if field_invitation_signature.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("invitation_signature"));
-                    }
-                    field_invitation_signature = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "member" => crate::deserialize_field(&mut map, &mut field_member, "member")?,
+                "result" => crate::deserialize_field(&mut map, &mut field_result, "result")?,
+                "sckey_sha1" => crate::deserialize_field(&mut map, &mut field_sckey_sha1, "sckey_sha1")?,
+                "invitation_signature" => crate::deserialize_field(&mut map, &mut field_invitation_signature, "invitation_signature")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -3378,8 +3189,9 @@ pub enum FileMemberRemoveActionResult {
     /// User was not able to remove this member.
     MemberError(FileMemberActionError),
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for FileMemberRemoveActionResult {
@@ -3406,7 +3218,7 @@ impl<'de> ::serde::de::Deserialize<'de> for FileMemberRemoveActionResult {
                             _ => return Err(de::Error::unknown_field(tag, VARIANTS))
                         }
                     }
-                    _ => FileMemberRemoveActionResult::Other,
+                    _ => FileMemberRemoveActionResult::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -3438,7 +3250,10 @@ impl ::serde::ser::Serialize for FileMemberRemoveActionResult {
                 s.serialize_field("member_error", x)?;
                 s.end()
             }
-            FileMemberRemoveActionResult::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            FileMemberRemoveActionResult::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -3491,28 +3306,10 @@ impl FilePermission {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "action" => {
-                    if field_action.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("action"));
-                    }
-                    field_action = Some(map.next_value()?);
-                }
-                "allow" => {
-                    if field_allow.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("allow"));
-                    }
-                    field_allow = Some(map.next_value()?);
-                }
-                "reason" => {
-                    if field_reason.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("reason"));
-                    }
-                    field_reason = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "action" => crate::deserialize_field(&mut map, &mut field_action, "action")?,
+                "allow" => crate::deserialize_field(&mut map, &mut field_allow, "allow")?,
+                "reason" => crate::deserialize_field(&mut map, &mut field_reason, "reason")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -3601,8 +3398,9 @@ pub enum FolderAction {
     /// Set whether the folder inherits permissions from its parent.
     SetAccessInheritance,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for FolderAction {
@@ -3635,7 +3433,7 @@ impl<'de> ::serde::de::Deserialize<'de> for FolderAction {
                     "share_link" => FolderAction::ShareLink,
                     "create_link" => FolderAction::CreateLink,
                     "set_access_inheritance" => FolderAction::SetAccessInheritance,
-                    _ => FolderAction::Other,
+                    _ => FolderAction::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -3749,7 +3547,10 @@ impl ::serde::ser::Serialize for FolderAction {
                 s.serialize_field(".tag", "set_access_inheritance")?;
                 s.end()
             }
-            FolderAction::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            FolderAction::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -3851,58 +3652,15 @@ impl FolderLinkMetadata {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "url" => {
-                    if field_url.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("url"));
-                    }
-                    field_url = Some(map.next_value()?);
-                }
-                "name" => {
-                    if field_name.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("name"));
-                    }
-                    field_name = Some(map.next_value()?);
-                }
-                "link_permissions" => {
-                    if field_link_permissions.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("link_permissions"));
-                    }
-                    field_link_permissions = Some(map.next_value()?);
-                }
-                "id" => {
-                    if field_id.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("id"));
-                    }
-                    field_id = Some(map.next_value()?);
-                }
-                "expires" => {
-                    if field_expires.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("expires"));
-                    }
-                    field_expires = Some(map.next_value()?);
-                }
-                "path_lower" => {
-                    if field_path_lower.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("path_lower"));
-                    }
-                    field_path_lower = Some(map.next_value()?);
-                }
-                "team_member_info" => {
-                    if field_team_member_info.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("team_member_info"));
-                    }
-                    field_team_member_info = Some(map.next_value()?);
-                }
-                "content_owner_team_info" => {
-                    if field_content_owner_team_info.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("content_owner_team_info"));
-                    }
-                    field_content_owner_team_info = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "url" => crate::deserialize_field(&mut map, &mut field_url, "url")?,
+                "name" => crate::deserialize_field(&mut map, &mut field_name, "name")?,
+                "link_permissions" => crate::deserialize_field(&mut map, &mut field_link_permissions, "link_permissions")?,
+                "id" => crate::deserialize_field(&mut map, &mut field_id, "id")?,
+                "expires" => crate::deserialize_field(&mut map, &mut field_expires, "expires")?,
+                "path_lower" => crate::deserialize_field(&mut map, &mut field_path_lower, "path_lower")?,
+                "team_member_info" => crate::deserialize_field(&mut map, &mut field_team_member_info, "team_member_info")?,
+                "content_owner_team_info" => crate::deserialize_field(&mut map, &mut field_content_owner_team_info, "content_owner_team_info")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -4031,28 +3789,10 @@ impl FolderPermission {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "action" => {
-                    if field_action.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("action"));
-                    }
-                    field_action = Some(map.next_value()?);
-                }
-                "allow" => {
-                    if field_allow.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("allow"));
-                    }
-                    field_allow = Some(map.next_value()?);
-                }
-                "reason" => {
-                    if field_reason.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("reason"));
-                    }
-                    field_reason = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "action" => crate::deserialize_field(&mut map, &mut field_action, "action")?,
+                "allow" => crate::deserialize_field(&mut map, &mut field_allow, "allow")?,
+                "reason" => crate::deserialize_field(&mut map, &mut field_reason, "reason")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -4180,40 +3920,12 @@ impl FolderPolicy {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "acl_update_policy" => {
-                    if field_acl_update_policy.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("acl_update_policy"));
-                    }
-                    field_acl_update_policy = Some(map.next_value()?);
-                }
-                "shared_link_policy" => {
-                    if field_shared_link_policy.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("shared_link_policy"));
-                    }
-                    field_shared_link_policy = Some(map.next_value()?);
-                }
-                "member_policy" => {
-                    if field_member_policy.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("member_policy"));
-                    }
-                    field_member_policy = Some(map.next_value()?);
-                }
-                "resolved_member_policy" => {
-                    if field_resolved_member_policy.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("resolved_member_policy"));
-                    }
-                    field_resolved_member_policy = Some(map.next_value()?);
-                }
-                "viewer_info_policy" => {
-                    if field_viewer_info_policy.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("viewer_info_policy"));
-                    }
-                    field_viewer_info_policy = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "acl_update_policy" => crate::deserialize_field(&mut map, &mut field_acl_update_policy, "acl_update_policy")?,
+                "shared_link_policy" => crate::deserialize_field(&mut map, &mut field_shared_link_policy, "shared_link_policy")?,
+                "member_policy" => crate::deserialize_field(&mut map, &mut field_member_policy, "member_policy")?,
+                "resolved_member_policy" => crate::deserialize_field(&mut map, &mut field_resolved_member_policy, "resolved_member_policy")?,
+                "viewer_info_policy" => crate::deserialize_field(&mut map, &mut field_viewer_info_policy, "viewer_info_policy")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -4322,22 +4034,9 @@ impl GetFileMetadataArg {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "file" => {
-                    if field_file.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("file"));
-                    }
-                    field_file = Some(map.next_value()?);
-                }
-                "actions" => {
-                    if field_actions.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("actions"));
-                    }
-                    field_actions = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "file" => crate::deserialize_field(&mut map, &mut field_file, "file")?,
+                "actions" => crate::deserialize_field(&mut map, &mut field_actions, "actions")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -4436,22 +4135,9 @@ impl GetFileMetadataBatchArg {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "files" => {
-                    if field_files.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("files"));
-                    }
-                    field_files = Some(map.next_value()?);
-                }
-                "actions" => {
-                    if field_actions.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("actions"));
-                    }
-                    field_actions = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "files" => crate::deserialize_field(&mut map, &mut field_files, "files")?,
+                "actions" => crate::deserialize_field(&mut map, &mut field_actions, "actions")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -4544,22 +4230,9 @@ impl GetFileMetadataBatchResult {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "file" => {
-                    if field_file.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("file"));
-                    }
-                    field_file = Some(map.next_value()?);
-                }
-                "result" => {
-                    if field_result.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("result"));
-                    }
-                    field_result = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "file" => crate::deserialize_field(&mut map, &mut field_file, "file")?,
+                "result" => crate::deserialize_field(&mut map, &mut field_result, "result")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -4618,8 +4291,9 @@ pub enum GetFileMetadataError {
     UserError(SharingUserError),
     AccessError(SharingFileAccessError),
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for GetFileMetadataError {
@@ -4652,7 +4326,7 @@ impl<'de> ::serde::de::Deserialize<'de> for GetFileMetadataError {
                             _ => return Err(de::Error::unknown_field(tag, VARIANTS))
                         }
                     }
-                    _ => GetFileMetadataError::Other,
+                    _ => GetFileMetadataError::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -4684,7 +4358,10 @@ impl ::serde::ser::Serialize for GetFileMetadataError {
                 s.serialize_field("access_error", x)?;
                 s.end()
             }
-            GetFileMetadataError::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            GetFileMetadataError::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -4702,8 +4379,8 @@ impl ::std::error::Error for GetFileMetadataError {
 impl ::std::fmt::Display for GetFileMetadataError {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         match self {
-            GetFileMetadataError::UserError(inner) => write!(f, "{}", inner),
-            GetFileMetadataError::AccessError(inner) => write!(f, "{}", inner),
+            GetFileMetadataError::UserError(inner) => write!(f, "user_error: {}", inner),
+            GetFileMetadataError::AccessError(inner) => write!(f, "access_error: {}", inner),
             _ => write!(f, "{:?}", *self),
         }
     }
@@ -4717,8 +4394,9 @@ pub enum GetFileMetadataIndividualResult {
     /// The result for this file if it was an error.
     AccessError(SharingFileAccessError),
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for GetFileMetadataIndividualResult {
@@ -4745,7 +4423,7 @@ impl<'de> ::serde::de::Deserialize<'de> for GetFileMetadataIndividualResult {
                             _ => return Err(de::Error::unknown_field(tag, VARIANTS))
                         }
                     }
-                    _ => GetFileMetadataIndividualResult::Other,
+                    _ => GetFileMetadataIndividualResult::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -4777,7 +4455,10 @@ impl ::serde::ser::Serialize for GetFileMetadataIndividualResult {
                 s.serialize_field("access_error", x)?;
                 s.end()
             }
-            GetFileMetadataIndividualResult::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            GetFileMetadataIndividualResult::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -4826,22 +4507,9 @@ impl GetMetadataArgs {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "shared_folder_id" => {
-                    if field_shared_folder_id.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("shared_folder_id"));
-                    }
-                    field_shared_folder_id = Some(map.next_value()?);
-                }
-                "actions" => {
-                    if field_actions.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("actions"));
-                    }
-                    field_actions = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "shared_folder_id" => crate::deserialize_field(&mut map, &mut field_shared_folder_id, "shared_folder_id")?,
+                "actions" => crate::deserialize_field(&mut map, &mut field_actions, "actions")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -4907,8 +4575,9 @@ pub enum GetSharedLinkFileError {
     /// Directories cannot be retrieved by this endpoint.
     SharedLinkIsDirectory,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for GetSharedLinkFileError {
@@ -4931,7 +4600,7 @@ impl<'de> ::serde::de::Deserialize<'de> for GetSharedLinkFileError {
                     "shared_link_access_denied" => GetSharedLinkFileError::SharedLinkAccessDenied,
                     "unsupported_link_type" => GetSharedLinkFileError::UnsupportedLinkType,
                     "shared_link_is_directory" => GetSharedLinkFileError::SharedLinkIsDirectory,
-                    _ => GetSharedLinkFileError::Other,
+                    _ => GetSharedLinkFileError::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -4975,7 +4644,10 @@ impl ::serde::ser::Serialize for GetSharedLinkFileError {
                 s.serialize_field(".tag", "shared_link_is_directory")?;
                 s.end()
             }
-            GetSharedLinkFileError::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            GetSharedLinkFileError::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -5001,7 +4673,7 @@ impl From<SharedLinkError> for GetSharedLinkFileError {
             SharedLinkError::SharedLinkNotFound => GetSharedLinkFileError::SharedLinkNotFound,
             SharedLinkError::SharedLinkAccessDenied => GetSharedLinkFileError::SharedLinkAccessDenied,
             SharedLinkError::UnsupportedLinkType => GetSharedLinkFileError::UnsupportedLinkType,
-            SharedLinkError::Other => GetSharedLinkFileError::Other,
+            SharedLinkError::Other(x) => GetSharedLinkFileError::Other(x),
         }
     }
 }
@@ -5058,28 +4730,10 @@ impl GetSharedLinkMetadataArg {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "url" => {
-                    if field_url.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("url"));
-                    }
-                    field_url = Some(map.next_value()?);
-                }
-                "path" => {
-                    if field_path.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("path"));
-                    }
-                    field_path = Some(map.next_value()?);
-                }
-                "link_password" => {
-                    if field_link_password.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("link_password"));
-                    }
-                    field_link_password = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "url" => crate::deserialize_field(&mut map, &mut field_url, "url")?,
+                "path" => crate::deserialize_field(&mut map, &mut field_path, "path")?,
+                "link_password" => crate::deserialize_field(&mut map, &mut field_link_password, "link_password")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -5160,16 +4814,8 @@ impl GetSharedLinksArg {
         let mut field_path = None;
         while let Some(key) = map.next_key::<&str>()? {
             match key {
-                "path" => {
-                    if field_path.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("path"));
-                    }
-                    field_path = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "path" => crate::deserialize_field(&mut map, &mut field_path, "path")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         let result = GetSharedLinksArg {
@@ -5223,8 +4869,9 @@ impl ::serde::ser::Serialize for GetSharedLinksArg {
 pub enum GetSharedLinksError {
     Path(crate::types::files::MalformedPathError),
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for GetSharedLinksError {
@@ -5250,7 +4897,7 @@ impl<'de> ::serde::de::Deserialize<'de> for GetSharedLinksError {
                             _ => return Err(de::Error::unknown_field(tag, VARIANTS))
                         }
                     }
-                    _ => GetSharedLinksError::Other,
+                    _ => GetSharedLinksError::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -5277,7 +4924,10 @@ impl ::serde::ser::Serialize for GetSharedLinksError {
                 }
                 s.end()
             }
-            GetSharedLinksError::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            GetSharedLinksError::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -5326,16 +4976,8 @@ impl GetSharedLinksResult {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "links" => {
-                    if field_links.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("links"));
-                    }
-                    field_links = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "links" => crate::deserialize_field(&mut map, &mut field_links, "links")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -5478,64 +5120,16 @@ impl GroupInfo {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "group_name" => {
-                    if field_group_name.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("group_name"));
-                    }
-                    field_group_name = Some(map.next_value()?);
-                }
-                "group_id" => {
-                    if field_group_id.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("group_id"));
-                    }
-                    field_group_id = Some(map.next_value()?);
-                }
-                "group_management_type" => {
-                    if field_group_management_type.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("group_management_type"));
-                    }
-                    field_group_management_type = Some(map.next_value()?);
-                }
-                "group_type" => {
-                    if field_group_type.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("group_type"));
-                    }
-                    field_group_type = Some(map.next_value()?);
-                }
-                "is_member" => {
-                    if field_is_member.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("is_member"));
-                    }
-                    field_is_member = Some(map.next_value()?);
-                }
-                "is_owner" => {
-                    if field_is_owner.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("is_owner"));
-                    }
-                    field_is_owner = Some(map.next_value()?);
-                }
-                "same_team" => {
-                    if field_same_team.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("same_team"));
-                    }
-                    field_same_team = Some(map.next_value()?);
-                }
-                "group_external_id" => {
-                    if field_group_external_id.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("group_external_id"));
-                    }
-                    field_group_external_id = Some(map.next_value()?);
-                }
-                "member_count" => {
-                    if field_member_count.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("member_count"));
-                    }
-                    field_member_count = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "group_name" => crate::deserialize_field(&mut map, &mut field_group_name, "group_name")?,
+                "group_id" => crate::deserialize_field(&mut map, &mut field_group_id, "group_id")?,
+                "group_management_type" => crate::deserialize_field(&mut map, &mut field_group_management_type, "group_management_type")?,
+                "group_type" => crate::deserialize_field(&mut map, &mut field_group_type, "group_type")?,
+                "is_member" => crate::deserialize_field(&mut map, &mut field_is_member, "is_member")?,
+                "is_owner" => crate::deserialize_field(&mut map, &mut field_is_owner, "is_owner")?,
+                "same_team" => crate::deserialize_field(&mut map, &mut field_same_team, "same_team")?,
+                "group_external_id" => crate::deserialize_field(&mut map, &mut field_group_external_id, "group_external_id")?,
+                "member_count" => crate::deserialize_field(&mut map, &mut field_member_count, "member_count")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -5687,40 +5281,12 @@ impl GroupMembershipInfo {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "access_type" => {
-                    if field_access_type.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("access_type"));
-                    }
-                    field_access_type = Some(map.next_value()?);
-                }
-                "group" => {
-                    if field_group.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("group"));
-                    }
-                    field_group = Some(map.next_value()?);
-                }
-                "permissions" => {
-                    if field_permissions.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("permissions"));
-                    }
-                    field_permissions = Some(map.next_value()?);
-                }
-                "initials" => {
-                    if field_initials.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("initials"));
-                    }
-                    field_initials = Some(map.next_value()?);
-                }
-                "is_inherited" => {
-                    if field_is_inherited.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("is_inherited"));
-                    }
-                    field_is_inherited = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "access_type" => crate::deserialize_field(&mut map, &mut field_access_type, "access_type")?,
+                "group" => crate::deserialize_field(&mut map, &mut field_group, "group")?,
+                "permissions" => crate::deserialize_field(&mut map, &mut field_permissions, "permissions")?,
+                "initials" => crate::deserialize_field(&mut map, &mut field_initials, "initials")?,
+                "is_inherited" => crate::deserialize_field(&mut map, &mut field_is_inherited, "is_inherited")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -5838,22 +5404,9 @@ impl InsufficientPlan {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "message" => {
-                    if field_message.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("message"));
-                    }
-                    field_message = Some(map.next_value()?);
-                }
-                "upsell_url" => {
-                    if field_upsell_url.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("upsell_url"));
-                    }
-                    field_upsell_url = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "message" => crate::deserialize_field(&mut map, &mut field_message, "message")?,
+                "upsell_url" => crate::deserialize_field(&mut map, &mut field_upsell_url, "upsell_url")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -5949,28 +5502,10 @@ impl InsufficientQuotaAmounts {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "space_needed" => {
-                    if field_space_needed.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("space_needed"));
-                    }
-                    field_space_needed = Some(map.next_value()?);
-                }
-                "space_shortage" => {
-                    if field_space_shortage.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("space_shortage"));
-                    }
-                    field_space_shortage = Some(map.next_value()?);
-                }
-                "space_left" => {
-                    if field_space_left.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("space_left"));
-                    }
-                    field_space_left = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "space_needed" => crate::deserialize_field(&mut map, &mut field_space_needed, "space_needed")?,
+                "space_shortage" => crate::deserialize_field(&mut map, &mut field_space_shortage, "space_shortage")?,
+                "space_left" => crate::deserialize_field(&mut map, &mut field_space_left, "space_left")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -6031,8 +5566,9 @@ pub enum InviteeInfo {
     /// Email address of invited user.
     Email(crate::types::common::EmailAddress),
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for InviteeInfo {
@@ -6058,7 +5594,7 @@ impl<'de> ::serde::de::Deserialize<'de> for InviteeInfo {
                             _ => return Err(de::Error::unknown_field(tag, VARIANTS))
                         }
                     }
-                    _ => InviteeInfo::Other,
+                    _ => InviteeInfo::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -6082,7 +5618,10 @@ impl ::serde::ser::Serialize for InviteeInfo {
                 s.serialize_field("email", x)?;
                 s.end()
             }
-            InviteeInfo::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            InviteeInfo::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -6167,46 +5706,13 @@ impl InviteeMembershipInfo {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "access_type" => {
-                    if field_access_type.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("access_type"));
-                    }
-                    field_access_type = Some(map.next_value()?);
-                }
-                "invitee" => {
-                    if field_invitee.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("invitee"));
-                    }
-                    field_invitee = Some(map.next_value()?);
-                }
-                "permissions" => {
-                    if field_permissions.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("permissions"));
-                    }
-                    field_permissions = Some(map.next_value()?);
-                }
-                "initials" => {
-                    if field_initials.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("initials"));
-                    }
-                    field_initials = Some(map.next_value()?);
-                }
-                "is_inherited" => {
-                    if field_is_inherited.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("is_inherited"));
-                    }
-                    field_is_inherited = Some(map.next_value()?);
-                }
-                "user" => {
-                    if field_user.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("user"));
-                    }
-                    field_user = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "access_type" => crate::deserialize_field(&mut map, &mut field_access_type, "access_type")?,
+                "invitee" => crate::deserialize_field(&mut map, &mut field_invitee, "invitee")?,
+                "permissions" => crate::deserialize_field(&mut map, &mut field_permissions, "permissions")?,
+                "initials" => crate::deserialize_field(&mut map, &mut field_initials, "initials")?,
+                "is_inherited" => crate::deserialize_field(&mut map, &mut field_is_inherited, "is_inherited")?,
+                "user" => crate::deserialize_field(&mut map, &mut field_user, "user")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -6300,8 +5806,9 @@ pub enum JobError {
     /// [`relinquish_folder_membership()`](crate::sharing::relinquish_folder_membership) action.
     RelinquishFolderMembershipError(RelinquishFolderMembershipError),
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for JobError {
@@ -6341,7 +5848,7 @@ impl<'de> ::serde::de::Deserialize<'de> for JobError {
                             _ => return Err(de::Error::unknown_field(tag, VARIANTS))
                         }
                     }
-                    _ => JobError::Other,
+                    _ => JobError::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -6381,7 +5888,10 @@ impl ::serde::ser::Serialize for JobError {
                 s.serialize_field("relinquish_folder_membership_error", x)?;
                 s.end()
             }
-            JobError::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            JobError::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -6400,9 +5910,9 @@ impl ::std::error::Error for JobError {
 impl ::std::fmt::Display for JobError {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         match self {
-            JobError::UnshareFolderError(inner) => write!(f, "{}", inner),
-            JobError::RemoveFolderMemberError(inner) => write!(f, "{}", inner),
-            JobError::RelinquishFolderMembershipError(inner) => write!(f, "{}", inner),
+            JobError::UnshareFolderError(inner) => write!(f, "unshare_folder_error: {}", inner),
+            JobError::RemoveFolderMemberError(inner) => write!(f, "remove_folder_member_error: {}", inner),
+            JobError::RelinquishFolderMembershipError(inner) => write!(f, "relinquish_folder_membership_error: {}", inner),
             _ => write!(f, "{:?}", *self),
         }
     }
@@ -6500,8 +6010,9 @@ pub enum LinkAccessLevel {
     /// Users who use the link can edit, view and comment on the content.
     Editor,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for LinkAccessLevel {
@@ -6522,7 +6033,7 @@ impl<'de> ::serde::de::Deserialize<'de> for LinkAccessLevel {
                 let value = match tag {
                     "viewer" => LinkAccessLevel::Viewer,
                     "editor" => LinkAccessLevel::Editor,
-                    _ => LinkAccessLevel::Other,
+                    _ => LinkAccessLevel::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -6552,7 +6063,10 @@ impl ::serde::ser::Serialize for LinkAccessLevel {
                 s.serialize_field(".tag", "editor")?;
                 s.end()
             }
-            LinkAccessLevel::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            LinkAccessLevel::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -6574,8 +6088,9 @@ pub enum LinkAction {
     /// Create or modify the password of the link.
     SetPassword,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for LinkAction {
@@ -6600,7 +6115,7 @@ impl<'de> ::serde::de::Deserialize<'de> for LinkAction {
                     "remove_password" => LinkAction::RemovePassword,
                     "set_expiry" => LinkAction::SetExpiry,
                     "set_password" => LinkAction::SetPassword,
-                    _ => LinkAction::Other,
+                    _ => LinkAction::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -6658,7 +6173,10 @@ impl ::serde::ser::Serialize for LinkAction {
                 s.serialize_field(".tag", "set_password")?;
                 s.end()
             }
-            LinkAction::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            LinkAction::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -6680,8 +6198,9 @@ pub enum LinkAudience {
     /// Link is accessible only by members of the content.
     Members,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for LinkAudience {
@@ -6705,7 +6224,7 @@ impl<'de> ::serde::de::Deserialize<'de> for LinkAudience {
                     "no_one" => LinkAudience::NoOne,
                     "password" => LinkAudience::Password,
                     "members" => LinkAudience::Members,
-                    _ => LinkAudience::Other,
+                    _ => LinkAudience::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -6756,7 +6275,10 @@ impl ::serde::ser::Serialize for LinkAudience {
                 s.serialize_field(".tag", "members")?;
                 s.end()
             }
-            LinkAudience::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            LinkAudience::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -6779,8 +6301,9 @@ pub enum LinkAudienceDisallowedReason {
     /// The user does not have permission.
     PermissionDenied,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for LinkAudienceDisallowedReason {
@@ -6805,7 +6328,7 @@ impl<'de> ::serde::de::Deserialize<'de> for LinkAudienceDisallowedReason {
                     "user_not_on_team" => LinkAudienceDisallowedReason::UserNotOnTeam,
                     "user_account_type" => LinkAudienceDisallowedReason::UserAccountType,
                     "permission_denied" => LinkAudienceDisallowedReason::PermissionDenied,
-                    _ => LinkAudienceDisallowedReason::Other,
+                    _ => LinkAudienceDisallowedReason::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -6863,7 +6386,10 @@ impl ::serde::ser::Serialize for LinkAudienceDisallowedReason {
                 s.serialize_field(".tag", "permission_denied")?;
                 s.end()
             }
-            LinkAudienceDisallowedReason::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            LinkAudienceDisallowedReason::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -6878,7 +6404,7 @@ impl From<VisibilityPolicyDisallowedReason> for LinkAudienceDisallowedReason {
             VisibilityPolicyDisallowedReason::UserNotOnTeam => LinkAudienceDisallowedReason::UserNotOnTeam,
             VisibilityPolicyDisallowedReason::UserAccountType => LinkAudienceDisallowedReason::UserAccountType,
             VisibilityPolicyDisallowedReason::PermissionDenied => LinkAudienceDisallowedReason::PermissionDenied,
-            VisibilityPolicyDisallowedReason::Other => LinkAudienceDisallowedReason::Other,
+            VisibilityPolicyDisallowedReason::Other(x) => LinkAudienceDisallowedReason::Other(x),
         }
     }
 }
@@ -6930,28 +6456,10 @@ impl LinkAudienceOption {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "audience" => {
-                    if field_audience.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("audience"));
-                    }
-                    field_audience = Some(map.next_value()?);
-                }
-                "allowed" => {
-                    if field_allowed.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("allowed"));
-                    }
-                    field_allowed = Some(map.next_value()?);
-                }
-                "disallowed_reason" => {
-                    if field_disallowed_reason.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("disallowed_reason"));
-                    }
-                    field_disallowed_reason = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "audience" => crate::deserialize_field(&mut map, &mut field_audience, "audience")?,
+                "allowed" => crate::deserialize_field(&mut map, &mut field_allowed, "allowed")?,
+                "disallowed_reason" => crate::deserialize_field(&mut map, &mut field_disallowed_reason, "disallowed_reason")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -7015,8 +6523,9 @@ pub enum LinkExpiry {
     /// Set a new expiry or change an existing expiry.
     SetExpiry(crate::types::common::DropboxTimestamp),
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for LinkExpiry {
@@ -7043,7 +6552,7 @@ impl<'de> ::serde::de::Deserialize<'de> for LinkExpiry {
                             _ => return Err(de::Error::unknown_field(tag, VARIANTS))
                         }
                     }
-                    _ => LinkExpiry::Other,
+                    _ => LinkExpiry::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -7074,7 +6583,10 @@ impl ::serde::ser::Serialize for LinkExpiry {
                 s.serialize_field("set_expiry", x)?;
                 s.end()
             }
-            LinkExpiry::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            LinkExpiry::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -7152,8 +6664,9 @@ pub enum LinkPassword {
     /// Set a new password or change an existing password.
     SetPassword(String),
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for LinkPassword {
@@ -7180,7 +6693,7 @@ impl<'de> ::serde::de::Deserialize<'de> for LinkPassword {
                             _ => return Err(de::Error::unknown_field(tag, VARIANTS))
                         }
                     }
-                    _ => LinkPassword::Other,
+                    _ => LinkPassword::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -7211,7 +6724,10 @@ impl ::serde::ser::Serialize for LinkPassword {
                 s.serialize_field("set_password", x)?;
                 s.end()
             }
-            LinkPassword::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            LinkPassword::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -7261,28 +6777,10 @@ impl LinkPermission {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "action" => {
-                    if field_action.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("action"));
-                    }
-                    field_action = Some(map.next_value()?);
-                }
-                "allow" => {
-                    if field_allow.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("allow"));
-                    }
-                    field_allow = Some(map.next_value()?);
-                }
-                "reason" => {
-                    if field_reason.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("reason"));
-                    }
-                    field_reason = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "action" => crate::deserialize_field(&mut map, &mut field_action, "action")?,
+                "allow" => crate::deserialize_field(&mut map, &mut field_allow, "allow")?,
+                "reason" => crate::deserialize_field(&mut map, &mut field_reason, "reason")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -7538,124 +7036,26 @@ impl LinkPermissions {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "can_revoke" => {
-                    if field_can_revoke.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("can_revoke"));
-                    }
-                    field_can_revoke = Some(map.next_value()?);
-                }
-                "visibility_policies" => {
-                    if field_visibility_policies.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("visibility_policies"));
-                    }
-                    field_visibility_policies = Some(map.next_value()?);
-                }
-                "can_set_expiry" => {
-                    if field_can_set_expiry.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("can_set_expiry"));
-                    }
-                    field_can_set_expiry = Some(map.next_value()?);
-                }
-                "can_remove_expiry" => {
-                    if field_can_remove_expiry.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("can_remove_expiry"));
-                    }
-                    field_can_remove_expiry = Some(map.next_value()?);
-                }
-                "allow_download" => {
-                    if field_allow_download.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("allow_download"));
-                    }
-                    field_allow_download = Some(map.next_value()?);
-                }
-                "can_allow_download" => {
-                    if field_can_allow_download.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("can_allow_download"));
-                    }
-                    field_can_allow_download = Some(map.next_value()?);
-                }
-                "can_disallow_download" => {
-                    if field_can_disallow_download.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("can_disallow_download"));
-                    }
-                    field_can_disallow_download = Some(map.next_value()?);
-                }
-                "allow_comments" => {
-                    if field_allow_comments.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("allow_comments"));
-                    }
-                    field_allow_comments = Some(map.next_value()?);
-                }
-                "team_restricts_comments" => {
-                    if field_team_restricts_comments.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("team_restricts_comments"));
-                    }
-                    field_team_restricts_comments = Some(map.next_value()?);
-                }
-                "resolved_visibility" => {
-                    if field_resolved_visibility.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("resolved_visibility"));
-                    }
-                    field_resolved_visibility = Some(map.next_value()?);
-                }
-                "requested_visibility" => {
-                    if field_requested_visibility.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("requested_visibility"));
-                    }
-                    field_requested_visibility = Some(map.next_value()?);
-                }
-                "revoke_failure_reason" => {
-                    if field_revoke_failure_reason.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("revoke_failure_reason"));
-                    }
-                    field_revoke_failure_reason = Some(map.next_value()?);
-                }
-                "effective_audience" => {
-                    if field_effective_audience.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("effective_audience"));
-                    }
-                    field_effective_audience = Some(map.next_value()?);
-                }
-                "link_access_level" => {
-                    if field_link_access_level.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("link_access_level"));
-                    }
-                    field_link_access_level = Some(map.next_value()?);
-                }
-                "audience_options" => {
-                    if field_audience_options.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("audience_options"));
-                    }
-                    field_audience_options = Some(map.next_value()?);
-                }
-                "can_set_password" => {
-                    if field_can_set_password.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("can_set_password"));
-                    }
-                    field_can_set_password = Some(map.next_value()?);
-                }
-                "can_remove_password" => {
-                    if field_can_remove_password.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("can_remove_password"));
-                    }
-                    field_can_remove_password = Some(map.next_value()?);
-                }
-                "require_password" => {
-                    if field_require_password.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("require_password"));
-                    }
-                    field_require_password = Some(map.next_value()?);
-                }
-                "can_use_extended_sharing_controls" => {
-                    if field_can_use_extended_sharing_controls.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("can_use_extended_sharing_controls"));
-                    }
-                    field_can_use_extended_sharing_controls = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "can_revoke" => crate::deserialize_field(&mut map, &mut field_can_revoke, "can_revoke")?,
+                "visibility_policies" => crate::deserialize_field(&mut map, &mut field_visibility_policies, "visibility_policies")?,
+                "can_set_expiry" => crate::deserialize_field(&mut map, &mut field_can_set_expiry, "can_set_expiry")?,
+                "can_remove_expiry" => crate::deserialize_field(&mut map, &mut field_can_remove_expiry, "can_remove_expiry")?,
+                "allow_download" => crate::deserialize_field(&mut map, &mut field_allow_download, "allow_download")?,
+                "can_allow_download" => crate::deserialize_field(&mut map, &mut field_can_allow_download, "can_allow_download")?,
+                "can_disallow_download" => crate::deserialize_field(&mut map, &mut field_can_disallow_download, "can_disallow_download")?,
+                "allow_comments" => crate::deserialize_field(&mut map, &mut field_allow_comments, "allow_comments")?,
+                "team_restricts_comments" => crate::deserialize_field(&mut map, &mut field_team_restricts_comments, "team_restricts_comments")?,
+                "resolved_visibility" => crate::deserialize_field(&mut map, &mut field_resolved_visibility, "resolved_visibility")?,
+                "requested_visibility" => crate::deserialize_field(&mut map, &mut field_requested_visibility, "requested_visibility")?,
+                "revoke_failure_reason" => crate::deserialize_field(&mut map, &mut field_revoke_failure_reason, "revoke_failure_reason")?,
+                "effective_audience" => crate::deserialize_field(&mut map, &mut field_effective_audience, "effective_audience")?,
+                "link_access_level" => crate::deserialize_field(&mut map, &mut field_link_access_level, "link_access_level")?,
+                "audience_options" => crate::deserialize_field(&mut map, &mut field_audience_options, "audience_options")?,
+                "can_set_password" => crate::deserialize_field(&mut map, &mut field_can_set_password, "can_set_password")?,
+                "can_remove_password" => crate::deserialize_field(&mut map, &mut field_can_remove_password, "can_remove_password")?,
+                "require_password" => crate::deserialize_field(&mut map, &mut field_require_password, "require_password")?,
+                "can_use_extended_sharing_controls" => crate::deserialize_field(&mut map, &mut field_can_use_extended_sharing_controls, "can_use_extended_sharing_controls")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -7813,34 +7213,11 @@ impl LinkSettings {
         let mut field_password = None;
         while let Some(key) = map.next_key::<&str>()? {
             match key {
-                "access_level" => {
-                    if field_access_level.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("access_level"));
-                    }
-                    field_access_level = Some(map.next_value()?);
-                }
-                "audience" => {
-                    if field_audience.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("audience"));
-                    }
-                    field_audience = Some(map.next_value()?);
-                }
-                "expiry" => {
-                    if field_expiry.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("expiry"));
-                    }
-                    field_expiry = Some(map.next_value()?);
-                }
-                "password" => {
-                    if field_password.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("password"));
-                    }
-                    field_password = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "access_level" => crate::deserialize_field(&mut map, &mut field_access_level, "access_level")?,
+                "audience" => crate::deserialize_field(&mut map, &mut field_audience, "audience")?,
+                "expiry" => crate::deserialize_field(&mut map, &mut field_expiry, "expiry")?,
+                "password" => crate::deserialize_field(&mut map, &mut field_password, "password")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         let result = LinkSettings {
@@ -7964,34 +7341,11 @@ impl ListFileMembersArg {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "file" => {
-                    if field_file.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("file"));
-                    }
-                    field_file = Some(map.next_value()?);
-                }
-                "actions" => {
-                    if field_actions.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("actions"));
-                    }
-                    field_actions = Some(map.next_value()?);
-                }
-                "include_inherited" => {
-                    if field_include_inherited.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("include_inherited"));
-                    }
-                    field_include_inherited = Some(map.next_value()?);
-                }
-                "limit" => {
-                    if field_limit.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("limit"));
-                    }
-                    field_limit = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "file" => crate::deserialize_field(&mut map, &mut field_file, "file")?,
+                "actions" => crate::deserialize_field(&mut map, &mut field_actions, "actions")?,
+                "include_inherited" => crate::deserialize_field(&mut map, &mut field_include_inherited, "include_inherited")?,
+                "limit" => crate::deserialize_field(&mut map, &mut field_limit, "limit")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -8096,22 +7450,9 @@ impl ListFileMembersBatchArg {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "files" => {
-                    if field_files.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("files"));
-                    }
-                    field_files = Some(map.next_value()?);
-                }
-                "limit" => {
-                    if field_limit.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("limit"));
-                    }
-                    field_limit = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "files" => crate::deserialize_field(&mut map, &mut field_files, "files")?,
+                "limit" => crate::deserialize_field(&mut map, &mut field_limit, "limit")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -8203,22 +7544,9 @@ impl ListFileMembersBatchResult {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "file" => {
-                    if field_file.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("file"));
-                    }
-                    field_file = Some(map.next_value()?);
-                }
-                "result" => {
-                    if field_result.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("result"));
-                    }
-                    field_result = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "file" => crate::deserialize_field(&mut map, &mut field_file, "file")?,
+                "result" => crate::deserialize_field(&mut map, &mut field_result, "result")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -8306,16 +7634,8 @@ impl ListFileMembersContinueArg {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "cursor" => {
-                    if field_cursor.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("cursor"));
-                    }
-                    field_cursor = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "cursor" => crate::deserialize_field(&mut map, &mut field_cursor, "cursor")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -8374,8 +7694,9 @@ pub enum ListFileMembersContinueError {
     /// [`ListFileMembersContinueArg::cursor`](ListFileMembersContinueArg) is invalid.
     InvalidCursor,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for ListFileMembersContinueError {
@@ -8409,7 +7730,7 @@ impl<'de> ::serde::de::Deserialize<'de> for ListFileMembersContinueError {
                         }
                     }
                     "invalid_cursor" => ListFileMembersContinueError::InvalidCursor,
-                    _ => ListFileMembersContinueError::Other,
+                    _ => ListFileMembersContinueError::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -8448,7 +7769,10 @@ impl ::serde::ser::Serialize for ListFileMembersContinueError {
                 s.serialize_field(".tag", "invalid_cursor")?;
                 s.end()
             }
-            ListFileMembersContinueError::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            ListFileMembersContinueError::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -8466,8 +7790,8 @@ impl ::std::error::Error for ListFileMembersContinueError {
 impl ::std::fmt::Display for ListFileMembersContinueError {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         match self {
-            ListFileMembersContinueError::UserError(inner) => write!(f, "{}", inner),
-            ListFileMembersContinueError::AccessError(inner) => write!(f, "{}", inner),
+            ListFileMembersContinueError::UserError(inner) => write!(f, "user_error: {}", inner),
+            ListFileMembersContinueError::AccessError(inner) => write!(f, "access_error: {}", inner),
             _ => write!(f, "{:?}", *self),
         }
     }
@@ -8510,22 +7834,9 @@ impl ListFileMembersCountResult {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "members" => {
-                    if field_members.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("members"));
-                    }
-                    field_members = Some(map.next_value()?);
-                }
-                "member_count" => {
-                    if field_member_count.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("member_count"));
-                    }
-                    field_member_count = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "members" => crate::deserialize_field(&mut map, &mut field_members, "members")?,
+                "member_count" => crate::deserialize_field(&mut map, &mut field_member_count, "member_count")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -8584,8 +7895,9 @@ pub enum ListFileMembersError {
     UserError(SharingUserError),
     AccessError(SharingFileAccessError),
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for ListFileMembersError {
@@ -8618,7 +7930,7 @@ impl<'de> ::serde::de::Deserialize<'de> for ListFileMembersError {
                             _ => return Err(de::Error::unknown_field(tag, VARIANTS))
                         }
                     }
-                    _ => ListFileMembersError::Other,
+                    _ => ListFileMembersError::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -8650,7 +7962,10 @@ impl ::serde::ser::Serialize for ListFileMembersError {
                 s.serialize_field("access_error", x)?;
                 s.end()
             }
-            ListFileMembersError::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            ListFileMembersError::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -8668,8 +7983,8 @@ impl ::std::error::Error for ListFileMembersError {
 impl ::std::fmt::Display for ListFileMembersError {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         match self {
-            ListFileMembersError::UserError(inner) => write!(f, "{}", inner),
-            ListFileMembersError::AccessError(inner) => write!(f, "{}", inner),
+            ListFileMembersError::UserError(inner) => write!(f, "user_error: {}", inner),
+            ListFileMembersError::AccessError(inner) => write!(f, "access_error: {}", inner),
             _ => write!(f, "{:?}", *self),
         }
     }
@@ -8683,8 +7998,9 @@ pub enum ListFileMembersIndividualResult {
     /// The result of the query for this file if it was an error.
     AccessError(SharingFileAccessError),
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for ListFileMembersIndividualResult {
@@ -8711,7 +8027,7 @@ impl<'de> ::serde::de::Deserialize<'de> for ListFileMembersIndividualResult {
                             _ => return Err(de::Error::unknown_field(tag, VARIANTS))
                         }
                     }
-                    _ => ListFileMembersIndividualResult::Other,
+                    _ => ListFileMembersIndividualResult::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -8743,7 +8059,10 @@ impl ::serde::ser::Serialize for ListFileMembersIndividualResult {
                 s.serialize_field("access_error", x)?;
                 s.end()
             }
-            ListFileMembersIndividualResult::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            ListFileMembersIndividualResult::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -8792,22 +8111,9 @@ impl ListFilesArg {
         let mut field_actions = None;
         while let Some(key) = map.next_key::<&str>()? {
             match key {
-                "limit" => {
-                    if field_limit.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("limit"));
-                    }
-                    field_limit = Some(map.next_value()?);
-                }
-                "actions" => {
-                    if field_actions.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("actions"));
-                    }
-                    field_actions = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "limit" => crate::deserialize_field(&mut map, &mut field_limit, "limit")?,
+                "actions" => crate::deserialize_field(&mut map, &mut field_actions, "actions")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         let result = ListFilesArg {
@@ -8893,16 +8199,8 @@ impl ListFilesContinueArg {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "cursor" => {
-                    if field_cursor.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("cursor"));
-                    }
-                    field_cursor = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "cursor" => crate::deserialize_field(&mut map, &mut field_cursor, "cursor")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -8962,8 +8260,9 @@ pub enum ListFilesContinueError {
     /// [`ListFilesContinueArg::cursor`](ListFilesContinueArg) is invalid.
     InvalidCursor,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for ListFilesContinueError {
@@ -8990,7 +8289,7 @@ impl<'de> ::serde::de::Deserialize<'de> for ListFilesContinueError {
                         }
                     }
                     "invalid_cursor" => ListFilesContinueError::InvalidCursor,
-                    _ => ListFilesContinueError::Other,
+                    _ => ListFilesContinueError::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -9021,7 +8320,10 @@ impl ::serde::ser::Serialize for ListFilesContinueError {
                 s.serialize_field(".tag", "invalid_cursor")?;
                 s.end()
             }
-            ListFilesContinueError::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            ListFilesContinueError::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -9087,22 +8389,9 @@ impl ListFilesResult {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "entries" => {
-                    if field_entries.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("entries"));
-                    }
-                    field_entries = Some(map.next_value()?);
-                }
-                "cursor" => {
-                    if field_cursor.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("cursor"));
-                    }
-                    field_cursor = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "entries" => crate::deserialize_field(&mut map, &mut field_entries, "entries")?,
+                "cursor" => crate::deserialize_field(&mut map, &mut field_cursor, "cursor")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -9211,28 +8500,10 @@ impl ListFolderMembersArgs {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "shared_folder_id" => {
-                    if field_shared_folder_id.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("shared_folder_id"));
-                    }
-                    field_shared_folder_id = Some(map.next_value()?);
-                }
-                "actions" => {
-                    if field_actions.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("actions"));
-                    }
-                    field_actions = Some(map.next_value()?);
-                }
-                "limit" => {
-                    if field_limit.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("limit"));
-                    }
-                    field_limit = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "shared_folder_id" => crate::deserialize_field(&mut map, &mut field_shared_folder_id, "shared_folder_id")?,
+                "actions" => crate::deserialize_field(&mut map, &mut field_actions, "actions")?,
+                "limit" => crate::deserialize_field(&mut map, &mut field_limit, "limit")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -9333,16 +8604,8 @@ impl ListFolderMembersContinueArg {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "cursor" => {
-                    if field_cursor.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("cursor"));
-                    }
-                    field_cursor = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "cursor" => crate::deserialize_field(&mut map, &mut field_cursor, "cursor")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -9399,8 +8662,9 @@ pub enum ListFolderMembersContinueError {
     /// [`ListFolderMembersContinueArg::cursor`](ListFolderMembersContinueArg) is invalid.
     InvalidCursor,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for ListFolderMembersContinueError {
@@ -9427,7 +8691,7 @@ impl<'de> ::serde::de::Deserialize<'de> for ListFolderMembersContinueError {
                         }
                     }
                     "invalid_cursor" => ListFolderMembersContinueError::InvalidCursor,
-                    _ => ListFolderMembersContinueError::Other,
+                    _ => ListFolderMembersContinueError::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -9458,7 +8722,10 @@ impl ::serde::ser::Serialize for ListFolderMembersContinueError {
                 s.serialize_field(".tag", "invalid_cursor")?;
                 s.end()
             }
-            ListFolderMembersContinueError::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            ListFolderMembersContinueError::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -9475,7 +8742,7 @@ impl ::std::error::Error for ListFolderMembersContinueError {
 impl ::std::fmt::Display for ListFolderMembersContinueError {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         match self {
-            ListFolderMembersContinueError::AccessError(inner) => write!(f, "{}", inner),
+            ListFolderMembersContinueError::AccessError(inner) => write!(f, "access_error: {}", inner),
             _ => write!(f, "{:?}", *self),
         }
     }
@@ -9525,22 +8792,9 @@ impl ListFolderMembersCursorArg {
         let mut field_limit = None;
         while let Some(key) = map.next_key::<&str>()? {
             match key {
-                "actions" => {
-                    if field_actions.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("actions"));
-                    }
-                    field_actions = Some(map.next_value()?);
-                }
-                "limit" => {
-                    if field_limit.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("limit"));
-                    }
-                    field_limit = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "actions" => crate::deserialize_field(&mut map, &mut field_actions, "actions")?,
+                "limit" => crate::deserialize_field(&mut map, &mut field_limit, "limit")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         let result = ListFolderMembersCursorArg {
@@ -9636,22 +8890,9 @@ impl ListFoldersArgs {
         let mut field_actions = None;
         while let Some(key) = map.next_key::<&str>()? {
             match key {
-                "limit" => {
-                    if field_limit.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("limit"));
-                    }
-                    field_limit = Some(map.next_value()?);
-                }
-                "actions" => {
-                    if field_actions.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("actions"));
-                    }
-                    field_actions = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "limit" => crate::deserialize_field(&mut map, &mut field_limit, "limit")?,
+                "actions" => crate::deserialize_field(&mut map, &mut field_actions, "actions")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         let result = ListFoldersArgs {
@@ -9736,16 +8977,8 @@ impl ListFoldersContinueArg {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "cursor" => {
-                    if field_cursor.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("cursor"));
-                    }
-                    field_cursor = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "cursor" => crate::deserialize_field(&mut map, &mut field_cursor, "cursor")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -9801,8 +9034,9 @@ pub enum ListFoldersContinueError {
     /// [`ListFoldersContinueArg::cursor`](ListFoldersContinueArg) is invalid.
     InvalidCursor,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for ListFoldersContinueError {
@@ -9822,7 +9056,7 @@ impl<'de> ::serde::de::Deserialize<'de> for ListFoldersContinueError {
                 };
                 let value = match tag {
                     "invalid_cursor" => ListFoldersContinueError::InvalidCursor,
-                    _ => ListFoldersContinueError::Other,
+                    _ => ListFoldersContinueError::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -9845,7 +9079,10 @@ impl ::serde::ser::Serialize for ListFoldersContinueError {
                 s.serialize_field(".tag", "invalid_cursor")?;
                 s.end()
             }
-            ListFoldersContinueError::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            ListFoldersContinueError::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -9909,22 +9146,9 @@ impl ListFoldersResult {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "entries" => {
-                    if field_entries.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("entries"));
-                    }
-                    field_entries = Some(map.next_value()?);
-                }
-                "cursor" => {
-                    if field_cursor.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("cursor"));
-                    }
-                    field_cursor = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "entries" => crate::deserialize_field(&mut map, &mut field_entries, "entries")?,
+                "cursor" => crate::deserialize_field(&mut map, &mut field_cursor, "cursor")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -10020,28 +9244,10 @@ impl ListSharedLinksArg {
         let mut field_direct_only = None;
         while let Some(key) = map.next_key::<&str>()? {
             match key {
-                "path" => {
-                    if field_path.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("path"));
-                    }
-                    field_path = Some(map.next_value()?);
-                }
-                "cursor" => {
-                    if field_cursor.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("cursor"));
-                    }
-                    field_cursor = Some(map.next_value()?);
-                }
-                "direct_only" => {
-                    if field_direct_only.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("direct_only"));
-                    }
-                    field_direct_only = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "path" => crate::deserialize_field(&mut map, &mut field_path, "path")?,
+                "cursor" => crate::deserialize_field(&mut map, &mut field_cursor, "cursor")?,
+                "direct_only" => crate::deserialize_field(&mut map, &mut field_direct_only, "direct_only")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         let result = ListSharedLinksArg {
@@ -10106,8 +9312,9 @@ pub enum ListSharedLinksError {
     /// [`list_shared_links()`](crate::sharing::list_shared_links) to obtain a new cursor.
     Reset,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for ListSharedLinksError {
@@ -10134,7 +9341,7 @@ impl<'de> ::serde::de::Deserialize<'de> for ListSharedLinksError {
                         }
                     }
                     "reset" => ListSharedLinksError::Reset,
-                    _ => ListSharedLinksError::Other,
+                    _ => ListSharedLinksError::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -10165,7 +9372,10 @@ impl ::serde::ser::Serialize for ListSharedLinksError {
                 s.serialize_field(".tag", "reset")?;
                 s.end()
             }
-            ListSharedLinksError::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            ListSharedLinksError::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -10182,7 +9392,7 @@ impl ::std::error::Error for ListSharedLinksError {
 impl ::std::fmt::Display for ListSharedLinksError {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         match self {
-            ListSharedLinksError::Path(inner) => write!(f, "{}", inner),
+            ListSharedLinksError::Path(inner) => write!(f, "path: {}", inner),
             _ => write!(f, "{:?}", *self),
         }
     }
@@ -10237,28 +9447,10 @@ impl ListSharedLinksResult {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "links" => {
-                    if field_links.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("links"));
-                    }
-                    field_links = Some(map.next_value()?);
-                }
-                "has_more" => {
-                    if field_has_more.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("has_more"));
-                    }
-                    field_has_more = Some(map.next_value()?);
-                }
-                "cursor" => {
-                    if field_cursor.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("cursor"));
-                    }
-                    field_cursor = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "links" => crate::deserialize_field(&mut map, &mut field_links, "links")?,
+                "has_more" => crate::deserialize_field(&mut map, &mut field_has_more, "has_more")?,
+                "cursor" => crate::deserialize_field(&mut map, &mut field_cursor, "cursor")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -10358,28 +9550,10 @@ impl MemberAccessLevelResult {
         let mut field_access_details = None;
         while let Some(key) = map.next_key::<&str>()? {
             match key {
-                "access_level" => {
-                    if field_access_level.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("access_level"));
-                    }
-                    field_access_level = Some(map.next_value()?);
-                }
-                "warning" => {
-                    if field_warning.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("warning"));
-                    }
-                    field_warning = Some(map.next_value()?);
-                }
-                "access_details" => {
-                    if field_access_details.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("access_details"));
-                    }
-                    field_access_details = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "access_level" => crate::deserialize_field(&mut map, &mut field_access_level, "access_level")?,
+                "warning" => crate::deserialize_field(&mut map, &mut field_warning, "warning")?,
+                "access_details" => crate::deserialize_field(&mut map, &mut field_access_details, "access_details")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         let result = MemberAccessLevelResult {
@@ -10453,8 +9627,9 @@ pub enum MemberAction {
     /// Remove the member from the folder.
     Remove,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for MemberAction {
@@ -10479,7 +9654,7 @@ impl<'de> ::serde::de::Deserialize<'de> for MemberAction {
                     "make_viewer" => MemberAction::MakeViewer,
                     "make_viewer_no_comment" => MemberAction::MakeViewerNoComment,
                     "remove" => MemberAction::Remove,
-                    _ => MemberAction::Other,
+                    _ => MemberAction::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -10537,7 +9712,10 @@ impl ::serde::ser::Serialize for MemberAction {
                 s.serialize_field(".tag", "remove")?;
                 s.end()
             }
-            MemberAction::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            MemberAction::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -10590,28 +9768,10 @@ impl MemberPermission {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "action" => {
-                    if field_action.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("action"));
-                    }
-                    field_action = Some(map.next_value()?);
-                }
-                "allow" => {
-                    if field_allow.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("allow"));
-                    }
-                    field_allow = Some(map.next_value()?);
-                }
-                "reason" => {
-                    if field_reason.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("reason"));
-                    }
-                    field_reason = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "action" => crate::deserialize_field(&mut map, &mut field_action, "action")?,
+                "allow" => crate::deserialize_field(&mut map, &mut field_allow, "allow")?,
+                "reason" => crate::deserialize_field(&mut map, &mut field_reason, "reason")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -10677,8 +9837,9 @@ pub enum MemberPolicy {
     /// Anyone can become a member.
     Anyone,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for MemberPolicy {
@@ -10699,7 +9860,7 @@ impl<'de> ::serde::de::Deserialize<'de> for MemberPolicy {
                 let value = match tag {
                     "team" => MemberPolicy::Team,
                     "anyone" => MemberPolicy::Anyone,
-                    _ => MemberPolicy::Other,
+                    _ => MemberPolicy::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -10729,7 +9890,10 @@ impl ::serde::ser::Serialize for MemberPolicy {
                 s.serialize_field(".tag", "anyone")?;
                 s.end()
             }
-            MemberPolicy::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            MemberPolicy::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -10743,8 +9907,9 @@ pub enum MemberSelector {
     /// Email address of member.
     Email(crate::types::common::EmailAddress),
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for MemberSelector {
@@ -10777,7 +9942,7 @@ impl<'de> ::serde::de::Deserialize<'de> for MemberSelector {
                             _ => return Err(de::Error::unknown_field(tag, VARIANTS))
                         }
                     }
-                    _ => MemberSelector::Other,
+                    _ => MemberSelector::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -10809,7 +9974,10 @@ impl ::serde::ser::Serialize for MemberSelector {
                 s.serialize_field("email", x)?;
                 s.end()
             }
-            MemberSelector::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            MemberSelector::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -10879,34 +10047,11 @@ impl MembershipInfo {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "access_type" => {
-                    if field_access_type.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("access_type"));
-                    }
-                    field_access_type = Some(map.next_value()?);
-                }
-                "permissions" => {
-                    if field_permissions.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("permissions"));
-                    }
-                    field_permissions = Some(map.next_value()?);
-                }
-                "initials" => {
-                    if field_initials.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("initials"));
-                    }
-                    field_initials = Some(map.next_value()?);
-                }
-                "is_inherited" => {
-                    if field_is_inherited.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("is_inherited"));
-                    }
-                    field_is_inherited = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "access_type" => crate::deserialize_field(&mut map, &mut field_access_type, "access_type")?,
+                "permissions" => crate::deserialize_field(&mut map, &mut field_permissions, "permissions")?,
+                "initials" => crate::deserialize_field(&mut map, &mut field_initials, "initials")?,
+                "is_inherited" => crate::deserialize_field(&mut map, &mut field_is_inherited, "is_inherited")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -11015,28 +10160,10 @@ impl ModifySharedLinkSettingsArgs {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "url" => {
-                    if field_url.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("url"));
-                    }
-                    field_url = Some(map.next_value()?);
-                }
-                "settings" => {
-                    if field_settings.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("settings"));
-                    }
-                    field_settings = Some(map.next_value()?);
-                }
-                "remove_expiration" => {
-                    if field_remove_expiration.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("remove_expiration"));
-                    }
-                    field_remove_expiration = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "url" => crate::deserialize_field(&mut map, &mut field_url, "url")?,
+                "settings" => crate::deserialize_field(&mut map, &mut field_settings, "settings")?,
+                "remove_expiration" => crate::deserialize_field(&mut map, &mut field_remove_expiration, "remove_expiration")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -11108,8 +10235,9 @@ pub enum ModifySharedLinkSettingsError {
     /// [here](https://www.dropbox.com/help/317).
     EmailNotVerified,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for ModifySharedLinkSettingsError {
@@ -11139,7 +10267,7 @@ impl<'de> ::serde::de::Deserialize<'de> for ModifySharedLinkSettingsError {
                         }
                     }
                     "email_not_verified" => ModifySharedLinkSettingsError::EmailNotVerified,
-                    _ => ModifySharedLinkSettingsError::Other,
+                    _ => ModifySharedLinkSettingsError::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -11191,7 +10319,10 @@ impl ::serde::ser::Serialize for ModifySharedLinkSettingsError {
                 s.serialize_field(".tag", "email_not_verified")?;
                 s.end()
             }
-            ModifySharedLinkSettingsError::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            ModifySharedLinkSettingsError::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -11223,7 +10354,7 @@ impl From<SharedLinkError> for ModifySharedLinkSettingsError {
             SharedLinkError::SharedLinkNotFound => ModifySharedLinkSettingsError::SharedLinkNotFound,
             SharedLinkError::SharedLinkAccessDenied => ModifySharedLinkSettingsError::SharedLinkAccessDenied,
             SharedLinkError::UnsupportedLinkType => ModifySharedLinkSettingsError::UnsupportedLinkType,
-            SharedLinkError::Other => ModifySharedLinkSettingsError::Other,
+            SharedLinkError::Other(x) => ModifySharedLinkSettingsError::Other(x),
         }
     }
 }
@@ -11259,16 +10390,8 @@ impl MountFolderArg {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "shared_folder_id" => {
-                    if field_shared_folder_id.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("shared_folder_id"));
-                    }
-                    field_shared_folder_id = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "shared_folder_id" => crate::deserialize_field(&mut map, &mut field_shared_folder_id, "shared_folder_id")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -11334,8 +10457,9 @@ pub enum MountFolderError {
     /// folder belongs within a team folder in the user's Dropbox.
     NotMountable,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for MountFolderError {
@@ -11366,7 +10490,7 @@ impl<'de> ::serde::de::Deserialize<'de> for MountFolderError {
                     "already_mounted" => MountFolderError::AlreadyMounted,
                     "no_permission" => MountFolderError::NoPermission,
                     "not_mountable" => MountFolderError::NotMountable,
-                    _ => MountFolderError::Other,
+                    _ => MountFolderError::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -11426,7 +10550,10 @@ impl ::serde::ser::Serialize for MountFolderError {
                 s.serialize_field(".tag", "not_mountable")?;
                 s.end()
             }
-            MountFolderError::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            MountFolderError::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -11443,7 +10570,7 @@ impl ::std::error::Error for MountFolderError {
 impl ::std::fmt::Display for MountFolderError {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         match self {
-            MountFolderError::AccessError(inner) => write!(f, "{}", inner),
+            MountFolderError::AccessError(inner) => write!(f, "access_error: {}", inner),
             MountFolderError::InsideSharedFolder => f.write_str("Mounting would cause a shared folder to be inside another, which is disallowed."),
             MountFolderError::InsufficientQuota(inner) => write!(f, "The current user does not have enough space to mount the shared folder: {:?}", inner),
             MountFolderError::AlreadyMounted => f.write_str("The shared folder is already mounted."),
@@ -11507,34 +10634,11 @@ impl ParentFolderAccessInfo {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "folder_name" => {
-                    if field_folder_name.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("folder_name"));
-                    }
-                    field_folder_name = Some(map.next_value()?);
-                }
-                "shared_folder_id" => {
-                    if field_shared_folder_id.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("shared_folder_id"));
-                    }
-                    field_shared_folder_id = Some(map.next_value()?);
-                }
-                "permissions" => {
-                    if field_permissions.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("permissions"));
-                    }
-                    field_permissions = Some(map.next_value()?);
-                }
-                "path" => {
-                    if field_path.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("path"));
-                    }
-                    field_path = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "folder_name" => crate::deserialize_field(&mut map, &mut field_folder_name, "folder_name")?,
+                "shared_folder_id" => crate::deserialize_field(&mut map, &mut field_shared_folder_id, "shared_folder_id")?,
+                "permissions" => crate::deserialize_field(&mut map, &mut field_permissions, "permissions")?,
+                "path" => crate::deserialize_field(&mut map, &mut field_path, "path")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -11643,34 +10747,11 @@ impl PathLinkMetadata {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "url" => {
-                    if field_url.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("url"));
-                    }
-                    field_url = Some(map.next_value()?);
-                }
-                "visibility" => {
-                    if field_visibility.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("visibility"));
-                    }
-                    field_visibility = Some(map.next_value()?);
-                }
-                "path" => {
-                    if field_path.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("path"));
-                    }
-                    field_path = Some(map.next_value()?);
-                }
-                "expires" => {
-                    if field_expires.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("expires"));
-                    }
-                    field_expires = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "url" => crate::deserialize_field(&mut map, &mut field_url, "url")?,
+                "visibility" => crate::deserialize_field(&mut map, &mut field_visibility, "visibility")?,
+                "path" => crate::deserialize_field(&mut map, &mut field_path, "path")?,
+                "expires" => crate::deserialize_field(&mut map, &mut field_expires, "expires")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -11828,8 +10909,9 @@ pub enum PermissionDeniedReason {
     RestrictedByParentFolder,
     InsufficientPlan(InsufficientPlan),
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for PermissionDeniedReason {
@@ -11863,7 +10945,7 @@ impl<'de> ::serde::de::Deserialize<'de> for PermissionDeniedReason {
                     "folder_is_inside_shared_folder" => PermissionDeniedReason::FolderIsInsideSharedFolder,
                     "restricted_by_parent_folder" => PermissionDeniedReason::RestrictedByParentFolder,
                     "insufficient_plan" => PermissionDeniedReason::InsufficientPlan(InsufficientPlan::internal_deserialize(&mut map)?),
-                    _ => PermissionDeniedReason::Other,
+                    _ => PermissionDeniedReason::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -11985,7 +11067,10 @@ impl ::serde::ser::Serialize for PermissionDeniedReason {
                 x.internal_serialize::<S>(&mut s)?;
                 s.end()
             }
-            PermissionDeniedReason::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            PermissionDeniedReason::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -12022,16 +11107,8 @@ impl RelinquishFileMembershipArg {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "file" => {
-                    if field_file.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("file"));
-                    }
-                    field_file = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "file" => crate::deserialize_field(&mut map, &mut field_file, "file")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -12091,8 +11168,9 @@ pub enum RelinquishFileMembershipError {
     /// The current user does not have permission to perform this action.
     NoPermission,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for RelinquishFileMembershipError {
@@ -12120,7 +11198,7 @@ impl<'de> ::serde::de::Deserialize<'de> for RelinquishFileMembershipError {
                     }
                     "group_access" => RelinquishFileMembershipError::GroupAccess,
                     "no_permission" => RelinquishFileMembershipError::NoPermission,
-                    _ => RelinquishFileMembershipError::Other,
+                    _ => RelinquishFileMembershipError::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -12158,7 +11236,10 @@ impl ::serde::ser::Serialize for RelinquishFileMembershipError {
                 s.serialize_field(".tag", "no_permission")?;
                 s.end()
             }
-            RelinquishFileMembershipError::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            RelinquishFileMembershipError::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -12175,7 +11256,7 @@ impl ::std::error::Error for RelinquishFileMembershipError {
 impl ::std::fmt::Display for RelinquishFileMembershipError {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         match self {
-            RelinquishFileMembershipError::AccessError(inner) => write!(f, "{}", inner),
+            RelinquishFileMembershipError::AccessError(inner) => write!(f, "access_error: {}", inner),
             RelinquishFileMembershipError::GroupAccess => f.write_str("The current user has access to the shared file via a group.  You can't relinquish membership to a file shared via groups."),
             RelinquishFileMembershipError::NoPermission => f.write_str("The current user does not have permission to perform this action."),
             _ => write!(f, "{:?}", *self),
@@ -12226,22 +11307,9 @@ impl RelinquishFolderMembershipArg {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "shared_folder_id" => {
-                    if field_shared_folder_id.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("shared_folder_id"));
-                    }
-                    field_shared_folder_id = Some(map.next_value()?);
-                }
-                "leave_a_copy" => {
-                    if field_leave_a_copy.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("leave_a_copy"));
-                    }
-                    field_leave_a_copy = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "shared_folder_id" => crate::deserialize_field(&mut map, &mut field_shared_folder_id, "shared_folder_id")?,
+                "leave_a_copy" => crate::deserialize_field(&mut map, &mut field_leave_a_copy, "leave_a_copy")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -12316,8 +11384,9 @@ pub enum RelinquishFolderMembershipError {
     /// inherited membership to folders.
     NoExplicitAccess,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for RelinquishFolderMembershipError {
@@ -12349,7 +11418,7 @@ impl<'de> ::serde::de::Deserialize<'de> for RelinquishFolderMembershipError {
                     "team_folder" => RelinquishFolderMembershipError::TeamFolder,
                     "no_permission" => RelinquishFolderMembershipError::NoPermission,
                     "no_explicit_access" => RelinquishFolderMembershipError::NoExplicitAccess,
-                    _ => RelinquishFolderMembershipError::Other,
+                    _ => RelinquishFolderMembershipError::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -12415,7 +11484,10 @@ impl ::serde::ser::Serialize for RelinquishFolderMembershipError {
                 s.serialize_field(".tag", "no_explicit_access")?;
                 s.end()
             }
-            RelinquishFolderMembershipError::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            RelinquishFolderMembershipError::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -12432,7 +11504,7 @@ impl ::std::error::Error for RelinquishFolderMembershipError {
 impl ::std::fmt::Display for RelinquishFolderMembershipError {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         match self {
-            RelinquishFolderMembershipError::AccessError(inner) => write!(f, "{}", inner),
+            RelinquishFolderMembershipError::AccessError(inner) => write!(f, "access_error: {}", inner),
             RelinquishFolderMembershipError::FolderOwner => f.write_str("The current user is the owner of the shared folder. Owners cannot relinquish membership to their own folders. Try unsharing or transferring ownership first."),
             RelinquishFolderMembershipError::Mounted => f.write_str("The shared folder is currently mounted.  Unmount the shared folder before relinquishing membership."),
             RelinquishFolderMembershipError::GroupAccess => f.write_str("The current user has access to the shared folder via a group.  You can't relinquish membership to folders shared via groups."),
@@ -12484,22 +11556,9 @@ impl RemoveFileMemberArg {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "file" => {
-                    if field_file.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("file"));
-                    }
-                    field_file = Some(map.next_value()?);
-                }
-                "member" => {
-                    if field_member.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("member"));
-                    }
-                    field_member = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "file" => crate::deserialize_field(&mut map, &mut field_file, "file")?,
+                "member" => crate::deserialize_field(&mut map, &mut field_member, "member")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -12561,8 +11620,9 @@ pub enum RemoveFileMemberError {
     /// return value is the access that a user might have to the file from a parent folder.
     NoExplicitAccess(MemberAccessLevelResult),
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for RemoveFileMemberError {
@@ -12596,7 +11656,7 @@ impl<'de> ::serde::de::Deserialize<'de> for RemoveFileMemberError {
                         }
                     }
                     "no_explicit_access" => RemoveFileMemberError::NoExplicitAccess(MemberAccessLevelResult::internal_deserialize(&mut map)?),
-                    _ => RemoveFileMemberError::Other,
+                    _ => RemoveFileMemberError::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -12636,7 +11696,10 @@ impl ::serde::ser::Serialize for RemoveFileMemberError {
                 x.internal_serialize::<S>(&mut s)?;
                 s.end()
             }
-            RemoveFileMemberError::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            RemoveFileMemberError::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -12654,8 +11717,8 @@ impl ::std::error::Error for RemoveFileMemberError {
 impl ::std::fmt::Display for RemoveFileMemberError {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         match self {
-            RemoveFileMemberError::UserError(inner) => write!(f, "{}", inner),
-            RemoveFileMemberError::AccessError(inner) => write!(f, "{}", inner),
+            RemoveFileMemberError::UserError(inner) => write!(f, "user_error: {}", inner),
+            RemoveFileMemberError::AccessError(inner) => write!(f, "access_error: {}", inner),
             RemoveFileMemberError::NoExplicitAccess(inner) => write!(f, "This member does not have explicit access to the file and therefore cannot be removed. The return value is the access that a user might have to the file from a parent folder: {:?}", inner),
             _ => write!(f, "{:?}", *self),
         }
@@ -12710,28 +11773,10 @@ impl RemoveFolderMemberArg {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "shared_folder_id" => {
-                    if field_shared_folder_id.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("shared_folder_id"));
-                    }
-                    field_shared_folder_id = Some(map.next_value()?);
-                }
-                "member" => {
-                    if field_member.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("member"));
-                    }
-                    field_member = Some(map.next_value()?);
-                }
-                "leave_a_copy" => {
-                    if field_leave_a_copy.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("leave_a_copy"));
-                    }
-                    field_leave_a_copy = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "shared_folder_id" => crate::deserialize_field(&mut map, &mut field_shared_folder_id, "shared_folder_id")?,
+                "member" => crate::deserialize_field(&mut map, &mut field_member, "member")?,
+                "leave_a_copy" => crate::deserialize_field(&mut map, &mut field_leave_a_copy, "leave_a_copy")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -12803,8 +11848,9 @@ pub enum RemoveFolderMemberError {
     /// without leaving a copy.
     TooManyFiles,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for RemoveFolderMemberError {
@@ -12842,7 +11888,7 @@ impl<'de> ::serde::de::Deserialize<'de> for RemoveFolderMemberError {
                     "team_folder" => RemoveFolderMemberError::TeamFolder,
                     "no_permission" => RemoveFolderMemberError::NoPermission,
                     "too_many_files" => RemoveFolderMemberError::TooManyFiles,
-                    _ => RemoveFolderMemberError::Other,
+                    _ => RemoveFolderMemberError::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -12909,7 +11955,10 @@ impl ::serde::ser::Serialize for RemoveFolderMemberError {
                 s.serialize_field(".tag", "too_many_files")?;
                 s.end()
             }
-            RemoveFolderMemberError::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            RemoveFolderMemberError::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -12927,8 +11976,8 @@ impl ::std::error::Error for RemoveFolderMemberError {
 impl ::std::fmt::Display for RemoveFolderMemberError {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         match self {
-            RemoveFolderMemberError::AccessError(inner) => write!(f, "{}", inner),
-            RemoveFolderMemberError::MemberError(inner) => write!(f, "{}", inner),
+            RemoveFolderMemberError::AccessError(inner) => write!(f, "access_error: {}", inner),
+            RemoveFolderMemberError::MemberError(inner) => write!(f, "member_error: {}", inner),
             RemoveFolderMemberError::FolderOwner => f.write_str("The target user is the owner of the shared folder. You can't remove this user until ownership has been transferred to another member."),
             RemoveFolderMemberError::GroupAccess => f.write_str("The target user has access to the shared folder via a group."),
             RemoveFolderMemberError::TeamFolder => f.write_str("This action cannot be performed on a team shared folder."),
@@ -13037,8 +12086,9 @@ pub enum RequestedLinkAccessLevel {
     /// Request for the default access level the user has set.
     Default,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for RequestedLinkAccessLevel {
@@ -13061,7 +12111,7 @@ impl<'de> ::serde::de::Deserialize<'de> for RequestedLinkAccessLevel {
                     "editor" => RequestedLinkAccessLevel::Editor,
                     "max" => RequestedLinkAccessLevel::Max,
                     "default" => RequestedLinkAccessLevel::Default,
-                    _ => RequestedLinkAccessLevel::Other,
+                    _ => RequestedLinkAccessLevel::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -13105,7 +12155,10 @@ impl ::serde::ser::Serialize for RequestedLinkAccessLevel {
                 s.serialize_field(".tag", "default")?;
                 s.end()
             }
-            RequestedLinkAccessLevel::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            RequestedLinkAccessLevel::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -13208,8 +12261,9 @@ pub enum ResolvedVisibility {
     /// Only the current user can view this link.
     OnlyYou,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for ResolvedVisibility {
@@ -13235,7 +12289,7 @@ impl<'de> ::serde::de::Deserialize<'de> for ResolvedVisibility {
                     "shared_folder_only" => ResolvedVisibility::SharedFolderOnly,
                     "no_one" => ResolvedVisibility::NoOne,
                     "only_you" => ResolvedVisibility::OnlyYou,
-                    _ => ResolvedVisibility::Other,
+                    _ => ResolvedVisibility::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -13300,7 +12354,10 @@ impl ::serde::ser::Serialize for ResolvedVisibility {
                 s.serialize_field(".tag", "only_you")?;
                 s.end()
             }
-            ResolvedVisibility::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            ResolvedVisibility::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -13347,16 +12404,8 @@ impl RevokeSharedLinkArg {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "url" => {
-                    if field_url.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("url"));
-                    }
-                    field_url = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "url" => crate::deserialize_field(&mut map, &mut field_url, "url")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -13418,8 +12467,9 @@ pub enum RevokeSharedLinkError {
     /// Shared link is malformed.
     SharedLinkMalformed,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for RevokeSharedLinkError {
@@ -13442,7 +12492,7 @@ impl<'de> ::serde::de::Deserialize<'de> for RevokeSharedLinkError {
                     "shared_link_access_denied" => RevokeSharedLinkError::SharedLinkAccessDenied,
                     "unsupported_link_type" => RevokeSharedLinkError::UnsupportedLinkType,
                     "shared_link_malformed" => RevokeSharedLinkError::SharedLinkMalformed,
-                    _ => RevokeSharedLinkError::Other,
+                    _ => RevokeSharedLinkError::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -13486,7 +12536,10 @@ impl ::serde::ser::Serialize for RevokeSharedLinkError {
                 s.serialize_field(".tag", "shared_link_malformed")?;
                 s.end()
             }
-            RevokeSharedLinkError::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            RevokeSharedLinkError::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -13512,7 +12565,7 @@ impl From<SharedLinkError> for RevokeSharedLinkError {
             SharedLinkError::SharedLinkNotFound => RevokeSharedLinkError::SharedLinkNotFound,
             SharedLinkError::SharedLinkAccessDenied => RevokeSharedLinkError::SharedLinkAccessDenied,
             SharedLinkError::UnsupportedLinkType => RevokeSharedLinkError::UnsupportedLinkType,
-            SharedLinkError::Other => RevokeSharedLinkError::Other,
+            SharedLinkError::Other(x) => RevokeSharedLinkError::Other(x),
         }
     }
 }
@@ -13558,22 +12611,9 @@ impl SetAccessInheritanceArg {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "shared_folder_id" => {
-                    if field_shared_folder_id.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("shared_folder_id"));
-                    }
-                    field_shared_folder_id = Some(map.next_value()?);
-                }
-                "access_inheritance" => {
-                    if field_access_inheritance.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("access_inheritance"));
-                    }
-                    field_access_inheritance = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "shared_folder_id" => crate::deserialize_field(&mut map, &mut field_shared_folder_id, "shared_folder_id")?,
+                "access_inheritance" => crate::deserialize_field(&mut map, &mut field_access_inheritance, "access_inheritance")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -13635,8 +12675,9 @@ pub enum SetAccessInheritanceError {
     /// The current user does not have permission to perform this action.
     NoPermission,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for SetAccessInheritanceError {
@@ -13663,7 +12704,7 @@ impl<'de> ::serde::de::Deserialize<'de> for SetAccessInheritanceError {
                         }
                     }
                     "no_permission" => SetAccessInheritanceError::NoPermission,
-                    _ => SetAccessInheritanceError::Other,
+                    _ => SetAccessInheritanceError::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -13694,7 +12735,10 @@ impl ::serde::ser::Serialize for SetAccessInheritanceError {
                 s.serialize_field(".tag", "no_permission")?;
                 s.end()
             }
-            SetAccessInheritanceError::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            SetAccessInheritanceError::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -13835,64 +12879,16 @@ impl ShareFolderArg {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "path" => {
-                    if field_path.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("path"));
-                    }
-                    field_path = Some(map.next_value()?);
-                }
-                "acl_update_policy" => {
-                    if field_acl_update_policy.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("acl_update_policy"));
-                    }
-                    field_acl_update_policy = Some(map.next_value()?);
-                }
-                "force_async" => {
-                    if field_force_async.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("force_async"));
-                    }
-                    field_force_async = Some(map.next_value()?);
-                }
-                "member_policy" => {
-                    if field_member_policy.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("member_policy"));
-                    }
-                    field_member_policy = Some(map.next_value()?);
-                }
-                "shared_link_policy" => {
-                    if field_shared_link_policy.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("shared_link_policy"));
-                    }
-                    field_shared_link_policy = Some(map.next_value()?);
-                }
-                "viewer_info_policy" => {
-                    if field_viewer_info_policy.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("viewer_info_policy"));
-                    }
-                    field_viewer_info_policy = Some(map.next_value()?);
-                }
-                "access_inheritance" => {
-                    if field_access_inheritance.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("access_inheritance"));
-                    }
-                    field_access_inheritance = Some(map.next_value()?);
-                }
-                "actions" => {
-                    if field_actions.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("actions"));
-                    }
-                    field_actions = Some(map.next_value()?);
-                }
-                "link_settings" => {
-                    if field_link_settings.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("link_settings"));
-                    }
-                    field_link_settings = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "path" => crate::deserialize_field(&mut map, &mut field_path, "path")?,
+                "acl_update_policy" => crate::deserialize_field(&mut map, &mut field_acl_update_policy, "acl_update_policy")?,
+                "force_async" => crate::deserialize_field(&mut map, &mut field_force_async, "force_async")?,
+                "member_policy" => crate::deserialize_field(&mut map, &mut field_member_policy, "member_policy")?,
+                "shared_link_policy" => crate::deserialize_field(&mut map, &mut field_shared_link_policy, "shared_link_policy")?,
+                "viewer_info_policy" => crate::deserialize_field(&mut map, &mut field_viewer_info_policy, "viewer_info_policy")?,
+                "access_inheritance" => crate::deserialize_field(&mut map, &mut field_access_inheritance, "access_inheritance")?,
+                "actions" => crate::deserialize_field(&mut map, &mut field_actions, "actions")?,
+                "link_settings" => crate::deserialize_field(&mut map, &mut field_link_settings, "link_settings")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -14083,52 +13079,14 @@ impl ShareFolderArgBase {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "path" => {
-                    if field_path.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("path"));
-                    }
-                    field_path = Some(map.next_value()?);
-                }
-                "acl_update_policy" => {
-                    if field_acl_update_policy.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("acl_update_policy"));
-                    }
-                    field_acl_update_policy = Some(map.next_value()?);
-                }
-                "force_async" => {
-                    if field_force_async.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("force_async"));
-                    }
-                    field_force_async = Some(map.next_value()?);
-                }
-                "member_policy" => {
-                    if field_member_policy.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("member_policy"));
-                    }
-                    field_member_policy = Some(map.next_value()?);
-                }
-                "shared_link_policy" => {
-                    if field_shared_link_policy.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("shared_link_policy"));
-                    }
-                    field_shared_link_policy = Some(map.next_value()?);
-                }
-                "viewer_info_policy" => {
-                    if field_viewer_info_policy.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("viewer_info_policy"));
-                    }
-                    field_viewer_info_policy = Some(map.next_value()?);
-                }
-                "access_inheritance" => {
-                    if field_access_inheritance.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("access_inheritance"));
-                    }
-                    field_access_inheritance = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "path" => crate::deserialize_field(&mut map, &mut field_path, "path")?,
+                "acl_update_policy" => crate::deserialize_field(&mut map, &mut field_acl_update_policy, "acl_update_policy")?,
+                "force_async" => crate::deserialize_field(&mut map, &mut field_force_async, "force_async")?,
+                "member_policy" => crate::deserialize_field(&mut map, &mut field_member_policy, "member_policy")?,
+                "shared_link_policy" => crate::deserialize_field(&mut map, &mut field_shared_link_policy, "shared_link_policy")?,
+                "viewer_info_policy" => crate::deserialize_field(&mut map, &mut field_viewer_info_policy, "viewer_info_policy")?,
+                "access_inheritance" => crate::deserialize_field(&mut map, &mut field_access_inheritance, "access_inheritance")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -14219,8 +13177,9 @@ pub enum ShareFolderError {
     /// The current user does not have permission to perform this action.
     NoPermission,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for ShareFolderError {
@@ -14250,7 +13209,7 @@ impl<'de> ::serde::de::Deserialize<'de> for ShareFolderError {
                     "team_policy_disallows_member_policy" => ShareFolderError::TeamPolicyDisallowsMemberPolicy,
                     "disallowed_shared_link_policy" => ShareFolderError::DisallowedSharedLinkPolicy,
                     "no_permission" => ShareFolderError::NoPermission,
-                    _ => ShareFolderError::Other,
+                    _ => ShareFolderError::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -14302,7 +13261,10 @@ impl ::serde::ser::Serialize for ShareFolderError {
                 s.serialize_field(".tag", "no_permission")?;
                 s.end()
             }
-            ShareFolderError::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            ShareFolderError::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -14319,7 +13281,7 @@ impl ::std::error::Error for ShareFolderError {
 impl ::std::fmt::Display for ShareFolderError {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         match self {
-            ShareFolderError::BadPath(inner) => write!(f, "{}", inner),
+            ShareFolderError::BadPath(inner) => write!(f, "bad_path: {}", inner),
             ShareFolderError::NoPermission => f.write_str("The current user does not have permission to perform this action."),
             _ => write!(f, "{:?}", *self),
         }
@@ -14334,7 +13296,7 @@ impl From<ShareFolderErrorBase> for ShareFolderError {
             ShareFolderErrorBase::BadPath(x) => ShareFolderError::BadPath(x),
             ShareFolderErrorBase::TeamPolicyDisallowsMemberPolicy => ShareFolderError::TeamPolicyDisallowsMemberPolicy,
             ShareFolderErrorBase::DisallowedSharedLinkPolicy => ShareFolderError::DisallowedSharedLinkPolicy,
-            ShareFolderErrorBase::Other => ShareFolderError::Other,
+            ShareFolderErrorBase::Other(x) => ShareFolderError::Other(x),
         }
     }
 }
@@ -14353,8 +13315,9 @@ pub enum ShareFolderErrorBase {
     /// [`ShareFolderArg::shared_link_policy`](ShareFolderArg).
     DisallowedSharedLinkPolicy,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for ShareFolderErrorBase {
@@ -14383,7 +13346,7 @@ impl<'de> ::serde::de::Deserialize<'de> for ShareFolderErrorBase {
                     }
                     "team_policy_disallows_member_policy" => ShareFolderErrorBase::TeamPolicyDisallowsMemberPolicy,
                     "disallowed_shared_link_policy" => ShareFolderErrorBase::DisallowedSharedLinkPolicy,
-                    _ => ShareFolderErrorBase::Other,
+                    _ => ShareFolderErrorBase::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -14428,7 +13391,10 @@ impl ::serde::ser::Serialize for ShareFolderErrorBase {
                 s.serialize_field(".tag", "disallowed_shared_link_policy")?;
                 s.end()
             }
-            ShareFolderErrorBase::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            ShareFolderErrorBase::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -14628,8 +13594,9 @@ pub enum SharePathError {
     /// We do not support sharing the Family folder.
     IsFamily,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for SharePathError {
@@ -14664,7 +13631,7 @@ impl<'de> ::serde::de::Deserialize<'de> for SharePathError {
                     "is_vault" => SharePathError::IsVault,
                     "is_vault_locked" => SharePathError::IsVaultLocked,
                     "is_family" => SharePathError::IsFamily,
-                    _ => SharePathError::Other,
+                    _ => SharePathError::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -14793,7 +13760,10 @@ impl ::serde::ser::Serialize for SharePathError {
                 s.serialize_field(".tag", "is_family")?;
                 s.end()
             }
-            SharePathError::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            SharePathError::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -14933,64 +13903,16 @@ impl SharedContentLinkMetadata {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "audience_options" => {
-                    if field_audience_options.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("audience_options"));
-                    }
-                    field_audience_options = Some(map.next_value()?);
-                }
-                "current_audience" => {
-                    if field_current_audience.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("current_audience"));
-                    }
-                    field_current_audience = Some(map.next_value()?);
-                }
-                "link_permissions" => {
-                    if field_link_permissions.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("link_permissions"));
-                    }
-                    field_link_permissions = Some(map.next_value()?);
-                }
-                "password_protected" => {
-                    if field_password_protected.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("password_protected"));
-                    }
-                    field_password_protected = Some(map.next_value()?);
-                }
-                "url" => {
-                    if field_url.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("url"));
-                    }
-                    field_url = Some(map.next_value()?);
-                }
-                "access_level" => {
-                    if field_access_level.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("access_level"));
-                    }
-                    field_access_level = Some(map.next_value()?);
-                }
-                "audience_restricting_shared_folder" => {
-                    if field_audience_restricting_shared_folder.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("audience_restricting_shared_folder"));
-                    }
-                    field_audience_restricting_shared_folder = Some(map.next_value()?);
-                }
-                "expiry" => {
-                    if field_expiry.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("expiry"));
-                    }
-                    field_expiry = Some(map.next_value()?);
-                }
-                "audience_exceptions" => {
-                    if field_audience_exceptions.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("audience_exceptions"));
-                    }
-                    field_audience_exceptions = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "audience_options" => crate::deserialize_field(&mut map, &mut field_audience_options, "audience_options")?,
+                "current_audience" => crate::deserialize_field(&mut map, &mut field_current_audience, "current_audience")?,
+                "link_permissions" => crate::deserialize_field(&mut map, &mut field_link_permissions, "link_permissions")?,
+                "password_protected" => crate::deserialize_field(&mut map, &mut field_password_protected, "password_protected")?,
+                "url" => crate::deserialize_field(&mut map, &mut field_url, "url")?,
+                "access_level" => crate::deserialize_field(&mut map, &mut field_access_level, "access_level")?,
+                "audience_restricting_shared_folder" => crate::deserialize_field(&mut map, &mut field_audience_restricting_shared_folder, "audience_restricting_shared_folder")?,
+                "expiry" => crate::deserialize_field(&mut map, &mut field_expiry, "expiry")?,
+                "audience_exceptions" => crate::deserialize_field(&mut map, &mut field_audience_exceptions, "audience_exceptions")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -15167,52 +14089,14 @@ impl SharedContentLinkMetadataBase {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "audience_options" => {
-                    if field_audience_options.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("audience_options"));
-                    }
-                    field_audience_options = Some(map.next_value()?);
-                }
-                "current_audience" => {
-                    if field_current_audience.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("current_audience"));
-                    }
-                    field_current_audience = Some(map.next_value()?);
-                }
-                "link_permissions" => {
-                    if field_link_permissions.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("link_permissions"));
-                    }
-                    field_link_permissions = Some(map.next_value()?);
-                }
-                "password_protected" => {
-                    if field_password_protected.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("password_protected"));
-                    }
-                    field_password_protected = Some(map.next_value()?);
-                }
-                "access_level" => {
-                    if field_access_level.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("access_level"));
-                    }
-                    field_access_level = Some(map.next_value()?);
-                }
-                "audience_restricting_shared_folder" => {
-                    if field_audience_restricting_shared_folder.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("audience_restricting_shared_folder"));
-                    }
-                    field_audience_restricting_shared_folder = Some(map.next_value()?);
-                }
-                "expiry" => {
-                    if field_expiry.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("expiry"));
-                    }
-                    field_expiry = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "audience_options" => crate::deserialize_field(&mut map, &mut field_audience_options, "audience_options")?,
+                "current_audience" => crate::deserialize_field(&mut map, &mut field_current_audience, "current_audience")?,
+                "link_permissions" => crate::deserialize_field(&mut map, &mut field_link_permissions, "link_permissions")?,
+                "password_protected" => crate::deserialize_field(&mut map, &mut field_password_protected, "password_protected")?,
+                "access_level" => crate::deserialize_field(&mut map, &mut field_access_level, "access_level")?,
+                "audience_restricting_shared_folder" => crate::deserialize_field(&mut map, &mut field_audience_restricting_shared_folder, "audience_restricting_shared_folder")?,
+                "expiry" => crate::deserialize_field(&mut map, &mut field_expiry, "expiry")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -15342,34 +14226,11 @@ impl SharedFileMembers {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "users" => {
-                    if field_users.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("users"));
-                    }
-                    field_users = Some(map.next_value()?);
-                }
-                "groups" => {
-                    if field_groups.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("groups"));
-                    }
-                    field_groups = Some(map.next_value()?);
-                }
-                "invitees" => {
-                    if field_invitees.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("invitees"));
-                    }
-                    field_invitees = Some(map.next_value()?);
-                }
-                "cursor" => {
-                    if field_cursor.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("cursor"));
-                    }
-                    field_cursor = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "users" => crate::deserialize_field(&mut map, &mut field_users, "users")?,
+                "groups" => crate::deserialize_field(&mut map, &mut field_groups, "groups")?,
+                "invitees" => crate::deserialize_field(&mut map, &mut field_invitees, "invitees")?,
+                "cursor" => crate::deserialize_field(&mut map, &mut field_cursor, "cursor")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -15597,94 +14458,21 @@ impl SharedFileMetadata {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "id" => {
-                    if field_id.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("id"));
-                    }
-                    field_id = Some(map.next_value()?);
-                }
-                "name" => {
-                    if field_name.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("name"));
-                    }
-                    field_name = Some(map.next_value()?);
-                }
-                "policy" => {
-                    if field_policy.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("policy"));
-                    }
-                    field_policy = Some(map.next_value()?);
-                }
-                "preview_url" => {
-                    if field_preview_url.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("preview_url"));
-                    }
-                    field_preview_url = Some(map.next_value()?);
-                }
-                "access_type" => {
-                    if field_access_type.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("access_type"));
-                    }
-                    field_access_type = Some(map.next_value()?);
-                }
-                "expected_link_metadata" => {
-                    if field_expected_link_metadata.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("expected_link_metadata"));
-                    }
-                    field_expected_link_metadata = Some(map.next_value()?);
-                }
-                "link_metadata" => {
-                    if field_link_metadata.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("link_metadata"));
-                    }
-                    field_link_metadata = Some(map.next_value()?);
-                }
-                "owner_display_names" => {
-                    if field_owner_display_names.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("owner_display_names"));
-                    }
-                    field_owner_display_names = Some(map.next_value()?);
-                }
-                "owner_team" => {
-                    if field_owner_team.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("owner_team"));
-                    }
-                    field_owner_team = Some(map.next_value()?);
-                }
-                "parent_shared_folder_id" => {
-                    if field_parent_shared_folder_id.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("parent_shared_folder_id"));
-                    }
-                    field_parent_shared_folder_id = Some(map.next_value()?);
-                }
-                "path_display" => {
-                    if field_path_display.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("path_display"));
-                    }
-                    field_path_display = Some(map.next_value()?);
-                }
-                "path_lower" => {
-                    if field_path_lower.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("path_lower"));
-                    }
-                    field_path_lower = Some(map.next_value()?);
-                }
-                "permissions" => {
-                    if field_permissions.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("permissions"));
-                    }
-                    field_permissions = Some(map.next_value()?);
-                }
-                "time_invited" => {
-                    if field_time_invited.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("time_invited"));
-                    }
-                    field_time_invited = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "id" => crate::deserialize_field(&mut map, &mut field_id, "id")?,
+                "name" => crate::deserialize_field(&mut map, &mut field_name, "name")?,
+                "policy" => crate::deserialize_field(&mut map, &mut field_policy, "policy")?,
+                "preview_url" => crate::deserialize_field(&mut map, &mut field_preview_url, "preview_url")?,
+                "access_type" => crate::deserialize_field(&mut map, &mut field_access_type, "access_type")?,
+                "expected_link_metadata" => crate::deserialize_field(&mut map, &mut field_expected_link_metadata, "expected_link_metadata")?,
+                "link_metadata" => crate::deserialize_field(&mut map, &mut field_link_metadata, "link_metadata")?,
+                "owner_display_names" => crate::deserialize_field(&mut map, &mut field_owner_display_names, "owner_display_names")?,
+                "owner_team" => crate::deserialize_field(&mut map, &mut field_owner_team, "owner_team")?,
+                "parent_shared_folder_id" => crate::deserialize_field(&mut map, &mut field_parent_shared_folder_id, "parent_shared_folder_id")?,
+                "path_display" => crate::deserialize_field(&mut map, &mut field_path_display, "path_display")?,
+                "path_lower" => crate::deserialize_field(&mut map, &mut field_path_lower, "path_lower")?,
+                "permissions" => crate::deserialize_field(&mut map, &mut field_permissions, "permissions")?,
+                "time_invited" => crate::deserialize_field(&mut map, &mut field_time_invited, "time_invited")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -15795,8 +14583,9 @@ pub enum SharedFolderAccessError {
     /// The shared folder is unmounted.
     Unmounted,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for SharedFolderAccessError {
@@ -15820,7 +14609,7 @@ impl<'de> ::serde::de::Deserialize<'de> for SharedFolderAccessError {
                     "invalid_member" => SharedFolderAccessError::InvalidMember,
                     "email_unverified" => SharedFolderAccessError::EmailUnverified,
                     "unmounted" => SharedFolderAccessError::Unmounted,
-                    _ => SharedFolderAccessError::Other,
+                    _ => SharedFolderAccessError::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -15871,7 +14660,10 @@ impl ::serde::ser::Serialize for SharedFolderAccessError {
                 s.serialize_field(".tag", "unmounted")?;
                 s.end()
             }
-            SharedFolderAccessError::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            SharedFolderAccessError::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -15902,8 +14694,9 @@ pub enum SharedFolderMemberError {
     /// The target member only has inherited access to the shared folder.
     NoExplicitAccess(MemberAccessLevelResult),
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for SharedFolderMemberError {
@@ -15925,7 +14718,7 @@ impl<'de> ::serde::de::Deserialize<'de> for SharedFolderMemberError {
                     "invalid_dropbox_id" => SharedFolderMemberError::InvalidDropboxId,
                     "not_a_member" => SharedFolderMemberError::NotAMember,
                     "no_explicit_access" => SharedFolderMemberError::NoExplicitAccess(MemberAccessLevelResult::internal_deserialize(&mut map)?),
-                    _ => SharedFolderMemberError::Other,
+                    _ => SharedFolderMemberError::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -15963,7 +14756,10 @@ impl ::serde::ser::Serialize for SharedFolderMemberError {
                 x.internal_serialize::<S>(&mut s)?;
                 s.end()
             }
-            SharedFolderMemberError::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            SharedFolderMemberError::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -16042,34 +14838,11 @@ impl SharedFolderMembers {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "users" => {
-                    if field_users.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("users"));
-                    }
-                    field_users = Some(map.next_value()?);
-                }
-                "groups" => {
-                    if field_groups.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("groups"));
-                    }
-                    field_groups = Some(map.next_value()?);
-                }
-                "invitees" => {
-                    if field_invitees.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("invitees"));
-                    }
-                    field_invitees = Some(map.next_value()?);
-                }
-                "cursor" => {
-                    if field_cursor.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("cursor"));
-                    }
-                    field_cursor = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "users" => crate::deserialize_field(&mut map, &mut field_users, "users")?,
+                "groups" => crate::deserialize_field(&mut map, &mut field_groups, "groups")?,
+                "invitees" => crate::deserialize_field(&mut map, &mut field_invitees, "invitees")?,
+                "cursor" => crate::deserialize_field(&mut map, &mut field_cursor, "cursor")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -16303,112 +15076,24 @@ impl SharedFolderMetadata {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "access_type" => {
-                    if field_access_type.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("access_type"));
-                    }
-                    field_access_type = Some(map.next_value()?);
-                }
-                "is_inside_team_folder" => {
-                    if field_is_inside_team_folder.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("is_inside_team_folder"));
-                    }
-                    field_is_inside_team_folder = Some(map.next_value()?);
-                }
-                "is_team_folder" => {
-                    if field_is_team_folder.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("is_team_folder"));
-                    }
-                    field_is_team_folder = Some(map.next_value()?);
-                }
-                "name" => {
-                    if field_name.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("name"));
-                    }
-                    field_name = Some(map.next_value()?);
-                }
-                "policy" => {
-                    if field_policy.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("policy"));
-                    }
-                    field_policy = Some(map.next_value()?);
-                }
-                "preview_url" => {
-                    if field_preview_url.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("preview_url"));
-                    }
-                    field_preview_url = Some(map.next_value()?);
-                }
-                "shared_folder_id" => {
-                    if field_shared_folder_id.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("shared_folder_id"));
-                    }
-                    field_shared_folder_id = Some(map.next_value()?);
-                }
-                "time_invited" => {
-                    if field_time_invited.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("time_invited"));
-                    }
-                    field_time_invited = Some(map.next_value()?);
-                }
-                "owner_display_names" => {
-                    if field_owner_display_names.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("owner_display_names"));
-                    }
-                    field_owner_display_names = Some(map.next_value()?);
-                }
-                "owner_team" => {
-                    if field_owner_team.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("owner_team"));
-                    }
-                    field_owner_team = Some(map.next_value()?);
-                }
-                "parent_shared_folder_id" => {
-                    if field_parent_shared_folder_id.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("parent_shared_folder_id"));
-                    }
-                    field_parent_shared_folder_id = Some(map.next_value()?);
-                }
-                "path_display" => {
-                    if field_path_display.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("path_display"));
-                    }
-                    field_path_display = Some(map.next_value()?);
-                }
-                "path_lower" => {
-                    if field_path_lower.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("path_lower"));
-                    }
-                    field_path_lower = Some(map.next_value()?);
-                }
-                "parent_folder_name" => {
-                    if field_parent_folder_name.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("parent_folder_name"));
-                    }
-                    field_parent_folder_name = Some(map.next_value()?);
-                }
-                "link_metadata" => {
-                    if field_link_metadata.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("link_metadata"));
-                    }
-                    field_link_metadata = Some(map.next_value()?);
-                }
-                "permissions" => {
-                    if field_permissions.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("permissions"));
-                    }
-                    field_permissions = Some(map.next_value()?);
-                }
-                "access_inheritance" => {
-                    if field_access_inheritance.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("access_inheritance"));
-                    }
-                    field_access_inheritance = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "access_type" => crate::deserialize_field(&mut map, &mut field_access_type, "access_type")?,
+                "is_inside_team_folder" => crate::deserialize_field(&mut map, &mut field_is_inside_team_folder, "is_inside_team_folder")?,
+                "is_team_folder" => crate::deserialize_field(&mut map, &mut field_is_team_folder, "is_team_folder")?,
+                "name" => crate::deserialize_field(&mut map, &mut field_name, "name")?,
+                "policy" => crate::deserialize_field(&mut map, &mut field_policy, "policy")?,
+                "preview_url" => crate::deserialize_field(&mut map, &mut field_preview_url, "preview_url")?,
+                "shared_folder_id" => crate::deserialize_field(&mut map, &mut field_shared_folder_id, "shared_folder_id")?,
+                "time_invited" => crate::deserialize_field(&mut map, &mut field_time_invited, "time_invited")?,
+                "owner_display_names" => crate::deserialize_field(&mut map, &mut field_owner_display_names, "owner_display_names")?,
+                "owner_team" => crate::deserialize_field(&mut map, &mut field_owner_team, "owner_team")?,
+                "parent_shared_folder_id" => crate::deserialize_field(&mut map, &mut field_parent_shared_folder_id, "parent_shared_folder_id")?,
+                "path_display" => crate::deserialize_field(&mut map, &mut field_path_display, "path_display")?,
+                "path_lower" => crate::deserialize_field(&mut map, &mut field_path_lower, "path_lower")?,
+                "parent_folder_name" => crate::deserialize_field(&mut map, &mut field_parent_folder_name, "parent_folder_name")?,
+                "link_metadata" => crate::deserialize_field(&mut map, &mut field_link_metadata, "link_metadata")?,
+                "permissions" => crate::deserialize_field(&mut map, &mut field_permissions, "permissions")?,
+                "access_inheritance" => crate::deserialize_field(&mut map, &mut field_access_inheritance, "access_inheritance")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -16638,64 +15323,16 @@ impl SharedFolderMetadataBase {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "access_type" => {
-                    if field_access_type.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("access_type"));
-                    }
-                    field_access_type = Some(map.next_value()?);
-                }
-                "is_inside_team_folder" => {
-                    if field_is_inside_team_folder.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("is_inside_team_folder"));
-                    }
-                    field_is_inside_team_folder = Some(map.next_value()?);
-                }
-                "is_team_folder" => {
-                    if field_is_team_folder.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("is_team_folder"));
-                    }
-                    field_is_team_folder = Some(map.next_value()?);
-                }
-                "owner_display_names" => {
-                    if field_owner_display_names.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("owner_display_names"));
-                    }
-                    field_owner_display_names = Some(map.next_value()?);
-                }
-                "owner_team" => {
-                    if field_owner_team.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("owner_team"));
-                    }
-                    field_owner_team = Some(map.next_value()?);
-                }
-                "parent_shared_folder_id" => {
-                    if field_parent_shared_folder_id.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("parent_shared_folder_id"));
-                    }
-                    field_parent_shared_folder_id = Some(map.next_value()?);
-                }
-                "path_display" => {
-                    if field_path_display.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("path_display"));
-                    }
-                    field_path_display = Some(map.next_value()?);
-                }
-                "path_lower" => {
-                    if field_path_lower.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("path_lower"));
-                    }
-                    field_path_lower = Some(map.next_value()?);
-                }
-                "parent_folder_name" => {
-                    if field_parent_folder_name.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("parent_folder_name"));
-                    }
-                    field_parent_folder_name = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "access_type" => crate::deserialize_field(&mut map, &mut field_access_type, "access_type")?,
+                "is_inside_team_folder" => crate::deserialize_field(&mut map, &mut field_is_inside_team_folder, "is_inside_team_folder")?,
+                "is_team_folder" => crate::deserialize_field(&mut map, &mut field_is_team_folder, "is_team_folder")?,
+                "owner_display_names" => crate::deserialize_field(&mut map, &mut field_owner_display_names, "owner_display_names")?,
+                "owner_team" => crate::deserialize_field(&mut map, &mut field_owner_team, "owner_team")?,
+                "parent_shared_folder_id" => crate::deserialize_field(&mut map, &mut field_parent_shared_folder_id, "parent_shared_folder_id")?,
+                "path_display" => crate::deserialize_field(&mut map, &mut field_path_display, "path_display")?,
+                "path_lower" => crate::deserialize_field(&mut map, &mut field_path_lower, "path_lower")?,
+                "parent_folder_name" => crate::deserialize_field(&mut map, &mut field_parent_folder_name, "parent_folder_name")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -16789,8 +15426,9 @@ pub enum SharedLinkAccessFailureReason {
     /// Access is allowed for the shared link's owner only.
     OwnerOnly,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for SharedLinkAccessFailureReason {
@@ -16814,7 +15452,7 @@ impl<'de> ::serde::de::Deserialize<'de> for SharedLinkAccessFailureReason {
                     "password_required" => SharedLinkAccessFailureReason::PasswordRequired,
                     "team_only" => SharedLinkAccessFailureReason::TeamOnly,
                     "owner_only" => SharedLinkAccessFailureReason::OwnerOnly,
-                    _ => SharedLinkAccessFailureReason::Other,
+                    _ => SharedLinkAccessFailureReason::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -16865,7 +15503,10 @@ impl ::serde::ser::Serialize for SharedLinkAccessFailureReason {
                 s.serialize_field(".tag", "owner_only")?;
                 s.end()
             }
-            SharedLinkAccessFailureReason::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            SharedLinkAccessFailureReason::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -16876,8 +15517,9 @@ pub enum SharedLinkAlreadyExistsMetadata {
     /// Metadata of the shared link that already exists.
     Metadata(SharedLinkMetadata),
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for SharedLinkAlreadyExistsMetadata {
@@ -16903,7 +15545,7 @@ impl<'de> ::serde::de::Deserialize<'de> for SharedLinkAlreadyExistsMetadata {
                             _ => return Err(de::Error::unknown_field(tag, VARIANTS))
                         }
                     }
-                    _ => SharedLinkAlreadyExistsMetadata::Other,
+                    _ => SharedLinkAlreadyExistsMetadata::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -16927,7 +15569,10 @@ impl ::serde::ser::Serialize for SharedLinkAlreadyExistsMetadata {
                 s.serialize_field("metadata", x)?;
                 s.end()
             }
-            SharedLinkAlreadyExistsMetadata::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            SharedLinkAlreadyExistsMetadata::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -16942,8 +15587,9 @@ pub enum SharedLinkError {
     /// This type of link is not supported; use [`files::export()`](crate::files::export) instead.
     UnsupportedLinkType,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for SharedLinkError {
@@ -16965,7 +15611,7 @@ impl<'de> ::serde::de::Deserialize<'de> for SharedLinkError {
                     "shared_link_not_found" => SharedLinkError::SharedLinkNotFound,
                     "shared_link_access_denied" => SharedLinkError::SharedLinkAccessDenied,
                     "unsupported_link_type" => SharedLinkError::UnsupportedLinkType,
-                    _ => SharedLinkError::Other,
+                    _ => SharedLinkError::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -17002,7 +15648,10 @@ impl ::serde::ser::Serialize for SharedLinkError {
                 s.serialize_field(".tag", "unsupported_link_type")?;
                 s.end()
             }
-            SharedLinkError::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            SharedLinkError::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -17095,8 +15744,9 @@ pub enum SharedLinkPolicy {
     /// Links can only be shared among members of the shared folder.
     Members,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for SharedLinkPolicy {
@@ -17118,7 +15768,7 @@ impl<'de> ::serde::de::Deserialize<'de> for SharedLinkPolicy {
                     "anyone" => SharedLinkPolicy::Anyone,
                     "team" => SharedLinkPolicy::Team,
                     "members" => SharedLinkPolicy::Members,
-                    _ => SharedLinkPolicy::Other,
+                    _ => SharedLinkPolicy::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -17155,7 +15805,10 @@ impl ::serde::ser::Serialize for SharedLinkPolicy {
                 s.serialize_field(".tag", "members")?;
                 s.end()
             }
-            SharedLinkPolicy::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            SharedLinkPolicy::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -17241,52 +15894,14 @@ impl SharedLinkSettings {
         let mut field_allow_download = None;
         while let Some(key) = map.next_key::<&str>()? {
             match key {
-                "require_password" => {
-                    if field_require_password.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("require_password"));
-                    }
-                    field_require_password = Some(map.next_value()?);
-                }
-                "link_password" => {
-                    if field_link_password.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("link_password"));
-                    }
-                    field_link_password = Some(map.next_value()?);
-                }
-                "expires" => {
-                    if field_expires.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("expires"));
-                    }
-                    field_expires = Some(map.next_value()?);
-                }
-                "audience" => {
-                    if field_audience.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("audience"));
-                    }
-                    field_audience = Some(map.next_value()?);
-                }
-                "access" => {
-                    if field_access.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("access"));
-                    }
-                    field_access = Some(map.next_value()?);
-                }
-                "requested_visibility" => {
-                    if field_requested_visibility.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("requested_visibility"));
-                    }
-                    field_requested_visibility = Some(map.next_value()?);
-                }
-                "allow_download" => {
-                    if field_allow_download.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("allow_download"));
-                    }
-                    field_allow_download = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "require_password" => crate::deserialize_field(&mut map, &mut field_require_password, "require_password")?,
+                "link_password" => crate::deserialize_field(&mut map, &mut field_link_password, "link_password")?,
+                "expires" => crate::deserialize_field(&mut map, &mut field_expires, "expires")?,
+                "audience" => crate::deserialize_field(&mut map, &mut field_audience, "audience")?,
+                "access" => crate::deserialize_field(&mut map, &mut field_access, "access")?,
+                "requested_visibility" => crate::deserialize_field(&mut map, &mut field_requested_visibility, "requested_visibility")?,
+                "allow_download" => crate::deserialize_field(&mut map, &mut field_allow_download, "allow_download")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         let result = SharedLinkSettings {
@@ -17449,8 +16064,9 @@ pub enum SharingFileAccessError {
     /// A Mac OS X package can't be shared this way. Use a shared link instead.
     InsideOsxPackage,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for SharingFileAccessError {
@@ -17474,7 +16090,7 @@ impl<'de> ::serde::de::Deserialize<'de> for SharingFileAccessError {
                     "is_folder" => SharingFileAccessError::IsFolder,
                     "inside_public_folder" => SharingFileAccessError::InsidePublicFolder,
                     "inside_osx_package" => SharingFileAccessError::InsideOsxPackage,
-                    _ => SharingFileAccessError::Other,
+                    _ => SharingFileAccessError::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -17525,7 +16141,10 @@ impl ::serde::ser::Serialize for SharingFileAccessError {
                 s.serialize_field(".tag", "inside_osx_package")?;
                 s.end()
             }
-            SharingFileAccessError::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            SharingFileAccessError::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -17555,8 +16174,9 @@ pub enum SharingUserError {
     /// [here](https://www.dropbox.com/help/317).
     EmailUnverified,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for SharingUserError {
@@ -17576,7 +16196,7 @@ impl<'de> ::serde::de::Deserialize<'de> for SharingUserError {
                 };
                 let value = match tag {
                     "email_unverified" => SharingUserError::EmailUnverified,
-                    _ => SharingUserError::Other,
+                    _ => SharingUserError::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -17599,7 +16219,10 @@ impl ::serde::ser::Serialize for SharingUserError {
                 s.serialize_field(".tag", "email_unverified")?;
                 s.end()
             }
-            SharingUserError::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            SharingUserError::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -17662,28 +16285,10 @@ impl TeamMemberInfo {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "team_info" => {
-                    if field_team_info.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("team_info"));
-                    }
-                    field_team_info = Some(map.next_value()?);
-                }
-                "display_name" => {
-                    if field_display_name.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("display_name"));
-                    }
-                    field_display_name = Some(map.next_value()?);
-                }
-                "member_id" => {
-                    if field_member_id.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("member_id"));
-                    }
-                    field_member_id = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "team_info" => crate::deserialize_field(&mut map, &mut field_team_info, "team_info")?,
+                "display_name" => crate::deserialize_field(&mut map, &mut field_display_name, "display_name")?,
+                "member_id" => crate::deserialize_field(&mut map, &mut field_member_id, "member_id")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -17779,22 +16384,9 @@ impl TransferFolderArg {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "shared_folder_id" => {
-                    if field_shared_folder_id.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("shared_folder_id"));
-                    }
-                    field_shared_folder_id = Some(map.next_value()?);
-                }
-                "to_dropbox_id" => {
-                    if field_to_dropbox_id.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("to_dropbox_id"));
-                    }
-                    field_to_dropbox_id = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "shared_folder_id" => crate::deserialize_field(&mut map, &mut field_shared_folder_id, "shared_folder_id")?,
+                "to_dropbox_id" => crate::deserialize_field(&mut map, &mut field_to_dropbox_id, "to_dropbox_id")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -17865,8 +16457,9 @@ pub enum TransferFolderError {
     /// The current user does not have permission to perform this action.
     NoPermission,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for TransferFolderError {
@@ -17898,7 +16491,7 @@ impl<'de> ::serde::de::Deserialize<'de> for TransferFolderError {
                     "new_owner_email_unverified" => TransferFolderError::NewOwnerEmailUnverified,
                     "team_folder" => TransferFolderError::TeamFolder,
                     "no_permission" => TransferFolderError::NoPermission,
-                    _ => TransferFolderError::Other,
+                    _ => TransferFolderError::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -17964,7 +16557,10 @@ impl ::serde::ser::Serialize for TransferFolderError {
                 s.serialize_field(".tag", "no_permission")?;
                 s.end()
             }
-            TransferFolderError::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            TransferFolderError::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -17981,7 +16577,7 @@ impl ::std::error::Error for TransferFolderError {
 impl ::std::fmt::Display for TransferFolderError {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         match self {
-            TransferFolderError::AccessError(inner) => write!(f, "{}", inner),
+            TransferFolderError::AccessError(inner) => write!(f, "access_error: {}", inner),
             TransferFolderError::NewOwnerNotAMember => f.write_str("The new designated owner is not currently a member of the shared folder."),
             TransferFolderError::NewOwnerUnmounted => f.write_str("The new designated owner has not added the folder to their Dropbox."),
             TransferFolderError::TeamFolder => f.write_str("This action cannot be performed on a team shared folder."),
@@ -18023,16 +16619,8 @@ impl UnmountFolderArg {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "shared_folder_id" => {
-                    if field_shared_folder_id.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("shared_folder_id"));
-                    }
-                    field_shared_folder_id = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "shared_folder_id" => crate::deserialize_field(&mut map, &mut field_shared_folder_id, "shared_folder_id")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -18092,8 +16680,9 @@ pub enum UnmountFolderError {
     /// folder's parent folder is also a shared folder that resides in the current user's Dropbox.
     NotUnmountable,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for UnmountFolderError {
@@ -18121,7 +16710,7 @@ impl<'de> ::serde::de::Deserialize<'de> for UnmountFolderError {
                     }
                     "no_permission" => UnmountFolderError::NoPermission,
                     "not_unmountable" => UnmountFolderError::NotUnmountable,
-                    _ => UnmountFolderError::Other,
+                    _ => UnmountFolderError::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -18159,7 +16748,10 @@ impl ::serde::ser::Serialize for UnmountFolderError {
                 s.serialize_field(".tag", "not_unmountable")?;
                 s.end()
             }
-            UnmountFolderError::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            UnmountFolderError::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -18176,7 +16768,7 @@ impl ::std::error::Error for UnmountFolderError {
 impl ::std::fmt::Display for UnmountFolderError {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         match self {
-            UnmountFolderError::AccessError(inner) => write!(f, "{}", inner),
+            UnmountFolderError::AccessError(inner) => write!(f, "access_error: {}", inner),
             UnmountFolderError::NoPermission => f.write_str("The current user does not have permission to perform this action."),
             UnmountFolderError::NotUnmountable => f.write_str("The shared folder can't be unmounted. One example where this can occur is when the shared folder's parent folder is also a shared folder that resides in the current user's Dropbox."),
             _ => write!(f, "{:?}", *self),
@@ -18217,16 +16809,8 @@ impl UnshareFileArg {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "file" => {
-                    if field_file.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("file"));
-                    }
-                    field_file = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "file" => crate::deserialize_field(&mut map, &mut field_file, "file")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -18283,8 +16867,9 @@ pub enum UnshareFileError {
     UserError(SharingUserError),
     AccessError(SharingFileAccessError),
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for UnshareFileError {
@@ -18317,7 +16902,7 @@ impl<'de> ::serde::de::Deserialize<'de> for UnshareFileError {
                             _ => return Err(de::Error::unknown_field(tag, VARIANTS))
                         }
                     }
-                    _ => UnshareFileError::Other,
+                    _ => UnshareFileError::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -18349,7 +16934,10 @@ impl ::serde::ser::Serialize for UnshareFileError {
                 s.serialize_field("access_error", x)?;
                 s.end()
             }
-            UnshareFileError::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            UnshareFileError::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -18367,8 +16955,8 @@ impl ::std::error::Error for UnshareFileError {
 impl ::std::fmt::Display for UnshareFileError {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         match self {
-            UnshareFileError::UserError(inner) => write!(f, "{}", inner),
-            UnshareFileError::AccessError(inner) => write!(f, "{}", inner),
+            UnshareFileError::UserError(inner) => write!(f, "user_error: {}", inner),
+            UnshareFileError::AccessError(inner) => write!(f, "access_error: {}", inner),
             _ => write!(f, "{:?}", *self),
         }
     }
@@ -18418,22 +17006,9 @@ impl UnshareFolderArg {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "shared_folder_id" => {
-                    if field_shared_folder_id.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("shared_folder_id"));
-                    }
-                    field_shared_folder_id = Some(map.next_value()?);
-                }
-                "leave_a_copy" => {
-                    if field_leave_a_copy.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("leave_a_copy"));
-                    }
-                    field_leave_a_copy = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "shared_folder_id" => crate::deserialize_field(&mut map, &mut field_shared_folder_id, "shared_folder_id")?,
+                "leave_a_copy" => crate::deserialize_field(&mut map, &mut field_leave_a_copy, "leave_a_copy")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -18498,8 +17073,9 @@ pub enum UnshareFolderError {
     /// This shared folder has too many files to be unshared.
     TooManyFiles,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for UnshareFolderError {
@@ -18528,7 +17104,7 @@ impl<'de> ::serde::de::Deserialize<'de> for UnshareFolderError {
                     "team_folder" => UnshareFolderError::TeamFolder,
                     "no_permission" => UnshareFolderError::NoPermission,
                     "too_many_files" => UnshareFolderError::TooManyFiles,
-                    _ => UnshareFolderError::Other,
+                    _ => UnshareFolderError::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -18573,7 +17149,10 @@ impl ::serde::ser::Serialize for UnshareFolderError {
                 s.serialize_field(".tag", "too_many_files")?;
                 s.end()
             }
-            UnshareFolderError::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            UnshareFolderError::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -18590,7 +17169,7 @@ impl ::std::error::Error for UnshareFolderError {
 impl ::std::fmt::Display for UnshareFolderError {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         match self {
-            UnshareFolderError::AccessError(inner) => write!(f, "{}", inner),
+            UnshareFolderError::AccessError(inner) => write!(f, "access_error: {}", inner),
             UnshareFolderError::TeamFolder => f.write_str("This action cannot be performed on a team shared folder."),
             UnshareFolderError::NoPermission => f.write_str("The current user does not have permission to perform this action."),
             UnshareFolderError::TooManyFiles => f.write_str("This shared folder has too many files to be unshared."),
@@ -18642,28 +17221,10 @@ impl UpdateFileMemberArgs {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "file" => {
-                    if field_file.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("file"));
-                    }
-                    field_file = Some(map.next_value()?);
-                }
-                "member" => {
-                    if field_member.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("member"));
-                    }
-                    field_member = Some(map.next_value()?);
-                }
-                "access_level" => {
-                    if field_access_level.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("access_level"));
-                    }
-                    field_access_level = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "file" => crate::deserialize_field(&mut map, &mut field_file, "file")?,
+                "member" => crate::deserialize_field(&mut map, &mut field_member, "member")?,
+                "access_level" => crate::deserialize_field(&mut map, &mut field_access_level, "access_level")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -18764,28 +17325,10 @@ impl UpdateFolderMemberArg {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "shared_folder_id" => {
-                    if field_shared_folder_id.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("shared_folder_id"));
-                    }
-                    field_shared_folder_id = Some(map.next_value()?);
-                }
-                "member" => {
-                    if field_member.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("member"));
-                    }
-                    field_member = Some(map.next_value()?);
-                }
-                "access_level" => {
-                    if field_access_level.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("access_level"));
-                    }
-                    field_access_level = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "shared_folder_id" => crate::deserialize_field(&mut map, &mut field_shared_folder_id, "shared_folder_id")?,
+                "member" => crate::deserialize_field(&mut map, &mut field_member, "member")?,
+                "access_level" => crate::deserialize_field(&mut map, &mut field_access_level, "access_level")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -18854,8 +17397,9 @@ pub enum UpdateFolderMemberError {
     /// The current user does not have permission to perform this action.
     NoPermission,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for UpdateFolderMemberError {
@@ -18897,7 +17441,7 @@ impl<'de> ::serde::de::Deserialize<'de> for UpdateFolderMemberError {
                     }
                     "insufficient_plan" => UpdateFolderMemberError::InsufficientPlan,
                     "no_permission" => UpdateFolderMemberError::NoPermission,
-                    _ => UpdateFolderMemberError::Other,
+                    _ => UpdateFolderMemberError::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -18951,7 +17495,10 @@ impl ::serde::ser::Serialize for UpdateFolderMemberError {
                 s.serialize_field(".tag", "no_permission")?;
                 s.end()
             }
-            UpdateFolderMemberError::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            UpdateFolderMemberError::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -18970,8 +17517,8 @@ impl ::std::error::Error for UpdateFolderMemberError {
 impl ::std::fmt::Display for UpdateFolderMemberError {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         match self {
-            UpdateFolderMemberError::AccessError(inner) => write!(f, "{}", inner),
-            UpdateFolderMemberError::MemberError(inner) => write!(f, "{}", inner),
+            UpdateFolderMemberError::AccessError(inner) => write!(f, "access_error: {}", inner),
+            UpdateFolderMemberError::MemberError(inner) => write!(f, "member_error: {}", inner),
             UpdateFolderMemberError::NoExplicitAccess(inner) => write!(f, "If updating the access type required the member to be added to the shared folder and there was an error when adding the member: {}", inner),
             UpdateFolderMemberError::InsufficientPlan => f.write_str("The current user's account doesn't support this action. An example of this is when downgrading a member from editor to viewer. This action can only be performed by users that have upgraded to a Pro or Business plan."),
             UpdateFolderMemberError::NoPermission => f.write_str("The current user does not have permission to perform this action."),
@@ -19077,52 +17624,14 @@ impl UpdateFolderPolicyArg {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "shared_folder_id" => {
-                    if field_shared_folder_id.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("shared_folder_id"));
-                    }
-                    field_shared_folder_id = Some(map.next_value()?);
-                }
-                "member_policy" => {
-                    if field_member_policy.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("member_policy"));
-                    }
-                    field_member_policy = Some(map.next_value()?);
-                }
-                "acl_update_policy" => {
-                    if field_acl_update_policy.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("acl_update_policy"));
-                    }
-                    field_acl_update_policy = Some(map.next_value()?);
-                }
-                "viewer_info_policy" => {
-                    if field_viewer_info_policy.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("viewer_info_policy"));
-                    }
-                    field_viewer_info_policy = Some(map.next_value()?);
-                }
-                "shared_link_policy" => {
-                    if field_shared_link_policy.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("shared_link_policy"));
-                    }
-                    field_shared_link_policy = Some(map.next_value()?);
-                }
-                "link_settings" => {
-                    if field_link_settings.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("link_settings"));
-                    }
-                    field_link_settings = Some(map.next_value()?);
-                }
-                "actions" => {
-                    if field_actions.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("actions"));
-                    }
-                    field_actions = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "shared_folder_id" => crate::deserialize_field(&mut map, &mut field_shared_folder_id, "shared_folder_id")?,
+                "member_policy" => crate::deserialize_field(&mut map, &mut field_member_policy, "member_policy")?,
+                "acl_update_policy" => crate::deserialize_field(&mut map, &mut field_acl_update_policy, "acl_update_policy")?,
+                "viewer_info_policy" => crate::deserialize_field(&mut map, &mut field_viewer_info_policy, "viewer_info_policy")?,
+                "shared_link_policy" => crate::deserialize_field(&mut map, &mut field_shared_link_policy, "shared_link_policy")?,
+                "link_settings" => crate::deserialize_field(&mut map, &mut field_link_settings, "link_settings")?,
+                "actions" => crate::deserialize_field(&mut map, &mut field_actions, "actions")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -19213,8 +17722,9 @@ pub enum UpdateFolderPolicyError {
     /// This action cannot be performed on a team shared folder.
     TeamFolder,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for UpdateFolderPolicyError {
@@ -19245,7 +17755,7 @@ impl<'de> ::serde::de::Deserialize<'de> for UpdateFolderPolicyError {
                     "disallowed_shared_link_policy" => UpdateFolderPolicyError::DisallowedSharedLinkPolicy,
                     "no_permission" => UpdateFolderPolicyError::NoPermission,
                     "team_folder" => UpdateFolderPolicyError::TeamFolder,
-                    _ => UpdateFolderPolicyError::Other,
+                    _ => UpdateFolderPolicyError::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -19304,7 +17814,10 @@ impl ::serde::ser::Serialize for UpdateFolderPolicyError {
                 s.serialize_field(".tag", "team_folder")?;
                 s.end()
             }
-            UpdateFolderPolicyError::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            UpdateFolderPolicyError::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -19321,7 +17834,7 @@ impl ::std::error::Error for UpdateFolderPolicyError {
 impl ::std::fmt::Display for UpdateFolderPolicyError {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         match self {
-            UpdateFolderPolicyError::AccessError(inner) => write!(f, "{}", inner),
+            UpdateFolderPolicyError::AccessError(inner) => write!(f, "access_error: {}", inner),
             UpdateFolderPolicyError::NoPermission => f.write_str("The current user does not have permission to perform this action."),
             UpdateFolderPolicyError::TeamFolder => f.write_str("This action cannot be performed on a team shared folder."),
             _ => write!(f, "{:?}", *self),
@@ -19420,52 +17933,14 @@ impl UserFileMembershipInfo {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "access_type" => {
-                    if field_access_type.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("access_type"));
-                    }
-                    field_access_type = Some(map.next_value()?);
-                }
-                "user" => {
-                    if field_user.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("user"));
-                    }
-                    field_user = Some(map.next_value()?);
-                }
-                "permissions" => {
-                    if field_permissions.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("permissions"));
-                    }
-                    field_permissions = Some(map.next_value()?);
-                }
-                "initials" => {
-                    if field_initials.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("initials"));
-                    }
-                    field_initials = Some(map.next_value()?);
-                }
-                "is_inherited" => {
-                    if field_is_inherited.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("is_inherited"));
-                    }
-                    field_is_inherited = Some(map.next_value()?);
-                }
-                "time_last_seen" => {
-                    if field_time_last_seen.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("time_last_seen"));
-                    }
-                    field_time_last_seen = Some(map.next_value()?);
-                }
-                "platform_type" => {
-                    if field_platform_type.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("platform_type"));
-                    }
-                    field_platform_type = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "access_type" => crate::deserialize_field(&mut map, &mut field_access_type, "access_type")?,
+                "user" => crate::deserialize_field(&mut map, &mut field_user, "user")?,
+                "permissions" => crate::deserialize_field(&mut map, &mut field_permissions, "permissions")?,
+                "initials" => crate::deserialize_field(&mut map, &mut field_initials, "initials")?,
+                "is_inherited" => crate::deserialize_field(&mut map, &mut field_is_inherited, "is_inherited")?,
+                "time_last_seen" => crate::deserialize_field(&mut map, &mut field_time_last_seen, "time_last_seen")?,
+                "platform_type" => crate::deserialize_field(&mut map, &mut field_platform_type, "platform_type")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -19614,40 +18089,12 @@ impl UserInfo {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "account_id" => {
-                    if field_account_id.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("account_id"));
-                    }
-                    field_account_id = Some(map.next_value()?);
-                }
-                "email" => {
-                    if field_email.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("email"));
-                    }
-                    field_email = Some(map.next_value()?);
-                }
-                "display_name" => {
-                    if field_display_name.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("display_name"));
-                    }
-                    field_display_name = Some(map.next_value()?);
-                }
-                "same_team" => {
-                    if field_same_team.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("same_team"));
-                    }
-                    field_same_team = Some(map.next_value()?);
-                }
-                "team_member_id" => {
-                    if field_team_member_id.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("team_member_id"));
-                    }
-                    field_team_member_id = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "account_id" => crate::deserialize_field(&mut map, &mut field_account_id, "account_id")?,
+                "email" => crate::deserialize_field(&mut map, &mut field_email, "email")?,
+                "display_name" => crate::deserialize_field(&mut map, &mut field_display_name, "display_name")?,
+                "same_team" => crate::deserialize_field(&mut map, &mut field_same_team, "same_team")?,
+                "team_member_id" => crate::deserialize_field(&mut map, &mut field_team_member_id, "team_member_id")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -19777,40 +18224,12 @@ impl UserMembershipInfo {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "access_type" => {
-                    if field_access_type.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("access_type"));
-                    }
-                    field_access_type = Some(map.next_value()?);
-                }
-                "user" => {
-                    if field_user.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("user"));
-                    }
-                    field_user = Some(map.next_value()?);
-                }
-                "permissions" => {
-                    if field_permissions.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("permissions"));
-                    }
-                    field_permissions = Some(map.next_value()?);
-                }
-                "initials" => {
-                    if field_initials.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("initials"));
-                    }
-                    field_initials = Some(map.next_value()?);
-                }
-                "is_inherited" => {
-                    if field_is_inherited.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("is_inherited"));
-                    }
-                    field_is_inherited = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "access_type" => crate::deserialize_field(&mut map, &mut field_access_type, "access_type")?,
+                "user" => crate::deserialize_field(&mut map, &mut field_user, "user")?,
+                "permissions" => crate::deserialize_field(&mut map, &mut field_permissions, "permissions")?,
+                "initials" => crate::deserialize_field(&mut map, &mut field_initials, "initials")?,
+                "is_inherited" => crate::deserialize_field(&mut map, &mut field_is_inherited, "is_inherited")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -19893,8 +18312,9 @@ pub enum ViewerInfoPolicy {
     /// Viewer information is disabled on this file.
     Disabled,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for ViewerInfoPolicy {
@@ -19915,7 +18335,7 @@ impl<'de> ::serde::de::Deserialize<'de> for ViewerInfoPolicy {
                 let value = match tag {
                     "enabled" => ViewerInfoPolicy::Enabled,
                     "disabled" => ViewerInfoPolicy::Disabled,
-                    _ => ViewerInfoPolicy::Other,
+                    _ => ViewerInfoPolicy::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -19945,7 +18365,10 @@ impl ::serde::ser::Serialize for ViewerInfoPolicy {
                 s.serialize_field(".tag", "disabled")?;
                 s.end()
             }
-            ViewerInfoPolicy::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            ViewerInfoPolicy::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -19967,8 +18390,9 @@ pub enum Visibility {
     /// required.
     SharedFolderOnly,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for Visibility {
@@ -19992,7 +18416,7 @@ impl<'de> ::serde::de::Deserialize<'de> for Visibility {
                     "password" => Visibility::Password,
                     "team_and_password" => Visibility::TeamAndPassword,
                     "shared_folder_only" => Visibility::SharedFolderOnly,
-                    _ => Visibility::Other,
+                    _ => Visibility::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -20043,7 +18467,10 @@ impl ::serde::ser::Serialize for Visibility {
                 s.serialize_field(".tag", "shared_folder_only")?;
                 s.end()
             }
-            Visibility::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            Visibility::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -20107,34 +18534,11 @@ impl VisibilityPolicy {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "policy" => {
-                    if field_policy.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("policy"));
-                    }
-                    field_policy = Some(map.next_value()?);
-                }
-                "resolved_policy" => {
-                    if field_resolved_policy.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("resolved_policy"));
-                    }
-                    field_resolved_policy = Some(map.next_value()?);
-                }
-                "allowed" => {
-                    if field_allowed.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("allowed"));
-                    }
-                    field_allowed = Some(map.next_value()?);
-                }
-                "disallowed_reason" => {
-                    if field_disallowed_reason.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("disallowed_reason"));
-                    }
-                    field_disallowed_reason = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "policy" => crate::deserialize_field(&mut map, &mut field_policy, "policy")?,
+                "resolved_policy" => crate::deserialize_field(&mut map, &mut field_resolved_policy, "resolved_policy")?,
+                "allowed" => crate::deserialize_field(&mut map, &mut field_allowed, "allowed")?,
+                "disallowed_reason" => crate::deserialize_field(&mut map, &mut field_disallowed_reason, "disallowed_reason")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -20209,8 +18613,9 @@ pub enum VisibilityPolicyDisallowedReason {
     /// The user does not have permission.
     PermissionDenied,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for VisibilityPolicyDisallowedReason {
@@ -20235,7 +18640,7 @@ impl<'de> ::serde::de::Deserialize<'de> for VisibilityPolicyDisallowedReason {
                     "user_not_on_team" => VisibilityPolicyDisallowedReason::UserNotOnTeam,
                     "user_account_type" => VisibilityPolicyDisallowedReason::UserAccountType,
                     "permission_denied" => VisibilityPolicyDisallowedReason::PermissionDenied,
-                    _ => VisibilityPolicyDisallowedReason::Other,
+                    _ => VisibilityPolicyDisallowedReason::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -20293,7 +18698,10 @@ impl ::serde::ser::Serialize for VisibilityPolicyDisallowedReason {
                 s.serialize_field(".tag", "permission_denied")?;
                 s.end()
             }
-            VisibilityPolicyDisallowedReason::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            VisibilityPolicyDisallowedReason::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
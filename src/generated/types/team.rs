@@ -23,6 +23,109 @@ pub type SecondaryEmail = crate::types::secondary_emails::SecondaryEmail;
 pub type TeamMemberRoleId = String;
 pub type UserQuota = u32;
 
+/// The names of this namespace's routes, as they appear in request URLs after the
+/// [`Endpoint`](crate::client_trait_common::Endpoint) base URL, for referring to them in logs and
+/// metrics without spelling them out.
+pub mod routes {
+    pub const DEVICES_LIST_MEMBER_DEVICES: &str = "team/devices/list_member_devices";
+    pub const DEVICES_LIST_MEMBERS_DEVICES: &str = "team/devices/list_members_devices";
+    pub const DEVICES_LIST_TEAM_DEVICES: &str = "team/devices/list_team_devices";
+    pub const DEVICES_REVOKE_DEVICE_SESSION: &str = "team/devices/revoke_device_session";
+    pub const DEVICES_REVOKE_DEVICE_SESSION_BATCH: &str = "team/devices/revoke_device_session_batch";
+    pub const FEATURES_GET_VALUES: &str = "team/features/get_values";
+    pub const GET_INFO: &str = "team/get_info";
+    pub const GROUPS_CREATE: &str = "team/groups/create";
+    pub const GROUPS_DELETE: &str = "team/groups/delete";
+    pub const GROUPS_GET_INFO: &str = "team/groups/get_info";
+    pub const GROUPS_JOB_STATUS_GET: &str = "team/groups/job_status/get";
+    pub const GROUPS_LIST: &str = "team/groups/list";
+    pub const GROUPS_LIST_CONTINUE: &str = "team/groups/list/continue";
+    pub const GROUPS_MEMBERS_ADD: &str = "team/groups/members/add";
+    pub const GROUPS_MEMBERS_LIST: &str = "team/groups/members/list";
+    pub const GROUPS_MEMBERS_LIST_CONTINUE: &str = "team/groups/members/list/continue";
+    pub const GROUPS_MEMBERS_REMOVE: &str = "team/groups/members/remove";
+    pub const GROUPS_MEMBERS_SET_ACCESS_TYPE: &str = "team/groups/members/set_access_type";
+    pub const GROUPS_UPDATE: &str = "team/groups/update";
+    pub const LEGAL_HOLDS_CREATE_POLICY: &str = "team/legal_holds/create_policy";
+    pub const LEGAL_HOLDS_GET_POLICY: &str = "team/legal_holds/get_policy";
+    pub const LEGAL_HOLDS_LIST_HELD_REVISIONS: &str = "team/legal_holds/list_held_revisions";
+    pub const LEGAL_HOLDS_LIST_HELD_REVISIONS_CONTINUE: &str = "team/legal_holds/list_held_revisions_continue";
+    pub const LEGAL_HOLDS_LIST_POLICIES: &str = "team/legal_holds/list_policies";
+    pub const LEGAL_HOLDS_RELEASE_POLICY: &str = "team/legal_holds/release_policy";
+    pub const LEGAL_HOLDS_UPDATE_POLICY: &str = "team/legal_holds/update_policy";
+    pub const LINKED_APPS_LIST_MEMBER_LINKED_APPS: &str = "team/linked_apps/list_member_linked_apps";
+    pub const LINKED_APPS_LIST_MEMBERS_LINKED_APPS: &str = "team/linked_apps/list_members_linked_apps";
+    pub const LINKED_APPS_LIST_TEAM_LINKED_APPS: &str = "team/linked_apps/list_team_linked_apps";
+    pub const LINKED_APPS_REVOKE_LINKED_APP: &str = "team/linked_apps/revoke_linked_app";
+    pub const LINKED_APPS_REVOKE_LINKED_APP_BATCH: &str = "team/linked_apps/revoke_linked_app_batch";
+    pub const MEMBER_SPACE_LIMITS_EXCLUDED_USERS_ADD: &str = "team/member_space_limits/excluded_users/add";
+    pub const MEMBER_SPACE_LIMITS_EXCLUDED_USERS_LIST: &str = "team/member_space_limits/excluded_users/list";
+    pub const MEMBER_SPACE_LIMITS_EXCLUDED_USERS_LIST_CONTINUE: &str = "team/member_space_limits/excluded_users/list/continue";
+    pub const MEMBER_SPACE_LIMITS_EXCLUDED_USERS_REMOVE: &str = "team/member_space_limits/excluded_users/remove";
+    pub const MEMBER_SPACE_LIMITS_GET_CUSTOM_QUOTA: &str = "team/member_space_limits/get_custom_quota";
+    pub const MEMBER_SPACE_LIMITS_REMOVE_CUSTOM_QUOTA: &str = "team/member_space_limits/remove_custom_quota";
+    pub const MEMBER_SPACE_LIMITS_SET_CUSTOM_QUOTA: &str = "team/member_space_limits/set_custom_quota";
+    pub const MEMBERS_ADD_V2: &str = "team/members/add_v2";
+    pub const MEMBERS_ADD: &str = "team/members/add";
+    pub const MEMBERS_ADD_JOB_STATUS_GET_V2: &str = "team/members/add/job_status/get_v2";
+    pub const MEMBERS_ADD_JOB_STATUS_GET: &str = "team/members/add/job_status/get";
+    pub const MEMBERS_DELETE_PROFILE_PHOTO_V2: &str = "team/members/delete_profile_photo_v2";
+    pub const MEMBERS_DELETE_PROFILE_PHOTO: &str = "team/members/delete_profile_photo";
+    pub const MEMBERS_GET_AVAILABLE_TEAM_MEMBER_ROLES: &str = "team/members/get_available_team_member_roles";
+    pub const MEMBERS_GET_INFO_V2: &str = "team/members/get_info_v2";
+    pub const MEMBERS_GET_INFO: &str = "team/members/get_info";
+    pub const MEMBERS_LIST_V2: &str = "team/members/list_v2";
+    pub const MEMBERS_LIST: &str = "team/members/list";
+    pub const MEMBERS_LIST_CONTINUE_V2: &str = "team/members/list/continue_v2";
+    pub const MEMBERS_LIST_CONTINUE: &str = "team/members/list/continue";
+    pub const MEMBERS_MOVE_FORMER_MEMBER_FILES: &str = "team/members/move_former_member_files";
+    pub const MEMBERS_MOVE_FORMER_MEMBER_FILES_JOB_STATUS_CHECK: &str = "team/members/move_former_member_files/job_status/check";
+    pub const MEMBERS_RECOVER: &str = "team/members/recover";
+    pub const MEMBERS_REMOVE: &str = "team/members/remove";
+    pub const MEMBERS_REMOVE_JOB_STATUS_GET: &str = "team/members/remove/job_status/get";
+    pub const MEMBERS_SECONDARY_EMAILS_ADD: &str = "team/members/secondary_emails/add";
+    pub const MEMBERS_SECONDARY_EMAILS_DELETE: &str = "team/members/secondary_emails/delete";
+    pub const MEMBERS_SECONDARY_EMAILS_RESEND_VERIFICATION_EMAILS: &str = "team/members/secondary_emails/resend_verification_emails";
+    pub const MEMBERS_SEND_WELCOME_EMAIL: &str = "team/members/send_welcome_email";
+    pub const MEMBERS_SET_ADMIN_PERMISSIONS_V2: &str = "team/members/set_admin_permissions_v2";
+    pub const MEMBERS_SET_ADMIN_PERMISSIONS: &str = "team/members/set_admin_permissions";
+    pub const MEMBERS_SET_PROFILE_V2: &str = "team/members/set_profile_v2";
+    pub const MEMBERS_SET_PROFILE: &str = "team/members/set_profile";
+    pub const MEMBERS_SET_PROFILE_PHOTO_V2: &str = "team/members/set_profile_photo_v2";
+    pub const MEMBERS_SET_PROFILE_PHOTO: &str = "team/members/set_profile_photo";
+    pub const MEMBERS_SUSPEND: &str = "team/members/suspend";
+    pub const MEMBERS_UNSUSPEND: &str = "team/members/unsuspend";
+    pub const NAMESPACES_LIST: &str = "team/namespaces/list";
+    pub const NAMESPACES_LIST_CONTINUE: &str = "team/namespaces/list/continue";
+    pub const PROPERTIES_TEMPLATE_ADD: &str = "team/properties/template/add";
+    pub const PROPERTIES_TEMPLATE_GET: &str = "team/properties/template/get";
+    pub const PROPERTIES_TEMPLATE_LIST: &str = "team/properties/template/list";
+    pub const PROPERTIES_TEMPLATE_UPDATE: &str = "team/properties/template/update";
+    pub const REPORTS_GET_ACTIVITY: &str = "team/reports/get_activity";
+    pub const REPORTS_GET_DEVICES: &str = "team/reports/get_devices";
+    pub const REPORTS_GET_MEMBERSHIP: &str = "team/reports/get_membership";
+    pub const REPORTS_GET_STORAGE: &str = "team/reports/get_storage";
+    #[cfg(feature = "unstable")]
+    pub const SHARING_ALLOWLIST_ADD: &str = "team/sharing_allowlist/add";
+    #[cfg(feature = "unstable")]
+    pub const SHARING_ALLOWLIST_LIST: &str = "team/sharing_allowlist/list";
+    #[cfg(feature = "unstable")]
+    pub const SHARING_ALLOWLIST_LIST_CONTINUE: &str = "team/sharing_allowlist/list/continue";
+    #[cfg(feature = "unstable")]
+    pub const SHARING_ALLOWLIST_REMOVE: &str = "team/sharing_allowlist/remove";
+    pub const TEAM_FOLDER_ACTIVATE: &str = "team/team_folder/activate";
+    pub const TEAM_FOLDER_ARCHIVE: &str = "team/team_folder/archive";
+    pub const TEAM_FOLDER_ARCHIVE_CHECK: &str = "team/team_folder/archive/check";
+    pub const TEAM_FOLDER_CREATE: &str = "team/team_folder/create";
+    pub const TEAM_FOLDER_GET_INFO: &str = "team/team_folder/get_info";
+    pub const TEAM_FOLDER_LIST: &str = "team/team_folder/list";
+    pub const TEAM_FOLDER_LIST_CONTINUE: &str = "team/team_folder/list/continue";
+    pub const TEAM_FOLDER_PERMANENTLY_DELETE: &str = "team/team_folder/permanently_delete";
+    pub const TEAM_FOLDER_RENAME: &str = "team/team_folder/rename";
+    pub const TEAM_FOLDER_UPDATE_SYNC_SETTINGS: &str = "team/team_folder/update_sync_settings";
+    pub const TOKEN_GET_AUTHENTICATED_ADMIN: &str = "team/token/get_authenticated_admin";
+}
+
 /// Information on active web sessions.
 #[derive(Debug, Clone, PartialEq, Eq)]
 #[non_exhaustive] // structs may have more fields added in the future.
@@ -121,64 +224,16 @@ impl ActiveWebSession {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "session_id" => {
-                    if field_session_id.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("session_id"));
-                    }
-                    field_session_id = Some(map.next_value()?);
-                }
-                "user_agent" => {
-                    if field_user_agent.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("user_agent"));
-                    }
-                    field_user_agent = Some(map.next_value()?);
-                }
-                "os" => {
-                    if field_os.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("os"));
-                    }
-                    field_os = Some(map.next_value()?);
-                }
-                "browser" => {
-                    if field_browser.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("browser"));
-                    }
-                    field_browser = Some(map.next_value()?);
-                }
-                "ip_address" => {
-                    if field_ip_address.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("ip_address"));
-                    }
-                    field_ip_address = Some(map.next_value()?);
-                }
-                "country" => {
-                    if field_country.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("country"));
-                    }
-                    field_country = Some(map.next_value()?);
-                }
-                "created" => {
-                    if field_created.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("created"));
-                    }
-                    field_created = Some(map.next_value()?);
-                }
-                "updated" => {
-                    if field_updated.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("updated"));
-                    }
-                    field_updated = Some(map.next_value()?);
-                }
-                "expires" => {
-                    if field_expires.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("expires"));
-                    }
-                    field_expires = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "session_id" => crate::deserialize_field(&mut map, &mut field_session_id, "session_id")?,
+                "user_agent" => crate::deserialize_field(&mut map, &mut field_user_agent, "user_agent")?,
+                "os" => crate::deserialize_field(&mut map, &mut field_os, "os")?,
+                "browser" => crate::deserialize_field(&mut map, &mut field_browser, "browser")?,
+                "ip_address" => crate::deserialize_field(&mut map, &mut field_ip_address, "ip_address")?,
+                "country" => crate::deserialize_field(&mut map, &mut field_country, "country")?,
+                "created" => crate::deserialize_field(&mut map, &mut field_created, "created")?,
+                "updated" => crate::deserialize_field(&mut map, &mut field_updated, "updated")?,
+                "expires" => crate::deserialize_field(&mut map, &mut field_expires, "expires")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -291,8 +346,9 @@ pub enum AddSecondaryEmailResult {
     /// Too many emails are being sent to this email address. Please try again later.
     RateLimited(crate::types::common::EmailAddress),
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for AddSecondaryEmailResult {
@@ -368,7 +424,7 @@ impl<'de> ::serde::de::Deserialize<'de> for AddSecondaryEmailResult {
                             _ => return Err(de::Error::unknown_field(tag, VARIANTS))
                         }
                     }
-                    _ => AddSecondaryEmailResult::Other,
+                    _ => AddSecondaryEmailResult::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -456,7 +512,10 @@ impl ::serde::ser::Serialize for AddSecondaryEmailResult {
                 s.serialize_field("rate_limited", x)?;
                 s.end()
             }
-            AddSecondaryEmailResult::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            AddSecondaryEmailResult::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -493,16 +552,8 @@ impl AddSecondaryEmailsArg {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "new_secondary_emails" => {
-                    if field_new_secondary_emails.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("new_secondary_emails"));
-                    }
-                    field_new_secondary_emails = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "new_secondary_emails" => crate::deserialize_field(&mut map, &mut field_new_secondary_emails, "new_secondary_emails")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -561,8 +612,9 @@ pub enum AddSecondaryEmailsError {
     /// A maximum of 20 secondary emails can be added in a single call.
     TooManyEmails,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for AddSecondaryEmailsError {
@@ -583,7 +635,7 @@ impl<'de> ::serde::de::Deserialize<'de> for AddSecondaryEmailsError {
                 let value = match tag {
                     "secondary_emails_disabled" => AddSecondaryEmailsError::SecondaryEmailsDisabled,
                     "too_many_emails" => AddSecondaryEmailsError::TooManyEmails,
-                    _ => AddSecondaryEmailsError::Other,
+                    _ => AddSecondaryEmailsError::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -613,7 +665,10 @@ impl ::serde::ser::Serialize for AddSecondaryEmailsError {
                 s.serialize_field(".tag", "too_many_emails")?;
                 s.end()
             }
-            AddSecondaryEmailsError::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            AddSecondaryEmailsError::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -663,16 +718,8 @@ impl AddSecondaryEmailsResult {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "results" => {
-                    if field_results.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("results"));
-                    }
-                    field_results = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "results" => crate::deserialize_field(&mut map, &mut field_results, "results")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -876,46 +923,13 @@ impl ApiApp {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "app_id" => {
-                    if field_app_id.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("app_id"));
-                    }
-                    field_app_id = Some(map.next_value()?);
-                }
-                "app_name" => {
-                    if field_app_name.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("app_name"));
-                    }
-                    field_app_name = Some(map.next_value()?);
-                }
-                "is_app_folder" => {
-                    if field_is_app_folder.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("is_app_folder"));
-                    }
-                    field_is_app_folder = Some(map.next_value()?);
-                }
-                "publisher" => {
-                    if field_publisher.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("publisher"));
-                    }
-                    field_publisher = Some(map.next_value()?);
-                }
-                "publisher_url" => {
-                    if field_publisher_url.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("publisher_url"));
-                    }
-                    field_publisher_url = Some(map.next_value()?);
-                }
-                "linked" => {
-                    if field_linked.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("linked"));
-                    }
-                    field_linked = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "app_id" => crate::deserialize_field(&mut map, &mut field_app_id, "app_id")?,
+                "app_name" => crate::deserialize_field(&mut map, &mut field_app_name, "app_name")?,
+                "is_app_folder" => crate::deserialize_field(&mut map, &mut field_is_app_folder, "is_app_folder")?,
+                "publisher" => crate::deserialize_field(&mut map, &mut field_publisher, "publisher")?,
+                "publisher_url" => crate::deserialize_field(&mut map, &mut field_publisher_url, "publisher_url")?,
+                "linked" => crate::deserialize_field(&mut map, &mut field_linked, "linked")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -1014,16 +1028,8 @@ impl BaseDfbReport {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "start_date" => {
-                    if field_start_date.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("start_date"));
-                    }
-                    field_start_date = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "start_date" => crate::deserialize_field(&mut map, &mut field_start_date, "start_date")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -1081,8 +1087,9 @@ pub enum BaseTeamFolderError {
     StatusError(TeamFolderInvalidStatusError),
     TeamSharedDropboxError(TeamFolderTeamSharedDropboxError),
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for BaseTeamFolderError {
@@ -1122,7 +1129,7 @@ impl<'de> ::serde::de::Deserialize<'de> for BaseTeamFolderError {
                             _ => return Err(de::Error::unknown_field(tag, VARIANTS))
                         }
                     }
-                    _ => BaseTeamFolderError::Other,
+                    _ => BaseTeamFolderError::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -1162,7 +1169,10 @@ impl ::serde::ser::Serialize for BaseTeamFolderError {
                 s.serialize_field("team_shared_dropbox_error", x)?;
                 s.end()
             }
-            BaseTeamFolderError::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            BaseTeamFolderError::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -1181,9 +1191,9 @@ impl ::std::error::Error for BaseTeamFolderError {
 impl ::std::fmt::Display for BaseTeamFolderError {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         match self {
-            BaseTeamFolderError::AccessError(inner) => write!(f, "{}", inner),
-            BaseTeamFolderError::StatusError(inner) => write!(f, "{}", inner),
-            BaseTeamFolderError::TeamSharedDropboxError(inner) => write!(f, "{}", inner),
+            BaseTeamFolderError::AccessError(inner) => write!(f, "access_error: {}", inner),
+            BaseTeamFolderError::StatusError(inner) => write!(f, "status_error: {}", inner),
+            BaseTeamFolderError::TeamSharedDropboxError(inner) => write!(f, "team_shared_dropbox_error: {}", inner),
             _ => write!(f, "{:?}", *self),
         }
     }
@@ -1196,8 +1206,9 @@ pub enum CustomQuotaError {
     /// A maximum of 1000 users can be set for a single call.
     TooManyUsers,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for CustomQuotaError {
@@ -1217,7 +1228,7 @@ impl<'de> ::serde::de::Deserialize<'de> for CustomQuotaError {
                 };
                 let value = match tag {
                     "too_many_users" => CustomQuotaError::TooManyUsers,
-                    _ => CustomQuotaError::Other,
+                    _ => CustomQuotaError::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -1240,7 +1251,10 @@ impl ::serde::ser::Serialize for CustomQuotaError {
                 s.serialize_field(".tag", "too_many_users")?;
                 s.end()
             }
-            CustomQuotaError::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            CustomQuotaError::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -1266,8 +1280,9 @@ pub enum CustomQuotaResult {
     /// Invalid user (not in team).
     InvalidUser(UserSelectorArg),
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for CustomQuotaResult {
@@ -1294,7 +1309,7 @@ impl<'de> ::serde::de::Deserialize<'de> for CustomQuotaResult {
                             _ => return Err(de::Error::unknown_field(tag, VARIANTS))
                         }
                     }
-                    _ => CustomQuotaResult::Other,
+                    _ => CustomQuotaResult::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -1326,7 +1341,10 @@ impl ::serde::ser::Serialize for CustomQuotaResult {
                 s.serialize_field("invalid_user", x)?;
                 s.end()
             }
-            CustomQuotaResult::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            CustomQuotaResult::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -1363,16 +1381,8 @@ impl CustomQuotaUsersArg {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "users" => {
-                    if field_users.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("users"));
-                    }
-                    field_users = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "users" => crate::deserialize_field(&mut map, &mut field_users, "users")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -1456,22 +1466,9 @@ impl DateRange {
         let mut field_end_date = None;
         while let Some(key) = map.next_key::<&str>()? {
             match key {
-                "start_date" => {
-                    if field_start_date.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("start_date"));
-                    }
-                    field_start_date = Some(map.next_value()?);
-                }
-                "end_date" => {
-                    if field_end_date.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("end_date"));
-                    }
-                    field_end_date = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "start_date" => crate::deserialize_field(&mut map, &mut field_start_date, "start_date")?,
+                "end_date" => crate::deserialize_field(&mut map, &mut field_end_date, "end_date")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         let result = DateRange {
@@ -1529,8 +1526,9 @@ impl ::serde::ser::Serialize for DateRange {
 #[non_exhaustive] // variants may be added in the future
 pub enum DateRangeError {
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for DateRangeError {
@@ -1549,9 +1547,7 @@ impl<'de> ::serde::de::Deserialize<'de> for DateRangeError {
                     _ => return Err(de::Error::missing_field(".tag"))
                 };
                 // open enum with no defined variants
-                let _ = tag;
-                crate::eat_json_fields(&mut map)?;
-                Ok(DateRangeError::Other)
+                Ok(DateRangeError::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?))
             }
         }
         const VARIANTS: &[&str] = &["other"];
@@ -1562,8 +1558,9 @@ impl<'de> ::serde::de::Deserialize<'de> for DateRangeError {
 impl ::serde::ser::Serialize for DateRangeError {
     fn serialize<S: ::serde::ser::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
         // union serializer
-        #![allow(unused_variables)]
-        Err(::serde::ser::Error::custom("cannot serialize an open union with no defined variants"))
+        // catch-all
+        let DateRangeError::Other(ref x) = *self;
+        ::serde::ser::Serialize::serialize(x, serializer)
     }
 }
 
@@ -1589,8 +1586,9 @@ pub enum DeleteSecondaryEmailResult {
     /// The email address is the primary email address of the user, and cannot be removed.
     CannotRemovePrimary(crate::types::common::EmailAddress),
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for DeleteSecondaryEmailResult {
@@ -1630,7 +1628,7 @@ impl<'de> ::serde::de::Deserialize<'de> for DeleteSecondaryEmailResult {
                             _ => return Err(de::Error::unknown_field(tag, VARIANTS))
                         }
                     }
-                    _ => DeleteSecondaryEmailResult::Other,
+                    _ => DeleteSecondaryEmailResult::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -1670,7 +1668,10 @@ impl ::serde::ser::Serialize for DeleteSecondaryEmailResult {
                 s.serialize_field("cannot_remove_primary", x)?;
                 s.end()
             }
-            DeleteSecondaryEmailResult::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            DeleteSecondaryEmailResult::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -1707,16 +1708,8 @@ impl DeleteSecondaryEmailsArg {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "emails_to_delete" => {
-                    if field_emails_to_delete.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("emails_to_delete"));
-                    }
-                    field_emails_to_delete = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "emails_to_delete" => crate::deserialize_field(&mut map, &mut field_emails_to_delete, "emails_to_delete")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -1797,16 +1790,8 @@ impl DeleteSecondaryEmailsResult {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "results" => {
-                    if field_results.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("results"));
-                    }
-                    field_results = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "results" => crate::deserialize_field(&mut map, &mut field_results, "results")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -1961,70 +1946,17 @@ impl DesktopClientSession {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "session_id" => {
-                    if field_session_id.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("session_id"));
-                    }
-                    field_session_id = Some(map.next_value()?);
-                }
-                "host_name" => {
-                    if field_host_name.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("host_name"));
-                    }
-                    field_host_name = Some(map.next_value()?);
-                }
-                "client_type" => {
-                    if field_client_type.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("client_type"));
-                    }
-                    field_client_type = Some(map.next_value()?);
-                }
-                "client_version" => {
-                    if field_client_version.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("client_version"));
-                    }
-                    field_client_version = Some(map.next_value()?);
-                }
-                "platform" => {
-                    if field_platform.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("platform"));
-                    }
-                    field_platform = Some(map.next_value()?);
-                }
-                "is_delete_on_unlink_supported" => {
-                    if field_is_delete_on_unlink_supported.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("is_delete_on_unlink_supported"));
-                    }
-                    field_is_delete_on_unlink_supported = Some(map.next_value()?);
-                }
-                "ip_address" => {
-                    if field_ip_address.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("ip_address"));
-                    }
-                    field_ip_address = Some(map.next_value()?);
-                }
-                "country" => {
-                    if field_country.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("country"));
-                    }
-                    field_country = Some(map.next_value()?);
-                }
-                "created" => {
-                    if field_created.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("created"));
-                    }
-                    field_created = Some(map.next_value()?);
-                }
-                "updated" => {
-                    if field_updated.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("updated"));
-                    }
-                    field_updated = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "session_id" => crate::deserialize_field(&mut map, &mut field_session_id, "session_id")?,
+                "host_name" => crate::deserialize_field(&mut map, &mut field_host_name, "host_name")?,
+                "client_type" => crate::deserialize_field(&mut map, &mut field_client_type, "client_type")?,
+                "client_version" => crate::deserialize_field(&mut map, &mut field_client_version, "client_version")?,
+                "platform" => crate::deserialize_field(&mut map, &mut field_platform, "platform")?,
+                "is_delete_on_unlink_supported" => crate::deserialize_field(&mut map, &mut field_is_delete_on_unlink_supported, "is_delete_on_unlink_supported")?,
+                "ip_address" => crate::deserialize_field(&mut map, &mut field_ip_address, "ip_address")?,
+                "country" => crate::deserialize_field(&mut map, &mut field_country, "country")?,
+                "created" => crate::deserialize_field(&mut map, &mut field_created, "created")?,
+                "updated" => crate::deserialize_field(&mut map, &mut field_updated, "updated")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -2122,8 +2054,9 @@ pub enum DesktopPlatform {
     /// Official Linux Dropbox desktop client.
     Linux,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for DesktopPlatform {
@@ -2145,7 +2078,7 @@ impl<'de> ::serde::de::Deserialize<'de> for DesktopPlatform {
                     "windows" => DesktopPlatform::Windows,
                     "mac" => DesktopPlatform::Mac,
                     "linux" => DesktopPlatform::Linux,
-                    _ => DesktopPlatform::Other,
+                    _ => DesktopPlatform::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -2182,7 +2115,10 @@ impl ::serde::ser::Serialize for DesktopPlatform {
                 s.serialize_field(".tag", "linux")?;
                 s.end()
             }
-            DesktopPlatform::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            DesktopPlatform::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -2259,40 +2195,12 @@ impl DeviceSession {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "session_id" => {
-                    if field_session_id.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("session_id"));
-                    }
-                    field_session_id = Some(map.next_value()?);
-                }
-                "ip_address" => {
-                    if field_ip_address.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("ip_address"));
-                    }
-                    field_ip_address = Some(map.next_value()?);
-                }
-                "country" => {
-                    if field_country.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("country"));
-                    }
-                    field_country = Some(map.next_value()?);
-                }
-                "created" => {
-                    if field_created.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("created"));
-                    }
-                    field_created = Some(map.next_value()?);
-                }
-                "updated" => {
-                    if field_updated.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("updated"));
-                    }
-                    field_updated = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "session_id" => crate::deserialize_field(&mut map, &mut field_session_id, "session_id")?,
+                "ip_address" => crate::deserialize_field(&mut map, &mut field_ip_address, "ip_address")?,
+                "country" => crate::deserialize_field(&mut map, &mut field_country, "country")?,
+                "created" => crate::deserialize_field(&mut map, &mut field_created, "created")?,
+                "updated" => crate::deserialize_field(&mut map, &mut field_updated, "updated")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -2395,22 +2303,9 @@ impl DeviceSessionArg {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "session_id" => {
-                    if field_session_id.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("session_id"));
-                    }
-                    field_session_id = Some(map.next_value()?);
-                }
-                "team_member_id" => {
-                    if field_team_member_id.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("team_member_id"));
-                    }
-                    field_team_member_id = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "session_id" => crate::deserialize_field(&mut map, &mut field_session_id, "session_id")?,
+                "team_member_id" => crate::deserialize_field(&mut map, &mut field_team_member_id, "team_member_id")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -2535,52 +2430,14 @@ impl DevicesActive {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "windows" => {
-                    if field_windows.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("windows"));
-                    }
-                    field_windows = Some(map.next_value()?);
-                }
-                "macos" => {
-                    if field_macos.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("macos"));
-                    }
-                    field_macos = Some(map.next_value()?);
-                }
-                "linux" => {
-                    if field_linux.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("linux"));
-                    }
-                    field_linux = Some(map.next_value()?);
-                }
-                "ios" => {
-                    if field_ios.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("ios"));
-                    }
-                    field_ios = Some(map.next_value()?);
-                }
-                "android" => {
-                    if field_android.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("android"));
-                    }
-                    field_android = Some(map.next_value()?);
-                }
-                "other" => {
-                    if field_other.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("other"));
-                    }
-                    field_other = Some(map.next_value()?);
-                }
-                "total" => {
-                    if field_total.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("total"));
-                    }
-                    field_total = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "windows" => crate::deserialize_field(&mut map, &mut field_windows, "windows")?,
+                "macos" => crate::deserialize_field(&mut map, &mut field_macos, "macos")?,
+                "linux" => crate::deserialize_field(&mut map, &mut field_linux, "linux")?,
+                "ios" => crate::deserialize_field(&mut map, &mut field_ios, "ios")?,
+                "android" => crate::deserialize_field(&mut map, &mut field_android, "android")?,
+                "other" => crate::deserialize_field(&mut map, &mut field_other, "other")?,
+                "total" => crate::deserialize_field(&mut map, &mut field_total, "total")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -2674,16 +2531,8 @@ impl ExcludedUsersListArg {
         let mut field_limit = None;
         while let Some(key) = map.next_key::<&str>()? {
             match key {
-                "limit" => {
-                    if field_limit.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("limit"));
-                    }
-                    field_limit = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "limit" => crate::deserialize_field(&mut map, &mut field_limit, "limit")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         let result = ExcludedUsersListArg {
@@ -2765,16 +2614,8 @@ impl ExcludedUsersListContinueArg {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "cursor" => {
-                    if field_cursor.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("cursor"));
-                    }
-                    field_cursor = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "cursor" => crate::deserialize_field(&mut map, &mut field_cursor, "cursor")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -2831,8 +2672,9 @@ pub enum ExcludedUsersListContinueError {
     /// The cursor is invalid.
     InvalidCursor,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for ExcludedUsersListContinueError {
@@ -2852,7 +2694,7 @@ impl<'de> ::serde::de::Deserialize<'de> for ExcludedUsersListContinueError {
                 };
                 let value = match tag {
                     "invalid_cursor" => ExcludedUsersListContinueError::InvalidCursor,
-                    _ => ExcludedUsersListContinueError::Other,
+                    _ => ExcludedUsersListContinueError::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -2875,7 +2717,10 @@ impl ::serde::ser::Serialize for ExcludedUsersListContinueError {
                 s.serialize_field(".tag", "invalid_cursor")?;
                 s.end()
             }
-            ExcludedUsersListContinueError::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            ExcludedUsersListContinueError::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -2899,8 +2744,9 @@ pub enum ExcludedUsersListError {
     /// An error occurred.
     ListError,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for ExcludedUsersListError {
@@ -2920,7 +2766,7 @@ impl<'de> ::serde::de::Deserialize<'de> for ExcludedUsersListError {
                 };
                 let value = match tag {
                     "list_error" => ExcludedUsersListError::ListError,
-                    _ => ExcludedUsersListError::Other,
+                    _ => ExcludedUsersListError::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -2943,7 +2789,10 @@ impl ::serde::ser::Serialize for ExcludedUsersListError {
                 s.serialize_field(".tag", "list_error")?;
                 s.end()
             }
-            ExcludedUsersListError::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            ExcludedUsersListError::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -3012,28 +2861,10 @@ impl ExcludedUsersListResult {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "users" => {
-                    if field_users.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("users"));
-                    }
-                    field_users = Some(map.next_value()?);
-                }
-                "has_more" => {
-                    if field_has_more.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("has_more"));
-                    }
-                    field_has_more = Some(map.next_value()?);
-                }
-                "cursor" => {
-                    if field_cursor.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("cursor"));
-                    }
-                    field_cursor = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "users" => crate::deserialize_field(&mut map, &mut field_users, "users")?,
+                "has_more" => crate::deserialize_field(&mut map, &mut field_has_more, "has_more")?,
+                "cursor" => crate::deserialize_field(&mut map, &mut field_cursor, "cursor")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -3114,16 +2945,8 @@ impl ExcludedUsersUpdateArg {
         let mut field_users = None;
         while let Some(key) = map.next_key::<&str>()? {
             match key {
-                "users" => {
-                    if field_users.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("users"));
-                    }
-                    field_users = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "users" => crate::deserialize_field(&mut map, &mut field_users, "users")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         let result = ExcludedUsersUpdateArg {
@@ -3181,8 +3004,9 @@ pub enum ExcludedUsersUpdateError {
     /// A maximum of 1000 users for each of addition/removal can be supplied.
     TooManyUsers,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for ExcludedUsersUpdateError {
@@ -3203,7 +3027,7 @@ impl<'de> ::serde::de::Deserialize<'de> for ExcludedUsersUpdateError {
                 let value = match tag {
                     "users_not_in_team" => ExcludedUsersUpdateError::UsersNotInTeam,
                     "too_many_users" => ExcludedUsersUpdateError::TooManyUsers,
-                    _ => ExcludedUsersUpdateError::Other,
+                    _ => ExcludedUsersUpdateError::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -3233,7 +3057,10 @@ impl ::serde::ser::Serialize for ExcludedUsersUpdateError {
                 s.serialize_field(".tag", "too_many_users")?;
                 s.end()
             }
-            ExcludedUsersUpdateError::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            ExcludedUsersUpdateError::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -3284,16 +3111,8 @@ impl ExcludedUsersUpdateResult {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "status" => {
-                    if field_status.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("status"));
-                    }
-                    field_status = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "status" => crate::deserialize_field(&mut map, &mut field_status, "status")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -3350,8 +3169,9 @@ pub enum ExcludedUsersUpdateStatus {
     /// Update successful.
     Success,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for ExcludedUsersUpdateStatus {
@@ -3371,7 +3191,7 @@ impl<'de> ::serde::de::Deserialize<'de> for ExcludedUsersUpdateStatus {
                 };
                 let value = match tag {
                     "success" => ExcludedUsersUpdateStatus::Success,
-                    _ => ExcludedUsersUpdateStatus::Other,
+                    _ => ExcludedUsersUpdateStatus::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -3394,7 +3214,10 @@ impl ::serde::ser::Serialize for ExcludedUsersUpdateStatus {
                 s.serialize_field(".tag", "success")?;
                 s.end()
             }
-            ExcludedUsersUpdateStatus::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            ExcludedUsersUpdateStatus::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -3412,8 +3235,9 @@ pub enum Feature {
     /// Does this team have team selective sync enabled.
     HasTeamSelectiveSync,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for Feature {
@@ -3436,7 +3260,7 @@ impl<'de> ::serde::de::Deserialize<'de> for Feature {
                     "has_team_shared_dropbox" => Feature::HasTeamSharedDropbox,
                     "has_team_file_events" => Feature::HasTeamFileEvents,
                     "has_team_selective_sync" => Feature::HasTeamSelectiveSync,
-                    _ => Feature::Other,
+                    _ => Feature::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -3480,7 +3304,10 @@ impl ::serde::ser::Serialize for Feature {
                 s.serialize_field(".tag", "has_team_selective_sync")?;
                 s.end()
             }
-            Feature::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            Feature::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -3495,8 +3322,9 @@ pub enum FeatureValue {
     HasTeamFileEvents(HasTeamFileEventsValue),
     HasTeamSelectiveSync(HasTeamSelectiveSyncValue),
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for FeatureValue {
@@ -3543,7 +3371,7 @@ impl<'de> ::serde::de::Deserialize<'de> for FeatureValue {
                             _ => return Err(de::Error::unknown_field(tag, VARIANTS))
                         }
                     }
-                    _ => FeatureValue::Other,
+                    _ => FeatureValue::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -3591,7 +3419,10 @@ impl ::serde::ser::Serialize for FeatureValue {
                 s.serialize_field("has_team_selective_sync", x)?;
                 s.end()
             }
-            FeatureValue::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            FeatureValue::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -3629,16 +3460,8 @@ impl FeaturesGetValuesBatchArg {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "features" => {
-                    if field_features.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("features"));
-                    }
-                    field_features = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "features" => crate::deserialize_field(&mut map, &mut field_features, "features")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -3695,8 +3518,9 @@ pub enum FeaturesGetValuesBatchError {
     /// [`FeaturesGetValuesBatchArg`](FeaturesGetValuesBatchArg).features list.
     EmptyFeaturesList,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for FeaturesGetValuesBatchError {
@@ -3716,7 +3540,7 @@ impl<'de> ::serde::de::Deserialize<'de> for FeaturesGetValuesBatchError {
                 };
                 let value = match tag {
                     "empty_features_list" => FeaturesGetValuesBatchError::EmptyFeaturesList,
-                    _ => FeaturesGetValuesBatchError::Other,
+                    _ => FeaturesGetValuesBatchError::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -3739,7 +3563,10 @@ impl ::serde::ser::Serialize for FeaturesGetValuesBatchError {
                 s.serialize_field(".tag", "empty_features_list")?;
                 s.end()
             }
-            FeaturesGetValuesBatchError::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            FeaturesGetValuesBatchError::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -3784,16 +3611,8 @@ impl FeaturesGetValuesBatchResult {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "values" => {
-                    if field_values.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("values"));
-                    }
-                    field_values = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "values" => crate::deserialize_field(&mut map, &mut field_values, "values")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -3965,100 +3784,22 @@ impl GetActivityReport {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "start_date" => {
-                    if field_start_date.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("start_date"));
-                    }
-                    field_start_date = Some(map.next_value()?);
-                }
-                "adds" => {
-                    if field_adds.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("adds"));
-                    }
-                    field_adds = Some(map.next_value()?);
-                }
-                "edits" => {
-                    if field_edits.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("edits"));
-                    }
-                    field_edits = Some(map.next_value()?);
-                }
-                "deletes" => {
-                    if field_deletes.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("deletes"));
-                    }
-                    field_deletes = Some(map.next_value()?);
-                }
-                "active_users_28_day" => {
-                    if field_active_users_28_day.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("active_users_28_day"));
-                    }
-                    field_active_users_28_day = Some(map.next_value()?);
-                }
-                "active_users_7_day" => {
-                    if field_active_users_7_day.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("active_users_7_day"));
-                    }
-                    field_active_users_7_day = Some(map.next_value()?);
-                }
-                "active_users_1_day" => {
-                    if field_active_users_1_day.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("active_users_1_day"));
-                    }
-                    field_active_users_1_day = Some(map.next_value()?);
-                }
-                "active_shared_folders_28_day" => {
-                    if field_active_shared_folders_28_day.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("active_shared_folders_28_day"));
-                    }
-                    field_active_shared_folders_28_day = Some(map.next_value()?);
-                }
-                "active_shared_folders_7_day" => {
-                    if field_active_shared_folders_7_day.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("active_shared_folders_7_day"));
-                    }
-                    field_active_shared_folders_7_day = Some(map.next_value()?);
-                }
-                "active_shared_folders_1_day" => {
-                    if field_active_shared_folders_1_day.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("active_shared_folders_1_day"));
-                    }
-                    field_active_shared_folders_1_day = Some(map.next_value()?);
-                }
-                "shared_links_created" => {
-                    if field_shared_links_created.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("shared_links_created"));
-                    }
-                    field_shared_links_created = Some(map.next_value()?);
-                }
-                "shared_links_viewed_by_team" => {
-                    if field_shared_links_viewed_by_team.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("shared_links_viewed_by_team"));
-                    }
-                    field_shared_links_viewed_by_team = Some(map.next_value()?);
-                }
-                "shared_links_viewed_by_outside_user" => {
-                    if field_shared_links_viewed_by_outside_user.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("shared_links_viewed_by_outside_user"));
-                    }
-                    field_shared_links_viewed_by_outside_user = Some(map.next_value()?);
-                }
-                "shared_links_viewed_by_not_logged_in" => {
-                    if field_shared_links_viewed_by_not_logged_in.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("shared_links_viewed_by_not_logged_in"));
-                    }
-                    field_shared_links_viewed_by_not_logged_in = Some(map.next_value()?);
-                }
-                "shared_links_viewed_total" => {
-                    if field_shared_links_viewed_total.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("shared_links_viewed_total"));
-                    }
-                    field_shared_links_viewed_total = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "start_date" => crate::deserialize_field(&mut map, &mut field_start_date, "start_date")?,
+                "adds" => crate::deserialize_field(&mut map, &mut field_adds, "adds")?,
+                "edits" => crate::deserialize_field(&mut map, &mut field_edits, "edits")?,
+                "deletes" => crate::deserialize_field(&mut map, &mut field_deletes, "deletes")?,
+                "active_users_28_day" => crate::deserialize_field(&mut map, &mut field_active_users_28_day, "active_users_28_day")?,
+                "active_users_7_day" => crate::deserialize_field(&mut map, &mut field_active_users_7_day, "active_users_7_day")?,
+                "active_users_1_day" => crate::deserialize_field(&mut map, &mut field_active_users_1_day, "active_users_1_day")?,
+                "active_shared_folders_28_day" => crate::deserialize_field(&mut map, &mut field_active_shared_folders_28_day, "active_shared_folders_28_day")?,
+                "active_shared_folders_7_day" => crate::deserialize_field(&mut map, &mut field_active_shared_folders_7_day, "active_shared_folders_7_day")?,
+                "active_shared_folders_1_day" => crate::deserialize_field(&mut map, &mut field_active_shared_folders_1_day, "active_shared_folders_1_day")?,
+                "shared_links_created" => crate::deserialize_field(&mut map, &mut field_shared_links_created, "shared_links_created")?,
+                "shared_links_viewed_by_team" => crate::deserialize_field(&mut map, &mut field_shared_links_viewed_by_team, "shared_links_viewed_by_team")?,
+                "shared_links_viewed_by_outside_user" => crate::deserialize_field(&mut map, &mut field_shared_links_viewed_by_outside_user, "shared_links_viewed_by_outside_user")?,
+                "shared_links_viewed_by_not_logged_in" => crate::deserialize_field(&mut map, &mut field_shared_links_viewed_by_not_logged_in, "shared_links_viewed_by_not_logged_in")?,
+                "shared_links_viewed_total" => crate::deserialize_field(&mut map, &mut field_shared_links_viewed_total, "shared_links_viewed_total")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -4199,34 +3940,11 @@ impl GetDevicesReport {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "start_date" => {
-                    if field_start_date.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("start_date"));
-                    }
-                    field_start_date = Some(map.next_value()?);
-                }
-                "active_1_day" => {
-                    if field_active_1_day.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("active_1_day"));
-                    }
-                    field_active_1_day = Some(map.next_value()?);
-                }
-                "active_7_day" => {
-                    if field_active_7_day.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("active_7_day"));
-                    }
-                    field_active_7_day = Some(map.next_value()?);
-                }
-                "active_28_day" => {
-                    if field_active_28_day.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("active_28_day"));
-                    }
-                    field_active_28_day = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "start_date" => crate::deserialize_field(&mut map, &mut field_start_date, "start_date")?,
+                "active_1_day" => crate::deserialize_field(&mut map, &mut field_active_1_day, "active_1_day")?,
+                "active_7_day" => crate::deserialize_field(&mut map, &mut field_active_7_day, "active_7_day")?,
+                "active_28_day" => crate::deserialize_field(&mut map, &mut field_active_28_day, "active_28_day")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -4356,46 +4074,13 @@ impl GetMembershipReport {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "start_date" => {
-                    if field_start_date.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("start_date"));
-                    }
-                    field_start_date = Some(map.next_value()?);
-                }
-                "team_size" => {
-                    if field_team_size.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("team_size"));
-                    }
-                    field_team_size = Some(map.next_value()?);
-                }
-                "pending_invites" => {
-                    if field_pending_invites.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("pending_invites"));
-                    }
-                    field_pending_invites = Some(map.next_value()?);
-                }
-                "members_joined" => {
-                    if field_members_joined.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("members_joined"));
-                    }
-                    field_members_joined = Some(map.next_value()?);
-                }
-                "suspended_members" => {
-                    if field_suspended_members.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("suspended_members"));
-                    }
-                    field_suspended_members = Some(map.next_value()?);
-                }
-                "licenses" => {
-                    if field_licenses.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("licenses"));
-                    }
-                    field_licenses = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "start_date" => crate::deserialize_field(&mut map, &mut field_start_date, "start_date")?,
+                "team_size" => crate::deserialize_field(&mut map, &mut field_team_size, "team_size")?,
+                "pending_invites" => crate::deserialize_field(&mut map, &mut field_pending_invites, "pending_invites")?,
+                "members_joined" => crate::deserialize_field(&mut map, &mut field_members_joined, "members_joined")?,
+                "suspended_members" => crate::deserialize_field(&mut map, &mut field_suspended_members, "suspended_members")?,
+                "licenses" => crate::deserialize_field(&mut map, &mut field_licenses, "licenses")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -4532,46 +4217,13 @@ impl GetStorageReport {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "start_date" => {
-                    if field_start_date.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("start_date"));
-                    }
-                    field_start_date = Some(map.next_value()?);
-                }
-                "total_usage" => {
-                    if field_total_usage.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("total_usage"));
-                    }
-                    field_total_usage = Some(map.next_value()?);
-                }
-                "shared_usage" => {
-                    if field_shared_usage.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("shared_usage"));
-                    }
-                    field_shared_usage = Some(map.next_value()?);
-                }
-                "unshared_usage" => {
-                    if field_unshared_usage.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("unshared_usage"));
-                    }
-                    field_unshared_usage = Some(map.next_value()?);
-                }
-                "shared_folders" => {
-                    if field_shared_folders.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("shared_folders"));
-                    }
-                    field_shared_folders = Some(map.next_value()?);
-                }
-                "member_storage_map" => {
-                    if field_member_storage_map.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("member_storage_map"));
-                    }
-                    field_member_storage_map = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "start_date" => crate::deserialize_field(&mut map, &mut field_start_date, "start_date")?,
+                "total_usage" => crate::deserialize_field(&mut map, &mut field_total_usage, "total_usage")?,
+                "shared_usage" => crate::deserialize_field(&mut map, &mut field_shared_usage, "shared_usage")?,
+                "unshared_usage" => crate::deserialize_field(&mut map, &mut field_unshared_usage, "unshared_usage")?,
+                "shared_folders" => crate::deserialize_field(&mut map, &mut field_shared_folders, "shared_folders")?,
+                "member_storage_map" => crate::deserialize_field(&mut map, &mut field_member_storage_map, "member_storage_map")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -4767,34 +4419,11 @@ impl GroupCreateArg {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "group_name" => {
-                    if field_group_name.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("group_name"));
-                    }
-                    field_group_name = Some(map.next_value()?);
-                }
-                "add_creator_as_owner" => {
-                    if field_add_creator_as_owner.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("add_creator_as_owner"));
-                    }
-                    field_add_creator_as_owner = Some(map.next_value()?);
-                }
-                "group_external_id" => {
-                    if field_group_external_id.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("group_external_id"));
-                    }
-                    field_group_external_id = Some(map.next_value()?);
-                }
-                "group_management_type" => {
-                    if field_group_management_type.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("group_management_type"));
-                    }
-                    field_group_management_type = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "group_name" => crate::deserialize_field(&mut map, &mut field_group_name, "group_name")?,
+                "add_creator_as_owner" => crate::deserialize_field(&mut map, &mut field_add_creator_as_owner, "add_creator_as_owner")?,
+                "group_external_id" => crate::deserialize_field(&mut map, &mut field_group_external_id, "group_external_id")?,
+                "group_management_type" => crate::deserialize_field(&mut map, &mut field_group_management_type, "group_management_type")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -4868,8 +4497,9 @@ pub enum GroupCreateError {
     /// System-managed group cannot be manually created.
     SystemManagedGroupDisallowed,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for GroupCreateError {
@@ -4892,7 +4522,7 @@ impl<'de> ::serde::de::Deserialize<'de> for GroupCreateError {
                     "group_name_invalid" => GroupCreateError::GroupNameInvalid,
                     "external_id_already_in_use" => GroupCreateError::ExternalIdAlreadyInUse,
                     "system_managed_group_disallowed" => GroupCreateError::SystemManagedGroupDisallowed,
-                    _ => GroupCreateError::Other,
+                    _ => GroupCreateError::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -4936,7 +4566,10 @@ impl ::serde::ser::Serialize for GroupCreateError {
                 s.serialize_field(".tag", "system_managed_group_disallowed")?;
                 s.end()
             }
-            GroupCreateError::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            GroupCreateError::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -4966,8 +4599,9 @@ pub enum GroupDeleteError {
     /// This group has already been deleted.
     GroupAlreadyDeleted,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for GroupDeleteError {
@@ -4989,7 +4623,7 @@ impl<'de> ::serde::de::Deserialize<'de> for GroupDeleteError {
                     "group_not_found" => GroupDeleteError::GroupNotFound,
                     "system_managed_group_disallowed" => GroupDeleteError::SystemManagedGroupDisallowed,
                     "group_already_deleted" => GroupDeleteError::GroupAlreadyDeleted,
-                    _ => GroupDeleteError::Other,
+                    _ => GroupDeleteError::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -5026,7 +4660,10 @@ impl ::serde::ser::Serialize for GroupDeleteError {
                 s.serialize_field(".tag", "group_already_deleted")?;
                 s.end()
             }
-            GroupDeleteError::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            GroupDeleteError::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -5050,7 +4687,7 @@ impl From<GroupSelectorWithTeamGroupError> for GroupDeleteError {
     fn from(parent: GroupSelectorWithTeamGroupError) -> Self {
         match parent {
             GroupSelectorWithTeamGroupError::GroupNotFound => GroupDeleteError::GroupNotFound,
-            GroupSelectorWithTeamGroupError::Other => GroupDeleteError::Other,
+            GroupSelectorWithTeamGroupError::Other(x) => GroupDeleteError::Other(x),
             GroupSelectorWithTeamGroupError::SystemManagedGroupDisallowed => GroupDeleteError::SystemManagedGroupDisallowed,
         }
     }
@@ -5139,52 +4776,14 @@ impl GroupFullInfo {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "group_name" => {
-                    if field_group_name.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("group_name"));
-                    }
-                    field_group_name = Some(map.next_value()?);
-                }
-                "group_id" => {
-                    if field_group_id.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("group_id"));
-                    }
-                    field_group_id = Some(map.next_value()?);
-                }
-                "group_management_type" => {
-                    if field_group_management_type.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("group_management_type"));
-                    }
-                    field_group_management_type = Some(map.next_value()?);
-                }
-                "created" => {
-                    if field_created.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("created"));
-                    }
-                    field_created = Some(map.next_value()?);
-                }
-                "group_external_id" => {
-                    if field_group_external_id.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("group_external_id"));
-                    }
-                    field_group_external_id = Some(map.next_value()?);
-                }
-                "member_count" => {
-                    if field_member_count.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("member_count"));
-                    }
-                    field_member_count = Some(map.next_value()?);
-                }
-                "members" => {
-                    if field_members.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("members"));
-                    }
-                    field_members = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "group_name" => crate::deserialize_field(&mut map, &mut field_group_name, "group_name")?,
+                "group_id" => crate::deserialize_field(&mut map, &mut field_group_id, "group_id")?,
+                "group_management_type" => crate::deserialize_field(&mut map, &mut field_group_management_type, "group_management_type")?,
+                "created" => crate::deserialize_field(&mut map, &mut field_created, "created")?,
+                "group_external_id" => crate::deserialize_field(&mut map, &mut field_group_external_id, "group_external_id")?,
+                "member_count" => crate::deserialize_field(&mut map, &mut field_member_count, "member_count")?,
+                "members" => crate::deserialize_field(&mut map, &mut field_members, "members")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -5302,22 +4901,9 @@ impl GroupMemberInfo {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "profile" => {
-                    if field_profile.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("profile"));
-                    }
-                    field_profile = Some(map.next_value()?);
-                }
-                "access_type" => {
-                    if field_access_type.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("access_type"));
-                    }
-                    field_access_type = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "profile" => crate::deserialize_field(&mut map, &mut field_profile, "profile")?,
+                "access_type" => crate::deserialize_field(&mut map, &mut field_access_type, "access_type")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -5407,22 +4993,9 @@ impl GroupMemberSelector {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "group" => {
-                    if field_group.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("group"));
-                    }
-                    field_group = Some(map.next_value()?);
-                }
-                "user" => {
-                    if field_user.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("user"));
-                    }
-                    field_user = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "group" => crate::deserialize_field(&mut map, &mut field_group, "group")?,
+                "user" => crate::deserialize_field(&mut map, &mut field_user, "user")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -5486,8 +5059,9 @@ pub enum GroupMemberSelectorError {
     /// The specified user is not a member of this group.
     MemberNotInGroup,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for GroupMemberSelectorError {
@@ -5509,7 +5083,7 @@ impl<'de> ::serde::de::Deserialize<'de> for GroupMemberSelectorError {
                     "group_not_found" => GroupMemberSelectorError::GroupNotFound,
                     "system_managed_group_disallowed" => GroupMemberSelectorError::SystemManagedGroupDisallowed,
                     "member_not_in_group" => GroupMemberSelectorError::MemberNotInGroup,
-                    _ => GroupMemberSelectorError::Other,
+                    _ => GroupMemberSelectorError::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -5546,7 +5120,10 @@ impl ::serde::ser::Serialize for GroupMemberSelectorError {
                 s.serialize_field(".tag", "member_not_in_group")?;
                 s.end()
             }
-            GroupMemberSelectorError::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            GroupMemberSelectorError::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -5570,7 +5147,7 @@ impl From<GroupSelectorWithTeamGroupError> for GroupMemberSelectorError {
     fn from(parent: GroupSelectorWithTeamGroupError) -> Self {
         match parent {
             GroupSelectorWithTeamGroupError::GroupNotFound => GroupMemberSelectorError::GroupNotFound,
-            GroupSelectorWithTeamGroupError::Other => GroupMemberSelectorError::Other,
+            GroupSelectorWithTeamGroupError::Other(x) => GroupMemberSelectorError::Other(x),
             GroupSelectorWithTeamGroupError::SystemManagedGroupDisallowed => GroupMemberSelectorError::SystemManagedGroupDisallowed,
         }
     }
@@ -5587,8 +5164,9 @@ pub enum GroupMemberSetAccessTypeError {
     /// A company managed group cannot be managed by a user.
     UserCannotBeManagerOfCompanyManagedGroup,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for GroupMemberSetAccessTypeError {
@@ -5611,7 +5189,7 @@ impl<'de> ::serde::de::Deserialize<'de> for GroupMemberSetAccessTypeError {
                     "system_managed_group_disallowed" => GroupMemberSetAccessTypeError::SystemManagedGroupDisallowed,
                     "member_not_in_group" => GroupMemberSetAccessTypeError::MemberNotInGroup,
                     "user_cannot_be_manager_of_company_managed_group" => GroupMemberSetAccessTypeError::UserCannotBeManagerOfCompanyManagedGroup,
-                    _ => GroupMemberSetAccessTypeError::Other,
+                    _ => GroupMemberSetAccessTypeError::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -5655,7 +5233,10 @@ impl ::serde::ser::Serialize for GroupMemberSetAccessTypeError {
                 s.serialize_field(".tag", "user_cannot_be_manager_of_company_managed_group")?;
                 s.end()
             }
-            GroupMemberSetAccessTypeError::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            GroupMemberSetAccessTypeError::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -5680,7 +5261,7 @@ impl From<GroupMemberSelectorError> for GroupMemberSetAccessTypeError {
     fn from(parent: GroupMemberSelectorError) -> Self {
         match parent {
             GroupMemberSelectorError::GroupNotFound => GroupMemberSetAccessTypeError::GroupNotFound,
-            GroupMemberSelectorError::Other => GroupMemberSetAccessTypeError::Other,
+            GroupMemberSelectorError::Other(x) => GroupMemberSetAccessTypeError::Other(x),
             GroupMemberSelectorError::SystemManagedGroupDisallowed => GroupMemberSetAccessTypeError::SystemManagedGroupDisallowed,
             GroupMemberSelectorError::MemberNotInGroup => GroupMemberSetAccessTypeError::MemberNotInGroup,
         }
@@ -5735,28 +5316,10 @@ impl GroupMembersAddArg {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "group" => {
-                    if field_group.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("group"));
-                    }
-                    field_group = Some(map.next_value()?);
-                }
-                "members" => {
-                    if field_members.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("members"));
-                    }
-                    field_members = Some(map.next_value()?);
-                }
-                "return_members" => {
-                    if field_return_members.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("return_members"));
-                    }
-                    field_return_members = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "group" => crate::deserialize_field(&mut map, &mut field_group, "group")?,
+                "members" => crate::deserialize_field(&mut map, &mut field_members, "members")?,
+                "return_members" => crate::deserialize_field(&mut map, &mut field_return_members, "return_members")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -5845,8 +5408,9 @@ pub enum GroupMembersAddError {
     /// A company-managed group cannot be managed by a user.
     UserCannotBeManagerOfCompanyManagedGroup(Vec<String>),
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for GroupMembersAddError {
@@ -5891,7 +5455,7 @@ impl<'de> ::serde::de::Deserialize<'de> for GroupMembersAddError {
                             _ => return Err(de::Error::unknown_field(tag, VARIANTS))
                         }
                     }
-                    _ => GroupMembersAddError::Other,
+                    _ => GroupMembersAddError::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -5966,7 +5530,10 @@ impl ::serde::ser::Serialize for GroupMembersAddError {
                 s.serialize_field("user_cannot_be_manager_of_company_managed_group", x)?;
                 s.end()
             }
-            GroupMembersAddError::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            GroupMembersAddError::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -5994,7 +5561,7 @@ impl From<GroupSelectorWithTeamGroupError> for GroupMembersAddError {
     fn from(parent: GroupSelectorWithTeamGroupError) -> Self {
         match parent {
             GroupSelectorWithTeamGroupError::GroupNotFound => GroupMembersAddError::GroupNotFound,
-            GroupSelectorWithTeamGroupError::Other => GroupMembersAddError::Other,
+            GroupSelectorWithTeamGroupError::Other(x) => GroupMembersAddError::Other(x),
             GroupSelectorWithTeamGroupError::SystemManagedGroupDisallowed => GroupMembersAddError::SystemManagedGroupDisallowed,
         }
     }
@@ -6043,22 +5610,9 @@ impl GroupMembersChangeResult {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "group_info" => {
-                    if field_group_info.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("group_info"));
-                    }
-                    field_group_info = Some(map.next_value()?);
-                }
-                "async_job_id" => {
-                    if field_async_job_id.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("async_job_id"));
-                    }
-                    field_async_job_id = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "group_info" => crate::deserialize_field(&mut map, &mut field_group_info, "group_info")?,
+                "async_job_id" => crate::deserialize_field(&mut map, &mut field_async_job_id, "async_job_id")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -6159,28 +5713,10 @@ impl GroupMembersRemoveArg {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "group" => {
-                    if field_group.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("group"));
-                    }
-                    field_group = Some(map.next_value()?);
-                }
-                "users" => {
-                    if field_users.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("users"));
-                    }
-                    field_users = Some(map.next_value()?);
-                }
-                "return_members" => {
-                    if field_return_members.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("return_members"));
-                    }
-                    field_return_members = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "group" => crate::deserialize_field(&mut map, &mut field_group, "group")?,
+                "users" => crate::deserialize_field(&mut map, &mut field_users, "users")?,
+                "return_members" => crate::deserialize_field(&mut map, &mut field_return_members, "return_members")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -6261,8 +5797,9 @@ pub enum GroupMembersRemoveError {
     /// These users were not found in Dropbox.
     UsersNotFound(Vec<String>),
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for GroupMembersRemoveError {
@@ -6299,7 +5836,7 @@ impl<'de> ::serde::de::Deserialize<'de> for GroupMembersRemoveError {
                             _ => return Err(de::Error::unknown_field(tag, VARIANTS))
                         }
                     }
-                    _ => GroupMembersRemoveError::Other,
+                    _ => GroupMembersRemoveError::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -6359,7 +5896,10 @@ impl ::serde::ser::Serialize for GroupMembersRemoveError {
                 s.serialize_field("users_not_found", x)?;
                 s.end()
             }
-            GroupMembersRemoveError::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            GroupMembersRemoveError::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -6386,7 +5926,7 @@ impl From<GroupMembersSelectorError> for GroupMembersRemoveError {
     fn from(parent: GroupMembersSelectorError) -> Self {
         match parent {
             GroupMembersSelectorError::GroupNotFound => GroupMembersRemoveError::GroupNotFound,
-            GroupMembersSelectorError::Other => GroupMembersRemoveError::Other,
+            GroupMembersSelectorError::Other(x) => GroupMembersRemoveError::Other(x),
             GroupMembersSelectorError::SystemManagedGroupDisallowed => GroupMembersRemoveError::SystemManagedGroupDisallowed,
             GroupMembersSelectorError::MemberNotInGroup => GroupMembersRemoveError::MemberNotInGroup,
         }
@@ -6430,22 +5970,9 @@ impl GroupMembersSelector {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "group" => {
-                    if field_group.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("group"));
-                    }
-                    field_group = Some(map.next_value()?);
-                }
-                "users" => {
-                    if field_users.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("users"));
-                    }
-                    field_users = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "group" => crate::deserialize_field(&mut map, &mut field_group, "group")?,
+                "users" => crate::deserialize_field(&mut map, &mut field_users, "users")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -6509,8 +6036,9 @@ pub enum GroupMembersSelectorError {
     /// At least one of the specified users is not a member of the group.
     MemberNotInGroup,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for GroupMembersSelectorError {
@@ -6532,7 +6060,7 @@ impl<'de> ::serde::de::Deserialize<'de> for GroupMembersSelectorError {
                     "group_not_found" => GroupMembersSelectorError::GroupNotFound,
                     "system_managed_group_disallowed" => GroupMembersSelectorError::SystemManagedGroupDisallowed,
                     "member_not_in_group" => GroupMembersSelectorError::MemberNotInGroup,
-                    _ => GroupMembersSelectorError::Other,
+                    _ => GroupMembersSelectorError::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -6569,7 +6097,10 @@ impl ::serde::ser::Serialize for GroupMembersSelectorError {
                 s.serialize_field(".tag", "member_not_in_group")?;
                 s.end()
             }
-            GroupMembersSelectorError::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            GroupMembersSelectorError::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -6593,7 +6124,7 @@ impl From<GroupSelectorWithTeamGroupError> for GroupMembersSelectorError {
     fn from(parent: GroupSelectorWithTeamGroupError) -> Self {
         match parent {
             GroupSelectorWithTeamGroupError::GroupNotFound => GroupMembersSelectorError::GroupNotFound,
-            GroupSelectorWithTeamGroupError::Other => GroupMembersSelectorError::Other,
+            GroupSelectorWithTeamGroupError::Other(x) => GroupMembersSelectorError::Other(x),
             GroupSelectorWithTeamGroupError::SystemManagedGroupDisallowed => GroupMembersSelectorError::SystemManagedGroupDisallowed,
         }
     }
@@ -6652,34 +6183,11 @@ impl GroupMembersSetAccessTypeArg {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "group" => {
-                    if field_group.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("group"));
-                    }
-                    field_group = Some(map.next_value()?);
-                }
-                "user" => {
-                    if field_user.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("user"));
-                    }
-                    field_user = Some(map.next_value()?);
-                }
-                "access_type" => {
-                    if field_access_type.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("access_type"));
-                    }
-                    field_access_type = Some(map.next_value()?);
-                }
-                "return_members" => {
-                    if field_return_members.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("return_members"));
-                    }
-                    field_return_members = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "group" => crate::deserialize_field(&mut map, &mut field_group, "group")?,
+                "user" => crate::deserialize_field(&mut map, &mut field_user, "user")?,
+                "access_type" => crate::deserialize_field(&mut map, &mut field_access_type, "access_type")?,
+                "return_members" => crate::deserialize_field(&mut map, &mut field_return_members, "return_members")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -6827,8 +6335,9 @@ pub enum GroupSelectorError {
     /// No matching group found. No groups match the specified group ID.
     GroupNotFound,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for GroupSelectorError {
@@ -6848,7 +6357,7 @@ impl<'de> ::serde::de::Deserialize<'de> for GroupSelectorError {
                 };
                 let value = match tag {
                     "group_not_found" => GroupSelectorError::GroupNotFound,
-                    _ => GroupSelectorError::Other,
+                    _ => GroupSelectorError::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -6871,7 +6380,10 @@ impl ::serde::ser::Serialize for GroupSelectorError {
                 s.serialize_field(".tag", "group_not_found")?;
                 s.end()
             }
-            GroupSelectorError::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            GroupSelectorError::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -6898,8 +6410,9 @@ pub enum GroupSelectorWithTeamGroupError {
     /// This operation is not supported on system-managed groups.
     SystemManagedGroupDisallowed,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for GroupSelectorWithTeamGroupError {
@@ -6920,7 +6433,7 @@ impl<'de> ::serde::de::Deserialize<'de> for GroupSelectorWithTeamGroupError {
                 let value = match tag {
                     "group_not_found" => GroupSelectorWithTeamGroupError::GroupNotFound,
                     "system_managed_group_disallowed" => GroupSelectorWithTeamGroupError::SystemManagedGroupDisallowed,
-                    _ => GroupSelectorWithTeamGroupError::Other,
+                    _ => GroupSelectorWithTeamGroupError::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -6950,7 +6463,10 @@ impl ::serde::ser::Serialize for GroupSelectorWithTeamGroupError {
                 s.serialize_field(".tag", "system_managed_group_disallowed")?;
                 s.end()
             }
-            GroupSelectorWithTeamGroupError::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            GroupSelectorWithTeamGroupError::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -6973,7 +6489,7 @@ impl From<GroupSelectorError> for GroupSelectorWithTeamGroupError {
     fn from(parent: GroupSelectorError) -> Self {
         match parent {
             GroupSelectorError::GroupNotFound => GroupSelectorWithTeamGroupError::GroupNotFound,
-            GroupSelectorError::Other => GroupSelectorWithTeamGroupError::Other,
+            GroupSelectorError::Other(x) => GroupSelectorWithTeamGroupError::Other(x),
         }
     }
 }
@@ -7058,40 +6574,12 @@ impl GroupUpdateArgs {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "group" => {
-                    if field_group.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("group"));
-                    }
-                    field_group = Some(map.next_value()?);
-                }
-                "return_members" => {
-                    if field_return_members.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("return_members"));
-                    }
-                    field_return_members = Some(map.next_value()?);
-                }
-                "new_group_name" => {
-                    if field_new_group_name.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("new_group_name"));
-                    }
-                    field_new_group_name = Some(map.next_value()?);
-                }
-                "new_group_external_id" => {
-                    if field_new_group_external_id.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("new_group_external_id"));
-                    }
-                    field_new_group_external_id = Some(map.next_value()?);
-                }
-                "new_group_management_type" => {
-                    if field_new_group_management_type.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("new_group_management_type"));
-                    }
-                    field_new_group_management_type = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "group" => crate::deserialize_field(&mut map, &mut field_group, "group")?,
+                "return_members" => crate::deserialize_field(&mut map, &mut field_return_members, "return_members")?,
+                "new_group_name" => crate::deserialize_field(&mut map, &mut field_new_group_name, "new_group_name")?,
+                "new_group_external_id" => crate::deserialize_field(&mut map, &mut field_new_group_external_id, "new_group_external_id")?,
+                "new_group_management_type" => crate::deserialize_field(&mut map, &mut field_new_group_management_type, "new_group_management_type")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -7179,8 +6667,9 @@ pub enum GroupUpdateError {
     /// The requested external ID is already being used by another group.
     ExternalIdAlreadyInUse,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for GroupUpdateError {
@@ -7204,7 +6693,7 @@ impl<'de> ::serde::de::Deserialize<'de> for GroupUpdateError {
                     "group_name_already_used" => GroupUpdateError::GroupNameAlreadyUsed,
                     "group_name_invalid" => GroupUpdateError::GroupNameInvalid,
                     "external_id_already_in_use" => GroupUpdateError::ExternalIdAlreadyInUse,
-                    _ => GroupUpdateError::Other,
+                    _ => GroupUpdateError::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -7255,7 +6744,10 @@ impl ::serde::ser::Serialize for GroupUpdateError {
                 s.serialize_field(".tag", "external_id_already_in_use")?;
                 s.end()
             }
-            GroupUpdateError::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            GroupUpdateError::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -7281,7 +6773,7 @@ impl From<GroupSelectorWithTeamGroupError> for GroupUpdateError {
     fn from(parent: GroupSelectorWithTeamGroupError) -> Self {
         match parent {
             GroupSelectorWithTeamGroupError::GroupNotFound => GroupUpdateError::GroupNotFound,
-            GroupSelectorWithTeamGroupError::Other => GroupUpdateError::Other,
+            GroupSelectorWithTeamGroupError::Other(x) => GroupUpdateError::Other(x),
             GroupSelectorWithTeamGroupError::SystemManagedGroupDisallowed => GroupUpdateError::SystemManagedGroupDisallowed,
         }
     }
@@ -7292,8 +6784,9 @@ pub enum GroupsGetInfoError {
     /// The group is not on your team.
     GroupNotOnTeam,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for GroupsGetInfoError {
@@ -7313,7 +6806,7 @@ impl<'de> ::serde::de::Deserialize<'de> for GroupsGetInfoError {
                 };
                 let value = match tag {
                     "group_not_on_team" => GroupsGetInfoError::GroupNotOnTeam,
-                    _ => GroupsGetInfoError::Other,
+                    _ => GroupsGetInfoError::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -7336,7 +6829,10 @@ impl ::serde::ser::Serialize for GroupsGetInfoError {
                 s.serialize_field(".tag", "group_not_on_team")?;
                 s.end()
             }
-            GroupsGetInfoError::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            GroupsGetInfoError::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -7453,16 +6949,8 @@ impl GroupsListArg {
         let mut field_limit = None;
         while let Some(key) = map.next_key::<&str>()? {
             match key {
-                "limit" => {
-                    if field_limit.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("limit"));
-                    }
-                    field_limit = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "limit" => crate::deserialize_field(&mut map, &mut field_limit, "limit")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         let result = GroupsListArg {
@@ -7543,16 +7031,8 @@ impl GroupsListContinueArg {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "cursor" => {
-                    if field_cursor.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("cursor"));
-                    }
-                    field_cursor = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "cursor" => crate::deserialize_field(&mut map, &mut field_cursor, "cursor")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -7608,8 +7088,9 @@ pub enum GroupsListContinueError {
     /// The cursor is invalid.
     InvalidCursor,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for GroupsListContinueError {
@@ -7629,7 +7110,7 @@ impl<'de> ::serde::de::Deserialize<'de> for GroupsListContinueError {
                 };
                 let value = match tag {
                     "invalid_cursor" => GroupsListContinueError::InvalidCursor,
-                    _ => GroupsListContinueError::Other,
+                    _ => GroupsListContinueError::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -7652,7 +7133,10 @@ impl ::serde::ser::Serialize for GroupsListContinueError {
                 s.serialize_field(".tag", "invalid_cursor")?;
                 s.end()
             }
-            GroupsListContinueError::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            GroupsListContinueError::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -7716,28 +7200,10 @@ impl GroupsListResult {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "groups" => {
-                    if field_groups.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("groups"));
-                    }
-                    field_groups = Some(map.next_value()?);
-                }
-                "cursor" => {
-                    if field_cursor.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("cursor"));
-                    }
-                    field_cursor = Some(map.next_value()?);
-                }
-                "has_more" => {
-                    if field_has_more.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("has_more"));
-                    }
-                    field_has_more = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "groups" => crate::deserialize_field(&mut map, &mut field_groups, "groups")?,
+                "cursor" => crate::deserialize_field(&mut map, &mut field_cursor, "cursor")?,
+                "has_more" => crate::deserialize_field(&mut map, &mut field_has_more, "has_more")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -7833,22 +7299,9 @@ impl GroupsMembersListArg {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "group" => {
-                    if field_group.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("group"));
-                    }
-                    field_group = Some(map.next_value()?);
-                }
-                "limit" => {
-                    if field_limit.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("limit"));
-                    }
-                    field_limit = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "group" => crate::deserialize_field(&mut map, &mut field_group, "group")?,
+                "limit" => crate::deserialize_field(&mut map, &mut field_limit, "limit")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -7934,16 +7387,8 @@ impl GroupsMembersListContinueArg {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "cursor" => {
-                    if field_cursor.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("cursor"));
-                    }
-                    field_cursor = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "cursor" => crate::deserialize_field(&mut map, &mut field_cursor, "cursor")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -7999,8 +7444,9 @@ pub enum GroupsMembersListContinueError {
     /// The cursor is invalid.
     InvalidCursor,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for GroupsMembersListContinueError {
@@ -8020,7 +7466,7 @@ impl<'de> ::serde::de::Deserialize<'de> for GroupsMembersListContinueError {
                 };
                 let value = match tag {
                     "invalid_cursor" => GroupsMembersListContinueError::InvalidCursor,
-                    _ => GroupsMembersListContinueError::Other,
+                    _ => GroupsMembersListContinueError::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -8043,7 +7489,10 @@ impl ::serde::ser::Serialize for GroupsMembersListContinueError {
                 s.serialize_field(".tag", "invalid_cursor")?;
                 s.end()
             }
-            GroupsMembersListContinueError::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            GroupsMembersListContinueError::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -8105,28 +7554,10 @@ impl GroupsMembersListResult {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "members" => {
-                    if field_members.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("members"));
-                    }
-                    field_members = Some(map.next_value()?);
-                }
-                "cursor" => {
-                    if field_cursor.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("cursor"));
-                    }
-                    field_cursor = Some(map.next_value()?);
-                }
-                "has_more" => {
-                    if field_has_more.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("has_more"));
-                    }
-                    field_has_more = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "members" => crate::deserialize_field(&mut map, &mut field_members, "members")?,
+                "cursor" => crate::deserialize_field(&mut map, &mut field_cursor, "cursor")?,
+                "has_more" => crate::deserialize_field(&mut map, &mut field_has_more, "has_more")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -8191,8 +7622,9 @@ pub enum GroupsPollError {
     /// You are not allowed to poll this job.
     AccessDenied,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for GroupsPollError {
@@ -8214,7 +7646,7 @@ impl<'de> ::serde::de::Deserialize<'de> for GroupsPollError {
                     "invalid_async_job_id" => GroupsPollError::InvalidAsyncJobId,
                     "internal_error" => GroupsPollError::InternalError,
                     "access_denied" => GroupsPollError::AccessDenied,
-                    _ => GroupsPollError::Other,
+                    _ => GroupsPollError::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -8251,7 +7683,10 @@ impl ::serde::ser::Serialize for GroupsPollError {
                 s.serialize_field(".tag", "access_denied")?;
                 s.end()
             }
-            GroupsPollError::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            GroupsPollError::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -8276,7 +7711,7 @@ impl From<crate::types::dbx_async::PollError> for GroupsPollError {
         match parent {
             crate::types::dbx_async::PollError::InvalidAsyncJobId => GroupsPollError::InvalidAsyncJobId,
             crate::types::dbx_async::PollError::InternalError => GroupsPollError::InternalError,
-            crate::types::dbx_async::PollError::Other => GroupsPollError::Other,
+            crate::types::dbx_async::PollError::Other(x) => GroupsPollError::Other(x),
         }
     }
 }
@@ -8361,8 +7796,9 @@ pub enum HasTeamFileEventsValue {
     /// Does this team have file events.
     Enabled(bool),
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for HasTeamFileEventsValue {
@@ -8388,7 +7824,7 @@ impl<'de> ::serde::de::Deserialize<'de> for HasTeamFileEventsValue {
                             _ => return Err(de::Error::unknown_field(tag, VARIANTS))
                         }
                     }
-                    _ => HasTeamFileEventsValue::Other,
+                    _ => HasTeamFileEventsValue::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -8412,7 +7848,10 @@ impl ::serde::ser::Serialize for HasTeamFileEventsValue {
                 s.serialize_field("enabled", x)?;
                 s.end()
             }
-            HasTeamFileEventsValue::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            HasTeamFileEventsValue::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -8424,8 +7863,9 @@ pub enum HasTeamSelectiveSyncValue {
     /// Does this team have team selective sync enabled.
     HasTeamSelectiveSync(bool),
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for HasTeamSelectiveSyncValue {
@@ -8451,7 +7891,7 @@ impl<'de> ::serde::de::Deserialize<'de> for HasTeamSelectiveSyncValue {
                             _ => return Err(de::Error::unknown_field(tag, VARIANTS))
                         }
                     }
-                    _ => HasTeamSelectiveSyncValue::Other,
+                    _ => HasTeamSelectiveSyncValue::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -8475,7 +7915,10 @@ impl ::serde::ser::Serialize for HasTeamSelectiveSyncValue {
                 s.serialize_field("has_team_selective_sync", x)?;
                 s.end()
             }
-            HasTeamSelectiveSyncValue::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            HasTeamSelectiveSyncValue::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -8487,8 +7930,9 @@ pub enum HasTeamSharedDropboxValue {
     /// Does this team have a shared team root.
     HasTeamSharedDropbox(bool),
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for HasTeamSharedDropboxValue {
@@ -8514,7 +7958,7 @@ impl<'de> ::serde::de::Deserialize<'de> for HasTeamSharedDropboxValue {
                             _ => return Err(de::Error::unknown_field(tag, VARIANTS))
                         }
                     }
-                    _ => HasTeamSharedDropboxValue::Other,
+                    _ => HasTeamSharedDropboxValue::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -8538,7 +7982,10 @@ impl ::serde::ser::Serialize for HasTeamSharedDropboxValue {
                 s.serialize_field("has_team_shared_dropbox", x)?;
                 s.end()
             }
-            HasTeamSharedDropboxValue::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            HasTeamSharedDropboxValue::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -8576,16 +8023,8 @@ impl IncludeMembersArg {
         let mut field_return_members = None;
         while let Some(key) = map.next_key::<&str>()? {
             match key {
-                "return_members" => {
-                    if field_return_members.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("return_members"));
-                    }
-                    field_return_members = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "return_members" => crate::deserialize_field(&mut map, &mut field_return_members, "return_members")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         let result = IncludeMembersArg {
@@ -8724,70 +8163,17 @@ impl LegalHoldHeldRevisionMetadata {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "new_filename" => {
-                    if field_new_filename.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("new_filename"));
-                    }
-                    field_new_filename = Some(map.next_value()?);
-                }
-                "original_revision_id" => {
-                    if field_original_revision_id.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("original_revision_id"));
-                    }
-                    field_original_revision_id = Some(map.next_value()?);
-                }
-                "original_file_path" => {
-                    if field_original_file_path.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("original_file_path"));
-                    }
-                    field_original_file_path = Some(map.next_value()?);
-                }
-                "server_modified" => {
-                    if field_server_modified.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("server_modified"));
-                    }
-                    field_server_modified = Some(map.next_value()?);
-                }
-                "author_member_id" => {
-                    if field_author_member_id.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("author_member_id"));
-                    }
-                    field_author_member_id = Some(map.next_value()?);
-                }
-                "author_member_status" => {
-                    if field_author_member_status.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("author_member_status"));
-                    }
-                    field_author_member_status = Some(map.next_value()?);
-                }
-                "author_email" => {
-                    if field_author_email.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("author_email"));
-                    }
-                    field_author_email = Some(map.next_value()?);
-                }
-                "file_type" => {
-                    if field_file_type.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("file_type"));
-                    }
-                    field_file_type = Some(map.next_value()?);
-                }
-                "size" => {
-                    if field_size.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("size"));
-                    }
-                    field_size = Some(map.next_value()?);
-                }
-                "content_hash" => {
-                    if field_content_hash.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("content_hash"));
-                    }
-                    field_content_hash = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "new_filename" => crate::deserialize_field(&mut map, &mut field_new_filename, "new_filename")?,
+                "original_revision_id" => crate::deserialize_field(&mut map, &mut field_original_revision_id, "original_revision_id")?,
+                "original_file_path" => crate::deserialize_field(&mut map, &mut field_original_file_path, "original_file_path")?,
+                "server_modified" => crate::deserialize_field(&mut map, &mut field_server_modified, "server_modified")?,
+                "author_member_id" => crate::deserialize_field(&mut map, &mut field_author_member_id, "author_member_id")?,
+                "author_member_status" => crate::deserialize_field(&mut map, &mut field_author_member_status, "author_member_status")?,
+                "author_email" => crate::deserialize_field(&mut map, &mut field_author_email, "author_email")?,
+                "file_type" => crate::deserialize_field(&mut map, &mut field_file_type, "file_type")?,
+                "size" => crate::deserialize_field(&mut map, &mut field_size, "size")?,
+                "content_hash" => crate::deserialize_field(&mut map, &mut field_content_hash, "content_hash")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -8943,58 +8329,15 @@ impl LegalHoldPolicy {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "id" => {
-                    if field_id.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("id"));
-                    }
-                    field_id = Some(map.next_value()?);
-                }
-                "name" => {
-                    if field_name.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("name"));
-                    }
-                    field_name = Some(map.next_value()?);
-                }
-                "members" => {
-                    if field_members.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("members"));
-                    }
-                    field_members = Some(map.next_value()?);
-                }
-                "status" => {
-                    if field_status.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("status"));
-                    }
-                    field_status = Some(map.next_value()?);
-                }
-                "start_date" => {
-                    if field_start_date.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("start_date"));
-                    }
-                    field_start_date = Some(map.next_value()?);
-                }
-                "description" => {
-                    if field_description.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("description"));
-                    }
-                    field_description = Some(map.next_value()?);
-                }
-                "activation_time" => {
-                    if field_activation_time.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("activation_time"));
-                    }
-                    field_activation_time = Some(map.next_value()?);
-                }
-                "end_date" => {
-                    if field_end_date.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("end_date"));
-                    }
-                    field_end_date = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "id" => crate::deserialize_field(&mut map, &mut field_id, "id")?,
+                "name" => crate::deserialize_field(&mut map, &mut field_name, "name")?,
+                "members" => crate::deserialize_field(&mut map, &mut field_members, "members")?,
+                "status" => crate::deserialize_field(&mut map, &mut field_status, "status")?,
+                "start_date" => crate::deserialize_field(&mut map, &mut field_start_date, "start_date")?,
+                "description" => crate::deserialize_field(&mut map, &mut field_description, "description")?,
+                "activation_time" => crate::deserialize_field(&mut map, &mut field_activation_time, "activation_time")?,
+                "end_date" => crate::deserialize_field(&mut map, &mut field_end_date, "end_date")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -9080,8 +8423,9 @@ pub enum LegalHoldStatus {
     /// The legal hold policy is releasing.
     Releasing,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for LegalHoldStatus {
@@ -9106,7 +8450,7 @@ impl<'de> ::serde::de::Deserialize<'de> for LegalHoldStatus {
                     "updating" => LegalHoldStatus::Updating,
                     "exporting" => LegalHoldStatus::Exporting,
                     "releasing" => LegalHoldStatus::Releasing,
-                    _ => LegalHoldStatus::Other,
+                    _ => LegalHoldStatus::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -9164,7 +8508,10 @@ impl ::serde::ser::Serialize for LegalHoldStatus {
                 s.serialize_field(".tag", "releasing")?;
                 s.end()
             }
-            LegalHoldStatus::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            LegalHoldStatus::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -9177,8 +8524,9 @@ pub enum LegalHoldsError {
     /// You don't have permissions to perform this action.
     InsufficientPermissions,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for LegalHoldsError {
@@ -9199,7 +8547,7 @@ impl<'de> ::serde::de::Deserialize<'de> for LegalHoldsError {
                 let value = match tag {
                     "unknown_legal_hold_error" => LegalHoldsError::UnknownLegalHoldError,
                     "insufficient_permissions" => LegalHoldsError::InsufficientPermissions,
-                    _ => LegalHoldsError::Other,
+                    _ => LegalHoldsError::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -9229,7 +8577,10 @@ impl ::serde::ser::Serialize for LegalHoldsError {
                 s.serialize_field(".tag", "insufficient_permissions")?;
                 s.end()
             }
-            LegalHoldsError::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            LegalHoldsError::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -9279,16 +8630,8 @@ impl LegalHoldsGetPolicyArg {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "id" => {
-                    if field_id.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("id"));
-                    }
-                    field_id = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "id" => crate::deserialize_field(&mut map, &mut field_id, "id")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -9348,8 +8691,9 @@ pub enum LegalHoldsGetPolicyError {
     /// Legal hold policy does not exist for [`LegalHoldsGetPolicyArg::id`](LegalHoldsGetPolicyArg).
     LegalHoldPolicyNotFound,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for LegalHoldsGetPolicyError {
@@ -9371,7 +8715,7 @@ impl<'de> ::serde::de::Deserialize<'de> for LegalHoldsGetPolicyError {
                     "unknown_legal_hold_error" => LegalHoldsGetPolicyError::UnknownLegalHoldError,
                     "insufficient_permissions" => LegalHoldsGetPolicyError::InsufficientPermissions,
                     "legal_hold_policy_not_found" => LegalHoldsGetPolicyError::LegalHoldPolicyNotFound,
-                    _ => LegalHoldsGetPolicyError::Other,
+                    _ => LegalHoldsGetPolicyError::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -9408,7 +8752,10 @@ impl ::serde::ser::Serialize for LegalHoldsGetPolicyError {
                 s.serialize_field(".tag", "legal_hold_policy_not_found")?;
                 s.end()
             }
-            LegalHoldsGetPolicyError::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            LegalHoldsGetPolicyError::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -9432,7 +8779,7 @@ impl From<LegalHoldsError> for LegalHoldsGetPolicyError {
         match parent {
             LegalHoldsError::UnknownLegalHoldError => LegalHoldsGetPolicyError::UnknownLegalHoldError,
             LegalHoldsError::InsufficientPermissions => LegalHoldsGetPolicyError::InsufficientPermissions,
-            LegalHoldsError::Other => LegalHoldsGetPolicyError::Other,
+            LegalHoldsError::Other(x) => LegalHoldsGetPolicyError::Other(x),
         }
     }
 }
@@ -9486,28 +8833,10 @@ impl LegalHoldsListHeldRevisionResult {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "entries" => {
-                    if field_entries.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("entries"));
-                    }
-                    field_entries = Some(map.next_value()?);
-                }
-                "has_more" => {
-                    if field_has_more.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("has_more"));
-                    }
-                    field_has_more = Some(map.next_value()?);
-                }
-                "cursor" => {
-                    if field_cursor.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("cursor"));
-                    }
-                    field_cursor = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "entries" => crate::deserialize_field(&mut map, &mut field_entries, "entries")?,
+                "has_more" => crate::deserialize_field(&mut map, &mut field_has_more, "has_more")?,
+                "cursor" => crate::deserialize_field(&mut map, &mut field_cursor, "cursor")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -9595,16 +8924,8 @@ impl LegalHoldsListHeldRevisionsArg {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "id" => {
-                    if field_id.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("id"));
-                    }
-                    field_id = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "id" => crate::deserialize_field(&mut map, &mut field_id, "id")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -9697,22 +9018,9 @@ impl LegalHoldsListHeldRevisionsContinueArg {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "id" => {
-                    if field_id.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("id"));
-                    }
-                    field_id = Some(map.next_value()?);
-                }
-                "cursor" => {
-                    if field_cursor.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("cursor"));
-                    }
-                    field_cursor = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "id" => crate::deserialize_field(&mut map, &mut field_id, "id")?,
+                "cursor" => crate::deserialize_field(&mut map, &mut field_cursor, "cursor")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -9778,8 +9086,9 @@ pub enum LegalHoldsListHeldRevisionsContinueError {
     /// again with an empty cursor to obtain a new cursor.
     Reset,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for LegalHoldsListHeldRevisionsContinueError {
@@ -9801,7 +9110,7 @@ impl<'de> ::serde::de::Deserialize<'de> for LegalHoldsListHeldRevisionsContinueE
                     "unknown_legal_hold_error" => LegalHoldsListHeldRevisionsContinueError::UnknownLegalHoldError,
                     "transient_error" => LegalHoldsListHeldRevisionsContinueError::TransientError,
                     "reset" => LegalHoldsListHeldRevisionsContinueError::Reset,
-                    _ => LegalHoldsListHeldRevisionsContinueError::Other,
+                    _ => LegalHoldsListHeldRevisionsContinueError::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -9838,7 +9147,10 @@ impl ::serde::ser::Serialize for LegalHoldsListHeldRevisionsContinueError {
                 s.serialize_field(".tag", "reset")?;
                 s.end()
             }
-            LegalHoldsListHeldRevisionsContinueError::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            LegalHoldsListHeldRevisionsContinueError::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -9870,8 +9182,9 @@ pub enum LegalHoldsListHeldRevisionsError {
     /// Trying to list revisions for an inactive legal hold.
     InactiveLegalHold,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for LegalHoldsListHeldRevisionsError {
@@ -9895,7 +9208,7 @@ impl<'de> ::serde::de::Deserialize<'de> for LegalHoldsListHeldRevisionsError {
                     "transient_error" => LegalHoldsListHeldRevisionsError::TransientError,
                     "legal_hold_still_empty" => LegalHoldsListHeldRevisionsError::LegalHoldStillEmpty,
                     "inactive_legal_hold" => LegalHoldsListHeldRevisionsError::InactiveLegalHold,
-                    _ => LegalHoldsListHeldRevisionsError::Other,
+                    _ => LegalHoldsListHeldRevisionsError::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -9946,7 +9259,10 @@ impl ::serde::ser::Serialize for LegalHoldsListHeldRevisionsError {
                 s.serialize_field(".tag", "inactive_legal_hold")?;
                 s.end()
             }
-            LegalHoldsListHeldRevisionsError::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            LegalHoldsListHeldRevisionsError::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -9973,7 +9289,7 @@ impl From<LegalHoldsError> for LegalHoldsListHeldRevisionsError {
         match parent {
             LegalHoldsError::UnknownLegalHoldError => LegalHoldsListHeldRevisionsError::UnknownLegalHoldError,
             LegalHoldsError::InsufficientPermissions => LegalHoldsListHeldRevisionsError::InsufficientPermissions,
-            LegalHoldsError::Other => LegalHoldsListHeldRevisionsError::Other,
+            LegalHoldsError::Other(x) => LegalHoldsListHeldRevisionsError::Other(x),
         }
     }
 }
@@ -10000,16 +9316,8 @@ impl LegalHoldsListPoliciesArg {
         let mut field_include_released = None;
         while let Some(key) = map.next_key::<&str>()? {
             match key {
-                "include_released" => {
-                    if field_include_released.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("include_released"));
-                    }
-                    field_include_released = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "include_released" => crate::deserialize_field(&mut map, &mut field_include_released, "include_released")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         let result = LegalHoldsListPoliciesArg {
@@ -10068,8 +9376,9 @@ pub enum LegalHoldsListPoliciesError {
     /// Temporary infrastructure failure, please retry.
     TransientError,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for LegalHoldsListPoliciesError {
@@ -10091,7 +9400,7 @@ impl<'de> ::serde::de::Deserialize<'de> for LegalHoldsListPoliciesError {
                     "unknown_legal_hold_error" => LegalHoldsListPoliciesError::UnknownLegalHoldError,
                     "insufficient_permissions" => LegalHoldsListPoliciesError::InsufficientPermissions,
                     "transient_error" => LegalHoldsListPoliciesError::TransientError,
-                    _ => LegalHoldsListPoliciesError::Other,
+                    _ => LegalHoldsListPoliciesError::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -10128,7 +9437,10 @@ impl ::serde::ser::Serialize for LegalHoldsListPoliciesError {
                 s.serialize_field(".tag", "transient_error")?;
                 s.end()
             }
-            LegalHoldsListPoliciesError::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            LegalHoldsListPoliciesError::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -10153,7 +9465,7 @@ impl From<LegalHoldsError> for LegalHoldsListPoliciesError {
         match parent {
             LegalHoldsError::UnknownLegalHoldError => LegalHoldsListPoliciesError::UnknownLegalHoldError,
             LegalHoldsError::InsufficientPermissions => LegalHoldsListPoliciesError::InsufficientPermissions,
-            LegalHoldsError::Other => LegalHoldsListPoliciesError::Other,
+            LegalHoldsError::Other(x) => LegalHoldsListPoliciesError::Other(x),
         }
     }
 }
@@ -10188,16 +9500,8 @@ impl LegalHoldsListPoliciesResult {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "policies" => {
-                    if field_policies.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("policies"));
-                    }
-                    field_policies = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "policies" => crate::deserialize_field(&mut map, &mut field_policies, "policies")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -10317,40 +9621,12 @@ impl LegalHoldsPolicyCreateArg {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "name" => {
-                    if field_name.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("name"));
-                    }
-                    field_name = Some(map.next_value()?);
-                }
-                "members" => {
-                    if field_members.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("members"));
-                    }
-                    field_members = Some(map.next_value()?);
-                }
-                "description" => {
-                    if field_description.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("description"));
-                    }
-                    field_description = Some(map.next_value()?);
-                }
-                "start_date" => {
-                    if field_start_date.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("start_date"));
-                    }
-                    field_start_date = Some(map.next_value()?);
-                }
-                "end_date" => {
-                    if field_end_date.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("end_date"));
-                    }
-                    field_end_date = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "name" => crate::deserialize_field(&mut map, &mut field_name, "name")?,
+                "members" => crate::deserialize_field(&mut map, &mut field_members, "members")?,
+                "description" => crate::deserialize_field(&mut map, &mut field_description, "description")?,
+                "start_date" => crate::deserialize_field(&mut map, &mut field_start_date, "start_date")?,
+                "end_date" => crate::deserialize_field(&mut map, &mut field_end_date, "end_date")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -10438,8 +9714,9 @@ pub enum LegalHoldsPolicyCreateError {
     /// The provided date is invalid.
     InvalidDate,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for LegalHoldsPolicyCreateError {
@@ -10468,7 +9745,7 @@ impl<'de> ::serde::de::Deserialize<'de> for LegalHoldsPolicyCreateError {
                     "name_must_be_unique" => LegalHoldsPolicyCreateError::NameMustBeUnique,
                     "team_exceeded_legal_hold_quota" => LegalHoldsPolicyCreateError::TeamExceededLegalHoldQuota,
                     "invalid_date" => LegalHoldsPolicyCreateError::InvalidDate,
-                    _ => LegalHoldsPolicyCreateError::Other,
+                    _ => LegalHoldsPolicyCreateError::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -10554,7 +9831,10 @@ impl ::serde::ser::Serialize for LegalHoldsPolicyCreateError {
                 s.serialize_field(".tag", "invalid_date")?;
                 s.end()
             }
-            LegalHoldsPolicyCreateError::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            LegalHoldsPolicyCreateError::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -10586,7 +9866,7 @@ impl From<LegalHoldsError> for LegalHoldsPolicyCreateError {
         match parent {
             LegalHoldsError::UnknownLegalHoldError => LegalHoldsPolicyCreateError::UnknownLegalHoldError,
             LegalHoldsError::InsufficientPermissions => LegalHoldsPolicyCreateError::InsufficientPermissions,
-            LegalHoldsError::Other => LegalHoldsPolicyCreateError::Other,
+            LegalHoldsError::Other(x) => LegalHoldsPolicyCreateError::Other(x),
         }
     }
 }
@@ -10622,16 +9902,8 @@ impl LegalHoldsPolicyReleaseArg {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "id" => {
-                    if field_id.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("id"));
-                    }
-                    field_id = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "id" => crate::deserialize_field(&mut map, &mut field_id, "id")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -10696,8 +9968,9 @@ pub enum LegalHoldsPolicyReleaseError {
     /// [`LegalHoldsPolicyReleaseArg::id`](LegalHoldsPolicyReleaseArg).
     LegalHoldPolicyNotFound,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for LegalHoldsPolicyReleaseError {
@@ -10721,7 +9994,7 @@ impl<'de> ::serde::de::Deserialize<'de> for LegalHoldsPolicyReleaseError {
                     "legal_hold_performing_another_operation" => LegalHoldsPolicyReleaseError::LegalHoldPerformingAnotherOperation,
                     "legal_hold_already_releasing" => LegalHoldsPolicyReleaseError::LegalHoldAlreadyReleasing,
                     "legal_hold_policy_not_found" => LegalHoldsPolicyReleaseError::LegalHoldPolicyNotFound,
-                    _ => LegalHoldsPolicyReleaseError::Other,
+                    _ => LegalHoldsPolicyReleaseError::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -10772,7 +10045,10 @@ impl ::serde::ser::Serialize for LegalHoldsPolicyReleaseError {
                 s.serialize_field(".tag", "legal_hold_policy_not_found")?;
                 s.end()
             }
-            LegalHoldsPolicyReleaseError::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            LegalHoldsPolicyReleaseError::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -10798,7 +10074,7 @@ impl From<LegalHoldsError> for LegalHoldsPolicyReleaseError {
         match parent {
             LegalHoldsError::UnknownLegalHoldError => LegalHoldsPolicyReleaseError::UnknownLegalHoldError,
             LegalHoldsError::InsufficientPermissions => LegalHoldsPolicyReleaseError::InsufficientPermissions,
-            LegalHoldsError::Other => LegalHoldsPolicyReleaseError::Other,
+            LegalHoldsError::Other(x) => LegalHoldsPolicyReleaseError::Other(x),
         }
     }
 }
@@ -10864,34 +10140,11 @@ impl LegalHoldsPolicyUpdateArg {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "id" => {
-                    if field_id.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("id"));
-                    }
-                    field_id = Some(map.next_value()?);
-                }
-                "name" => {
-                    if field_name.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("name"));
-                    }
-                    field_name = Some(map.next_value()?);
-                }
-                "description" => {
-                    if field_description.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("description"));
-                    }
-                    field_description = Some(map.next_value()?);
-                }
-                "members" => {
-                    if field_members.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("members"));
-                    }
-                    field_members = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "id" => crate::deserialize_field(&mut map, &mut field_id, "id")?,
+                "name" => crate::deserialize_field(&mut map, &mut field_name, "name")?,
+                "description" => crate::deserialize_field(&mut map, &mut field_description, "description")?,
+                "members" => crate::deserialize_field(&mut map, &mut field_members, "members")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -10978,8 +10231,9 @@ pub enum LegalHoldsPolicyUpdateError {
     /// [`LegalHoldsPolicyUpdateArg::id`](LegalHoldsPolicyUpdateArg).
     LegalHoldPolicyNotFound,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for LegalHoldsPolicyUpdateError {
@@ -11008,7 +10262,7 @@ impl<'de> ::serde::de::Deserialize<'de> for LegalHoldsPolicyUpdateError {
                     "empty_members_list" => LegalHoldsPolicyUpdateError::EmptyMembersList,
                     "name_must_be_unique" => LegalHoldsPolicyUpdateError::NameMustBeUnique,
                     "legal_hold_policy_not_found" => LegalHoldsPolicyUpdateError::LegalHoldPolicyNotFound,
-                    _ => LegalHoldsPolicyUpdateError::Other,
+                    _ => LegalHoldsPolicyUpdateError::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -11094,7 +10348,10 @@ impl ::serde::ser::Serialize for LegalHoldsPolicyUpdateError {
                 s.serialize_field(".tag", "legal_hold_policy_not_found")?;
                 s.end()
             }
-            LegalHoldsPolicyUpdateError::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            LegalHoldsPolicyUpdateError::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -11125,7 +10382,7 @@ impl From<LegalHoldsError> for LegalHoldsPolicyUpdateError {
         match parent {
             LegalHoldsError::UnknownLegalHoldError => LegalHoldsPolicyUpdateError::UnknownLegalHoldError,
             LegalHoldsError::InsufficientPermissions => LegalHoldsPolicyUpdateError::InsufficientPermissions,
-            LegalHoldsError::Other => LegalHoldsPolicyUpdateError::Other,
+            LegalHoldsError::Other(x) => LegalHoldsPolicyUpdateError::Other(x),
         }
     }
 }
@@ -11161,16 +10418,8 @@ impl ListMemberAppsArg {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "team_member_id" => {
-                    if field_team_member_id.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("team_member_id"));
-                    }
-                    field_team_member_id = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "team_member_id" => crate::deserialize_field(&mut map, &mut field_team_member_id, "team_member_id")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -11228,8 +10477,9 @@ pub enum ListMemberAppsError {
     /// Member not found.
     MemberNotFound,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for ListMemberAppsError {
@@ -11249,7 +10499,7 @@ impl<'de> ::serde::de::Deserialize<'de> for ListMemberAppsError {
                 };
                 let value = match tag {
                     "member_not_found" => ListMemberAppsError::MemberNotFound,
-                    _ => ListMemberAppsError::Other,
+                    _ => ListMemberAppsError::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -11272,7 +10522,10 @@ impl ::serde::ser::Serialize for ListMemberAppsError {
                 s.serialize_field(".tag", "member_not_found")?;
                 s.end()
             }
-            ListMemberAppsError::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            ListMemberAppsError::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -11321,16 +10574,8 @@ impl ListMemberAppsResult {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "linked_api_apps" => {
-                    if field_linked_api_apps.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("linked_api_apps"));
-                    }
-                    field_linked_api_apps = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "linked_api_apps" => crate::deserialize_field(&mut map, &mut field_linked_api_apps, "linked_api_apps")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -11442,34 +10687,11 @@ impl ListMemberDevicesArg {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "team_member_id" => {
-                    if field_team_member_id.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("team_member_id"));
-                    }
-                    field_team_member_id = Some(map.next_value()?);
-                }
-                "include_web_sessions" => {
-                    if field_include_web_sessions.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("include_web_sessions"));
-                    }
-                    field_include_web_sessions = Some(map.next_value()?);
-                }
-                "include_desktop_clients" => {
-                    if field_include_desktop_clients.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("include_desktop_clients"));
-                    }
-                    field_include_desktop_clients = Some(map.next_value()?);
-                }
-                "include_mobile_clients" => {
-                    if field_include_mobile_clients.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("include_mobile_clients"));
-                    }
-                    field_include_mobile_clients = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "team_member_id" => crate::deserialize_field(&mut map, &mut field_team_member_id, "team_member_id")?,
+                "include_web_sessions" => crate::deserialize_field(&mut map, &mut field_include_web_sessions, "include_web_sessions")?,
+                "include_desktop_clients" => crate::deserialize_field(&mut map, &mut field_include_desktop_clients, "include_desktop_clients")?,
+                "include_mobile_clients" => crate::deserialize_field(&mut map, &mut field_include_mobile_clients, "include_mobile_clients")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -11537,8 +10759,9 @@ pub enum ListMemberDevicesError {
     /// Member not found.
     MemberNotFound,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for ListMemberDevicesError {
@@ -11558,7 +10781,7 @@ impl<'de> ::serde::de::Deserialize<'de> for ListMemberDevicesError {
                 };
                 let value = match tag {
                     "member_not_found" => ListMemberDevicesError::MemberNotFound,
-                    _ => ListMemberDevicesError::Other,
+                    _ => ListMemberDevicesError::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -11581,7 +10804,10 @@ impl ::serde::ser::Serialize for ListMemberDevicesError {
                 s.serialize_field(".tag", "member_not_found")?;
                 s.end()
             }
-            ListMemberDevicesError::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            ListMemberDevicesError::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -11639,28 +10865,10 @@ impl ListMemberDevicesResult {
         let mut field_mobile_client_sessions = None;
         while let Some(key) = map.next_key::<&str>()? {
             match key {
-                "active_web_sessions" => {
-                    if field_active_web_sessions.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("active_web_sessions"));
-                    }
-                    field_active_web_sessions = Some(map.next_value()?);
-                }
-                "desktop_client_sessions" => {
-                    if field_desktop_client_sessions.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("desktop_client_sessions"));
-                    }
-                    field_desktop_client_sessions = Some(map.next_value()?);
-                }
-                "mobile_client_sessions" => {
-                    if field_mobile_client_sessions.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("mobile_client_sessions"));
-                    }
-                    field_mobile_client_sessions = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "active_web_sessions" => crate::deserialize_field(&mut map, &mut field_active_web_sessions, "active_web_sessions")?,
+                "desktop_client_sessions" => crate::deserialize_field(&mut map, &mut field_desktop_client_sessions, "desktop_client_sessions")?,
+                "mobile_client_sessions" => crate::deserialize_field(&mut map, &mut field_mobile_client_sessions, "mobile_client_sessions")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         let result = ListMemberDevicesResult {
@@ -11746,16 +10954,8 @@ impl ListMembersAppsArg {
         let mut field_cursor = None;
         while let Some(key) = map.next_key::<&str>()? {
             match key {
-                "cursor" => {
-                    if field_cursor.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("cursor"));
-                    }
-                    field_cursor = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "cursor" => crate::deserialize_field(&mut map, &mut field_cursor, "cursor")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         let result = ListMembersAppsArg {
@@ -11814,8 +11014,9 @@ pub enum ListMembersAppsError {
     /// again with an empty cursor to obtain a new cursor.
     Reset,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for ListMembersAppsError {
@@ -11835,7 +11036,7 @@ impl<'de> ::serde::de::Deserialize<'de> for ListMembersAppsError {
                 };
                 let value = match tag {
                     "reset" => ListMembersAppsError::Reset,
-                    _ => ListMembersAppsError::Other,
+                    _ => ListMembersAppsError::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -11858,7 +11059,10 @@ impl ::serde::ser::Serialize for ListMembersAppsError {
                 s.serialize_field(".tag", "reset")?;
                 s.end()
             }
-            ListMembersAppsError::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            ListMembersAppsError::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -11925,28 +11129,10 @@ impl ListMembersAppsResult {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "apps" => {
-                    if field_apps.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("apps"));
-                    }
-                    field_apps = Some(map.next_value()?);
-                }
-                "has_more" => {
-                    if field_has_more.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("has_more"));
-                    }
-                    field_has_more = Some(map.next_value()?);
-                }
-                "cursor" => {
-                    if field_cursor.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("cursor"));
-                    }
-                    field_cursor = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "apps" => crate::deserialize_field(&mut map, &mut field_apps, "apps")?,
+                "has_more" => crate::deserialize_field(&mut map, &mut field_has_more, "has_more")?,
+                "cursor" => crate::deserialize_field(&mut map, &mut field_cursor, "cursor")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -12067,34 +11253,11 @@ impl ListMembersDevicesArg {
         let mut field_include_mobile_clients = None;
         while let Some(key) = map.next_key::<&str>()? {
             match key {
-                "cursor" => {
-                    if field_cursor.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("cursor"));
-                    }
-                    field_cursor = Some(map.next_value()?);
-                }
-                "include_web_sessions" => {
-                    if field_include_web_sessions.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("include_web_sessions"));
-                    }
-                    field_include_web_sessions = Some(map.next_value()?);
-                }
-                "include_desktop_clients" => {
-                    if field_include_desktop_clients.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("include_desktop_clients"));
-                    }
-                    field_include_desktop_clients = Some(map.next_value()?);
-                }
-                "include_mobile_clients" => {
-                    if field_include_mobile_clients.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("include_mobile_clients"));
-                    }
-                    field_include_mobile_clients = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "cursor" => crate::deserialize_field(&mut map, &mut field_cursor, "cursor")?,
+                "include_web_sessions" => crate::deserialize_field(&mut map, &mut field_include_web_sessions, "include_web_sessions")?,
+                "include_desktop_clients" => crate::deserialize_field(&mut map, &mut field_include_desktop_clients, "include_desktop_clients")?,
+                "include_mobile_clients" => crate::deserialize_field(&mut map, &mut field_include_mobile_clients, "include_mobile_clients")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         let result = ListMembersDevicesArg {
@@ -12163,8 +11326,9 @@ pub enum ListMembersDevicesError {
     /// empty cursor to obtain a new cursor.
     Reset,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for ListMembersDevicesError {
@@ -12184,7 +11348,7 @@ impl<'de> ::serde::de::Deserialize<'de> for ListMembersDevicesError {
                 };
                 let value = match tag {
                     "reset" => ListMembersDevicesError::Reset,
-                    _ => ListMembersDevicesError::Other,
+                    _ => ListMembersDevicesError::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -12207,7 +11371,10 @@ impl ::serde::ser::Serialize for ListMembersDevicesError {
                 s.serialize_field(".tag", "reset")?;
                 s.end()
             }
-            ListMembersDevicesError::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            ListMembersDevicesError::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -12272,28 +11439,10 @@ impl ListMembersDevicesResult {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "devices" => {
-                    if field_devices.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("devices"));
-                    }
-                    field_devices = Some(map.next_value()?);
-                }
-                "has_more" => {
-                    if field_has_more.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("has_more"));
-                    }
-                    field_has_more = Some(map.next_value()?);
-                }
-                "cursor" => {
-                    if field_cursor.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("cursor"));
-                    }
-                    field_cursor = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "devices" => crate::deserialize_field(&mut map, &mut field_devices, "devices")?,
+                "has_more" => crate::deserialize_field(&mut map, &mut field_has_more, "has_more")?,
+                "cursor" => crate::deserialize_field(&mut map, &mut field_cursor, "cursor")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -12378,16 +11527,8 @@ impl ListTeamAppsArg {
         let mut field_cursor = None;
         while let Some(key) = map.next_key::<&str>()? {
             match key {
-                "cursor" => {
-                    if field_cursor.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("cursor"));
-                    }
-                    field_cursor = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "cursor" => crate::deserialize_field(&mut map, &mut field_cursor, "cursor")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         let result = ListTeamAppsArg {
@@ -12446,8 +11587,9 @@ pub enum ListTeamAppsError {
     /// again with an empty cursor to obtain a new cursor.
     Reset,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for ListTeamAppsError {
@@ -12467,7 +11609,7 @@ impl<'de> ::serde::de::Deserialize<'de> for ListTeamAppsError {
                 };
                 let value = match tag {
                     "reset" => ListTeamAppsError::Reset,
-                    _ => ListTeamAppsError::Other,
+                    _ => ListTeamAppsError::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -12490,7 +11632,10 @@ impl ::serde::ser::Serialize for ListTeamAppsError {
                 s.serialize_field(".tag", "reset")?;
                 s.end()
             }
-            ListTeamAppsError::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            ListTeamAppsError::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -12557,28 +11702,10 @@ impl ListTeamAppsResult {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "apps" => {
-                    if field_apps.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("apps"));
-                    }
-                    field_apps = Some(map.next_value()?);
-                }
-                "has_more" => {
-                    if field_has_more.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("has_more"));
-                    }
-                    field_has_more = Some(map.next_value()?);
-                }
-                "cursor" => {
-                    if field_cursor.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("cursor"));
-                    }
-                    field_cursor = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "apps" => crate::deserialize_field(&mut map, &mut field_apps, "apps")?,
+                "has_more" => crate::deserialize_field(&mut map, &mut field_has_more, "has_more")?,
+                "cursor" => crate::deserialize_field(&mut map, &mut field_cursor, "cursor")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -12698,34 +11825,11 @@ impl ListTeamDevicesArg {
         let mut field_include_mobile_clients = None;
         while let Some(key) = map.next_key::<&str>()? {
             match key {
-                "cursor" => {
-                    if field_cursor.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("cursor"));
-                    }
-                    field_cursor = Some(map.next_value()?);
-                }
-                "include_web_sessions" => {
-                    if field_include_web_sessions.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("include_web_sessions"));
-                    }
-                    field_include_web_sessions = Some(map.next_value()?);
-                }
-                "include_desktop_clients" => {
-                    if field_include_desktop_clients.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("include_desktop_clients"));
-                    }
-                    field_include_desktop_clients = Some(map.next_value()?);
-                }
-                "include_mobile_clients" => {
-                    if field_include_mobile_clients.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("include_mobile_clients"));
-                    }
-                    field_include_mobile_clients = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "cursor" => crate::deserialize_field(&mut map, &mut field_cursor, "cursor")?,
+                "include_web_sessions" => crate::deserialize_field(&mut map, &mut field_include_web_sessions, "include_web_sessions")?,
+                "include_desktop_clients" => crate::deserialize_field(&mut map, &mut field_include_desktop_clients, "include_desktop_clients")?,
+                "include_mobile_clients" => crate::deserialize_field(&mut map, &mut field_include_mobile_clients, "include_mobile_clients")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         let result = ListTeamDevicesArg {
@@ -12794,8 +11898,9 @@ pub enum ListTeamDevicesError {
     /// cursor to obtain a new cursor.
     Reset,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for ListTeamDevicesError {
@@ -12815,7 +11920,7 @@ impl<'de> ::serde::de::Deserialize<'de> for ListTeamDevicesError {
                 };
                 let value = match tag {
                     "reset" => ListTeamDevicesError::Reset,
-                    _ => ListTeamDevicesError::Other,
+                    _ => ListTeamDevicesError::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -12838,7 +11943,10 @@ impl ::serde::ser::Serialize for ListTeamDevicesError {
                 s.serialize_field(".tag", "reset")?;
                 s.end()
             }
-            ListTeamDevicesError::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            ListTeamDevicesError::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -12902,28 +12010,10 @@ impl ListTeamDevicesResult {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "devices" => {
-                    if field_devices.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("devices"));
-                    }
-                    field_devices = Some(map.next_value()?);
-                }
-                "has_more" => {
-                    if field_has_more.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("has_more"));
-                    }
-                    field_has_more = Some(map.next_value()?);
-                }
-                "cursor" => {
-                    if field_cursor.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("cursor"));
-                    }
-                    field_cursor = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "devices" => crate::deserialize_field(&mut map, &mut field_devices, "devices")?,
+                "has_more" => crate::deserialize_field(&mut map, &mut field_has_more, "has_more")?,
+                "cursor" => crate::deserialize_field(&mut map, &mut field_cursor, "cursor")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -13017,22 +12107,9 @@ impl MemberAccess {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "user" => {
-                    if field_user.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("user"));
-                    }
-                    field_user = Some(map.next_value()?);
-                }
-                "access_type" => {
-                    if field_access_type.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("access_type"));
-                    }
-                    field_access_type = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "user" => crate::deserialize_field(&mut map, &mut field_user, "user")?,
+                "access_type" => crate::deserialize_field(&mut map, &mut field_access_type, "access_type")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -13193,58 +12270,15 @@ impl MemberAddArg {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "member_email" => {
-                    if field_member_email.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("member_email"));
-                    }
-                    field_member_email = Some(map.next_value()?);
-                }
-                "member_given_name" => {
-                    if field_member_given_name.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("member_given_name"));
-                    }
-                    field_member_given_name = Some(map.next_value()?);
-                }
-                "member_surname" => {
-                    if field_member_surname.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("member_surname"));
-                    }
-                    field_member_surname = Some(map.next_value()?);
-                }
-                "member_external_id" => {
-                    if field_member_external_id.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("member_external_id"));
-                    }
-                    field_member_external_id = Some(map.next_value()?);
-                }
-                "member_persistent_id" => {
-                    if field_member_persistent_id.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("member_persistent_id"));
-                    }
-                    field_member_persistent_id = Some(map.next_value()?);
-                }
-                "send_welcome_email" => {
-                    if field_send_welcome_email.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("send_welcome_email"));
-                    }
-                    field_send_welcome_email = Some(map.next_value()?);
-                }
-                "is_directory_restricted" => {
-                    if field_is_directory_restricted.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("is_directory_restricted"));
-                    }
-                    field_is_directory_restricted = Some(map.next_value()?);
-                }
-                "role" => {
-                    if field_role.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("role"));
-                    }
-                    field_role = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "member_email" => crate::deserialize_field(&mut map, &mut field_member_email, "member_email")?,
+                "member_given_name" => crate::deserialize_field(&mut map, &mut field_member_given_name, "member_given_name")?,
+                "member_surname" => crate::deserialize_field(&mut map, &mut field_member_surname, "member_surname")?,
+                "member_external_id" => crate::deserialize_field(&mut map, &mut field_member_external_id, "member_external_id")?,
+                "member_persistent_id" => crate::deserialize_field(&mut map, &mut field_member_persistent_id, "member_persistent_id")?,
+                "send_welcome_email" => crate::deserialize_field(&mut map, &mut field_send_welcome_email, "send_welcome_email")?,
+                "is_directory_restricted" => crate::deserialize_field(&mut map, &mut field_is_directory_restricted, "is_directory_restricted")?,
+                "role" => crate::deserialize_field(&mut map, &mut field_role, "role")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -13436,52 +12470,14 @@ impl MemberAddArgBase {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "member_email" => {
-                    if field_member_email.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("member_email"));
-                    }
-                    field_member_email = Some(map.next_value()?);
-                }
-                "member_given_name" => {
-                    if field_member_given_name.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("member_given_name"));
-                    }
-                    field_member_given_name = Some(map.next_value()?);
-                }
-                "member_surname" => {
-                    if field_member_surname.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("member_surname"));
-                    }
-                    field_member_surname = Some(map.next_value()?);
-                }
-                "member_external_id" => {
-                    if field_member_external_id.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("member_external_id"));
-                    }
-                    field_member_external_id = Some(map.next_value()?);
-                }
-                "member_persistent_id" => {
-                    if field_member_persistent_id.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("member_persistent_id"));
-                    }
-                    field_member_persistent_id = Some(map.next_value()?);
-                }
-                "send_welcome_email" => {
-                    if field_send_welcome_email.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("send_welcome_email"));
-                    }
-                    field_send_welcome_email = Some(map.next_value()?);
-                }
-                "is_directory_restricted" => {
-                    if field_is_directory_restricted.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("is_directory_restricted"));
-                    }
-                    field_is_directory_restricted = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "member_email" => crate::deserialize_field(&mut map, &mut field_member_email, "member_email")?,
+                "member_given_name" => crate::deserialize_field(&mut map, &mut field_member_given_name, "member_given_name")?,
+                "member_surname" => crate::deserialize_field(&mut map, &mut field_member_surname, "member_surname")?,
+                "member_external_id" => crate::deserialize_field(&mut map, &mut field_member_external_id, "member_external_id")?,
+                "member_persistent_id" => crate::deserialize_field(&mut map, &mut field_member_persistent_id, "member_persistent_id")?,
+                "send_welcome_email" => crate::deserialize_field(&mut map, &mut field_send_welcome_email, "send_welcome_email")?,
+                "is_directory_restricted" => crate::deserialize_field(&mut map, &mut field_is_directory_restricted, "is_directory_restricted")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -14123,58 +13119,15 @@ impl MemberAddV2Arg {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "member_email" => {
-                    if field_member_email.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("member_email"));
-                    }
-                    field_member_email = Some(map.next_value()?);
-                }
-                "member_given_name" => {
-                    if field_member_given_name.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("member_given_name"));
-                    }
-                    field_member_given_name = Some(map.next_value()?);
-                }
-                "member_surname" => {
-                    if field_member_surname.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("member_surname"));
-                    }
-                    field_member_surname = Some(map.next_value()?);
-                }
-                "member_external_id" => {
-                    if field_member_external_id.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("member_external_id"));
-                    }
-                    field_member_external_id = Some(map.next_value()?);
-                }
-                "member_persistent_id" => {
-                    if field_member_persistent_id.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("member_persistent_id"));
-                    }
-                    field_member_persistent_id = Some(map.next_value()?);
-                }
-                "send_welcome_email" => {
-                    if field_send_welcome_email.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("send_welcome_email"));
-                    }
-                    field_send_welcome_email = Some(map.next_value()?);
-                }
-                "is_directory_restricted" => {
-                    if field_is_directory_restricted.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("is_directory_restricted"));
-                    }
-                    field_is_directory_restricted = Some(map.next_value()?);
-                }
-                "role_ids" => {
-                    if field_role_ids.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("role_ids"));
-                    }
-                    field_role_ids = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "member_email" => crate::deserialize_field(&mut map, &mut field_member_email, "member_email")?,
+                "member_given_name" => crate::deserialize_field(&mut map, &mut field_member_given_name, "member_given_name")?,
+                "member_surname" => crate::deserialize_field(&mut map, &mut field_member_surname, "member_surname")?,
+                "member_external_id" => crate::deserialize_field(&mut map, &mut field_member_external_id, "member_external_id")?,
+                "member_persistent_id" => crate::deserialize_field(&mut map, &mut field_member_persistent_id, "member_persistent_id")?,
+                "send_welcome_email" => crate::deserialize_field(&mut map, &mut field_send_welcome_email, "send_welcome_email")?,
+                "is_directory_restricted" => crate::deserialize_field(&mut map, &mut field_is_directory_restricted, "is_directory_restricted")?,
+                "role_ids" => crate::deserialize_field(&mut map, &mut field_role_ids, "role_ids")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -14300,8 +13253,9 @@ pub enum MemberAddV2Result {
     /// Describes a user that was successfully added to the team.
     Success(TeamMemberInfoV2),
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for MemberAddV2Result {
@@ -14391,7 +13345,7 @@ impl<'de> ::serde::de::Deserialize<'de> for MemberAddV2Result {
                         }
                     }
                     "success" => MemberAddV2Result::Success(TeamMemberInfoV2::internal_deserialize(&mut map)?),
-                    _ => MemberAddV2Result::Other,
+                    _ => MemberAddV2Result::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -14495,7 +13449,10 @@ impl ::serde::ser::Serialize for MemberAddV2Result {
                 x.internal_serialize::<S>(&mut s)?;
                 s.end()
             }
-            MemberAddV2Result::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            MemberAddV2Result::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -14580,34 +13537,11 @@ impl MemberDevices {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "team_member_id" => {
-                    if field_team_member_id.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("team_member_id"));
-                    }
-                    field_team_member_id = Some(map.next_value()?);
-                }
-                "web_sessions" => {
-                    if field_web_sessions.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("web_sessions"));
-                    }
-                    field_web_sessions = Some(map.next_value()?);
-                }
-                "desktop_clients" => {
-                    if field_desktop_clients.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("desktop_clients"));
-                    }
-                    field_desktop_clients = Some(map.next_value()?);
-                }
-                "mobile_clients" => {
-                    if field_mobile_clients.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("mobile_clients"));
-                    }
-                    field_mobile_clients = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "team_member_id" => crate::deserialize_field(&mut map, &mut field_team_member_id, "team_member_id")?,
+                "web_sessions" => crate::deserialize_field(&mut map, &mut field_web_sessions, "web_sessions")?,
+                "desktop_clients" => crate::deserialize_field(&mut map, &mut field_desktop_clients, "desktop_clients")?,
+                "mobile_clients" => crate::deserialize_field(&mut map, &mut field_mobile_clients, "mobile_clients")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -14707,22 +13641,9 @@ impl MemberLinkedApps {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "team_member_id" => {
-                    if field_team_member_id.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("team_member_id"));
-                    }
-                    field_team_member_id = Some(map.next_value()?);
-                }
-                "linked_api_apps" => {
-                    if field_linked_api_apps.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("linked_api_apps"));
-                    }
-                    field_linked_api_apps = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "team_member_id" => crate::deserialize_field(&mut map, &mut field_team_member_id, "team_member_id")?,
+                "linked_api_apps" => crate::deserialize_field(&mut map, &mut field_linked_api_apps, "linked_api_apps")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -14937,100 +13858,22 @@ impl MemberProfile {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "team_member_id" => {
-                    if field_team_member_id.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("team_member_id"));
-                    }
-                    field_team_member_id = Some(map.next_value()?);
-                }
-                "email" => {
-                    if field_email.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("email"));
-                    }
-                    field_email = Some(map.next_value()?);
-                }
-                "email_verified" => {
-                    if field_email_verified.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("email_verified"));
-                    }
-                    field_email_verified = Some(map.next_value()?);
-                }
-                "status" => {
-                    if field_status.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("status"));
-                    }
-                    field_status = Some(map.next_value()?);
-                }
-                "name" => {
-                    if field_name.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("name"));
-                    }
-                    field_name = Some(map.next_value()?);
-                }
-                "membership_type" => {
-                    if field_membership_type.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("membership_type"));
-                    }
-                    field_membership_type = Some(map.next_value()?);
-                }
-                "external_id" => {
-                    if field_external_id.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("external_id"));
-                    }
-                    field_external_id = Some(map.next_value()?);
-                }
-                "account_id" => {
-                    if field_account_id.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("account_id"));
-                    }
-                    field_account_id = Some(map.next_value()?);
-                }
-                "secondary_emails" => {
-                    if field_secondary_emails.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("secondary_emails"));
-                    }
-                    field_secondary_emails = Some(map.next_value()?);
-                }
-                "invited_on" => {
-                    if field_invited_on.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("invited_on"));
-                    }
-                    field_invited_on = Some(map.next_value()?);
-                }
-                "joined_on" => {
-                    if field_joined_on.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("joined_on"));
-                    }
-                    field_joined_on = Some(map.next_value()?);
-                }
-                "suspended_on" => {
-                    if field_suspended_on.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("suspended_on"));
-                    }
-                    field_suspended_on = Some(map.next_value()?);
-                }
-                "persistent_id" => {
-                    if field_persistent_id.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("persistent_id"));
-                    }
-                    field_persistent_id = Some(map.next_value()?);
-                }
-                "is_directory_restricted" => {
-                    if field_is_directory_restricted.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("is_directory_restricted"));
-                    }
-                    field_is_directory_restricted = Some(map.next_value()?);
-                }
-                "profile_photo_url" => {
-                    if field_profile_photo_url.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("profile_photo_url"));
-                    }
-                    field_profile_photo_url = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "team_member_id" => crate::deserialize_field(&mut map, &mut field_team_member_id, "team_member_id")?,
+                "email" => crate::deserialize_field(&mut map, &mut field_email, "email")?,
+                "email_verified" => crate::deserialize_field(&mut map, &mut field_email_verified, "email_verified")?,
+                "status" => crate::deserialize_field(&mut map, &mut field_status, "status")?,
+                "name" => crate::deserialize_field(&mut map, &mut field_name, "name")?,
+                "membership_type" => crate::deserialize_field(&mut map, &mut field_membership_type, "membership_type")?,
+                "external_id" => crate::deserialize_field(&mut map, &mut field_external_id, "external_id")?,
+                "account_id" => crate::deserialize_field(&mut map, &mut field_account_id, "account_id")?,
+                "secondary_emails" => crate::deserialize_field(&mut map, &mut field_secondary_emails, "secondary_emails")?,
+                "invited_on" => crate::deserialize_field(&mut map, &mut field_invited_on, "invited_on")?,
+                "joined_on" => crate::deserialize_field(&mut map, &mut field_joined_on, "joined_on")?,
+                "suspended_on" => crate::deserialize_field(&mut map, &mut field_suspended_on, "suspended_on")?,
+                "persistent_id" => crate::deserialize_field(&mut map, &mut field_persistent_id, "persistent_id")?,
+                "is_directory_restricted" => crate::deserialize_field(&mut map, &mut field_is_directory_restricted, "is_directory_restricted")?,
+                "profile_photo_url" => crate::deserialize_field(&mut map, &mut field_profile_photo_url, "profile_photo_url")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -15248,22 +14091,9 @@ impl MembersAddArg {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "new_members" => {
-                    if field_new_members.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("new_members"));
-                    }
-                    field_new_members = Some(map.next_value()?);
-                }
-                "force_async" => {
-                    if field_force_async.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("force_async"));
-                    }
-                    field_force_async = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "new_members" => crate::deserialize_field(&mut map, &mut field_new_members, "new_members")?,
+                "force_async" => crate::deserialize_field(&mut map, &mut field_force_async, "force_async")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -15348,16 +14178,8 @@ impl MembersAddArgBase {
         let mut field_force_async = None;
         while let Some(key) = map.next_key::<&str>()? {
             match key {
-                "force_async" => {
-                    if field_force_async.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("force_async"));
-                    }
-                    field_force_async = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "force_async" => crate::deserialize_field(&mut map, &mut field_force_async, "force_async")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         let result = MembersAddArgBase {
@@ -15512,8 +14334,9 @@ pub enum MembersAddJobStatusV2Result {
     /// The asynchronous job returned an error. The string contains an error message.
     Failed(String),
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for MembersAddJobStatusV2Result {
@@ -15547,7 +14370,7 @@ impl<'de> ::serde::de::Deserialize<'de> for MembersAddJobStatusV2Result {
                             _ => return Err(de::Error::unknown_field(tag, VARIANTS))
                         }
                     }
-                    _ => MembersAddJobStatusV2Result::Other,
+                    _ => MembersAddJobStatusV2Result::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -15586,7 +14409,10 @@ impl ::serde::ser::Serialize for MembersAddJobStatusV2Result {
                 s.serialize_field("failed", x)?;
                 s.end()
             }
-            MembersAddJobStatusV2Result::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            MembersAddJobStatusV2Result::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -15688,8 +14514,9 @@ pub enum MembersAddLaunchV2Result {
     AsyncJobId(crate::types::dbx_async::AsyncJobId),
     Complete(Vec<MemberAddV2Result>),
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for MembersAddLaunchV2Result {
@@ -15722,7 +14549,7 @@ impl<'de> ::serde::de::Deserialize<'de> for MembersAddLaunchV2Result {
                             _ => return Err(de::Error::unknown_field(tag, VARIANTS))
                         }
                     }
-                    _ => MembersAddLaunchV2Result::Other,
+                    _ => MembersAddLaunchV2Result::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -15754,7 +14581,10 @@ impl ::serde::ser::Serialize for MembersAddLaunchV2Result {
                 s.serialize_field("complete", x)?;
                 s.end()
             }
-            MembersAddLaunchV2Result::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            MembersAddLaunchV2Result::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -15809,22 +14639,9 @@ impl MembersAddV2Arg {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "new_members" => {
-                    if field_new_members.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("new_members"));
-                    }
-                    field_new_members = Some(map.next_value()?);
-                }
-                "force_async" => {
-                    if field_force_async.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("force_async"));
-                    }
-                    field_force_async = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "new_members" => crate::deserialize_field(&mut map, &mut field_new_members, "new_members")?,
+                "force_async" => crate::deserialize_field(&mut map, &mut field_force_async, "force_async")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -15932,28 +14749,10 @@ impl MembersDataTransferArg {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "user" => {
-                    if field_user.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("user"));
-                    }
-                    field_user = Some(map.next_value()?);
-                }
-                "transfer_dest_id" => {
-                    if field_transfer_dest_id.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("transfer_dest_id"));
-                    }
-                    field_transfer_dest_id = Some(map.next_value()?);
-                }
-                "transfer_admin_id" => {
-                    if field_transfer_admin_id.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("transfer_admin_id"));
-                    }
-                    field_transfer_admin_id = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "user" => crate::deserialize_field(&mut map, &mut field_user, "user")?,
+                "transfer_dest_id" => crate::deserialize_field(&mut map, &mut field_transfer_dest_id, "transfer_dest_id")?,
+                "transfer_admin_id" => crate::deserialize_field(&mut map, &mut field_transfer_admin_id, "transfer_admin_id")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -16057,22 +14856,9 @@ impl MembersDeactivateArg {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "user" => {
-                    if field_user.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("user"));
-                    }
-                    field_user = Some(map.next_value()?);
-                }
-                "wipe_data" => {
-                    if field_wipe_data.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("wipe_data"));
-                    }
-                    field_wipe_data = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "user" => crate::deserialize_field(&mut map, &mut field_user, "user")?,
+                "wipe_data" => crate::deserialize_field(&mut map, &mut field_wipe_data, "wipe_data")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -16168,16 +14954,8 @@ impl MembersDeactivateBaseArg {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "user" => {
-                    if field_user.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("user"));
-                    }
-                    field_user = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "user" => crate::deserialize_field(&mut map, &mut field_user, "user")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -16236,8 +15014,9 @@ pub enum MembersDeactivateError {
     /// The user is not a member of the team.
     UserNotInTeam,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for MembersDeactivateError {
@@ -16258,7 +15037,7 @@ impl<'de> ::serde::de::Deserialize<'de> for MembersDeactivateError {
                 let value = match tag {
                     "user_not_found" => MembersDeactivateError::UserNotFound,
                     "user_not_in_team" => MembersDeactivateError::UserNotInTeam,
-                    _ => MembersDeactivateError::Other,
+                    _ => MembersDeactivateError::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -16288,7 +15067,10 @@ impl ::serde::ser::Serialize for MembersDeactivateError {
                 s.serialize_field(".tag", "user_not_in_team")?;
                 s.end()
             }
-            MembersDeactivateError::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            MembersDeactivateError::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -16346,16 +15128,8 @@ impl MembersDeleteProfilePhotoArg {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "user" => {
-                    if field_user.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("user"));
-                    }
-                    field_user = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "user" => crate::deserialize_field(&mut map, &mut field_user, "user")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -16416,8 +15190,9 @@ pub enum MembersDeleteProfilePhotoError {
     /// Modifying deleted users is not allowed.
     SetProfileDisallowed,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for MembersDeleteProfilePhotoError {
@@ -16439,7 +15214,7 @@ impl<'de> ::serde::de::Deserialize<'de> for MembersDeleteProfilePhotoError {
                     "user_not_found" => MembersDeleteProfilePhotoError::UserNotFound,
                     "user_not_in_team" => MembersDeleteProfilePhotoError::UserNotInTeam,
                     "set_profile_disallowed" => MembersDeleteProfilePhotoError::SetProfileDisallowed,
-                    _ => MembersDeleteProfilePhotoError::Other,
+                    _ => MembersDeleteProfilePhotoError::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -16476,7 +15251,10 @@ impl ::serde::ser::Serialize for MembersDeleteProfilePhotoError {
                 s.serialize_field(".tag", "set_profile_disallowed")?;
                 s.end()
             }
-            MembersDeleteProfilePhotoError::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            MembersDeleteProfilePhotoError::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -16538,16 +15316,8 @@ impl MembersGetAvailableTeamMemberRolesResult {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "roles" => {
-                    if field_roles.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("roles"));
-                    }
-                    field_roles = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "roles" => crate::deserialize_field(&mut map, &mut field_roles, "roles")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -16629,16 +15399,8 @@ impl MembersGetInfoArgs {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "members" => {
-                    if field_members.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("members"));
-                    }
-                    field_members = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "members" => crate::deserialize_field(&mut map, &mut field_members, "members")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -16693,8 +15455,9 @@ impl ::serde::ser::Serialize for MembersGetInfoArgs {
 #[non_exhaustive] // variants may be added in the future
 pub enum MembersGetInfoError {
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for MembersGetInfoError {
@@ -16713,9 +15476,7 @@ impl<'de> ::serde::de::Deserialize<'de> for MembersGetInfoError {
                     _ => return Err(de::Error::missing_field(".tag"))
                 };
                 // open enum with no defined variants
-                let _ = tag;
-                crate::eat_json_fields(&mut map)?;
-                Ok(MembersGetInfoError::Other)
+                Ok(MembersGetInfoError::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?))
             }
         }
         const VARIANTS: &[&str] = &["other"];
@@ -16726,8 +15487,9 @@ impl<'de> ::serde::de::Deserialize<'de> for MembersGetInfoError {
 impl ::serde::ser::Serialize for MembersGetInfoError {
     fn serialize<S: ::serde::ser::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
         // union serializer
-        #![allow(unused_variables)]
-        Err(::serde::ser::Error::custom("cannot serialize an open union with no defined variants"))
+        // catch-all
+        let MembersGetInfoError::Other(ref x) = *self;
+        ::serde::ser::Serialize::serialize(x, serializer)
     }
 }
 
@@ -16895,8 +15657,9 @@ pub enum MembersGetInfoItemV2 {
     /// Info about a team member.
     MemberInfo(TeamMemberInfoV2),
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for MembersGetInfoItemV2 {
@@ -16923,7 +15686,7 @@ impl<'de> ::serde::de::Deserialize<'de> for MembersGetInfoItemV2 {
                         }
                     }
                     "member_info" => MembersGetInfoItemV2::MemberInfo(TeamMemberInfoV2::internal_deserialize(&mut map)?),
-                    _ => MembersGetInfoItemV2::Other,
+                    _ => MembersGetInfoItemV2::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -16955,7 +15718,10 @@ impl ::serde::ser::Serialize for MembersGetInfoItemV2 {
                 x.internal_serialize::<S>(&mut s)?;
                 s.end()
             }
-            MembersGetInfoItemV2::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            MembersGetInfoItemV2::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -17000,16 +15766,8 @@ impl MembersGetInfoV2Arg {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "members" => {
-                    if field_members.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("members"));
-                    }
-                    field_members = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "members" => crate::deserialize_field(&mut map, &mut field_members, "members")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -17091,16 +15849,8 @@ impl MembersGetInfoV2Result {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "members_info" => {
-                    if field_members_info.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("members_info"));
-                    }
-                    field_members_info = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "members_info" => crate::deserialize_field(&mut map, &mut field_members_info, "members_info")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -17190,22 +15940,9 @@ impl MembersInfo {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "team_member_ids" => {
-                    if field_team_member_ids.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("team_member_ids"));
-                    }
-                    field_team_member_ids = Some(map.next_value()?);
-                }
-                "permanently_deleted_users" => {
-                    if field_permanently_deleted_users.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("permanently_deleted_users"));
-                    }
-                    field_permanently_deleted_users = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "team_member_ids" => crate::deserialize_field(&mut map, &mut field_team_member_ids, "team_member_ids")?,
+                "permanently_deleted_users" => crate::deserialize_field(&mut map, &mut field_permanently_deleted_users, "permanently_deleted_users")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -17298,22 +16035,9 @@ impl MembersListArg {
         let mut field_include_removed = None;
         while let Some(key) = map.next_key::<&str>()? {
             match key {
-                "limit" => {
-                    if field_limit.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("limit"));
-                    }
-                    field_limit = Some(map.next_value()?);
-                }
-                "include_removed" => {
-                    if field_include_removed.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("include_removed"));
-                    }
-                    field_include_removed = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "limit" => crate::deserialize_field(&mut map, &mut field_limit, "limit")?,
+                "include_removed" => crate::deserialize_field(&mut map, &mut field_include_removed, "include_removed")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         let result = MembersListArg {
@@ -17398,16 +16122,8 @@ impl MembersListContinueArg {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "cursor" => {
-                    if field_cursor.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("cursor"));
-                    }
-                    field_cursor = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "cursor" => crate::deserialize_field(&mut map, &mut field_cursor, "cursor")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -17463,8 +16179,9 @@ pub enum MembersListContinueError {
     /// The cursor is invalid.
     InvalidCursor,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for MembersListContinueError {
@@ -17484,7 +16201,7 @@ impl<'de> ::serde::de::Deserialize<'de> for MembersListContinueError {
                 };
                 let value = match tag {
                     "invalid_cursor" => MembersListContinueError::InvalidCursor,
-                    _ => MembersListContinueError::Other,
+                    _ => MembersListContinueError::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -17507,7 +16224,10 @@ impl ::serde::ser::Serialize for MembersListContinueError {
                 s.serialize_field(".tag", "invalid_cursor")?;
                 s.end()
             }
-            MembersListContinueError::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            MembersListContinueError::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -17529,8 +16249,9 @@ impl ::std::fmt::Display for MembersListContinueError {
 #[non_exhaustive] // variants may be added in the future
 pub enum MembersListError {
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for MembersListError {
@@ -17549,9 +16270,7 @@ impl<'de> ::serde::de::Deserialize<'de> for MembersListError {
                     _ => return Err(de::Error::missing_field(".tag"))
                 };
                 // open enum with no defined variants
-                let _ = tag;
-                crate::eat_json_fields(&mut map)?;
-                Ok(MembersListError::Other)
+                Ok(MembersListError::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?))
             }
         }
         const VARIANTS: &[&str] = &["other"];
@@ -17562,8 +16281,9 @@ impl<'de> ::serde::de::Deserialize<'de> for MembersListError {
 impl ::serde::ser::Serialize for MembersListError {
     fn serialize<S: ::serde::ser::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
         // union serializer
-        #![allow(unused_variables)]
-        Err(::serde::ser::Error::custom("cannot serialize an open union with no defined variants"))
+        // catch-all
+        let MembersListError::Other(ref x) = *self;
+        ::serde::ser::Serialize::serialize(x, serializer)
     }
 }
 
@@ -17620,28 +16340,10 @@ impl MembersListResult {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "members" => {
-                    if field_members.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("members"));
-                    }
-                    field_members = Some(map.next_value()?);
-                }
-                "cursor" => {
-                    if field_cursor.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("cursor"));
-                    }
-                    field_cursor = Some(map.next_value()?);
-                }
-                "has_more" => {
-                    if field_has_more.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("has_more"));
-                    }
-                    field_has_more = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "members" => crate::deserialize_field(&mut map, &mut field_members, "members")?,
+                "cursor" => crate::deserialize_field(&mut map, &mut field_cursor, "cursor")?,
+                "has_more" => crate::deserialize_field(&mut map, &mut field_has_more, "has_more")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -17740,28 +16442,10 @@ impl MembersListV2Result {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "members" => {
-                    if field_members.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("members"));
-                    }
-                    field_members = Some(map.next_value()?);
-                }
-                "cursor" => {
-                    if field_cursor.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("cursor"));
-                    }
-                    field_cursor = Some(map.next_value()?);
-                }
-                "has_more" => {
-                    if field_has_more.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("has_more"));
-                    }
-                    field_has_more = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "members" => crate::deserialize_field(&mut map, &mut field_members, "members")?,
+                "cursor" => crate::deserialize_field(&mut map, &mut field_cursor, "cursor")?,
+                "has_more" => crate::deserialize_field(&mut map, &mut field_has_more, "has_more")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -17849,16 +16533,8 @@ impl MembersRecoverArg {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "user" => {
-                    if field_user.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("user"));
-                    }
-                    field_user = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "user" => crate::deserialize_field(&mut map, &mut field_user, "user")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -17921,8 +16597,9 @@ pub enum MembersRecoverError {
     /// Team is full. The organization has no available licenses.
     TeamLicenseLimit,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for MembersRecoverError {
@@ -17945,7 +16622,7 @@ impl<'de> ::serde::de::Deserialize<'de> for MembersRecoverError {
                     "user_unrecoverable" => MembersRecoverError::UserUnrecoverable,
                     "user_not_in_team" => MembersRecoverError::UserNotInTeam,
                     "team_license_limit" => MembersRecoverError::TeamLicenseLimit,
-                    _ => MembersRecoverError::Other,
+                    _ => MembersRecoverError::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -17989,7 +16666,10 @@ impl ::serde::ser::Serialize for MembersRecoverError {
                 s.serialize_field(".tag", "team_license_limit")?;
                 s.end()
             }
-            MembersRecoverError::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            MembersRecoverError::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -18106,46 +16786,13 @@ impl MembersRemoveArg {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "user" => {
-                    if field_user.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("user"));
-                    }
-                    field_user = Some(map.next_value()?);
-                }
-                "wipe_data" => {
-                    if field_wipe_data.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("wipe_data"));
-                    }
-                    field_wipe_data = Some(map.next_value()?);
-                }
-                "transfer_dest_id" => {
-                    if field_transfer_dest_id.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("transfer_dest_id"));
-                    }
-                    field_transfer_dest_id = Some(map.next_value()?);
-                }
-                "transfer_admin_id" => {
-                    if field_transfer_admin_id.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("transfer_admin_id"));
-                    }
-                    field_transfer_admin_id = Some(map.next_value()?);
-                }
-                "keep_account" => {
-                    if field_keep_account.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("keep_account"));
-                    }
-                    field_keep_account = Some(map.next_value()?);
-                }
-                "retain_team_shares" => {
-                    if field_retain_team_shares.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("retain_team_shares"));
-                    }
-                    field_retain_team_shares = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "user" => crate::deserialize_field(&mut map, &mut field_user, "user")?,
+                "wipe_data" => crate::deserialize_field(&mut map, &mut field_wipe_data, "wipe_data")?,
+                "transfer_dest_id" => crate::deserialize_field(&mut map, &mut field_transfer_dest_id, "transfer_dest_id")?,
+                "transfer_admin_id" => crate::deserialize_field(&mut map, &mut field_transfer_admin_id, "transfer_admin_id")?,
+                "keep_account" => crate::deserialize_field(&mut map, &mut field_keep_account, "keep_account")?,
+                "retain_team_shares" => crate::deserialize_field(&mut map, &mut field_retain_team_shares, "retain_team_shares")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -18279,8 +16926,9 @@ pub enum MembersRemoveError {
     /// agree to the terms of service.
     CannotKeepAccountRequiredToSignTos,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for MembersRemoveError {
@@ -18321,7 +16969,7 @@ impl<'de> ::serde::de::Deserialize<'de> for MembersRemoveError {
                     "cannot_keep_account" => MembersRemoveError::CannotKeepAccount,
                     "cannot_keep_account_under_legal_hold" => MembersRemoveError::CannotKeepAccountUnderLegalHold,
                     "cannot_keep_account_required_to_sign_tos" => MembersRemoveError::CannotKeepAccountRequiredToSignTos,
-                    _ => MembersRemoveError::Other,
+                    _ => MembersRemoveError::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -18491,7 +17139,10 @@ impl ::serde::ser::Serialize for MembersRemoveError {
                 s.serialize_field(".tag", "cannot_keep_account_required_to_sign_tos")?;
                 s.end()
             }
-            MembersRemoveError::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            MembersRemoveError::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -18532,7 +17183,7 @@ impl From<MembersTransferFilesError> for MembersRemoveError {
         match parent {
             MembersTransferFilesError::UserNotFound => MembersRemoveError::UserNotFound,
             MembersTransferFilesError::UserNotInTeam => MembersRemoveError::UserNotInTeam,
-            MembersTransferFilesError::Other => MembersRemoveError::Other,
+            MembersTransferFilesError::Other(x) => MembersRemoveError::Other(x),
             MembersTransferFilesError::RemovedAndTransferDestShouldDiffer => MembersRemoveError::RemovedAndTransferDestShouldDiffer,
             MembersTransferFilesError::RemovedAndTransferAdminShouldDiffer => MembersRemoveError::RemovedAndTransferAdminShouldDiffer,
             MembersTransferFilesError::TransferDestUserNotFound => MembersRemoveError::TransferDestUserNotFound,
@@ -18555,8 +17206,9 @@ pub enum MembersSendWelcomeError {
     /// The user is not a member of the team.
     UserNotInTeam,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for MembersSendWelcomeError {
@@ -18577,7 +17229,7 @@ impl<'de> ::serde::de::Deserialize<'de> for MembersSendWelcomeError {
                 let value = match tag {
                     "user_not_found" => MembersSendWelcomeError::UserNotFound,
                     "user_not_in_team" => MembersSendWelcomeError::UserNotInTeam,
-                    _ => MembersSendWelcomeError::Other,
+                    _ => MembersSendWelcomeError::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -18607,7 +17259,10 @@ impl ::serde::ser::Serialize for MembersSendWelcomeError {
                 s.serialize_field(".tag", "user_not_in_team")?;
                 s.end()
             }
-            MembersSendWelcomeError::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            MembersSendWelcomeError::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -18679,22 +17334,9 @@ impl MembersSetPermissions2Arg {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "user" => {
-                    if field_user.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("user"));
-                    }
-                    field_user = Some(map.next_value()?);
-                }
-                "new_roles" => {
-                    if field_new_roles.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("new_roles"));
-                    }
-                    field_new_roles = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "user" => crate::deserialize_field(&mut map, &mut field_user, "user")?,
+                "new_roles" => crate::deserialize_field(&mut map, &mut field_new_roles, "new_roles")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -18763,8 +17405,9 @@ pub enum MembersSetPermissions2Error {
     /// No matching role found. At least one of the provided new_roles does not exist on this team.
     RoleNotFound,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for MembersSetPermissions2Error {
@@ -18788,7 +17431,7 @@ impl<'de> ::serde::de::Deserialize<'de> for MembersSetPermissions2Error {
                     "user_not_in_team" => MembersSetPermissions2Error::UserNotInTeam,
                     "cannot_set_permissions" => MembersSetPermissions2Error::CannotSetPermissions,
                     "role_not_found" => MembersSetPermissions2Error::RoleNotFound,
-                    _ => MembersSetPermissions2Error::Other,
+                    _ => MembersSetPermissions2Error::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -18839,7 +17482,10 @@ impl ::serde::ser::Serialize for MembersSetPermissions2Error {
                 s.serialize_field(".tag", "role_not_found")?;
                 s.end()
             }
-            MembersSetPermissions2Error::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            MembersSetPermissions2Error::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -18910,22 +17556,9 @@ impl MembersSetPermissions2Result {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "team_member_id" => {
-                    if field_team_member_id.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("team_member_id"));
-                    }
-                    field_team_member_id = Some(map.next_value()?);
-                }
-                "roles" => {
-                    if field_roles.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("roles"));
-                    }
-                    field_roles = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "team_member_id" => crate::deserialize_field(&mut map, &mut field_team_member_id, "team_member_id")?,
+                "roles" => crate::deserialize_field(&mut map, &mut field_roles, "roles")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -19018,22 +17651,9 @@ impl MembersSetPermissionsArg {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "user" => {
-                    if field_user.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("user"));
-                    }
-                    field_user = Some(map.next_value()?);
-                }
-                "new_role" => {
-                    if field_new_role.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("new_role"));
-                    }
-                    field_new_role = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "user" => crate::deserialize_field(&mut map, &mut field_user, "user")?,
+                "new_role" => crate::deserialize_field(&mut map, &mut field_new_role, "new_role")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -19100,8 +17720,9 @@ pub enum MembersSetPermissionsError {
     /// Team is full. The organization has no available licenses.
     TeamLicenseLimit,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for MembersSetPermissionsError {
@@ -19125,7 +17746,7 @@ impl<'de> ::serde::de::Deserialize<'de> for MembersSetPermissionsError {
                     "user_not_in_team" => MembersSetPermissionsError::UserNotInTeam,
                     "cannot_set_permissions" => MembersSetPermissionsError::CannotSetPermissions,
                     "team_license_limit" => MembersSetPermissionsError::TeamLicenseLimit,
-                    _ => MembersSetPermissionsError::Other,
+                    _ => MembersSetPermissionsError::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -19176,7 +17797,10 @@ impl ::serde::ser::Serialize for MembersSetPermissionsError {
                 s.serialize_field(".tag", "team_license_limit")?;
                 s.end()
             }
-            MembersSetPermissionsError::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            MembersSetPermissionsError::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -19242,22 +17866,9 @@ impl MembersSetPermissionsResult {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "team_member_id" => {
-                    if field_team_member_id.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("team_member_id"));
-                    }
-                    field_team_member_id = Some(map.next_value()?);
-                }
-                "role" => {
-                    if field_role.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("role"));
-                    }
-                    field_role = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "team_member_id" => crate::deserialize_field(&mut map, &mut field_team_member_id, "team_member_id")?,
+                "role" => crate::deserialize_field(&mut map, &mut field_role, "role")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -19408,52 +18019,14 @@ impl MembersSetProfileArg {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "user" => {
-                    if field_user.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("user"));
-                    }
-                    field_user = Some(map.next_value()?);
-                }
-                "new_email" => {
-                    if field_new_email.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("new_email"));
-                    }
-                    field_new_email = Some(map.next_value()?);
-                }
-                "new_external_id" => {
-                    if field_new_external_id.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("new_external_id"));
-                    }
-                    field_new_external_id = Some(map.next_value()?);
-                }
-                "new_given_name" => {
-                    if field_new_given_name.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("new_given_name"));
-                    }
-                    field_new_given_name = Some(map.next_value()?);
-                }
-                "new_surname" => {
-                    if field_new_surname.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("new_surname"));
-                    }
-                    field_new_surname = Some(map.next_value()?);
-                }
-                "new_persistent_id" => {
-                    if field_new_persistent_id.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("new_persistent_id"));
-                    }
-                    field_new_persistent_id = Some(map.next_value()?);
-                }
-                "new_is_directory_restricted" => {
-                    if field_new_is_directory_restricted.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("new_is_directory_restricted"));
-                    }
-                    field_new_is_directory_restricted = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "user" => crate::deserialize_field(&mut map, &mut field_user, "user")?,
+                "new_email" => crate::deserialize_field(&mut map, &mut field_new_email, "new_email")?,
+                "new_external_id" => crate::deserialize_field(&mut map, &mut field_new_external_id, "new_external_id")?,
+                "new_given_name" => crate::deserialize_field(&mut map, &mut field_new_given_name, "new_given_name")?,
+                "new_surname" => crate::deserialize_field(&mut map, &mut field_new_surname, "new_surname")?,
+                "new_persistent_id" => crate::deserialize_field(&mut map, &mut field_new_persistent_id, "new_persistent_id")?,
+                "new_is_directory_restricted" => crate::deserialize_field(&mut map, &mut field_new_is_directory_restricted, "new_is_directory_restricted")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -19555,8 +18128,9 @@ pub enum MembersSetProfileError {
     /// Directory Restrictions option is not available.
     DirectoryRestrictedOff,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for MembersSetProfileError {
@@ -19586,7 +18160,7 @@ impl<'de> ::serde::de::Deserialize<'de> for MembersSetProfileError {
                     "persistent_id_disabled" => MembersSetProfileError::PersistentIdDisabled,
                     "persistent_id_used_by_other_user" => MembersSetProfileError::PersistentIdUsedByOtherUser,
                     "directory_restricted_off" => MembersSetProfileError::DirectoryRestrictedOff,
-                    _ => MembersSetProfileError::Other,
+                    _ => MembersSetProfileError::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -19679,7 +18253,10 @@ impl ::serde::ser::Serialize for MembersSetProfileError {
                 s.serialize_field(".tag", "directory_restricted_off")?;
                 s.end()
             }
-            MembersSetProfileError::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            MembersSetProfileError::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -19752,22 +18329,9 @@ impl MembersSetProfilePhotoArg {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "user" => {
-                    if field_user.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("user"));
-                    }
-                    field_user = Some(map.next_value()?);
-                }
-                "photo" => {
-                    if field_photo.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("photo"));
-                    }
-                    field_photo = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "user" => crate::deserialize_field(&mut map, &mut field_user, "user")?,
+                "photo" => crate::deserialize_field(&mut map, &mut field_photo, "photo")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -19831,8 +18395,9 @@ pub enum MembersSetProfilePhotoError {
     SetProfileDisallowed,
     PhotoError(crate::types::account::SetProfilePhotoError),
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for MembersSetProfilePhotoError {
@@ -19861,7 +18426,7 @@ impl<'de> ::serde::de::Deserialize<'de> for MembersSetProfilePhotoError {
                             _ => return Err(de::Error::unknown_field(tag, VARIANTS))
                         }
                     }
-                    _ => MembersSetProfilePhotoError::Other,
+                    _ => MembersSetProfilePhotoError::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -19906,7 +18471,10 @@ impl ::serde::ser::Serialize for MembersSetProfilePhotoError {
                 s.serialize_field("photo_error", x)?;
                 s.end()
             }
-            MembersSetProfilePhotoError::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            MembersSetProfilePhotoError::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -19926,7 +18494,7 @@ impl ::std::fmt::Display for MembersSetProfilePhotoError {
             MembersSetProfilePhotoError::UserNotFound => f.write_str("No matching user found. The provided team_member_id, email, or external_id does not exist on this team."),
             MembersSetProfilePhotoError::UserNotInTeam => f.write_str("The user is not a member of the team."),
             MembersSetProfilePhotoError::SetProfileDisallowed => f.write_str("Modifying deleted users is not allowed."),
-            MembersSetProfilePhotoError::PhotoError(inner) => write!(f, "{}", inner),
+            MembersSetProfilePhotoError::PhotoError(inner) => write!(f, "photo_error: {}", inner),
             _ => write!(f, "{:?}", *self),
         }
     }
@@ -19956,8 +18524,9 @@ pub enum MembersSuspendError {
     /// Team is full. The organization has no available licenses.
     TeamLicenseLimit,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for MembersSuspendError {
@@ -19981,7 +18550,7 @@ impl<'de> ::serde::de::Deserialize<'de> for MembersSuspendError {
                     "suspend_inactive_user" => MembersSuspendError::SuspendInactiveUser,
                     "suspend_last_admin" => MembersSuspendError::SuspendLastAdmin,
                     "team_license_limit" => MembersSuspendError::TeamLicenseLimit,
-                    _ => MembersSuspendError::Other,
+                    _ => MembersSuspendError::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -20032,7 +18601,10 @@ impl ::serde::ser::Serialize for MembersSuspendError {
                 s.serialize_field(".tag", "team_license_limit")?;
                 s.end()
             }
-            MembersSuspendError::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            MembersSuspendError::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -20059,7 +18631,7 @@ impl From<MembersDeactivateError> for MembersSuspendError {
         match parent {
             MembersDeactivateError::UserNotFound => MembersSuspendError::UserNotFound,
             MembersDeactivateError::UserNotInTeam => MembersSuspendError::UserNotInTeam,
-            MembersDeactivateError::Other => MembersSuspendError::Other,
+            MembersDeactivateError::Other(x) => MembersSuspendError::Other(x),
         }
     }
 }
@@ -20090,8 +18662,9 @@ pub enum MembersTransferFilesError {
     /// The recipient user's email is not verified.
     RecipientNotVerified,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for MembersTransferFilesError {
@@ -20121,7 +18694,7 @@ impl<'de> ::serde::de::Deserialize<'de> for MembersTransferFilesError {
                     "unspecified_transfer_admin_id" => MembersTransferFilesError::UnspecifiedTransferAdminId,
                     "transfer_admin_is_not_admin" => MembersTransferFilesError::TransferAdminIsNotAdmin,
                     "recipient_not_verified" => MembersTransferFilesError::RecipientNotVerified,
-                    _ => MembersTransferFilesError::Other,
+                    _ => MembersTransferFilesError::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -20214,7 +18787,10 @@ impl ::serde::ser::Serialize for MembersTransferFilesError {
                 s.serialize_field(".tag", "recipient_not_verified")?;
                 s.end()
             }
-            MembersTransferFilesError::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            MembersTransferFilesError::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -20247,7 +18823,7 @@ impl From<MembersDeactivateError> for MembersTransferFilesError {
         match parent {
             MembersDeactivateError::UserNotFound => MembersTransferFilesError::UserNotFound,
             MembersDeactivateError::UserNotInTeam => MembersTransferFilesError::UserNotInTeam,
-            MembersDeactivateError::Other => MembersTransferFilesError::Other,
+            MembersDeactivateError::Other(x) => MembersTransferFilesError::Other(x),
         }
     }
 }
@@ -20286,8 +18862,9 @@ pub enum MembersTransferFormerMembersFilesError {
     /// User's data has already been transferred to another user.
     UserDataAlreadyTransferred,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for MembersTransferFormerMembersFilesError {
@@ -20321,7 +18898,7 @@ impl<'de> ::serde::de::Deserialize<'de> for MembersTransferFormerMembersFilesErr
                     "user_not_removed" => MembersTransferFormerMembersFilesError::UserNotRemoved,
                     "user_data_cannot_be_transferred" => MembersTransferFormerMembersFilesError::UserDataCannotBeTransferred,
                     "user_data_already_transferred" => MembersTransferFormerMembersFilesError::UserDataAlreadyTransferred,
-                    _ => MembersTransferFormerMembersFilesError::Other,
+                    _ => MembersTransferFormerMembersFilesError::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -20442,7 +19019,10 @@ impl ::serde::ser::Serialize for MembersTransferFormerMembersFilesError {
                 s.serialize_field(".tag", "user_data_already_transferred")?;
                 s.end()
             }
-            MembersTransferFormerMembersFilesError::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            MembersTransferFormerMembersFilesError::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -20479,7 +19059,7 @@ impl From<MembersTransferFilesError> for MembersTransferFormerMembersFilesError
         match parent {
             MembersTransferFilesError::UserNotFound => MembersTransferFormerMembersFilesError::UserNotFound,
             MembersTransferFilesError::UserNotInTeam => MembersTransferFormerMembersFilesError::UserNotInTeam,
-            MembersTransferFilesError::Other => MembersTransferFormerMembersFilesError::Other,
+            MembersTransferFilesError::Other(x) => MembersTransferFormerMembersFilesError::Other(x),
             MembersTransferFilesError::RemovedAndTransferDestShouldDiffer => MembersTransferFormerMembersFilesError::RemovedAndTransferDestShouldDiffer,
             MembersTransferFilesError::RemovedAndTransferAdminShouldDiffer => MembersTransferFormerMembersFilesError::RemovedAndTransferAdminShouldDiffer,
             MembersTransferFilesError::TransferDestUserNotFound => MembersTransferFormerMembersFilesError::TransferDestUserNotFound,
@@ -20526,16 +19106,8 @@ impl MembersUnsuspendArg {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "user" => {
-                    if field_user.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("user"));
-                    }
-                    field_user = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "user" => crate::deserialize_field(&mut map, &mut field_user, "user")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -20598,8 +19170,9 @@ pub enum MembersUnsuspendError {
     /// Team is full. The organization has no available licenses.
     TeamLicenseLimit,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for MembersUnsuspendError {
@@ -20622,7 +19195,7 @@ impl<'de> ::serde::de::Deserialize<'de> for MembersUnsuspendError {
                     "user_not_in_team" => MembersUnsuspendError::UserNotInTeam,
                     "unsuspend_non_suspended_member" => MembersUnsuspendError::UnsuspendNonSuspendedMember,
                     "team_license_limit" => MembersUnsuspendError::TeamLicenseLimit,
-                    _ => MembersUnsuspendError::Other,
+                    _ => MembersUnsuspendError::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -20666,7 +19239,10 @@ impl ::serde::ser::Serialize for MembersUnsuspendError {
                 s.serialize_field(".tag", "team_license_limit")?;
                 s.end()
             }
-            MembersUnsuspendError::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            MembersUnsuspendError::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -20692,7 +19268,7 @@ impl From<MembersDeactivateError> for MembersUnsuspendError {
         match parent {
             MembersDeactivateError::UserNotFound => MembersUnsuspendError::UserNotFound,
             MembersDeactivateError::UserNotInTeam => MembersUnsuspendError::UserNotInTeam,
-            MembersDeactivateError::Other => MembersUnsuspendError::Other,
+            MembersDeactivateError::Other(x) => MembersUnsuspendError::Other(x),
         }
     }
 }
@@ -20710,8 +19286,9 @@ pub enum MobileClientPlatform {
     /// Official Dropbox Blackberry client.
     Blackberry,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for MobileClientPlatform {
@@ -20735,7 +19312,7 @@ impl<'de> ::serde::de::Deserialize<'de> for MobileClientPlatform {
                     "android" => MobileClientPlatform::Android,
                     "windows_phone" => MobileClientPlatform::WindowsPhone,
                     "blackberry" => MobileClientPlatform::Blackberry,
-                    _ => MobileClientPlatform::Other,
+                    _ => MobileClientPlatform::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -20786,7 +19363,10 @@ impl ::serde::ser::Serialize for MobileClientPlatform {
                 s.serialize_field(".tag", "blackberry")?;
                 s.end()
             }
-            MobileClientPlatform::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            MobileClientPlatform::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -20908,70 +19488,17 @@ impl MobileClientSession {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "session_id" => {
-                    if field_session_id.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("session_id"));
-                    }
-                    field_session_id = Some(map.next_value()?);
-                }
-                "device_name" => {
-                    if field_device_name.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("device_name"));
-                    }
-                    field_device_name = Some(map.next_value()?);
-                }
-                "client_type" => {
-                    if field_client_type.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("client_type"));
-                    }
-                    field_client_type = Some(map.next_value()?);
-                }
-                "ip_address" => {
-                    if field_ip_address.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("ip_address"));
-                    }
-                    field_ip_address = Some(map.next_value()?);
-                }
-                "country" => {
-                    if field_country.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("country"));
-                    }
-                    field_country = Some(map.next_value()?);
-                }
-                "created" => {
-                    if field_created.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("created"));
-                    }
-                    field_created = Some(map.next_value()?);
-                }
-                "updated" => {
-                    if field_updated.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("updated"));
-                    }
-                    field_updated = Some(map.next_value()?);
-                }
-                "client_version" => {
-                    if field_client_version.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("client_version"));
-                    }
-                    field_client_version = Some(map.next_value()?);
-                }
-                "os_version" => {
-                    if field_os_version.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("os_version"));
-                    }
-                    field_os_version = Some(map.next_value()?);
-                }
-                "last_carrier" => {
-                    if field_last_carrier.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("last_carrier"));
-                    }
-                    field_last_carrier = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "session_id" => crate::deserialize_field(&mut map, &mut field_session_id, "session_id")?,
+                "device_name" => crate::deserialize_field(&mut map, &mut field_device_name, "device_name")?,
+                "client_type" => crate::deserialize_field(&mut map, &mut field_client_type, "client_type")?,
+                "ip_address" => crate::deserialize_field(&mut map, &mut field_ip_address, "ip_address")?,
+                "country" => crate::deserialize_field(&mut map, &mut field_country, "country")?,
+                "created" => crate::deserialize_field(&mut map, &mut field_created, "created")?,
+                "updated" => crate::deserialize_field(&mut map, &mut field_updated, "updated")?,
+                "client_version" => crate::deserialize_field(&mut map, &mut field_client_version, "client_version")?,
+                "os_version" => crate::deserialize_field(&mut map, &mut field_os_version, "os_version")?,
+                "last_carrier" => crate::deserialize_field(&mut map, &mut field_last_carrier, "last_carrier")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -21123,34 +19650,11 @@ impl NamespaceMetadata {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "name" => {
-                    if field_name.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("name"));
-                    }
-                    field_name = Some(map.next_value()?);
-                }
-                "namespace_id" => {
-                    if field_namespace_id.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("namespace_id"));
-                    }
-                    field_namespace_id = Some(map.next_value()?);
-                }
-                "namespace_type" => {
-                    if field_namespace_type.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("namespace_type"));
-                    }
-                    field_namespace_type = Some(map.next_value()?);
-                }
-                "team_member_id" => {
-                    if field_team_member_id.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("team_member_id"));
-                    }
-                    field_team_member_id = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "name" => crate::deserialize_field(&mut map, &mut field_name, "name")?,
+                "namespace_id" => crate::deserialize_field(&mut map, &mut field_namespace_id, "namespace_id")?,
+                "namespace_type" => crate::deserialize_field(&mut map, &mut field_namespace_type, "namespace_type")?,
+                "team_member_id" => crate::deserialize_field(&mut map, &mut field_team_member_id, "team_member_id")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -21220,8 +19724,9 @@ pub enum NamespaceType {
     /// Team member's home folder.
     TeamMemberFolder,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for NamespaceType {
@@ -21244,7 +19749,7 @@ impl<'de> ::serde::de::Deserialize<'de> for NamespaceType {
                     "shared_folder" => NamespaceType::SharedFolder,
                     "team_folder" => NamespaceType::TeamFolder,
                     "team_member_folder" => NamespaceType::TeamMemberFolder,
-                    _ => NamespaceType::Other,
+                    _ => NamespaceType::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -21288,7 +19793,10 @@ impl ::serde::ser::Serialize for NamespaceType {
                 s.serialize_field(".tag", "team_member_folder")?;
                 s.end()
             }
-            NamespaceType::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            NamespaceType::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -21302,8 +19810,9 @@ pub enum RemoveCustomQuotaResult {
     /// Invalid user (not in team).
     InvalidUser(UserSelectorArg),
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for RemoveCustomQuotaResult {
@@ -21336,7 +19845,7 @@ impl<'de> ::serde::de::Deserialize<'de> for RemoveCustomQuotaResult {
                             _ => return Err(de::Error::unknown_field(tag, VARIANTS))
                         }
                     }
-                    _ => RemoveCustomQuotaResult::Other,
+                    _ => RemoveCustomQuotaResult::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -21368,7 +19877,10 @@ impl ::serde::ser::Serialize for RemoveCustomQuotaResult {
                 s.serialize_field("invalid_user", x)?;
                 s.end()
             }
-            RemoveCustomQuotaResult::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            RemoveCustomQuotaResult::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -21410,22 +19922,9 @@ impl RemovedStatus {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "is_recoverable" => {
-                    if field_is_recoverable.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("is_recoverable"));
-                    }
-                    field_is_recoverable = Some(map.next_value()?);
-                }
-                "is_disconnected" => {
-                    if field_is_disconnected.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("is_disconnected"));
-                    }
-                    field_is_disconnected = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "is_recoverable" => crate::deserialize_field(&mut map, &mut field_is_recoverable, "is_recoverable")?,
+                "is_disconnected" => crate::deserialize_field(&mut map, &mut field_is_disconnected, "is_disconnected")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -21490,8 +19989,9 @@ pub enum ResendSecondaryEmailResult {
     /// Too many emails are being sent to this email address. Please try again later.
     RateLimited(crate::types::common::EmailAddress),
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for ResendSecondaryEmailResult {
@@ -21531,7 +20031,7 @@ impl<'de> ::serde::de::Deserialize<'de> for ResendSecondaryEmailResult {
                             _ => return Err(de::Error::unknown_field(tag, VARIANTS))
                         }
                     }
-                    _ => ResendSecondaryEmailResult::Other,
+                    _ => ResendSecondaryEmailResult::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -21571,7 +20071,10 @@ impl ::serde::ser::Serialize for ResendSecondaryEmailResult {
                 s.serialize_field("rate_limited", x)?;
                 s.end()
             }
-            ResendSecondaryEmailResult::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            ResendSecondaryEmailResult::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -21608,16 +20111,8 @@ impl ResendVerificationEmailArg {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "emails_to_resend" => {
-                    if field_emails_to_resend.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("emails_to_resend"));
-                    }
-                    field_emails_to_resend = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "emails_to_resend" => crate::deserialize_field(&mut map, &mut field_emails_to_resend, "emails_to_resend")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -21699,16 +20194,8 @@ impl ResendVerificationEmailResult {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "results" => {
-                    if field_results.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("results"));
-                    }
-                    field_results = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "results" => crate::deserialize_field(&mut map, &mut field_results, "results")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -21806,28 +20293,10 @@ impl RevokeDesktopClientArg {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "session_id" => {
-                    if field_session_id.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("session_id"));
-                    }
-                    field_session_id = Some(map.next_value()?);
-                }
-                "team_member_id" => {
-                    if field_team_member_id.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("team_member_id"));
-                    }
-                    field_team_member_id = Some(map.next_value()?);
-                }
-                "delete_on_unlink" => {
-                    if field_delete_on_unlink.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("delete_on_unlink"));
-                    }
-                    field_delete_on_unlink = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "session_id" => crate::deserialize_field(&mut map, &mut field_session_id, "session_id")?,
+                "team_member_id" => crate::deserialize_field(&mut map, &mut field_team_member_id, "team_member_id")?,
+                "delete_on_unlink" => crate::deserialize_field(&mut map, &mut field_delete_on_unlink, "delete_on_unlink")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -21995,16 +20464,8 @@ impl RevokeDeviceSessionBatchArg {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "revoke_devices" => {
-                    if field_revoke_devices.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("revoke_devices"));
-                    }
-                    field_revoke_devices = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "revoke_devices" => crate::deserialize_field(&mut map, &mut field_revoke_devices, "revoke_devices")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -22059,8 +20520,9 @@ impl ::serde::ser::Serialize for RevokeDeviceSessionBatchArg {
 #[non_exhaustive] // variants may be added in the future
 pub enum RevokeDeviceSessionBatchError {
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for RevokeDeviceSessionBatchError {
@@ -22079,9 +20541,7 @@ impl<'de> ::serde::de::Deserialize<'de> for RevokeDeviceSessionBatchError {
                     _ => return Err(de::Error::missing_field(".tag"))
                 };
                 // open enum with no defined variants
-                let _ = tag;
-                crate::eat_json_fields(&mut map)?;
-                Ok(RevokeDeviceSessionBatchError::Other)
+                Ok(RevokeDeviceSessionBatchError::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?))
             }
         }
         const VARIANTS: &[&str] = &["other"];
@@ -22092,8 +20552,9 @@ impl<'de> ::serde::de::Deserialize<'de> for RevokeDeviceSessionBatchError {
 impl ::serde::ser::Serialize for RevokeDeviceSessionBatchError {
     fn serialize<S: ::serde::ser::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
         // union serializer
-        #![allow(unused_variables)]
-        Err(::serde::ser::Error::custom("cannot serialize an open union with no defined variants"))
+        // catch-all
+        let RevokeDeviceSessionBatchError::Other(ref x) = *self;
+        ::serde::ser::Serialize::serialize(x, serializer)
     }
 }
 
@@ -22137,16 +20598,8 @@ impl RevokeDeviceSessionBatchResult {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "revoke_devices_status" => {
-                    if field_revoke_devices_status.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("revoke_devices_status"));
-                    }
-                    field_revoke_devices_status = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "revoke_devices_status" => crate::deserialize_field(&mut map, &mut field_revoke_devices_status, "revoke_devices_status")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -22204,8 +20657,9 @@ pub enum RevokeDeviceSessionError {
     /// Member not found.
     MemberNotFound,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for RevokeDeviceSessionError {
@@ -22226,7 +20680,7 @@ impl<'de> ::serde::de::Deserialize<'de> for RevokeDeviceSessionError {
                 let value = match tag {
                     "device_session_not_found" => RevokeDeviceSessionError::DeviceSessionNotFound,
                     "member_not_found" => RevokeDeviceSessionError::MemberNotFound,
-                    _ => RevokeDeviceSessionError::Other,
+                    _ => RevokeDeviceSessionError::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -22256,7 +20710,10 @@ impl ::serde::ser::Serialize for RevokeDeviceSessionError {
                 s.serialize_field(".tag", "member_not_found")?;
                 s.end()
             }
-            RevokeDeviceSessionError::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            RevokeDeviceSessionError::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -22316,22 +20773,9 @@ impl RevokeDeviceSessionStatus {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "success" => {
-                    if field_success.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("success"));
-                    }
-                    field_success = Some(map.next_value()?);
-                }
-                "error_type" => {
-                    if field_error_type.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("error_type"));
-                    }
-                    field_error_type = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "success" => crate::deserialize_field(&mut map, &mut field_success, "success")?,
+                "error_type" => crate::deserialize_field(&mut map, &mut field_error_type, "error_type")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -22433,28 +20877,10 @@ impl RevokeLinkedApiAppArg {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "app_id" => {
-                    if field_app_id.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("app_id"));
-                    }
-                    field_app_id = Some(map.next_value()?);
-                }
-                "team_member_id" => {
-                    if field_team_member_id.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("team_member_id"));
-                    }
-                    field_team_member_id = Some(map.next_value()?);
-                }
-                "keep_app_folder" => {
-                    if field_keep_app_folder.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("keep_app_folder"));
-                    }
-                    field_keep_app_folder = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "app_id" => crate::deserialize_field(&mut map, &mut field_app_id, "app_id")?,
+                "team_member_id" => crate::deserialize_field(&mut map, &mut field_team_member_id, "team_member_id")?,
+                "keep_app_folder" => crate::deserialize_field(&mut map, &mut field_keep_app_folder, "keep_app_folder")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -22541,16 +20967,8 @@ impl RevokeLinkedApiAppBatchArg {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "revoke_linked_app" => {
-                    if field_revoke_linked_app.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("revoke_linked_app"));
-                    }
-                    field_revoke_linked_app = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "revoke_linked_app" => crate::deserialize_field(&mut map, &mut field_revoke_linked_app, "revoke_linked_app")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -22606,8 +21024,9 @@ impl ::serde::ser::Serialize for RevokeLinkedApiAppBatchArg {
 #[non_exhaustive] // variants may be added in the future
 pub enum RevokeLinkedAppBatchError {
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for RevokeLinkedAppBatchError {
@@ -22626,9 +21045,7 @@ impl<'de> ::serde::de::Deserialize<'de> for RevokeLinkedAppBatchError {
                     _ => return Err(de::Error::missing_field(".tag"))
                 };
                 // open enum with no defined variants
-                let _ = tag;
-                crate::eat_json_fields(&mut map)?;
-                Ok(RevokeLinkedAppBatchError::Other)
+                Ok(RevokeLinkedAppBatchError::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?))
             }
         }
         const VARIANTS: &[&str] = &["other"];
@@ -22639,8 +21056,9 @@ impl<'de> ::serde::de::Deserialize<'de> for RevokeLinkedAppBatchError {
 impl ::serde::ser::Serialize for RevokeLinkedAppBatchError {
     fn serialize<S: ::serde::ser::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
         // union serializer
-        #![allow(unused_variables)]
-        Err(::serde::ser::Error::custom("cannot serialize an open union with no defined variants"))
+        // catch-all
+        let RevokeLinkedAppBatchError::Other(ref x) = *self;
+        ::serde::ser::Serialize::serialize(x, serializer)
     }
 }
 
@@ -22684,16 +21102,8 @@ impl RevokeLinkedAppBatchResult {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "revoke_linked_app_status" => {
-                    if field_revoke_linked_app_status.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("revoke_linked_app_status"));
-                    }
-                    field_revoke_linked_app_status = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "revoke_linked_app_status" => crate::deserialize_field(&mut map, &mut field_revoke_linked_app_status, "revoke_linked_app_status")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -22755,8 +21165,9 @@ pub enum RevokeLinkedAppError {
     /// App folder removal is not supported.
     AppFolderRemovalNotSupported,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for RevokeLinkedAppError {
@@ -22778,7 +21189,7 @@ impl<'de> ::serde::de::Deserialize<'de> for RevokeLinkedAppError {
                     "app_not_found" => RevokeLinkedAppError::AppNotFound,
                     "member_not_found" => RevokeLinkedAppError::MemberNotFound,
                     "app_folder_removal_not_supported" => RevokeLinkedAppError::AppFolderRemovalNotSupported,
-                    _ => RevokeLinkedAppError::Other,
+                    _ => RevokeLinkedAppError::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -22815,7 +21226,10 @@ impl ::serde::ser::Serialize for RevokeLinkedAppError {
                 s.serialize_field(".tag", "app_folder_removal_not_supported")?;
                 s.end()
             }
-            RevokeLinkedAppError::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            RevokeLinkedAppError::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -22876,22 +21290,9 @@ impl RevokeLinkedAppStatus {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "success" => {
-                    if field_success.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("success"));
-                    }
-                    field_success = Some(map.next_value()?);
-                }
-                "error_type" => {
-                    if field_error_type.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("error_type"));
-                    }
-                    field_error_type = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "success" => crate::deserialize_field(&mut map, &mut field_success, "success")?,
+                "error_type" => crate::deserialize_field(&mut map, &mut field_error_type, "error_type")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -22977,16 +21378,8 @@ impl SetCustomQuotaArg {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "users_and_quotas" => {
-                    if field_users_and_quotas.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("users_and_quotas"));
-                    }
-                    field_users_and_quotas = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "users_and_quotas" => crate::deserialize_field(&mut map, &mut field_users_and_quotas, "users_and_quotas")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -23045,8 +21438,9 @@ pub enum SetCustomQuotaError {
     /// Some of the users are on the excluded users list and can't have custom quota set.
     SomeUsersAreExcluded,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for SetCustomQuotaError {
@@ -23067,7 +21461,7 @@ impl<'de> ::serde::de::Deserialize<'de> for SetCustomQuotaError {
                 let value = match tag {
                     "too_many_users" => SetCustomQuotaError::TooManyUsers,
                     "some_users_are_excluded" => SetCustomQuotaError::SomeUsersAreExcluded,
-                    _ => SetCustomQuotaError::Other,
+                    _ => SetCustomQuotaError::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -23097,7 +21491,10 @@ impl ::serde::ser::Serialize for SetCustomQuotaError {
                 s.serialize_field(".tag", "some_users_are_excluded")?;
                 s.end()
             }
-            SetCustomQuotaError::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            SetCustomQuotaError::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -23120,7 +21517,7 @@ impl From<CustomQuotaError> for SetCustomQuotaError {
     fn from(parent: CustomQuotaError) -> Self {
         match parent {
             CustomQuotaError::TooManyUsers => SetCustomQuotaError::TooManyUsers,
-            CustomQuotaError::Other => SetCustomQuotaError::Other,
+            CustomQuotaError::Other(x) => SetCustomQuotaError::Other(x),
         }
     }
 }
@@ -23158,22 +21555,9 @@ impl SharingAllowlistAddArgs {
         let mut field_emails = None;
         while let Some(key) = map.next_key::<&str>()? {
             match key {
-                "domains" => {
-                    if field_domains.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("domains"));
-                    }
-                    field_domains = Some(map.next_value()?);
-                }
-                "emails" => {
-                    if field_emails.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("emails"));
-                    }
-                    field_emails = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "domains" => crate::deserialize_field(&mut map, &mut field_domains, "domains")?,
+                "emails" => crate::deserialize_field(&mut map, &mut field_emails, "emails")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         let result = SharingAllowlistAddArgs {
@@ -23242,8 +21626,9 @@ pub enum SharingAllowlistAddError {
     /// Entries already exists.
     EntriesAlreadyExist(String),
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for SharingAllowlistAddError {
@@ -23280,7 +21665,7 @@ impl<'de> ::serde::de::Deserialize<'de> for SharingAllowlistAddError {
                             _ => return Err(de::Error::unknown_field(tag, VARIANTS))
                         }
                     }
-                    _ => SharingAllowlistAddError::Other,
+                    _ => SharingAllowlistAddError::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -23340,7 +21725,10 @@ impl ::serde::ser::Serialize for SharingAllowlistAddError {
                 s.serialize_field("entries_already_exist", x)?;
                 s.end()
             }
-            SharingAllowlistAddError::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            SharingAllowlistAddError::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -23438,16 +21826,8 @@ impl SharingAllowlistListArg {
         let mut field_limit = None;
         while let Some(key) = map.next_key::<&str>()? {
             match key {
-                "limit" => {
-                    if field_limit.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("limit"));
-                    }
-                    field_limit = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "limit" => crate::deserialize_field(&mut map, &mut field_limit, "limit")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         let result = SharingAllowlistListArg {
@@ -23530,16 +21910,8 @@ impl SharingAllowlistListContinueArg {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "cursor" => {
-                    if field_cursor.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("cursor"));
-                    }
-                    field_cursor = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "cursor" => crate::deserialize_field(&mut map, &mut field_cursor, "cursor")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -23595,8 +21967,9 @@ pub enum SharingAllowlistListContinueError {
     /// Provided cursor is not valid.
     InvalidCursor,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for SharingAllowlistListContinueError {
@@ -23616,7 +21989,7 @@ impl<'de> ::serde::de::Deserialize<'de> for SharingAllowlistListContinueError {
                 };
                 let value = match tag {
                     "invalid_cursor" => SharingAllowlistListContinueError::InvalidCursor,
-                    _ => SharingAllowlistListContinueError::Other,
+                    _ => SharingAllowlistListContinueError::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -23639,7 +22012,10 @@ impl ::serde::ser::Serialize for SharingAllowlistListContinueError {
                 s.serialize_field(".tag", "invalid_cursor")?;
                 s.end()
             }
-            SharingAllowlistListContinueError::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            SharingAllowlistListContinueError::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -23769,34 +22145,11 @@ impl SharingAllowlistListResponse {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "domains" => {
-                    if field_domains.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("domains"));
-                    }
-                    field_domains = Some(map.next_value()?);
-                }
-                "emails" => {
-                    if field_emails.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("emails"));
-                    }
-                    field_emails = Some(map.next_value()?);
-                }
-                "cursor" => {
-                    if field_cursor.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("cursor"));
-                    }
-                    field_cursor = Some(map.next_value()?);
-                }
-                "has_more" => {
-                    if field_has_more.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("has_more"));
-                    }
-                    field_has_more = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "domains" => crate::deserialize_field(&mut map, &mut field_domains, "domains")?,
+                "emails" => crate::deserialize_field(&mut map, &mut field_emails, "emails")?,
+                "cursor" => crate::deserialize_field(&mut map, &mut field_cursor, "cursor")?,
+                "has_more" => crate::deserialize_field(&mut map, &mut field_has_more, "has_more")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -23888,22 +22241,9 @@ impl SharingAllowlistRemoveArgs {
         let mut field_emails = None;
         while let Some(key) = map.next_key::<&str>()? {
             match key {
-                "domains" => {
-                    if field_domains.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("domains"));
-                    }
-                    field_domains = Some(map.next_value()?);
-                }
-                "emails" => {
-                    if field_emails.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("emails"));
-                    }
-                    field_emails = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "domains" => crate::deserialize_field(&mut map, &mut field_domains, "domains")?,
+                "emails" => crate::deserialize_field(&mut map, &mut field_emails, "emails")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         let result = SharingAllowlistRemoveArgs {
@@ -23970,8 +22310,9 @@ pub enum SharingAllowlistRemoveError {
     /// Unknown error.
     UnknownError,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for SharingAllowlistRemoveError {
@@ -24007,7 +22348,7 @@ impl<'de> ::serde::de::Deserialize<'de> for SharingAllowlistRemoveError {
                     "no_entries_provided" => SharingAllowlistRemoveError::NoEntriesProvided,
                     "too_many_entries_provided" => SharingAllowlistRemoveError::TooManyEntriesProvided,
                     "unknown_error" => SharingAllowlistRemoveError::UnknownError,
-                    _ => SharingAllowlistRemoveError::Other,
+                    _ => SharingAllowlistRemoveError::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -24060,7 +22401,10 @@ impl ::serde::ser::Serialize for SharingAllowlistRemoveError {
                 s.serialize_field(".tag", "unknown_error")?;
                 s.end()
             }
-            SharingAllowlistRemoveError::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            SharingAllowlistRemoveError::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -24165,22 +22509,9 @@ impl StorageBucket {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "bucket" => {
-                    if field_bucket.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("bucket"));
-                    }
-                    field_bucket = Some(map.next_value()?);
-                }
-                "users" => {
-                    if field_users.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("users"));
-                    }
-                    field_users = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "bucket" => crate::deserialize_field(&mut map, &mut field_bucket, "bucket")?,
+                "users" => crate::deserialize_field(&mut map, &mut field_users, "users")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -24240,8 +22571,9 @@ pub enum TeamFolderAccessError {
     /// The authenticated app does not have permission to manage that team folder.
     NoAccess,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for TeamFolderAccessError {
@@ -24262,7 +22594,7 @@ impl<'de> ::serde::de::Deserialize<'de> for TeamFolderAccessError {
                 let value = match tag {
                     "invalid_team_folder_id" => TeamFolderAccessError::InvalidTeamFolderId,
                     "no_access" => TeamFolderAccessError::NoAccess,
-                    _ => TeamFolderAccessError::Other,
+                    _ => TeamFolderAccessError::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -24292,7 +22624,10 @@ impl ::serde::ser::Serialize for TeamFolderAccessError {
                 s.serialize_field(".tag", "no_access")?;
                 s.end()
             }
-            TeamFolderAccessError::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            TeamFolderAccessError::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -24318,8 +22653,9 @@ pub enum TeamFolderActivateError {
     StatusError(TeamFolderInvalidStatusError),
     TeamSharedDropboxError(TeamFolderTeamSharedDropboxError),
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for TeamFolderActivateError {
@@ -24359,7 +22695,7 @@ impl<'de> ::serde::de::Deserialize<'de> for TeamFolderActivateError {
                             _ => return Err(de::Error::unknown_field(tag, VARIANTS))
                         }
                     }
-                    _ => TeamFolderActivateError::Other,
+                    _ => TeamFolderActivateError::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -24399,7 +22735,10 @@ impl ::serde::ser::Serialize for TeamFolderActivateError {
                 s.serialize_field("team_shared_dropbox_error", x)?;
                 s.end()
             }
-            TeamFolderActivateError::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            TeamFolderActivateError::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -24418,9 +22757,9 @@ impl ::std::error::Error for TeamFolderActivateError {
 impl ::std::fmt::Display for TeamFolderActivateError {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         match self {
-            TeamFolderActivateError::AccessError(inner) => write!(f, "{}", inner),
-            TeamFolderActivateError::StatusError(inner) => write!(f, "{}", inner),
-            TeamFolderActivateError::TeamSharedDropboxError(inner) => write!(f, "{}", inner),
+            TeamFolderActivateError::AccessError(inner) => write!(f, "access_error: {}", inner),
+            TeamFolderActivateError::StatusError(inner) => write!(f, "status_error: {}", inner),
+            TeamFolderActivateError::TeamSharedDropboxError(inner) => write!(f, "team_shared_dropbox_error: {}", inner),
             _ => write!(f, "{:?}", *self),
         }
     }
@@ -24433,7 +22772,7 @@ impl From<BaseTeamFolderError> for TeamFolderActivateError {
             BaseTeamFolderError::AccessError(x) => TeamFolderActivateError::AccessError(x),
             BaseTeamFolderError::StatusError(x) => TeamFolderActivateError::StatusError(x),
             BaseTeamFolderError::TeamSharedDropboxError(x) => TeamFolderActivateError::TeamSharedDropboxError(x),
-            BaseTeamFolderError::Other => TeamFolderActivateError::Other,
+            BaseTeamFolderError::Other(x) => TeamFolderActivateError::Other(x),
         }
     }
 }
@@ -24479,22 +22818,9 @@ impl TeamFolderArchiveArg {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "team_folder_id" => {
-                    if field_team_folder_id.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("team_folder_id"));
-                    }
-                    field_team_folder_id = Some(map.next_value()?);
-                }
-                "force_async_off" => {
-                    if field_force_async_off.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("force_async_off"));
-                    }
-                    field_force_async_off = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "team_folder_id" => crate::deserialize_field(&mut map, &mut field_team_folder_id, "team_folder_id")?,
+                "force_async_off" => crate::deserialize_field(&mut map, &mut field_force_async_off, "force_async_off")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -24564,8 +22890,9 @@ pub enum TeamFolderArchiveError {
     StatusError(TeamFolderInvalidStatusError),
     TeamSharedDropboxError(TeamFolderTeamSharedDropboxError),
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for TeamFolderArchiveError {
@@ -24605,7 +22932,7 @@ impl<'de> ::serde::de::Deserialize<'de> for TeamFolderArchiveError {
                             _ => return Err(de::Error::unknown_field(tag, VARIANTS))
                         }
                     }
-                    _ => TeamFolderArchiveError::Other,
+                    _ => TeamFolderArchiveError::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -24645,7 +22972,10 @@ impl ::serde::ser::Serialize for TeamFolderArchiveError {
                 s.serialize_field("team_shared_dropbox_error", x)?;
                 s.end()
             }
-            TeamFolderArchiveError::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            TeamFolderArchiveError::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -24664,9 +22994,9 @@ impl ::std::error::Error for TeamFolderArchiveError {
 impl ::std::fmt::Display for TeamFolderArchiveError {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         match self {
-            TeamFolderArchiveError::AccessError(inner) => write!(f, "{}", inner),
-            TeamFolderArchiveError::StatusError(inner) => write!(f, "{}", inner),
-            TeamFolderArchiveError::TeamSharedDropboxError(inner) => write!(f, "{}", inner),
+            TeamFolderArchiveError::AccessError(inner) => write!(f, "access_error: {}", inner),
+            TeamFolderArchiveError::StatusError(inner) => write!(f, "status_error: {}", inner),
+            TeamFolderArchiveError::TeamSharedDropboxError(inner) => write!(f, "team_shared_dropbox_error: {}", inner),
             _ => write!(f, "{:?}", *self),
         }
     }
@@ -24679,7 +23009,7 @@ impl From<BaseTeamFolderError> for TeamFolderArchiveError {
             BaseTeamFolderError::AccessError(x) => TeamFolderArchiveError::AccessError(x),
             BaseTeamFolderError::StatusError(x) => TeamFolderArchiveError::StatusError(x),
             BaseTeamFolderError::TeamSharedDropboxError(x) => TeamFolderArchiveError::TeamSharedDropboxError(x),
-            BaseTeamFolderError::Other => TeamFolderArchiveError::Other,
+            BaseTeamFolderError::Other(x) => TeamFolderArchiveError::Other(x),
         }
     }
 }
@@ -24887,22 +23217,9 @@ impl TeamFolderCreateArg {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "name" => {
-                    if field_name.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("name"));
-                    }
-                    field_name = Some(map.next_value()?);
-                }
-                "sync_setting" => {
-                    if field_sync_setting.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("sync_setting"));
-                    }
-                    field_sync_setting = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "name" => crate::deserialize_field(&mut map, &mut field_name, "name")?,
+                "sync_setting" => crate::deserialize_field(&mut map, &mut field_sync_setting, "sync_setting")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -24968,8 +23285,9 @@ pub enum TeamFolderCreateError {
     /// An error occurred setting the sync settings.
     SyncSettingsError(crate::types::files::SyncSettingsError),
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for TeamFolderCreateError {
@@ -24998,7 +23316,7 @@ impl<'de> ::serde::de::Deserialize<'de> for TeamFolderCreateError {
                             _ => return Err(de::Error::unknown_field(tag, VARIANTS))
                         }
                     }
-                    _ => TeamFolderCreateError::Other,
+                    _ => TeamFolderCreateError::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -25043,7 +23361,10 @@ impl ::serde::ser::Serialize for TeamFolderCreateError {
                 s.serialize_field("sync_settings_error", x)?;
                 s.end()
             }
-            TeamFolderCreateError::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            TeamFolderCreateError::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -25170,16 +23491,8 @@ impl TeamFolderIdArg {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "team_folder_id" => {
-                    if field_team_folder_id.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("team_folder_id"));
-                    }
-                    field_team_folder_id = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "team_folder_id" => crate::deserialize_field(&mut map, &mut field_team_folder_id, "team_folder_id")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -25261,16 +23574,8 @@ impl TeamFolderIdListArg {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "team_folder_ids" => {
-                    if field_team_folder_ids.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("team_folder_ids"));
-                    }
-                    field_team_folder_ids = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "team_folder_ids" => crate::deserialize_field(&mut map, &mut field_team_folder_ids, "team_folder_ids")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -25330,8 +23635,9 @@ pub enum TeamFolderInvalidStatusError {
     /// The folder is being archived and the operation did not succeed.
     ArchiveInProgress,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for TeamFolderInvalidStatusError {
@@ -25353,7 +23659,7 @@ impl<'de> ::serde::de::Deserialize<'de> for TeamFolderInvalidStatusError {
                     "active" => TeamFolderInvalidStatusError::Active,
                     "archived" => TeamFolderInvalidStatusError::Archived,
                     "archive_in_progress" => TeamFolderInvalidStatusError::ArchiveInProgress,
-                    _ => TeamFolderInvalidStatusError::Other,
+                    _ => TeamFolderInvalidStatusError::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -25390,7 +23696,10 @@ impl ::serde::ser::Serialize for TeamFolderInvalidStatusError {
                 s.serialize_field(".tag", "archive_in_progress")?;
                 s.end()
             }
-            TeamFolderInvalidStatusError::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            TeamFolderInvalidStatusError::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -25440,16 +23749,8 @@ impl TeamFolderListArg {
         let mut field_limit = None;
         while let Some(key) = map.next_key::<&str>()? {
             match key {
-                "limit" => {
-                    if field_limit.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("limit"));
-                    }
-                    field_limit = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "limit" => crate::deserialize_field(&mut map, &mut field_limit, "limit")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         let result = TeamFolderListArg {
@@ -25530,16 +23831,8 @@ impl TeamFolderListContinueArg {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "cursor" => {
-                    if field_cursor.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("cursor"));
-                    }
-                    field_cursor = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "cursor" => crate::deserialize_field(&mut map, &mut field_cursor, "cursor")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -25595,8 +23888,9 @@ pub enum TeamFolderListContinueError {
     /// The cursor is invalid.
     InvalidCursor,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for TeamFolderListContinueError {
@@ -25616,7 +23910,7 @@ impl<'de> ::serde::de::Deserialize<'de> for TeamFolderListContinueError {
                 };
                 let value = match tag {
                     "invalid_cursor" => TeamFolderListContinueError::InvalidCursor,
-                    _ => TeamFolderListContinueError::Other,
+                    _ => TeamFolderListContinueError::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -25639,7 +23933,10 @@ impl ::serde::ser::Serialize for TeamFolderListContinueError {
                 s.serialize_field(".tag", "invalid_cursor")?;
                 s.end()
             }
-            TeamFolderListContinueError::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            TeamFolderListContinueError::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -25687,16 +23984,8 @@ impl TeamFolderListError {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "access_error" => {
-                    if field_access_error.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("access_error"));
-                    }
-                    field_access_error = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "access_error" => crate::deserialize_field(&mut map, &mut field_access_error, "access_error")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -25802,28 +24091,10 @@ impl TeamFolderListResult {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "team_folders" => {
-                    if field_team_folders.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("team_folders"));
-                    }
-                    field_team_folders = Some(map.next_value()?);
-                }
-                "cursor" => {
-                    if field_cursor.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("cursor"));
-                    }
-                    field_cursor = Some(map.next_value()?);
-                }
-                "has_more" => {
-                    if field_has_more.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("has_more"));
-                    }
-                    field_has_more = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "team_folders" => crate::deserialize_field(&mut map, &mut field_team_folders, "team_folders")?,
+                "cursor" => crate::deserialize_field(&mut map, &mut field_cursor, "cursor")?,
+                "has_more" => crate::deserialize_field(&mut map, &mut field_has_more, "has_more")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -25942,46 +24213,13 @@ impl TeamFolderMetadata {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "team_folder_id" => {
-                    if field_team_folder_id.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("team_folder_id"));
-                    }
-                    field_team_folder_id = Some(map.next_value()?);
-                }
-                "name" => {
-                    if field_name.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("name"));
-                    }
-                    field_name = Some(map.next_value()?);
-                }
-                "status" => {
-                    if field_status.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("status"));
-                    }
-                    field_status = Some(map.next_value()?);
-                }
-                "is_team_shared_dropbox" => {
-                    if field_is_team_shared_dropbox.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("is_team_shared_dropbox"));
-                    }
-                    field_is_team_shared_dropbox = Some(map.next_value()?);
-                }
-                "sync_setting" => {
-                    if field_sync_setting.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("sync_setting"));
-                    }
-                    field_sync_setting = Some(map.next_value()?);
-                }
-                "content_sync_settings" => {
-                    if field_content_sync_settings.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("content_sync_settings"));
-                    }
-                    field_content_sync_settings = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "team_folder_id" => crate::deserialize_field(&mut map, &mut field_team_folder_id, "team_folder_id")?,
+                "name" => crate::deserialize_field(&mut map, &mut field_name, "name")?,
+                "status" => crate::deserialize_field(&mut map, &mut field_status, "status")?,
+                "is_team_shared_dropbox" => crate::deserialize_field(&mut map, &mut field_is_team_shared_dropbox, "is_team_shared_dropbox")?,
+                "sync_setting" => crate::deserialize_field(&mut map, &mut field_sync_setting, "sync_setting")?,
+                "content_sync_settings" => crate::deserialize_field(&mut map, &mut field_content_sync_settings, "content_sync_settings")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -26049,8 +24287,9 @@ pub enum TeamFolderPermanentlyDeleteError {
     StatusError(TeamFolderInvalidStatusError),
     TeamSharedDropboxError(TeamFolderTeamSharedDropboxError),
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for TeamFolderPermanentlyDeleteError {
@@ -26090,7 +24329,7 @@ impl<'de> ::serde::de::Deserialize<'de> for TeamFolderPermanentlyDeleteError {
                             _ => return Err(de::Error::unknown_field(tag, VARIANTS))
                         }
                     }
-                    _ => TeamFolderPermanentlyDeleteError::Other,
+                    _ => TeamFolderPermanentlyDeleteError::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -26130,7 +24369,10 @@ impl ::serde::ser::Serialize for TeamFolderPermanentlyDeleteError {
                 s.serialize_field("team_shared_dropbox_error", x)?;
                 s.end()
             }
-            TeamFolderPermanentlyDeleteError::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            TeamFolderPermanentlyDeleteError::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -26149,9 +24391,9 @@ impl ::std::error::Error for TeamFolderPermanentlyDeleteError {
 impl ::std::fmt::Display for TeamFolderPermanentlyDeleteError {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         match self {
-            TeamFolderPermanentlyDeleteError::AccessError(inner) => write!(f, "{}", inner),
-            TeamFolderPermanentlyDeleteError::StatusError(inner) => write!(f, "{}", inner),
-            TeamFolderPermanentlyDeleteError::TeamSharedDropboxError(inner) => write!(f, "{}", inner),
+            TeamFolderPermanentlyDeleteError::AccessError(inner) => write!(f, "access_error: {}", inner),
+            TeamFolderPermanentlyDeleteError::StatusError(inner) => write!(f, "status_error: {}", inner),
+            TeamFolderPermanentlyDeleteError::TeamSharedDropboxError(inner) => write!(f, "team_shared_dropbox_error: {}", inner),
             _ => write!(f, "{:?}", *self),
         }
     }
@@ -26164,7 +24406,7 @@ impl From<BaseTeamFolderError> for TeamFolderPermanentlyDeleteError {
             BaseTeamFolderError::AccessError(x) => TeamFolderPermanentlyDeleteError::AccessError(x),
             BaseTeamFolderError::StatusError(x) => TeamFolderPermanentlyDeleteError::StatusError(x),
             BaseTeamFolderError::TeamSharedDropboxError(x) => TeamFolderPermanentlyDeleteError::TeamSharedDropboxError(x),
-            BaseTeamFolderError::Other => TeamFolderPermanentlyDeleteError::Other,
+            BaseTeamFolderError::Other(x) => TeamFolderPermanentlyDeleteError::Other(x),
         }
     }
 }
@@ -26205,22 +24447,9 @@ impl TeamFolderRenameArg {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "team_folder_id" => {
-                    if field_team_folder_id.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("team_folder_id"));
-                    }
-                    field_team_folder_id = Some(map.next_value()?);
-                }
-                "name" => {
-                    if field_name.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("name"));
-                    }
-                    field_name = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "team_folder_id" => crate::deserialize_field(&mut map, &mut field_team_folder_id, "team_folder_id")?,
+                "name" => crate::deserialize_field(&mut map, &mut field_name, "name")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -26293,8 +24522,9 @@ pub enum TeamFolderRenameError {
     /// The provided name cannot be used because it is reserved.
     FolderNameReserved,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for TeamFolderRenameError {
@@ -26337,7 +24567,7 @@ impl<'de> ::serde::de::Deserialize<'de> for TeamFolderRenameError {
                     "invalid_folder_name" => TeamFolderRenameError::InvalidFolderName,
                     "folder_name_already_used" => TeamFolderRenameError::FolderNameAlreadyUsed,
                     "folder_name_reserved" => TeamFolderRenameError::FolderNameReserved,
-                    _ => TeamFolderRenameError::Other,
+                    _ => TeamFolderRenameError::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -26398,7 +24628,10 @@ impl ::serde::ser::Serialize for TeamFolderRenameError {
                 s.serialize_field(".tag", "folder_name_reserved")?;
                 s.end()
             }
-            TeamFolderRenameError::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            TeamFolderRenameError::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -26417,9 +24650,9 @@ impl ::std::error::Error for TeamFolderRenameError {
 impl ::std::fmt::Display for TeamFolderRenameError {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         match self {
-            TeamFolderRenameError::AccessError(inner) => write!(f, "{}", inner),
-            TeamFolderRenameError::StatusError(inner) => write!(f, "{}", inner),
-            TeamFolderRenameError::TeamSharedDropboxError(inner) => write!(f, "{}", inner),
+            TeamFolderRenameError::AccessError(inner) => write!(f, "access_error: {}", inner),
+            TeamFolderRenameError::StatusError(inner) => write!(f, "status_error: {}", inner),
+            TeamFolderRenameError::TeamSharedDropboxError(inner) => write!(f, "team_shared_dropbox_error: {}", inner),
             TeamFolderRenameError::InvalidFolderName => f.write_str("The provided folder name cannot be used."),
             TeamFolderRenameError::FolderNameAlreadyUsed => f.write_str("There is already a team folder with the same name."),
             TeamFolderRenameError::FolderNameReserved => f.write_str("The provided name cannot be used because it is reserved."),
@@ -26435,7 +24668,7 @@ impl From<BaseTeamFolderError> for TeamFolderRenameError {
             BaseTeamFolderError::AccessError(x) => TeamFolderRenameError::AccessError(x),
             BaseTeamFolderError::StatusError(x) => TeamFolderRenameError::StatusError(x),
             BaseTeamFolderError::TeamSharedDropboxError(x) => TeamFolderRenameError::TeamSharedDropboxError(x),
-            BaseTeamFolderError::Other => TeamFolderRenameError::Other,
+            BaseTeamFolderError::Other(x) => TeamFolderRenameError::Other(x),
         }
     }
 }
@@ -26449,8 +24682,9 @@ pub enum TeamFolderStatus {
     /// The team folder is not accessible outside of the team folder manager.
     ArchiveInProgress,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for TeamFolderStatus {
@@ -26472,7 +24706,7 @@ impl<'de> ::serde::de::Deserialize<'de> for TeamFolderStatus {
                     "active" => TeamFolderStatus::Active,
                     "archived" => TeamFolderStatus::Archived,
                     "archive_in_progress" => TeamFolderStatus::ArchiveInProgress,
-                    _ => TeamFolderStatus::Other,
+                    _ => TeamFolderStatus::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -26509,7 +24743,10 @@ impl ::serde::ser::Serialize for TeamFolderStatus {
                 s.serialize_field(".tag", "archive_in_progress")?;
                 s.end()
             }
-            TeamFolderStatus::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            TeamFolderStatus::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -26520,8 +24757,9 @@ pub enum TeamFolderTeamSharedDropboxError {
     /// This action is not allowed for a shared team root.
     Disallowed,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for TeamFolderTeamSharedDropboxError {
@@ -26541,7 +24779,7 @@ impl<'de> ::serde::de::Deserialize<'de> for TeamFolderTeamSharedDropboxError {
                 };
                 let value = match tag {
                     "disallowed" => TeamFolderTeamSharedDropboxError::Disallowed,
-                    _ => TeamFolderTeamSharedDropboxError::Other,
+                    _ => TeamFolderTeamSharedDropboxError::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -26564,7 +24802,10 @@ impl ::serde::ser::Serialize for TeamFolderTeamSharedDropboxError {
                 s.serialize_field(".tag", "disallowed")?;
                 s.end()
             }
-            TeamFolderTeamSharedDropboxError::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            TeamFolderTeamSharedDropboxError::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -26637,28 +24878,10 @@ impl TeamFolderUpdateSyncSettingsArg {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "team_folder_id" => {
-                    if field_team_folder_id.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("team_folder_id"));
-                    }
-                    field_team_folder_id = Some(map.next_value()?);
-                }
-                "sync_setting" => {
-                    if field_sync_setting.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("sync_setting"));
-                    }
-                    field_sync_setting = Some(map.next_value()?);
-                }
-                "content_sync_settings" => {
-                    if field_content_sync_settings.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("content_sync_settings"));
-                    }
-                    field_content_sync_settings = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "team_folder_id" => crate::deserialize_field(&mut map, &mut field_team_folder_id, "team_folder_id")?,
+                "sync_setting" => crate::deserialize_field(&mut map, &mut field_sync_setting, "sync_setting")?,
+                "content_sync_settings" => crate::deserialize_field(&mut map, &mut field_content_sync_settings, "content_sync_settings")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -26733,8 +24956,9 @@ pub enum TeamFolderUpdateSyncSettingsError {
     /// An error occurred setting the sync settings.
     SyncSettingsError(crate::types::files::SyncSettingsError),
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for TeamFolderUpdateSyncSettingsError {
@@ -26781,7 +25005,7 @@ impl<'de> ::serde::de::Deserialize<'de> for TeamFolderUpdateSyncSettingsError {
                             _ => return Err(de::Error::unknown_field(tag, VARIANTS))
                         }
                     }
-                    _ => TeamFolderUpdateSyncSettingsError::Other,
+                    _ => TeamFolderUpdateSyncSettingsError::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -26829,7 +25053,10 @@ impl ::serde::ser::Serialize for TeamFolderUpdateSyncSettingsError {
                 s.serialize_field("sync_settings_error", x)?;
                 s.end()
             }
-            TeamFolderUpdateSyncSettingsError::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            TeamFolderUpdateSyncSettingsError::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -26849,9 +25076,9 @@ impl ::std::error::Error for TeamFolderUpdateSyncSettingsError {
 impl ::std::fmt::Display for TeamFolderUpdateSyncSettingsError {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         match self {
-            TeamFolderUpdateSyncSettingsError::AccessError(inner) => write!(f, "{}", inner),
-            TeamFolderUpdateSyncSettingsError::StatusError(inner) => write!(f, "{}", inner),
-            TeamFolderUpdateSyncSettingsError::TeamSharedDropboxError(inner) => write!(f, "{}", inner),
+            TeamFolderUpdateSyncSettingsError::AccessError(inner) => write!(f, "access_error: {}", inner),
+            TeamFolderUpdateSyncSettingsError::StatusError(inner) => write!(f, "status_error: {}", inner),
+            TeamFolderUpdateSyncSettingsError::TeamSharedDropboxError(inner) => write!(f, "team_shared_dropbox_error: {}", inner),
             TeamFolderUpdateSyncSettingsError::SyncSettingsError(inner) => write!(f, "An error occurred setting the sync settings: {}", inner),
             _ => write!(f, "{:?}", *self),
         }
@@ -26865,7 +25092,7 @@ impl From<BaseTeamFolderError> for TeamFolderUpdateSyncSettingsError {
             BaseTeamFolderError::AccessError(x) => TeamFolderUpdateSyncSettingsError::AccessError(x),
             BaseTeamFolderError::StatusError(x) => TeamFolderUpdateSyncSettingsError::StatusError(x),
             BaseTeamFolderError::TeamSharedDropboxError(x) => TeamFolderUpdateSyncSettingsError::TeamSharedDropboxError(x),
-            BaseTeamFolderError::Other => TeamFolderUpdateSyncSettingsError::Other,
+            BaseTeamFolderError::Other(x) => TeamFolderUpdateSyncSettingsError::Other(x),
         }
     }
 }
@@ -26936,46 +25163,13 @@ impl TeamGetInfoResult {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "name" => {
-                    if field_name.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("name"));
-                    }
-                    field_name = Some(map.next_value()?);
-                }
-                "team_id" => {
-                    if field_team_id.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("team_id"));
-                    }
-                    field_team_id = Some(map.next_value()?);
-                }
-                "num_licensed_users" => {
-                    if field_num_licensed_users.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("num_licensed_users"));
-                    }
-                    field_num_licensed_users = Some(map.next_value()?);
-                }
-                "num_provisioned_users" => {
-                    if field_num_provisioned_users.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("num_provisioned_users"));
-                    }
-                    field_num_provisioned_users = Some(map.next_value()?);
-                }
-                "policies" => {
-                    if field_policies.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("policies"));
-                    }
-                    field_policies = Some(map.next_value()?);
-                }
-                "num_used_licenses" => {
-                    if field_num_used_licenses.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("num_used_licenses"));
-                    }
-                    field_num_used_licenses = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "name" => crate::deserialize_field(&mut map, &mut field_name, "name")?,
+                "team_id" => crate::deserialize_field(&mut map, &mut field_team_id, "team_id")?,
+                "num_licensed_users" => crate::deserialize_field(&mut map, &mut field_num_licensed_users, "num_licensed_users")?,
+                "num_provisioned_users" => crate::deserialize_field(&mut map, &mut field_num_provisioned_users, "num_provisioned_users")?,
+                "policies" => crate::deserialize_field(&mut map, &mut field_policies, "policies")?,
+                "num_used_licenses" => crate::deserialize_field(&mut map, &mut field_num_used_licenses, "num_used_licenses")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -27075,22 +25269,9 @@ impl TeamMemberInfo {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "profile" => {
-                    if field_profile.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("profile"));
-                    }
-                    field_profile = Some(map.next_value()?);
-                }
-                "role" => {
-                    if field_role.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("role"));
-                    }
-                    field_role = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "profile" => crate::deserialize_field(&mut map, &mut field_profile, "profile")?,
+                "role" => crate::deserialize_field(&mut map, &mut field_role, "role")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -27185,22 +25366,9 @@ impl TeamMemberInfoV2 {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "profile" => {
-                    if field_profile.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("profile"));
-                    }
-                    field_profile = Some(map.next_value()?);
-                }
-                "roles" => {
-                    if field_roles.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("roles"));
-                    }
-                    field_roles = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "profile" => crate::deserialize_field(&mut map, &mut field_profile, "profile")?,
+                "roles" => crate::deserialize_field(&mut map, &mut field_roles, "roles")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -27288,16 +25456,8 @@ impl TeamMemberInfoV2Result {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "member_info" => {
-                    if field_member_info.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("member_info"));
-                    }
-                    field_member_info = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "member_info" => crate::deserialize_field(&mut map, &mut field_member_info, "member_info")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -27522,112 +25682,24 @@ impl TeamMemberProfile {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "team_member_id" => {
-                    if field_team_member_id.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("team_member_id"));
-                    }
-                    field_team_member_id = Some(map.next_value()?);
-                }
-                "email" => {
-                    if field_email.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("email"));
-                    }
-                    field_email = Some(map.next_value()?);
-                }
-                "email_verified" => {
-                    if field_email_verified.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("email_verified"));
-                    }
-                    field_email_verified = Some(map.next_value()?);
-                }
-                "status" => {
-                    if field_status.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("status"));
-                    }
-                    field_status = Some(map.next_value()?);
-                }
-                "name" => {
-                    if field_name.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("name"));
-                    }
-                    field_name = Some(map.next_value()?);
-                }
-                "membership_type" => {
-                    if field_membership_type.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("membership_type"));
-                    }
-                    field_membership_type = Some(map.next_value()?);
-                }
-                "groups" => {
-                    if field_groups.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("groups"));
-                    }
-                    field_groups = Some(map.next_value()?);
-                }
-                "member_folder_id" => {
-                    if field_member_folder_id.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("member_folder_id"));
-                    }
-                    field_member_folder_id = Some(map.next_value()?);
-                }
-                "external_id" => {
-                    if field_external_id.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("external_id"));
-                    }
-                    field_external_id = Some(map.next_value()?);
-                }
-                "account_id" => {
-                    if field_account_id.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("account_id"));
-                    }
-                    field_account_id = Some(map.next_value()?);
-                }
-                "secondary_emails" => {
-                    if field_secondary_emails.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("secondary_emails"));
-                    }
-                    field_secondary_emails = Some(map.next_value()?);
-                }
-                "invited_on" => {
-                    if field_invited_on.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("invited_on"));
-                    }
-                    field_invited_on = Some(map.next_value()?);
-                }
-                "joined_on" => {
-                    if field_joined_on.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("joined_on"));
-                    }
-                    field_joined_on = Some(map.next_value()?);
-                }
-                "suspended_on" => {
-                    if field_suspended_on.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("suspended_on"));
-                    }
-                    field_suspended_on = Some(map.next_value()?);
-                }
-                "persistent_id" => {
-                    if field_persistent_id.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("persistent_id"));
-                    }
-                    field_persistent_id = Some(map.next_value()?);
-                }
-                "is_directory_restricted" => {
-                    if field_is_directory_restricted.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("is_directory_restricted"));
-                    }
-                    field_is_directory_restricted = Some(map.next_value()?);
-                }
-                "profile_photo_url" => {
-                    if field_profile_photo_url.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("profile_photo_url"));
-                    }
-                    field_profile_photo_url = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "team_member_id" => crate::deserialize_field(&mut map, &mut field_team_member_id, "team_member_id")?,
+                "email" => crate::deserialize_field(&mut map, &mut field_email, "email")?,
+                "email_verified" => crate::deserialize_field(&mut map, &mut field_email_verified, "email_verified")?,
+                "status" => crate::deserialize_field(&mut map, &mut field_status, "status")?,
+                "name" => crate::deserialize_field(&mut map, &mut field_name, "name")?,
+                "membership_type" => crate::deserialize_field(&mut map, &mut field_membership_type, "membership_type")?,
+                "groups" => crate::deserialize_field(&mut map, &mut field_groups, "groups")?,
+                "member_folder_id" => crate::deserialize_field(&mut map, &mut field_member_folder_id, "member_folder_id")?,
+                "external_id" => crate::deserialize_field(&mut map, &mut field_external_id, "external_id")?,
+                "account_id" => crate::deserialize_field(&mut map, &mut field_account_id, "account_id")?,
+                "secondary_emails" => crate::deserialize_field(&mut map, &mut field_secondary_emails, "secondary_emails")?,
+                "invited_on" => crate::deserialize_field(&mut map, &mut field_invited_on, "invited_on")?,
+                "joined_on" => crate::deserialize_field(&mut map, &mut field_joined_on, "joined_on")?,
+                "suspended_on" => crate::deserialize_field(&mut map, &mut field_suspended_on, "suspended_on")?,
+                "persistent_id" => crate::deserialize_field(&mut map, &mut field_persistent_id, "persistent_id")?,
+                "is_directory_restricted" => crate::deserialize_field(&mut map, &mut field_is_directory_restricted, "is_directory_restricted")?,
+                "profile_photo_url" => crate::deserialize_field(&mut map, &mut field_profile_photo_url, "profile_photo_url")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -27794,28 +25866,10 @@ impl TeamMemberRole {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "role_id" => {
-                    if field_role_id.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("role_id"));
-                    }
-                    field_role_id = Some(map.next_value()?);
-                }
-                "name" => {
-                    if field_name.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("name"));
-                    }
-                    field_name = Some(map.next_value()?);
-                }
-                "description" => {
-                    if field_description.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("description"));
-                    }
-                    field_description = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "role_id" => crate::deserialize_field(&mut map, &mut field_role_id, "role_id")?,
+                "name" => crate::deserialize_field(&mut map, &mut field_name, "name")?,
+                "description" => crate::deserialize_field(&mut map, &mut field_description, "description")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -28043,16 +26097,8 @@ impl TeamNamespacesListArg {
         let mut field_limit = None;
         while let Some(key) = map.next_key::<&str>()? {
             match key {
-                "limit" => {
-                    if field_limit.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("limit"));
-                    }
-                    field_limit = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "limit" => crate::deserialize_field(&mut map, &mut field_limit, "limit")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         let result = TeamNamespacesListArg {
@@ -28133,16 +26179,8 @@ impl TeamNamespacesListContinueArg {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "cursor" => {
-                    if field_cursor.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("cursor"));
-                    }
-                    field_cursor = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "cursor" => crate::deserialize_field(&mut map, &mut field_cursor, "cursor")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -28200,8 +26238,9 @@ pub enum TeamNamespacesListContinueError {
     /// The cursor is invalid.
     InvalidCursor,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for TeamNamespacesListContinueError {
@@ -28222,7 +26261,7 @@ impl<'de> ::serde::de::Deserialize<'de> for TeamNamespacesListContinueError {
                 let value = match tag {
                     "invalid_arg" => TeamNamespacesListContinueError::InvalidArg,
                     "invalid_cursor" => TeamNamespacesListContinueError::InvalidCursor,
-                    _ => TeamNamespacesListContinueError::Other,
+                    _ => TeamNamespacesListContinueError::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -28252,7 +26291,10 @@ impl ::serde::ser::Serialize for TeamNamespacesListContinueError {
                 s.serialize_field(".tag", "invalid_cursor")?;
                 s.end()
             }
-            TeamNamespacesListContinueError::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            TeamNamespacesListContinueError::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -28275,7 +26317,7 @@ impl From<TeamNamespacesListError> for TeamNamespacesListContinueError {
     fn from(parent: TeamNamespacesListError) -> Self {
         match parent {
             TeamNamespacesListError::InvalidArg => TeamNamespacesListContinueError::InvalidArg,
-            TeamNamespacesListError::Other => TeamNamespacesListContinueError::Other,
+            TeamNamespacesListError::Other(x) => TeamNamespacesListContinueError::Other(x),
         }
     }
 }
@@ -28285,8 +26327,9 @@ pub enum TeamNamespacesListError {
     /// Argument passed in is invalid.
     InvalidArg,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for TeamNamespacesListError {
@@ -28306,7 +26349,7 @@ impl<'de> ::serde::de::Deserialize<'de> for TeamNamespacesListError {
                 };
                 let value = match tag {
                     "invalid_arg" => TeamNamespacesListError::InvalidArg,
-                    _ => TeamNamespacesListError::Other,
+                    _ => TeamNamespacesListError::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -28329,7 +26372,10 @@ impl ::serde::ser::Serialize for TeamNamespacesListError {
                 s.serialize_field(".tag", "invalid_arg")?;
                 s.end()
             }
-            TeamNamespacesListError::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            TeamNamespacesListError::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -28390,28 +26436,10 @@ impl TeamNamespacesListResult {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "namespaces" => {
-                    if field_namespaces.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("namespaces"));
-                    }
-                    field_namespaces = Some(map.next_value()?);
-                }
-                "cursor" => {
-                    if field_cursor.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("cursor"));
-                    }
-                    field_cursor = Some(map.next_value()?);
-                }
-                "has_more" => {
-                    if field_has_more.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("has_more"));
-                    }
-                    field_has_more = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "namespaces" => crate::deserialize_field(&mut map, &mut field_namespaces, "namespaces")?,
+                "cursor" => crate::deserialize_field(&mut map, &mut field_cursor, "cursor")?,
+                "has_more" => crate::deserialize_field(&mut map, &mut field_has_more, "has_more")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -28477,8 +26505,9 @@ pub enum TeamReportFailureReason {
     /// We couldn't create the report. Try creating the report again with less data.
     TooMuchData,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for TeamReportFailureReason {
@@ -28500,7 +26529,7 @@ impl<'de> ::serde::de::Deserialize<'de> for TeamReportFailureReason {
                     "temporary_error" => TeamReportFailureReason::TemporaryError,
                     "many_reports_at_once" => TeamReportFailureReason::ManyReportsAtOnce,
                     "too_much_data" => TeamReportFailureReason::TooMuchData,
-                    _ => TeamReportFailureReason::Other,
+                    _ => TeamReportFailureReason::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -28537,7 +26566,10 @@ impl ::serde::ser::Serialize for TeamReportFailureReason {
                 s.serialize_field(".tag", "too_much_data")?;
                 s.end()
             }
-            TeamReportFailureReason::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            TeamReportFailureReason::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -28555,8 +26587,9 @@ pub enum TokenGetAuthenticatedAdminError {
     /// or no longer a team admin.
     AdminNotActive,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for TokenGetAuthenticatedAdminError {
@@ -28577,7 +26610,7 @@ impl<'de> ::serde::de::Deserialize<'de> for TokenGetAuthenticatedAdminError {
                 let value = match tag {
                     "mapping_not_found" => TokenGetAuthenticatedAdminError::MappingNotFound,
                     "admin_not_active" => TokenGetAuthenticatedAdminError::AdminNotActive,
-                    _ => TokenGetAuthenticatedAdminError::Other,
+                    _ => TokenGetAuthenticatedAdminError::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -28607,7 +26640,10 @@ impl ::serde::ser::Serialize for TokenGetAuthenticatedAdminError {
                 s.serialize_field(".tag", "admin_not_active")?;
                 s.end()
             }
-            TokenGetAuthenticatedAdminError::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            TokenGetAuthenticatedAdminError::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -28658,16 +26694,8 @@ impl TokenGetAuthenticatedAdminResult {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "admin_profile" => {
-                    if field_admin_profile.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("admin_profile"));
-                    }
-                    field_admin_profile = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "admin_profile" => crate::deserialize_field(&mut map, &mut field_admin_profile, "admin_profile")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -28727,8 +26755,9 @@ pub enum UploadApiRateLimitValue {
     /// The number of upload API calls allowed per month.
     Limit(u32),
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for UploadApiRateLimitValue {
@@ -28755,7 +26784,7 @@ impl<'de> ::serde::de::Deserialize<'de> for UploadApiRateLimitValue {
                             _ => return Err(de::Error::unknown_field(tag, VARIANTS))
                         }
                     }
-                    _ => UploadApiRateLimitValue::Other,
+                    _ => UploadApiRateLimitValue::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -28786,7 +26815,10 @@ impl ::serde::ser::Serialize for UploadApiRateLimitValue {
                 s.serialize_field("limit", x)?;
                 s.end()
             }
-            UploadApiRateLimitValue::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            UploadApiRateLimitValue::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -28806,8 +26838,9 @@ pub enum UserAddResult {
     /// Secondary emails cannot be added to placeholder users.
     PlaceholderUser(UserSelectorArg),
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for UserAddResult {
@@ -28848,7 +26881,7 @@ impl<'de> ::serde::de::Deserialize<'de> for UserAddResult {
                             _ => return Err(de::Error::unknown_field(tag, VARIANTS))
                         }
                     }
-                    _ => UserAddResult::Other,
+                    _ => UserAddResult::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -28896,7 +26929,10 @@ impl ::serde::ser::Serialize for UserAddResult {
                 s.serialize_field("placeholder_user", x)?;
                 s.end()
             }
-            UserAddResult::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            UserAddResult::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -28937,22 +26973,9 @@ impl UserCustomQuotaArg {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "user" => {
-                    if field_user.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("user"));
-                    }
-                    field_user = Some(map.next_value()?);
-                }
-                "quota_gb" => {
-                    if field_quota_gb.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("quota_gb"));
-                    }
-                    field_quota_gb = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "user" => crate::deserialize_field(&mut map, &mut field_user, "user")?,
+                "quota_gb" => crate::deserialize_field(&mut map, &mut field_quota_gb, "quota_gb")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -29046,22 +27069,9 @@ impl UserCustomQuotaResult {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "user" => {
-                    if field_user.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("user"));
-                    }
-                    field_user = Some(map.next_value()?);
-                }
-                "quota_gb" => {
-                    if field_quota_gb.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("quota_gb"));
-                    }
-                    field_quota_gb = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "user" => crate::deserialize_field(&mut map, &mut field_user, "user")?,
+                "quota_gb" => crate::deserialize_field(&mut map, &mut field_quota_gb, "quota_gb")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -29150,22 +27160,9 @@ impl UserDeleteEmailsResult {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "user" => {
-                    if field_user.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("user"));
-                    }
-                    field_user = Some(map.next_value()?);
-                }
-                "results" => {
-                    if field_results.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("results"));
-                    }
-                    field_results = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "user" => crate::deserialize_field(&mut map, &mut field_user, "user")?,
+                "results" => crate::deserialize_field(&mut map, &mut field_results, "results")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -29228,8 +27225,9 @@ pub enum UserDeleteResult {
     /// Specified user is not a valid target for deleting secondary emails.
     InvalidUser(UserSelectorArg),
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for UserDeleteResult {
@@ -29256,7 +27254,7 @@ impl<'de> ::serde::de::Deserialize<'de> for UserDeleteResult {
                             _ => return Err(de::Error::unknown_field(tag, VARIANTS))
                         }
                     }
-                    _ => UserDeleteResult::Other,
+                    _ => UserDeleteResult::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -29288,7 +27286,10 @@ impl ::serde::ser::Serialize for UserDeleteResult {
                 s.serialize_field("invalid_user", x)?;
                 s.end()
             }
-            UserDeleteResult::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            UserDeleteResult::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -29328,22 +27329,9 @@ impl UserResendEmailsResult {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "user" => {
-                    if field_user.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("user"));
-                    }
-                    field_user = Some(map.next_value()?);
-                }
-                "results" => {
-                    if field_results.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("results"));
-                    }
-                    field_results = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "user" => crate::deserialize_field(&mut map, &mut field_user, "user")?,
+                "results" => crate::deserialize_field(&mut map, &mut field_results, "results")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -29406,8 +27394,9 @@ pub enum UserResendResult {
     /// Specified user is not a valid target for resending verification emails.
     InvalidUser(UserSelectorArg),
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for UserResendResult {
@@ -29434,7 +27423,7 @@ impl<'de> ::serde::de::Deserialize<'de> for UserResendResult {
                             _ => return Err(de::Error::unknown_field(tag, VARIANTS))
                         }
                     }
-                    _ => UserResendResult::Other,
+                    _ => UserResendResult::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -29466,7 +27455,10 @@ impl ::serde::ser::Serialize for UserResendResult {
                 s.serialize_field("invalid_user", x)?;
                 s.end()
             }
-            UserResendResult::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            UserResendResult::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -29510,22 +27502,9 @@ impl UserSecondaryEmailsArg {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "user" => {
-                    if field_user.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("user"));
-                    }
-                    field_user = Some(map.next_value()?);
-                }
-                "secondary_emails" => {
-                    if field_secondary_emails.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("secondary_emails"));
-                    }
-                    field_secondary_emails = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "user" => crate::deserialize_field(&mut map, &mut field_user, "user")?,
+                "secondary_emails" => crate::deserialize_field(&mut map, &mut field_secondary_emails, "secondary_emails")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -29612,22 +27591,9 @@ impl UserSecondaryEmailsResult {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "user" => {
-                    if field_user.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("user"));
-                    }
-                    field_user = Some(map.next_value()?);
-                }
-                "results" => {
-                    if field_results.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("results"));
-                    }
-                    field_results = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "user" => crate::deserialize_field(&mut map, &mut field_user, "user")?,
+                "results" => crate::deserialize_field(&mut map, &mut field_results, "results")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -26,8 +26,9 @@ pub enum GroupManagementType {
     /// A group which is managed automatically by Dropbox.
     SystemManaged,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for GroupManagementType {
@@ -49,7 +50,7 @@ impl<'de> ::serde::de::Deserialize<'de> for GroupManagementType {
                     "user_managed" => GroupManagementType::UserManaged,
                     "company_managed" => GroupManagementType::CompanyManaged,
                     "system_managed" => GroupManagementType::SystemManaged,
-                    _ => GroupManagementType::Other,
+                    _ => GroupManagementType::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -86,7 +87,10 @@ impl ::serde::ser::Serialize for GroupManagementType {
                 s.serialize_field(".tag", "system_managed")?;
                 s.end()
             }
-            GroupManagementType::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            GroupManagementType::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -156,40 +160,12 @@ impl GroupSummary {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "group_name" => {
-                    if field_group_name.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("group_name"));
-                    }
-                    field_group_name = Some(map.next_value()?);
-                }
-                "group_id" => {
-                    if field_group_id.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("group_id"));
-                    }
-                    field_group_id = Some(map.next_value()?);
-                }
-                "group_management_type" => {
-                    if field_group_management_type.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("group_management_type"));
-                    }
-                    field_group_management_type = Some(map.next_value()?);
-                }
-                "group_external_id" => {
-                    if field_group_external_id.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("group_external_id"));
-                    }
-                    field_group_external_id = Some(map.next_value()?);
-                }
-                "member_count" => {
-                    if field_member_count.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("member_count"));
-                    }
-                    field_member_count = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "group_name" => crate::deserialize_field(&mut map, &mut field_group_name, "group_name")?,
+                "group_id" => crate::deserialize_field(&mut map, &mut field_group_id, "group_id")?,
+                "group_management_type" => crate::deserialize_field(&mut map, &mut field_group_management_type, "group_management_type")?,
+                "group_external_id" => crate::deserialize_field(&mut map, &mut field_group_external_id, "group_external_id")?,
+                "member_count" => crate::deserialize_field(&mut map, &mut field_member_count, "member_count")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -261,8 +237,9 @@ pub enum GroupType {
     /// A group is created and managed by a user.
     UserManaged,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for GroupType {
@@ -283,7 +260,7 @@ impl<'de> ::serde::de::Deserialize<'de> for GroupType {
                 let value = match tag {
                     "team" => GroupType::Team,
                     "user_managed" => GroupType::UserManaged,
-                    _ => GroupType::Other,
+                    _ => GroupType::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -313,7 +290,10 @@ impl ::serde::ser::Serialize for GroupType {
                 s.serialize_field(".tag", "user_managed")?;
                 s.end()
             }
-            GroupType::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            GroupType::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -331,8 +311,9 @@ pub enum MemberSpaceLimitType {
     /// is reached.
     StopSync,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for MemberSpaceLimitType {
@@ -354,7 +335,7 @@ impl<'de> ::serde::de::Deserialize<'de> for MemberSpaceLimitType {
                     "off" => MemberSpaceLimitType::Off,
                     "alert_only" => MemberSpaceLimitType::AlertOnly,
                     "stop_sync" => MemberSpaceLimitType::StopSync,
-                    _ => MemberSpaceLimitType::Other,
+                    _ => MemberSpaceLimitType::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -391,7 +372,10 @@ impl ::serde::ser::Serialize for MemberSpaceLimitType {
                 s.serialize_field(".tag", "stop_sync")?;
                 s.end()
             }
-            MemberSpaceLimitType::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            MemberSpaceLimitType::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -429,22 +413,9 @@ impl TimeRange {
         let mut field_end_time = None;
         while let Some(key) = map.next_key::<&str>()? {
             match key {
-                "start_time" => {
-                    if field_start_time.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("start_time"));
-                    }
-                    field_start_time = Some(map.next_value()?);
-                }
-                "end_time" => {
-                    if field_end_time.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("end_time"));
-                    }
-                    field_end_time = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "start_time" => crate::deserialize_field(&mut map, &mut field_start_time, "start_time")?,
+                "end_time" => crate::deserialize_field(&mut map, &mut field_end_time, "end_time")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         let result = TimeRange {
@@ -16,8 +16,9 @@ pub enum CameraUploadsPolicyState {
     /// Background camera uploads are allowed.
     Enabled,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for CameraUploadsPolicyState {
@@ -38,7 +39,7 @@ impl<'de> ::serde::de::Deserialize<'de> for CameraUploadsPolicyState {
                 let value = match tag {
                     "disabled" => CameraUploadsPolicyState::Disabled,
                     "enabled" => CameraUploadsPolicyState::Enabled,
-                    _ => CameraUploadsPolicyState::Other,
+                    _ => CameraUploadsPolicyState::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -68,7 +69,10 @@ impl ::serde::ser::Serialize for CameraUploadsPolicyState {
                 s.serialize_field(".tag", "enabled")?;
                 s.end()
             }
-            CameraUploadsPolicyState::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            CameraUploadsPolicyState::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -83,8 +87,9 @@ pub enum ComputerBackupPolicyState {
     /// Computer Backup defaults to ON for SSB teams, and OFF for Enterprise teams.
     Default,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for ComputerBackupPolicyState {
@@ -106,7 +111,7 @@ impl<'de> ::serde::de::Deserialize<'de> for ComputerBackupPolicyState {
                     "disabled" => ComputerBackupPolicyState::Disabled,
                     "enabled" => ComputerBackupPolicyState::Enabled,
                     "default" => ComputerBackupPolicyState::Default,
-                    _ => ComputerBackupPolicyState::Other,
+                    _ => ComputerBackupPolicyState::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -143,7 +148,10 @@ impl ::serde::ser::Serialize for ComputerBackupPolicyState {
                 s.serialize_field(".tag", "default")?;
                 s.end()
             }
-            ComputerBackupPolicyState::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            ComputerBackupPolicyState::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -158,8 +166,9 @@ pub enum EmmState {
     /// Emm token is required.
     Required,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for EmmState {
@@ -181,7 +190,7 @@ impl<'de> ::serde::de::Deserialize<'de> for EmmState {
                     "disabled" => EmmState::Disabled,
                     "optional" => EmmState::Optional,
                     "required" => EmmState::Required,
-                    _ => EmmState::Other,
+                    _ => EmmState::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -218,7 +227,10 @@ impl ::serde::ser::Serialize for EmmState {
                 s.serialize_field(".tag", "required")?;
                 s.end()
             }
-            EmmState::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            EmmState::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -233,8 +245,9 @@ pub enum ExternalDriveBackupPolicyState {
     /// External Drive Backup default value based on team tier.
     Default,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for ExternalDriveBackupPolicyState {
@@ -256,7 +269,7 @@ impl<'de> ::serde::de::Deserialize<'de> for ExternalDriveBackupPolicyState {
                     "disabled" => ExternalDriveBackupPolicyState::Disabled,
                     "enabled" => ExternalDriveBackupPolicyState::Enabled,
                     "default" => ExternalDriveBackupPolicyState::Default,
-                    _ => ExternalDriveBackupPolicyState::Other,
+                    _ => ExternalDriveBackupPolicyState::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -293,7 +306,10 @@ impl ::serde::ser::Serialize for ExternalDriveBackupPolicyState {
                 s.serialize_field(".tag", "default")?;
                 s.end()
             }
-            ExternalDriveBackupPolicyState::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            ExternalDriveBackupPolicyState::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -306,8 +322,9 @@ pub enum FileLockingPolicyState {
     /// File locking feature is allowed.
     Enabled,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for FileLockingPolicyState {
@@ -328,7 +345,7 @@ impl<'de> ::serde::de::Deserialize<'de> for FileLockingPolicyState {
                 let value = match tag {
                     "disabled" => FileLockingPolicyState::Disabled,
                     "enabled" => FileLockingPolicyState::Enabled,
-                    _ => FileLockingPolicyState::Other,
+                    _ => FileLockingPolicyState::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -358,7 +375,10 @@ impl ::serde::ser::Serialize for FileLockingPolicyState {
                 s.serialize_field(".tag", "enabled")?;
                 s.end()
             }
-            FileLockingPolicyState::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            FileLockingPolicyState::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -373,8 +393,9 @@ pub enum FileProviderMigrationPolicyState {
     /// Team admin has default value based on team tier.
     Default,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for FileProviderMigrationPolicyState {
@@ -396,7 +417,7 @@ impl<'de> ::serde::de::Deserialize<'de> for FileProviderMigrationPolicyState {
                     "disabled" => FileProviderMigrationPolicyState::Disabled,
                     "enabled" => FileProviderMigrationPolicyState::Enabled,
                     "default" => FileProviderMigrationPolicyState::Default,
-                    _ => FileProviderMigrationPolicyState::Other,
+                    _ => FileProviderMigrationPolicyState::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -433,7 +454,10 @@ impl ::serde::ser::Serialize for FileProviderMigrationPolicyState {
                 s.serialize_field(".tag", "default")?;
                 s.end()
             }
-            FileProviderMigrationPolicyState::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            FileProviderMigrationPolicyState::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -505,8 +529,9 @@ pub enum OfficeAddInPolicy {
     /// Office Add-In is enabled.
     Enabled,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for OfficeAddInPolicy {
@@ -527,7 +552,7 @@ impl<'de> ::serde::de::Deserialize<'de> for OfficeAddInPolicy {
                 let value = match tag {
                     "disabled" => OfficeAddInPolicy::Disabled,
                     "enabled" => OfficeAddInPolicy::Enabled,
-                    _ => OfficeAddInPolicy::Other,
+                    _ => OfficeAddInPolicy::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -557,7 +582,10 @@ impl ::serde::ser::Serialize for OfficeAddInPolicy {
                 s.serialize_field(".tag", "enabled")?;
                 s.end()
             }
-            OfficeAddInPolicy::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            OfficeAddInPolicy::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -570,8 +598,9 @@ pub enum PaperDefaultFolderPolicy {
     /// Invite only will be the default option when creating a folder in Paper.
     InviteOnly,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for PaperDefaultFolderPolicy {
@@ -592,7 +621,7 @@ impl<'de> ::serde::de::Deserialize<'de> for PaperDefaultFolderPolicy {
                 let value = match tag {
                     "everyone_in_team" => PaperDefaultFolderPolicy::EveryoneInTeam,
                     "invite_only" => PaperDefaultFolderPolicy::InviteOnly,
-                    _ => PaperDefaultFolderPolicy::Other,
+                    _ => PaperDefaultFolderPolicy::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -622,7 +651,10 @@ impl ::serde::ser::Serialize for PaperDefaultFolderPolicy {
                 s.serialize_field(".tag", "invite_only")?;
                 s.end()
             }
-            PaperDefaultFolderPolicy::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            PaperDefaultFolderPolicy::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -636,8 +668,9 @@ pub enum PaperDeploymentPolicy {
     /// 'is_paper_whitelisted' on 'account/info'.
     Partial,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for PaperDeploymentPolicy {
@@ -658,7 +691,7 @@ impl<'de> ::serde::de::Deserialize<'de> for PaperDeploymentPolicy {
                 let value = match tag {
                     "full" => PaperDeploymentPolicy::Full,
                     "partial" => PaperDeploymentPolicy::Partial,
-                    _ => PaperDeploymentPolicy::Other,
+                    _ => PaperDeploymentPolicy::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -688,7 +721,10 @@ impl ::serde::ser::Serialize for PaperDeploymentPolicy {
                 s.serialize_field(".tag", "partial")?;
                 s.end()
             }
-            PaperDeploymentPolicy::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            PaperDeploymentPolicy::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -701,8 +737,9 @@ pub enum PaperDesktopPolicy {
     /// Allow team members to use Paper Desktop.
     Enabled,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for PaperDesktopPolicy {
@@ -723,7 +760,7 @@ impl<'de> ::serde::de::Deserialize<'de> for PaperDesktopPolicy {
                 let value = match tag {
                     "disabled" => PaperDesktopPolicy::Disabled,
                     "enabled" => PaperDesktopPolicy::Enabled,
-                    _ => PaperDesktopPolicy::Other,
+                    _ => PaperDesktopPolicy::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -753,7 +790,10 @@ impl ::serde::ser::Serialize for PaperDesktopPolicy {
                 s.serialize_field(".tag", "enabled")?;
                 s.end()
             }
-            PaperDesktopPolicy::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            PaperDesktopPolicy::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -768,8 +808,9 @@ pub enum PaperEnabledPolicy {
     /// Unspecified policy.
     Unspecified,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for PaperEnabledPolicy {
@@ -791,7 +832,7 @@ impl<'de> ::serde::de::Deserialize<'de> for PaperEnabledPolicy {
                     "disabled" => PaperEnabledPolicy::Disabled,
                     "enabled" => PaperEnabledPolicy::Enabled,
                     "unspecified" => PaperEnabledPolicy::Unspecified,
-                    _ => PaperEnabledPolicy::Other,
+                    _ => PaperEnabledPolicy::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -828,7 +869,10 @@ impl ::serde::ser::Serialize for PaperEnabledPolicy {
                 s.serialize_field(".tag", "unspecified")?;
                 s.end()
             }
-            PaperEnabledPolicy::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            PaperEnabledPolicy::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -841,8 +885,9 @@ pub enum PasswordControlMode {
     /// Password is enabled.
     Enabled,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for PasswordControlMode {
@@ -863,7 +908,7 @@ impl<'de> ::serde::de::Deserialize<'de> for PasswordControlMode {
                 let value = match tag {
                     "disabled" => PasswordControlMode::Disabled,
                     "enabled" => PasswordControlMode::Enabled,
-                    _ => PasswordControlMode::Other,
+                    _ => PasswordControlMode::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -893,7 +938,10 @@ impl ::serde::ser::Serialize for PasswordControlMode {
                 s.serialize_field(".tag", "enabled")?;
                 s.end()
             }
-            PasswordControlMode::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            PasswordControlMode::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -908,8 +956,9 @@ pub enum PasswordStrengthPolicy {
     /// User passwords will adhere to the very strong password strength policy.
     StrongPassword,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for PasswordStrengthPolicy {
@@ -931,7 +980,7 @@ impl<'de> ::serde::de::Deserialize<'de> for PasswordStrengthPolicy {
                     "minimal_requirements" => PasswordStrengthPolicy::MinimalRequirements,
                     "moderate_password" => PasswordStrengthPolicy::ModeratePassword,
                     "strong_password" => PasswordStrengthPolicy::StrongPassword,
-                    _ => PasswordStrengthPolicy::Other,
+                    _ => PasswordStrengthPolicy::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -968,7 +1017,10 @@ impl ::serde::ser::Serialize for PasswordStrengthPolicy {
                 s.serialize_field(".tag", "strong_password")?;
                 s.end()
             }
-            PasswordStrengthPolicy::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            PasswordStrengthPolicy::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -1051,8 +1103,9 @@ pub enum SharedFolderBlanketLinkRestrictionPolicy {
     /// Anyone can access folder content via shared link.
     Anyone,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for SharedFolderBlanketLinkRestrictionPolicy {
@@ -1073,7 +1126,7 @@ impl<'de> ::serde::de::Deserialize<'de> for SharedFolderBlanketLinkRestrictionPo
                 let value = match tag {
                     "members" => SharedFolderBlanketLinkRestrictionPolicy::Members,
                     "anyone" => SharedFolderBlanketLinkRestrictionPolicy::Anyone,
-                    _ => SharedFolderBlanketLinkRestrictionPolicy::Other,
+                    _ => SharedFolderBlanketLinkRestrictionPolicy::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -1103,7 +1156,10 @@ impl ::serde::ser::Serialize for SharedFolderBlanketLinkRestrictionPolicy {
                 s.serialize_field(".tag", "anyone")?;
                 s.end()
             }
-            SharedFolderBlanketLinkRestrictionPolicy::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            SharedFolderBlanketLinkRestrictionPolicy::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -1117,8 +1173,9 @@ pub enum SharedFolderJoinPolicy {
     /// Team members can join any shared folder, including those shared by users outside the team.
     FromAnyone,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for SharedFolderJoinPolicy {
@@ -1139,7 +1196,7 @@ impl<'de> ::serde::de::Deserialize<'de> for SharedFolderJoinPolicy {
                 let value = match tag {
                     "from_team_only" => SharedFolderJoinPolicy::FromTeamOnly,
                     "from_anyone" => SharedFolderJoinPolicy::FromAnyone,
-                    _ => SharedFolderJoinPolicy::Other,
+                    _ => SharedFolderJoinPolicy::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -1169,7 +1226,10 @@ impl ::serde::ser::Serialize for SharedFolderJoinPolicy {
                 s.serialize_field(".tag", "from_anyone")?;
                 s.end()
             }
-            SharedFolderJoinPolicy::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            SharedFolderJoinPolicy::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -1183,8 +1243,9 @@ pub enum SharedFolderMemberPolicy {
     /// Anyone can be a member of a folder shared by a team member.
     Anyone,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for SharedFolderMemberPolicy {
@@ -1205,7 +1266,7 @@ impl<'de> ::serde::de::Deserialize<'de> for SharedFolderMemberPolicy {
                 let value = match tag {
                     "team" => SharedFolderMemberPolicy::Team,
                     "anyone" => SharedFolderMemberPolicy::Anyone,
-                    _ => SharedFolderMemberPolicy::Other,
+                    _ => SharedFolderMemberPolicy::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -1235,7 +1296,10 @@ impl ::serde::ser::Serialize for SharedFolderMemberPolicy {
                 s.serialize_field(".tag", "anyone")?;
                 s.end()
             }
-            SharedFolderMemberPolicy::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            SharedFolderMemberPolicy::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -1258,8 +1322,9 @@ pub enum SharedLinkCreatePolicy {
     /// shared links unless overridden.
     DefaultNoOne,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for SharedLinkCreatePolicy {
@@ -1282,7 +1347,7 @@ impl<'de> ::serde::de::Deserialize<'de> for SharedLinkCreatePolicy {
                     "default_team_only" => SharedLinkCreatePolicy::DefaultTeamOnly,
                     "team_only" => SharedLinkCreatePolicy::TeamOnly,
                     "default_no_one" => SharedLinkCreatePolicy::DefaultNoOne,
-                    _ => SharedLinkCreatePolicy::Other,
+                    _ => SharedLinkCreatePolicy::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -1326,7 +1391,10 @@ impl ::serde::ser::Serialize for SharedLinkCreatePolicy {
                 s.serialize_field(".tag", "default_no_one")?;
                 s.end()
             }
-            SharedLinkCreatePolicy::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            SharedLinkCreatePolicy::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -1339,8 +1407,9 @@ pub enum ShowcaseDownloadPolicy {
     /// Allow files to be downloaded from Showcases.
     Enabled,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for ShowcaseDownloadPolicy {
@@ -1361,7 +1430,7 @@ impl<'de> ::serde::de::Deserialize<'de> for ShowcaseDownloadPolicy {
                 let value = match tag {
                     "disabled" => ShowcaseDownloadPolicy::Disabled,
                     "enabled" => ShowcaseDownloadPolicy::Enabled,
-                    _ => ShowcaseDownloadPolicy::Other,
+                    _ => ShowcaseDownloadPolicy::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -1391,7 +1460,10 @@ impl ::serde::ser::Serialize for ShowcaseDownloadPolicy {
                 s.serialize_field(".tag", "enabled")?;
                 s.end()
             }
-            ShowcaseDownloadPolicy::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            ShowcaseDownloadPolicy::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -1404,8 +1476,9 @@ pub enum ShowcaseEnabledPolicy {
     /// Showcase is enabled.
     Enabled,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for ShowcaseEnabledPolicy {
@@ -1426,7 +1499,7 @@ impl<'de> ::serde::de::Deserialize<'de> for ShowcaseEnabledPolicy {
                 let value = match tag {
                     "disabled" => ShowcaseEnabledPolicy::Disabled,
                     "enabled" => ShowcaseEnabledPolicy::Enabled,
-                    _ => ShowcaseEnabledPolicy::Other,
+                    _ => ShowcaseEnabledPolicy::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -1456,7 +1529,10 @@ impl ::serde::ser::Serialize for ShowcaseEnabledPolicy {
                 s.serialize_field(".tag", "enabled")?;
                 s.end()
             }
-            ShowcaseEnabledPolicy::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            ShowcaseEnabledPolicy::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -1469,8 +1545,9 @@ pub enum ShowcaseExternalSharingPolicy {
     /// Allow showcases to be shared with people not on the team.
     Enabled,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for ShowcaseExternalSharingPolicy {
@@ -1491,7 +1568,7 @@ impl<'de> ::serde::de::Deserialize<'de> for ShowcaseExternalSharingPolicy {
                 let value = match tag {
                     "disabled" => ShowcaseExternalSharingPolicy::Disabled,
                     "enabled" => ShowcaseExternalSharingPolicy::Enabled,
-                    _ => ShowcaseExternalSharingPolicy::Other,
+                    _ => ShowcaseExternalSharingPolicy::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -1521,7 +1598,10 @@ impl ::serde::ser::Serialize for ShowcaseExternalSharingPolicy {
                 s.serialize_field(".tag", "enabled")?;
                 s.end()
             }
-            ShowcaseExternalSharingPolicy::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            ShowcaseExternalSharingPolicy::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -1534,8 +1614,9 @@ pub enum SmartSyncPolicy {
     /// The specified content will be synced as on-demand files by default.
     OnDemand,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for SmartSyncPolicy {
@@ -1556,7 +1637,7 @@ impl<'de> ::serde::de::Deserialize<'de> for SmartSyncPolicy {
                 let value = match tag {
                     "local" => SmartSyncPolicy::Local,
                     "on_demand" => SmartSyncPolicy::OnDemand,
-                    _ => SmartSyncPolicy::Other,
+                    _ => SmartSyncPolicy::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -1586,7 +1667,10 @@ impl ::serde::ser::Serialize for SmartSyncPolicy {
                 s.serialize_field(".tag", "on_demand")?;
                 s.end()
             }
-            SmartSyncPolicy::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            SmartSyncPolicy::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -1599,8 +1683,9 @@ pub enum SmarterSmartSyncPolicyState {
     /// Smarter Smart Sync feature is enabled.
     Enabled,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for SmarterSmartSyncPolicyState {
@@ -1621,7 +1706,7 @@ impl<'de> ::serde::de::Deserialize<'de> for SmarterSmartSyncPolicyState {
                 let value = match tag {
                     "disabled" => SmarterSmartSyncPolicyState::Disabled,
                     "enabled" => SmarterSmartSyncPolicyState::Enabled,
-                    _ => SmarterSmartSyncPolicyState::Other,
+                    _ => SmarterSmartSyncPolicyState::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -1651,7 +1736,10 @@ impl ::serde::ser::Serialize for SmarterSmartSyncPolicyState {
                 s.serialize_field(".tag", "enabled")?;
                 s.end()
             }
-            SmarterSmartSyncPolicyState::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            SmarterSmartSyncPolicyState::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -1666,8 +1754,9 @@ pub enum SsoPolicy {
     /// Users will be required to sign in with their single sign-on credentials.
     Required,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for SsoPolicy {
@@ -1689,7 +1778,7 @@ impl<'de> ::serde::de::Deserialize<'de> for SsoPolicy {
                     "disabled" => SsoPolicy::Disabled,
                     "optional" => SsoPolicy::Optional,
                     "required" => SsoPolicy::Required,
-                    _ => SsoPolicy::Other,
+                    _ => SsoPolicy::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -1726,7 +1815,10 @@ impl ::serde::ser::Serialize for SsoPolicy {
                 s.serialize_field(".tag", "required")?;
                 s.end()
             }
-            SsoPolicy::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            SsoPolicy::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -1739,8 +1831,9 @@ pub enum SuggestMembersPolicy {
     /// Suggest members is enabled.
     Enabled,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for SuggestMembersPolicy {
@@ -1761,7 +1854,7 @@ impl<'de> ::serde::de::Deserialize<'de> for SuggestMembersPolicy {
                 let value = match tag {
                     "disabled" => SuggestMembersPolicy::Disabled,
                     "enabled" => SuggestMembersPolicy::Enabled,
-                    _ => SuggestMembersPolicy::Other,
+                    _ => SuggestMembersPolicy::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -1791,7 +1884,10 @@ impl ::serde::ser::Serialize for SuggestMembersPolicy {
                 s.serialize_field(".tag", "enabled")?;
                 s.end()
             }
-            SuggestMembersPolicy::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            SuggestMembersPolicy::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -1854,34 +1950,11 @@ impl TeamMemberPolicies {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "sharing" => {
-                    if field_sharing.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("sharing"));
-                    }
-                    field_sharing = Some(map.next_value()?);
-                }
-                "emm_state" => {
-                    if field_emm_state.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("emm_state"));
-                    }
-                    field_emm_state = Some(map.next_value()?);
-                }
-                "office_addin" => {
-                    if field_office_addin.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("office_addin"));
-                    }
-                    field_office_addin = Some(map.next_value()?);
-                }
-                "suggest_members_policy" => {
-                    if field_suggest_members_policy.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("suggest_members_policy"));
-                    }
-                    field_suggest_members_policy = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "sharing" => crate::deserialize_field(&mut map, &mut field_sharing, "sharing")?,
+                "emm_state" => crate::deserialize_field(&mut map, &mut field_emm_state, "emm_state")?,
+                "office_addin" => crate::deserialize_field(&mut map, &mut field_office_addin, "office_addin")?,
+                "suggest_members_policy" => crate::deserialize_field(&mut map, &mut field_suggest_members_policy, "suggest_members_policy")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -1996,40 +2069,12 @@ impl TeamSharingPolicies {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "shared_folder_member_policy" => {
-                    if field_shared_folder_member_policy.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("shared_folder_member_policy"));
-                    }
-                    field_shared_folder_member_policy = Some(map.next_value()?);
-                }
-                "shared_folder_join_policy" => {
-                    if field_shared_folder_join_policy.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("shared_folder_join_policy"));
-                    }
-                    field_shared_folder_join_policy = Some(map.next_value()?);
-                }
-                "shared_link_create_policy" => {
-                    if field_shared_link_create_policy.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("shared_link_create_policy"));
-                    }
-                    field_shared_link_create_policy = Some(map.next_value()?);
-                }
-                "group_creation_policy" => {
-                    if field_group_creation_policy.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("group_creation_policy"));
-                    }
-                    field_group_creation_policy = Some(map.next_value()?);
-                }
-                "shared_folder_link_restriction_policy" => {
-                    if field_shared_folder_link_restriction_policy.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("shared_folder_link_restriction_policy"));
-                    }
-                    field_shared_folder_link_restriction_policy = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "shared_folder_member_policy" => crate::deserialize_field(&mut map, &mut field_shared_folder_member_policy, "shared_folder_member_policy")?,
+                "shared_folder_join_policy" => crate::deserialize_field(&mut map, &mut field_shared_folder_join_policy, "shared_folder_join_policy")?,
+                "shared_link_create_policy" => crate::deserialize_field(&mut map, &mut field_shared_link_create_policy, "shared_link_create_policy")?,
+                "group_creation_policy" => crate::deserialize_field(&mut map, &mut field_group_creation_policy, "group_creation_policy")?,
+                "shared_folder_link_restriction_policy" => crate::deserialize_field(&mut map, &mut field_shared_folder_link_restriction_policy, "shared_folder_link_restriction_policy")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -2095,8 +2140,9 @@ pub enum TwoStepVerificationPolicy {
     /// Disabled require two factor authorization.
     RequireTfaDisable,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for TwoStepVerificationPolicy {
@@ -2117,7 +2163,7 @@ impl<'de> ::serde::de::Deserialize<'de> for TwoStepVerificationPolicy {
                 let value = match tag {
                     "require_tfa_enable" => TwoStepVerificationPolicy::RequireTfaEnable,
                     "require_tfa_disable" => TwoStepVerificationPolicy::RequireTfaDisable,
-                    _ => TwoStepVerificationPolicy::Other,
+                    _ => TwoStepVerificationPolicy::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -2147,7 +2193,10 @@ impl ::serde::ser::Serialize for TwoStepVerificationPolicy {
                 s.serialize_field(".tag", "require_tfa_disable")?;
                 s.end()
             }
-            TwoStepVerificationPolicy::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            TwoStepVerificationPolicy::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -2162,8 +2211,9 @@ pub enum TwoStepVerificationState {
     /// Disabled require two factor authorization.
     Disabled,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for TwoStepVerificationState {
@@ -2185,7 +2235,7 @@ impl<'de> ::serde::de::Deserialize<'de> for TwoStepVerificationState {
                     "required" => TwoStepVerificationState::Required,
                     "optional" => TwoStepVerificationState::Optional,
                     "disabled" => TwoStepVerificationState::Disabled,
-                    _ => TwoStepVerificationState::Other,
+                    _ => TwoStepVerificationState::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -2222,7 +2272,10 @@ impl ::serde::ser::Serialize for TwoStepVerificationState {
                 s.serialize_field(".tag", "disabled")?;
                 s.end()
             }
-            TwoStepVerificationState::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            TwoStepVerificationState::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
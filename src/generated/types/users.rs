@@ -12,6 +12,17 @@
 
 pub type GetAccountBatchResult = Vec<BasicAccount>;
 
+/// The names of this namespace's routes, as they appear in request URLs after the
+/// [`Endpoint`](crate::client_trait_common::Endpoint) base URL, for referring to them in logs and
+/// metrics without spelling them out.
+pub mod routes {
+    pub const FEATURES_GET_VALUES: &str = "users/features/get_values";
+    pub const GET_ACCOUNT: &str = "users/get_account";
+    pub const GET_ACCOUNT_BATCH: &str = "users/get_account_batch";
+    pub const GET_CURRENT_ACCOUNT: &str = "users/get_current_account";
+    pub const GET_SPACE_USAGE: &str = "users/get_space_usage";
+}
+
 /// The amount of detail revealed about an account depends on the user being queried and the user
 /// making the query.
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -83,46 +94,13 @@ impl Account {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "account_id" => {
-                    if field_account_id.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("account_id"));
-                    }
-                    field_account_id = Some(map.next_value()?);
-                }
-                "name" => {
-                    if field_name.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("name"));
-                    }
-                    field_name = Some(map.next_value()?);
-                }
-                "email" => {
-                    if field_email.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("email"));
-                    }
-                    field_email = Some(map.next_value()?);
-                }
-                "email_verified" => {
-                    if field_email_verified.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("email_verified"));
-                    }
-                    field_email_verified = Some(map.next_value()?);
-                }
-                "disabled" => {
-                    if field_disabled.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("disabled"));
-                    }
-                    field_disabled = Some(map.next_value()?);
-                }
-                "profile_photo_url" => {
-                    if field_profile_photo_url.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("profile_photo_url"));
-                    }
-                    field_profile_photo_url = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "account_id" => crate::deserialize_field(&mut map, &mut field_account_id, "account_id")?,
+                "name" => crate::deserialize_field(&mut map, &mut field_name, "name")?,
+                "email" => crate::deserialize_field(&mut map, &mut field_email, "email")?,
+                "email_verified" => crate::deserialize_field(&mut map, &mut field_email_verified, "email_verified")?,
+                "disabled" => crate::deserialize_field(&mut map, &mut field_disabled, "disabled")?,
+                "profile_photo_url" => crate::deserialize_field(&mut map, &mut field_profile_photo_url, "profile_photo_url")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -272,58 +250,15 @@ impl BasicAccount {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "account_id" => {
-                    if field_account_id.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("account_id"));
-                    }
-                    field_account_id = Some(map.next_value()?);
-                }
-                "name" => {
-                    if field_name.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("name"));
-                    }
-                    field_name = Some(map.next_value()?);
-                }
-                "email" => {
-                    if field_email.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("email"));
-                    }
-                    field_email = Some(map.next_value()?);
-                }
-                "email_verified" => {
-                    if field_email_verified.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("email_verified"));
-                    }
-                    field_email_verified = Some(map.next_value()?);
-                }
-                "disabled" => {
-                    if field_disabled.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("disabled"));
-                    }
-                    field_disabled = Some(map.next_value()?);
-                }
-                "is_teammate" => {
-                    if field_is_teammate.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("is_teammate"));
-                    }
-                    field_is_teammate = Some(map.next_value()?);
-                }
-                "profile_photo_url" => {
-                    if field_profile_photo_url.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("profile_photo_url"));
-                    }
-                    field_profile_photo_url = Some(map.next_value()?);
-                }
-                "team_member_id" => {
-                    if field_team_member_id.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("team_member_id"));
-                    }
-                    field_team_member_id = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "account_id" => crate::deserialize_field(&mut map, &mut field_account_id, "account_id")?,
+                "name" => crate::deserialize_field(&mut map, &mut field_name, "name")?,
+                "email" => crate::deserialize_field(&mut map, &mut field_email, "email")?,
+                "email_verified" => crate::deserialize_field(&mut map, &mut field_email_verified, "email_verified")?,
+                "disabled" => crate::deserialize_field(&mut map, &mut field_disabled, "disabled")?,
+                "is_teammate" => crate::deserialize_field(&mut map, &mut field_is_teammate, "is_teammate")?,
+                "profile_photo_url" => crate::deserialize_field(&mut map, &mut field_profile_photo_url, "profile_photo_url")?,
+                "team_member_id" => crate::deserialize_field(&mut map, &mut field_team_member_id, "team_member_id")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -413,8 +348,9 @@ pub enum FileLockingValue {
     /// others.
     Enabled(bool),
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for FileLockingValue {
@@ -440,7 +376,7 @@ impl<'de> ::serde::de::Deserialize<'de> for FileLockingValue {
                             _ => return Err(de::Error::unknown_field(tag, VARIANTS))
                         }
                     }
-                    _ => FileLockingValue::Other,
+                    _ => FileLockingValue::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -464,7 +400,10 @@ impl ::serde::ser::Serialize for FileLockingValue {
                 s.serialize_field("enabled", x)?;
                 s.end()
             }
-            FileLockingValue::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            FileLockingValue::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -602,94 +541,21 @@ impl FullAccount {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "account_id" => {
-                    if field_account_id.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("account_id"));
-                    }
-                    field_account_id = Some(map.next_value()?);
-                }
-                "name" => {
-                    if field_name.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("name"));
-                    }
-                    field_name = Some(map.next_value()?);
-                }
-                "email" => {
-                    if field_email.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("email"));
-                    }
-                    field_email = Some(map.next_value()?);
-                }
-                "email_verified" => {
-                    if field_email_verified.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("email_verified"));
-                    }
-                    field_email_verified = Some(map.next_value()?);
-                }
-                "disabled" => {
-                    if field_disabled.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("disabled"));
-                    }
-                    field_disabled = Some(map.next_value()?);
-                }
-                "locale" => {
-                    if field_locale.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("locale"));
-                    }
-                    field_locale = Some(map.next_value()?);
-                }
-                "referral_link" => {
-                    if field_referral_link.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("referral_link"));
-                    }
-                    field_referral_link = Some(map.next_value()?);
-                }
-                "is_paired" => {
-                    if field_is_paired.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("is_paired"));
-                    }
-                    field_is_paired = Some(map.next_value()?);
-                }
-                "account_type" => {
-                    if field_account_type.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("account_type"));
-                    }
-                    field_account_type = Some(map.next_value()?);
-                }
-                "root_info" => {
-                    if field_root_info.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("root_info"));
-                    }
-                    field_root_info = Some(map.next_value()?);
-                }
-                "profile_photo_url" => {
-                    if field_profile_photo_url.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("profile_photo_url"));
-                    }
-                    field_profile_photo_url = Some(map.next_value()?);
-                }
-                "country" => {
-                    if field_country.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("country"));
-                    }
-                    field_country = Some(map.next_value()?);
-                }
-                "team" => {
-                    if field_team.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("team"));
-                    }
-                    field_team = Some(map.next_value()?);
-                }
-                "team_member_id" => {
-                    if field_team_member_id.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("team_member_id"));
-                    }
-                    field_team_member_id = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "account_id" => crate::deserialize_field(&mut map, &mut field_account_id, "account_id")?,
+                "name" => crate::deserialize_field(&mut map, &mut field_name, "name")?,
+                "email" => crate::deserialize_field(&mut map, &mut field_email, "email")?,
+                "email_verified" => crate::deserialize_field(&mut map, &mut field_email_verified, "email_verified")?,
+                "disabled" => crate::deserialize_field(&mut map, &mut field_disabled, "disabled")?,
+                "locale" => crate::deserialize_field(&mut map, &mut field_locale, "locale")?,
+                "referral_link" => crate::deserialize_field(&mut map, &mut field_referral_link, "referral_link")?,
+                "is_paired" => crate::deserialize_field(&mut map, &mut field_is_paired, "is_paired")?,
+                "account_type" => crate::deserialize_field(&mut map, &mut field_account_type, "account_type")?,
+                "root_info" => crate::deserialize_field(&mut map, &mut field_root_info, "root_info")?,
+                "profile_photo_url" => crate::deserialize_field(&mut map, &mut field_profile_photo_url, "profile_photo_url")?,
+                "country" => crate::deserialize_field(&mut map, &mut field_country, "country")?,
+                "team" => crate::deserialize_field(&mut map, &mut field_team, "team")?,
+                "team_member_id" => crate::deserialize_field(&mut map, &mut field_team_member_id, "team_member_id")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -839,34 +705,11 @@ impl FullTeam {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "id" => {
-                    if field_id.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("id"));
-                    }
-                    field_id = Some(map.next_value()?);
-                }
-                "name" => {
-                    if field_name.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("name"));
-                    }
-                    field_name = Some(map.next_value()?);
-                }
-                "sharing_policies" => {
-                    if field_sharing_policies.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("sharing_policies"));
-                    }
-                    field_sharing_policies = Some(map.next_value()?);
-                }
-                "office_addin_policy" => {
-                    if field_office_addin_policy.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("office_addin_policy"));
-                    }
-                    field_office_addin_policy = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "id" => crate::deserialize_field(&mut map, &mut field_id, "id")?,
+                "name" => crate::deserialize_field(&mut map, &mut field_name, "name")?,
+                "sharing_policies" => crate::deserialize_field(&mut map, &mut field_sharing_policies, "sharing_policies")?,
+                "office_addin_policy" => crate::deserialize_field(&mut map, &mut field_office_addin_policy, "office_addin_policy")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -963,16 +806,8 @@ impl GetAccountArg {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "account_id" => {
-                    if field_account_id.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("account_id"));
-                    }
-                    field_account_id = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "account_id" => crate::deserialize_field(&mut map, &mut field_account_id, "account_id")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -1054,16 +889,8 @@ impl GetAccountBatchArg {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "account_ids" => {
-                    if field_account_ids.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("account_ids"));
-                    }
-                    field_account_ids = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "account_ids" => crate::deserialize_field(&mut map, &mut field_account_ids, "account_ids")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -1120,8 +947,9 @@ pub enum GetAccountBatchError {
     /// [`GetAccountBatchArg::account_ids`](GetAccountBatchArg) that does not exist.
     NoAccount(crate::types::users_common::AccountId),
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for GetAccountBatchError {
@@ -1147,7 +975,7 @@ impl<'de> ::serde::de::Deserialize<'de> for GetAccountBatchError {
                             _ => return Err(de::Error::unknown_field(tag, VARIANTS))
                         }
                     }
-                    _ => GetAccountBatchError::Other,
+                    _ => GetAccountBatchError::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -1171,7 +999,10 @@ impl ::serde::ser::Serialize for GetAccountBatchError {
                 s.serialize_field("no_account", x)?;
                 s.end()
             }
-            GetAccountBatchError::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            GetAccountBatchError::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -1194,8 +1025,9 @@ pub enum GetAccountError {
     /// The specified [`GetAccountArg::account_id`](GetAccountArg) does not exist.
     NoAccount,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for GetAccountError {
@@ -1215,7 +1047,7 @@ impl<'de> ::serde::de::Deserialize<'de> for GetAccountError {
                 };
                 let value = match tag {
                     "no_account" => GetAccountError::NoAccount,
-                    _ => GetAccountError::Other,
+                    _ => GetAccountError::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -1238,7 +1070,10 @@ impl ::serde::ser::Serialize for GetAccountError {
                 s.serialize_field(".tag", "no_account")?;
                 s.end()
             }
-            GetAccountError::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            GetAccountError::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -1284,16 +1119,8 @@ impl IndividualSpaceAllocation {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "allocated" => {
-                    if field_allocated.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("allocated"));
-                    }
-                    field_allocated = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "allocated" => crate::deserialize_field(&mut map, &mut field_allocated, "allocated")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -1403,40 +1230,12 @@ impl Name {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "given_name" => {
-                    if field_given_name.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("given_name"));
-                    }
-                    field_given_name = Some(map.next_value()?);
-                }
-                "surname" => {
-                    if field_surname.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("surname"));
-                    }
-                    field_surname = Some(map.next_value()?);
-                }
-                "familiar_name" => {
-                    if field_familiar_name.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("familiar_name"));
-                    }
-                    field_familiar_name = Some(map.next_value()?);
-                }
-                "display_name" => {
-                    if field_display_name.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("display_name"));
-                    }
-                    field_display_name = Some(map.next_value()?);
-                }
-                "abbreviated_name" => {
-                    if field_abbreviated_name.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("abbreviated_name"));
-                    }
-                    field_abbreviated_name = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "given_name" => crate::deserialize_field(&mut map, &mut field_given_name, "given_name")?,
+                "surname" => crate::deserialize_field(&mut map, &mut field_surname, "surname")?,
+                "familiar_name" => crate::deserialize_field(&mut map, &mut field_familiar_name, "familiar_name")?,
+                "display_name" => crate::deserialize_field(&mut map, &mut field_display_name, "display_name")?,
+                "abbreviated_name" => crate::deserialize_field(&mut map, &mut field_abbreviated_name, "abbreviated_name")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -1504,8 +1303,9 @@ pub enum PaperAsFilesValue {
     /// via the /paper endpoints.
     Enabled(bool),
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for PaperAsFilesValue {
@@ -1531,7 +1331,7 @@ impl<'de> ::serde::de::Deserialize<'de> for PaperAsFilesValue {
                             _ => return Err(de::Error::unknown_field(tag, VARIANTS))
                         }
                     }
-                    _ => PaperAsFilesValue::Other,
+                    _ => PaperAsFilesValue::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -1555,7 +1355,10 @@ impl ::serde::ser::Serialize for PaperAsFilesValue {
                 s.serialize_field("enabled", x)?;
                 s.end()
             }
-            PaperAsFilesValue::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            PaperAsFilesValue::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -1569,8 +1372,9 @@ pub enum SpaceAllocation {
     /// The user shares space with other members of their team.
     Team(TeamSpaceAllocation),
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for SpaceAllocation {
@@ -1591,7 +1395,7 @@ impl<'de> ::serde::de::Deserialize<'de> for SpaceAllocation {
                 let value = match tag {
                     "individual" => SpaceAllocation::Individual(IndividualSpaceAllocation::internal_deserialize(&mut map)?),
                     "team" => SpaceAllocation::Team(TeamSpaceAllocation::internal_deserialize(&mut map)?),
-                    _ => SpaceAllocation::Other,
+                    _ => SpaceAllocation::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -1623,7 +1427,10 @@ impl ::serde::ser::Serialize for SpaceAllocation {
                 x.internal_serialize::<S>(&mut s)?;
                 s.end()
             }
-            SpaceAllocation::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            SpaceAllocation::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -1666,22 +1473,9 @@ impl SpaceUsage {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "used" => {
-                    if field_used.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("used"));
-                    }
-                    field_used = Some(map.next_value()?);
-                }
-                "allocation" => {
-                    if field_allocation.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("allocation"));
-                    }
-                    field_allocation = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "used" => crate::deserialize_field(&mut map, &mut field_used, "used")?,
+                "allocation" => crate::deserialize_field(&mut map, &mut field_allocation, "allocation")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -1771,22 +1565,9 @@ impl Team {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "id" => {
-                    if field_id.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("id"));
-                    }
-                    field_id = Some(map.next_value()?);
-                }
-                "name" => {
-                    if field_name.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("name"));
-                    }
-                    field_name = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "id" => crate::deserialize_field(&mut map, &mut field_id, "id")?,
+                "name" => crate::deserialize_field(&mut map, &mut field_name, "name")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -1897,40 +1678,12 @@ impl TeamSpaceAllocation {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "used" => {
-                    if field_used.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("used"));
-                    }
-                    field_used = Some(map.next_value()?);
-                }
-                "allocated" => {
-                    if field_allocated.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("allocated"));
-                    }
-                    field_allocated = Some(map.next_value()?);
-                }
-                "user_within_team_space_allocated" => {
-                    if field_user_within_team_space_allocated.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("user_within_team_space_allocated"));
-                    }
-                    field_user_within_team_space_allocated = Some(map.next_value()?);
-                }
-                "user_within_team_space_limit_type" => {
-                    if field_user_within_team_space_limit_type.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("user_within_team_space_limit_type"));
-                    }
-                    field_user_within_team_space_limit_type = Some(map.next_value()?);
-                }
-                "user_within_team_space_used_cached" => {
-                    if field_user_within_team_space_used_cached.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("user_within_team_space_used_cached"));
-                    }
-                    field_user_within_team_space_used_cached = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "used" => crate::deserialize_field(&mut map, &mut field_used, "used")?,
+                "allocated" => crate::deserialize_field(&mut map, &mut field_allocated, "allocated")?,
+                "user_within_team_space_allocated" => crate::deserialize_field(&mut map, &mut field_user_within_team_space_allocated, "user_within_team_space_allocated")?,
+                "user_within_team_space_limit_type" => crate::deserialize_field(&mut map, &mut field_user_within_team_space_limit_type, "user_within_team_space_limit_type")?,
+                "user_within_team_space_used_cached" => crate::deserialize_field(&mut map, &mut field_user_within_team_space_used_cached, "user_within_team_space_used_cached")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -1997,8 +1750,9 @@ pub enum UserFeature {
     /// This feature allows users to lock files in order to restrict other users from editing them.
     FileLocking,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for UserFeature {
@@ -2019,7 +1773,7 @@ impl<'de> ::serde::de::Deserialize<'de> for UserFeature {
                 let value = match tag {
                     "paper_as_files" => UserFeature::PaperAsFiles,
                     "file_locking" => UserFeature::FileLocking,
-                    _ => UserFeature::Other,
+                    _ => UserFeature::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -2049,7 +1803,10 @@ impl ::serde::ser::Serialize for UserFeature {
                 s.serialize_field(".tag", "file_locking")?;
                 s.end()
             }
-            UserFeature::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            UserFeature::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -2061,8 +1818,9 @@ pub enum UserFeatureValue {
     PaperAsFiles(PaperAsFilesValue),
     FileLocking(FileLockingValue),
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for UserFeatureValue {
@@ -2095,7 +1853,7 @@ impl<'de> ::serde::de::Deserialize<'de> for UserFeatureValue {
                             _ => return Err(de::Error::unknown_field(tag, VARIANTS))
                         }
                     }
-                    _ => UserFeatureValue::Other,
+                    _ => UserFeatureValue::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -2127,7 +1885,10 @@ impl ::serde::ser::Serialize for UserFeatureValue {
                 s.serialize_field("file_locking", x)?;
                 s.end()
             }
-            UserFeatureValue::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            UserFeatureValue::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -2165,16 +1926,8 @@ impl UserFeaturesGetValuesBatchArg {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "features" => {
-                    if field_features.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("features"));
-                    }
-                    field_features = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "features" => crate::deserialize_field(&mut map, &mut field_features, "features")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
@@ -2231,8 +1984,9 @@ pub enum UserFeaturesGetValuesBatchError {
     /// [`UserFeaturesGetValuesBatchArg`](UserFeaturesGetValuesBatchArg).features list.
     EmptyFeaturesList,
     /// Catch-all used for unrecognized values returned from the server. Encountering this value
-    /// typically indicates that this SDK version is out of date.
-    Other,
+    /// typically indicates that this SDK version is out of date. It holds the value as it was
+    /// received, and serializes back to it unchanged.
+    Other(crate::UnknownVariant),
 }
 
 impl<'de> ::serde::de::Deserialize<'de> for UserFeaturesGetValuesBatchError {
@@ -2252,7 +2006,7 @@ impl<'de> ::serde::de::Deserialize<'de> for UserFeaturesGetValuesBatchError {
                 };
                 let value = match tag {
                     "empty_features_list" => UserFeaturesGetValuesBatchError::EmptyFeaturesList,
-                    _ => UserFeaturesGetValuesBatchError::Other,
+                    _ => UserFeaturesGetValuesBatchError::Other(crate::UnknownVariant::deserialize_fields(tag, &mut map)?),
                 };
                 crate::eat_json_fields(&mut map)?;
                 Ok(value)
@@ -2275,7 +2029,10 @@ impl ::serde::ser::Serialize for UserFeaturesGetValuesBatchError {
                 s.serialize_field(".tag", "empty_features_list")?;
                 s.end()
             }
-            UserFeaturesGetValuesBatchError::Other => Err(::serde::ser::Error::custom("cannot serialize 'Other' variant"))
+            UserFeaturesGetValuesBatchError::Other(ref x) => {
+                // catch-all
+                ::serde::ser::Serialize::serialize(x, serializer)
+            }
         }
     }
 }
@@ -2320,16 +2077,8 @@ impl UserFeaturesGetValuesBatchResult {
         while let Some(key) = map.next_key::<&str>()? {
             nothing = false;
             match key {
-                "values" => {
-                    if field_values.is_some() {
-                        return Err(::serde::de::Error::duplicate_field("values"));
-                    }
-                    field_values = Some(map.next_value()?);
-                }
-                _ => {
-                    // unknown field allowed and ignored
-                    map.next_value::<::serde_json::Value>()?;
-                }
+                "values" => crate::deserialize_field(&mut map, &mut field_values, "values")?,
+                _ => crate::eat_json_field(&mut map)?,
             }
         }
         if optional && nothing {
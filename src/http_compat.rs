@@ -0,0 +1,239 @@
+//! Adapters between the client traits and the types of the [`http`] crate.
+//!
+//! Most Rust HTTP stacks, such as hyper and tower, take an [`http::Request`] and return an
+//! [`http::Response`]. To make one of them into an
+//! [`HttpClient`](crate::async_client_trait::HttpClient), use an [`http::request::Builder`] as its
+//! `Request` type (it implements [`HttpRequest`]), start requests with [`new_request`], and
+//! convert them with [`build_request`] and [`async_response`]. For example:
+//!
+//! ```ignore
+//! impl HttpClient for MyClient {
+//!     type Request = http::request::Builder;
+//!
+//!     async fn execute(&self, request: Self::Request, body: Bytes)
+//!         -> Result<HttpRequestResultRaw, dropbox_sdk::Error>
+//!     {
+//!         let request = http_compat::build_request(request, body)?;
+//!         let response = self.inner.call(request).await
+//!             .map_err(|e| dropbox_sdk::Error::HttpClient(Box::new(e)))?;
+//!         http_compat::async_response(response.map(|body| my_body_to_async_read(body)))
+//!     }
+//!
+//!     fn new_request(&self, url: &str) -> Self::Request {
+//!         http_compat::new_request(url)
+//!     }
+//! }
+//! ```
+//!
+//! Sync clients do the same, using [`sync_response`].
+//!
+//! For calls which don't need authentication, such as obtaining OAuth2 tokens with
+//! [`Authorization::obtain_access_token_async`](crate::oauth2::Authorization::obtain_access_token_async),
+//! a [`FnClient`] makes a [`NoauthClient`](crate::async_client_trait::NoauthClient) out of just a
+//! function which sends an [`http::Request`]:
+//!
+//! ```ignore
+//! let client = http_compat::FnClient::new(|request| async move {
+//!     let response = my_http_stack.send(request).await?;
+//!     Ok::<_, MyError>(response.map(|body| body.to_vec()))
+//! });
+//! let token = auth.obtain_access_token_async(client).await?;
+//! ```
+
+use std::future::Future;
+use std::io::Read;
+use std::time::Duration;
+use bytes::Bytes;
+use futures::{AsyncRead, TryFutureExt};
+use crate::async_client_trait::{HttpClient, HttpRequestResultRaw, NoauthClient};
+use crate::client_trait_common::HttpRequest;
+use crate::Error;
+
+/// How long to wait for a request to complete, if it should be longer than the client's usual
+/// timeout. This is stored in the request's [extensions](http::Request::extensions) by
+/// [`HttpRequest::set_timeout`], and clients should apply it if it's there; see [`timeout`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Timeout(pub Duration);
+
+impl HttpRequest for http::request::Builder {
+    fn set_header(self, name: &str, value: &str) -> Self {
+        self.header(name, value)
+    }
+
+    fn set_timeout(self, timeout: Duration) -> Self {
+        self.extension(Timeout(timeout))
+    }
+}
+
+/// Start building a request to the given URL. All API requests are POST requests.
+pub fn new_request(url: &str) -> http::request::Builder {
+    http::Request::post(url)
+}
+
+/// Finish building a request, with the given body.
+///
+/// This fails if any of the headers set on the builder were invalid.
+pub fn build_request(builder: http::request::Builder, body: Bytes)
+    -> Result<http::Request<Bytes>, Error>
+{
+    builder.body(body).map_err(|e| Error::HttpClient(Box::new(e)))
+}
+
+/// The timeout set on a request with [`HttpRequest::set_timeout`], if any.
+pub fn timeout<B>(request: &http::Request<B>) -> Option<Duration> {
+    request.extensions().get::<Timeout>().map(|t| t.0)
+}
+
+/// Convert a response, with its body as an [`AsyncRead`], for returning from
+/// [`async_client_trait::HttpClient::execute`](crate::async_client_trait::HttpClient::execute).
+///
+/// This fails if the response's `Dropbox-API-Result` or `Content-Length` header is invalid.
+pub fn async_response<R>(response: http::Response<R>)
+    -> Result<HttpRequestResultRaw, Error>
+where
+    R: AsyncRead + Send + Unpin + 'static,
+{
+    let (parts, body) = response.into_parts();
+    let headers = Headers::new(&parts.headers)?;
+    Ok(HttpRequestResultRaw {
+        status: parts.status.as_u16(),
+        result_header: headers.result,
+        content_length: headers.content_length,
+        retry_after: headers.retry_after,
+        content_range: headers.content_range,
+        content_disposition: headers.content_disposition,
+        request_id: headers.request_id,
+        body: Box::new(body),
+    })
+}
+
+/// Convert a response, with its body as a [`Read`], for returning from
+/// [`client_trait::HttpClient::execute`](crate::client_trait::HttpClient::execute).
+///
+/// This fails if the response's `Dropbox-API-Result` or `Content-Length` header is invalid.
+pub fn sync_response<R>(response: http::Response<R>)
+    -> Result<crate::client_trait::HttpRequestResultRaw, Error>
+where
+    R: Read + Send + 'static,
+{
+    let (parts, body) = response.into_parts();
+    let headers = Headers::new(&parts.headers)?;
+    Ok(crate::client_trait::HttpRequestResultRaw {
+        status: parts.status.as_u16(),
+        result_header: headers.result,
+        content_length: headers.content_length,
+        retry_after: headers.retry_after,
+        content_range: headers.content_range,
+        content_disposition: headers.content_disposition,
+        request_id: headers.request_id,
+        body: Box::new(body),
+    })
+}
+
+/// A [`NoauthClient`] which sends requests by calling a function.
+///
+/// The function takes the request and returns a future of the complete response, or an error if
+/// it couldn't be sent. The response body is read from memory, so this is meant for calls with
+/// small responses, such as obtaining OAuth2 tokens; to download files, implement
+/// [`HttpClient`] instead, using the other functions in this module. The function should apply
+/// the request's [`timeout`], if it has one.
+pub struct FnClient<F> {
+    send: F,
+}
+
+impl<F> FnClient<F> {
+    /// Make a client which sends requests with the given function.
+    pub fn new(send: F) -> Self {
+        Self { send }
+    }
+}
+
+impl<F> std::fmt::Debug for FnClient<F> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("FnClient").finish_non_exhaustive()
+    }
+}
+
+impl<F, Fut, B, E> HttpClient for FnClient<F>
+where
+    F: Fn(http::Request<Bytes>) -> Fut + Sync,
+    Fut: Future<Output = Result<http::Response<B>, E>> + Send,
+    B: AsRef<[u8]> + Send + Unpin + 'static,
+    E: std::error::Error + Send + Sync + 'static,
+{
+    type Request = http::request::Builder;
+
+    fn execute(&self, request: Self::Request, body: Bytes)
+        -> impl Future<Output = Result<HttpRequestResultRaw, Error>> + Send
+    {
+        let response = build_request(request, body).map(|request| {
+            (self.send)(request).map_err(|e| Error::HttpClient(Box::new(e)))
+        });
+        async move {
+            let response = response?.await?;
+            async_response(response.map(futures::io::Cursor::new))
+        }
+    }
+
+    fn new_request(&self, url: &str) -> Self::Request {
+        new_request(url)
+    }
+}
+
+impl<F, Fut, B, E> NoauthClient for FnClient<F>
+where
+    F: Fn(http::Request<Bytes>) -> Fut + Sync,
+    Fut: Future<Output = Result<http::Response<B>, E>> + Send,
+    B: AsRef<[u8]> + Send + Unpin + 'static,
+    E: std::error::Error + Send + Sync + 'static,
+{}
+
+/// The response headers which the SDK uses.
+struct Headers {
+    result: Option<String>,
+    content_length: Option<u64>,
+    retry_after: Option<String>,
+    content_range: Option<String>,
+    content_disposition: Option<String>,
+    request_id: Option<String>,
+}
+
+impl Headers {
+    fn new(headers: &http::HeaderMap) -> Result<Self, Error> {
+        let result = headers.get("Dropbox-API-Result")
+            .map(|v| v.to_str().map(ToOwned::to_owned))
+            .transpose()
+            .map_err(|e| unexpected(e, "invalid Dropbox-API-Result header"))?;
+        let content_length = headers.get(http::header::CONTENT_LENGTH)
+            .map(|v| {
+                v.to_str()
+                    .map_err(|e| unexpected(e, "invalid Content-Length"))
+                    .and_then(|s| s.parse().map_err(|e| unexpected(e, "invalid Content-Length")))
+            })
+            .transpose()?;
+        let retry_after = headers.get(http::header::RETRY_AFTER)
+            .and_then(|v| v.to_str().ok())
+            .map(ToOwned::to_owned);
+        let content_range = headers.get(http::header::CONTENT_RANGE)
+            .and_then(|v| v.to_str().ok())
+            .map(ToOwned::to_owned);
+        let content_disposition = headers.get(http::header::CONTENT_DISPOSITION)
+            .and_then(|v| v.to_str().ok())
+            .map(ToOwned::to_owned);
+        let request_id = headers.get("X-Dropbox-Request-Id")
+            .and_then(|v| v.to_str().ok())
+            .map(ToOwned::to_owned);
+        Ok(Self {
+            result,
+            content_length,
+            retry_after,
+            content_range,
+            content_disposition,
+            request_id,
+        })
+    }
+}
+
+fn unexpected(e: impl std::fmt::Display, msg: &str) -> Error {
+    Error::UnexpectedResponse(format!("{msg}: {e}"))
+}
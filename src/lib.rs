@@ -56,6 +56,61 @@ pub(crate) mod client_helpers;
 
 pub mod oauth2;
 
+pub mod prelude;
+
+pub mod timestamp;
+
+pub mod content_hash;
+
+pub mod clock;
+
+pub mod retry;
+
+pub mod pagination;
+
+pub mod deprecation;
+
+if_feature! { "dbx_files", pub mod files_helpers; }
+
+#[cfg(all(feature = "dbx_files", feature = "unstable"))]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "dbx_files", feature = "unstable"))))]
+pub mod paper_helpers;
+
+if_feature! { "dbx_file_requests", pub mod file_requests_helpers; }
+
+if_feature! { "dbx_sharing", pub mod sharing_helpers; }
+
+if_feature! { "dbx_team", pub mod team_helpers; }
+
+if_feature! { "dbx_team_log", pub mod team_log_helpers; }
+
+if_feature! { "dbx_users", pub mod users_helpers; }
+
+#[cfg(any(feature = "dbx_sharing", feature = "dbx_team"))]
+mod async_job;
+
+if_feature! { "dispatch", pub mod dispatch; }
+
+if_feature! { "http", pub mod http_compat; }
+
+if_feature! { "body_logging", pub mod body_logging; }
+
+if_feature! { "compact_serde", pub mod compact_serde; }
+
+if_feature! { "reports", pub mod reports; }
+
+if_feature! { "fake", pub mod fake; }
+
+if_feature! { "blocking", pub mod blocking; }
+
+if_feature! { "mirror", pub mod mirror; }
+
+if_feature! { "disk_cache", pub mod disk_cache; }
+
+if_feature! { "zip", pub mod zip_download; }
+
+if_feature! { "unicode-normalization", pub mod unicode_paths; }
+
 mod generated;
 
 // You need to run the Stone generator to create this module.
@@ -69,5 +124,17 @@ pub use generated::async_routes::*;
 #[cfg(feature = "sync_routes_in_root")]
 pub use generated::sync_routes::*;
 
+if_feature! { "sync_routes", pub mod sync; }
+
+if_feature! { "async_routes", pub mod asynch; }
+
 mod error;
-pub use error::{BoxedError, Error, NoError};
+
+mod unknown_variant;
+pub use unknown_variant::UnknownVariant;
+
+if_feature! { "miette", pub mod diagnostic; }
+pub use error::{
+    BackoffScope, BoxedApiError, BoxedError, Error, NoError, PolicyError, RateLimitCategory,
+    WRITE_CONTENTION_RETRY_AFTER,
+};
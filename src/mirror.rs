@@ -0,0 +1,307 @@
+//! Mirroring a local directory to a Dropbox folder, by watching it for changes.
+//!
+//! [`Mirror::run`] watches the local directory and applies each change to the Dropbox folder:
+//! created and modified files are uploaded, renames are done with `files/move_v2`, and deletions
+//! with `files/delete_v2`. Changes are collected until the directory has been quiet for a short
+//! while, so that a file being written in several steps is only uploaded once.
+//!
+//! Only changes made while the mirror is running are applied; it doesn't do an initial sync of
+//! the directory's existing contents.
+//!
+//! This is only built with the `mirror` Cargo feature.
+
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+use futures::channel::mpsc;
+use futures::future::{self, Either};
+use futures::StreamExt;
+use notify::event::{MetadataKind, ModifyKind, RenameMode};
+use notify::{EventKind, RecursiveMode, Watcher};
+use crate::async_client_trait::UserAuthClient;
+use crate::files_helpers::{self, UploadOptions};
+use crate::generated::async_routes::files;
+
+/// An error which stops a [`Mirror`].
+///
+/// Errors applying individual changes are logged, and don't stop the mirror.
+#[derive(thiserror::Error, Debug)]
+pub enum MirrorError {
+    /// The local directory couldn't be watched.
+    #[error("error watching the local directory: {0}")]
+    Watch(#[from] notify::Error),
+
+    /// An I/O error, such as the local directory not existing.
+    #[error("I/O error: {0}")]
+    Io(#[from] std::io::Error),
+}
+
+/// Continuously mirrors changes in a local directory to a Dropbox folder.
+#[derive(Debug, Clone)]
+pub struct Mirror {
+    local_root: PathBuf,
+    remote_root: String,
+    debounce: Duration,
+    upload_options: UploadOptions,
+    #[cfg(feature = "unicode-normalization")]
+    normalize_paths: bool,
+}
+
+impl Mirror {
+    /// Mirror the given local directory to the given Dropbox folder path, such as `"/Backup"`.
+    pub fn new(local_root: impl Into<PathBuf>, remote_root: impl Into<String>) -> Self {
+        Self {
+            local_root: local_root.into(),
+            remote_root: remote_root.into().trim_end_matches('/').to_owned(),
+            debounce: Duration::from_millis(500),
+            upload_options: UploadOptions::default(),
+            #[cfg(feature = "unicode-normalization")]
+            normalize_paths: false,
+        }
+    }
+
+    /// Set how long the directory has to be quiet before changes are applied. The default is half
+    /// a second.
+    pub fn with_debounce(mut self, debounce: Duration) -> Self {
+        self.debounce = debounce;
+        self
+    }
+
+    /// Set the options used for uploading files.
+    pub fn with_upload_options(mut self, options: UploadOptions) -> Self {
+        self.upload_options = options;
+        self
+    }
+
+    /// Set whether to convert local file names to Unicode NFC form in the Dropbox paths they're
+    /// mirrored to. The default is false.
+    ///
+    /// This is useful on macOS, whose file names are in NFD form, so that files keep the same
+    /// names as when they're created on other systems. See [`crate::unicode_paths`].
+    #[cfg(feature = "unicode-normalization")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "unicode-normalization")))]
+    pub fn with_normalize_paths(mut self, value: bool) -> Self {
+        self.normalize_paths = value;
+        self
+    }
+
+    /// Watch the local directory and mirror changes until an error occurs, or the returned future
+    /// is dropped.
+    ///
+    /// This needs an executor which supports timers, and it does blocking I/O when reading files.
+    pub async fn run(&self, client: &impl UserAuthClient) -> Result<(), MirrorError> {
+        let local_root = std::fs::canonicalize(&self.local_root)?;
+        let (tx, mut rx) = mpsc::unbounded();
+        let mut watcher = notify::recommended_watcher(move |event| {
+            // This only fails if the receiver is gone, in which case we're done anyway.
+            let _ = tx.unbounded_send(event);
+        })?;
+        watcher.watch(&local_root, RecursiveMode::Recursive)?;
+        info!("mirroring {} to {}", local_root.display(), self.remote_root);
+
+        let mut pending = PendingChanges::default();
+        // The sender is owned by the watcher, which lives as long as this loop.
+        while let Some(event) = rx.next().await {
+            pending.record(event?);
+
+            // Keep collecting events until none arrive for the debounce period.
+            while let Either::Left((Some(event), _)) =
+                future::select(rx.next(), futures_timer::Delay::new(self.debounce)).await
+            {
+                pending.record(event?);
+            }
+
+            for (path, change) in pending.take() {
+                self.apply(client, &local_root, &path, change).await;
+            }
+        }
+        Ok(())
+    }
+
+    async fn apply(&self, client: &impl UserAuthClient, local_root: &Path, path: &Path, change: Change) {
+        let Some(remote) = self.remote_path(local_root, path) else {
+            return;
+        };
+        match change {
+            Change::Upload => self.upload(client, local_root, path).await,
+            Change::Delete => {
+                debug!("deleting {remote}");
+                let arg = files::DeleteArg::new(remote.clone());
+                match files::delete_v2(client, &arg).await {
+                    Ok(_) => (),
+                    Err(crate::Error::Api(files::DeleteError::PathLookup(files::LookupError::NotFound))) => {
+                        debug!("{remote} was already deleted");
+                    }
+                    Err(e) => warn!("failed to delete {remote}: {e}"),
+                }
+            }
+            Change::MoveFrom(from) => {
+                let Some(from_remote) = self.remote_path(local_root, &from) else {
+                    return self.upload(client, local_root, path).await;
+                };
+                debug!("moving {from_remote} to {remote}");
+                let arg = files::RelocationArg::new(from_remote.clone(), remote.clone());
+                if let Err(e) = files::move_v2(client, &arg).await {
+                    // Most likely the source was never uploaded; upload the destination instead.
+                    warn!("failed to move {from_remote} to {remote}, uploading instead: {e}");
+                    self.upload(client, local_root, path).await;
+                }
+            }
+        }
+    }
+
+    /// Upload a file, or create a folder and upload everything in it.
+    async fn upload(&self, client: &impl UserAuthClient, local_root: &Path, path: &Path) {
+        let Some(remote) = self.remote_path(local_root, path) else {
+            return;
+        };
+        let mut to_upload = vec![(path.to_owned(), remote)];
+        while let Some((path, remote)) = to_upload.pop() {
+            let metadata = match std::fs::metadata(&path) {
+                Ok(metadata) => metadata,
+                Err(e) => {
+                    // Probably deleted again since the event; there'll be another event for that.
+                    debug!("skipping {}: {e}", path.display());
+                    continue;
+                }
+            };
+
+            if metadata.is_dir() {
+                debug!("creating folder {remote}");
+                let arg = files::CreateFolderArg::new(remote.clone());
+                match files::create_folder_v2(client, &arg).await {
+                    Ok(_) => (),
+                    Err(crate::Error::Api(files::CreateFolderError::Path(
+                        files::WriteError::Conflict(files::WriteConflictError::Folder)))) => (),
+                    Err(e) => {
+                        warn!("failed to create folder {remote}: {e}");
+                        continue;
+                    }
+                }
+                match std::fs::read_dir(&path) {
+                    Ok(entries) => {
+                        for entry in entries.flatten() {
+                            let child = entry.path();
+                            if let Some(child_remote) = self.remote_path(local_root, &child) {
+                                to_upload.push((child, child_remote));
+                            }
+                        }
+                    }
+                    Err(e) => warn!("failed to list {}: {e}", path.display()),
+                }
+            } else {
+                debug!("uploading {} to {remote}", path.display());
+                let commit = files::CommitInfo::new(remote.clone())
+                    .with_mode(files::WriteMode::Overwrite);
+                if let Err(e) = files_helpers::upload_file(client, &path, commit, &self.upload_options).await {
+                    warn!("failed to upload {} to {remote}: {e}", path.display());
+                }
+            }
+        }
+    }
+
+    /// The Dropbox path corresponding to a local path.
+    fn remote_path(&self, local_root: &Path, path: &Path) -> Option<String> {
+        let relative = path.strip_prefix(local_root).ok()?;
+        let mut remote = self.remote_root.clone();
+        for component in relative.components() {
+            match component.as_os_str().to_str() {
+                Some(name) => {
+                    remote.push('/');
+                    remote.push_str(name);
+                }
+                None => {
+                    warn!("skipping {}: not valid UTF-8", path.display());
+                    return None;
+                }
+            }
+        }
+        #[cfg(feature = "unicode-normalization")]
+        if self.normalize_paths {
+            remote = crate::unicode_paths::normalize_path(&remote).into_owned();
+        }
+        // The root itself can't be uploaded or moved.
+        if remote.len() > self.remote_root.len() {
+            Some(remote)
+        } else {
+            None
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Change {
+    Upload,
+    Delete,
+    /// Moved to this path from the given path.
+    MoveFrom(PathBuf),
+}
+
+/// Changes waiting to be applied, with only the latest change for any path, in the order they
+/// happened.
+#[derive(Debug, Default)]
+struct PendingChanges {
+    changes: Vec<(PathBuf, Change)>,
+}
+
+impl PendingChanges {
+    fn record(&mut self, event: notify::Event) {
+        trace!("{event:?}");
+        let mut paths = event.paths.into_iter();
+        match event.kind {
+            EventKind::Create(_)
+            | EventKind::Modify(ModifyKind::Any | ModifyKind::Data(_) | ModifyKind::Other)
+            | EventKind::Modify(ModifyKind::Metadata(MetadataKind::WriteTime))
+            | EventKind::Modify(ModifyKind::Name(RenameMode::To)) => {
+                for path in paths {
+                    self.set(path, Change::Upload);
+                }
+            }
+            EventKind::Remove(_) | EventKind::Modify(ModifyKind::Name(RenameMode::From)) => {
+                for path in paths {
+                    self.set(path, Change::Delete);
+                }
+            }
+            EventKind::Modify(ModifyKind::Name(RenameMode::Both)) => {
+                if let (Some(from), Some(to)) = (paths.next(), paths.next()) {
+                    self.rename(from, to);
+                }
+            }
+            EventKind::Modify(ModifyKind::Name(_)) => {
+                // Some platforms don't say which side of a rename a path is on.
+                for path in paths {
+                    let change = if path.exists() { Change::Upload } else { Change::Delete };
+                    self.set(path, change);
+                }
+            }
+            EventKind::Any | EventKind::Access(_) | EventKind::Other
+            | EventKind::Modify(ModifyKind::Metadata(_)) => (),
+        }
+    }
+
+    fn set(&mut self, path: PathBuf, change: Change) {
+        self.remove(&path);
+        self.changes.push((path, change));
+    }
+
+    fn remove(&mut self, path: &Path) -> Option<Change> {
+        let idx = self.changes.iter().position(|(p, _)| p == path)?;
+        Some(self.changes.remove(idx).1)
+    }
+
+    fn rename(&mut self, from: PathBuf, to: PathBuf) {
+        self.remove(&to);
+        let change = match self.remove(&from) {
+            // Not uploaded yet, so upload it at the new path instead.
+            Some(Change::Upload) => Change::Upload,
+            // Moved twice; just move it once.
+            Some(Change::MoveFrom(original)) => Change::MoveFrom(original),
+            // Some platforms report each side of a rename separately as well as together.
+            Some(Change::Delete) | None => Change::MoveFrom(from),
+        };
+        self.changes.push((to, change));
+    }
+
+    fn take(&mut self) -> Vec<(PathBuf, Change)> {
+        std::mem::take(&mut self.changes)
+    }
+}
@@ -15,7 +15,11 @@
 use std::env;
 use std::io::{self, Write};
 use std::sync::Arc;
-use async_lock::RwLock;
+use std::time::{Duration, SystemTime};
+use async_lock::Mutex;
+use bytes::Bytes;
+use futures::future::BoxFuture;
+use futures::FutureExt;
 use base64::Engine;
 use base64::engine::general_purpose::{URL_SAFE, URL_SAFE_NO_PAD};
 use ring::rand::{SecureRandom, SystemRandom};
@@ -23,8 +27,9 @@ use url::form_urlencoded::Serializer as UrlEncoder;
 use url::Url;
 use crate::Error;
 use crate::async_client_trait::NoauthClient;
-use crate::client_helpers::{parse_response, prepare_request};
+use crate::client_helpers::{deserialize_error, parse_response, prepare_request};
 use crate::client_trait_common::{Endpoint, ParamsType, Style};
+use crate::types::auth::AuthError;
 
 /// Which type of OAuth2 flow to use.
 #[derive(Debug, Clone)]
@@ -121,6 +126,106 @@ impl PkceCode {
     }
 }
 
+/// A random `state` value for an authorization request, which protects against cross-site request
+/// forgery (CSRF).
+///
+/// Generate one for each authorization, pass it to [`AuthorizeUrlBuilder::csrf_token`], and keep it
+/// with the user's session (for example, by saving its [`secret`](Self::secret) and
+/// [`expires_at`](Self::expires_at) server-side, and restoring it with
+/// [`from_parts`](Self::from_parts)). When the user is redirected back, [`verify`](Self::verify)
+/// the `state` parameter of the redirect (see [`parse_redirect_url`]) against it before using the
+/// code, so that a redirect which was started by someone else, on another device, is rejected.
+#[derive(Clone)]
+pub struct CsrfToken {
+    secret: String,
+    expires_at: Option<SystemTime>,
+}
+
+impl CsrfToken {
+    /// Generate a new random token, which doesn't expire.
+    #[allow(clippy::new_without_default)]
+    pub fn new() -> Self {
+        let mut bytes = [0u8; 32];
+        SystemRandom::new().fill(&mut bytes).expect("failed to get random bytes for CSRF token");
+        Self {
+            secret: URL_SAFE_NO_PAD.encode(bytes),
+            expires_at: None,
+        }
+    }
+
+    /// Make the token expire after the given amount of time from now, after which it no longer
+    /// verifies. This limits how long a user can take to authorize the app.
+    pub fn with_expiry(mut self, lifetime: Duration) -> Self {
+        self.expires_at = Some(SystemTime::now() + lifetime);
+        self
+    }
+
+    /// Restore a token from its secret and expiry time, as previously saved.
+    pub fn from_parts(secret: String, expires_at: Option<SystemTime>) -> Self {
+        Self { secret, expires_at }
+    }
+
+    /// The value sent as the `state` parameter. Keep this private to the user's session.
+    pub fn secret(&self) -> &str {
+        &self.secret
+    }
+
+    /// When the token expires, if it does.
+    pub fn expires_at(&self) -> Option<SystemTime> {
+        self.expires_at
+    }
+
+    /// Check the `state` parameter given to the redirect URI against this token.
+    ///
+    /// The comparison takes the same time wherever the values differ, so it doesn't reveal how
+    /// much of the secret a forged value got right.
+    pub fn verify(&self, state: Option<&str>) -> Result<(), CsrfError> {
+        let state = state.ok_or(CsrfError::Missing)?;
+        if self.expires_at.map_or(false, |t| t <= SystemTime::now()) {
+            return Err(CsrfError::Expired);
+        }
+        // Compare hashes of the values, so that their lengths don't matter either.
+        let expected = ring::digest::digest(&ring::digest::SHA256, self.secret.as_bytes());
+        let actual = ring::digest::digest(&ring::digest::SHA256, state.as_bytes());
+        let diff = expected.as_ref().iter()
+            .zip(actual.as_ref())
+            .fold(0u8, |diff, (a, b)| diff | (a ^ b));
+        if diff == 0 {
+            Ok(())
+        } else {
+            Err(CsrfError::Mismatch)
+        }
+    }
+}
+
+// Not derived, to keep the secret out of logs.
+impl std::fmt::Debug for CsrfToken {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CsrfToken")
+            .field("secret", &"<redacted>")
+            .field("expires_at", &self.expires_at)
+            .finish()
+    }
+}
+
+/// Why a redirect's `state` parameter failed [verification](CsrfToken::verify).
+#[derive(thiserror::Error, Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum CsrfError {
+    /// The redirect didn't have a `state` parameter.
+    #[error("the authorization redirect has no state parameter")]
+    Missing,
+
+    /// The `state` parameter doesn't match the token, so the redirect wasn't for an authorization
+    /// which this session started.
+    #[error("the authorization redirect's state parameter doesn't match")]
+    Mismatch,
+
+    /// The token expired before the user was redirected back.
+    #[error("the authorization took too long, and its CSRF token has expired")]
+    Expired,
+}
+
 /// Builds a URL that can be given to the user to visit to have Dropbox authorize your app.
 ///
 /// If this app is a server-side app, you should redirect the user's browser to this URL to begin
@@ -199,11 +304,20 @@ impl<'a> AuthorizeUrlBuilder<'a> {
 
     /// Up to 500 bytes of arbitrary data that will be passed back to your redirect URI. This
     /// parameter should be used to protect against cross-site request forgery (CSRF).
+    ///
+    /// To generate and check a random value, use [`csrf_token`](Self::csrf_token) instead.
     pub fn state(mut self, value: &'a str) -> Self {
         self.state = Some(value);
         self
     }
 
+    /// Set the `state` parameter to the given token's secret, to [verify](CsrfToken::verify) when
+    /// the user is redirected back. This replaces any [`state`](Self::state) set before.
+    pub fn csrf_token(mut self, token: &'a CsrfToken) -> Self {
+        self.state = Some(token.secret());
+        self
+    }
+
     /// If this parameter is specified, the user will be asked to authorize with a particular type
     /// of Dropbox account, either `work` for a team account or `personal` for a personal account.
     /// Your app should still verify the type of Dropbox account after authorization since the user
@@ -277,6 +391,143 @@ impl<'a> AuthorizeUrlBuilder<'a> {
     }
 }
 
+/// What the user was redirected back to your redirect URI with, from [`parse_redirect_url`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum AuthRedirect {
+    /// An authorization code, from the [`Oauth2Type::AuthorizationCode`] or [`Oauth2Type::PKCE`]
+    /// flows, given in the query string. Pass it to [`Authorization::from_auth_code`] to get a
+    /// token.
+    Code {
+        /// The authorization code.
+        code: String,
+
+        /// The `state` given to the [`AuthorizeUrlBuilder`], if any.
+        state: Option<String>,
+    },
+
+    /// An access token, from the [`Oauth2Type::ImplicitGrant`] flow, given in the fragment.
+    Token(ImplicitGrantToken),
+}
+
+impl AuthRedirect {
+    /// The `state` given to the [`AuthorizeUrlBuilder`], if any, to be checked with
+    /// [`CsrfToken::verify`].
+    pub fn state(&self) -> Option<&str> {
+        match self {
+            AuthRedirect::Code { state, .. } => state.as_deref(),
+            AuthRedirect::Token(token) => token.state.as_deref(),
+        }
+    }
+}
+
+/// The access token and related parameters from an [`Oauth2Type::ImplicitGrant`] redirect.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct ImplicitGrantToken {
+    /// The access token.
+    pub access_token: String,
+
+    /// The type of token, which is always `bearer`.
+    pub token_type: Option<String>,
+
+    /// How many seconds the token is valid for, if it expires.
+    pub expires_in: Option<u64>,
+
+    /// The scopes the token has, separated by spaces.
+    pub scope: Option<String>,
+
+    /// The ID of the user's account, for a user token.
+    pub account_id: Option<String>,
+
+    /// The ID of the team, for a team token.
+    pub team_id: Option<String>,
+
+    /// The legacy numeric ID of the user.
+    pub uid: Option<String>,
+
+    /// The `state` given to the [`AuthorizeUrlBuilder`], if any.
+    pub state: Option<String>,
+}
+
+/// Why a redirect URL couldn't be parsed by [`parse_redirect_url`].
+#[derive(thiserror::Error, Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum RedirectError {
+    /// The URL isn't valid.
+    #[error("invalid redirect URL: {0}")]
+    InvalidUrl(#[from] url::ParseError),
+
+    /// The authorization didn't succeed, usually because the user declined it. The `error` is an
+    /// OAuth2 error code, such as `access_denied`.
+    #[error("authorization failed: {error}{}",
+        description.as_ref().map(|d| format!(": {d}")).unwrap_or_default())]
+    Failed {
+        /// The OAuth2 error code.
+        error: String,
+
+        /// A description of the error, for developers.
+        description: Option<String>,
+
+        /// The `state` given to the [`AuthorizeUrlBuilder`], if any.
+        state: Option<String>,
+    },
+
+    /// The URL has neither an authorization code nor an access token.
+    #[error("the redirect URL has no authorization code or access token")]
+    Missing,
+}
+
+/// Get the authorization code or access token from the URL which the user was redirected back to
+/// after authorizing the app.
+///
+/// The URL can be absolute, or just the path onwards, as web frameworks often give it. Codes are
+/// looked for in the query string, and implicit grant tokens in the fragment; if the user declined
+/// the authorization, the error from whichever one has it is returned.
+///
+/// This doesn't check the `state`; use [`CsrfToken::verify`] on [`AuthRedirect::state`] for that.
+pub fn parse_redirect_url(url: &str) -> Result<AuthRedirect, RedirectError> {
+    let url = match Url::parse(url) {
+        Err(url::ParseError::RelativeUrlWithoutBase) => {
+            Url::parse("http://localhost/").unwrap().join(url)?
+        }
+        result => result?,
+    };
+    let query = url.query_pairs().into_owned().collect::<Vec<_>>();
+    let fragment = url::form_urlencoded::parse(url.fragment().unwrap_or_default().as_bytes())
+        .into_owned()
+        .collect::<Vec<_>>();
+    let get = |params: &[(String, String)], name: &str| params.iter()
+        .find(|(k, _)| k == name)
+        .map(|(_, v)| v.clone());
+
+    for params in [&query, &fragment].iter().copied() {
+        if let Some(error) = get(params, "error") {
+            return Err(RedirectError::Failed {
+                error,
+                description: get(params, "error_description"),
+                state: get(params, "state"),
+            });
+        }
+    }
+    if let Some(code) = get(&query, "code") {
+        return Ok(AuthRedirect::Code { code, state: get(&query, "state") });
+    }
+    if let Some(access_token) = get(&fragment, "access_token") {
+        return Ok(AuthRedirect::Token(ImplicitGrantToken {
+            access_token,
+            token_type: get(&fragment, "token_type"),
+            expires_in: get(&fragment, "expires_in").and_then(|s| s.parse().ok()),
+            scope: get(&fragment, "scope"),
+            account_id: get(&fragment, "account_id"),
+            team_id: get(&fragment, "team_id"),
+            uid: get(&fragment, "uid"),
+            state: get(&fragment, "state"),
+        }));
+    }
+    Err(RedirectError::Missing)
+}
+
 /// [`Authorization`] is a state-machine.
 ///
 /// Every flow starts with the `InitialAuth` state, which is just after the user authorizes the app
@@ -288,6 +539,10 @@ impl<'a> AuthorizeUrlBuilder<'a> {
 /// `AccessToken` contains just the access token itself, which is either a long-lived access token
 /// not expected to expire, or a short-lived token which, if it expires, cannot be refreshed except
 /// by starting the authorization flow over again.
+///
+/// `ShortLivedToken` contains a short-lived token obtained without a refresh token or client
+/// secret, such as by the implicit grant flow. It is kept apart from `AccessToken` so that it isn't
+/// mistaken for a legacy long-lived token.
 #[derive(Debug, Clone)]
 enum AuthorizationState {
     InitialAuth {
@@ -303,6 +558,9 @@ enum AuthorizationState {
         client_secret: Option<String>,
         token: String,
     },
+    ShortLivedToken {
+        token: String,
+    },
 }
 
 /// Provides for continuing authorization of the app.
@@ -311,6 +569,124 @@ pub struct Authorization {
     /// Dropbox app key
     pub client_id: String,
     state: AuthorizationState,
+    metadata: AuthorizationMetadata,
+}
+
+/// Details about an [`Authorization`], recorded when a token is obtained, which are useful for
+/// diagnosing problems with it later.
+///
+/// These are all optional, as authorizations saved in the older formats or created from a token
+/// don't have them, and not every token response includes all of them.
+#[derive(Debug, Clone, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[non_exhaustive]
+pub struct AuthorizationMetadata {
+    /// The scopes granted to the app, if the token response listed them.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub scopes: Option<Vec<String>>,
+
+    /// The ID of the authorized user's account.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub account_id: Option<String>,
+
+    /// The ID of the authorized team, for apps with team scopes.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub team_id: Option<String>,
+
+    /// The legacy numeric ID of the authorized user.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub uid: Option<String>,
+
+    /// When the most recent access token was obtained, as a timestamp in the format described in
+    /// [`crate::timestamp`].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub obtained_at: Option<String>,
+
+    /// When the most recent access token expires, as a timestamp in the format described in
+    /// [`crate::timestamp`].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expires_at: Option<String>,
+}
+
+impl AuthorizationMetadata {
+    /// Record the details from an OAuth2 token response.
+    fn update(&mut self, response: &serde_json::Map<String, serde_json::Value>) {
+        let string = |key: &str| response.get(key).and_then(|v| v.as_str()).map(str::to_owned);
+        let now = std::time::SystemTime::now();
+        if let Some(scope) = string("scope") {
+            self.scopes = Some(scope.split_whitespace().map(str::to_owned).collect());
+        }
+        if let Some(account_id) = string("account_id") {
+            self.account_id = Some(account_id);
+        }
+        if let Some(team_id) = string("team_id") {
+            self.team_id = Some(team_id);
+        }
+        if let Some(uid) = string("uid") {
+            self.uid = Some(uid);
+        }
+        self.obtained_at = Some(crate::timestamp::from_system_time(now));
+        self.expires_at = response.get("expires_in")
+            .and_then(|v| v.as_u64())
+            .map(|secs| crate::timestamp::from_system_time(now + Duration::from_secs(secs)));
+    }
+}
+
+/// The authorized account isn't the expected one, from [`Authorization::check_account`].
+#[derive(thiserror::Error, Debug, Clone, PartialEq, Eq)]
+#[error("authorized account {} is not the expected account {expected}",
+    actual.as_deref().unwrap_or("(unknown)"))]
+pub struct AccountMismatch {
+    /// The expected account or team ID.
+    pub expected: String,
+
+    /// The account or team ID which was authorized, if known.
+    pub actual: Option<String>,
+}
+
+/// The token of a [`SavedAuthorization`].
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum SavedToken {
+    /// A refresh token, used to obtain short-lived access tokens.
+    Refresh {
+        /// The refresh token.
+        refresh_token: String,
+    },
+
+    /// A legacy long-lived access token.
+    LongLivedAccessToken {
+        /// The access token.
+        access_token: String,
+    },
+}
+
+/// The saved state of an [`Authorization`], for apps which prefer to store it as JSON (or some
+/// other format supported by Serde) rather than as the string produced by
+/// [`Authorization::save`].
+///
+/// Like the saved string, this doesn't include the client ID or client secret, which are needed
+/// again to load it.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[non_exhaustive]
+pub struct SavedAuthorization {
+    /// The token needed to continue the authorization.
+    #[serde(flatten)]
+    pub token: SavedToken,
+
+    /// Details about the authorization, for diagnostics.
+    #[serde(flatten)]
+    pub metadata: AuthorizationMetadata,
+}
+
+impl SavedAuthorization {
+    /// Save a token, with no metadata.
+    pub fn new(token: SavedToken) -> Self {
+        Self {
+            token,
+            metadata: AuthorizationMetadata::default(),
+        }
+    }
 }
 
 impl Authorization {
@@ -334,6 +710,44 @@ impl Authorization {
         Self {
             client_id,
             state: AuthorizationState::InitialAuth { flow_type, auth_code, redirect_uri },
+            metadata: AuthorizationMetadata::default(),
+        }
+    }
+
+    /// Get the details recorded about this authorization, such as the scopes granted to it.
+    pub fn metadata(&self) -> &AuthorizationMetadata {
+        &self.metadata
+    }
+
+    /// The ID of the authorized user's account, if the token response included it.
+    pub fn account_id(&self) -> Option<&str> {
+        self.metadata.account_id.as_deref()
+    }
+
+    /// The ID of the authorized team, if the token response included it.
+    pub fn team_id(&self) -> Option<&str> {
+        self.metadata.team_id.as_deref()
+    }
+
+    /// The legacy numeric ID of the authorized user, if the token response included it.
+    pub fn uid(&self) -> Option<&str> {
+        self.metadata.uid.as_deref()
+    }
+
+    /// Check that the authorized account is the expected one, for apps which know which account
+    /// should be authorized, such as when re-authorizing it.
+    ///
+    /// `expected` is an account ID, or a team ID for apps with team scopes. This should be called
+    /// after obtaining a token; it fails if the token response didn't say which account it was
+    /// for, as well as if it was for a different one.
+    pub fn check_account(&self, expected: &str) -> Result<(), AccountMismatch> {
+        if self.account_id() == Some(expected) || self.team_id() == Some(expected) {
+            Ok(())
+        } else {
+            Err(AccountMismatch {
+                expected: expected.to_owned(),
+                actual: self.account_id().or(self.team_id()).map(str::to_owned),
+            })
         }
     }
 
@@ -341,20 +755,51 @@ impl Authorization {
     ///
     /// Returns `None` if the state cannot be saved (e.g. authorization has not completed getting a
     /// token yet).
+    ///
+    /// This doesn't include the [metadata](Authorization::metadata), so that the string can still
+    /// be loaded by older versions of this SDK. Use
+    /// [`save_with_metadata`](Authorization::save_with_metadata) to include it.
     pub fn save(&self) -> Option<String> {
-        match &self.state {
+        match self.saved()?.token {
+            SavedToken::LongLivedAccessToken { access_token } => Some(format!("1&{}", access_token)),
+            SavedToken::Refresh { refresh_token } => Some(format!("2&{}", refresh_token)),
+        }
+    }
+
+    /// Save the authorization state and its [metadata](Authorization::metadata) to a string which
+    /// can be reloaded later by [`load`](Authorization::load).
+    ///
+    /// Returns `None` if the state cannot be saved, like [`save`](Authorization::save). The string
+    /// can only be loaded by versions of this SDK which support the metadata.
+    pub fn save_with_metadata(&self) -> Option<String> {
+        let json = serde_json::to_string(&self.saved()?).expect("failed to serialize authorization");
+        Some(format!("3&{}", json))
+    }
+
+    /// Get the authorization state and its [metadata](Authorization::metadata) in a form which can
+    /// be serialized, and then loaded later by [`from_saved`](Authorization::from_saved).
+    ///
+    /// Returns `None` if the state cannot be saved, like [`save`](Authorization::save).
+    pub fn saved(&self) -> Option<SavedAuthorization> {
+        let token = match &self.state {
             AuthorizationState::AccessToken { token, client_secret } if client_secret.is_none() => {
                 // Legacy long-lived access token.
-                Some(format!("1&{}", token))
+                SavedToken::LongLivedAccessToken { access_token: token.clone() }
+            },
+            AuthorizationState::ShortLivedToken { token } => {
+                // Saved the same way, to be used until it expires.
+                SavedToken::LongLivedAccessToken { access_token: token.clone() }
             },
             AuthorizationState::Refresh { refresh_token, .. } => {
-                Some(format!("2&{}", refresh_token))
+                SavedToken::Refresh { refresh_token: refresh_token.clone() }
             },
-            _ => None,
-        }
+            _ => return None,
+        };
+        Some(SavedAuthorization { token, metadata: self.metadata.clone() })
     }
 
-    /// Reload a saved authorization state produced by [`save`](Authorization::save).
+    /// Reload a saved authorization state produced by [`save`](Authorization::save) or
+    /// [`save_with_metadata`](Authorization::save_with_metadata).
     ///
     /// Returns `None` if the string could not be recognized. In this case, you should start the
     /// authorization procedure from scratch.
@@ -362,18 +807,49 @@ impl Authorization {
     /// Note that a loaded authorization state is not necessarily still valid and may produce
     /// [`Authentication`](crate::Error::Authentication) errors. In such a case you should also
     /// start the authorization procedure from scratch.
+    ///
+    /// With the `deny_legacy_tokens` Cargo feature, saved long-lived access tokens are refused, and
+    /// `None` is returned for them as well.
     pub fn load(client_id: String, saved: &str) -> Option<Self> {
-        Some(match saved.get(0..2) {
-            Some("1&") => {
-                #[allow(deprecated)]
-                Self::from_long_lived_access_token(saved[2..].to_owned())
+        let saved = match saved.get(0..2) {
+            Some("1&") => SavedAuthorization::new(
+                SavedToken::LongLivedAccessToken { access_token: saved[2..].to_owned() }),
+            Some("2&") => SavedAuthorization::new(
+                SavedToken::Refresh { refresh_token: saved[2..].to_owned() }),
+            Some("3&") => match serde_json::from_str(&saved[2..]) {
+                Ok(saved) => saved,
+                Err(e) => {
+                    error!("invalid saved Authorization: {}", e);
+                    return None;
+                }
             },
-            Some("2&") => Self::from_refresh_token(client_id, saved[2..].to_owned()),
             _ => {
                 error!("unrecognized saved Authorization representation: {:?}", saved);
                 return None;
             }
-        })
+        };
+        if cfg!(feature = "deny_legacy_tokens")
+            && matches!(saved.token, SavedToken::LongLivedAccessToken { .. })
+        {
+            error!("refusing to load a long-lived access token, due to deny_legacy_tokens");
+            return None;
+        }
+        Some(Self::from_saved(client_id, saved))
+    }
+
+    /// Reload a saved authorization state produced by [`saved`](Authorization::saved).
+    ///
+    /// The same caveats as for [`load`](Authorization::load) apply.
+    pub fn from_saved(client_id: String, saved: SavedAuthorization) -> Self {
+        let mut auth = match saved.token {
+            SavedToken::LongLivedAccessToken { access_token } => {
+                #[allow(deprecated)]
+                Self::from_long_lived_access_token(access_token)
+            },
+            SavedToken::Refresh { refresh_token } => Self::from_refresh_token(client_id, refresh_token),
+        };
+        auth.metadata = saved.metadata;
+        auth
     }
 
     /// Recreate the authorization from a refresh token obtained using the [`Oauth2Type::PKCE`]
@@ -388,6 +864,7 @@ impl Authorization {
                 refresh_token,
                 client_secret: None,
             },
+            metadata: AuthorizationMetadata::default(),
         }
     }
 
@@ -404,6 +881,7 @@ impl Authorization {
                 refresh_token,
                 client_secret: Some(client_secret),
             },
+            metadata: AuthorizationMetadata::default(),
         }
     }
 
@@ -413,6 +891,9 @@ impl Authorization {
     ///
     /// Long-lived tokens are deprecated and the ability to generate them will be removed in the
     /// future.
+    ///
+    /// With the `deny_legacy_tokens` Cargo feature, obtaining a token from an authorization created
+    /// this way always fails, as does [`load`](Authorization::load)ing a saved long-lived token.
     #[deprecated]
     pub fn from_long_lived_access_token(
         access_token: String,
@@ -420,6 +901,7 @@ impl Authorization {
         Self {
             client_id: String::new(),
             state: AuthorizationState::AccessToken { token: access_token, client_secret: None },
+            metadata: AuthorizationMetadata::default(),
         }
     }
 
@@ -434,11 +916,40 @@ impl Authorization {
                 .now_or_never()
                 .expect("sync client future should resolve immediately")
         }
+
+        /// Compatibility shim for working with sync HTTP clients.
+        pub fn obtain_token_response(
+            &mut self,
+            sync_client: impl crate::client_trait::NoauthClient
+        ) -> Result<TokenResponse, Error> {
+            use futures::FutureExt;
+            self.obtain_token_response_async(sync_client)
+                .now_or_never()
+                .expect("sync client future should resolve immediately")
+        }
     }
 
     /// Obtain an access token. Use this to complete the authorization process, or to obtain an
     /// updated token when a short-lived access token has expired.
+    ///
+    /// This is [`obtain_token_response_async`](Self::obtain_token_response_async) returning only
+    /// the token.
     pub async fn obtain_access_token_async(&mut self, client: impl NoauthClient) -> Result<String, Error> {
+        self.obtain_token_response_async(client)
+            .await
+            .map(|response| response.access_token)
+    }
+
+    /// Obtain an access token, along with the other details of the grant. Use this to complete
+    /// the authorization process, or to obtain an updated token when a short-lived access token has
+    /// expired.
+    ///
+    /// When there is no request to make, because the authorization already has a long-lived token
+    /// or was completed by the implicit grant flow, only the token is filled in.
+    pub async fn obtain_token_response_async(
+        &mut self,
+        client: impl NoauthClient,
+    ) -> Result<TokenResponse, Error> {
         let mut redirect_uri = None;
         let mut client_secret = None;
         let mut pkce_code = None;
@@ -448,22 +959,29 @@ impl Authorization {
         match self.state.clone() {
             AuthorizationState::AccessToken { token, client_secret: secret } => {
                 match secret {
+                    None if cfg!(feature = "deny_legacy_tokens") => {
+                        error!("refusing to use a long-lived access token, due to deny_legacy_tokens");
+                        return Err(Error::Authentication(AuthError::InvalidAccessToken));
+                    },
                     None => {
                         // Long-lived token which cannot be refreshed
-                        return Ok(token)
+                        return Ok(TokenResponse::token_only(token))
                     },
                     Some(secret) => {
                         client_secret = Some(secret);
                     }
                 }
             }
+            AuthorizationState::ShortLivedToken { token } => {
+                return Ok(TokenResponse::token_only(token));
+            }
             AuthorizationState::InitialAuth {
                 flow_type, auth_code: code, redirect_uri: uri } =>
             {
                 match flow_type {
                     Oauth2Type::ImplicitGrant => {
-                        self.state = AuthorizationState::AccessToken { client_secret: None, token: code.clone() };
-                        return Ok(code);
+                        self.state = AuthorizationState::ShortLivedToken { token: code.clone() };
+                        return Ok(TokenResponse::token_only(code));
                     }
                     Oauth2Type::AuthorizationCode { client_secret: secret } => {
                         client_secret = Some(secret);
@@ -518,7 +1036,7 @@ impl Authorization {
                 params.append_pair("redirect_uri", &value);
             }
 
-            params.finish()
+            Bytes::from(params.finish())
         };
 
         let (req, body) = prepare_request(
@@ -533,18 +1051,21 @@ impl Authorization {
             None,
             None,
             None,
+            None,
         );
         let body = body.unwrap_or_default();
 
         debug!("Requesting OAuth2 token");
         let resp = client.execute(req, body).await?;
-        let (result_json, _, _) = parse_response(resp, Style::Rpc).await?;
-        let result_value = serde_json::from_str(&result_json)?;
+        let (result_json, _, _) = parse_response(resp, Style::Rpc, "oauth2/token").await?;
+        let result_value = serde_json::from_str(&result_json)
+            .map_err(|e| deserialize_error("oauth2/token", e, &result_json))?;
 
         debug!("OAuth2 response: {:?}", result_value);
 
         let access_token: String;
-        let refresh_token: Option<String>;
+        let new_refresh_token: Option<String>;
+        let response_map;
 
         match result_value {
             serde_json::Value::Object(mut map) => {
@@ -553,53 +1074,158 @@ impl Authorization {
                     _ => return Err(Error::UnexpectedResponse("no access token in response!".to_owned())),
                 }
                 match map.remove("refresh_token") {
-                    Some(serde_json::Value::String(refresh)) => refresh_token = Some(refresh),
+                    Some(serde_json::Value::String(refresh)) => new_refresh_token = Some(refresh),
                     Some(_) => {
                         return Err(Error::UnexpectedResponse("refresh token is not a string!".to_owned()));
                     },
-                    None => refresh_token = None,
+                    None => new_refresh_token = None,
                 }
+                self.metadata.update(&map);
+                response_map = map;
             },
             _ => return Err(Error::UnexpectedResponse("response is not a JSON object".to_owned())),
         }
 
-        match refresh_token {
+        let string = |key: &str| response_map.get(key).and_then(|v| v.as_str()).map(str::to_owned);
+        let response = TokenResponse {
+            access_token: access_token.clone(),
+            token_type: string("token_type"),
+            expires_in: response_map.get("expires_in")
+                .and_then(|v| v.as_u64())
+                .map(Duration::from_secs),
+            refresh_token_rotated: refresh_token.is_some()
+                && new_refresh_token.is_some()
+                && new_refresh_token != refresh_token,
+            scope: string("scope")
+                .map(|scope| scope.split_whitespace().map(str::to_owned).collect()),
+            account_id: string("account_id"),
+            team_id: string("team_id"),
+            id_token: string("id_token"),
+        };
+
+        match new_refresh_token {
             Some(refresh) => {
                 self.state = AuthorizationState::Refresh { refresh_token: refresh, client_secret };
             }
             None if !matches!(self.state, AuthorizationState::Refresh {..}) => {
-                self.state = AuthorizationState::AccessToken {
-                    token: access_token.clone(),
-                    client_secret,
+                self.state = match client_secret {
+                    None => AuthorizationState::ShortLivedToken { token: access_token.clone() },
+                    Some(_) => AuthorizationState::AccessToken {
+                        token: access_token.clone(),
+                        client_secret,
+                    },
                 };
             }
             _ => (),
         }
 
-        Ok(access_token)
+        Ok(response)
+    }
+}
+
+/// The details of an OAuth2 grant, from [`Authorization::obtain_token_response_async`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct TokenResponse {
+    /// The access token.
+    pub access_token: String,
+
+    /// The type of the access token, which is `"bearer"` for Dropbox.
+    pub token_type: Option<String>,
+
+    /// How long the access token is valid for, if it is short-lived.
+    pub expires_in: Option<Duration>,
+
+    /// Whether the server issued a new refresh token in place of the one used to obtain this
+    /// access token. The new one is kept by the [`Authorization`], so it should be saved again.
+    pub refresh_token_rotated: bool,
+
+    /// The scopes granted to the app, if the response listed them.
+    pub scope: Option<Vec<String>>,
+
+    /// The ID of the authorized user's account.
+    pub account_id: Option<String>,
+
+    /// The ID of the authorized team, for apps with team scopes.
+    pub team_id: Option<String>,
+
+    /// The OpenID Connect ID token, for apps which requested the `openid` scope.
+    pub id_token: Option<String>,
+}
+
+impl TokenResponse {
+    fn token_only(access_token: String) -> Self {
+        Self {
+            access_token,
+            token_type: None,
+            expires_in: None,
+            refresh_token_rotated: false,
+            scope: None,
+            account_id: None,
+            team_id: None,
+            id_token: None,
+        }
     }
 }
 
 /// `TokenCache` provides the current OAuth2 token and a means to refresh it in a thread-safe way.
+///
+/// One cache can be shared (in an `Arc`) between any number of clients, including a mix of sync and
+/// async ones. Only one refresh happens at a time across all of them: when several requests find
+/// that the token has expired, the first one refreshes it and the rest wait for that and then use
+/// the new token.
+///
+/// Getting the current token never waits for a refresh in progress, so it doesn't block an async
+/// runtime. Async clients wait for a refresh by `await`ing, and sync clients by blocking their
+/// thread.
+///
+/// To also have only one refresh at a time across many processes sharing the same refresh token,
+/// give the cache a [`RefreshCoordinator`]. To be told when tokens are refreshed, or when
+/// refreshing fails, give it [`TokenEvents`].
 pub struct TokenCache {
-    auth: RwLock<(Authorization, Arc<String>)>,
+    /// Held for the duration of a refresh, so that only one happens at a time.
+    auth: Mutex<Authorization>,
+
+    /// The current token. This lock is only held briefly, never during a refresh.
+    token: std::sync::RwLock<Arc<String>>,
+
+    coordinator: Arc<dyn RefreshCoordinator>,
+
+    events: Option<Arc<dyn TokenEvents>>,
 }
 
 impl TokenCache {
     /// Make a new token cache, using the given [`Authorization`] as a source of tokens.
     pub fn new(auth: Authorization) -> Self {
         Self {
-            auth: RwLock::new((auth, Arc::new(String::new()))),
+            auth: Mutex::new(auth),
+            token: std::sync::RwLock::new(Arc::new(String::new())),
+            coordinator: Arc::new(NoopRefreshCoordinator),
+            events: None,
         }
     }
 
+    /// Coordinate refreshes with other processes using the given [`RefreshCoordinator`], instead
+    /// of only within this cache.
+    pub fn with_refresh_coordinator(mut self, coordinator: Arc<dyn RefreshCoordinator>) -> Self {
+        self.coordinator = coordinator;
+        self
+    }
+
+    /// Report each [`TokenEvent`] to the given [`TokenEvents`], such as to alert when the app's
+    /// access has been revoked.
+    pub fn with_token_events(mut self, events: Arc<dyn TokenEvents>) -> Self {
+        self.events = Some(events);
+        self
+    }
+
     /// Get the current token, unless no cached token is set yet.
     pub fn get_token(&self) -> Option<Arc<String>> {
-        let read = self.auth.read_blocking();
-        if read.1.is_empty() {
+        let token = self.token.read().unwrap();
+        if token.is_empty() {
             None
         } else {
-            Some(Arc::clone(&read.1))
+            Some(Arc::clone(&token))
         }
     }
 
@@ -610,21 +1236,216 @@ impl TokenCache {
     pub async fn update_token(&self, client: impl NoauthClient, old_token: Arc<String>)
         -> Result<Arc<String>, Error>
     {
-        let mut write = self.auth.write().await;
-        // Check if the token changed while we were unlocked; only update it if it
+        let mut auth = self.auth.lock().await;
+        self.update_locked(&mut auth, client, old_token).await
+    }
+
+    if_feature! { "sync_routes",
+        /// Like [`update_token`](TokenCache::update_token), for sync clients. If another client is
+        /// refreshing the token, this blocks the thread until it is done.
+        ///
+        /// This doesn't run an executor, so it can be called from within one, such as
+        /// `blocking::block_on`. That means the [`RefreshCoordinator`] futures
+        /// have to be ready straight away, and it panics if they aren't.
+        pub fn update_token_blocking(
+            &self,
+            client: impl crate::client_trait::NoauthClient,
+            old_token: Arc<String>,
+        ) -> Result<Arc<String>, Error> {
+            let mut auth = self.auth.lock_blocking();
+            self.update_locked(&mut auth, client, old_token)
+                .now_or_never()
+                .expect("sync client and refresh coordinator futures should resolve immediately")
+        }
+    }
+
+    async fn update_locked(
+        &self,
+        auth: &mut Authorization,
+        client: impl NoauthClient,
+        old_token: Arc<String>,
+    ) -> Result<Arc<String>, Error> {
+        // Check if the token changed while we were waiting for the lock; only update it if it
         // didn't.
-        if write.1 == old_token {
-            write.1 = Arc::new(write.0.obtain_access_token_async(client).await?);
+        let current = Arc::clone(&self.token.read().unwrap());
+        if current != old_token {
+            return Ok(current);
+        }
+
+        let result = self.refresh(auth, client, &old_token).await;
+        let (token, expires_in, refresh_token_rotated) = match result {
+            Ok(refreshed) => refreshed,
+            Err(error) => {
+                if is_revoked(&error) {
+                    self.emit(&TokenEvent::Revoked { error: &error });
+                } else {
+                    self.emit(&TokenEvent::RefreshFailed { error: &error });
+                }
+                return Err(error);
+            }
+        };
+        if old_token.is_empty() {
+            self.emit(&TokenEvent::Obtained { expires_in });
+        } else {
+            let authorization = if refresh_token_rotated { auth.saved() } else { None };
+            self.emit(&TokenEvent::Refreshed { expires_in, refresh_token_rotated, authorization });
+        }
+        let token = Arc::new(token);
+        *self.token.write().unwrap() = Arc::clone(&token);
+        Ok(token)
+    }
+
+    /// Get a new token, from the [`RefreshCoordinator`] or else the [`Authorization`], along with
+    /// how long it is valid for and whether the refresh token was rotated, if known.
+    async fn refresh(
+        &self,
+        auth: &mut Authorization,
+        client: impl NoauthClient,
+        old_token: &str,
+    ) -> Result<(String, Option<Duration>, bool), Error> {
+        match self.coordinator.acquire(old_token).await.map_err(Error::HttpClient)? {
+            Some(token) => {
+                debug!("using a token refreshed elsewhere");
+                self.coordinator.release(None).await;
+                Ok((token, None, false))
+            }
+            None => {
+                let result = auth.obtain_token_response_async(client).await;
+                self.coordinator.release(result.as_ref().ok().map(|r| r.access_token.as_str()))
+                    .await;
+                let response = result?;
+                Ok((response.access_token, response.expires_in, response.refresh_token_rotated))
+            }
+        }
+    }
+
+    fn emit(&self, event: &TokenEvent<'_>) {
+        if let Some(events) = &self.events {
+            events.on_event(event);
         }
-        Ok(Arc::clone(&write.1))
     }
 
     /// Set the current short-lived token to a specific provided value. Normally it should not be
     /// necessary to call this function; the token should be obtained automatically using the
     /// refresh token.
     pub fn set_access_token(&self, access_token: String) {
-        let mut write = self.auth.write_blocking();
-        write.1 = Arc::new(access_token);
+        *self.token.write().unwrap() = Arc::new(access_token);
+    }
+}
+
+/// Something which happened to the tokens of a [`TokenCache`], reported to its [`TokenEvents`].
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum TokenEvent<'a> {
+    /// The cache got its first access token.
+    Obtained {
+        /// How long the token is valid for, if it is short-lived and this is known.
+        expires_in: Option<Duration>,
+    },
+
+    /// The cache replaced an expired access token with a new one. This includes tokens refreshed
+    /// by another process and shared through the [`RefreshCoordinator`], for which how long they
+    /// are valid for isn't known.
+    Refreshed {
+        /// How long the new token is valid for, if it is short-lived and this is known.
+        expires_in: Option<Duration>,
+
+        /// Whether a new refresh token was issued, in which case the old one no longer works, and
+        /// the authorization should be saved again, from `authorization`.
+        refresh_token_rotated: bool,
+
+        /// If the refresh token was rotated, the authorization with the new one, to save in place
+        /// of the old one.
+        authorization: Option<SavedAuthorization>,
+    },
+
+    /// Getting a new access token failed, such as due to a network error. Later requests will
+    /// try again.
+    RefreshFailed {
+        /// The error from the refresh.
+        error: &'a Error,
+    },
+
+    /// Getting a new access token failed because the refresh token is no longer valid, such as
+    /// because the user revoked the app's access. Retrying won't help; the user has to authorize
+    /// the app again.
+    Revoked {
+        /// The error from the refresh.
+        error: &'a Error,
+    },
+}
+
+/// Receives each [`TokenEvent`] of a [`TokenCache`], given to it with
+/// [`TokenCache::with_token_events`].
+///
+/// Events are reported on whichever thread or task refreshed the token, while other requests wait
+/// for the refresh, so this should return quickly. It is implemented for closures taking a
+/// `&TokenEvent`.
+pub trait TokenEvents: Send + Sync {
+    /// Handle an event.
+    fn on_event(&self, event: &TokenEvent<'_>);
+}
+
+impl<F: Fn(&TokenEvent<'_>) + Send + Sync> TokenEvents for F {
+    fn on_event(&self, event: &TokenEvent<'_>) {
+        self(event)
+    }
+}
+
+/// Whether a refresh failed because the grant is no longer valid, which the OAuth2 token endpoint
+/// reports as an `invalid_grant` error (RFC 6749 section 5.2).
+fn is_revoked(error: &Error) -> bool {
+    match error {
+        Error::BadRequest(body) => serde_json::from_str::<serde_json::Value>(body)
+            .map_or(false, |value| value["error"] == "invalid_grant"),
+        _ => false,
+    }
+}
+
+/// Coordinates token refreshes between processes which share the same refresh token, so that
+/// only one of them refreshes it at a time.
+///
+/// A [`TokenCache`] only refreshes once at a time within a process. When many processes share a
+/// refresh token, and the token expires, they'd all refresh it at once, and may be rate limited.
+/// Implement this with a lock they all share, such as one in Redis or a database, and give it to
+/// each process's cache with [`TokenCache::with_refresh_coordinator`].
+///
+/// With sync clients, the futures returned must be ready straight away, as they're not run on an
+/// executor: wait for the lock by blocking the thread in `acquire` instead.
+pub trait RefreshCoordinator: Send + Sync {
+    /// Wait until this process may refresh the token, such as by taking a lock, which is held
+    /// until [`release`](Self::release) is called.
+    ///
+    /// `stale` is the token being replaced, or an empty string if there isn't one. If another
+    /// process has already replaced it, return the new token, and the cache will use that instead
+    /// of refreshing. Otherwise, return `None`, and the cache will refresh the token.
+    ///
+    /// An error is returned from the refresh as an [`Error::HttpClient`], and the token isn't
+    /// refreshed.
+    fn acquire<'a>(&'a self, stale: &'a str)
+        -> BoxFuture<'a, Result<Option<String>, Box<dyn std::error::Error + Send + Sync>>>;
+
+    /// Allow other processes to refresh the token again, such as by releasing the lock.
+    ///
+    /// This is called after every successful [`acquire`](Self::acquire). `refreshed` is the new
+    /// token if this process refreshed it, for sharing with the others, or `None` if it didn't, or
+    /// refreshing failed.
+    fn release<'a>(&'a self, refreshed: Option<&'a str>) -> BoxFuture<'a, ()>;
+}
+
+/// The default [`RefreshCoordinator`], which doesn't coordinate with other processes.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct NoopRefreshCoordinator;
+
+impl RefreshCoordinator for NoopRefreshCoordinator {
+    fn acquire<'a>(&'a self, _stale: &'a str)
+        -> BoxFuture<'a, Result<Option<String>, Box<dyn std::error::Error + Send + Sync>>>
+    {
+        futures::future::ready(Ok(None)).boxed()
+    }
+
+    fn release<'a>(&'a self, _refreshed: Option<&'a str>) -> BoxFuture<'a, ()> {
+        futures::future::ready(()).boxed()
     }
 }
 
@@ -645,7 +1466,11 @@ impl TokenCache {
 /// The refresh token should only be stored somewhere safe like a file or database with restricted
 /// access permissions.
 pub fn get_auth_from_env_or_prompt() -> Authorization {
-    if let Ok(long_lived) = env::var("DBX_OAUTH_TOKEN") {
+    if cfg!(feature = "deny_legacy_tokens") {
+        if env::var_os("DBX_OAUTH_TOKEN").is_some() {
+            eprintln!("ignoring DBX_OAUTH_TOKEN, due to deny_legacy_tokens");
+        }
+    } else if let Ok(long_lived) = env::var("DBX_OAUTH_TOKEN") {
         // Used to provide a legacy long-lived token.
         #[allow(deprecated)]
         return Authorization::from_long_lived_access_token(long_lived);
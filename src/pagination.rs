@@ -0,0 +1,42 @@
+//! Page sizes for the helpers which follow a cursor through pages of results.
+//!
+//! Routes which return results a page at a time take a limit on how many results to put in each
+//! page, which the cursor for the following pages keeps. Fewer, larger pages mean fewer round
+//! trips, so unless told otherwise, the helpers ask for the largest pages each route allows, as
+//! given by [`default_page_size`]. Most of them can be told to use smaller pages, such as with
+//! [`ListFolderOptions::with_page_size`](crate::files_helpers::ListFolderOptions::with_page_size)
+//! when the `dbx_files` feature is enabled.
+
+/// The most results each paginated route allows in one page.
+const MAX_PAGE_SIZES: &[(&str, u32)] = &[
+    ("files/list_folder", 2000),
+    ("files/search_v2", 1000),
+    ("team/groups/list", 1000),
+    ("team/groups/members/list", 1000),
+    ("team/members/list_v2", 1000),
+    ("team_log/get_events", 1000),
+];
+
+/// The page size the helpers use for a route when none is given, or `None` if the route isn't
+/// one which the SDK knows to be paginated. This is the most results the route allows in one page.
+pub fn default_page_size(route: &str) -> Option<u32> {
+    MAX_PAGE_SIZES.iter()
+        .find(|(name, _)| *name == route)
+        .map(|(_, size)| *size)
+}
+
+/// The page size to request from a route: the requested size, clamped to what the route allows,
+/// or the route's default.
+// Only the helpers for paginated routes use this.
+#[cfg_attr(
+    not(any(
+        feature = "dbx_files",
+        feature = "dbx_team",
+        feature = "dbx_team_log",
+    )),
+    allow(dead_code)
+)]
+pub(crate) fn page_size(route: &str, requested: Option<u32>) -> u32 {
+    let max = default_page_size(route).expect("route is not paginated");
+    requested.map_or(max, |size| size.clamp(1, max))
+}
@@ -0,0 +1,140 @@
+//! Helpers for creating, updating, and exporting Paper docs.
+//!
+//! Paper docs are stored in the user's Dropbox as files with a `.paper` extension. They're created
+//! and updated by uploading their content in one of the [`files::ImportFormat`]s, and their content
+//! can be downloaded by exporting them as Markdown or HTML. The functions here wrap the
+//! `files/paper/create`, `files/paper/update` and `files/export` routes, which are only available
+//! with the `unstable` Cargo feature.
+//!
+//! Like the [`files_helpers`](crate::files_helpers), these are async functions, which can also be
+//! used with sync clients by driving them with any executor.
+
+use bytes::Bytes;
+use futures::AsyncReadExt;
+use crate::async_client_trait::UserAuthClient;
+use crate::files_helpers::TransferError;
+use crate::generated::async_routes::files;
+use crate::Error;
+
+/// The file extension of Paper docs.
+pub const PAPER_EXTENSION: &str = ".paper";
+
+/// The path of a Paper doc with the given title, in the given folder.
+///
+/// The title is used as the file name, with the `.paper` extension added. Slashes in the title
+/// are replaced with dashes, as they can't be in file names.
+pub fn paper_doc_path(folder: &str, title: &str) -> String {
+    format!("{}/{}{PAPER_EXTENSION}", folder.trim_end_matches('/'), title.replace('/', "-"))
+}
+
+/// Create a Paper doc at the given path, with the given content.
+///
+/// The `.paper` extension is added to the path if it doesn't have it already. If there's already a
+/// file at the path, the doc is created with a different name; the path it was created at is
+/// returned in the result.
+pub async fn create_paper_doc(
+    client: &impl UserAuthClient,
+    path: impl Into<String>,
+    format: files::ImportFormat,
+    content: impl Into<Bytes>,
+) -> Result<files::PaperCreateResult, Error<files::PaperCreateError>> {
+    let mut path = path.into();
+    if !path.to_ascii_lowercase().ends_with(PAPER_EXTENSION) {
+        path.push_str(PAPER_EXTENSION);
+    }
+    let arg = files::PaperCreateArg::new(path, format);
+    files::paper_create(client, &arg, content.into()).await
+}
+
+/// How [`update_paper_doc`] changes a doc's content.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PaperUpdate {
+    /// Replace the content, if the doc is still at the given revision, such as the one returned by
+    /// [`create_paper_doc`] or [`export_paper_doc`]. Otherwise, fail with
+    /// [`files::PaperUpdateError::RevisionMismatch`].
+    Replace {
+        /// The revision the new content is based on.
+        revision: i64,
+    },
+
+    /// Replace the content, regardless of any changes made since it was read.
+    Overwrite,
+
+    /// Add the content to the beginning of the doc.
+    Prepend,
+
+    /// Add the content to the end of the doc.
+    Append,
+}
+
+/// Update the content of a Paper doc, given by path or ID. Returns the doc's new revision.
+pub async fn update_paper_doc(
+    client: &impl UserAuthClient,
+    path: impl Into<String>,
+    format: files::ImportFormat,
+    content: impl Into<Bytes>,
+    update: PaperUpdate,
+) -> Result<i64, Error<files::PaperUpdateError>> {
+    let (policy, revision) = match update {
+        PaperUpdate::Replace { revision } => (files::PaperDocUpdatePolicy::Update, Some(revision)),
+        PaperUpdate::Overwrite => (files::PaperDocUpdatePolicy::Overwrite, None),
+        PaperUpdate::Prepend => (files::PaperDocUpdatePolicy::Prepend, None),
+        PaperUpdate::Append => (files::PaperDocUpdatePolicy::Append, None),
+    };
+    let mut arg = files::PaperUpdateArg::new(path.into(), format, policy);
+    arg.paper_revision = revision;
+    Ok(files::paper_update(client, &arg, content.into()).await?.paper_revision)
+}
+
+/// A format which Paper docs can be exported to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PaperExportFormat {
+    /// Markdown.
+    Markdown,
+
+    /// HTML.
+    Html,
+}
+
+impl PaperExportFormat {
+    /// The name of the format, as used in [`files::ExportArg::export_format`].
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Markdown => "markdown",
+            Self::Html => "html",
+        }
+    }
+}
+
+/// A Paper doc's content, from [`export_paper_doc`].
+#[derive(Debug, Clone)]
+pub struct PaperExport {
+    /// The doc's content, in the requested format.
+    pub content: String,
+
+    /// The doc's revision, which can be given to [`update_paper_doc`] to replace the content
+    /// only if it hasn't changed since.
+    pub revision: Option<i64>,
+
+    /// The metadata of the doc's file.
+    pub metadata: files::FileMetadata,
+}
+
+/// Export the content of a Paper doc, given by path or ID, in the given format.
+pub async fn export_paper_doc(
+    client: &impl UserAuthClient,
+    path: impl Into<String>,
+    format: PaperExportFormat,
+) -> Result<PaperExport, TransferError> {
+    let arg = files::ExportArg::new(path.into()).with_export_format(format.as_str().to_owned());
+    let result = files::export(client, &arg, None, None).await?;
+    let mut content = String::with_capacity(result.content_length.unwrap_or(0) as usize);
+    if let Some(mut body) = result.body {
+        body.read_to_string(&mut content).await?;
+    }
+    Ok(PaperExport {
+        content,
+        revision: result.result.export_metadata.paper_revision,
+        metadata: result.result.file_metadata,
+    })
+}
@@ -0,0 +1,32 @@
+//! The most commonly needed items, for importing all at once with `use dropbox_sdk::prelude::*`.
+//!
+//! This has the error types, authorization, the client traits and default clients, and a few
+//! types used with all kinds of routes. The client traits and default clients are the ones which
+//! go with the routes in the crate root: the sync ones with the `sync_routes_in_root` feature, and
+//! the async ones otherwise.
+//!
+//! Each namespace also has a prelude with its types, such as `dropbox_sdk::files::prelude`.
+
+pub use crate::{BoxedError, Error};
+pub use crate::client_trait_common::{Endpoint, TeamSelect};
+pub use crate::oauth2::{Authorization, Oauth2Type, PkceCode, TokenCache};
+pub use crate::retry::RetryBudget;
+
+#[cfg(feature = "dbx_common")]
+pub use crate::types::common::PathRoot;
+
+#[cfg(feature = "sync_routes_in_root")]
+pub use crate::client_trait::{AppAuthClient, NoauthClient, TeamAuthClient, UserAuthClient};
+
+#[cfg(not(feature = "sync_routes_in_root"))]
+pub use crate::async_client_trait::{AppAuthClient, NoauthClient, TeamAuthClient, UserAuthClient};
+
+#[cfg(feature = "default_client")]
+pub use crate::default_client::{
+    AppAuthDefaultClient, NoauthDefaultClient, TeamAuthDefaultClient, UserAuthDefaultClient,
+};
+
+#[cfg(all(feature = "default_async_client", not(feature = "sync_routes_in_root")))]
+pub use crate::default_async_client::{
+    AppAuthDefaultClient, NoauthDefaultClient, TeamAuthDefaultClient, UserAuthDefaultClient,
+};
@@ -0,0 +1,286 @@
+//! Reports of shared links, file locks, and team members, for compliance exports.
+//!
+//! Each report walks everything of its kind, following the cursor through all the pages of the
+//! routes it uses, and writes one row for each item to a [`Write`], either as CSV with a header
+//! row, or as JSON lines with one object per row. Requests which fail with a transient error, such
+//! as being rate limited, are retried within the limits of the report's
+//! [`retry_budget`](ReportOptions::retry_budget).
+//!
+//! Values which are absent are written as empty CSV fields, or as `null` in JSON. Enumerated
+//! values, such as a member's status, are written as their API tag, such as `"active"`.
+//!
+//! This is only built with the `reports` Cargo feature. The team members report also needs the
+//! `dbx_team` feature.
+
+use std::io::Write;
+use futures::TryStreamExt;
+use serde::Serialize;
+use crate::async_client_trait::UserAuthClient;
+use crate::files_helpers::{self, ListFolderOptions};
+use crate::generated::async_routes::{files, sharing};
+use crate::retry::RetryBudget;
+use crate::{BoxedError, Error};
+
+/// The format of a report's rows.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReportFormat {
+    /// Comma-separated values, with a header row of the column names, and fields quoted as in
+    /// RFC 4180 where needed.
+    Csv,
+
+    /// One JSON object per line, keyed by the column names.
+    JsonLines,
+}
+
+/// Options for the reports.
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub struct ReportOptions {
+    /// The format of the rows. Defaults to CSV.
+    pub format: ReportFormat,
+
+    /// Limits on retrying the requests which make up the report. Defaults to 10 retries in
+    /// total.
+    pub retry_budget: RetryBudget,
+}
+
+impl Default for ReportOptions {
+    fn default() -> Self {
+        Self {
+            format: ReportFormat::Csv,
+            retry_budget: RetryBudget::new(10),
+        }
+    }
+}
+
+impl ReportOptions {
+    /// Set the format of the rows.
+    pub fn with_format(mut self, value: ReportFormat) -> Self {
+        self.format = value;
+        self
+    }
+
+    /// Set the limits on retrying requests.
+    pub fn with_retry_budget(mut self, value: RetryBudget) -> Self {
+        self.retry_budget = value;
+        self
+    }
+}
+
+/// An error which stopped a report. Rows written before it are left in the output.
+#[derive(thiserror::Error, Debug)]
+pub enum ReportError {
+    /// A request to Dropbox failed.
+    #[error("Dropbox API error: {0}")]
+    Api(#[source] BoxedError),
+
+    /// Writing the report failed.
+    #[error("error writing the report: {0}")]
+    Io(#[from] std::io::Error),
+}
+
+impl<E: std::error::Error + Send + Sync + 'static> From<Error<E>> for ReportError {
+    fn from(e: Error<E>) -> Self {
+        ReportError::Api(e.boxed())
+    }
+}
+
+/// The columns of [`shared_links_report`].
+pub const SHARED_LINK_COLUMNS: &[&str] = &[
+    "url", "kind", "name", "path", "id", "visibility", "expires", "allow_download",
+];
+
+/// The columns of [`file_locks_report`].
+pub const FILE_LOCK_COLUMNS: &[&str] = &[
+    "path", "id", "lock_holder_name", "lock_holder_account_id", "created",
+];
+
+/// The columns of [`members_report`].
+#[cfg(feature = "dbx_team")]
+pub const MEMBER_COLUMNS: &[&str] = &[
+    "team_member_id", "email", "display_name", "status", "membership_type", "account_id",
+    "external_id", "joined_on",
+];
+
+/// Write a row for each of the user's shared links, from `sharing/list_shared_links`, returning
+/// how many there were.
+pub async fn shared_links_report(
+    client: &impl UserAuthClient,
+    out: impl Write,
+    options: &ReportOptions,
+) -> Result<usize, ReportError> {
+    let mut rows = RowWriter::new(out, options.format, SHARED_LINK_COLUMNS)?;
+    let scope = options.retry_budget.start();
+    let mut arg = sharing::ListSharedLinksArg::default();
+    loop {
+        let page = scope.call(|| sharing::list_shared_links(client, &arg)).await?;
+        for link in page.links {
+            let (kind, url, name, path, id, expires, permissions) = match link {
+                sharing::SharedLinkMetadata::File(f) => ("file", f.url, f.name, f.path_lower, f.id,
+                    f.expires, f.link_permissions),
+                sharing::SharedLinkMetadata::Folder(f) => ("folder", f.url, f.name, f.path_lower,
+                    f.id, f.expires, f.link_permissions),
+                sharing::SharedLinkMetadata::Other => continue,
+            };
+            rows.write(&[
+                Some(url),
+                Some(kind.to_owned()),
+                Some(name),
+                path,
+                id,
+                permissions.resolved_visibility.as_ref().and_then(tag),
+                expires,
+                Some(permissions.allow_download.to_string()),
+            ])?;
+        }
+        match page.cursor {
+            Some(cursor) if page.has_more => arg.cursor = Some(cursor),
+            _ => break,
+        }
+    }
+    rows.finish()
+}
+
+/// Write a row for each locked file in a folder and its subfolders, found by listing them with
+/// [`files_helpers::list_folder`], returning how many there were.
+///
+/// If the listing has to be restarted because its cursor was reset, locked files found before
+/// the restart may be written again.
+pub async fn file_locks_report(
+    client: &impl UserAuthClient,
+    path: &str,
+    out: impl Write,
+    options: &ReportOptions,
+) -> Result<usize, ReportError> {
+    let mut rows = RowWriter::new(out, options.format, FILE_LOCK_COLUMNS)?;
+    let arg = files::ListFolderArg::new(path.to_owned()).with_recursive(true);
+    let list_options = ListFolderOptions::default()
+        .with_retry_budget(options.retry_budget.clone());
+    let mut entries = Box::pin(files_helpers::list_folder(client, arg, &list_options));
+    while let Some(entry) = entries.try_next().await.map_err(ReportError::Api)? {
+        let files::Metadata::File(file) = entry.metadata else {
+            continue;
+        };
+        let Some(lock) = file.file_lock_info else {
+            continue;
+        };
+        rows.write(&[
+            file.path_display.or(file.path_lower),
+            Some(file.id),
+            lock.lockholder_name,
+            lock.lockholder_account_id,
+            lock.created,
+        ])?;
+    }
+    rows.finish()
+}
+
+/// Write a row for each of the team's members, including removed ones, from
+/// `team/members/list_v2`, returning how many there were.
+#[cfg(feature = "dbx_team")]
+pub async fn members_report(
+    client: &impl crate::async_client_trait::TeamAuthClient,
+    out: impl Write,
+    options: &ReportOptions,
+) -> Result<usize, ReportError> {
+    use crate::generated::async_routes::team;
+
+    let mut rows = RowWriter::new(out, options.format, MEMBER_COLUMNS)?;
+    let scope = options.retry_budget.start();
+    let arg = team::MembersListArg::default()
+        .with_include_removed(true)
+        .with_limit(crate::pagination::page_size("team/members/list_v2", None));
+    let mut page = scope.call(|| team::members_list_v2(client, &arg)).await?;
+    loop {
+        for member in page.members {
+            let profile = member.profile;
+            rows.write(&[
+                Some(profile.team_member_id),
+                Some(profile.email),
+                Some(profile.name.display_name),
+                tag(&profile.status),
+                tag(&profile.membership_type),
+                profile.account_id,
+                profile.external_id,
+                profile.joined_on,
+            ])?;
+        }
+        if !page.has_more {
+            break;
+        }
+        let arg = team::MembersListContinueArg::new(page.cursor);
+        page = scope.call(|| team::members_list_continue_v2(client, &arg)).await?;
+    }
+    rows.finish()
+}
+
+/// The API tag of an enumerated value, such as `"active"` for a member's status.
+fn tag<T: Serialize>(value: &T) -> Option<String> {
+    match serde_json::to_value(value).ok()? {
+        serde_json::Value::Object(mut fields) => match fields.remove(".tag")? {
+            serde_json::Value::String(tag) => Some(tag),
+            _ => None,
+        },
+        serde_json::Value::String(tag) => Some(tag),
+        _ => None,
+    }
+}
+
+/// Writes rows of a report in its format.
+struct RowWriter<W> {
+    out: W,
+    format: ReportFormat,
+    columns: &'static [&'static str],
+    rows: usize,
+}
+
+impl<W: Write> RowWriter<W> {
+    fn new(mut out: W, format: ReportFormat, columns: &'static [&'static str])
+        -> std::io::Result<Self>
+    {
+        if format == ReportFormat::Csv {
+            write_csv_record(&mut out, columns.iter().copied())?;
+        }
+        Ok(Self { out, format, columns, rows: 0 })
+    }
+
+    fn write(&mut self, values: &[Option<String>]) -> std::io::Result<()> {
+        debug_assert_eq!(self.columns.len(), values.len());
+        match self.format {
+            ReportFormat::Csv => {
+                write_csv_record(&mut self.out, values.iter().map(|v| v.as_deref().unwrap_or("")))?;
+            }
+            ReportFormat::JsonLines => {
+                let object = self.columns.iter()
+                    .zip(values)
+                    .map(|(column, value)| ((*column).to_owned(), serde_json::json!(value)))
+                    .collect::<serde_json::Map<_, _>>();
+                serde_json::to_writer(&mut self.out, &object)?;
+                self.out.write_all(b"\n")?;
+            }
+        }
+        self.rows += 1;
+        Ok(())
+    }
+
+    fn finish(mut self) -> Result<usize, ReportError> {
+        self.out.flush()?;
+        Ok(self.rows)
+    }
+}
+
+fn write_csv_record<'a>(out: &mut impl Write, fields: impl Iterator<Item = &'a str>)
+    -> std::io::Result<()>
+{
+    for (i, field) in fields.enumerate() {
+        if i > 0 {
+            out.write_all(b",")?;
+        }
+        if field.contains(['"', ',', '\n', '\r']) {
+            write!(out, "\"{}\"", field.replace('"', "\"\""))?;
+        } else {
+            out.write_all(field.as_bytes())?;
+        }
+    }
+    out.write_all(b"\r\n")
+}
@@ -0,0 +1,312 @@
+//! Retrying failed requests within limits shared across a whole operation.
+//!
+//! A [`RetryBudget`] sets how many times, and for how long, the requests making up one logical
+//! operation (such as all the requests of a chunked upload) may be retried in total. Call
+//! [`RetryBudget::start`] at the start of the operation, and make each of its requests through
+//! [`RetryScope::call`]. This way a bad connection causes the operation to fail within a bounded
+//! time, rather than each request retrying on its own indefinitely.
+//!
+//! A budget can also have a deadline, such as one passed down from the caller's own, after which
+//! the operation fails with [`DeadlineExceeded`], even partway through a request. Unlike the
+//! limits on retrying, this bounds the total time spent in the operation.
+//!
+//! Requests sharing a scope also back off together: when one is rate limited, the others wait
+//! too before their next attempt, as widely as the [`BackoffScope`] of the kind of rate limit
+//! says. Make requests with [`RetryScope::call_route`] for write contention to only hold back
+//! requests in the same route namespace.
+//!
+//! For retry loops of your own, [`retry_after`] waits on a [`Clock`] as long as an error says to
+//! before trying again.
+//!
+//! Retrying waits between attempts, so it needs an executor which supports timers, unless it is
+//! given a [`Clock`] which doesn't need one, such as a [`FakeClock`](crate::clock::FakeClock) in
+//! tests.
+
+use std::future::Future;
+use std::sync::{Arc, Mutex};
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::time::{Duration, Instant};
+use futures::future::Either;
+use crate::client_trait_common::{DeadlineExceeded, Stalled};
+use crate::clock::{Clock, SystemClock};
+use crate::{BackoffScope, Error, RateLimitCategory};
+
+/// The longest to wait between attempts, when the server doesn't say how long to wait.
+const MAX_BACKOFF: Duration = Duration::from_secs(32);
+
+/// Limits on retrying the requests which make up one logical operation.
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub struct RetryBudget {
+    /// The maximum number of retries, in total across all the requests.
+    pub max_retries: u32,
+
+    /// Don't retry any more once this much time has passed since the operation started, or if
+    /// waiting to retry would go past it.
+    pub max_elapsed: Duration,
+
+    /// Fail with [`DeadlineExceeded`] once this time has passed, according to the budget's
+    /// clock, rather than starting or waiting for any more requests.
+    pub deadline: Option<Instant>,
+
+    clock: Arc<dyn Clock>,
+}
+
+impl RetryBudget {
+    /// Allow up to the given number of retries in total, with no limit on time.
+    pub fn new(max_retries: u32) -> Self {
+        Self {
+            max_retries,
+            max_elapsed: Duration::MAX,
+            deadline: None,
+            clock: Arc::new(SystemClock),
+        }
+    }
+
+    /// Set the maximum time to keep retrying for.
+    pub fn with_max_elapsed(mut self, value: Duration) -> Self {
+        self.max_elapsed = value;
+        self
+    }
+
+    /// Set a deadline for the operation.
+    pub fn with_deadline(mut self, value: Instant) -> Self {
+        self.deadline = Some(value);
+        self
+    }
+
+    /// Use the given clock for measuring the time taken, and waiting between attempts, instead of
+    /// the [`SystemClock`].
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// Start an operation using this budget.
+    pub fn start(&self) -> RetryScope {
+        RetryScope {
+            budget: self.clone(),
+            started: self.clock.now(),
+            retries: AtomicU32::new(0),
+            backoffs: Mutex::new(Vec::new()),
+        }
+    }
+}
+
+/// The state of a [`RetryBudget`] for one operation in progress.
+///
+/// This can be shared between requests running concurrently.
+#[derive(Debug)]
+pub struct RetryScope {
+    budget: RetryBudget,
+    started: Instant,
+    retries: AtomicU32,
+
+    /// Until when requests in each scope should hold off, after one of them was rate limited.
+    backoffs: Mutex<Vec<(BackoffScope, Instant)>>,
+}
+
+impl RetryScope {
+    /// Make a request by calling `f`, and call it again if it fails with a transient error, for
+    /// as long as the budget allows.
+    ///
+    /// Rate-limiting, write contention, server errors (including the service being unavailable for
+    /// maintenance), and HTTP client errors (such as network failures) are retried. Between
+    /// attempts, this waits as long as the server said to, if it did, or otherwise for an
+    /// exponentially increasing time.
+    ///
+    /// If a request is rate limited for making too many requests, the other requests made through
+    /// this scope wait as well before their next attempt. Write contention only holds back this
+    /// request; use [`call_route`](Self::call_route) to hold back others to the same namespace too.
+    ///
+    /// If the budget has a deadline, this fails with [`DeadlineExceeded`] once it passes, dropping
+    /// any request in progress.
+    pub async fn call<T, E, F, Fut>(&self, f: F) -> Result<T, Error<E>>
+    where
+        E: std::error::Error + serde::Serialize,
+        F: FnMut() -> Fut,
+        Fut: Future<Output = Result<T, Error<E>>>,
+    {
+        self.call_inner(None, f).await
+    }
+
+    /// Like [`call`](Self::call), for a request to the given route, such as `"files/upload"`.
+    ///
+    /// Knowing the route, requests back off as widely as the [`BackoffScope`] for the kind of rate
+    /// limit they ran into, from [`RateLimitCategory::backoff_scope`].
+    /// When one is held back by write contention, so are the others to routes in the same
+    /// namespace, while ones to other namespaces carry on.
+    pub async fn call_route<T, E, F, Fut>(&self, route: &str, f: F) -> Result<T, Error<E>>
+    where
+        E: std::error::Error + serde::Serialize,
+        F: FnMut() -> Fut,
+        Fut: Future<Output = Result<T, Error<E>>>,
+    {
+        self.call_inner(Some(route), f).await
+    }
+
+    async fn call_inner<T, E, F, Fut>(&self, route: Option<&str>, mut f: F)
+        -> Result<T, Error<E>>
+    where
+        E: std::error::Error + serde::Serialize,
+        F: FnMut() -> Fut,
+        Fut: Future<Output = Result<T, Error<E>>>,
+    {
+        let mut attempt = 0;
+        loop {
+            let request = async {
+                self.wait_for_backoff(route).await;
+                f().await
+            };
+            let e = match self.before_deadline(request).await {
+                Ok(result) => return Ok(result),
+                Err(e) => e,
+            };
+            let wait = match e.retry_after() {
+                Some(wait) => wait,
+                None if matches!(e, Error::ServerError(_) | Error::HttpClient(_)) => {
+                    MAX_BACKOFF.min(Duration::from_secs(1 << attempt.min(5)))
+                }
+                None => return Err(e),
+            };
+            // Hold back the other requests even if this one isn't retried.
+            if let Some(category) = e.rate_limit_category() {
+                let scope = match (route, category) {
+                    (Some(route), category) => Some(category.backoff_scope(route)),
+                    (None, RateLimitCategory::TooManyRequests) => Some(BackoffScope::Global),
+                    // Without the route, write contention can't be scoped to its namespace.
+                    (None, _) => None,
+                };
+                if let Some(scope) = scope {
+                    self.back_off(scope, self.budget.clock.now() + wait);
+                }
+            }
+            if !self.take_retry(wait) {
+                debug!("retry budget exhausted, not retrying: {e}");
+                return Err(e);
+            }
+            attempt += 1;
+            debug!("retrying in {wait:?} after error: {e}");
+            self.budget.clock.sleep(wait).await;
+        }
+    }
+
+    /// Hold back the requests in the given scope until the given time.
+    fn back_off(&self, scope: BackoffScope, until: Instant) {
+        let mut backoffs = self.backoffs.lock().unwrap();
+        match backoffs.iter_mut().find(|(s, _)| *s == scope) {
+            Some((_, t)) => *t = (*t).max(until),
+            None => backoffs.push((scope, until)),
+        }
+    }
+
+    /// Wait until no other request's backoff applies to a request to the given route, or to any
+    /// route if it isn't known.
+    async fn wait_for_backoff(&self, route: Option<&str>) {
+        let now = self.budget.clock.now();
+        let until = self.backoffs.lock().unwrap().iter()
+            .filter(|(scope, _)| match route {
+                Some(route) => scope.applies_to(route),
+                None => *scope == BackoffScope::Global,
+            })
+            .map(|(_, until)| *until)
+            .max();
+        if let Some(until) = until.filter(|until| *until > now) {
+            debug!("backing off for {:?}", until - now);
+            self.budget.clock.sleep(until - now).await;
+        }
+    }
+
+    /// Whether to resume a transfer whose response body [stalled](Stalled), using up one retry if
+    /// so.
+    ///
+    /// A stall means the connection died, rather than the server asking for less load, so the
+    /// transfer can be resumed straight away, if the budget has any retries and time left.
+    pub fn retry_stalled(&self, stalled: &Stalled) -> bool {
+        if !self.take_retry(Duration::ZERO) {
+            debug!("retry budget exhausted, not resuming: {stalled}");
+            return false;
+        }
+        debug!("resuming after: {stalled}");
+        true
+    }
+
+    /// The number of retries used so far.
+    pub fn retries(&self) -> u32 {
+        self.retries.load(Ordering::SeqCst)
+    }
+
+    /// How much time has passed since the operation started, according to the budget's clock.
+    pub fn elapsed(&self) -> Duration {
+        self.budget.clock.now().saturating_duration_since(self.started)
+    }
+
+    /// Run a request, failing if the deadline passes first.
+    async fn before_deadline<T, E, Fut>(&self, request: Fut) -> Result<T, Error<E>>
+    where
+        Fut: Future<Output = Result<T, Error<E>>>,
+    {
+        let Some(deadline) = self.budget.deadline else {
+            return request.await;
+        };
+        let left = deadline.saturating_duration_since(self.budget.clock.now());
+        if left.is_zero() {
+            return Err(DeadlineExceeded.into());
+        }
+        futures::pin_mut!(request);
+        // Only start sleeping if the request doesn't complete straight away, so that a fake clock
+        // isn't moved on by requests which don't wait.
+        let timer = async { self.budget.clock.sleep(left).await };
+        futures::pin_mut!(timer);
+        match futures::future::select(request, timer).await {
+            Either::Left((result, _)) => result,
+            Either::Right(_) => Err(DeadlineExceeded.into()),
+        }
+    }
+
+    /// Use up one retry, if there are any left and waiting the given time wouldn't run out the
+    /// clock.
+    fn take_retry(&self, wait: Duration) -> bool {
+        if self.elapsed().saturating_add(wait) > self.budget.max_elapsed {
+            return false;
+        }
+        if self.budget.deadline.map_or(false, |deadline| self.budget.clock.now() + wait >= deadline) {
+            return false;
+        }
+        self.retries
+            .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |n| {
+                if n < self.budget.max_retries { Some(n + 1) } else { None }
+            })
+            .is_ok()
+    }
+}
+
+/// If an error says to wait before retrying, wait that long on the given clock, plus a random
+/// amount up to a quarter as long again, and return `true`. Otherwise, return `false` straight
+/// away.
+///
+/// Use it in a loop around a request which gives up after some number of attempts, such as:
+///
+/// ```ignore
+/// let clock = SystemClock;
+/// let mut attempts = 0;
+/// let metadata = loop {
+///     attempts += 1;
+///     match files::get_metadata(&client, &arg).await {
+///         Err(e) if attempts < 5 && retry_after(&e, &clock).await => continue,
+///         result => break result?,
+///     }
+/// };
+/// ```
+///
+/// For limits on the total time spent retrying too, or across several requests, use a
+/// [`RetryBudget`] instead.
+pub async fn retry_after<E: serde::Serialize>(err: &Error<E>, clock: &dyn Clock) -> bool {
+    let Some(wait) = err.retry_after() else {
+        return false;
+    };
+    let wait = wait + crate::error::jitter(wait / 4);
+    debug!("waiting {wait:?} to retry after error");
+    clock.sleep(wait).await;
+    true
+}
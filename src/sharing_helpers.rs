@@ -0,0 +1,443 @@
+//! Higher-level helpers for working with shared links and shared folders.
+//!
+//! The shared link functions here use App authentication, so they only need the app's key and
+//! secret (see [`AppAuthDefaultClient`](crate::default_client::AppAuthDefaultClient)) and not any
+//! user's token. This is enough to build things like link previews for any shared link the app is
+//! given.
+//!
+//! Like the [`files_helpers`](crate::files_helpers), these are async functions, which can also be
+//! used with sync clients by driving them with any executor.
+
+use std::collections::HashMap;
+use std::time::Duration;
+use futures::AsyncReadExt;
+use crate::async_client_trait::{AppAuthClient, UserAuthClient};
+use crate::files_helpers::TransferError;
+use crate::generated::async_routes::{dbx_async, files, sharing};
+use crate::{BoxedError, Error, NoError};
+
+/// Get the metadata of a shared link.
+///
+/// For a link to a folder, `link.path` can be set to get the metadata of a file or folder within
+/// it instead. `link.password` is needed if the link is password-protected.
+pub async fn shared_link_metadata(
+    client: &impl AppAuthClient,
+    link: &files::SharedLinkFileInfo,
+) -> Result<sharing::SharedLinkMetadata, Error<sharing::SharedLinkError>> {
+    let arg = sharing::GetSharedLinkMetadataArg {
+        url: link.url.clone(),
+        path: link.path.clone(),
+        link_password: link.password.clone(),
+    };
+    sharing::get_shared_link_metadata_app_auth(client, &arg).await
+}
+
+/// Get a thumbnail of the file a shared link points to, as image data in the requested format.
+///
+/// The link's metadata is returned along with the image. For a link to a folder, `link.path`
+/// must be set to the file within it.
+pub async fn shared_link_thumbnail(
+    client: &impl AppAuthClient,
+    link: &files::SharedLinkFileInfo,
+    size: files::ThumbnailSize,
+    format: files::ThumbnailFormat,
+) -> Result<(files::MinimalFileLinkMetadata, Vec<u8>), TransferError> {
+    let arg = files::ThumbnailV2Arg::new(files::PathOrLink::Link(link.clone()))
+        .with_size(size)
+        .with_format(format);
+    let result = files::get_thumbnail_v2_app_auth(client, &arg, None, None).await?;
+
+    let link_metadata = result.result.link_metadata.ok_or_else(|| {
+        TransferError::from(Error::<NoError>::UnexpectedResponse(
+            "thumbnail response is missing link metadata".to_owned()))
+    })?;
+    let mut data = Vec::with_capacity(result.content_length.unwrap_or(0) as usize);
+    if let Some(mut body) = result.body {
+        body.read_to_end(&mut data).await?;
+    }
+    Ok((link_metadata, data))
+}
+
+/// The most files `sharing/get_file_metadata/batch` accepts in one request.
+const FILE_METADATA_BATCH_SIZE: usize = 100;
+
+/// Get the sharing metadata of many files, each given by path or ID, including whether the user
+/// is allowed to take each of the given `actions` on them.
+///
+/// The files are looked up in batches with `sharing/get_file_metadata/batch`. The result maps
+/// each file, as it was given, to either its metadata or the error looking it up; use
+/// [`SharedFileMetadata::allows`](sharing::SharedFileMetadata::allows) to check the actions.
+/// Files whose result is of a kind unknown to this SDK version are left out.
+pub async fn file_sharing_metadata(
+    client: &impl UserAuthClient,
+    files: &[String],
+    actions: &[sharing::FileAction],
+) -> Result<
+    HashMap<String, Result<sharing::SharedFileMetadata, sharing::SharingFileAccessError>>,
+    Error<sharing::SharingUserError>,
+> {
+    let mut results = HashMap::with_capacity(files.len());
+    for chunk in files.chunks(FILE_METADATA_BATCH_SIZE) {
+        let arg = sharing::GetFileMetadataBatchArg::new(chunk.to_vec())
+            .with_actions(actions.to_vec());
+        for file in sharing::get_file_metadata_batch(client, &arg).await? {
+            let result = match file.result {
+                sharing::GetFileMetadataIndividualResult::Metadata(metadata) => Ok(metadata),
+                sharing::GetFileMetadataIndividualResult::AccessError(e) => Err(e),
+                sharing::GetFileMetadataIndividualResult::Other(_) => {
+                    warn!("skipping a result for {:?} of a kind unknown to this SDK version", file.file);
+                    continue;
+                }
+            };
+            results.insert(file.file, result);
+        }
+    }
+    Ok(results)
+}
+
+impl crate::types::sharing::SharedFileMetadata {
+    /// Whether the user is allowed to take the given action on the file, or `None` if the action
+    /// wasn't evaluated because it wasn't asked for in the request.
+    pub fn allows(&self, action: &sharing::FileAction) -> Option<bool> {
+        self.permissions.as_ref()?
+            .iter()
+            .find(|permission| &permission.action == action)
+            .map(|permission| permission.allow)
+    }
+}
+
+/// A member of a shared folder.
+#[derive(Debug, Clone, PartialEq)]
+pub enum FolderMember {
+    /// A user who is a member of the folder.
+    User(sharing::UserMembershipInfo),
+
+    /// A group which is a member of the folder.
+    Group(sharing::GroupMembershipInfo),
+
+    /// Someone who has been invited to the folder but hasn't joined yet.
+    Invitee(sharing::InviteeMembershipInfo),
+}
+
+impl FolderMember {
+    /// The member's level of access to the folder.
+    pub fn access_type(&self) -> &sharing::AccessLevel {
+        match self {
+            FolderMember::User(info) => &info.access_type,
+            FolderMember::Group(info) => &info.access_type,
+            FolderMember::Invitee(info) => &info.access_type,
+        }
+    }
+
+    /// Whether the member has access because of a parent folder.
+    pub fn is_inherited(&self) -> bool {
+        match self {
+            FolderMember::User(info) => info.is_inherited,
+            FolderMember::Group(info) => info.is_inherited,
+            FolderMember::Invitee(info) => info.is_inherited,
+        }
+    }
+}
+
+/// List all the members of a shared folder: users, groups, and invitees.
+///
+/// This follows the cursor through all the pages of `sharing/list_folder_members`. The users come
+/// first, then the groups, then the invitees, each in the order the server returned them.
+pub async fn list_all_folder_members(
+    client: &impl UserAuthClient,
+    shared_folder_id: impl Into<String>,
+) -> Result<Vec<FolderMember>, BoxedError> {
+    let arg = sharing::ListFolderMembersArgs::new(shared_folder_id.into());
+    let mut page = sharing::list_folder_members(client, &arg).await.map_err(Error::boxed)?;
+    let mut users = vec![];
+    let mut groups = vec![];
+    let mut invitees = vec![];
+    loop {
+        users.extend(page.users.into_iter().map(FolderMember::User));
+        groups.extend(page.groups.into_iter().map(FolderMember::Group));
+        invitees.extend(page.invitees.into_iter().map(FolderMember::Invitee));
+        let Some(cursor) = page.cursor else {
+            break;
+        };
+        let arg = sharing::ListFolderMembersContinueArg::new(cursor);
+        page = sharing::list_folder_members_continue(client, &arg).await.map_err(Error::boxed)?;
+    }
+    users.append(&mut groups);
+    users.append(&mut invitees);
+    Ok(users)
+}
+
+/// An invitation to a shared folder which the user hasn't added to their Dropbox yet.
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub struct Invitation {
+    /// The ID of the shared folder.
+    pub shared_folder_id: String,
+
+    /// The name of the shared folder.
+    pub name: String,
+
+    /// The access the user would have to the folder.
+    pub access_type: sharing::AccessLevel,
+
+    /// The display names of the folder's owners, if the user is allowed to see them.
+    pub owner_display_names: Vec<String>,
+
+    /// The name of the team the folder belongs to, if it's owned by a team.
+    pub owner_team_name: Option<String>,
+
+    /// When the user was invited, in the format described in [`crate::timestamp`].
+    pub time_invited: String,
+
+    /// A URL for previewing the folder on the website.
+    pub preview_url: String,
+}
+
+impl Invitation {
+    /// The invitation to a folder, if it isn't mounted yet.
+    fn from_metadata(metadata: sharing::SharedFolderMetadata) -> Option<Self> {
+        if metadata.path_lower.is_some() {
+            return None;
+        }
+        Some(Self {
+            shared_folder_id: metadata.shared_folder_id,
+            name: metadata.name,
+            access_type: metadata.access_type,
+            owner_display_names: metadata.owner_display_names.unwrap_or_default(),
+            owner_team_name: metadata.owner_team.map(|team| team.name),
+            time_invited: metadata.time_invited,
+            preview_url: metadata.preview_url,
+        })
+    }
+}
+
+/// List all the shared folders the user can mount, following the cursor through all the pages of
+/// `sharing/list_mountable_folders`.
+///
+/// This includes both the folders the user has already mounted, which have a
+/// [`path_lower`](sharing::SharedFolderMetadata::path_lower), and the ones they've been invited
+/// to but haven't mounted yet, which don't.
+pub async fn list_all_mountable_folders(client: &impl UserAuthClient)
+    -> Result<Vec<sharing::SharedFolderMetadata>, BoxedError>
+{
+    let mut page = sharing::list_mountable_folders(client, &sharing::ListFoldersArgs::default())
+        .await
+        .map_err(Error::boxed)?;
+    let mut folders = page.entries;
+    while let Some(cursor) = page.cursor {
+        let arg = sharing::ListFoldersContinueArg::new(cursor);
+        page = sharing::list_mountable_folders_continue(client, &arg).await.map_err(Error::boxed)?;
+        folders.append(&mut page.entries);
+    }
+    Ok(folders)
+}
+
+/// List the user's pending invitations to shared folders: the mountable folders which they haven't
+/// mounted yet.
+pub async fn list_invitations(client: &impl UserAuthClient)
+    -> Result<Vec<Invitation>, BoxedError>
+{
+    Ok(list_all_mountable_folders(client).await?
+        .into_iter()
+        .filter_map(Invitation::from_metadata)
+        .collect())
+}
+
+/// Accept an invitation to a shared folder, by mounting it. Returns the folder's metadata, which
+/// has the path it was mounted at.
+pub async fn accept_invitation(
+    client: &impl UserAuthClient,
+    invitation: &Invitation,
+) -> Result<sharing::SharedFolderMetadata, Error<sharing::MountFolderError>> {
+    mount_folder(client, &invitation.shared_folder_id).await
+}
+
+/// Mount a shared folder, adding it to the user's Dropbox. Returns the folder's metadata, which
+/// has the path it was mounted at.
+pub async fn mount_folder(
+    client: &impl UserAuthClient,
+    shared_folder_id: &str,
+) -> Result<sharing::SharedFolderMetadata, Error<sharing::MountFolderError>> {
+    let arg = sharing::MountFolderArg::new(shared_folder_id.to_owned());
+    sharing::mount_folder(client, &arg).await
+}
+
+/// Unmount a shared folder, removing it from the user's Dropbox without leaving it. It can be
+/// mounted again later, and it will be listed as an [`Invitation`] until then.
+pub async fn unmount_folder(
+    client: &impl UserAuthClient,
+    shared_folder_id: &str,
+) -> Result<(), Error<sharing::UnmountFolderError>> {
+    let arg = sharing::UnmountFolderArg::new(shared_folder_id.to_owned());
+    sharing::unmount_folder(client, &arg).await
+}
+
+/// Options for [`transfer_folder_ownership`].
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub struct OwnershipTransferOptions {
+    /// After transferring ownership, leave the folder, so the previous owner no longer has access
+    /// to it. Defaults to false.
+    pub relinquish: bool,
+
+    /// When leaving the folder, keep a copy of its contents in the previous owner's Dropbox.
+    /// Defaults to false. Must be false if the folder is inside a team folder or another shared
+    /// folder.
+    pub leave_a_copy: bool,
+
+    /// How long to wait between checks on the job leaving the folder, if the server runs it
+    /// asynchronously. Defaults to 1 second.
+    pub poll_interval: Duration,
+}
+
+impl Default for OwnershipTransferOptions {
+    fn default() -> Self {
+        Self {
+            relinquish: false,
+            leave_a_copy: false,
+            poll_interval: Duration::from_secs(1),
+        }
+    }
+}
+
+impl OwnershipTransferOptions {
+    /// Set whether to leave the folder after transferring ownership.
+    pub fn with_relinquish(mut self, value: bool) -> Self {
+        self.relinquish = value;
+        self
+    }
+
+    /// Set whether to keep a copy of the folder's contents when leaving it.
+    pub fn with_leave_a_copy(mut self, value: bool) -> Self {
+        self.leave_a_copy = value;
+        self
+    }
+
+    /// Set the interval for checking on the job leaving the folder.
+    pub fn with_poll_interval(mut self, value: Duration) -> Self {
+        self.poll_interval = value;
+        self
+    }
+}
+
+/// What [`transfer_folder_ownership`] did.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct OwnershipTransferOutcome {
+    /// The account ID of the new owner.
+    pub new_owner_account_id: String,
+
+    /// Whether the previous owner left the folder.
+    pub relinquished: bool,
+
+    /// The ID of the asynchronous job the server used for leaving the folder, if it wasn't
+    /// completed immediately.
+    pub job_id: Option<String>,
+}
+
+/// An error from [`transfer_folder_ownership`].
+#[derive(thiserror::Error, Debug)]
+pub enum OwnershipTransferError {
+    /// Listing the folder's members, to check the new owner is one of them, failed. Ownership was
+    /// not transferred.
+    #[error("failed to list shared folder members: {0}")]
+    ListMembers(#[source] BoxedError),
+
+    /// Ownership was not transferred, either because the server rejected it, or because the new
+    /// owner isn't a member of the folder. In the latter case, no request to transfer it was
+    /// made, and the error is the same
+    /// [`NewOwnerNotAMember`](sharing::TransferFolderError::NewOwnerNotAMember) the server would
+    /// have returned.
+    #[error("failed to transfer shared folder ownership: {0}")]
+    Transfer(#[source] Error<sharing::TransferFolderError>),
+
+    /// Ownership was transferred, but leaving the folder failed.
+    #[error("transferred shared folder ownership, but failed to leave the folder: {0}")]
+    Relinquish(#[source] Error<sharing::RelinquishFolderMembershipError>),
+
+    /// Ownership was transferred and leaving the folder was started, but checking its progress
+    /// failed, so it's unknown whether it finished.
+    #[error("failed to check on job {job_id} leaving the shared folder: {error}")]
+    Poll {
+        /// The ID of the job, which can be used to check on it again later with
+        /// [`check_job_status`](crate::sharing::check_job_status).
+        job_id: String,
+
+        /// The error checking on the job.
+        #[source]
+        error: Error<dbx_async::PollError>,
+    },
+
+    /// Ownership was transferred, but the job leaving the folder failed.
+    #[error("job {job_id} leaving the shared folder failed: {error}")]
+    JobFailed {
+        /// The ID of the job.
+        job_id: String,
+
+        /// Why the job failed.
+        #[source]
+        error: sharing::JobError,
+    },
+}
+
+/// Transfer ownership of a shared folder to another member of it, and optionally leave the folder
+/// afterwards, waiting for that to finish. This is what offboarding tools need to do with each
+/// folder a departing user owns before their account is removed.
+///
+/// `new_owner` is the account ID or team member ID of the new owner. The folder's members are
+/// listed first, to check that the new owner is a direct member of it, as the server requires.
+pub async fn transfer_folder_ownership(
+    client: &impl UserAuthClient,
+    shared_folder_id: &str,
+    new_owner: &str,
+    options: &OwnershipTransferOptions,
+) -> Result<OwnershipTransferOutcome, OwnershipTransferError> {
+    let members = list_all_folder_members(client, shared_folder_id).await
+        .map_err(OwnershipTransferError::ListMembers)?;
+    let new_owner_account_id = members.iter()
+        .find_map(|member| match member {
+            FolderMember::User(info) if !info.is_inherited
+                && (info.user.account_id == new_owner
+                    || info.user.team_member_id.as_deref() == Some(new_owner)) =>
+            {
+                Some(info.user.account_id.clone())
+            }
+            _ => None,
+        })
+        .ok_or(OwnershipTransferError::Transfer(
+            Error::Api(sharing::TransferFolderError::NewOwnerNotAMember)))?;
+
+    let arg = sharing::TransferFolderArg::new(shared_folder_id.to_owned(), new_owner.to_owned());
+    sharing::transfer_folder(client, &arg).await.map_err(OwnershipTransferError::Transfer)?;
+    debug!("transferred ownership of shared folder {shared_folder_id} to {new_owner}");
+
+    let mut outcome = OwnershipTransferOutcome {
+        new_owner_account_id,
+        relinquished: false,
+        job_id: None,
+    };
+    if !options.relinquish {
+        return Ok(outcome);
+    }
+
+    let arg = sharing::RelinquishFolderMembershipArg::new(shared_folder_id.to_owned())
+        .with_leave_a_copy(options.leave_a_copy);
+    let launch = sharing::relinquish_folder_membership(client, &arg).await
+        .map_err(OwnershipTransferError::Relinquish)?;
+    if let dbx_async::LaunchEmptyResult::AsyncJobId(job_id) = launch {
+        debug!("leaving shared folder {shared_folder_id} is running as job {job_id}");
+        let poll_arg = dbx_async::PollArg::new(job_id.clone());
+        let status = crate::async_job::poll_until_complete(options.poll_interval, || async {
+            match sharing::check_job_status(client, &poll_arg).await? {
+                sharing::JobStatus::InProgress => Ok(None),
+                status => Ok(Some(status)),
+            }
+        }).await.map_err(|error| OwnershipTransferError::Poll { job_id: job_id.clone(), error })?;
+        if let sharing::JobStatus::Failed(error) = status {
+            return Err(OwnershipTransferError::JobFailed { job_id, error });
+        }
+        outcome.job_id = Some(job_id);
+    }
+    outcome.relinquished = true;
+    Ok(outcome)
+}
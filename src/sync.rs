@@ -0,0 +1,10 @@
+//! The sync routes and client traits, at paths which don't depend on which routes are in the
+//! crate root.
+//!
+//! Whether `dropbox_sdk::files` is the sync or async version of the routes depends on the
+//! `sync_routes_in_root` feature, so code importing from there breaks when another crate in the
+//! build turns the feature on or off. The routes here, such as `dropbox_sdk::sync::files`, are
+//! always the sync ones, and exist whenever the `sync_routes` feature is on.
+
+pub use crate::generated::sync_routes::*;
+pub use crate::client_trait::{AppAuthClient, NoauthClient, TeamAuthClient, UserAuthClient};
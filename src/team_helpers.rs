@@ -0,0 +1,615 @@
+//! Higher-level helpers for common team administration tasks.
+//!
+//! [`MemberResolver`] turns the team member IDs, emails, and account IDs which other calls return
+//! into members' profiles, looking them up in bulk and caching them.
+//!
+//! Like the [`files_helpers`](crate::files_helpers), these are async functions, which can also be
+//! used with sync clients by driving them with any executor. Functions which wait on server-side
+//! jobs need an executor which supports timers, such as `futures::executor::block_on`.
+
+use std::collections::{HashMap, HashSet};
+use std::sync::Mutex;
+use std::time::Duration;
+use crate::async_client_trait::TeamAuthClient;
+use crate::generated::async_routes::{dbx_async, team};
+use crate::types::team_common::GroupSummary;
+use crate::{pagination, BoxedError, Error};
+
+/// Options for [`offboard_member`].
+///
+/// The defaults match those of [`MembersRemoveArg`](team::MembersRemoveArg): the member's data is
+/// wiped from their linked devices and their account is not kept.
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub struct OffboardOptions {
+    /// Wipe the member's data from their linked devices. Must be false if `keep_account` is set.
+    pub wipe_data: bool,
+
+    /// Downgrade the member to a Basic account instead of deleting it. Can't be combined with
+    /// transferring their files to another member.
+    pub keep_account: bool,
+
+    /// Let the member keep access to team folders and files shared with them, as a Basic account.
+    /// Requires `keep_account`.
+    pub retain_team_shares: bool,
+
+    /// How long to wait between checks on the removal job, if the server runs it asynchronously.
+    pub poll_interval: Duration,
+}
+
+impl Default for OffboardOptions {
+    fn default() -> Self {
+        Self {
+            wipe_data: true,
+            keep_account: false,
+            retain_team_shares: false,
+            poll_interval: Duration::from_secs(1),
+        }
+    }
+}
+
+impl OffboardOptions {
+    /// Set whether to wipe the member's data from their linked devices.
+    pub fn with_wipe_data(mut self, value: bool) -> Self {
+        self.wipe_data = value;
+        self
+    }
+
+    /// Set whether to keep the member's account as a Basic account.
+    pub fn with_keep_account(mut self, value: bool) -> Self {
+        self.keep_account = value;
+        self
+    }
+
+    /// Set whether the member keeps access to team shares.
+    pub fn with_retain_team_shares(mut self, value: bool) -> Self {
+        self.retain_team_shares = value;
+        self
+    }
+
+    /// Set the interval for checking on the removal job.
+    pub fn with_poll_interval(mut self, value: Duration) -> Self {
+        self.poll_interval = value;
+        self
+    }
+}
+
+/// What [`offboard_member`] did.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct OffboardOutcome {
+    /// The team member ID of the removed member.
+    pub member_id: String,
+
+    /// The team member ID the removed member's files were transferred to, if any.
+    pub transferred_to: Option<String>,
+
+    /// Whether the member's account was kept as a Basic account.
+    pub kept_account: bool,
+
+    /// The ID of the asynchronous job the server used for the removal, if it wasn't completed
+    /// immediately.
+    pub job_id: Option<String>,
+}
+
+/// An error from [`offboard_member`].
+#[derive(thiserror::Error, Debug)]
+pub enum OffboardError {
+    /// The removal was not started, either because the server rejected it, or because the
+    /// combination of arguments was invalid. In the latter case, no request was made, and the
+    /// error is the same [`Error::Api`] the server would have returned.
+    #[error("failed to remove team member: {0}")]
+    Remove(#[source] Error<team::MembersRemoveError>),
+
+    /// The removal was started, but checking its progress failed, so it's unknown whether it
+    /// finished.
+    #[error("failed to check on team member removal job {job_id}: {error}")]
+    Poll {
+        /// The ID of the removal job, which can be used to check on it again later with
+        /// [`members_remove_job_status_get`](crate::team::members_remove_job_status_get).
+        job_id: String,
+
+        /// The error checking on the job.
+        #[source]
+        error: Error<dbx_async::PollError>,
+    },
+}
+
+/// Remove a member from the team, optionally transferring their files to another member, and wait
+/// for the removal to finish.
+///
+/// All IDs are team member IDs. If `transfer_to` is given, `admin_id` must be as well: it's the
+/// admin who will be emailed about any errors in the transfer.
+///
+/// The combinations of arguments that the server is known to reject are checked before making any
+/// request.
+pub async fn offboard_member(
+    client: &impl TeamAuthClient,
+    member_id: &str,
+    transfer_to: Option<&str>,
+    admin_id: Option<&str>,
+    options: &OffboardOptions,
+) -> Result<OffboardOutcome, OffboardError> {
+    validate_offboard(member_id, transfer_to, admin_id, options)
+        .map_err(|e| OffboardError::Remove(Error::Api(e)))?;
+
+    let selector = |id: &str| team::UserSelectorArg::TeamMemberId(id.to_owned());
+    let arg = team::MembersRemoveArg {
+        user: selector(member_id),
+        wipe_data: options.wipe_data,
+        transfer_dest_id: transfer_to.map(selector),
+        transfer_admin_id: admin_id.map(selector),
+        keep_account: options.keep_account,
+        retain_team_shares: options.retain_team_shares,
+    };
+
+    let job_id = match team::members_remove(client, &arg).await.map_err(OffboardError::Remove)? {
+        dbx_async::LaunchEmptyResult::Complete => None,
+        dbx_async::LaunchEmptyResult::AsyncJobId(job_id) => {
+            debug!("removal of team member {member_id} is running as job {job_id}");
+            let poll_arg = dbx_async::PollArg::new(job_id.clone());
+            crate::async_job::poll_until_complete(options.poll_interval, || async {
+                match team::members_remove_job_status_get(client, &poll_arg).await? {
+                    dbx_async::PollEmptyResult::InProgress => Ok(None),
+                    dbx_async::PollEmptyResult::Complete => Ok(Some(())),
+                }
+            }).await.map_err(|error| OffboardError::Poll { job_id: job_id.clone(), error })?;
+            Some(job_id)
+        }
+    };
+
+    Ok(OffboardOutcome {
+        member_id: member_id.to_owned(),
+        transferred_to: transfer_to.map(str::to_owned),
+        kept_account: options.keep_account,
+        job_id,
+    })
+}
+
+fn validate_offboard(
+    member_id: &str,
+    transfer_to: Option<&str>,
+    admin_id: Option<&str>,
+    options: &OffboardOptions,
+) -> Result<(), team::MembersRemoveError> {
+    use team::MembersRemoveError as E;
+    if transfer_to == Some(member_id) {
+        Err(E::RemovedAndTransferDestShouldDiffer)
+    } else if transfer_to.is_some() && admin_id.is_none() {
+        Err(E::UnspecifiedTransferAdminId)
+    } else if options.keep_account && transfer_to.is_some() {
+        Err(E::CannotKeepAccountAndTransfer)
+    } else if options.keep_account && options.wipe_data {
+        Err(E::CannotKeepAccountAndDeleteData)
+    } else if options.retain_team_shares && options.wipe_data {
+        Err(E::CannotRetainSharesWhenDataWiped)
+    } else if options.retain_team_shares && !options.keep_account {
+        Err(E::CannotRetainSharesWhenNoAccountKept)
+    } else {
+        Ok(())
+    }
+}
+
+/// Manages the team's groups, for provisioning tools which keep them in sync with another system.
+///
+/// Members are identified by their team member IDs. Adding and removing members can run as
+/// server-side jobs, which this waits for, checking on them every
+/// [`poll_interval`](Self::with_poll_interval).
+#[derive(Debug)]
+pub struct Groups<'a, C> {
+    client: &'a C,
+    poll_interval: Duration,
+    page_size: Option<u32>,
+}
+
+/// What [`Groups::ensure_group`] did.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct EnsureGroupOutcome {
+    /// The ID of the group.
+    pub group_id: String,
+
+    /// Whether the group was created.
+    pub created: bool,
+
+    /// The team member IDs of the members that were added.
+    pub added: Vec<String>,
+
+    /// The team member IDs of the members that were removed.
+    pub removed: Vec<String>,
+}
+
+impl<'a, C: TeamAuthClient> Groups<'a, C> {
+    /// Manage the groups of the team the client is authorized for.
+    pub fn new(client: &'a C) -> Self {
+        Self {
+            client,
+            poll_interval: Duration::from_secs(1),
+            page_size: None,
+        }
+    }
+
+    /// Set the interval for checking on jobs which add or remove members. Defaults to 1 second.
+    pub fn with_poll_interval(mut self, value: Duration) -> Self {
+        self.poll_interval = value;
+        self
+    }
+
+    /// Set how many groups or group members to ask for in each page when listing them. Defaults
+    /// to the most the routes allow; see [`pagination`].
+    pub fn with_page_size(mut self, value: u32) -> Self {
+        self.page_size = Some(value);
+        self
+    }
+
+    /// List all the groups in the team, following the cursor through all the pages of
+    /// `team/groups/list`.
+    pub async fn list(&self) -> Result<Vec<GroupSummary>, BoxedError> {
+        let arg = team::GroupsListArg::default()
+            .with_limit(pagination::page_size("team/groups/list", self.page_size));
+        let mut page = team::groups_list(self.client, &arg).await.map_err(Error::boxed)?;
+        let mut groups = page.groups;
+        while page.has_more {
+            let arg = team::GroupsListContinueArg::new(page.cursor);
+            page = team::groups_list_continue(self.client, &arg).await.map_err(Error::boxed)?;
+            groups.append(&mut page.groups);
+        }
+        Ok(groups)
+    }
+
+    /// Find a group by its name. Group names are compared case-insensitively, as the server does.
+    pub async fn find(&self, name: &str) -> Result<Option<GroupSummary>, BoxedError> {
+        let name = name.to_lowercase();
+        Ok(self.list().await?
+            .into_iter()
+            .find(|group| group.group_name.to_lowercase() == name))
+    }
+
+    /// Create a group with the given name and no members.
+    pub async fn create(&self, name: &str)
+        -> Result<team::GroupFullInfo, Error<team::GroupCreateError>>
+    {
+        team::groups_create(self.client, &team::GroupCreateArg::new(name.to_owned())).await
+    }
+
+    /// List all the members of a group, following the cursor through all the pages of
+    /// `team/groups/members/list`.
+    pub async fn members(&self, group_id: &str)
+        -> Result<Vec<team::GroupMemberInfo>, BoxedError>
+    {
+        let arg = team::GroupsMembersListArg::new(group_selector(group_id))
+            .with_limit(pagination::page_size("team/groups/members/list", self.page_size));
+        let mut page = team::groups_members_list(self.client, &arg).await.map_err(Error::boxed)?;
+        let mut members = page.members;
+        while page.has_more {
+            let arg = team::GroupsMembersListContinueArg::new(page.cursor);
+            page = team::groups_members_list_continue(self.client, &arg)
+                .await
+                .map_err(Error::boxed)?;
+            members.append(&mut page.members);
+        }
+        Ok(members)
+    }
+
+    /// Add members to a group, with the given access type, and wait for it to finish.
+    pub async fn add_members(
+        &self,
+        group_id: &str,
+        member_ids: &[&str],
+        access_type: team::GroupAccessType,
+    ) -> Result<(), BoxedError> {
+        let members = member_ids.iter()
+            .map(|id| team::MemberAccess::new(user_selector(id), access_type.clone()))
+            .collect();
+        let arg = team::GroupMembersAddArg::new(group_selector(group_id), members)
+            .with_return_members(false);
+        let result = team::groups_members_add(self.client, &arg).await.map_err(Error::boxed)?;
+        self.wait_for_job(result.async_job_id).await
+    }
+
+    /// Remove members from a group, and wait for it to finish.
+    pub async fn remove_members(&self, group_id: &str, member_ids: &[&str])
+        -> Result<(), BoxedError>
+    {
+        let users = member_ids.iter().map(|id| user_selector(id)).collect();
+        let arg = team::GroupMembersRemoveArg::new(group_selector(group_id), users)
+            .with_return_members(false);
+        let result = team::groups_members_remove(self.client, &arg).await.map_err(Error::boxed)?;
+        self.wait_for_job(result.async_job_id).await
+    }
+
+    /// Make sure a group with the given name exists and has exactly the given members, creating
+    /// it and adding and removing members as needed.
+    ///
+    /// This is idempotent: running it again with the same arguments makes no changes. Members who
+    /// are added get the [`Member`](team::GroupAccessType::Member) access type, and the access
+    /// type of existing members is left alone.
+    pub async fn ensure_group(&self, name: &str, member_ids: &[&str])
+        -> Result<EnsureGroupOutcome, BoxedError>
+    {
+        let (group_id, created) = match self.find(name).await? {
+            Some(group) => (group.group_id, false),
+            None => match self.create(name).await {
+                Ok(group) => (group.group_id, true),
+                // Someone else created it in the meantime.
+                Err(Error::Api(team::GroupCreateError::GroupNameAlreadyUsed)) => {
+                    match self.find(name).await? {
+                        Some(group) => (group.group_id, false),
+                        None => return Err(
+                            Error::Api(team::GroupCreateError::GroupNameAlreadyUsed).boxed()),
+                    }
+                }
+                Err(e) => return Err(e.boxed()),
+            },
+        };
+
+        let current = if created {
+            HashSet::new()
+        } else {
+            self.members(&group_id).await?
+                .into_iter()
+                .map(|member| member.profile.team_member_id)
+                .collect::<HashSet<_>>()
+        };
+        let mut wanted = HashSet::new();
+        let added = member_ids.iter()
+            .copied()
+            .filter(|id| wanted.insert(*id) && !current.contains(*id))
+            .collect::<Vec<_>>();
+        let mut removed = current.iter()
+            .map(String::as_str)
+            .filter(|id| !wanted.contains(id))
+            .collect::<Vec<_>>();
+        removed.sort_unstable();
+
+        if !added.is_empty() {
+            debug!("adding {} members to group {group_id}", added.len());
+            self.add_members(&group_id, &added, team::GroupAccessType::Member).await?;
+        }
+        if !removed.is_empty() {
+            debug!("removing {} members from group {group_id}", removed.len());
+            self.remove_members(&group_id, &removed).await?;
+        }
+
+        Ok(EnsureGroupOutcome {
+            created,
+            added: added.into_iter().map(str::to_owned).collect(),
+            removed: removed.into_iter().map(str::to_owned).collect(),
+            group_id,
+        })
+    }
+
+    async fn wait_for_job(&self, job_id: String) -> Result<(), BoxedError> {
+        if job_id.is_empty() {
+            return Ok(());
+        }
+        let poll_arg = dbx_async::PollArg::new(job_id);
+        crate::async_job::poll_until_complete(self.poll_interval, || async {
+            match team::groups_job_status_get(self.client, &poll_arg).await? {
+                dbx_async::PollEmptyResult::InProgress => Ok(None),
+                dbx_async::PollEmptyResult::Complete => Ok(Some(())),
+            }
+        }).await.map_err(Error::boxed)
+    }
+}
+
+fn group_selector(group_id: &str) -> team::GroupSelector {
+    team::GroupSelector::GroupId(group_id.to_owned())
+}
+
+fn user_selector(member_id: &str) -> team::UserSelectorArg {
+    team::UserSelectorArg::TeamMemberId(member_id.to_owned())
+}
+
+/// The most members to look up in one `team/members/get_info_v2` request.
+const GET_INFO_BATCH_SIZE: usize = 100;
+
+/// Resolves team members' identities into their profiles, caching the results, for rendering
+/// team member IDs, emails, and account IDs found in the results of other calls as people.
+///
+/// Members can be looked up by team member ID, email, or external ID, which uses
+/// `team/members/get_info_v2`, or by account ID, which isn't something that call accepts, so the
+/// first lookup by an account ID that isn't cached lists all of the team's members instead. Once
+/// a member has been looked up, looking them up again by any of their IDs or their email uses the
+/// cache. So do lookups of IDs which didn't match any member.
+///
+/// The cache isn't invalidated, so members who change their email, join, or leave while the
+/// resolver is in use might not be resolved correctly; use a new resolver, or
+/// [`clear`](Self::clear) it, to see the changes.
+#[derive(Debug)]
+pub struct MemberResolver<'a, C> {
+    client: &'a C,
+    cache: Mutex<MemberCache>,
+    page_size: Option<u32>,
+}
+
+#[derive(Debug, Default)]
+struct MemberCache {
+    /// Profiles by team member ID.
+    profiles: HashMap<String, team::TeamMemberProfile>,
+
+    /// The team member IDs of the selectors which have been looked up, or `None` for ones which
+    /// didn't match any member.
+    index: HashMap<MemberKey, Option<String>>,
+
+    /// Whether all of the team's members have been listed into the cache.
+    listed: bool,
+}
+
+/// The identities members can be looked up by. Emails are compared case-insensitively.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+enum MemberKey {
+    TeamMemberId(String),
+    ExternalId(String),
+    Email(String),
+    AccountId(String),
+}
+
+impl From<&team::UserSelectorArg> for MemberKey {
+    fn from(selector: &team::UserSelectorArg) -> Self {
+        match selector {
+            team::UserSelectorArg::TeamMemberId(id) => MemberKey::TeamMemberId(id.clone()),
+            team::UserSelectorArg::ExternalId(id) => MemberKey::ExternalId(id.clone()),
+            team::UserSelectorArg::Email(email) => MemberKey::Email(email.to_lowercase()),
+        }
+    }
+}
+
+impl MemberCache {
+    fn get(&self, key: &MemberKey) -> Option<Option<&team::TeamMemberProfile>> {
+        self.index.get(key).map(|id| id.as_ref().and_then(|id| self.profiles.get(id)))
+    }
+
+    fn insert(&mut self, profile: team::TeamMemberProfile) {
+        let id = &profile.team_member_id;
+        self.index.insert(MemberKey::TeamMemberId(id.clone()), Some(id.clone()));
+        self.index.insert(MemberKey::Email(profile.email.to_lowercase()), Some(id.clone()));
+        if let Some(external_id) = &profile.external_id {
+            self.index.insert(MemberKey::ExternalId(external_id.clone()), Some(id.clone()));
+        }
+        if let Some(account_id) = &profile.account_id {
+            self.index.insert(MemberKey::AccountId(account_id.clone()), Some(id.clone()));
+        }
+        self.profiles.insert(id.clone(), profile);
+    }
+}
+
+impl<'a, C: TeamAuthClient> MemberResolver<'a, C> {
+    /// Resolve members of the team the client is authorized for, starting with an empty cache.
+    pub fn new(client: &'a C) -> Self {
+        Self {
+            client,
+            cache: Mutex::new(MemberCache::default()),
+            page_size: None,
+        }
+    }
+
+    /// Set how many members to ask for in each page when listing all of them. Defaults to the
+    /// most `team/members/list_v2` allows; see [`pagination`].
+    pub fn with_page_size(mut self, value: u32) -> Self {
+        self.page_size = Some(value);
+        self
+    }
+
+    /// Look up members by team member ID, email, or external ID, returning their profiles in the
+    /// same order, or `None` for ones which don't match any member.
+    ///
+    /// Only the members which aren't cached are requested, in as few requests as possible.
+    pub async fn resolve(&self, members: &[team::UserSelectorArg])
+        -> Result<Vec<Option<team::TeamMemberProfile>>, Error<team::MembersGetInfoError>>
+    {
+        let mut missing = vec![];
+        {
+            let cache = self.cache.lock().unwrap();
+            for selector in members {
+                if cache.get(&MemberKey::from(selector)).is_none()
+                    && !missing.contains(selector)
+                {
+                    missing.push(selector.clone());
+                }
+            }
+        }
+
+        for batch in missing.chunks(GET_INFO_BATCH_SIZE) {
+            debug!("looking up {} team members", batch.len());
+            let arg = team::MembersGetInfoV2Arg::new(batch.to_vec());
+            let result = team::members_get_info_v2(self.client, &arg).await?;
+            let mut cache = self.cache.lock().unwrap();
+            // The results are in the same order as the selectors.
+            for (selector, item) in batch.iter().zip(result.members_info) {
+                let key = MemberKey::from(selector);
+                match item {
+                    team::MembersGetInfoItemV2::MemberInfo(info) => {
+                        let id = info.profile.team_member_id.clone();
+                        cache.insert(info.profile);
+                        // The member may have been looked up by a former email or external ID.
+                        cache.index.insert(key, Some(id));
+                    }
+                    team::MembersGetInfoItemV2::IdNotFound(_) => {
+                        cache.index.insert(key, None);
+                    }
+                    team::MembersGetInfoItemV2::Other(_) => (),
+                }
+            }
+        }
+
+        let cache = self.cache.lock().unwrap();
+        Ok(members.iter()
+            .map(|selector| cache.get(&MemberKey::from(selector)).flatten().cloned())
+            .collect())
+    }
+
+    /// Look up a member by their team member ID.
+    pub async fn by_member_id(&self, team_member_id: &str)
+        -> Result<Option<team::TeamMemberProfile>, Error<team::MembersGetInfoError>>
+    {
+        self.resolve_one(team::UserSelectorArg::TeamMemberId(team_member_id.to_owned())).await
+    }
+
+    /// Look up a member by their email address, case-insensitively.
+    pub async fn by_email(&self, email: &str)
+        -> Result<Option<team::TeamMemberProfile>, Error<team::MembersGetInfoError>>
+    {
+        self.resolve_one(team::UserSelectorArg::Email(email.to_owned())).await
+    }
+
+    /// Look up a member by their account ID.
+    ///
+    /// If no cached member has this account ID, all of the team's members are listed into the
+    /// cache, the first time this happens.
+    pub async fn by_account_id(&self, account_id: &str)
+        -> Result<Option<team::TeamMemberProfile>, BoxedError>
+    {
+        let key = MemberKey::AccountId(account_id.to_owned());
+        {
+            let cache = self.cache.lock().unwrap();
+            if let Some(profile) = cache.get(&key) {
+                return Ok(profile.cloned());
+            }
+            if cache.listed {
+                return Ok(None);
+            }
+        }
+        self.load_all().await?;
+        Ok(self.cache.lock().unwrap().get(&key).flatten().cloned())
+    }
+
+    /// List all of the team's members into the cache, following the cursor through all the pages
+    /// of `team/members/list_v2`, so that later lookups don't need any requests.
+    ///
+    /// Removed members are included, so that IDs found in older records can still be resolved.
+    pub async fn load_all(&self) -> Result<(), BoxedError> {
+        let arg = team::MembersListArg::default()
+            .with_include_removed(true)
+            .with_limit(pagination::page_size("team/members/list_v2", self.page_size));
+        let mut page = team::members_list_v2(self.client, &arg).await.map_err(Error::boxed)?;
+        loop {
+            {
+                let mut cache = self.cache.lock().unwrap();
+                for member in page.members {
+                    cache.insert(member.profile);
+                }
+            }
+            if !page.has_more {
+                break;
+            }
+            let arg = team::MembersListContinueArg::new(page.cursor);
+            page = team::members_list_continue_v2(self.client, &arg)
+                .await
+                .map_err(Error::boxed)?;
+        }
+        self.cache.lock().unwrap().listed = true;
+        Ok(())
+    }
+
+    /// Forget everything that's been looked up.
+    pub fn clear(&self) {
+        *self.cache.lock().unwrap() = MemberCache::default();
+    }
+
+    async fn resolve_one(&self, selector: team::UserSelectorArg)
+        -> Result<Option<team::TeamMemberProfile>, Error<team::MembersGetInfoError>>
+    {
+        Ok(self.resolve(&[selector]).await?.pop().flatten())
+    }
+}
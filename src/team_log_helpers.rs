@@ -0,0 +1,239 @@
+//! Helpers for reading the team's activity log, from the `team_log` namespace.
+//!
+//! [`team_events`] lists the events matching an [`EventFilter`], and accessors on
+//! [`TeamEvent`](team_log::TeamEvent) and [`UserLogInfo`](team_log::UserLogInfo) pull out the
+//! fields which are most often needed, such as who did something and which files it involved,
+//! without matching on all the unions they're nested in.
+//!
+//! Like the [`files_helpers`](crate::files_helpers), these are async functions, which can also be
+//! used with sync clients by driving them with any executor.
+
+use std::time::SystemTime;
+use futures::Stream;
+use crate::async_client_trait::TeamAuthClient;
+use crate::generated::async_routes::team_log;
+use crate::types::team_common::TimeRange;
+use crate::{pagination, timestamp, BoxedError};
+
+/// Which kinds of events an [`EventFilter`] matches.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EventKind {
+    /// Events in the given category.
+    Category(team_log::EventCategory),
+
+    /// Events of the given type.
+    Type(team_log::EventTypeArg),
+}
+
+/// Which events [`team_events`] returns. By default, it returns all of them.
+#[derive(Debug, Clone, Default)]
+#[non_exhaustive]
+pub struct EventFilter {
+    /// Only return events of this kind.
+    pub kind: Option<EventKind>,
+
+    /// Only return events which involve the account with this ID, as the actor, the context, or a
+    /// participant.
+    pub account_id: Option<String>,
+
+    /// Only return events which happened at or after this time.
+    pub start_time: Option<SystemTime>,
+
+    /// Only return events which happened before this time.
+    pub end_time: Option<SystemTime>,
+
+    /// How many events to ask for in each page. Defaults to the most `team_log/get_events`
+    /// allows; see [`pagination`].
+    pub page_size: Option<u32>,
+}
+
+impl EventFilter {
+    /// Only return events in the given category.
+    pub fn with_category(mut self, value: team_log::EventCategory) -> Self {
+        self.kind = Some(EventKind::Category(value));
+        self
+    }
+
+    /// Only return events of the given type.
+    pub fn with_event_type(mut self, value: team_log::EventTypeArg) -> Self {
+        self.kind = Some(EventKind::Type(value));
+        self
+    }
+
+    /// Only return events involving the account with the given ID.
+    pub fn with_account_id(mut self, value: impl Into<String>) -> Self {
+        self.account_id = Some(value.into());
+        self
+    }
+
+    /// Only return events which happened at or after the given time.
+    pub fn with_start_time(mut self, value: SystemTime) -> Self {
+        self.start_time = Some(value);
+        self
+    }
+
+    /// Only return events which happened before the given time.
+    pub fn with_end_time(mut self, value: SystemTime) -> Self {
+        self.end_time = Some(value);
+        self
+    }
+
+    /// Set how many events to ask for in each page.
+    pub fn with_page_size(mut self, value: u32) -> Self {
+        self.page_size = Some(value);
+        self
+    }
+
+    fn to_arg(&self) -> team_log::GetTeamEventsArg {
+        let mut arg = team_log::GetTeamEventsArg::default()
+            .with_limit(pagination::page_size("team_log/get_events", self.page_size));
+        match &self.kind {
+            Some(EventKind::Category(category)) => arg.category = Some(category.clone()),
+            Some(EventKind::Type(event_type)) => arg.event_type = Some(event_type.clone()),
+            None => (),
+        }
+        arg.account_id.clone_from(&self.account_id);
+        if self.start_time.is_some() || self.end_time.is_some() {
+            arg.time = Some(TimeRange {
+                start_time: self.start_time.map(timestamp::from_system_time),
+                end_time: self.end_time.map(timestamp::from_system_time),
+            });
+        }
+        arg
+    }
+}
+
+/// List the events in the team's activity log which match the filter, as a stream, following the
+/// cursor through all the pages of `team_log/get_events` and `team_log/get_events/continue`.
+///
+/// Events are in order of when they happened, oldest first. The stream ends after the first
+/// error.
+pub fn team_events<'a>(
+    client: &'a impl TeamAuthClient,
+    filter: &EventFilter,
+) -> impl Stream<Item = Result<team_log::TeamEvent, BoxedError>> + Send + 'a {
+    struct State<'a, C> {
+        client: &'a C,
+        arg: Option<team_log::GetTeamEventsArg>,
+        cursor: Option<String>,
+        events: std::vec::IntoIter<team_log::TeamEvent>,
+        done: bool,
+    }
+
+    let state = State {
+        client,
+        arg: Some(filter.to_arg()),
+        cursor: None,
+        events: vec![].into_iter(),
+        done: false,
+    };
+
+    futures::stream::unfold(state, |mut state| async move {
+        loop {
+            if let Some(event) = state.events.next() {
+                return Some((Ok(event), state));
+            }
+            if state.done {
+                return None;
+            }
+
+            let page = match (state.arg.take(), state.cursor.take()) {
+                (Some(arg), _) => team_log::get_events(state.client, &arg).await
+                    .map_err(crate::Error::boxed),
+                (None, Some(cursor)) => {
+                    let arg = team_log::GetTeamEventsContinueArg::new(cursor);
+                    team_log::get_events_continue(state.client, &arg).await
+                        .map_err(crate::Error::boxed)
+                }
+                (None, None) => return None,
+            };
+            match page {
+                Ok(page) => {
+                    state.events = page.events.into_iter();
+                    state.done = !page.has_more;
+                    state.cursor = Some(page.cursor);
+                }
+                Err(e) => {
+                    state.done = true;
+                    return Some((Err(e), state));
+                }
+            }
+        }
+    })
+}
+
+impl crate::types::team_log::UserLogInfo {
+    /// The user's account ID, if known.
+    pub fn account_id(&self) -> Option<&str> {
+        match self {
+            Self::TeamMember(info) => info.account_id.as_deref(),
+            Self::TrustedNonTeamMember(info) => info.account_id.as_deref(),
+            Self::NonTeamMember(info) => info.account_id.as_deref(),
+            Self::Other => None,
+        }
+    }
+
+    /// The user's display name, if known.
+    pub fn display_name(&self) -> Option<&str> {
+        match self {
+            Self::TeamMember(info) => info.display_name.as_deref(),
+            Self::TrustedNonTeamMember(info) => info.display_name.as_deref(),
+            Self::NonTeamMember(info) => info.display_name.as_deref(),
+            Self::Other => None,
+        }
+    }
+
+    /// The user's email address, if known.
+    pub fn email(&self) -> Option<&str> {
+        match self {
+            Self::TeamMember(info) => info.email.as_deref(),
+            Self::TrustedNonTeamMember(info) => info.email.as_deref(),
+            Self::NonTeamMember(info) => info.email.as_deref(),
+            Self::Other => None,
+        }
+    }
+}
+
+impl crate::types::team_log::TeamEvent {
+    /// The user or admin who did what the event is about, if it was done by one.
+    pub fn actor_user(&self) -> Option<&team_log::UserLogInfo> {
+        match self.actor.as_ref()? {
+            team_log::ActorLogInfo::Admin(user) | team_log::ActorLogInfo::User(user) => Some(user),
+            _ => None,
+        }
+    }
+
+    /// The email address of the user, admin, or reseller who did what the event is about, if
+    /// known.
+    pub fn actor_email(&self) -> Option<&str> {
+        match self.actor.as_ref()? {
+            team_log::ActorLogInfo::Reseller(reseller) => Some(&reseller.reseller_email),
+            _ => self.actor_user()?.email(),
+        }
+    }
+
+    /// The IP address the event came from, if known.
+    pub fn ip_address(&self) -> Option<&str> {
+        let geo_location = self.origin.as_ref()?.geo_location.as_ref()?;
+        Some(&geo_location.ip_address)
+    }
+
+    /// The paths of the files and folders involved in the event, as seen by the user who did it
+    /// where known, or else relative to their namespace.
+    pub fn involved_paths(&self) -> Vec<&str> {
+        let Some(assets) = &self.assets else {
+            return vec![];
+        };
+        assets.iter()
+            .filter_map(|asset| {
+                let path = match asset {
+                    team_log::AssetLogInfo::File(file) => &file.path,
+                    team_log::AssetLogInfo::Folder(folder) => &folder.path,
+                    _ => return None,
+                };
+                path.contextual.as_deref()
+                    .or(path.namespace_relative.relative_path.as_deref())
+            })
+            .collect()
+    }
+}
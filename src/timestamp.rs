@@ -0,0 +1,121 @@
+//! Conversion between [`SystemTime`] and the timestamp strings used by the Dropbox API.
+//!
+//! The API represents timestamps (such as the `client_modified` field of
+//! [`CommitInfo`](crate::types::files::CommitInfo)) as strings in the format
+//! `%Y-%m-%dT%H:%M:%SZ`, always in UTC, and with no fractional seconds.
+
+use std::time::{Duration, SystemTime};
+
+/// A string could not be parsed as a Dropbox API timestamp.
+#[derive(thiserror::Error, Debug, Clone, PartialEq, Eq)]
+#[error("invalid timestamp {0:?}: expected the format YYYY-MM-DDTHH:MM:SSZ")]
+pub struct InvalidTimestamp(pub String);
+
+/// Format a [`SystemTime`] as a Dropbox API timestamp string.
+///
+/// Any fractional seconds are truncated, as the API doesn't accept them.
+pub fn from_system_time(t: SystemTime) -> String {
+    let secs: i64 = match t.duration_since(SystemTime::UNIX_EPOCH) {
+        Ok(duration) => duration.as_secs() as i64,
+        Err(e) => {
+            // Round towards negative infinity, so that the seconds don't jump ahead.
+            let before = e.duration();
+            -(before.as_secs() as i64) - i64::from(before.subsec_nanos() != 0)
+        }
+    };
+
+    let days = secs.div_euclid(86400);
+    let secs_of_day = secs.rem_euclid(86400);
+    let (year, month, day) = civil_from_days(days);
+
+    format!("{:04}-{:02}-{:02}T{:02}:{:02}:{:02}Z",
+        year,
+        month,
+        day,
+        secs_of_day / 3600,
+        secs_of_day % 3600 / 60,
+        secs_of_day % 60)
+}
+
+/// Parse a Dropbox API timestamp string into a [`SystemTime`].
+pub fn to_system_time(s: &str) -> Result<SystemTime, InvalidTimestamp> {
+    let invalid = || InvalidTimestamp(s.to_owned());
+
+    let b = s.as_bytes();
+    if b.len() != 20
+        || b[4] != b'-' || b[7] != b'-' || b[10] != b'T'
+        || b[13] != b':' || b[16] != b':' || b[19] != b'Z'
+    {
+        return Err(invalid());
+    }
+
+    let num = |range: std::ops::Range<usize>| -> Result<i64, InvalidTimestamp> {
+        let digits = &s[range];
+        if digits.bytes().all(|c| c.is_ascii_digit()) {
+            digits.parse().map_err(|_| invalid())
+        } else {
+            Err(invalid())
+        }
+    };
+
+    let year = num(0..4)?;
+    let month = num(5..7)?;
+    let day = num(8..10)?;
+    let hour = num(11..13)?;
+    let minute = num(14..16)?;
+    let second = num(17..19)?;
+
+    if !(1..=12).contains(&month)
+        || day < 1 || day > days_in_month(year, month)
+        || hour > 23 || minute > 59 || second > 59
+    {
+        return Err(invalid());
+    }
+
+    let secs = days_from_civil(year, month, day) * 86400 + hour * 3600 + minute * 60 + second;
+    Ok(if secs >= 0 {
+        SystemTime::UNIX_EPOCH + Duration::from_secs(secs as u64)
+    } else {
+        SystemTime::UNIX_EPOCH - Duration::from_secs(secs.unsigned_abs())
+    })
+}
+
+fn is_leap_year(year: i64) -> bool {
+    year % 4 == 0 && (year % 100 != 0 || year % 400 == 0)
+}
+
+fn days_in_month(year: i64, month: i64) -> i64 {
+    match month {
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+// These two functions convert between days since the Unix epoch and a (year, month, day) in the
+// proleptic Gregorian calendar. The algorithms are from Howard Hinnant's "chrono-Compatible
+// Low-Level Date Algorithms": <https://howardhinnant.github.io/date_algorithms.html>
+
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let y = if month <= 2 { year - 1 } else { year };
+    let era = y.div_euclid(400);
+    let yoe = y - era * 400;
+    let mp = (month + 9) % 12;
+    let doy = (153 * mp + 2) / 5 + day - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    era * 146097 + doe - 719468
+}
+
+fn civil_from_days(days: i64) -> (i64, i64, i64) {
+    let z = days + 719468;
+    let era = z.div_euclid(146097);
+    let doe = z - era * 146097;
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + i64::from(month <= 2);
+    (year, month, day)
+}
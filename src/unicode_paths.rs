@@ -0,0 +1,106 @@
+//! Unicode normalization of paths and file names.
+//!
+//! The same name can be written with different sequences of Unicode code points: for example,
+//! "é" can be a single precomposed character (NFC form) or an "e" followed by a combining accent
+//! (NFD form). macOS file systems give file names in NFD form, while names typed on other systems
+//! are usually in NFC form. Dropbox keeps names the way they were given, so a path built from a
+//! local file name may not match the path of the same file on Dropbox, and lookups fail with
+//! "not found" errors.
+//!
+//! [`normalize_path`] converts a path to NFC form before it's sent to the API, as does making a
+//! [`DropboxPath`] with [`DropboxPath::normalized`], and [`names_match`] compares a local name with
+//! a Dropbox one regardless of their normalization. The upload helpers normalize the paths they
+//! upload to if [`UploadOptions::normalize_paths`](crate::files_helpers::UploadOptions) is set,
+//! and [`Mirror`](crate::mirror::Mirror) does too if told to.
+//!
+//! This is only built with the `unicode-normalization` Cargo feature.
+
+use std::borrow::Cow;
+use unicode_normalization::{is_nfc_quick, IsNormalized, UnicodeNormalization};
+
+/// Convert a path or file name to Unicode NFC form.
+///
+/// A warning is logged if this changed the path, as that means it may not match paths which came
+/// from elsewhere unless they're normalized too.
+pub fn normalize_path(path: &str) -> Cow<'_, str> {
+    let normalized = to_nfc(path);
+    if let Cow::Owned(ref changed) = normalized {
+        warn!("path {path:?} was normalized to NFC form as {changed:?}");
+    }
+    normalized
+}
+
+/// A path to send to the API, made from a local file name or path.
+///
+/// Use [`DropboxPath::normalized`] to opt into Unicode NFC normalization, and [`DropboxPath::new`]
+/// to send the path as it is. Either way it converts into the `String` the route arguments take.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct DropboxPath(String);
+
+impl DropboxPath {
+    /// A path which is sent as it is.
+    pub fn new(path: impl Into<String>) -> Self {
+        Self(path.into())
+    }
+
+    /// A path converted to Unicode NFC form, as by [`normalize_path`], logging a warning if this
+    /// changed it.
+    pub fn normalized(path: impl Into<String>) -> Self {
+        let path = path.into();
+        match normalize_path(&path) {
+            Cow::Borrowed(_) => Self(path),
+            Cow::Owned(normalized) => Self(normalized),
+        }
+    }
+
+    /// The path.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Whether this refers to the same Dropbox file as a name or path from the API, as by
+    /// [`names_match`].
+    pub fn matches(&self, remote: &str) -> bool {
+        names_match(&self.0, remote)
+    }
+}
+
+impl AsRef<str> for DropboxPath {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl std::fmt::Display for DropboxPath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl From<DropboxPath> for String {
+    fn from(path: DropboxPath) -> Self {
+        path.0
+    }
+}
+
+/// Whether a local file name or path refers to the same Dropbox file as a name or path from the
+/// API.
+///
+/// Both are compared in Unicode NFC form, and ignoring case, as Dropbox paths are
+/// case-insensitive.
+pub fn names_match(local: &str, remote: &str) -> bool {
+    local == remote || to_nfc(local).to_lowercase() == to_nfc(remote).to_lowercase()
+}
+
+/// NFC form of a string, without copying it if it's already in that form.
+fn to_nfc(s: &str) -> Cow<'_, str> {
+    if is_nfc_quick(s.chars()) == IsNormalized::Yes {
+        return Cow::Borrowed(s);
+    }
+    let normalized = s.nfc().collect::<String>();
+    if normalized == s {
+        Cow::Borrowed(s)
+    } else {
+        Cow::Owned(normalized)
+    }
+}
@@ -0,0 +1,55 @@
+use serde::de::MapAccess;
+use serde::ser::{Serialize, SerializeMap, Serializer};
+use serde_json::{Map, Value};
+
+/// A variant of a union which this version of the SDK doesn't know about, as it was received from
+/// the server.
+///
+/// Unions which may have variants added to the API have an `Other` variant holding one of these.
+/// It keeps the variant's tag and any fields which came with it, so that serializing the union
+/// gives back what was received, such as when storing the value or passing it on to another
+/// program which may be using a newer version of the API.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnknownVariant {
+    tag: String,
+    fields: Map<String, Value>,
+}
+
+impl UnknownVariant {
+    /// Make a variant with the given tag and fields. The fields shouldn't include the tag.
+    pub fn new(tag: impl Into<String>, fields: Map<String, Value>) -> Self {
+        Self { tag: tag.into(), fields }
+    }
+
+    /// The variant's tag: its name in the API.
+    pub fn tag(&self) -> &str {
+        &self.tag
+    }
+
+    /// The fields which came with the variant, other than its tag.
+    pub fn fields(&self) -> &Map<String, Value> {
+        &self.fields
+    }
+
+    /// Read the rest of a union's fields, after its tag, as a variant with that tag.
+    pub(crate) fn deserialize_fields<'de, V: MapAccess<'de>>(tag: &str, map: &mut V)
+        -> Result<Self, V::Error>
+    {
+        let mut fields = Map::new();
+        while let Some((key, value)) = map.next_entry::<String, Value>()? {
+            fields.insert(key, value);
+        }
+        Ok(Self::new(tag, fields))
+    }
+}
+
+impl Serialize for UnknownVariant {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(Some(self.fields.len() + 1))?;
+        map.serialize_entry(".tag", &self.tag)?;
+        for (key, value) in &self.fields {
+            map.serialize_entry(key, value)?;
+        }
+        map.end()
+    }
+}
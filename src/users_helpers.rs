@@ -0,0 +1,100 @@
+//! Higher-level helpers for the `users` namespace.
+//!
+//! Like the [`files_helpers`](crate::files_helpers), these are async functions, which can also be
+//! used with sync clients by driving them with any executor.
+
+use std::fmt::{self, Display};
+use crate::async_client_trait::UserAuthClient;
+use crate::generated::async_routes::users;
+use crate::Error;
+
+/// How much space a user has used, out of the space which limits them, from [`space_usage`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct SpaceUsageSummary {
+    /// The space used, in bytes. For a team member whose space is pooled with the rest of the
+    /// team, this is the space used by the whole team.
+    pub used: u64,
+
+    /// The space available, in bytes, or `None` if the kind of allocation isn't known to this
+    /// version of the SDK.
+    pub allocated: Option<u64>,
+
+    /// The full details, as returned by `users/get_space_usage`.
+    pub usage: users::SpaceUsage,
+}
+
+impl SpaceUsageSummary {
+    fn new(usage: users::SpaceUsage) -> Self {
+        let (used, allocated) = match &usage.allocation {
+            users::SpaceAllocation::Individual(individual) => {
+                (usage.used, Some(individual.allocated))
+            }
+            // The member has a quota of their own within the team's space.
+            users::SpaceAllocation::Team(team) if team.user_within_team_space_allocated != 0 => {
+                (usage.used, Some(team.user_within_team_space_allocated))
+            }
+            users::SpaceAllocation::Team(team) => (team.used, Some(team.allocated)),
+            users::SpaceAllocation::Other(_) => (usage.used, None),
+        };
+        Self { used, allocated, usage }
+    }
+
+    /// The fraction of the allocated space which has been used, such as `0.25` for a quarter of
+    /// it, or `None` if the allocation isn't known.
+    ///
+    /// This can be more than 1 if the account is over quota.
+    pub fn fraction_used(&self) -> Option<f64> {
+        match self.allocated? {
+            0 => Some(if self.used == 0 { 0. } else { f64::INFINITY }),
+            allocated => Some(self.used as f64 / allocated as f64),
+        }
+    }
+
+    /// The percentage of the allocated space which has been used, or `None` if the allocation
+    /// isn't known.
+    pub fn percent_used(&self) -> Option<f64> {
+        self.fraction_used().map(|f| f * 100.)
+    }
+
+    /// Whether at least the given fraction of the allocated space has been used, such as
+    /// `is_over(0.9)` to warn when the account is 90% full. This is `false` if the allocation
+    /// isn't known.
+    pub fn is_over(&self, threshold: f64) -> bool {
+        self.fraction_used().map_or(false, |f| f >= threshold)
+    }
+}
+
+impl Display for SpaceUsageSummary {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (self.allocated, self.percent_used()) {
+            (Some(allocated), Some(percent)) => write!(f, "{} of {} used ({percent:.1}%)",
+                format_bytes(self.used), format_bytes(allocated)),
+            _ => write!(f, "{} used", format_bytes(self.used)),
+        }
+    }
+}
+
+/// Get how much space the user has used, and how much they have available, using
+/// `users/get_space_usage`.
+pub async fn space_usage(client: &impl UserAuthClient) -> Result<SpaceUsageSummary, Error> {
+    users::get_space_usage(client).await.map(SpaceUsageSummary::new)
+}
+
+/// Format a number of bytes for people to read, using decimal units, such as `"1.50 GB"`.
+///
+/// Amounts under 1000 bytes are given exactly, such as `"999 B"`; larger ones are given to two
+/// decimal places.
+pub fn format_bytes(bytes: u64) -> String {
+    const PREFIXES: [char; 6] = ['k', 'M', 'G', 'T', 'P', 'E'];
+    if bytes < 1000 {
+        return format!("{bytes} B");
+    }
+    let mut n = bytes as f64 / 1000.;
+    let mut prefix = 0;
+    // Round up to the next prefix if this would otherwise show as 1000.00.
+    while n >= 999.995 && prefix < PREFIXES.len() - 1 {
+        n /= 1000.;
+        prefix += 1;
+    }
+    format!("{n:.2} {}B", PREFIXES[prefix])
+}
@@ -0,0 +1,213 @@
+//! Downloading a selection of files and folders into a local zip archive.
+//!
+//! `files/download_zip` only downloads a single folder. [`download_selection_as_zip`] takes any
+//! set of files and folders, downloads the files several at a time, and writes them into a zip
+//! archive, keeping their folder structure relative to the folder they were all selected from.
+//!
+//! This is only built with the `zip` Cargo feature.
+
+use std::collections::BTreeMap;
+use std::io::{Seek, Write};
+use futures::{StreamExt, TryStreamExt};
+use zip::write::SimpleFileOptions;
+use zip::{CompressionMethod, ZipWriter};
+use crate::async_client_trait::UserAuthClient;
+use crate::files_helpers::{self, ListFolderOptions, TransferError};
+use crate::generated::async_routes::files;
+use crate::retry::RetryBudget;
+use crate::{Error, NoError};
+
+/// Options for [`download_selection_as_zip`].
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub struct ZipDownloadOptions {
+    /// How many files to download at once. Defaults to 4.
+    ///
+    /// Each file being downloaded is held in memory until it's written to the archive, so this
+    /// also limits how much memory is used.
+    pub concurrency: usize,
+
+    /// Compress the files in the archive. Defaults to true.
+    pub compress: bool,
+
+    /// Limits on retrying each file's download if it fails with a transient error. Defaults to no
+    /// retries.
+    pub retry_budget: RetryBudget,
+}
+
+impl Default for ZipDownloadOptions {
+    fn default() -> Self {
+        Self {
+            concurrency: 4,
+            compress: true,
+            retry_budget: RetryBudget::new(0),
+        }
+    }
+}
+
+impl ZipDownloadOptions {
+    /// Set how many files to download at once.
+    pub fn with_concurrency(mut self, value: usize) -> Self {
+        self.concurrency = value;
+        self
+    }
+
+    /// Set whether to compress the files in the archive.
+    pub fn with_compress(mut self, value: bool) -> Self {
+        self.compress = value;
+        self
+    }
+
+    /// Set the limits on retrying each file's download.
+    pub fn with_retry_budget(mut self, value: RetryBudget) -> Self {
+        self.retry_budget = value;
+        self
+    }
+}
+
+/// Something to put in the archive, with its path in the archive.
+enum Item {
+    Folder { name: String },
+    File { name: String, id: String },
+}
+
+/// Download the files and folders at the given paths (or IDs) into a zip archive written to
+/// `dest`, and return `dest` once the archive is finished.
+///
+/// Folders are included with everything in them. Each file and folder is put in the archive at its
+/// path relative to the deepest folder which contains everything selected, so selecting
+/// `/Photos/a.jpg` and `/Photos/2024` gives an archive with `a.jpg` and `2024/...` in it. The root
+/// folder can't be selected itself.
+///
+/// Each file's content hash is checked as it's downloaded. This does blocking I/O when writing to
+/// `dest`.
+pub async fn download_selection_as_zip<W: Write + Seek>(
+    client: &impl UserAuthClient,
+    paths: impl IntoIterator<Item = impl Into<String>>,
+    dest: W,
+    options: &ZipDownloadOptions,
+) -> Result<W, TransferError> {
+    let items = resolve_selection(client, paths).await?;
+
+    let file_options = SimpleFileOptions::default()
+        .compression_method(if options.compress {
+            CompressionMethod::Deflated
+        } else {
+            CompressionMethod::Stored
+        });
+    let mut zip = ZipWriter::new(dest);
+    let mut to_download = vec![];
+    for item in items {
+        match item {
+            Item::Folder { name } => {
+                zip.add_directory(name, file_options).map_err(std::io::Error::from)?;
+            }
+            Item::File { name, id } => to_download.push((name, id)),
+        }
+    }
+
+    let mut downloads = futures::stream::iter(to_download)
+        .map(|(name, id)| async move {
+            let arg = files::DownloadArg::new(id);
+            let mut data = vec![];
+            files_helpers::download_with_report(client, &arg, &mut data, &options.retry_budget).await?;
+            Ok::<_, TransferError>((name, data))
+        })
+        .buffer_unordered(options.concurrency.max(1));
+    while let Some((name, data)) = downloads.try_next().await? {
+        debug!("adding {name} to the archive");
+        zip.start_file(name, file_options.large_file(data.len() as u64 >= u32::MAX as u64))
+            .map_err(std::io::Error::from)?;
+        zip.write_all(&data)?;
+    }
+    Ok(zip.finish().map_err(std::io::Error::from)?)
+}
+
+/// Look up the selected paths, and list everything in the selected folders, as the items to put
+/// in the archive, in order of their path.
+async fn resolve_selection(
+    client: &impl UserAuthClient,
+    paths: impl IntoIterator<Item = impl Into<String>>,
+) -> Result<Vec<Item>, TransferError> {
+    // Keyed by lower-case path, so entries listed twice because a listing restarted are only
+    // included once.
+    let mut entries = BTreeMap::new();
+    let mut selected_parents = vec![];
+    for path in paths {
+        let arg = files::GetMetadataArg::new(path.into());
+        let metadata = files::get_metadata(client, &arg).await?;
+        let Some(path_display) = path_display(&metadata) else {
+            return Err(Error::<NoError>::UnexpectedResponse(
+                "selected item has no path".to_owned()).into());
+        };
+        selected_parents.push(parent(path_display).to_owned());
+
+        if let files::Metadata::Folder(folder) = &metadata {
+            let arg = files::ListFolderArg::new(folder.id.clone()).with_recursive(true);
+            let mut listing = Box::pin(
+                files_helpers::list_folder(client, arg, &ListFolderOptions::default()));
+            while let Some(entry) = listing.try_next().await.map_err(TransferError::Dropbox)? {
+                if let Some(key) = path_lower(&entry.metadata) {
+                    entries.insert(key.to_owned(), entry.metadata);
+                }
+            }
+        }
+        if let Some(key) = path_lower(&metadata) {
+            entries.insert(key.to_owned(), metadata);
+        }
+    }
+
+    let base = common_ancestor(&selected_parents);
+    Ok(entries.into_values()
+        .filter_map(|metadata| {
+            let name = path_display(&metadata)?
+                .split('/')
+                .skip(base + 1)
+                .collect::<Vec<_>>()
+                .join("/");
+            match metadata {
+                files::Metadata::Folder(_) => Some(Item::Folder { name }),
+                files::Metadata::File(file) => Some(Item::File { name, id: file.id }),
+                files::Metadata::Deleted(_) => None,
+            }
+        })
+        .collect())
+}
+
+fn path_display(metadata: &files::Metadata) -> Option<&str> {
+    match metadata {
+        files::Metadata::File(file) => file.path_display.as_deref(),
+        files::Metadata::Folder(folder) => folder.path_display.as_deref(),
+        files::Metadata::Deleted(deleted) => deleted.path_display.as_deref(),
+    }
+}
+
+fn path_lower(metadata: &files::Metadata) -> Option<&str> {
+    match metadata {
+        files::Metadata::File(file) => file.path_lower.as_deref(),
+        files::Metadata::Folder(folder) => folder.path_lower.as_deref(),
+        files::Metadata::Deleted(deleted) => deleted.path_lower.as_deref(),
+    }
+}
+
+/// The path of the folder containing the given path, which is `""` for the root.
+fn parent(path: &str) -> &str {
+    path.rsplit_once('/').map_or("", |(parent, _)| parent)
+}
+
+/// How many path components the given folders have in common, ignoring case.
+fn common_ancestor(folders: &[String]) -> usize {
+    let Some((first, rest)) = folders.split_first() else {
+        return 0;
+    };
+    let first = first.split('/').skip(1).collect::<Vec<_>>();
+    rest.iter()
+        .map(|folder| {
+            folder.split('/')
+                .skip(1)
+                .zip(&first)
+                .take_while(|(a, b)| a.to_lowercase() == b.to_lowercase())
+                .count()
+        })
+        .fold(first.len(), usize::min)
+}
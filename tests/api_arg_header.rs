@@ -0,0 +1,31 @@
+#![warn(rust_2018_idioms)]
+
+use dropbox_sdk::files;
+
+mod mock;
+use mock::{FILE_METADATA, MockClient, Response};
+
+#[test]
+fn test_non_ascii_api_arg() {
+    let names = ["/文件.txt", "/ファイル 😀.txt", "/tro\u{161}kovi\x7f.txt"];
+    let client = MockClient::new(names.iter().map(|_| Response::download(FILE_METADATA, b"hello")));
+
+    for name in names {
+        let arg = files::DownloadArg::new(name.to_owned());
+        files::download(&client, &arg, None, None).unwrap();
+    }
+
+    let requests = client.requests();
+    let headers = requests.iter()
+        .map(|r| r.header("Dropbox-API-Arg").unwrap())
+        .collect::<Vec<_>>();
+    assert_eq!(r#"{"path":"/\u6587\u4ef6.txt"}"#, headers[0]);
+    assert_eq!(r#"{"path":"/\u30d5\u30a1\u30a4\u30eb \ud83d\ude00.txt"}"#, headers[1]);
+    assert_eq!(r#"{"path":"/tro\u0161kovi\u007f.txt"}"#, headers[2]);
+
+    for (name, header) in names.iter().zip(headers) {
+        assert!(header.bytes().all(|b| b.is_ascii_graphic() || b == b' '), "not escaped: {}", header);
+        let arg: serde_json::Value = serde_json::from_str(header).unwrap();
+        assert_eq!(*name, arg["path"]);
+    }
+}
@@ -0,0 +1,70 @@
+#![warn(rust_2018_idioms)]
+#![cfg(unix)]
+
+use std::io::Read;
+use std::os::unix::net::UnixListener;
+use std::time::{Duration, Instant};
+use dropbox_sdk::async_routes::files;
+use dropbox_sdk::client_trait_common::Endpoint;
+use dropbox_sdk::default_async_client::{Connector, NoauthDefaultClient};
+
+#[tokio::test]
+async fn test_unix_socket_connector() {
+    let path = std::env::temp_dir().join(format!("dropbox-sdk-test-{}.sock", std::process::id()));
+    let _ = std::fs::remove_file(&path);
+    let listener = UnixListener::bind(&path).unwrap();
+    let server = std::thread::spawn(move || {
+        let (mut conn, _) = listener.accept().unwrap();
+        let mut first = [0u8; 1];
+        conn.read_exact(&mut first).unwrap();
+        first[0]
+    });
+
+    let client = NoauthDefaultClient::default()
+        .with_connector(Connector::UnixSocket(path.clone()));
+    // The fake server hangs up without answering, so the request itself fails.
+    let result = files::list_folder_longpoll(
+        &client, &files::ListFolderLongpollArg::new("cursor".to_owned())).await;
+    assert!(result.is_err());
+
+    // But it should have connected over the socket and started a TLS handshake.
+    assert_eq!(0x16, server.join().unwrap(), "expected a TLS handshake record");
+    std::fs::remove_file(&path).unwrap();
+}
+
+#[tokio::test]
+async fn test_concurrency_limit() {
+    let path = std::env::temp_dir().join(format!("dropbox-sdk-test-limit-{}.sock", std::process::id()));
+    let _ = std::fs::remove_file(&path);
+    let listener = UnixListener::bind(&path).unwrap();
+    let server = std::thread::spawn(move || {
+        // Hold the first connection open for a while before hanging up.
+        let (first, _) = listener.accept().unwrap();
+        let first_time = Instant::now();
+        std::thread::sleep(Duration::from_millis(500));
+        drop(first);
+        // Without the limit, both requests would have used the first connection.
+        listener.set_nonblocking(true).unwrap();
+        while first_time.elapsed() < Duration::from_secs(5) {
+            if listener.accept().is_ok() {
+                return Some(first_time.elapsed());
+            }
+            std::thread::sleep(Duration::from_millis(10));
+        }
+        None
+    });
+
+    let client = NoauthDefaultClient::default()
+        .with_concurrency_limit(Endpoint::Notify, 1)
+        .with_connector(Connector::UnixSocket(path.clone()));
+    let arg = files::ListFolderLongpollArg::new("cursor".to_owned());
+    let (a, b) = futures::join!(
+        files::list_folder_longpoll(&client, &arg),
+        files::list_folder_longpoll(&client, &arg));
+    assert!(a.is_err() && b.is_err());
+
+    // The second request only connected once the first one had finished.
+    let second_time = server.join().unwrap().expect("no second connection");
+    assert!(second_time >= Duration::from_millis(500));
+    std::fs::remove_file(&path).unwrap();
+}
@@ -70,5 +70,5 @@ fn fetch_files() {
 
     println!("{:?}", times);
     println!("average: {} seconds",
-        times.iter().map(Duration::as_secs_f64).sum::<f64>() / times.len() as f64)
+        times.iter().map(Duration::as_secs_f64).sum::<f64>() / times.len() as f64);
 }
@@ -0,0 +1,181 @@
+#![warn(rust_2018_idioms)]
+
+use std::sync::Arc;
+use futures::future::{BoxFuture, FutureExt};
+use dropbox_sdk::blocking;
+use dropbox_sdk::client_trait::{HttpClient, HttpRequestResultRaw, NoauthClient, UserAuthClient};
+use dropbox_sdk::default_client::UserAuthDefaultClient;
+use dropbox_sdk::files;
+use dropbox_sdk::files_helpers::{self, ListFolderOptions, UploadOptions, WatchOptions};
+use dropbox_sdk::oauth2::{Authorization, RefreshCoordinator, TokenCache};
+use dropbox_sdk::retry::RetryBudget;
+
+mod mock;
+use mock::{FILE_METADATA, MockClient, Request, Response};
+
+fn page(names: &[&str], cursor: &str, has_more: bool) -> Response {
+    let entries = names.iter()
+        .map(|name| format!(r#"{{".tag": "deleted", "name": "{name}"}}"#))
+        .collect::<Vec<_>>()
+        .join(",");
+    Response::json(&format!(
+        r#"{{"entries": [{entries}], "cursor": "{cursor}", "has_more": {has_more}}}"#))
+}
+
+fn names<E: std::fmt::Debug>(
+    entries: impl Iterator<Item = Result<files::Metadata, E>>,
+) -> Vec<String> {
+    entries.map(|entry| match entry.unwrap() {
+        files::Metadata::Deleted(deleted) => deleted.name,
+        other => panic!("unexpected metadata: {:?}", other),
+    }).collect()
+}
+
+#[test]
+fn test_iter_watch_changes() {
+    let client = MockClient::new([
+        Response::json(r#"{"changes": true}"#),
+        page(&["a", "b"], "c1", false),
+        Response::json(r#"{"changes": true}"#),
+        page(&["c"], "c2", false),
+    ]);
+    let changes = blocking::iter(
+        files_helpers::watch_changes(&client, "c0", &WatchOptions::default()));
+
+    // Only as many requests are made as are needed for the changes taken.
+    let changes = changes.take(3).map(|change| change.map(|c| c.metadata));
+    assert_eq!(vec!["a", "b", "c"], names(changes));
+    assert_eq!(4, client.requests().len());
+}
+
+#[test]
+fn test_block_on_and_iter() {
+    // Waiting to retry needs a timer, which works without a runtime.
+    let client = MockClient::new([
+        Response::status(429, r#"{
+            "error_summary": "too_many_requests/",
+            "error": {"reason": {".tag": "too_many_requests"}, "retry_after": 0}
+        }"#),
+        Response::json(FILE_METADATA),
+        page(&["a"], "c1", true),
+        page(&["b"], "c2", false),
+    ]);
+    let metadata = blocking::block_on(files_helpers::upload(
+        &client,
+        files::CommitInfo::new("/a.txt".to_owned()),
+        &b"hello"[..],
+        &UploadOptions::default().with_retry_budget(RetryBudget::new(1)),
+    )).unwrap();
+    assert_eq!("a.txt", metadata.name);
+
+    let entries = blocking::iter(files_helpers::list_folder(
+        &client, files::ListFolderArg::new(String::new()), &ListFolderOptions::default()));
+    assert_eq!(vec!["a", "b"], names(entries.map(|entry| entry.map(|e| e.metadata))));
+    assert_eq!(4, client.requests().len());
+}
+
+/// A coordinator which always has a token from another process, so no refresh request is made.
+struct RefreshedElsewhere;
+
+impl RefreshCoordinator for RefreshedElsewhere {
+    fn acquire<'a>(&'a self, _stale: &'a str)
+        -> BoxFuture<'a, Result<Option<String>, Box<dyn std::error::Error + Send + Sync>>>
+    {
+        futures::future::ready(Ok(Some("shared".to_owned()))).boxed()
+    }
+
+    fn release<'a>(&'a self, _refreshed: Option<&'a str>) -> BoxFuture<'a, ()> {
+        futures::future::ready(()).boxed()
+    }
+}
+
+#[test]
+fn test_block_on_sync_client_token_refresh() {
+    // A sync client refreshes its token without an executor of its own, so it works from within
+    // this one.
+    let tokens = TokenCache::new(Authorization::load("client".to_owned(), "2&refresh").unwrap())
+        .with_refresh_coordinator(Arc::new(RefreshedElsewhere));
+    let client = UserAuthDefaultClient::from_token_cache(Arc::new(tokens));
+    assert_eq!(None, client.token());
+
+    let updated = blocking::block_on(async {
+        client.update_token(Arc::new(String::new()))
+    }).unwrap();
+    assert!(updated);
+    assert_eq!("shared", client.token().unwrap().as_str());
+}
+
+/// A sync client which gets its token from a [`TokenCache`], refreshing it with the same mock
+/// client used for everything else.
+struct RefreshingClient {
+    mock: MockClient,
+    tokens: TokenCache,
+}
+
+struct Refresher<'a>(&'a MockClient);
+
+impl HttpClient for Refresher<'_> {
+    type Request = Request;
+
+    fn execute(&self, request: Request, body: &[u8])
+        -> Result<HttpRequestResultRaw, dropbox_sdk::Error>
+    {
+        self.0.execute(request, body)
+    }
+
+    fn new_request(&self, url: &str) -> Request {
+        self.0.new_request(url)
+    }
+}
+
+impl NoauthClient for Refresher<'_> {}
+
+impl HttpClient for RefreshingClient {
+    type Request = Request;
+
+    fn execute(&self, request: Request, body: &[u8])
+        -> Result<HttpRequestResultRaw, dropbox_sdk::Error>
+    {
+        self.mock.execute(request, body)
+    }
+
+    fn new_request(&self, url: &str) -> Request {
+        self.mock.new_request(url)
+    }
+
+    fn update_token(&self, old_token: Arc<String>) -> Result<bool, dropbox_sdk::Error> {
+        self.tokens.update_token_blocking(Refresher(&self.mock), old_token).map(|_| true)
+    }
+
+    fn token(&self) -> Option<Arc<String>> {
+        self.tokens.get_token()
+    }
+}
+
+impl UserAuthClient for RefreshingClient {}
+
+#[test]
+fn test_iter_refreshes_token() {
+    let client = RefreshingClient {
+        mock: MockClient::new([
+            Response::json(r#"{"access_token": "first", "expires_in": 60, "token_type": "bearer"}"#),
+            page(&["a"], "c1", true),
+            Response::status(401, r#"{
+                "error_summary": "expired_access_token/",
+                "error": {".tag": "expired_access_token"}
+            }"#),
+            Response::json(r#"{"access_token": "second", "expires_in": 60, "token_type": "bearer"}"#),
+            page(&["b"], "c2", false),
+        ]),
+        tokens: TokenCache::new(Authorization::load("client".to_owned(), "2&refresh").unwrap()),
+    };
+
+    let entries = blocking::iter(files_helpers::list_folder(
+        &client, files::ListFolderArg::new(String::new()), &ListFolderOptions::default()));
+    assert_eq!(vec!["a", "b"], names(entries.map(|entry| entry.map(|e| e.metadata))));
+
+    let auth = client.mock.requests().iter()
+        .filter_map(|request| request.header("Authorization").map(str::to_owned))
+        .collect::<Vec<_>>();
+    assert_eq!(vec!["Bearer first", "Bearer first", "Bearer second"], auth);
+}
@@ -0,0 +1,91 @@
+#![warn(rust_2018_idioms)]
+
+use std::sync::{Arc, Mutex};
+use dropbox_sdk::body_logging::{BodyLogOptions, LoggingClient};
+use dropbox_sdk::files;
+
+mod mock;
+use mock::{MockClient, Response};
+
+/// Records the messages logged by the SDK's body logging.
+struct Logger(Arc<Mutex<Vec<String>>>);
+
+impl log::Log for Logger {
+    fn enabled(&self, metadata: &log::Metadata<'_>) -> bool {
+        metadata.target() == "dropbox_sdk::body_logging"
+    }
+
+    fn log(&self, record: &log::Record<'_>) {
+        if self.enabled(record.metadata()) {
+            self.0.lock().unwrap().push(record.args().to_string());
+        }
+    }
+
+    fn flush(&self) {}
+}
+
+const FILE_METADATA: &str = r#"{
+    ".tag": "file",
+    "name": "secret plans.txt",
+    "path_display": "/Secret Plans.txt",
+    "id": "id:abc",
+    "client_modified": "2020-01-01T00:00:00Z",
+    "server_modified": "2020-01-01T00:00:00Z",
+    "rev": "0123456789abcdef",
+    "size": 5,
+    "sharing_info": {
+        "read_only": false,
+        "parent_shared_folder_id": "1",
+        "modified_by": "dbid:someone@example.com"
+    }
+}"#;
+
+#[test]
+fn test_body_logging() {
+    let logged = Arc::new(Mutex::new(vec![]));
+    log::set_logger(Box::leak(Box::new(Logger(logged.clone())))).unwrap();
+    log::set_max_level(log::LevelFilter::Debug);
+
+    let client = LoggingClient::new(
+        MockClient::new([
+            Response::json(FILE_METADATA),
+            Response::download(FILE_METADATA, b"hello"),
+            Response::status(500, &"x".repeat(2000)),
+        ]),
+        BodyLogOptions::default().with_redact_field("rev").with_max_len(1000),
+    );
+
+    // The response is still deserialized after being logged.
+    let arg = files::GetMetadataArg::new("/Secret Plans.txt".to_owned());
+    let metadata = files::get_metadata(&client, &arg).unwrap();
+    assert!(matches!(metadata, files::Metadata::File(ref f) if f.size == 5), "{:?}", metadata);
+
+    let arg = files::DownloadArg::new("/Secret Plans.txt".to_owned());
+    let mut download = files::download(&client, &arg, None, None).unwrap();
+    let mut body = String::new();
+    std::io::Read::read_to_string(download.body.as_mut().unwrap(), &mut body).unwrap();
+    assert_eq!("hello", body);
+
+    files::get_metadata(&client, &files::GetMetadataArg::new("/a".to_owned())).unwrap_err();
+
+    let logged = logged.lock().unwrap().clone();
+    assert_eq!(6, logged.len(), "{:#?}", logged);
+    for message in &logged {
+        assert!(!message.contains("Secret Plans"), "path not redacted: {}", message);
+        assert!(!message.contains("someone@example.com"), "email not redacted: {}", message);
+        assert!(!message.contains("0123456789abcdef"), "field not redacted: {}", message);
+    }
+    assert!(logged[0].starts_with("request to files/get_metadata:\n{\n"), "{}", logged[0]);
+    assert!(logged[0].contains(r#""path": "<redacted>""#), "{}", logged[0]);
+    assert!(logged[1].starts_with("response from files/get_metadata (200):\n{\n"), "{}", logged[1]);
+    assert!(logged[1].contains(r#""name": "<redacted>""#), "{}", logged[1]);
+    assert!(logged[1].contains(r#""id": "id:abc""#), "{}", logged[1]);
+    assert!(logged[2].starts_with("request to files/download:\n"), "{}", logged[2]);
+    assert!(logged[3].starts_with("response from files/download (200):\n{"), "{}", logged[3]);
+    assert!(!logged[3].contains("hello"), "{}", logged[3]);
+
+    // Long bodies are truncated.
+    let expected = format!("response from files/get_metadata (500):\n{}... (2000 bytes in total)",
+        "x".repeat(1000));
+    assert_eq!(expected, logged[5]);
+}
@@ -0,0 +1,88 @@
+#![warn(rust_2018_idioms)]
+
+use serde_json::json;
+use dropbox_sdk::compact_serde::{self, UnionRepr};
+use dropbox_sdk::files;
+
+fn file_metadata() -> files::Metadata {
+    files::Metadata::File(files::FileMetadata::new(
+        "a.txt".to_owned(),
+        "id:abc".to_owned(),
+        "2020-01-01T00:00:00Z".to_owned(),
+        "2020-01-01T00:00:00Z".to_owned(),
+        "0123456789abcdef".to_owned(),
+        5,
+    ).with_path_lower("/a.txt".to_owned()))
+}
+
+#[test]
+fn test_external() {
+    let metadata = file_metadata();
+    let value = compact_serde::to_value(&metadata, UnionRepr::External).unwrap();
+    assert_eq!("a.txt", value["file"]["name"]);
+    assert_eq!(None, value["file"].get(".tag"));
+    assert_eq!(metadata, compact_serde::from_value(&value, UnionRepr::External).unwrap());
+
+    let error = files::GetMetadataError::Path(files::LookupError::NotFound);
+    let value = compact_serde::to_value(&error, UnionRepr::External).unwrap();
+    assert_eq!(json!({"path": "not_found"}), value);
+    assert_eq!(error, compact_serde::from_value(&value, UnionRepr::External).unwrap());
+
+    let commit = files::CommitInfo::new("/a.txt".to_owned())
+        .with_mode(files::WriteMode::Update("0123456789abcdef".to_owned()));
+    let value = compact_serde::to_value(&commit, UnionRepr::External).unwrap();
+    assert_eq!(json!({"update": "0123456789abcdef"}), value["mode"]);
+    assert_eq!(commit, compact_serde::from_value(&value, UnionRepr::External).unwrap());
+
+    // A struct with only one field isn't mistaken for a union.
+    let arg = files::GetMetadataArg::new("/a.txt".to_owned());
+    let value = compact_serde::to_value(&arg, UnionRepr::External).unwrap();
+    assert_eq!(Some(&json!("/a.txt")), value.get("path"));
+    let value = json!({"path": "/a.txt"});
+    assert_eq!(arg, compact_serde::from_value(&value, UnionRepr::External).unwrap());
+}
+
+#[test]
+fn test_adjacent() {
+    let metadata = file_metadata();
+    let value = compact_serde::to_value(&metadata, UnionRepr::ADJACENT).unwrap();
+    assert_eq!("file", value["tag"]);
+    assert_eq!("a.txt", value["content"]["name"]);
+    assert_eq!(metadata, compact_serde::from_value(&value, UnionRepr::ADJACENT).unwrap());
+
+    let error = files::GetMetadataError::Path(files::LookupError::NotFound);
+    let value = compact_serde::to_value(&error, UnionRepr::ADJACENT).unwrap();
+    assert_eq!(json!({"tag": "path", "content": {"tag": "not_found"}}), value);
+    assert_eq!(error, compact_serde::from_value(&value, UnionRepr::ADJACENT).unwrap());
+
+    let repr = UnionRepr::Adjacent { tag: "t", content: "c" };
+    let mode = files::WriteMode::Overwrite;
+    let value = compact_serde::to_value(&mode, repr).unwrap();
+    assert_eq!(json!({"t": "overwrite"}), value);
+    assert_eq!(mode, compact_serde::from_value(&value, repr).unwrap());
+}
+
+#[test]
+fn test_serde_with() {
+    #[derive(Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+    struct Stored {
+        #[serde(with = "compact_serde::external")]
+        metadata: files::Metadata,
+
+        #[serde(with = "compact_serde::adjacent")]
+        entries: Vec<files::Metadata>,
+    }
+
+    let stored = Stored {
+        metadata: file_metadata(),
+        entries: vec![
+            file_metadata(),
+            files::Metadata::Deleted(files::DeletedMetadata::new("b.txt".to_owned())),
+        ],
+    };
+    let json = serde_json::to_string(&stored).unwrap();
+    assert!(!json.contains(".tag"), "{}", json);
+    let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+    assert_eq!("deleted", value["entries"][1]["tag"]);
+    assert_eq!(stored, serde_json::from_str(&json).unwrap());
+}
@@ -32,8 +32,9 @@ fn test_open_union_void() {
         "some field": "some value"
     }"#;
     let x = serde_json::from_str::<dropbox_sdk::files::ListFolderLongpollError>(json).unwrap();
-    if let dropbox_sdk::files::ListFolderLongpollError::Other = x {
-        // yay
+    if let dropbox_sdk::files::ListFolderLongpollError::Other(ref unknown) = x {
+        assert_eq!("some other variant", unknown.tag());
+        assert_eq!(1, unknown.fields().len());
     } else {
         panic!("wrong variant");
     }
@@ -47,8 +48,9 @@ fn test_open_union_fields() {
         "another field": "another value"
     }"#;
     let x = serde_json::from_str::<dropbox_sdk::users::SpaceAllocation>(json).unwrap();
-    if let dropbox_sdk::users::SpaceAllocation::Other = x {
-        // yay
+    if let dropbox_sdk::users::SpaceAllocation::Other(ref unknown) = x {
+        assert_eq!("some other variant", unknown.tag());
+        assert_eq!(Some("another value"), unknown.fields()["another field"].as_str());
     } else {
         panic!("wrong variant");
     }
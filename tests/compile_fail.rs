@@ -0,0 +1,8 @@
+//! Check that routes can't be called with a client using the wrong kind of authentication.
+
+#[test]
+#[ignore] // The expected compiler output varies between Rust versions; CI runs this on stable.
+fn auth_bounds() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/compile_fail/*.rs");
+}
@@ -0,0 +1,9 @@
+use dropbox_sdk::default_client::UserAuthDefaultClient;
+use dropbox_sdk::sharing;
+
+fn metadata(client: &UserAuthDefaultClient) {
+    let arg = sharing::GetSharedLinkMetadataArg::new(String::new());
+    let _ = sharing::get_shared_link_metadata_app_auth(client, &arg);
+}
+
+fn main() {}
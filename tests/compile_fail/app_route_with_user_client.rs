@@ -0,0 +1,9 @@
+use dropbox_sdk::auth;
+use dropbox_sdk::default_client::UserAuthDefaultClient;
+
+fn exchange(client: &UserAuthDefaultClient) {
+    let arg = auth::TokenFromOAuth1Arg::new(String::new(), String::new());
+    let _ = auth::token_from_oauth1(client, &arg);
+}
+
+fn main() {}
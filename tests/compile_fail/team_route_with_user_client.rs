@@ -0,0 +1,8 @@
+use dropbox_sdk::default_client::UserAuthDefaultClient;
+use dropbox_sdk::team;
+
+fn info(client: &UserAuthDefaultClient) {
+    let _ = team::get_info(client);
+}
+
+fn main() {}
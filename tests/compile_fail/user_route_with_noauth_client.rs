@@ -0,0 +1,8 @@
+use dropbox_sdk::default_client::NoauthDefaultClient;
+use dropbox_sdk::users;
+
+fn account(client: &NoauthDefaultClient) {
+    let _ = users::get_current_account(client);
+}
+
+fn main() {}
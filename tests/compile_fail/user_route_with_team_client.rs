@@ -0,0 +1,8 @@
+use dropbox_sdk::default_client::TeamAuthDefaultClient;
+use dropbox_sdk::files;
+
+fn list(client: &TeamAuthDefaultClient) {
+    let _ = files::list_folder(client, &files::ListFolderArg::new(String::new()));
+}
+
+fn main() {}
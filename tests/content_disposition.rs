@@ -0,0 +1,52 @@
+#![warn(rust_2018_idioms)]
+
+use dropbox_sdk::client_trait_common::content_disposition_filename;
+use dropbox_sdk::files;
+
+mod mock;
+use mock::{FILE_METADATA, MockClient, Response};
+
+#[test]
+fn test_parse_filename() {
+    let parse = content_disposition_filename;
+    assert_eq!(Some("a.txt".to_owned()), parse("attachment; filename=a.txt"));
+    assert_eq!(Some("a b;\"c\".txt".to_owned()), parse(r#"attachment; filename="a b;\"c\".txt""#));
+    assert_eq!(Some("a.txt".to_owned()), parse("inline;FileName = \"a.txt\" ; size=5"));
+    assert_eq!(Some("a.txt".to_owned()), parse("attachment; foo; filename=a.txt"));
+
+    // The extended parameter wins, wherever it is, unless it can't be decoded.
+    assert_eq!(Some("é €.txt".to_owned()),
+        parse(r#"attachment; filename*=UTF-8''%C3%A9%20%E2%82%AC.txt; filename="e.txt""#));
+    assert_eq!(Some("é.txt".to_owned()),
+        parse(r#"attachment; filename="e.txt"; filename*=utf-8'en'%C3%A9.txt"#));
+    assert_eq!(Some("é.txt".to_owned()),
+        parse("attachment; filename*=ISO-8859-1''%E9.txt"));
+    assert_eq!(Some("e.txt".to_owned()),
+        parse("attachment; filename=e.txt; filename*=KOI8-R''%E9.txt"));
+    assert_eq!(Some("e.txt".to_owned()),
+        parse("attachment; filename=e.txt; filename*=UTF-8''%FF.txt"));
+
+    // Only the last component of a path is kept.
+    assert_eq!(Some("passwd".to_owned()), parse("attachment; filename=\"../../etc/passwd\""));
+    assert_eq!(Some("a.txt".to_owned()), parse(r#"attachment; filename="C:\\temp\\a.txt""#));
+
+    assert_eq!(None, parse("attachment"));
+    assert_eq!(None, parse("attachment; filename=\"unterminated"));
+    assert_eq!(None, parse("attachment; filename=\"\""));
+    assert_eq!(None, parse("attachment; filename=dir/"));
+    assert_eq!(None, parse("attachment; filename=.."));
+}
+
+#[test]
+fn test_download_filename() {
+    let client = MockClient::new([
+        Response::download(FILE_METADATA, b"hello")
+            .with_content_disposition("attachment; filename*=UTF-8''r%C3%A9sum%C3%A9.pdf"),
+        Response::download(FILE_METADATA, b"hello"),
+    ]);
+    let arg = files::DownloadArg::new("/a.txt".to_owned());
+    let result = files::download(&client, &arg, None, None).unwrap();
+    assert_eq!(Some("résumé.pdf"), result.filename.as_deref());
+    let result = files::download(&client, &arg, None, None).unwrap();
+    assert_eq!(None, result.filename);
+}
@@ -0,0 +1,26 @@
+#![warn(rust_2018_idioms)]
+
+use std::io::Write;
+use dropbox_sdk::content_hash::{content_hash, content_hash_reader, ContentHasher, BLOCK_SIZE};
+
+#[test]
+fn test_content_hash() {
+    assert_eq!("e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855", content_hash(b""));
+    assert_eq!("9595c9df90075148eb06860365df33584b75bff782a510c6cd4883a419833d50",
+        content_hash(b"hello"));
+}
+
+#[test]
+fn test_content_hash_blocks() {
+    let data = (0 .. BLOCK_SIZE + 1000).map(|i| (i % 251) as u8).collect::<Vec<u8>>();
+    let expected = "dcb4ba5ff947d11766fb50a93a46724c8cd5d530b3e98c6540b893fcbf4d8f4a";
+    assert_eq!(expected, content_hash(&data));
+    assert_eq!(expected, content_hash_reader(&data[..]).unwrap());
+
+    // Pieces which don't line up with the blocks.
+    let mut hasher = ContentHasher::new();
+    for piece in data.chunks(1_000_003) {
+        hasher.write_all(piece).unwrap();
+    }
+    assert_eq!(expected, hasher.finish());
+}
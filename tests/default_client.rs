@@ -0,0 +1,62 @@
+#![warn(rust_2018_idioms)]
+
+use std::io::{ErrorKind, Read, Write};
+use std::net::TcpListener;
+use std::time::Duration;
+use dropbox_sdk::client_trait::HttpClient;
+use dropbox_sdk::client_trait_common::{HttpRequest, TeamSelect};
+use dropbox_sdk::default_client::{NoauthDefaultClient, TeamAuthDefaultClient};
+use dropbox_sdk::oauth2::{Authorization, TokenCache};
+
+/// Serve one response over plain HTTP, whose body arrives in two halves `pause` apart, and return
+/// the URL to request it from.
+fn slow_body_server(pause: Duration) -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let url = format!("http://{}/2/files/download", listener.local_addr().unwrap());
+    std::thread::spawn(move || {
+        let (mut conn, _) = listener.accept().unwrap();
+        let mut request = [0u8; 4096];
+        let _ = conn.read(&mut request).unwrap();
+        conn.write_all(b"HTTP/1.1 200 OK\r\nContent-Length: 10\r\n\r\nhello").unwrap();
+        std::thread::sleep(pause);
+        // The client may have given up and hung up by now.
+        let _ = conn.write_all(b"world");
+    });
+    url
+}
+
+/// Make a request the way the SDK does for a client with a total timeout, giving it all the time
+/// left, and read the body.
+fn read_slow_body(total_timeout: Duration, pause: Duration) -> std::io::Result<Vec<u8>> {
+    let client = NoauthDefaultClient::default().with_total_timeout(total_timeout);
+    let request = client.new_request(&slow_body_server(pause))
+        .set_timeout(client.total_timeout().unwrap());
+    let mut body = vec![];
+    client.execute(request, b"").unwrap().body.read_to_end(&mut body)?;
+    Ok(body)
+}
+
+#[test]
+fn test_total_timeout_includes_body() {
+    let err = read_slow_body(Duration::from_millis(300), Duration::from_secs(2)).unwrap_err();
+    assert_eq!(ErrorKind::TimedOut, err.kind(), "wrong error: {err:?}");
+
+    let body = read_slow_body(Duration::from_secs(10), Duration::from_millis(100)).unwrap();
+    assert_eq!(b"helloworld", &body[..]);
+}
+
+#[test]
+fn test_team_client_for_member() {
+    let auth = Authorization::from_refresh_token("client".to_owned(), "refresh".to_owned());
+    let team = TeamAuthDefaultClient::new(TokenCache::new(auth.clone()));
+    let member = team.as_member("dbmid:a");
+    let admin = team.as_admin("dbmid:b");
+    assert_eq!(Some(&TeamSelect::User("dbmid:a".to_owned())), member.team_select());
+    assert_eq!(Some(&TeamSelect::Admin("dbmid:b".to_owned())), admin.team_select());
+    assert_eq!(None, team.team_select());
+
+    let member = TeamAuthDefaultClient::for_member(TokenCache::new(auth.clone()), "dbmid:c");
+    let admin = TeamAuthDefaultClient::for_admin(TokenCache::new(auth), "dbmid:d");
+    assert_eq!(Some(&TeamSelect::User("dbmid:c".to_owned())), member.team_select());
+    assert_eq!(Some(&TeamSelect::Admin("dbmid:d".to_owned())), admin.team_select());
+}
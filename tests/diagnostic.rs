@@ -0,0 +1,98 @@
+#![warn(rust_2018_idioms)]
+
+use std::error::Error as _;
+use futures::executor::block_on;
+use dropbox_sdk::{async_routes, auth, diagnostic, files, Error};
+use miette::Diagnostic;
+
+mod mock;
+use mock::{MockClient, Response};
+
+fn not_found() -> Response {
+    Response::status(409, r#"{
+        "error_summary": "path/not_found/..",
+        "error": {".tag": "path", "path": {".tag": "not_found"}}
+    }"#)
+}
+
+fn get_metadata() -> miette::Result<files::Metadata> {
+    let client = MockClient::new([not_found()]);
+    Ok(files::get_metadata(&client, &files::GetMetadataArg::new("/foo".to_owned()))?)
+}
+
+#[test]
+fn test_api_error() {
+    let report = get_metadata().unwrap_err();
+    assert_eq!("dropbox::api::path::not_found", report.code().unwrap().to_string());
+    assert!(report.help().is_none());
+    assert!(report.downcast_ref::<Error<files::GetMetadataError>>().is_some());
+}
+
+#[test]
+fn test_help() {
+    let err = Error::<files::GetMetadataError>::RateLimited {
+        reason: auth::RateLimitReason::TooManyRequests,
+        retry_after_seconds: 5,
+    };
+    assert_eq!("dropbox::rate_limited", err.code().unwrap().to_string());
+    assert_eq!("this is temporary; retry after 5s", err.help().unwrap().to_string());
+
+    let err = Error::<files::GetMetadataError>::Authentication(auth::AuthError::MissingScope(
+        auth::TokenScopeError::new("files.metadata.read".to_owned())));
+    assert_eq!("dropbox::authentication::missing_scope", err.code().unwrap().to_string());
+    assert!(err.help().unwrap().to_string().contains("`files.metadata.read` scope"));
+
+    let err = Error::<files::GetMetadataError>::UnexpectedHttpError {
+        code: 418,
+        response: String::new(),
+    };
+    assert_eq!("dropbox::http::418", err.code().unwrap().to_string());
+}
+
+#[test]
+fn test_boxed() {
+    let err = Error::Api(files::GetMetadataError::Path(files::LookupError::NotFound)).boxed();
+    assert_eq!("dropbox::api", err.code().unwrap().to_string());
+
+    let err = Error::<files::GetMetadataError>::ServerError(String::new()).boxed();
+    assert_eq!("dropbox::server_error", err.code().unwrap().to_string());
+    assert_eq!("this is probably temporary; retry later", err.help().unwrap().to_string());
+}
+
+#[test]
+fn test_capture() {
+    let client = MockClient::new([not_found().with_request_id("abc123")]);
+    let arg = files::GetMetadataArg::new("/foo".to_owned());
+    let report = diagnostic::capture(|| files::get_metadata(&client, &arg)).unwrap_err();
+    let context = report.context().unwrap();
+    assert_eq!("files/get_metadata", context.route);
+    assert_eq!(409, context.status);
+    assert_eq!(Some("abc123"), context.request_id.as_deref());
+    assert_eq!("files/get_metadata returned HTTP 409 (request ID abc123)", report.to_string());
+    assert_eq!("dropbox::api::path::not_found", report.code().unwrap().to_string());
+    assert!(report.source().unwrap().is::<Error<files::GetMetadataError>>());
+    assert!(matches!(report.into_error(), Error::Api(files::GetMetadataError::Path(_))));
+}
+
+#[test]
+fn test_capture_async() {
+    let client = MockClient::new([Response::status(500, "oops")]);
+    let arg = files::GetMetadataArg::new("/foo".to_owned());
+    let call = async_routes::files::get_metadata(&client, &arg);
+    let report = block_on(diagnostic::capture_async(call)).unwrap_err();
+    let context = report.context().unwrap();
+    assert_eq!("files/get_metadata", context.route);
+    assert_eq!(500, context.status);
+    assert_eq!(None, context.request_id);
+    assert_eq!("files/get_metadata returned HTTP 500", report.to_string());
+    assert!(matches!(report.error(), Error::ServerError(_)));
+}
+
+#[test]
+fn test_capture_without_response() {
+    let client = MockClient::new([Response::io_error(std::io::ErrorKind::ConnectionRefused)]);
+    let arg = files::GetMetadataArg::new("/foo".to_owned());
+    let report = diagnostic::capture(|| files::get_metadata(&client, &arg)).unwrap_err();
+    assert!(report.context().is_none());
+    assert_eq!(report.error().to_string(), report.to_string());
+}
@@ -0,0 +1,96 @@
+#![warn(rust_2018_idioms)]
+
+use futures::executor::block_on;
+use dropbox_sdk::content_hash::content_hash;
+use dropbox_sdk::disk_cache::DiskCache;
+
+mod mock;
+use mock::{MockClient, Response};
+
+fn file_metadata(data: &[u8]) -> String {
+    serde_json::json!({
+        ".tag": "file",
+        "name": "a.txt",
+        "id": "id:abc",
+        "client_modified": "2020-01-01T00:00:00Z",
+        "server_modified": "2020-01-01T00:00:00Z",
+        "rev": "0123456789abcdef",
+        "size": data.len(),
+        "content_hash": content_hash(data),
+    }).to_string()
+}
+
+fn temp_dir(name: &str) -> std::path::PathBuf {
+    let mut dir = std::env::temp_dir();
+    dir.push(format!("dropbox-sdk-test-{}-{}", name, std::process::id()));
+    let _ = std::fs::remove_dir_all(&dir);
+    dir
+}
+
+#[test]
+fn test_get_or_download() {
+    let dir = temp_dir("disk-cache");
+    let cache = DiskCache::open(&dir).unwrap();
+
+    let client = MockClient::new([
+        Response::json(&file_metadata(b"hello")),
+        Response::download(&file_metadata(b"hello"), b"hello"),
+        Response::json(&file_metadata(b"hello")),
+    ]);
+
+    let path = block_on(cache.get_or_download(&client, "/a.txt")).unwrap();
+    assert_eq!(b"hello".to_vec(), std::fs::read(&path).unwrap());
+    assert_eq!(dir.join(content_hash(b"hello")), path);
+
+    // The second time, it's only looked up.
+    let again = block_on(cache.get_or_download(&client, "/a.txt")).unwrap();
+    assert_eq!(path, again);
+    let requests = client.requests();
+    assert_eq!(3, requests.len());
+    assert!(requests[1].url.ends_with("files/download"), "wrong request: {}", requests[1].url);
+    assert!(requests[2].url.ends_with("files/get_metadata"), "wrong request: {}", requests[2].url);
+
+    // Reopening finds the existing entry.
+    let cache = DiskCache::open(&dir).unwrap();
+    assert_eq!(Some(path), cache.get(&content_hash(b"hello")));
+    assert_eq!(5, cache.size());
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn test_insert_and_evict() {
+    let dir = temp_dir("disk-cache-evict");
+    let cache = DiskCache::open(&dir).unwrap().with_max_size(10);
+
+    let err = cache.insert(&content_hash(b"hello"), &b"wrong"[..]).unwrap_err();
+    assert_eq!(std::io::ErrorKind::InvalidData, err.kind());
+    assert_eq!(0, std::fs::read_dir(&dir).unwrap().count());
+
+    let a = content_hash(b"aaaa");
+    let b = content_hash(b"bbbb");
+    let c = content_hash(b"cccc");
+    cache.insert(&a, &b"aaaa"[..]).unwrap();
+    std::thread::sleep(std::time::Duration::from_millis(10));
+    cache.insert(&b, &b"bbbb"[..]).unwrap();
+    std::thread::sleep(std::time::Duration::from_millis(10));
+
+    // Using `a` makes `b` the least recently used.
+    assert!(cache.get(&a).is_some());
+    std::thread::sleep(std::time::Duration::from_millis(10));
+    cache.insert(&c, &b"cccc"[..]).unwrap();
+
+    assert!(cache.get(&a).is_some());
+    assert!(cache.get(&b).is_none());
+    assert!(cache.get(&c).is_some());
+    assert!(!dir.join(&b).exists());
+    assert_eq!(8, cache.size());
+
+    // A file bigger than the limit is kept, but everything else is evicted.
+    let big = b"0123456789abcdef";
+    let path = cache.insert(&content_hash(big), &big[..]).unwrap();
+    assert!(path.exists());
+    assert_eq!(16, cache.size());
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}
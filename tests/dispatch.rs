@@ -0,0 +1,166 @@
+#![warn(rust_2018_idioms)]
+
+use futures::FutureExt;
+use dropbox_sdk::client_trait_common::{Endpoint, Style};
+use dropbox_sdk::dispatch::{call_form, call_raw, dispatch, route, routes, DispatchError, RawApiError};
+use dropbox_sdk::Error;
+
+mod mock;
+use mock::{MockClient, Response};
+
+#[test]
+fn test_route_lookup() {
+    let upload = route("files/upload").unwrap();
+    assert_eq!(Endpoint::Content, upload.endpoint);
+    assert_eq!(Style::Upload, upload.style);
+    assert_eq!(&["user"], upload.auth);
+
+    assert_eq!(&["app", "user"], route("files/get_thumbnail_v2").unwrap().auth);
+    assert!(route("files/nonexistent").is_none());
+    assert!(routes().windows(2).all(|pair| pair[0].name < pair[1].name));
+}
+
+#[test]
+fn test_deprecated_routes() {
+    use dropbox_sdk::deprecation;
+
+    let copy = route("files/copy").unwrap();
+    assert!(copy.deprecated);
+    assert_eq!(Some("files/copy_v2"), copy.replaced_by);
+    assert!(!route("files/upload").unwrap().deprecated);
+    assert!(deprecation::is_deprecated("files/copy"));
+    assert_eq!(Some("files/copy_v2"), deprecation::replaced_by("files/copy"));
+    assert!(!deprecation::is_deprecated("files/copy_v2"));
+
+    let reports = route("team/reports/get_storage").unwrap();
+    assert!(reports.deprecated);
+    assert_eq!(None, reports.replaced_by);
+    assert!(deprecation::is_deprecated("team/reports/get_storage"));
+    assert_eq!(None, deprecation::replaced_by("team/reports/get_storage"));
+
+    // Every deprecated route in the table is known to be deprecated.
+    for info in routes() {
+        assert_eq!(info.deprecated, deprecation::is_deprecated(info.name), "{}", info.name);
+    }
+}
+
+#[test]
+fn test_dispatch() {
+    let client = MockClient::new([
+        Response::json(r#"{"entries": [], "cursor": "abc", "has_more": false}"#),
+        Response::status(409, r#"{"error_summary": "path/not_found/..", "error": {".tag": "path", "path": {".tag": "not_found"}}}"#),
+    ]);
+    let params = serde_json::json!({"path": "/foo"});
+
+    let result = dispatch(&client, "files/list_folder", &params, None)
+        .now_or_never().unwrap().unwrap();
+    assert_eq!("abc", result.result["cursor"]);
+    assert!(result.body.is_none());
+
+    match dispatch(&client, "files/list_folder", &params, None).now_or_never().unwrap() {
+        Err(DispatchError::Call(Error::Api(RawApiError(e)))) => assert_eq!("not_found", e["path"][".tag"]),
+        other => panic!("unexpected result: {:?}", other.map(|r| r.result)),
+    }
+
+    let requests = client.requests();
+    assert_eq!("https://api.dropboxapi.com/2/files/list_folder", requests[0].url);
+    assert_eq!(params, serde_json::from_slice::<serde_json::Value>(&requests[0].body).unwrap());
+
+    assert!(matches!(
+        dispatch(&client, "files/bogus", &params, None).now_or_never().unwrap(),
+        Err(DispatchError::UnknownRoute(name)) if name == "files/bogus"));
+}
+
+#[test]
+fn test_dispatch_wrong_auth() {
+    let client = MockClient::new([]).with_auth("team");
+    let params = serde_json::json!({"path": "/foo"});
+    assert!(matches!(
+        dispatch(&client, "files/list_folder", &params, None).now_or_never().unwrap(),
+        Err(DispatchError::WrongAuth { route: "files/list_folder", auth: "team" })));
+
+    let client = MockClient::new([]).with_auth("app");
+    assert!(matches!(
+        dispatch(&client, "team/get_info", &serde_json::Value::Null, None)
+            .now_or_never().unwrap(),
+        Err(DispatchError::WrongAuth { route: "team/get_info", auth: "app" })));
+    assert!(client.requests().is_empty());
+}
+
+#[test]
+fn test_call_raw() {
+    let client = MockClient::new([
+        Response::json(r#"{"beta": true}"#),
+        Response::status(409, r#"{"error_summary": "nope/", "error": {".tag": "nope"}}"#),
+        Response::status(400, "bad input"),
+    ]).with_token("token");
+    let params = serde_json::json!({"path": "/foo"});
+
+    let mut result = call_raw(&client, Endpoint::Api, Style::Rpc, "files/beta_thing", &params, None)
+        .now_or_never().unwrap().unwrap();
+    assert_eq!(200, result.status);
+    let mut body = String::new();
+    futures::AsyncReadExt::read_to_string(&mut result.body, &mut body)
+        .now_or_never().unwrap().unwrap();
+    assert_eq!(r#"{"beta": true}"#, body);
+
+    match call_raw(&client, Endpoint::Api, Style::Rpc, "files/beta_thing", &params, None)
+        .now_or_never().unwrap()
+    {
+        Err(Error::Api(RawApiError(e))) => assert_eq!("nope", e[".tag"]),
+        other => panic!("unexpected result: {:?}", other.map(|r| r.status)),
+    }
+    assert!(matches!(
+        call_raw(&client, Endpoint::Api, Style::Rpc, "files/beta_thing", &params, None)
+            .now_or_never().unwrap(),
+        Err(Error::BadRequest(msg)) if msg == "bad input"));
+
+    let requests = client.requests();
+    assert_eq!("https://api.dropboxapi.com/2/files/beta_thing", requests[0].url);
+    assert_eq!(Some("Bearer token"), requests[0].header("Authorization"));
+    assert_eq!(params, serde_json::from_slice::<serde_json::Value>(&requests[0].body).unwrap());
+}
+
+#[test]
+fn test_call_form() {
+    #[derive(serde::Serialize)]
+    struct Params {
+        grant_type: &'static str,
+        code: String,
+        pkce: bool,
+        expires_in: u32,
+        redirect_uri: Option<String>,
+    }
+
+    let client = MockClient::new([Response::json(r#"{"ok": true}"#)]);
+    let params = Params {
+        grant_type: "custom",
+        code: "a b&c".to_owned(),
+        pkce: true,
+        expires_in: 60,
+        redirect_uri: None,
+    };
+    let result = call_form(&client, Endpoint::OAuth2, "oauth2/custom", &params)
+        .now_or_never().unwrap().unwrap();
+    assert_eq!(200, result.status);
+
+    let request = &client.requests()[0];
+    assert_eq!("https://api.dropboxapi.com/oauth2/custom", request.url);
+    assert_eq!(Some("application/x-www-form-urlencoded"), request.header("Content-Type"));
+    assert!(!request.body.contains(&b' '));
+    let mut fields = url::form_urlencoded::parse(&request.body).into_owned().collect::<Vec<_>>();
+    fields.sort();
+    assert_eq!(vec![
+        ("code".to_owned(), "a b&c".to_owned()),
+        ("expires_in".to_owned(), "60".to_owned()),
+        ("grant_type".to_owned(), "custom".to_owned()),
+        ("pkce".to_owned(), "true".to_owned()),
+    ], fields);
+
+    // Only flat values can be form-encoded.
+    let nested = serde_json::json!({"a": {"b": 1}});
+    assert!(matches!(
+        call_form(&client, Endpoint::Api, "files/thing", &nested).now_or_never().unwrap(),
+        Err(Error::Json(_))));
+    assert_eq!(1, client.requests().len());
+}
@@ -0,0 +1,201 @@
+#![warn(rust_2018_idioms)]
+
+use std::time::Duration;
+use dropbox_sdk::{files, sharing};
+use dropbox_sdk::{Error, PolicyError};
+
+mod mock;
+use mock::{MockClient, Response};
+
+#[test]
+fn test_missing_scope() {
+    let client = MockClient::new([Response::status(401, r#"{
+        "error_summary": "missing_scope/.",
+        "error": {
+            ".tag": "missing_scope",
+            "required_scope": "files.metadata.read"
+        }
+    }"#)]);
+
+    let err = files::get_metadata(&client, &files::GetMetadataArg::new("/foo".to_owned()))
+        .unwrap_err();
+    assert!(matches!(err, Error::Authentication(_)), "wrong error: {:?}", err);
+    assert_eq!(Some("files.metadata.read"), err.missing_scope());
+
+    // Other errors don't have a missing scope.
+    assert_eq!(None, Error::<files::GetMetadataError>::BadRequest(String::new()).missing_scope());
+}
+
+#[test]
+fn test_deserialize_error_body() {
+    let long = format!(r#"{{"name": "{}"#, "é".repeat(1000));
+    let client = MockClient::new([
+        Response::json(r#"{"unexpected": true}"#),
+        Response::status(409, r#"{"error": {".tag": 1}}"#),
+        Response::json(&long),
+    ]);
+    let arg = files::GetMetadataArg::new("/foo".to_owned());
+
+    match files::get_metadata(&client, &arg) {
+        Err(Error::Deserialize { route, body_snippet, .. }) => {
+            assert_eq!("files/get_metadata", route);
+            assert_eq!(r#"{"unexpected": true}"#, body_snippet);
+        }
+        other => panic!("wrong result: {:?}", other),
+    }
+
+    match files::get_metadata(&client, &arg) {
+        Err(Error::Deserialize { body_snippet, .. }) => {
+            assert_eq!(r#"{"error": {".tag": 1}}"#, body_snippet);
+        }
+        other => panic!("wrong result: {:?}", other),
+    }
+
+    // Long bodies are truncated, on a character boundary.
+    match files::get_metadata(&client, &arg) {
+        Err(Error::Deserialize { body_snippet, .. }) => {
+            assert!(body_snippet.len() <= 1024);
+            assert!(long.starts_with(&body_snippet));
+            assert!(body_snippet.len() > 1000);
+        }
+        other => panic!("wrong result: {:?}", other),
+    }
+
+    // As are error responses which can't be parsed.
+    let client = MockClient::new([Response::status(401, "not json")]);
+    match files::get_metadata(&client, &arg) {
+        Err(Error::Deserialize { route, body_snippet, .. }) => {
+            assert_eq!("files/get_metadata", route);
+            assert_eq!("not json", body_snippet);
+        }
+        other => panic!("wrong result: {:?}", other),
+    }
+}
+
+#[test]
+fn test_retry_after() {
+    let client = MockClient::new([
+        Response::status(409, r#"{
+            "error_summary": "path/too_many_write_operations/",
+            "error": {
+                ".tag": "path",
+                "reason": {".tag": "too_many_write_operations"},
+                "upload_session_id": "abc"
+            }
+        }"#),
+        Response::status(409, r#"{
+            "error_summary": "too_many_write_operations/",
+            "error": {".tag": "too_many_write_operations"}
+        }"#),
+        Response::status(429, r#"{
+            "error_summary": "too_many_requests/",
+            "error": {"reason": {".tag": "too_many_requests"}, "retry_after": 30}
+        }"#),
+        Response::status(409, r#"{
+            "error_summary": "path_lookup/not_found/",
+            "error": {".tag": "path_lookup", "path_lookup": {".tag": "not_found"}}
+        }"#),
+    ]);
+
+    let err = files::upload(&client, &files::UploadArg::new("/foo".to_owned()), &[])
+        .unwrap_err();
+    assert!(matches!(err, Error::Api(files::UploadError::Path(_))), "wrong error: {:?}", err);
+    assert_eq!(Some(dropbox_sdk::WRITE_CONTENTION_RETRY_AFTER), err.retry_after());
+
+    let arg = files::DeleteArg::new("/foo".to_owned());
+    let err = files::delete_v2(&client, &arg).unwrap_err();
+    assert!(matches!(err, Error::Api(files::DeleteError::TooManyWriteOperations)),
+        "wrong error: {:?}", err);
+    assert_eq!(Some(dropbox_sdk::WRITE_CONTENTION_RETRY_AFTER), err.retry_after());
+
+    let err = files::delete_v2(&client, &arg).unwrap_err();
+    assert_eq!(Some(std::time::Duration::from_secs(30)), err.retry_after());
+    assert_eq!(Some(std::time::Duration::from_secs(30)), err.retry_delay(Duration::ZERO));
+    for _ in 0..20 {
+        let delay = err.retry_delay(Duration::from_secs(5)).unwrap();
+        assert!(delay >= Duration::from_secs(30) && delay < Duration::from_secs(35),
+            "delay out of range: {:?}", delay);
+    }
+
+    let err = files::delete_v2(&client, &arg).unwrap_err();
+    assert_eq!(None, err.retry_after());
+    assert_eq!(None, err.retry_delay(Duration::from_secs(5)));
+}
+
+#[test]
+fn test_policy_error() {
+    let client = MockClient::new([
+        Response::status(403, r#"{
+            "error_summary": "paper_access_denied/paper_disabled/",
+            "error": {".tag": "paper_access_denied", "paper_access_denied": {".tag": "paper_disabled"}}
+        }"#),
+        Response::status(409, r#"{
+            "error_summary": "shared_link_already_exists/",
+            "error": {".tag": "shared_link_already_exists"}
+        }"#),
+        Response::status(409, r#"{
+            "error_summary": "team_folder/",
+            "error": {".tag": "team_folder"}
+        }"#),
+        Response::status(409, r#"{
+            "error_summary": "cant_share_outside_team/",
+            "error": {".tag": "cant_share_outside_team"}
+        }"#),
+    ]);
+
+    let err = files::get_metadata(&client, &files::GetMetadataArg::new("/foo".to_owned()))
+        .unwrap_err();
+    assert_eq!(Some(PolicyError::PaperDisabled), err.policy_error());
+
+    let arg = sharing::CreateSharedLinkWithSettingsArg::new("/foo".to_owned());
+    let err = sharing::create_shared_link_with_settings(&client, &arg).unwrap_err();
+    assert_eq!(None, err.policy_error());
+
+    let arg = sharing::RemoveFolderMemberArg::new(
+        "123".to_owned(),
+        sharing::MemberSelector::Email("a@example.com".to_owned()),
+        false);
+    let err = sharing::remove_folder_member(&client, &arg).unwrap_err();
+    assert_eq!(Some(PolicyError::TeamFolder), err.policy_error());
+
+    let arg = sharing::AddFolderMemberArg::new("123".to_owned(), vec![]);
+    let err = sharing::add_folder_member(&client, &arg).unwrap_err();
+    assert!(matches!(err, Error::Api(sharing::AddFolderMemberError::CantShareOutsideTeam)),
+        "wrong error: {:?}", err);
+    assert_eq!(Some(PolicyError::TeamPolicy), err.policy_error());
+}
+
+#[test]
+fn test_service_unavailable() {
+    let client = MockClient::new([
+        Response::status(503, "down for maintenance").with_retry_after("3600"),
+    ]);
+    let err = files::get_metadata(&client, &files::GetMetadataArg::new("/foo".to_owned()))
+        .unwrap_err();
+    match &err {
+        Error::ServiceUnavailable { retry_after_seconds, response } => {
+            assert_eq!(3600, *retry_after_seconds);
+            assert_eq!("down for maintenance", response);
+        }
+        other => panic!("wrong error: {:?}", other),
+    }
+    assert_eq!(Some(std::time::Duration::from_secs(3600)), err.retry_after());
+}
+
+#[test]
+fn test_nested_display() {
+    let err = Error::Api(files::DownloadError::Path(files::LookupError::NotFound));
+    assert_eq!(
+        "Dropbox API endpoint returned an error: path: There is nothing at the given path.",
+        err.to_string());
+
+    let err = files::DeleteError::PathLookup(files::LookupError::NotFound);
+    assert_eq!("path_lookup: There is nothing at the given path.", err.to_string());
+
+    let err = files::RelocationError::To(files::WriteError::Conflict(
+        files::WriteConflictError::File));
+    assert_eq!(
+        "to: Couldn't write to the target path because there was something in the way: \
+        There's a file in the way.",
+        err.to_string());
+}
@@ -0,0 +1,34 @@
+#![warn(rust_2018_idioms)]
+
+use dropbox_sdk::{files, users};
+
+mod mock;
+use mock::{MockClient, Response};
+
+const SPACE_USAGE: &str = r#"{"used": 1, "allocation": {".tag": "individual", "allocated": 2}}"#;
+
+#[test]
+fn test_extra_headers() {
+    let client = MockClient::new([
+        Response::json(SPACE_USAGE),
+        Response::json(r#"{"changes": false}"#),
+    ])
+        .with_token("secret")
+        .with_extra_header("Dropbox-API-Feature-Flag", "beta")
+        .with_extra_header("X-Other", "1");
+
+    users::get_space_usage(&client).unwrap();
+    files::list_folder_longpoll(&client, &files::ListFolderLongpollArg::new("c".to_owned()))
+        .unwrap();
+
+    for request in client.requests() {
+        assert_eq!(Some("beta"), request.header("Dropbox-API-Feature-Flag"), "{}", request.url);
+        assert_eq!(Some("1"), request.header("X-Other"), "{}", request.url);
+    }
+    // The SDK's own headers are still sent.
+    assert_eq!(Some("Bearer secret"), client.requests()[0].header("Authorization"));
+
+    let client = MockClient::new([Response::json(SPACE_USAGE)]);
+    users::get_space_usage(&client).unwrap();
+    assert_eq!(None, client.requests()[0].header("Dropbox-API-Feature-Flag"));
+}
@@ -0,0 +1,140 @@
+#![warn(rust_2018_idioms)]
+
+use futures::executor::block_on;
+use futures::TryStreamExt;
+use dropbox_sdk::async_routes::files;
+use dropbox_sdk::fake::FakeDropbox;
+use dropbox_sdk::files_helpers::{self, ListFolderOptions, UploadOptions};
+
+fn list(fake: &FakeDropbox, path: &str, recursive: bool) -> Vec<String> {
+    let arg = files::ListFolderArg::new(path.to_owned()).with_recursive(recursive);
+    block_on(files_helpers::list_folder(fake, arg, &ListFolderOptions::default())
+        .map_ok(|entry| match entry.metadata {
+            files::Metadata::File(f) => f.path_display.unwrap(),
+            files::Metadata::Folder(f) => f.path_display.unwrap() + "/",
+            files::Metadata::Deleted(d) => d.name,
+        })
+        .try_collect())
+        .unwrap()
+}
+
+#[test]
+fn test_upload_download() {
+    let fake = FakeDropbox::new();
+    let metadata = block_on(files_helpers::upload(
+        &fake,
+        files::CommitInfo::new("/Docs/a.txt".to_owned()),
+        &b"hello"[..],
+        &UploadOptions::default(),
+    )).unwrap();
+    assert_eq!("a.txt", metadata.name);
+    assert_eq!(Some("/docs/a.txt"), metadata.path_lower.as_deref());
+    assert_eq!(5, metadata.size);
+    assert_eq!(Some(dropbox_sdk::content_hash::content_hash(b"hello")), metadata.content_hash);
+    assert_eq!(Some(b"hello".to_vec()), fake.contents("/DOCS/A.TXT"));
+    assert_eq!(vec!["/Docs", "/Docs/a.txt"], fake.paths());
+
+    let arg = files::DownloadArg::new("/docs/a.txt".to_owned());
+    let mut downloaded = Vec::new();
+    block_on(files_helpers::download_with_report(
+        &fake, &arg, &mut downloaded, &dropbox_sdk::retry::RetryBudget::new(0))).unwrap();
+    assert_eq!(b"hello", &downloaded[..]);
+
+    // Adding different contents conflicts, but overwriting doesn't.
+    let err = block_on(files::upload(
+        &fake, &files::UploadArg::new("/docs/a.txt".to_owned()), b"bye".to_vec().into()))
+        .unwrap_err();
+    assert!(matches!(err, dropbox_sdk::Error::Api(files::UploadError::Path(ref failed))
+        if failed.reason == files::WriteError::Conflict(files::WriteConflictError::File)),
+        "wrong error: {:?}", err);
+    let updated = block_on(files::upload(
+        &fake,
+        &files::UploadArg::new("/docs/a.txt".to_owned()).with_mode(files::WriteMode::Overwrite),
+        b"bye".to_vec().into(),
+    )).unwrap();
+    assert_eq!(metadata.id, updated.id);
+    assert_ne!(metadata.rev, updated.rev);
+
+    let arg = files::DownloadArg::new("/b.txt".to_owned());
+    let err = match block_on(files::download(&fake, &arg, None, None)) {
+        Ok(_) => panic!("downloaded a file which doesn't exist"),
+        Err(e) => e,
+    };
+    assert!(matches!(err,
+        dropbox_sdk::Error::Api(files::DownloadError::Path(files::LookupError::NotFound))),
+        "wrong error: {:?}", err);
+}
+
+#[test]
+fn test_upload_session() {
+    let fake = FakeDropbox::new();
+    let data = vec![7u8; files_helpers::UPLOAD_CHUNK_SIZE * 2 + 3];
+    let metadata = block_on(files_helpers::upload(
+        &fake,
+        files::CommitInfo::new("/big".to_owned()),
+        &data[..],
+        &UploadOptions::default(),
+    )).unwrap();
+    assert_eq!(data.len() as u64, metadata.size);
+    assert_eq!(Some(data), fake.contents("/big"));
+    assert_eq!(
+        vec!["files/upload_session/start", "files/upload_session/append_v2",
+            "files/upload_session/finish"],
+        fake.calls());
+}
+
+#[test]
+fn test_list_move_delete() {
+    let fake = FakeDropbox::new()
+        .with_file("/a/x.txt", "x")
+        .with_file("/a/b/y.txt", "y")
+        .with_folder("/c")
+        .with_file("/z.txt", "z");
+
+    assert_eq!(vec!["/a/", "/c/", "/z.txt"], list(&fake, "", false));
+    assert_eq!(vec!["/a/", "/a/b/", "/a/b/y.txt", "/a/x.txt"], list(&fake, "/A", true));
+
+    // Small pages are followed with the cursor.
+    let arg = files::ListFolderArg::new(String::new()).with_recursive(true).with_limit(2);
+    let page = block_on(files::list_folder(&fake, &arg)).unwrap();
+    assert_eq!(2, page.entries.len());
+    assert!(page.has_more);
+    let page = block_on(files::list_folder_continue(
+        &fake, &files::ListFolderContinueArg::new(page.cursor))).unwrap();
+    assert_eq!(2, page.entries.len());
+
+    let arg = files::RelocationArg::new("/a".to_owned(), "/c/moved".to_owned());
+    block_on(files::move_v2(&fake, &arg)).unwrap();
+    assert_eq!(
+        vec!["/c/moved/", "/c/moved/b/", "/c/moved/b/y.txt", "/c/moved/x.txt"],
+        list(&fake, "/c/moved", true));
+    assert_eq!(Some(b"y".to_vec()), fake.contents("/c/moved/b/y.txt"));
+
+    let arg = files::RelocationArg::new("/z.txt".to_owned(), "/c/moved/x.txt".to_owned());
+    let err = block_on(files::move_v2(&fake, &arg)).unwrap_err();
+    assert!(matches!(err, dropbox_sdk::Error::Api(files::RelocationError::To(
+        files::WriteError::Conflict(files::WriteConflictError::File)))), "wrong error: {:?}", err);
+
+    let arg = files::DeleteArg::new("/c/moved/b".to_owned());
+    let deleted = block_on(files::delete_v2(&fake, &arg)).unwrap();
+    assert!(matches!(deleted.metadata, files::Metadata::Folder(_)));
+    assert_eq!(vec!["/c", "/c/moved", "/c/moved/x.txt", "/z.txt"], fake.paths());
+    let err = block_on(files::delete_v2(&fake, &arg)).unwrap_err();
+    assert!(matches!(err,
+        dropbox_sdk::Error::Api(files::DeleteError::PathLookup(files::LookupError::NotFound))),
+        "wrong error: {:?}", err);
+
+    // IDs work as paths, and folders can be created.
+    let id = match fake.metadata("/z.txt").unwrap() {
+        files::Metadata::File(f) => f.id,
+        other => panic!("not a file: {:?}", other),
+    };
+    assert!(block_on(files_helpers::exists(&fake, &id)).unwrap());
+    block_on(files_helpers::ensure_folder(&fake, "/new/folder")).unwrap();
+    assert!(matches!(fake.metadata("/new/folder"), Some(files::Metadata::Folder(_))));
+
+    // Routes which aren't simulated fail.
+    let arg = files::RelocationArg::new("/z.txt".to_owned(), "/copy.txt".to_owned());
+    let err = block_on(files::copy_v2(&fake, &arg)).unwrap_err();
+    assert!(matches!(err, dropbox_sdk::Error::BadRequest(_)), "wrong error: {:?}", err);
+}
@@ -0,0 +1,148 @@
+#![warn(rust_2018_idioms)]
+
+use std::time::{Duration, SystemTime};
+use futures::executor::block_on;
+use futures::TryStreamExt;
+use dropbox_sdk::file_requests_helpers::{
+    close_expired_file_requests, extend_file_request_deadlines, list_all_file_requests,
+};
+use dropbox_sdk::retry::RetryBudget;
+
+mod mock;
+use mock::{MockClient, Response};
+
+fn file_request(id: &str, is_open: bool, deadline: Option<(&str, &str)>) -> String {
+    let deadline = match deadline {
+        Some((time, grace)) => format!(
+            r#", "deadline": {{"deadline": "{time}", "allow_late_uploads": {{".tag": "{grace}"}}}}"#),
+        None => String::new(),
+    };
+    format!(r#"{{
+        "id": "{id}",
+        "url": "https://www.dropbox.com/request/{id}",
+        "title": "Request {id}",
+        "created": "2024-01-01T00:00:00Z",
+        "is_open": {is_open},
+        "file_count": 0{deadline}
+    }}"#)
+}
+
+fn page(requests: &[String], cursor: &str, has_more: bool) -> Response {
+    Response::json(&format!(r#"{{"file_requests": [{}], "cursor": "{cursor}", "has_more": {has_more}}}"#,
+        requests.join(",")))
+}
+
+fn rate_limited() -> Response {
+    Response::status(429, r#"{
+        "error_summary": "too_many_requests/",
+        "error": {"reason": {".tag": "too_many_requests"}, "retry_after": 0}
+    }"#)
+}
+
+fn time(s: &str) -> SystemTime {
+    dropbox_sdk::timestamp::to_system_time(s).unwrap()
+}
+
+#[test]
+fn test_list_all_file_requests() {
+    let client = MockClient::new([
+        page(&[file_request("a", true, None)], "c1", true),
+        page(&[file_request("b", false, None)], "c2", false),
+    ]);
+    let requests: Vec<_> = block_on(list_all_file_requests(&client).try_collect()).unwrap();
+    assert_eq!(vec!["a", "b"], requests.iter().map(|r| r.id.as_str()).collect::<Vec<_>>());
+
+    let sent = client.requests();
+    assert!(sent[0].url.ends_with("/file_requests/list_v2"));
+    assert!(sent[1].url.ends_with("/file_requests/list/continue"));
+    let arg: serde_json::Value = serde_json::from_slice(&sent[1].body).unwrap();
+    assert_eq!("c1", arg["cursor"]);
+}
+
+#[test]
+fn test_deadlines() {
+    let now = time("2024-03-10T00:00:00Z");
+    let list = [
+        // Past its deadline and its grace period.
+        file_request("expired", true, Some(("2024-03-01T00:00:00Z", "seven_days"))),
+        // Past its deadline, but still in its grace period.
+        file_request("grace", true, Some(("2024-03-05T00:00:00Z", "seven_days"))),
+        // Late uploads are always allowed.
+        file_request("always", true, Some(("2024-01-01T00:00:00Z", "always"))),
+        file_request("closed", false, Some(("2024-01-01T00:00:00Z", "one_day"))),
+        file_request("soon", true, Some(("2024-03-11T00:00:00Z", "one_day"))),
+        file_request("none", true, None),
+    ];
+    let client = MockClient::new([page(&list, "c1", false)]);
+    let requests: Vec<_> = block_on(list_all_file_requests(&client).try_collect()).unwrap();
+    let expired = requests.iter()
+        .filter(|r| r.is_expired(now))
+        .map(|r| r.id.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(vec!["expired"], expired);
+    let due = requests.iter()
+        .filter(|r| r.is_due_within(now, Duration::from_secs(2 * 24 * 60 * 60)))
+        .map(|r| r.id.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(vec!["soon"], due);
+    assert_eq!(Some(time("2024-03-12T00:00:00Z")), requests[4].closes_at());
+    assert_eq!(None, requests[2].closes_at());
+    assert_eq!(None, requests[5].deadline_time());
+}
+
+#[test]
+fn test_close_expired() {
+    let now = time("2024-03-10T00:00:00Z");
+    let client = MockClient::new([
+        rate_limited(),
+        page(&[
+            file_request("a", true, Some(("2024-03-01T00:00:00Z", "one_day"))),
+            file_request("b", true, Some(("2024-03-20T00:00:00Z", "one_day"))),
+        ], "c1", true),
+        page(&[file_request("c", true, Some(("2024-03-02T00:00:00Z", "two_days")))], "c2", false),
+        Response::json(&file_request("a", false, Some(("2024-03-01T00:00:00Z", "one_day")))),
+        Response::status(409, r#"{
+            "error_summary": "not_found/",
+            "error": {".tag": "not_found"}
+        }"#),
+    ]);
+    let report = block_on(close_expired_file_requests(&client, now, &RetryBudget::new(1)))
+        .unwrap();
+    assert_eq!(1, report.updated.len());
+    assert!(!report.updated[0].is_open);
+    assert_eq!(1, report.failed.len());
+    assert_eq!("c", report.failed[0].0);
+    assert!(matches!(report.failed[0].1, dropbox_sdk::Error::Api(_)));
+
+    let sent = client.requests();
+    assert_eq!(5, sent.len());
+    assert!(sent[3].url.ends_with("/file_requests/update"));
+    let arg: serde_json::Value = serde_json::from_slice(&sent[3].body).unwrap();
+    assert_eq!("a", arg["id"]);
+    assert_eq!(false, arg["open"]);
+}
+
+#[test]
+fn test_extend_deadlines() {
+    let client = MockClient::new([
+        page(&[
+            file_request("a", true, Some(("2024-03-01T00:00:00Z", "seven_days"))),
+            file_request("b", true, Some(("2024-05-01T00:00:00Z", "one_day"))),
+            file_request("c", false, Some(("2024-03-01T00:00:00Z", "one_day"))),
+            file_request("d", true, None),
+        ], "c1", false),
+        Response::json(&file_request("a", true, Some(("2024-04-01T00:00:00Z", "seven_days")))),
+    ]);
+    let report = block_on(extend_file_request_deadlines(
+        &client, time("2024-04-01T00:00:00Z"), &RetryBudget::new(0))).unwrap();
+    assert_eq!(1, report.updated.len());
+    assert!(report.failed.is_empty());
+
+    let sent = client.requests();
+    assert_eq!(2, sent.len());
+    let arg: serde_json::Value = serde_json::from_slice(&sent[1].body).unwrap();
+    assert_eq!("a", arg["id"]);
+    assert_eq!("update", arg["deadline"][".tag"]);
+    assert_eq!("2024-04-01T00:00:00Z", arg["deadline"]["deadline"]);
+    assert_eq!("seven_days", arg["deadline"]["allow_late_uploads"][".tag"]);
+}
@@ -0,0 +1,1143 @@
+#![warn(rust_2018_idioms)]
+
+use std::io::Write;
+use futures::{FutureExt, StreamExt};
+use dropbox_sdk::files;
+use dropbox_sdk::files_helpers::{self, FolderStatsOptions, UploadOptions};
+
+mod mock;
+use mock::{FILE_METADATA, MockClient, Response, file_entry};
+
+fn arg_json(request: &mock::Request) -> serde_json::Value {
+    serde_json::from_str(request.header("Dropbox-API-Arg").expect("no Dropbox-API-Arg header"))
+        .unwrap()
+}
+
+#[test]
+fn test_upload_file_mtime() {
+    let mut local = std::env::temp_dir();
+    local.push(format!("dropbox-sdk-test-{}", std::process::id()));
+    std::fs::File::create(&local).unwrap().write_all(b"hello").unwrap();
+    let mtime = std::fs::metadata(&local).unwrap().modified().unwrap();
+
+    let client = MockClient::new([Response::json(FILE_METADATA), Response::json(FILE_METADATA)]);
+    let commit = files::CommitInfo::new("/a.txt".to_owned());
+
+    files_helpers::upload_file(&client, &local, commit.clone(), &UploadOptions::default())
+        .now_or_never().unwrap().unwrap();
+    files_helpers::upload_file(
+        &client, &local, commit, &UploadOptions::default().with_preserve_mtime(false))
+        .now_or_never().unwrap().unwrap();
+    std::fs::remove_file(&local).unwrap();
+
+    let requests = client.requests();
+    assert!(requests[0].url.ends_with("/files/upload"));
+    assert_eq!(b"hello", requests[0].body.as_slice());
+    let arg = arg_json(&requests[0]);
+    assert_eq!(dropbox_sdk::timestamp::from_system_time(mtime), arg["client_modified"]);
+    assert!(arg_json(&requests[1]).get("client_modified").is_none());
+}
+
+#[test]
+fn test_upload_session() {
+    // One full chunk and a bit more needs a session start and a finish.
+    let data = vec![7u8; files_helpers::UPLOAD_CHUNK_SIZE + 10];
+    let client = MockClient::new([
+        Response::json(r#"{"session_id": "sess"}"#),
+        Response::json(FILE_METADATA),
+    ]);
+    files_helpers::upload(
+        &client,
+        files::CommitInfo::new("/big".to_owned()),
+        data.as_slice(),
+        &UploadOptions::default(),
+    ).now_or_never().unwrap().unwrap();
+
+    let requests = client.requests();
+    assert_eq!(2, requests.len());
+    assert!(requests[0].url.ends_with("/files/upload_session/start"));
+    assert_eq!(files_helpers::UPLOAD_CHUNK_SIZE, requests[0].body.len());
+    assert!(requests[1].url.ends_with("/files/upload_session/finish"));
+    assert_eq!(10, requests[1].body.len());
+    let arg = arg_json(&requests[1]);
+    assert_eq!(files_helpers::UPLOAD_CHUNK_SIZE as u64, arg["cursor"]["offset"]);
+    assert_eq!("/big", arg["commit"]["path"]);
+}
+
+#[test]
+fn test_upload_batch() {
+    let client = MockClient::new([
+        Response::json(r#"{"session_ids": ["s1", "s2"]}"#),
+        Response::json("null"),
+        Response::json("null"),
+        Response::json("null"),
+        Response::json(&format!(r#"{{"entries": [
+            {{".tag": "success", {}}},
+            {{".tag": "failure", "failure": {{".tag": "path", "path": {{".tag": "conflict",
+                "conflict": {{".tag": "file"}}}}}}}}
+        ]}}"#, &FILE_METADATA[1..FILE_METADATA.len() - 1])),
+    ]);
+    let big = vec![7u8; files_helpers::UPLOAD_CHUNK_SIZE + 3];
+    let results = files_helpers::upload_batch(
+        &client,
+        vec![
+            (files::CommitInfo::new("/a.txt".to_owned()), &b"hello"[..]),
+            (files::CommitInfo::new("/big".to_owned()), big.as_slice()),
+        ],
+        &files_helpers::BatchUploadOptions::default(),
+    ).now_or_never().unwrap().unwrap();
+    assert_eq!("a.txt", results[0].as_ref().unwrap().name);
+    assert!(matches!(results[1], Err(files::UploadSessionFinishError::Path(
+        files::WriteError::Conflict(files::WriteConflictError::File)))), "{:?}", results[1]);
+
+    let requests = client.requests();
+    assert_eq!(5, requests.len());
+    assert!(requests[0].url.ends_with("/files/upload_session/start_batch"));
+    assert_eq!(br#"{"num_sessions":2}"#, requests[0].body.as_slice());
+    let appends = requests[1..4].iter()
+        .map(|request| {
+            assert!(request.url.ends_with("/files/upload_session/append_v2"));
+            let arg = arg_json(request);
+            (arg["cursor"]["session_id"].as_str().unwrap().to_owned(),
+                arg["cursor"]["offset"].as_u64().unwrap(), arg["close"] == true,
+                request.body.len())
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(vec![
+        ("s1".to_owned(), 0, true, 5),
+        ("s2".to_owned(), 0, false, files_helpers::UPLOAD_CHUNK_SIZE),
+        ("s2".to_owned(), files_helpers::UPLOAD_CHUNK_SIZE as u64, true, 3),
+    ], appends);
+    assert!(requests[4].url.ends_with("/files/upload_session/finish_batch_v2"));
+    let finish: serde_json::Value = serde_json::from_slice(&requests[4].body).unwrap();
+    assert_eq!(5, finish["entries"][0]["cursor"]["offset"]);
+    assert_eq!("/a.txt", finish["entries"][0]["commit"]["path"]);
+    assert_eq!(big.len() as u64, finish["entries"][1]["cursor"]["offset"]);
+    assert_eq!("/big", finish["entries"][1]["commit"]["path"]);
+}
+
+#[test]
+fn test_metadata_opt() {
+    let client = MockClient::new([
+        Response::json(&file_entry()),
+        Response::status(409, r#"{
+            "error_summary": "path/not_found/",
+            "error": {".tag": "path", "path": {".tag": "not_found"}}
+        }"#),
+        Response::status(409, r#"{
+            "error_summary": "path/not_found/",
+            "error": {".tag": "path", "path": {".tag": "not_found"}}
+        }"#),
+        Response::status(409, r#"{
+            "error_summary": "path/restricted_content/",
+            "error": {".tag": "path", "path": {".tag": "restricted_content"}}
+        }"#),
+    ]);
+
+    let metadata = files_helpers::metadata_opt(&client, "/a.txt")
+        .now_or_never().unwrap().unwrap();
+    assert!(matches!(metadata, Some(files::Metadata::File(_))), "wrong result: {:?}", metadata);
+    assert_eq!(None, files_helpers::metadata_opt(&client, "/b.txt").now_or_never().unwrap().unwrap());
+    assert!(!files_helpers::exists(&client, "/b.txt").now_or_never().unwrap().unwrap());
+
+    // Other errors are returned.
+    match files_helpers::exists(&client, "/c.txt").now_or_never().unwrap() {
+        Err(dropbox_sdk::Error::Api(files::GetMetadataError::Path(
+            files::LookupError::RestrictedContent))) => (),
+        other => panic!("wrong result: {:?}", other),
+    }
+}
+
+#[test]
+fn test_addressing() {
+    use files_helpers::{FileId, InvalidAddress, Rev};
+
+    assert_eq!("id:a4ayc_80_OEAAAAAAAAAXw", FileId::new("a4ayc_80_OEAAAAAAAAAXw").unwrap().as_path());
+    assert_eq!("id:abc-1", FileId::new("id:abc-1").unwrap().as_path());
+    for bad in ["", "id:", "/a.txt", "id:abc/def", "id:a b"] {
+        assert_eq!(Err(InvalidAddress::Id(bad.to_owned())), FileId::new(bad));
+    }
+
+    let rev = Rev::new("rev:0123456789abcdef").unwrap();
+    assert_eq!("0123456789abcdef", rev.as_str());
+    assert_eq!("rev:0123456789abcdef", rev.as_path());
+    assert_eq!(rev, Rev::new("0123456789abcdef").unwrap());
+    for bad in ["", "rev:", "01234567", "0123456789ABCDEF", "/a.txt"] {
+        assert_eq!(Err(InvalidAddress::Rev(bad.to_owned())), Rev::new(bad));
+    }
+
+    let client = MockClient::new([
+        Response::json(&file_entry()),
+        Response::json(&file_entry()),
+        Response::json(r#"{".tag": "folder", "name": "a", "id": "id:abc"}"#),
+    ]);
+    let id = FileId::new("id:abc").unwrap();
+    let metadata = files_helpers::get_metadata_by_id(&client, &id).now_or_never().unwrap().unwrap();
+    assert!(matches!(metadata, files::Metadata::File(_)), "{:?}", metadata);
+    let file = files_helpers::get_by_rev(&client, &rev).now_or_never().unwrap().unwrap();
+    assert_eq!("0123456789abcdef", file.rev);
+    files_helpers::get_by_rev(&client, &rev).now_or_never().unwrap().unwrap_err();
+
+    let paths = client.requests().iter()
+        .map(|r| serde_json::from_slice::<serde_json::Value>(&r.body).unwrap()["path"].clone())
+        .collect::<Vec<_>>();
+    assert_eq!(vec!["id:abc", "rev:0123456789abcdef", "rev:0123456789abcdef"], paths);
+}
+
+fn folder_metadata(path: &str) -> String {
+    let name = path.rsplit('/').next().unwrap();
+    format!(r#"{{"name": "{}", "id": "id:{}", "path_display": "{}"}}"#, name, name, path)
+}
+
+fn folder_conflict() -> Response {
+    Response::status(409, r#"{
+        "error_summary": "path/conflict/folder/",
+        "error": {".tag": "path", "path": {".tag": "conflict", "conflict": {".tag": "folder"}}}
+    }"#)
+}
+
+#[test]
+fn test_ensure_folders() {
+    let client = MockClient::new([
+        // /a already exists.
+        folder_conflict(),
+        Response::json(&format!(r#"{{".tag": "folder", {}"#, &folder_metadata("/a")[1..])),
+        // /a/b is created.
+        Response::json(&format!(r#"{{"metadata": {}}}"#, folder_metadata("/a/b"))),
+        // /a/b/c is a file.
+        folder_conflict(),
+        Response::json(&file_entry()),
+    ]);
+
+    match files_helpers::ensure_folders(&client, "/a/b/c/").now_or_never().unwrap() {
+        Err(dropbox_sdk::Error::Api(e)) => {
+            assert!(matches!(
+                e.downcast_ref::<files::CreateFolderError>(),
+                Some(files::CreateFolderError::Path(files::WriteError::Conflict(_)))),
+                "wrong error: {:?}", e);
+        }
+        other => panic!("wrong result: {:?}", other),
+    }
+
+    let requests = client.requests();
+    let paths = requests.iter()
+        .map(|r| {
+            let arg: serde_json::Value = serde_json::from_slice(&r.body).unwrap();
+            format!("{} {}", r.route(), arg["path"].as_str().unwrap())
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(vec![
+        "files/create_folder_v2 /a",
+        "files/get_metadata /a",
+        "files/create_folder_v2 /a/b",
+        "files/create_folder_v2 /a/b/c",
+        "files/get_metadata /a/b/c",
+    ], paths);
+
+    let client = MockClient::new([
+        Response::json(&format!(r#"{{"metadata": {}}}"#, folder_metadata("/a"))),
+        Response::json(&format!(r#"{{"metadata": {}}}"#, folder_metadata("/a/b"))),
+    ]);
+    let folders = files_helpers::ensure_folders(&client, "/a/b").now_or_never().unwrap().unwrap();
+    assert_eq!(vec!["/a", "/a/b"],
+        folders.iter().map(|f| f.path_display.as_deref().unwrap()).collect::<Vec<_>>());
+}
+
+#[test]
+fn test_longpoll() {
+    let client = MockClient::new([
+        Response::json(r#"{"changes": true}"#),
+        Response::json(r#"{"changes": false, "backoff": 60}"#),
+    ]).with_token("secret");
+
+    let result = files_helpers::longpoll(&client, "cursor", std::time::Duration::from_secs(10))
+        .now_or_never().unwrap().unwrap();
+    assert!(result.changes);
+    let result = files_helpers::longpoll(&client, "cursor", std::time::Duration::from_secs(3600))
+        .now_or_never().unwrap().unwrap();
+    assert_eq!(Some(60), result.backoff);
+
+    let requests = client.requests();
+    for (request, timeout) in requests.iter().zip([30, 480]) {
+        assert_eq!("https://notify.dropboxapi.com/2/files/list_folder/longpoll", request.url);
+        assert_eq!(None, request.header("Authorization"));
+        assert_eq!(Some(dropbox_sdk::client_trait_common::NOTIFY_TIMEOUT), request.timeout);
+        let arg: serde_json::Value = serde_json::from_slice(&request.body).unwrap();
+        // The default of 30 is left out.
+        assert_eq!(timeout, arg["timeout"].as_u64().unwrap_or(30));
+    }
+}
+
+#[test]
+fn test_forward_changes() {
+    let page = |names: &[&str], cursor: &str, has_more: bool| {
+        let entries = names.iter()
+            .map(|name| format!(r#"{{".tag": "deleted", "name": "{}"}}"#, name))
+            .collect::<Vec<_>>()
+            .join(",");
+        Response::json(&format!(r#"{{"entries": [{}], "cursor": "{}", "has_more": {}}}"#,
+            entries, cursor, has_more))
+    };
+    let client = MockClient::new([
+        Response::json(r#"{"changes": false, "backoff": 5}"#),
+        Response::json(r#"{"changes": true}"#),
+        page(&["a", "b"], "c1", true),
+        page(&["c"], "c2", false),
+        Response::json(r#"{"changes": true}"#),
+        Response::status(409, r#"{"error_summary": "reset/", "error": {".tag": "reset"}}"#),
+    ]);
+    let clock = std::sync::Arc::new(dropbox_sdk::clock::FakeClock::new());
+    let options = files_helpers::WatchOptions::default().with_clock(clock.clone());
+
+    let (tx, rx) = futures::channel::mpsc::channel(10);
+    let result = futures::executor::block_on(
+        files_helpers::forward_changes(&client, "c0", &options, tx));
+    match result {
+        Err(files_helpers::ForwardError::Watch(e)) => assert!(e.to_string().contains("Reset"), "{}", e),
+        other => panic!("unexpected result: {:?}", other),
+    }
+    assert_eq!(vec![std::time::Duration::from_secs(5)], clock.sleeps());
+
+    let changes = futures::executor::block_on(rx.collect::<Vec<_>>());
+    let changes = changes.iter()
+        .map(|change| match &change.metadata {
+            files::Metadata::Deleted(deleted) => (deleted.name.as_str(), change.cursor.as_str()),
+            other => panic!("unexpected metadata: {:?}", other),
+        })
+        .collect::<Vec<_>>();
+    // Only the last change of each page moves the cursor forward.
+    assert_eq!(vec![("a", "c0"), ("b", "c1"), ("c", "c2")], changes);
+
+    let requests = client.requests();
+    let urls = requests.iter().map(|r| r.route()).collect::<Vec<_>>();
+    assert_eq!(vec![
+        "files/list_folder/longpoll",
+        "files/list_folder/longpoll",
+        "files/list_folder/continue",
+        "files/list_folder/continue",
+        "files/list_folder/longpoll",
+        "files/list_folder/continue",
+    ], urls);
+    let cursors = requests.iter()
+        .map(|r| serde_json::from_slice::<serde_json::Value>(&r.body).unwrap()["cursor"].clone())
+        .collect::<Vec<_>>();
+    assert_eq!(vec!["c0", "c0", "c0", "c1", "c2", "c2"], cursors);
+    assert_eq!(None, requests[0].header("Authorization"));
+}
+
+#[test]
+fn test_media_info() {
+    let file = |media_info: &str| -> files::FileMetadata {
+        serde_json::from_str(&FILE_METADATA.replace('{', &format!(r#"{{"media_info": {},"#, media_info)))
+            .unwrap()
+    };
+
+    let photo = file(r#"{
+        ".tag": "metadata",
+        "metadata": {
+            ".tag": "photo",
+            "dimensions": {"height": 768, "width": 1024},
+            "location": {"latitude": 10.123456, "longitude": 5.123456},
+            "time_taken": "2016-09-04T17:00:27Z"
+        }
+    }"#);
+    assert_eq!(Some(&files::Dimensions::new(768, 1024)), photo.image_dimensions());
+    assert_eq!(Some(&files::GpsCoordinates::new(10.123456, 5.123456)), photo.gps());
+    assert_eq!(Some("2016-09-04T17:00:27Z"), photo.time_taken());
+
+    let video = file(r#"{
+        ".tag": "metadata",
+        "metadata": {".tag": "video", "dimensions": {"height": 1080, "width": 1920}, "duration": 5}
+    }"#);
+    assert_eq!(Some(&files::Dimensions::new(1080, 1920)), video.image_dimensions());
+    assert_eq!(None, video.gps());
+    assert_eq!(None, video.time_taken());
+
+    let pending = file(r#"{".tag": "pending"}"#);
+    assert_eq!(None, pending.media_metadata());
+    assert_eq!(None, pending.image_dimensions());
+}
+
+#[test]
+fn test_upload_file_sync() {
+    let mut local = std::env::temp_dir();
+    local.push(format!("dropbox-sdk-sync-test-{}", std::process::id()));
+    let chunk = files_helpers::UPLOAD_CHUNK_SIZE;
+    let data = (0 .. chunk * 2 + 10).map(|i| (i % 251) as u8).collect::<Vec<u8>>();
+    std::fs::write(&local, &data).unwrap();
+
+    let client = MockClient::new([
+        Response::json(r#"{"session_id": "sess"}"#),
+        Response::json("null"),
+        Response::json(FILE_METADATA),
+    ]);
+    files_helpers::upload_file_sync(
+        &client, &local, files::CommitInfo::new("/big".to_owned()), &UploadOptions::default(),
+    ).unwrap();
+    std::fs::remove_file(&local).unwrap();
+
+    let requests = client.requests();
+    let urls = requests.iter()
+        .map(|r| r.route())
+        .collect::<Vec<_>>();
+    assert_eq!(vec![
+        "files/upload_session/start",
+        "files/upload_session/append_v2",
+        "files/upload_session/finish",
+    ], urls);
+    assert_eq!(
+        vec![Some(chunk as u64), Some(chunk as u64), Some(10)],
+        requests.iter().map(|r| r.streamed).collect::<Vec<_>>());
+    assert_eq!(data, requests.iter().flat_map(|r| r.body.clone()).collect::<Vec<u8>>());
+    assert_eq!(chunk as u64 * 2, arg_json(&requests[2])["cursor"]["offset"]);
+    assert!(arg_json(&requests[2])["commit"].get("client_modified").is_some());
+}
+
+#[test]
+fn test_list_folder_reset() {
+    use futures::StreamExt;
+
+    let entry = |name: &str| format!(
+        r#"{{".tag": "folder", "name": "{}", "id": "id:{}"}}"#, name, name);
+    let page = |names: &[&str], cursor: &str, has_more: bool| Response::json(&format!(
+        r#"{{"entries": [{}], "cursor": "{}", "has_more": {}}}"#,
+        names.iter().map(|n| entry(n)).collect::<Vec<_>>().join(","), cursor, has_more));
+    let reset = || Response::status(409, r#"{"error_summary": "reset/", "error": {".tag": "reset"}}"#);
+
+    let client = MockClient::new([
+        page(&["a", "b"], "c1", true),
+        reset(),
+        page(&["a"], "c2", true),
+        page(&["b", "c"], "c3", false),
+        // With restarting disabled:
+        page(&["a"], "c1", true),
+        reset(),
+    ]);
+    let arg = files::ListFolderArg::new("/x".to_owned());
+
+    let entries = futures::executor::block_on(
+        files_helpers::list_folder(&client, arg.clone(), &files_helpers::ListFolderOptions::default())
+            .map(|entry| {
+                let entry = entry.unwrap();
+                match entry.metadata {
+                    files::Metadata::Folder(f) => (f.name, entry.resets),
+                    other => panic!("unexpected entry {:?}", other),
+                }
+            })
+            .collect::<Vec<_>>());
+    assert_eq!(
+        vec![("a", 0), ("b", 0), ("a", 1), ("b", 1), ("c", 1)],
+        entries.iter().map(|(n, r)| (n.as_str(), *r)).collect::<Vec<_>>());
+
+    let options = files_helpers::ListFolderOptions::default().with_restart_on_reset(false);
+    let results = futures::executor::block_on(
+        files_helpers::list_folder(&client, arg, &options).collect::<Vec<_>>());
+    assert_eq!(2, results.len());
+    assert!(results[0].is_ok());
+    match &results[1] {
+        Err(dropbox_sdk::Error::Api(e)) => {
+            assert!(matches!(
+                e.downcast_ref::<files::ListFolderContinueError>(),
+                Some(files::ListFolderContinueError::Reset)),
+                "wrong error: {:?}", e);
+        }
+        other => panic!("wrong result: {:?}", other),
+    }
+
+    let urls = client.requests().iter()
+        .map(|r| r.route().to_owned())
+        .collect::<Vec<_>>();
+    assert_eq!(vec![
+        "files/list_folder",
+        "files/list_folder/continue",
+        "files/list_folder",
+        "files/list_folder/continue",
+        "files/list_folder",
+        "files/list_folder/continue",
+    ], urls);
+
+    // A cursor which keeps being reset only restarts the listing so many times.
+    let client = MockClient::new((0..=files_helpers::LIST_FOLDER_MAX_RESTARTS)
+        .flat_map(|_| [page(&["a"], "c1", true), reset()]));
+    let results = futures::executor::block_on(files_helpers::list_folder(
+        &client, files::ListFolderArg::new("/x".to_owned()),
+        &files_helpers::ListFolderOptions::default()).collect::<Vec<_>>());
+    assert_eq!(files_helpers::LIST_FOLDER_MAX_RESTARTS as usize + 2, results.len());
+    assert!(results.last().unwrap().is_err());
+    assert_eq!(files_helpers::LIST_FOLDER_MAX_RESTARTS as usize * 2 + 2, client.requests().len());
+}
+
+#[test]
+fn test_list_folder_page_size() {
+    let page = || Response::json(r#"{"entries": [], "cursor": "c1", "has_more": false}"#);
+    let server_error = || Response::status(500, "oops");
+    let client = MockClient::new([
+        page(),
+        page(),
+        server_error(),
+        Response::io_error(std::io::ErrorKind::TimedOut),
+        page(),
+        // Errors which aren't from the page being too big:
+        Response::io_error(std::io::ErrorKind::ConnectionRefused),
+        // With adaptive page sizes disabled:
+        server_error(),
+    ]);
+    let list = |arg: files::ListFolderArg, options: files_helpers::ListFolderOptions| {
+        futures::executor::block_on(
+            files_helpers::list_folder(&client, arg, &options).collect::<Vec<_>>())
+    };
+    let arg = files::ListFolderArg::new("/x".to_owned());
+    let options = files_helpers::ListFolderOptions::default();
+
+    // The default is the most the route allows.
+    assert!(list(arg.clone(), options.clone()).is_empty());
+    // A limit in the argument is used, but clamped.
+    assert!(list(arg.clone().with_limit(5000), options.clone()).is_empty());
+    // Server errors and timeouts on the first page make the pages smaller.
+    assert!(list(arg.clone(), options.clone().with_page_size(300)).is_empty());
+    let results = list(arg.clone(), options.clone().with_page_size(300));
+    assert!(matches!(results[..], [Err(_)]), "{:?}", results);
+    let results = list(arg, options.with_page_size(300).with_adaptive_page_size(false));
+    assert!(matches!(results[..], [Err(_)]), "{:?}", results);
+
+    let limits = client.requests().iter()
+        .map(|r| serde_json::from_slice::<serde_json::Value>(&r.body).unwrap()["limit"].clone())
+        .collect::<Vec<_>>();
+    assert_eq!(vec![2000, 2000, 300, 150, 100, 300, 300], limits);
+    assert_eq!(Some(2000), dropbox_sdk::pagination::default_page_size("files/list_folder"));
+    assert_eq!(None, dropbox_sdk::pagination::default_page_size("files/get_metadata"));
+}
+
+#[test]
+fn test_list_folder_filter() {
+    use files_helpers::{EntryFilter, EntryKind, ListFolderOptions};
+
+    let file = |name: &str, size: u64| FILE_METADATA
+        .replace('{', r#"{".tag": "file", "#)
+        .replace("a.txt", name)
+        .replace(r#""size": 5"#, &format!(r#""size": {}"#, size));
+    let page = format!(
+        r#"{{"entries": [{}, {}, {}, {}], "cursor": "c1", "has_more": false}}"#,
+        file("a.JPG", 5),
+        file("b.txt", 10),
+        r#"{".tag": "folder", "name": "c.jpg", "id": "id:c"}"#,
+        r#"{".tag": "deleted", "name": "d.jpg"}"#);
+    let client = MockClient::new(std::iter::repeat_with(|| Response::json(&page)).take(6));
+
+    let names = |filter: EntryFilter| -> Vec<String> {
+        let options = ListFolderOptions::default().with_filter(filter);
+        let arg = files::ListFolderArg::new("/x".to_owned());
+        futures::executor::block_on(files_helpers::list_folder(&client, arg, &options)
+            .map(|entry| match entry.unwrap().metadata {
+                files::Metadata::File(f) => f.name,
+                files::Metadata::Folder(f) => f.name,
+                files::Metadata::Deleted(d) => d.name,
+            })
+            .collect())
+    };
+    assert_eq!(vec!["a.JPG", "b.txt"], names(EntryFilter::default().with_kind(EntryKind::File)));
+    assert_eq!(vec!["c.jpg"], names(EntryFilter::default().with_kind(EntryKind::Folder)));
+    assert_eq!(vec!["d.jpg"], names(EntryFilter::default().with_kind(EntryKind::Deleted)));
+    assert_eq!(vec!["a.JPG", "d.jpg"], names(EntryFilter::default().with_extensions(vec!["jpg".to_owned()])));
+    assert_eq!(vec!["b.txt"], names(EntryFilter::default().with_file_predicate(|f| f.size > 5)));
+
+    let options = ListFolderOptions::default()
+        .with_filter(EntryFilter::default().with_extensions(vec!["jpg".to_owned()]));
+    let raw = futures::executor::block_on(
+        files_helpers::list_folder_raw(&client, files::ListFolderArg::new("/x".to_owned()), &options)
+            .map(|entry| entry.unwrap().metadata.name().to_owned())
+            .collect::<Vec<_>>());
+    assert_eq!(vec!["a.JPG", "d.jpg"], raw);
+
+    // Filters which the server can apply are passed on.
+    let args = client.requests().iter()
+        .map(|r| serde_json::from_slice::<serde_json::Value>(&r.body).unwrap())
+        .collect::<Vec<_>>();
+    assert_eq!(Some(false), args[1]["include_non_downloadable_files"].as_bool());
+    assert_eq!(Some(true), args[2]["include_deleted"].as_bool());
+    assert_eq!(None, args[0].get("include_deleted"));
+}
+
+#[test]
+fn test_list_folder_stream_entries() {
+    use futures::StreamExt;
+
+    let entry = |name: &str| format!(
+        r#"{{".tag": "folder", "name": "{}", "id": "id:{}"}}"#, name, name);
+    let reset = || Response::status(409, r#"{"error_summary": "reset/", "error": {".tag": "reset"}}"#);
+
+    let client = MockClient::new([
+        Response::json(&format!(r#"{{"entries": [{}, {}], "cursor": "c1", "has_more": true}}"#,
+            entry("a"), entry("b"))),
+        reset(),
+        // The fields can come in any order, and unknown ones are skipped.
+        Response::json(&format!(
+            r#" {{ "cursor" : "c2", "extra": [1, {{"x": 2}}], "has_more": true,
+                "entries" : [ {} ] , "count": 12 }} "#,
+            entry("a"))),
+        Response::json(r#"{"entries": [], "cursor": "c3", "has_more": true}"#),
+        Response::json(&format!(r#"{{"entries": [{}], "cursor": "c4", "has_more": false"#,
+            entry("c"))),
+    ]);
+    let arg = files::ListFolderArg::new("/x".to_owned());
+    let options = files_helpers::ListFolderOptions::default().with_stream_entries(true);
+    let results = futures::executor::block_on(
+        files_helpers::list_folder(&client, arg, &options).collect::<Vec<_>>());
+
+    let names = results.iter()
+        .filter_map(|result| match result {
+            Ok(entry) => match &entry.metadata {
+                files::Metadata::Folder(f) => Some((f.name.as_str(), entry.resets)),
+                other => panic!("unexpected entry {:?}", other),
+            },
+            Err(_) => None,
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(vec![("a", 0), ("b", 0), ("a", 1), ("c", 1)], names);
+    // The last page is cut off, which is only noticed after its entry is returned.
+    assert_eq!(5, results.len());
+    assert!(matches!(results[4], Err(dropbox_sdk::Error::UnexpectedResponse(_))),
+        "wrong result: {:?}", results[4]);
+
+    let cursors = client.requests().iter()
+        .skip(1)
+        .map(|r| serde_json::from_slice::<serde_json::Value>(&r.body).unwrap()["cursor"].clone())
+        .collect::<Vec<_>>();
+    assert_eq!(vec![
+        serde_json::json!("c1"),
+        serde_json::Value::Null,
+        serde_json::json!("c2"),
+        serde_json::json!("c3"),
+    ], cursors);
+}
+
+#[test]
+fn test_folder_stats() {
+    let file = |path: &str, size: u64| format!(
+        r#"{{".tag": "file", "name": "f", "id": "id:{path}", "path_lower": "{path}",
+            "client_modified": "2020-01-01T00:00:00Z", "server_modified": "2020-01-01T00:00:00Z",
+            "rev": "0123456789abcdef", "size": {size}}}"#);
+    let folder = |path: &str| format!(
+        r#"{{".tag": "folder", "name": "d", "id": "id:{path}", "path_lower": "{path}"}}"#);
+    let page = |entries: &[String], cursor: &str, has_more: bool| Response::json(&format!(
+        r#"{{"entries": [{}], "cursor": "{}", "has_more": {}}}"#,
+        entries.join(","), cursor, has_more));
+    let reset = || Response::status(409, r#"{"error_summary": "reset/", "error": {".tag": "reset"}}"#);
+
+    let client = MockClient::new([
+        page(&[file("/x/a", 100)], "c1", true),
+        reset(),
+        page(&[file("/x/a", 100), folder("/x/y")], "c2", true),
+        page(&[file("/x/b", 20), folder("/x/z")], "c3", false),
+        // The subfolders are listed at the same time, so either can get either response.
+        page(&[file("/x/?/c", 3)], "c4", false),
+        page(&[file("/x/?/d", 4)], "c5", false),
+    ]);
+    let stats = files_helpers::folder_stats(&client, "/X", &FolderStatsOptions::default())
+        .now_or_never().unwrap().unwrap();
+    assert_eq!((127, 4, 2), (stats.total_bytes, stats.files, stats.folders));
+    let requests = client.requests();
+    let arg: serde_json::Value = serde_json::from_slice(&requests[0].body).unwrap();
+    assert_eq!("/X", arg["path"]);
+    assert!(arg.get("recursive").is_none());
+    let mut listed = requests[4..].iter()
+        .map(|r| serde_json::from_slice::<serde_json::Value>(&r.body).unwrap()["path"].clone())
+        .collect::<Vec<_>>();
+    listed.sort_by_key(|path| path.to_string());
+    assert_eq!(vec![serde_json::json!("id:/x/y"), serde_json::json!("id:/x/z")], listed);
+}
+
+#[test]
+fn test_find_duplicates() {
+    let file = |path: &str, hash: &str, size: u64| format!(
+        r#"{{".tag": "file", "name": "f", "id": "id:{path}", "path_lower": "{path}",
+            "client_modified": "2020-01-01T00:00:00Z", "server_modified": "2020-01-01T00:00:00Z",
+            "rev": "0123456789abcdef", "size": {size}, "content_hash": "{hash}"}}"#);
+    let page = |entries: &[String], cursor: &str, has_more: bool| Response::json(&format!(
+        r#"{{"entries": [{}], "cursor": "{}", "has_more": {}}}"#,
+        entries.join(","), cursor, has_more));
+
+    let client = MockClient::new([
+        page(&[file("/x/a", "h1", 10), file("/x/b", "h2", 20), file("/x/e1", "e", 0)], "c1", true),
+        page(&[
+            file("/x/y/a", "h1", 10),
+            file("/x/y/b", "h2", 20),
+            file("/x/y/c", "h1", 11),
+            file("/x/y/d", "h1", 10),
+            file("/x/y/e2", "e", 0),
+        ], "c2", false),
+    ]);
+    let options = files_helpers::ListFolderOptions::default();
+    let groups = futures::executor::block_on(
+        files_helpers::find_duplicates(&client, "/x", &options)
+            .map(|group| group.unwrap())
+            .collect::<Vec<_>>());
+    let paths = groups.iter()
+        .map(|group| group.files.iter().map(|f| f.path_lower.as_deref().unwrap()).collect())
+        .collect::<Vec<Vec<_>>>();
+    assert_eq!(vec![vec!["/x/a", "/x/y/a", "/x/y/d"], vec!["/x/b", "/x/y/b"]], paths);
+    assert_eq!(("h1", 10), (groups[0].content_hash.as_str(), groups[0].size));
+    assert_eq!(20, groups[0].redundant_bytes());
+    assert_eq!(20, groups[1].redundant_bytes());
+    let arg: serde_json::Value = serde_json::from_slice(&client.requests()[0].body).unwrap();
+    assert_eq!(true, arg["recursive"]);
+}
+
+#[test]
+fn test_list_folder_raw() {
+    use futures::StreamExt;
+
+    let client = MockClient::new([Response::json(&format!(
+        r#"{{"entries": [{}, {{".tag": "folder", "name": "b", "id": "id:b", "path_lower": "/x/b"}}],
+            "cursor": "c1", "has_more": false}}"#,
+        file_entry()))]);
+    let arg = files::ListFolderArg::new("/x".to_owned());
+    let entries = futures::executor::block_on(
+        files_helpers::list_folder_raw(&client, arg, &files_helpers::ListFolderOptions::default())
+            .map(|entry| entry.unwrap().metadata)
+            .collect::<Vec<_>>());
+    assert_eq!(2, entries.len());
+
+    assert_eq!("a.txt", entries[0].name());
+    assert!(entries[0].is_file());
+    match entries[0].parse().unwrap() {
+        files::Metadata::File(f) => {
+            assert_eq!(serde_json::from_str::<files::FileMetadata>(FILE_METADATA).unwrap(), f);
+        }
+        other => panic!("unexpected entry {:?}", other),
+    }
+
+    assert_eq!("b", entries[1].name());
+    assert_eq!(Some("/x/b"), entries[1].path_lower());
+    assert_eq!(None, entries[1].path_display());
+    assert!(entries[1].is_folder());
+    assert!(matches!(entries[1].parse().unwrap(), files::Metadata::Folder(_)));
+}
+
+#[test]
+fn test_upload_file_if_changed() {
+    let mut local = std::env::temp_dir();
+    local.push(format!("dropbox-sdk-test-if-changed-{}", std::process::id()));
+    std::fs::File::create(&local).unwrap().write_all(b"hello").unwrap();
+
+    let remote = |hash: &str| {
+        let mut json: serde_json::Value = serde_json::from_str(FILE_METADATA).unwrap();
+        json[".tag"] = "file".into();
+        json["content_hash"] = hash.into();
+        json.to_string()
+    };
+    let client = MockClient::new([
+        Response::json(&remote(&dropbox_sdk::content_hash::content_hash(b"hello"))),
+        Response::json(&remote(&dropbox_sdk::content_hash::content_hash(b"different"))),
+        Response::json(FILE_METADATA),
+    ]);
+    let commit = files::CommitInfo::new("/a.txt".to_owned())
+        .with_mode(files::WriteMode::Overwrite);
+
+    let outcome = files_helpers::upload_file_if_changed(
+        &client, &local, commit.clone(), &UploadOptions::default())
+        .now_or_never().unwrap().unwrap();
+    assert!(matches!(outcome, files_helpers::UploadOutcome::AlreadyUpToDate(_)),
+        "wrong outcome: {:?}", outcome);
+
+    let outcome = files_helpers::upload_file_if_changed(
+        &client, &local, commit, &UploadOptions::default())
+        .now_or_never().unwrap().unwrap();
+    assert!(matches!(outcome, files_helpers::UploadOutcome::Uploaded(_)),
+        "wrong outcome: {:?}", outcome);
+    std::fs::remove_file(&local).unwrap();
+
+    let requests = client.requests();
+    assert_eq!(3, requests.len());
+    assert!(requests[1].url.ends_with("/files/get_metadata"));
+    assert!(requests[2].url.ends_with("/files/upload"));
+    assert_eq!(b"hello", requests[2].body.as_slice());
+}
+
+#[test]
+fn test_upload_with_backup() {
+    let new_file = FILE_METADATA
+        .replace("0123456789abcdef", "fedcba9876543210")
+        .replacen('{', r#"{"path_lower": "/a.txt", "#, 1);
+    let client = MockClient::new([
+        Response::json(&file_entry()),
+        Response::json(&new_file),
+        Response::status(409, r#"{
+            "error_summary": "path/not_found/",
+            "error": {".tag": "path", "path": {".tag": "not_found"}}
+        }"#),
+        Response::json(&new_file),
+    ]);
+
+    let upload = files_helpers::upload_with_backup(
+        &client, "/a.txt", b"hello", &UploadOptions::default())
+        .now_or_never().unwrap().unwrap();
+    assert_eq!(Some("0123456789abcdef"), upload.previous_rev.as_deref());
+    assert_eq!("fedcba9876543210", upload.metadata.rev);
+    let restore = upload.restore_arg().unwrap();
+    assert_eq!("/a.txt", restore.path);
+    assert_eq!("0123456789abcdef", restore.rev);
+
+    // Nothing was there before, so there's nothing to restore.
+    let upload = files_helpers::upload_with_backup(
+        &client, "/a.txt", b"hello", &UploadOptions::default())
+        .now_or_never().unwrap().unwrap();
+    assert_eq!(None, upload.previous_rev);
+    assert_eq!(None, upload.restore_arg());
+
+    let requests = client.requests();
+    assert!(requests[0].url.ends_with("/files/get_metadata"));
+    assert_eq!(
+        serde_json::json!({".tag": "update", "update": "0123456789abcdef"}),
+        arg_json(&requests[1])["mode"]);
+    assert_eq!(b"hello", requests[1].body.as_slice());
+    // The default mode, "add", is left out.
+    assert_eq!(serde_json::Value::Null, arg_json(&requests[3])["mode"]);
+}
+
+#[test]
+fn test_list_and_restore_deleted() {
+    let listing = || Response::json(r#"{"entries": [
+        {".tag": "deleted", "name": "a.txt", "path_display": "/Old/a.txt"},
+        {".tag": "deleted", "name": "Old", "path_display": "/Old"},
+        {".tag": "deleted", "name": "b.txt", "path_display": "/b.txt"},
+        {".tag": "folder", "name": "c", "id": "id:c", "path_display": "/c"}
+    ], "cursor": "c1", "has_more": false}"#);
+    let revisions = |rev: &str, deleted: &str| Response::json(&format!(
+        r#"{{"is_deleted": true, "server_deleted": "{}", "entries": [{}]}}"#,
+        deleted, FILE_METADATA.replace("0123456789abcdef", rev)));
+    let not_file = || Response::status(409,
+        r#"{"error_summary": "path/not_file/", "error": {".tag": "path", "path": {".tag": "not_file"}}}"#);
+    let client = MockClient::new([
+        listing(),
+        revisions("bbbbbbbbb", "2024-01-02T00:00:00Z"),
+        not_file(),
+        revisions("aaaaaaaaa", "2023-06-01T00:00:00Z"),
+        // Restoring:
+        Response::json(&FILE_METADATA.replace("0123456789abcdef", "bbbbbbbbb")),
+        Response::status(409, r#"{"error_summary": "invalid_revision/", "error": {".tag": "invalid_revision"}}"#),
+        // Listing again with a time window:
+        listing(),
+        revisions("bbbbbbbbb", "2024-01-02T00:00:00Z"),
+        not_file(),
+        revisions("aaaaaaaaa", "2023-06-01T00:00:00Z"),
+    ]);
+
+    let options = files_helpers::ListDeletedOptions::default().with_concurrency(1);
+    let deleted = files_helpers::list_deleted(&client, "", &options).now_or_never().unwrap().unwrap();
+    let summary = deleted.iter()
+        .map(|file| (file.path.as_str(), file.last_revision.rev.as_str()))
+        .collect::<Vec<_>>();
+    assert_eq!(vec![("/b.txt", "bbbbbbbbb"), ("/Old/a.txt", "aaaaaaaaa")], summary);
+    assert_eq!(Some(std::time::UNIX_EPOCH + std::time::Duration::from_secs(1704153600)),
+        deleted[0].deleted_at);
+
+    let mut progress = vec![];
+    let report = files_helpers::restore_deleted(&client, &deleted, 1, |done, total| {
+        progress.push((done, total));
+    }).now_or_never().unwrap();
+    assert_eq!(vec![(1, 2), (2, 2)], progress);
+    assert_eq!(1, report.restored.len());
+    assert_eq!("/Old/a.txt", report.failed[0].0);
+
+    let after = dropbox_sdk::timestamp::to_system_time("2024-01-01T00:00:00Z").unwrap();
+    let options = options.with_deleted_after(after);
+    let deleted = files_helpers::list_deleted(&client, "", &options).now_or_never().unwrap().unwrap();
+    assert_eq!(1, deleted.len());
+    assert_eq!("/b.txt", deleted[0].path);
+
+    let requests = client.requests();
+    assert_eq!(Some(true), serde_json::from_slice::<serde_json::Value>(&requests[0].body)
+        .unwrap()["include_deleted"].as_bool());
+    let restore: serde_json::Value = serde_json::from_slice(&requests[4].body).unwrap();
+    assert_eq!(serde_json::json!({"path": "/b.txt", "rev": "bbbbbbbbb"}), restore);
+}
+
+#[test]
+fn test_move_path_locked() {
+    let mut file: serde_json::Value = serde_json::from_str(FILE_METADATA).unwrap();
+    file[".tag"] = "file".into();
+    file["file_lock_info"] = serde_json::json!({
+        "is_lockholder": false,
+        "lockholder_name": "Alice",
+        "lockholder_account_id": "dbid:alice",
+        "created": "2020-01-01T00:00:00Z",
+    });
+    let locks = serde_json::json!({"entries": [{
+        ".tag": "success",
+        "metadata": file,
+        "lock": {"content": {
+            ".tag": "single_user",
+            "created": "2020-01-01T00:00:00Z",
+            "lock_holder_account_id": "dbid:alice",
+        }},
+    }]});
+    let client = MockClient::new([
+        Response::status(409, r#"{
+            "error_summary": "from_lookup/locked/",
+            "error": {".tag": "from_lookup", "from_lookup": {".tag": "locked"}}
+        }"#),
+        Response::json(&locks.to_string()),
+    ]);
+
+    let arg = files::RelocationArg::new("/a.txt".to_owned(), "/b.txt".to_owned());
+    let err = files_helpers::move_path(&client, &arg).now_or_never().unwrap().unwrap_err();
+    match err {
+        files_helpers::TransferError::Locked { path, lock_holder: Some(holder) } => {
+            assert_eq!("/a.txt", path);
+            assert_eq!("dbid:alice", holder.account_id);
+            assert_eq!(Some("Alice"), holder.name.as_deref());
+            assert_eq!(None, holder.team_id);
+        }
+        other => panic!("wrong error: {:?}", other),
+    }
+
+    let requests = client.requests();
+    assert!(requests[1].url.ends_with("/files/get_file_lock_batch"));
+    let body: serde_json::Value = serde_json::from_slice(&requests[1].body).unwrap();
+    assert_eq!("/a.txt", body["entries"][0]["path"]);
+}
+
+#[test]
+fn test_get_preview() {
+    let client = MockClient::new([
+        Response::download(&FILE_METADATA.replace("a.txt", "Report.DOCX"), b"%PDF"),
+        Response::download(&FILE_METADATA.replace("a.txt", "b.xlsx"), b"<html>"),
+    ]);
+
+    let mut preview = files_helpers::get_preview(&client, "/Report.DOCX")
+        .now_or_never().unwrap().unwrap();
+    assert_eq!(Some(files_helpers::PreviewFormat::Pdf), preview.format);
+    assert_eq!("application/pdf", preview.format.unwrap().content_type());
+    let mut body = vec![];
+    futures::AsyncReadExt::read_to_end(&mut preview.body, &mut body).now_or_never().unwrap().unwrap();
+    assert_eq!(b"%PDF".to_vec(), body);
+
+    // Without a file name, the format comes from the metadata.
+    let preview = files_helpers::get_preview(&client, "id:abc").now_or_never().unwrap().unwrap();
+    assert_eq!(Some(files_helpers::PreviewFormat::Html), preview.format);
+
+    // Unsupported extensions fail without making a request.
+    match files_helpers::get_preview(&client, "/photo.jpg").now_or_never().unwrap() {
+        Err(dropbox_sdk::Error::Api(files::PreviewError::UnsupportedExtension)) => (),
+        other => panic!("wrong result: {:?}", other.map(|p| p.metadata)),
+    }
+    assert_eq!(2, client.requests().len());
+}
+
+#[test]
+fn test_get_thumbnail() {
+    let client = MockClient::new([
+        Response::download(&format!(r#"{{"file_metadata": {}}}"#, FILE_METADATA), b"\x89PNG"),
+    ]);
+    let options = files_helpers::ThumbnailOptions::default()
+        .with_format(files::ThumbnailFormat::Png)
+        .with_size(files::ThumbnailSize::W256h256);
+    let thumbnail = files_helpers::get_thumbnail(&client, "/a.png", &options)
+        .now_or_never().unwrap().unwrap()
+        .read_all().now_or_never().unwrap().unwrap();
+    assert_eq!("image/png", thumbnail.format.content_type());
+    assert_eq!(b"\x89PNG".to_vec(), thumbnail.data);
+    assert_eq!("id:abc", thumbnail.metadata.id);
+
+    let arg = arg_json(&client.requests()[0]);
+    assert_eq!("path", arg["resource"][".tag"]);
+    assert_eq!("/a.png", arg["resource"]["path"]);
+    assert_eq!("png", arg["format"][".tag"]);
+    assert_eq!("w256h256", arg["size"][".tag"]);
+}
+
+#[test]
+fn test_get_thumbnails() {
+    let client = MockClient::new([
+        Response::json(&format!(r#"{{"entries": [
+            {{".tag": "success", "metadata": {}, "thumbnail": "aGk="}},
+            {{".tag": "failure", "failure": {{".tag": "unsupported_extension"}}}}
+        ]}}"#, FILE_METADATA)),
+        // The next batch is rejected, so its files are fetched one at a time.
+        Response::status(409, r#"{
+            "error_summary": "too_many_files/",
+            "error": {".tag": "too_many_files"}
+        }"#),
+        Response::download(&format!(r#"{{"file_metadata": {}}}"#, FILE_METADATA), b"jpeg"),
+    ]);
+    let mut paths = vec!["/a.jpg".to_owned(), "/b.txt".to_owned()];
+    let batch = files_helpers::get_thumbnails(&client, paths.clone(), &Default::default())
+        .now_or_never().unwrap().unwrap();
+    assert_eq!(2, batch.len());
+    assert_eq!(b"hi".to_vec(), batch[0].as_ref().unwrap().data);
+    assert!(matches!(batch[1],
+        Err(dropbox_sdk::Error::Api(files::ThumbnailV2Error::UnsupportedExtension))));
+    let arg: serde_json::Value = serde_json::from_slice(&client.requests()[0].body).unwrap();
+    assert_eq!("/b.txt", arg["entries"][1]["path"]);
+
+    paths.truncate(1);
+    let fallback = files_helpers::get_thumbnails(&client, paths, &Default::default())
+        .now_or_never().unwrap().unwrap();
+    assert_eq!(b"jpeg".to_vec(), fallback[0].as_ref().unwrap().data);
+    let requests = client.requests();
+    assert_eq!(3, requests.len());
+    assert!(requests[2].url.ends_with("/files/get_thumbnail_v2"));
+}
+
+#[test]
+fn test_upload_to_temporary_link() {
+    let link = files::GetTemporaryUploadLinkResult::new(
+        "https://content.dropboxapi.com/apitul/1/abc".to_owned());
+    let client = MockClient::new([
+        Response::status(500, "oops"),
+        Response::json(""),
+        Response::status(409, "link expired"),
+    ]);
+    let clock = std::sync::Arc::new(dropbox_sdk::clock::FakeClock::new());
+    let options = UploadOptions::default()
+        .with_retry_budget(dropbox_sdk::retry::RetryBudget::new(1).with_clock(clock));
+    let upload = |data: &'static [u8]| files_helpers::upload_to_temporary_link(
+        &client, &link, futures::io::Cursor::new(data), &options);
+
+    futures::executor::block_on(upload(b"hello")).unwrap();
+    let requests = client.requests();
+    assert_eq!(2, requests.len());
+    assert_eq!(link.link, requests[1].url);
+    assert_eq!(b"hello", &requests[1].body[..]);
+    assert_eq!(None, requests[1].header("Authorization"));
+
+    match futures::executor::block_on(upload(b"again")) {
+        Err(files_helpers::TransferError::Dropbox(dropbox_sdk::Error::UnexpectedHttpError {
+            code: 409, ..
+        })) => (),
+        other => panic!("wrong result: {:?}", other),
+    }
+}
+
+#[test]
+fn test_integrity_report() {
+    use std::time::Duration;
+
+    let data = b"hello";
+    let hash = dropbox_sdk::content_hash::content_hash(data);
+    let with_hash = |hash: &str| FILE_METADATA.replacen('{',
+        &format!(r#"{{"content_hash": "{hash}","#), 1);
+    let client = MockClient::new([
+        Response::status(503, "maintenance").with_retry_after("3"),
+        Response::json(&with_hash(&hash)),
+        Response::download(&with_hash(&hash), data),
+        Response::download(&with_hash(&hash), b"jello"),
+        Response::download(FILE_METADATA, data),
+    ]);
+    let clock = std::sync::Arc::new(dropbox_sdk::clock::FakeClock::new());
+    let budget = dropbox_sdk::retry::RetryBudget::new(1).with_clock(clock);
+    let options = UploadOptions::default().with_retry_budget(budget.clone());
+
+    let (metadata, report) = futures::executor::block_on(files_helpers::upload_with_report(
+        &client, files::CommitInfo::new("/a.txt".to_owned()), &data[..], &options)).unwrap();
+    assert_eq!(Some(hash.as_str()), metadata.content_hash.as_deref());
+    assert_eq!((5, Duration::from_secs(3), 1, true),
+        (report.bytes, report.duration, report.retries, report.content_hash_verified));
+    assert_eq!(Some(5. / 3.), report.average_rate());
+
+    let arg = files::DownloadArg::new("/a.txt".to_owned());
+    let mut dest = vec![];
+    let (_, report) = futures::executor::block_on(files_helpers::download_with_report(
+        &client, &arg, &mut dest, &budget)).unwrap();
+    assert_eq!(data, &dest[..]);
+    assert_eq!((5, 0, true), (report.bytes, report.retries, report.content_hash_verified));
+    assert_eq!(None, report.average_rate());
+
+    match futures::executor::block_on(files_helpers::download_with_report(
+        &client, &arg, &mut vec![], &budget))
+    {
+        Err(files_helpers::TransferError::ContentHashMismatch { remote, .. }) => {
+            assert_eq!(hash, remote);
+        }
+        other => panic!("wrong result: {:?}", other),
+    }
+
+    // Without a content hash from Dropbox, the download succeeds without being verified.
+    let (_, report) = futures::executor::block_on(files_helpers::download_with_report(
+        &client, &arg, &mut vec![], &budget)).unwrap();
+    assert!(!report.content_hash_verified);
+}
+
+#[test]
+fn test_download_resumes_after_stall() {
+    let client = MockClient::new([
+        Response::download(FILE_METADATA, b"hel").with_stall(),
+        Response::download(FILE_METADATA, b"lo").with_content_range("bytes 3-4/5"),
+        Response::download(FILE_METADATA, b"he").with_stall(),
+    ]);
+    let arg = files::DownloadArg::new("/a.txt".to_owned());
+    let budget = dropbox_sdk::retry::RetryBudget::new(1);
+
+    // The download is resumed from where it stopped, from the same revision of the file.
+    let mut dest = vec![];
+    let (_, report) = futures::executor::block_on(files_helpers::download_with_report(
+        &client, &arg, &mut dest, &budget)).unwrap();
+    assert_eq!(b"hello", &dest[..]);
+    assert_eq!((5, 1), (report.bytes, report.retries));
+    let requests = client.requests();
+    assert_eq!(None, requests[0].header("Range"));
+    assert_eq!(Some("bytes=3-"), requests[1].header("Range"));
+    assert_eq!("rev:0123456789abcdef", arg_json(&requests[1])["path"]);
+
+    // Once the budget is used up, the stall is returned.
+    let budget = dropbox_sdk::retry::RetryBudget::new(0);
+    let result = futures::executor::block_on(files_helpers::download_with_report(
+        &client, &arg, &mut vec![], &budget));
+    assert!(matches!(result, Err(files_helpers::TransferError::Stalled(_))), "{:?}", result);
+}
+
+#[test]
+fn test_search_filenames() {
+    use futures::StreamExt;
+
+    let file_match = |name: &str| format!(
+        r#"{{"metadata": {{".tag": "metadata", "metadata": {}}}}}"#,
+        FILE_METADATA.replacen('{', &format!(r#"{{".tag": "file", "path_display": "/x/{name}","#), 1));
+    let client = MockClient::new([
+        Response::json(&format!(
+            r#"{{"matches": [{}, {{"metadata": {{".tag": "future"}}}}], "has_more": true,
+                "cursor": "c1"}}"#,
+            file_match("a.txt"))),
+        Response::json(&format!(r#"{{"matches": [{}], "has_more": false}}"#, file_match("b.txt"))),
+        Response::status(409, r#"{"error_summary": "path/not_found/", "error": {".tag": "path",
+            "path": {".tag": "not_found"}}}"#),
+    ]);
+
+    let results = futures::executor::block_on(
+        files_helpers::search_filenames(&client, "txt", Some("/x")).collect::<Vec<_>>());
+    let paths = results.into_iter()
+        .map(|result| match result.unwrap() {
+            files::Metadata::File(f) => f.path_display.unwrap(),
+            other => panic!("unexpected entry {:?}", other),
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(vec!["/x/a.txt", "/x/b.txt"], paths);
+
+    let options = files_helpers::SearchFilesOptions::default()
+        .with_match_contents(true)
+        .with_extensions(vec!["pdf".to_owned()]);
+    let results = futures::executor::block_on(
+        files_helpers::search_files(&client, "report", None, &options).collect::<Vec<_>>());
+    assert_eq!(1, results.len());
+    assert!(matches!(results[0], Err(dropbox_sdk::Error::Api(_))), "wrong result: {:?}", results[0]);
+
+    let requests = client.requests();
+    let args = requests.iter()
+        .map(|r| serde_json::from_slice::<serde_json::Value>(&r.body).unwrap())
+        .collect::<Vec<_>>();
+    assert_eq!(serde_json::json!({
+        "query": "txt",
+        "options": {
+            "path": "/x",
+            "max_results": 1000,
+            "filename_only": true,
+        },
+    }), args[0]);
+    assert!(requests[1].url.ends_with("/2/files/search/continue_v2"));
+    assert_eq!(serde_json::json!({"cursor": "c1"}), args[1]);
+    // false is the default, so it isn't sent.
+    assert_eq!(None, args[2]["options"].get("filename_only"));
+    assert_eq!(serde_json::json!(["pdf"]), args[2]["options"]["file_extensions"]);
+    assert_eq!(None, args[2]["options"].get("path"));
+}
@@ -0,0 +1,124 @@
+#![warn(rust_2018_idioms)]
+
+use std::future::Future;
+use std::sync::Mutex;
+use std::time::Duration;
+use bytes::Bytes;
+use futures::FutureExt;
+use dropbox_sdk::async_client_trait::{HttpClient, HttpRequestResultRaw, NoauthClient};
+use dropbox_sdk::async_routes::files;
+use dropbox_sdk::http_compat;
+use dropbox_sdk::oauth2::Authorization;
+use dropbox_sdk::Error;
+
+/// A client for an HTTP stack which works with `http` crate types; here, one which answers every
+/// request with the same response.
+struct ServiceClient {
+    response: fn() -> http::Response<Vec<u8>>,
+    requests: Mutex<Vec<http::Request<Bytes>>>,
+}
+
+impl HttpClient for ServiceClient {
+    type Request = http::request::Builder;
+
+    fn execute(&self, request: Self::Request, body: Bytes)
+        -> impl Future<Output = Result<HttpRequestResultRaw, Error>> + Send
+    {
+        let result = http_compat::build_request(request, body).and_then(|request| {
+            self.requests.lock().unwrap().push(request);
+            let response = (self.response)();
+            http_compat::async_response(response.map(futures::io::Cursor::new))
+        });
+        futures::future::ready(result)
+    }
+
+    fn new_request(&self, url: &str) -> Self::Request {
+        http_compat::new_request(url)
+    }
+}
+
+impl NoauthClient for ServiceClient {}
+
+#[test]
+fn test_http_client() {
+    let client = ServiceClient {
+        response: || http::Response::builder()
+            .header("Content-Type", "application/json")
+            .body(br#"{"changes": true}"#.to_vec())
+            .unwrap(),
+        requests: Mutex::new(vec![]),
+    };
+    let arg = files::ListFolderLongpollArg::new("cursor".to_owned());
+    let result = files::list_folder_longpoll(&client, &arg).now_or_never().unwrap().unwrap();
+    assert!(result.changes);
+
+    let requests = client.requests.lock().unwrap();
+    assert_eq!(http::Method::POST, requests[0].method());
+    assert_eq!("https://notify.dropboxapi.com/2/files/list_folder/longpoll", requests[0].uri());
+    assert_eq!("application/json", requests[0].headers()["Content-Type"]);
+    let body: serde_json::Value = serde_json::from_slice(requests[0].body()).unwrap();
+    assert_eq!("cursor", body["cursor"]);
+}
+
+#[test]
+fn test_http_errors() {
+    let client = ServiceClient {
+        response: || http::Response::builder()
+            .status(503)
+            .header("Retry-After", "7")
+            .body(b"down for maintenance".to_vec())
+            .unwrap(),
+        requests: Mutex::new(vec![]),
+    };
+    let arg = files::ListFolderLongpollArg::new("cursor".to_owned());
+    match files::list_folder_longpoll(&client, &arg).now_or_never().unwrap() {
+        Err(Error::ServiceUnavailable { retry_after_seconds: 7, .. }) => (),
+        other => panic!("wrong result: {:?}", other.map(|_| ())),
+    }
+}
+
+#[test]
+fn test_timeout() {
+    use dropbox_sdk::client_trait_common::HttpRequest;
+    let request = http_compat::new_request("https://api.dropboxapi.com/2/files/copy_v2")
+        .set_header("Authorization", "Bearer token")
+        .set_timeout(Duration::from_secs(300));
+    let request = http_compat::build_request(request, Bytes::new()).unwrap();
+    assert_eq!(Some(Duration::from_secs(300)), http_compat::timeout(&request));
+    assert_eq!("Bearer token", request.headers()["Authorization"]);
+}
+
+#[test]
+fn test_fn_client() {
+    let client = http_compat::FnClient::new(|request: http::Request<Bytes>| {
+        assert_eq!("https://api.dropboxapi.com/oauth2/token", request.uri());
+        let body = String::from_utf8(request.body().to_vec()).unwrap();
+        assert!(body.contains("grant_type=refresh_token"), "wrong body: {}", body);
+        assert!(body.contains("refresh_token=refresh"), "wrong body: {}", body);
+        let response = http::Response::builder()
+            .header("Content-Type", "application/json")
+            .body(r#"{
+                "access_token": "short",
+                "token_type": "bearer",
+                "expires_in": 14400
+            }"#)
+            .unwrap();
+        futures::future::ready(Ok::<_, std::io::Error>(response))
+    });
+    let mut auth = Authorization::load("client".to_owned(), "2&refresh").unwrap();
+    let token = auth.obtain_access_token_async(client).now_or_never().unwrap().unwrap();
+    assert_eq!("short", token);
+}
+
+#[test]
+fn test_fn_client_error() {
+    let client = http_compat::FnClient::new(|_request| {
+        futures::future::ready(Err::<http::Response<Vec<u8>>, _>(
+            std::io::Error::new(std::io::ErrorKind::ConnectionRefused, "no network")))
+    });
+    let mut auth = Authorization::load("client".to_owned(), "2&refresh").unwrap();
+    match auth.obtain_access_token_async(client).now_or_never().unwrap() {
+        Err(Error::HttpClient(e)) => assert_eq!("no network", e.to_string()),
+        other => panic!("wrong result: {:?}", other),
+    }
+}
@@ -67,3 +67,71 @@ fn test_missing_null_and_default() {
     let b_de = serde_json::from_str::<Metadata>(b).unwrap();
     assert_eq!(a_de, b_de);
 }
+
+#[test]
+fn test_other_variant_roundtrip() {
+    // Unknown variants deserialize to `Other`, which keeps the tag and fields received, and
+    // serializes back to them unchanged.
+    let json = r#"{".tag":"some_new_error","detail":{"limit":42},"reason":"new"}"#;
+    let value = serde_json::from_str::<dropbox_sdk::files::DeleteError>(json).unwrap();
+    match value {
+        dropbox_sdk::files::DeleteError::Other(ref unknown) => {
+            assert_eq!("some_new_error", unknown.tag());
+            assert_eq!(2, unknown.fields().len());
+        }
+        ref other => panic!("wrong variant: {:?}", other),
+    }
+
+    let s = serde_json::to_string(&value).unwrap();
+    assert_eq!(json, s);
+    let roundtrip = serde_json::from_str::<dropbox_sdk::files::DeleteError>(&s).unwrap();
+    assert_eq!(value, roundtrip);
+
+    // Including inside another value.
+    let json = r#"{".tag":"failure","failure":{".tag":"some_new_error","detail":1}}"#;
+    let value = serde_json::from_str::<dropbox_sdk::files::DeleteBatchResultEntry>(json).unwrap();
+    let s = serde_json::to_string(&value).unwrap();
+    assert_eq!(json, s);
+
+    // And for unions which don't have any variants known to the SDK.
+    let json = r#"{".tag":"start_date_after_end_date"}"#;
+    let value = serde_json::from_str::<dropbox_sdk::team::DateRangeError>(json).unwrap();
+    assert_eq!(json, serde_json::to_string(&value).unwrap());
+}
+
+#[test]
+fn test_unknown_and_duplicate_fields() {
+    // Unknown fields are ignored.
+    let value = serde_json::from_str::<dropbox_sdk::files::GetMetadataArg>(
+        r#"{"path": "/foo", "some_new_field": {"a": [1, 2]}, "include_deleted": true}"#).unwrap();
+    assert_eq!(
+        dropbox_sdk::files::GetMetadataArg::new("/foo".to_owned()).with_include_deleted(true),
+        value);
+
+    // Duplicate fields are an error.
+    let err = serde_json::from_str::<dropbox_sdk::files::GetMetadataArg>(
+        r#"{"path": "/foo", "path": "/bar"}"#).unwrap_err();
+    assert!(err.to_string().contains("duplicate field `path`"), "wrong error: {}", err);
+}
+
+#[cfg(feature = "dbx_team_log")]
+#[test]
+fn test_camel_case_aliases() {
+    use dropbox_sdk::team_log::{AccountCaptureAvailability, AccountCaptureChangeAvailabilityDetails};
+
+    // team_log fields are also accepted in camelCase, but always serialized in snake_case.
+    let value = serde_json::from_str::<AccountCaptureChangeAvailabilityDetails>(
+        r#"{"newValue": {".tag": "available"}, "previousValue": {".tag": "unavailable"}}"#).unwrap();
+    assert_eq!(
+        AccountCaptureChangeAvailabilityDetails::new(AccountCaptureAvailability::Available)
+            .with_previous_value(AccountCaptureAvailability::Unavailable),
+        value);
+    assert_eq!(
+        r#"{"new_value":{".tag":"available"},"previous_value":{".tag":"unavailable"}}"#,
+        serde_json::to_string(&value).unwrap());
+
+    // Both names are the same field.
+    let err = serde_json::from_str::<AccountCaptureChangeAvailabilityDetails>(
+        r#"{"new_value": {".tag": "available"}, "newValue": {".tag": "available"}}"#).unwrap_err();
+    assert!(err.to_string().contains("duplicate field `new_value`"), "wrong error: {}", err);
+}
@@ -0,0 +1,19 @@
+#![warn(rust_2018_idioms)]
+
+use dropbox_sdk::users;
+
+mod mock;
+use mock::{MockClient, Response};
+
+const SPACE_USAGE: &str = r#"{"used": 1, "allocation": {".tag": "individual", "allocated": 2}}"#;
+
+#[test]
+fn test_locale_header() {
+    let client = MockClient::new([Response::json(SPACE_USAGE)]);
+    users::get_space_usage(&client).unwrap();
+    assert_eq!(None, client.requests()[0].header("Dropbox-API-User-Locale"));
+
+    let client = MockClient::new([Response::json(SPACE_USAGE)]).with_locale("fr-CA");
+    users::get_space_usage(&client).unwrap();
+    assert_eq!(Some("fr-CA"), client.requests()[0].header("Dropbox-API-User-Locale"));
+}
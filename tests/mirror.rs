@@ -0,0 +1,59 @@
+#![warn(rust_2018_idioms)]
+
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use dropbox_sdk::mirror::Mirror;
+
+mod mock;
+use mock::{FILE_METADATA, MockClient, Response, file_entry};
+
+/// Wait for the client to have made the given number of requests, and return the last one's URL.
+fn wait_for_request(client: &MockClient, count: usize) -> String {
+    let start = Instant::now();
+    loop {
+        let requests = client.requests();
+        if requests.len() >= count {
+            assert_eq!(count, requests.len(), "too many requests: {:?}", requests);
+            return requests[count - 1].url.clone();
+        }
+        assert!(start.elapsed() < Duration::from_secs(10), "timed out waiting for request {}", count);
+        std::thread::sleep(Duration::from_millis(50));
+    }
+}
+
+#[test]
+fn test_mirror() {
+    let dir = std::env::temp_dir().join(format!("dropbox-sdk-mirror-test-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+
+    let client = Arc::new(MockClient::new([]));
+    let mirror = Mirror::new(&dir, "/Backup/").with_debounce(Duration::from_millis(200));
+    {
+        let client = Arc::clone(&client);
+        std::thread::spawn(move || futures::executor::block_on(mirror.run(&*client)));
+    }
+    // Give the watcher a moment to start.
+    std::thread::sleep(Duration::from_millis(500));
+
+    client.push(Response::json(FILE_METADATA));
+    std::fs::write(dir.join("a.txt"), b"hello").unwrap();
+    assert!(wait_for_request(&client, 1).ends_with("/files/upload"));
+    let arg = client.requests()[0].header("Dropbox-API-Arg").unwrap().to_owned();
+    assert!(arg.contains(r#""path":"/Backup/a.txt""#), "{}", arg);
+    assert_eq!(b"hello", client.requests()[0].body.as_slice());
+
+    client.push(Response::json(&format!(r#"{{"metadata": {}}}"#, file_entry())));
+    std::fs::rename(dir.join("a.txt"), dir.join("b.txt")).unwrap();
+    assert!(wait_for_request(&client, 2).ends_with("/files/move_v2"));
+    let body: serde_json::Value = serde_json::from_slice(&client.requests()[1].body).unwrap();
+    assert_eq!("/Backup/a.txt", body["from_path"]);
+    assert_eq!("/Backup/b.txt", body["to_path"]);
+
+    client.push(Response::json(&format!(r#"{{"metadata": {}}}"#, file_entry())));
+    std::fs::remove_file(dir.join("b.txt")).unwrap();
+    assert!(wait_for_request(&client, 3).ends_with("/files/delete_v2"));
+    let body: serde_json::Value = serde_json::from_slice(&client.requests()[2].body).unwrap();
+    assert_eq!("/Backup/b.txt", body["path"]);
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}
@@ -0,0 +1,325 @@
+//! A sync HTTP client which returns canned responses and records the requests made with it, for
+//! testing the SDK's request and response handling without hitting the network.
+
+#![allow(dead_code)] // not every test uses every part of this
+
+use std::collections::VecDeque;
+use std::io::{Cursor, Read};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use dropbox_sdk::client_trait::*;
+use dropbox_sdk::client_trait_common::{HttpRequest, Stalled};
+
+/// The metadata of a file, as returned by routes which return a `FileMetadata`.
+pub const FILE_METADATA: &str = r#"{
+    "name": "a.txt",
+    "id": "id:abc",
+    "client_modified": "2020-01-01T00:00:00Z",
+    "server_modified": "2020-01-01T00:00:00Z",
+    "rev": "0123456789abcdef",
+    "size": 5
+}"#;
+
+/// [`FILE_METADATA`] tagged as a file, as returned by routes which return any kind of `Metadata`.
+pub fn file_entry() -> String {
+    FILE_METADATA.replacen('{', r#"{".tag": "file", "#, 1)
+}
+
+/// A response for the mock client to return.
+pub struct Response {
+    pub status: u16,
+    pub result_header: Option<String>,
+    pub retry_after: Option<String>,
+    pub content_range: Option<String>,
+    pub content_disposition: Option<String>,
+    pub request_id: Option<String>,
+    pub body: Vec<u8>,
+    /// Whether reading the body stalls after the data in it.
+    pub stall: bool,
+    /// An I/O error to fail the request with instead of responding.
+    pub io_error: Option<std::io::ErrorKind>,
+}
+
+impl Response {
+    /// A successful response with the given JSON in the body.
+    pub fn json(body: &str) -> Self {
+        Self::status(200, body)
+    }
+
+    /// A response with the given status code and body.
+    pub fn status(status: u16, body: &str) -> Self {
+        Self {
+            status,
+            result_header: None,
+            retry_after: None,
+            content_range: None,
+            content_disposition: None,
+            request_id: None,
+            body: body.as_bytes().to_vec(),
+            stall: false,
+            io_error: None,
+        }
+    }
+
+    /// A successful download-style response, with the given JSON result header and body.
+    pub fn download(result: &str, body: &[u8]) -> Self {
+        Self {
+            status: 200,
+            result_header: Some(result.to_owned()),
+            retry_after: None,
+            content_range: None,
+            content_disposition: None,
+            request_id: None,
+            body: body.to_vec(),
+            stall: false,
+            io_error: None,
+        }
+    }
+
+    /// Fail the request with an I/O error of the given kind, as an HTTP client error.
+    pub fn io_error(kind: std::io::ErrorKind) -> Self {
+        Self {
+            io_error: Some(kind),
+            ..Self::status(0, "")
+        }
+    }
+
+    /// Set the `Retry-After` header of the response.
+    pub fn with_retry_after(mut self, value: &str) -> Self {
+        self.retry_after = Some(value.to_owned());
+        self
+    }
+
+    /// Make this a partial response, with the given `Content-Range` header.
+    pub fn with_content_range(mut self, value: &str) -> Self {
+        self.status = 206;
+        self.content_range = Some(value.to_owned());
+        self
+    }
+
+    /// Set the `Content-Disposition` header of the response.
+    pub fn with_content_disposition(mut self, value: &str) -> Self {
+        self.content_disposition = Some(value.to_owned());
+        self
+    }
+
+    /// Set the `X-Dropbox-Request-Id` header of the response.
+    pub fn with_request_id(mut self, value: &str) -> Self {
+        self.request_id = Some(value.to_owned());
+        self
+    }
+
+    /// Make reading the body fail with a [`Stalled`] error after the data in it.
+    pub fn with_stall(mut self) -> Self {
+        self.stall = true;
+        self
+    }
+}
+
+/// A response body which has stalled.
+struct StalledBody;
+
+impl Read for StalledBody {
+    fn read(&mut self, _buf: &mut [u8]) -> std::io::Result<usize> {
+        Err(Stalled { timeout: Duration::from_secs(30) }.into())
+    }
+}
+
+/// A request made with the mock client.
+#[derive(Debug, Clone, Default)]
+pub struct Request {
+    pub url: String,
+    pub headers: Vec<(String, String)>,
+    pub body: Vec<u8>,
+    pub timeout: Option<Duration>,
+    /// The content length given, if the body was streamed.
+    pub streamed: Option<u64>,
+}
+
+impl Request {
+    /// The route the request was made to, such as `"files/upload"`.
+    pub fn route(&self) -> &str {
+        self.url.rsplit("/2/").next().unwrap()
+    }
+
+    /// Get the value of a header, if it was set.
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers.iter()
+            .find(|(n, _)| n.eq_ignore_ascii_case(name))
+            .map(|(_, v)| v.as_str())
+    }
+}
+
+impl HttpRequest for Request {
+    fn set_header(mut self, name: &str, value: &str) -> Self {
+        self.headers.push((name.to_owned(), value.to_owned()));
+        self
+    }
+
+    fn set_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+}
+
+#[derive(Default)]
+pub struct MockClient {
+    responses: Mutex<VecDeque<Response>>,
+    requests: Mutex<Vec<Request>>,
+    locale: Option<String>,
+    token: Option<Arc<String>>,
+    gzip_threshold: Option<usize>,
+    extra_headers: Vec<(String, String)>,
+    total_timeout: Option<Duration>,
+    auth: &'static str,
+}
+
+impl MockClient {
+    /// Make a new client which will return the given responses, in order.
+    pub fn new(responses: impl IntoIterator<Item = Response>) -> Self {
+        Self {
+            responses: Mutex::new(responses.into_iter().collect()),
+            requests: Mutex::new(vec![]),
+            locale: None,
+            token: None,
+            gzip_threshold: None,
+            extra_headers: Vec::new(),
+            total_timeout: None,
+            auth: "user",
+        }
+    }
+
+    /// Set the locale the client reports to the SDK.
+    pub fn with_locale(mut self, locale: &str) -> Self {
+        self.locale = Some(locale.to_owned());
+        self
+    }
+
+    /// Set the access token the client reports to the SDK.
+    pub fn with_token(mut self, token: &str) -> Self {
+        self.token = Some(Arc::new(token.to_owned()));
+        self
+    }
+
+    /// Set the size over which the client asks for request bodies to be compressed.
+    pub fn with_gzip_threshold(mut self, threshold: usize) -> Self {
+        self.gzip_threshold = Some(threshold);
+        self
+    }
+
+    /// Add a header the client asks for every request to be sent with.
+    pub fn with_extra_header(mut self, name: &str, value: &str) -> Self {
+        self.extra_headers.push((name.to_owned(), value.to_owned()));
+        self
+    }
+
+    /// Set the time limit the client reports for each call of a route.
+    pub fn with_total_timeout(mut self, timeout: Duration) -> Self {
+        self.total_timeout = Some(timeout);
+        self
+    }
+
+    /// Set the kind of authentication the client reports for calling routes by name.
+    pub fn with_auth(mut self, auth: &'static str) -> Self {
+        self.auth = auth;
+        self
+    }
+
+    /// Add another response to be returned after the existing ones.
+    pub fn push(&self, response: Response) {
+        self.responses.lock().unwrap().push_back(response);
+    }
+
+    /// All the requests made so far.
+    pub fn requests(&self) -> Vec<Request> {
+        self.requests.lock().unwrap().clone()
+    }
+}
+
+impl HttpClient for MockClient {
+    type Request = Request;
+
+    fn execute(
+        &self,
+        mut request: Self::Request,
+        body: &[u8],
+    ) -> Result<HttpRequestResultRaw, dropbox_sdk::Error> {
+        request.body = body.to_vec();
+        self.requests.lock().unwrap().push(request);
+        let response = self.responses.lock().unwrap()
+            .pop_front()
+            .expect("mock client ran out of responses");
+        if let Some(kind) = response.io_error {
+            return Err(dropbox_sdk::Error::HttpClient(Box::new(std::io::Error::from(kind))));
+        }
+        let content_length = response.body.len() as u64;
+        let body: Box<dyn Read + Send> = if response.stall {
+            Box::new(Cursor::new(response.body).chain(StalledBody))
+        } else {
+            Box::new(Cursor::new(response.body))
+        };
+        let mut result = HttpRequestResultRaw::new(response.status, body);
+        result.result_header = response.result_header;
+        result.content_length = Some(content_length);
+        result.retry_after = response.retry_after;
+        result.content_range = response.content_range;
+        result.content_disposition = response.content_disposition;
+        result.request_id = response.request_id;
+        Ok(result)
+    }
+
+    fn execute_streaming(
+        &self,
+        mut request: Self::Request,
+        body: &mut (dyn std::io::Read + Send),
+        content_length: u64,
+    ) -> Result<HttpRequestResultRaw, dropbox_sdk::Error> {
+        let mut buf = vec![];
+        body.read_to_end(&mut buf).unwrap();
+        assert_eq!(content_length, buf.len() as u64);
+        request.streamed = Some(content_length);
+        self.execute(request, &buf)
+    }
+
+    fn new_request(&self, url: &str) -> Self::Request {
+        Request {
+            url: url.to_owned(),
+            ..Default::default()
+        }
+    }
+
+    fn token(&self) -> Option<Arc<String>> {
+        self.token.clone()
+    }
+
+    fn locale(&self) -> Option<&str> {
+        self.locale.as_deref()
+    }
+
+    fn extra_headers(&self) -> &[(String, String)] {
+        &self.extra_headers
+    }
+
+    fn total_timeout(&self) -> Option<Duration> {
+        self.total_timeout
+    }
+
+    #[cfg(feature = "gzip")]
+    fn gzip_requests_over(&self) -> Option<usize> {
+        self.gzip_threshold
+    }
+}
+
+#[cfg(feature = "dispatch")]
+impl dropbox_sdk::dispatch::DispatchClient for MockClient {
+    fn auth(&self) -> &'static str {
+        self.auth
+    }
+}
+
+// The mock stands in for every kind of client in the tests, so unlike a real client, it implements
+// all the auth marker traits.
+impl NoauthClient for MockClient {}
+impl UserAuthClient for MockClient {}
+impl TeamAuthClient for MockClient {}
+impl AppAuthClient for MockClient {}
@@ -0,0 +1,341 @@
+#![warn(rust_2018_idioms)]
+
+use std::sync::Arc;
+use std::time::{Duration, SystemTime};
+use dropbox_sdk::oauth2::{
+    Authorization, AuthorizationMetadata, AuthorizeUrlBuilder, AuthRedirect, CsrfError, CsrfToken,
+    Oauth2Type, PkceCode, RedirectError, RefreshCoordinator, SavedAuthorization, SavedToken,
+    TokenCache, TokenEvent, TokenEvents,
+};
+use futures::future::BoxFuture;
+use futures::FutureExt;
+
+mod mock;
+use mock::{MockClient, Response};
+
+#[test]
+fn test_save_load_legacy() {
+    let auth = Authorization::load("client".to_owned(), "2&refresh").unwrap();
+    assert_eq!(Some("2&refresh".to_owned()), auth.save());
+    assert_eq!(&AuthorizationMetadata::default(), auth.metadata());
+
+    #[cfg(not(feature = "deny_legacy_tokens"))]
+    {
+        let auth = Authorization::load("client".to_owned(), "1&token").unwrap();
+        assert_eq!(Some("1&token".to_owned()), auth.save());
+    }
+
+    assert!(Authorization::load("client".to_owned(), "4&what").is_none());
+    assert!(Authorization::load("client".to_owned(), "3&{").is_none());
+}
+
+#[test]
+fn test_csrf_token() {
+    let token = CsrfToken::new();
+    assert_ne!(token.secret(), CsrfToken::new().secret());
+    assert!(!format!("{:?}", token).contains(token.secret()));
+
+    let flow = Oauth2Type::PKCE(PkceCode::new());
+    let url = AuthorizeUrlBuilder::new("client", &flow)
+        .state("ignored")
+        .csrf_token(&token)
+        .build();
+    let state = url.query_pairs().find(|(k, _)| k == "state").unwrap().1.into_owned();
+    assert_eq!(Ok(()), token.verify(Some(&state)));
+
+    assert_eq!(Err(CsrfError::Missing), token.verify(None));
+    assert_eq!(Err(CsrfError::Mismatch), token.verify(Some(&state[1..])));
+    assert_eq!(Err(CsrfError::Mismatch), token.verify(Some(CsrfToken::new().secret())));
+
+    let saved = CsrfToken::new().with_expiry(Duration::from_secs(600));
+    let restored = CsrfToken::from_parts(saved.secret().to_owned(), saved.expires_at());
+    assert_eq!(Ok(()), restored.verify(Some(saved.secret())));
+    let expired = CsrfToken::from_parts(
+        saved.secret().to_owned(), Some(SystemTime::now() - Duration::from_secs(1)));
+    assert_eq!(Err(CsrfError::Expired), expired.verify(Some(saved.secret())));
+}
+
+#[test]
+fn test_parse_redirect_url() {
+    use dropbox_sdk::oauth2::parse_redirect_url;
+
+    let code = parse_redirect_url("https://example.com/cb?code=abc%2B1&state=xyz").unwrap();
+    assert_eq!(AuthRedirect::Code { code: "abc+1".to_owned(), state: Some("xyz".to_owned()) }, code);
+    assert_eq!(Some("xyz"), code.state());
+
+    match parse_redirect_url("/cb#access_token=tok&token_type=bearer&account_id=dbid%3Aa&uid=1") {
+        Ok(AuthRedirect::Token(token)) => {
+            assert_eq!("tok", token.access_token);
+            assert_eq!(Some("dbid:a"), token.account_id.as_deref());
+            assert_eq!(None, token.state);
+        }
+        other => panic!("wrong result: {:?}", other),
+    }
+
+    let denied = parse_redirect_url(
+        "/cb?error=access_denied&error_description=The+user+chose+not+to+give+your+app+access&state=s");
+    assert_eq!(Err(RedirectError::Failed {
+        error: "access_denied".to_owned(),
+        description: Some("The user chose not to give your app access".to_owned()),
+        state: Some("s".to_owned()),
+    }), denied);
+    assert_eq!(Err(RedirectError::Missing), parse_redirect_url("https://example.com/cb?state=s"));
+    assert!(matches!(parse_redirect_url("http://[::1"), Err(RedirectError::InvalidUrl(_))));
+}
+
+#[test]
+fn test_save_load_metadata() {
+    let client = MockClient::new([Response::json(r#"{
+        "access_token": "short",
+        "expires_in": 14400,
+        "token_type": "bearer",
+        "scope": "account_info.read files.metadata.read",
+        "account_id": "dbid:abc",
+        "uid": "123"
+    }"#)]);
+
+    let mut auth = Authorization::load("client".to_owned(), "2&refresh").unwrap();
+    assert_eq!("short", auth.obtain_access_token(client).unwrap());
+
+    let metadata = auth.metadata().clone();
+    assert_eq!(
+        Some(vec!["account_info.read".to_owned(), "files.metadata.read".to_owned()]),
+        metadata.scopes);
+    assert_eq!(Some("dbid:abc"), metadata.account_id.as_deref());
+    assert_eq!(None, metadata.team_id);
+    assert_eq!(Some("123"), metadata.uid.as_deref());
+    assert_eq!(Some("dbid:abc"), auth.account_id());
+    assert_eq!(Some("123"), auth.uid());
+    assert_eq!(Ok(()), auth.check_account("dbid:abc"));
+    let err = auth.check_account("dbid:other").unwrap_err();
+    assert_eq!(Some("dbid:abc"), err.actual.as_deref());
+    assert_eq!("authorized account dbid:abc is not the expected account dbid:other", err.to_string());
+    let obtained_at = dropbox_sdk::timestamp::to_system_time(metadata.obtained_at.as_ref().unwrap())
+        .unwrap();
+    let expires_at = dropbox_sdk::timestamp::to_system_time(metadata.expires_at.as_ref().unwrap())
+        .unwrap();
+    assert_eq!(14400, expires_at.duration_since(obtained_at).unwrap().as_secs());
+
+    // The legacy format leaves out the metadata.
+    assert_eq!(Some("2&refresh".to_owned()), auth.save());
+
+    let saved = auth.save_with_metadata().unwrap();
+    assert!(saved.starts_with("3&"), "wrong format: {}", saved);
+    let loaded = Authorization::load("client".to_owned(), &saved).unwrap();
+    assert_eq!(&metadata, loaded.metadata());
+    assert_eq!(Some("2&refresh".to_owned()), loaded.save());
+
+    let json = serde_json::to_value(auth.saved().unwrap()).unwrap();
+    assert_eq!("refresh", json["type"]);
+    assert_eq!("refresh", json["refresh_token"]);
+    assert_eq!("dbid:abc", json["account_id"]);
+    let saved: SavedAuthorization = serde_json::from_value(json).unwrap();
+    assert_eq!(SavedToken::Refresh { refresh_token: "refresh".to_owned() }, saved.token);
+    let loaded = Authorization::from_saved("client".to_owned(), saved);
+    assert_eq!(&metadata, loaded.metadata());
+}
+
+#[test]
+fn test_token_response() {
+    let client = MockClient::new([Response::json(r#"{
+        "access_token": "short",
+        "expires_in": 14400,
+        "token_type": "bearer",
+        "refresh_token": "rotated",
+        "scope": "account_info.read openid",
+        "account_id": "dbid:abc",
+        "id_token": "jwt"
+    }"#)]);
+
+    let mut auth = Authorization::load("client".to_owned(), "2&refresh").unwrap();
+    let response = auth.obtain_token_response_async(client).now_or_never().unwrap().unwrap();
+    assert_eq!("short", response.access_token);
+    assert_eq!(Some("bearer"), response.token_type.as_deref());
+    assert_eq!(Some(Duration::from_secs(14400)), response.expires_in);
+    assert!(response.refresh_token_rotated);
+    assert_eq!(Some(vec!["account_info.read".to_owned(), "openid".to_owned()]), response.scope);
+    assert_eq!(Some("dbid:abc"), response.account_id.as_deref());
+    assert_eq!(None, response.team_id);
+    assert_eq!(Some("jwt"), response.id_token.as_deref());
+    assert_eq!(Some("2&rotated".to_owned()), auth.save());
+
+    let client = MockClient::new([Response::json(
+        r#"{"access_token": "again", "token_type": "bearer", "expires_in": 60}"#)]);
+    let response = auth.obtain_token_response_async(client).now_or_never().unwrap().unwrap();
+    assert_eq!("again", response.access_token);
+    assert_eq!(Some(Duration::from_secs(60)), response.expires_in);
+    assert!(!response.refresh_token_rotated);
+    assert_eq!(None, response.scope);
+
+    // A long-lived token needs no request.
+    if let Some(mut auth) = Authorization::load("client".to_owned(), "1&legacy") {
+        let response = auth.obtain_token_response_async(MockClient::new([]))
+            .now_or_never().unwrap().unwrap();
+        assert_eq!("legacy", response.access_token);
+        assert_eq!(None, response.expires_in);
+    }
+}
+
+#[cfg(feature = "sync_routes")]
+#[test]
+fn test_token_cache_sync_and_async() {
+    let token_response = |token: &str| Response::json(&format!(
+        r#"{{"access_token": "{token}", "expires_in": 14400, "token_type": "bearer"}}"#));
+    let cache = Arc::new(TokenCache::new(
+        Authorization::load("client".to_owned(), "2&refresh").unwrap()));
+    assert_eq!(None, cache.get_token());
+
+    let empty = Arc::new(String::new());
+    let client = MockClient::new([token_response("first")]);
+    let first = cache.update_token_blocking(client, Arc::clone(&empty)).unwrap();
+    assert_eq!("first", first.as_str());
+
+    // A stale token doesn't cause another refresh, whichever kind of client asks.
+    let token = cache.update_token(MockClient::new([]), Arc::clone(&empty))
+        .now_or_never().unwrap().unwrap();
+    assert_eq!("first", token.as_str());
+
+    let client = MockClient::new([token_response("second")]);
+    let second = cache.update_token(client, first).now_or_never().unwrap().unwrap();
+    assert_eq!("second", second.as_str());
+    let token = cache.update_token_blocking(MockClient::new([]), empty).unwrap();
+    assert_eq!("second", token.as_str());
+    assert_eq!(Some(second), cache.get_token());
+}
+
+/// A coordinator standing in for a lock shared between processes, with the token last published
+/// by any of them.
+#[derive(Default)]
+struct SharedToken {
+    token: std::sync::Mutex<String>,
+    calls: std::sync::Mutex<Vec<String>>,
+}
+
+impl RefreshCoordinator for SharedToken {
+    fn acquire<'a>(&'a self, stale: &'a str)
+        -> BoxFuture<'a, Result<Option<String>, Box<dyn std::error::Error + Send + Sync>>>
+    {
+        self.calls.lock().unwrap().push(format!("acquire {stale}"));
+        let shared = self.token.lock().unwrap().clone();
+        futures::future::ready(Ok(Some(shared).filter(|shared| shared != stale))).boxed()
+    }
+
+    fn release<'a>(&'a self, refreshed: Option<&'a str>) -> BoxFuture<'a, ()> {
+        self.calls.lock().unwrap().push(format!("release {refreshed:?}"));
+        if let Some(token) = refreshed {
+            *self.token.lock().unwrap() = token.to_owned();
+        }
+        futures::future::ready(()).boxed()
+    }
+}
+
+#[test]
+fn test_token_cache_refresh_coordinator() {
+    let coordinator = Arc::new(SharedToken::default());
+    let cache = |coordinator: &Arc<SharedToken>| TokenCache::new(
+        Authorization::load("client".to_owned(), "2&refresh").unwrap())
+        .with_refresh_coordinator(Arc::clone(coordinator) as Arc<dyn RefreshCoordinator>);
+    let (first, second) = (cache(&coordinator), cache(&coordinator));
+    let empty = Arc::new(String::new());
+
+    let client = MockClient::new([Response::json(
+        r#"{"access_token": "token", "expires_in": 14400, "token_type": "bearer"}"#)]);
+    let token = first.update_token(client, Arc::clone(&empty)).now_or_never().unwrap().unwrap();
+    assert_eq!("token", token.as_str());
+
+    // The other cache uses the token the first one shared, without refreshing.
+    let token = second.update_token(MockClient::new([]), empty).now_or_never().unwrap().unwrap();
+    assert_eq!("token", token.as_str());
+    assert_eq!(Some(token), second.get_token());
+
+    assert_eq!(vec![
+        "acquire ".to_owned(),
+        "release Some(\"token\")".to_owned(),
+        "acquire ".to_owned(),
+        "release None".to_owned(),
+    ], *coordinator.calls.lock().unwrap());
+}
+
+#[test]
+fn test_token_cache_events() {
+    let events = Arc::new(std::sync::Mutex::new(Vec::new()));
+    let recorder = Arc::clone(&events);
+    let cache = TokenCache::new(Authorization::load("client".to_owned(), "2&refresh").unwrap())
+        .with_token_events(Arc::new(move |event: &TokenEvent<'_>| {
+            let name = match event {
+                TokenEvent::Obtained { expires_in } => format!("obtained {expires_in:?}"),
+                TokenEvent::Refreshed { expires_in, refresh_token_rotated, authorization } => {
+                    let saved = authorization.as_ref().map(|saved| &saved.token);
+                    format!("refreshed {expires_in:?} {refresh_token_rotated} {saved:?}")
+                }
+                TokenEvent::RefreshFailed { .. } => "failed".to_owned(),
+                TokenEvent::Revoked { .. } => "revoked".to_owned(),
+                other => panic!("unexpected event: {:?}", other),
+            };
+            recorder.lock().unwrap().push(name);
+        }) as Arc<dyn TokenEvents>);
+
+    let client = MockClient::new([Response::json(
+        r#"{"access_token": "first", "expires_in": 60, "token_type": "bearer"}"#)]);
+    let first = cache.update_token(client, Arc::new(String::new()))
+        .now_or_never().unwrap().unwrap();
+    let client = MockClient::new([Response::json(
+        r#"{"access_token": "second", "token_type": "bearer", "refresh_token": "new"}"#)]);
+    let second = cache.update_token(client, first).now_or_never().unwrap().unwrap();
+
+    let client = MockClient::new([Response::status(500, "oops")]);
+    cache.update_token(client, Arc::clone(&second)).now_or_never().unwrap().unwrap_err();
+    let client = MockClient::new([Response::status(400,
+        r#"{"error": "invalid_grant", "error_description": "refresh token is invalid"}"#)]);
+    let err = cache.update_token(client, second).now_or_never().unwrap().unwrap_err();
+    assert!(matches!(err, dropbox_sdk::Error::BadRequest(_)), "wrong error: {:?}", err);
+
+    assert_eq!(vec![
+        "obtained Some(60s)".to_owned(),
+        r#"refreshed None true Some(Refresh { refresh_token: "new" })"#.to_owned(),
+        "failed".to_owned(),
+        "revoked".to_owned(),
+    ], *events.lock().unwrap());
+}
+
+#[cfg(feature = "default_client")]
+#[test]
+fn test_cloned_client_shares_tokens() {
+    use dropbox_sdk::client_trait::HttpClient;
+    use dropbox_sdk::default_client::UserAuthDefaultClient;
+
+    let cache = Arc::new(TokenCache::new(
+        Authorization::load("client".to_owned(), "2&refresh").unwrap()));
+    let client = UserAuthDefaultClient::from_token_cache(Arc::clone(&cache));
+    let clone = client.clone();
+    std::thread::spawn(move || {
+        assert_eq!(None, clone.token());
+    }).join().unwrap();
+
+    cache.set_access_token("token".to_owned());
+    let clone = client.clone();
+    std::thread::spawn(move || {
+        assert_eq!("token", clone.token().unwrap().as_str());
+    }).join().unwrap();
+}
+
+#[cfg(feature = "deny_legacy_tokens")]
+#[test]
+fn test_deny_legacy_tokens() {
+    assert!(Authorization::load("client".to_owned(), "1&token").is_none());
+    let saved = r#"3&{"type":"long_lived_access_token","access_token":"token"}"#;
+    assert!(Authorization::load("client".to_owned(), saved).is_none());
+
+    #[allow(deprecated)]
+    let mut auth = Authorization::from_long_lived_access_token("token".to_owned());
+    let err = auth.obtain_access_token(MockClient::new([])).unwrap_err();
+    assert!(
+        matches!(err, dropbox_sdk::Error::Authentication(dropbox_sdk::auth::AuthError::InvalidAccessToken)),
+        "wrong error: {:?}", err);
+
+    // Tokens from the implicit grant flow aren't legacy tokens, however many times they're used.
+    let mut auth = Authorization::from_auth_code(
+        "client".to_owned(), Oauth2Type::ImplicitGrant, "token".to_owned(), None);
+    assert_eq!("token", auth.obtain_access_token(MockClient::new([])).unwrap());
+    assert_eq!("token", auth.obtain_access_token(MockClient::new([])).unwrap());
+}
@@ -0,0 +1,85 @@
+#![warn(rust_2018_idioms)]
+
+use futures::FutureExt;
+use dropbox_sdk::files;
+use dropbox_sdk::paper_helpers::{self, PaperExportFormat, PaperUpdate};
+
+mod mock;
+use mock::{MockClient, Response};
+
+#[test]
+fn test_paper_doc_path() {
+    assert_eq!("/Notes/Q1-Q2 plan.paper", paper_helpers::paper_doc_path("/Notes/", "Q1/Q2 plan"));
+    assert_eq!("/Plan.paper", paper_helpers::paper_doc_path("", "Plan"));
+}
+
+#[test]
+fn test_create_paper_doc() {
+    let client = MockClient::new([Response::json(r#"{
+        "url": "https://www.dropbox.com/scl/fi/abc/Plan.paper",
+        "result_path": "/Notes/Plan.paper",
+        "file_id": "id:abc",
+        "paper_revision": 1
+    }"#)]);
+    let result = paper_helpers::create_paper_doc(
+        &client, "/Notes/Plan", files::ImportFormat::Markdown, "# Plan",
+    ).now_or_never().unwrap().unwrap();
+    assert_eq!(1, result.paper_revision);
+
+    let request = &client.requests()[0];
+    assert!(request.url.ends_with("/files/paper/create"));
+    let arg: serde_json::Value = serde_json::from_str(request.header("Dropbox-API-Arg").unwrap()).unwrap();
+    assert_eq!("/Notes/Plan.paper", arg["path"]);
+    assert_eq!("markdown", arg["import_format"][".tag"]);
+    assert_eq!(b"# Plan", request.body.as_slice());
+}
+
+#[test]
+fn test_update_paper_doc() {
+    let client = MockClient::new([
+        Response::json(r#"{"paper_revision": 3}"#),
+        Response::json(r#"{"paper_revision": 4}"#),
+    ]);
+    let revision = paper_helpers::update_paper_doc(
+        &client, "id:abc", files::ImportFormat::Html, "<p>new</p>", PaperUpdate::Replace { revision: 2 },
+    ).now_or_never().unwrap().unwrap();
+    assert_eq!(3, revision);
+    let revision = paper_helpers::update_paper_doc(
+        &client, "id:abc", files::ImportFormat::PlainText, "more", PaperUpdate::Append,
+    ).now_or_never().unwrap().unwrap();
+    assert_eq!(4, revision);
+
+    let requests = client.requests();
+    assert!(requests[0].url.ends_with("/files/paper/update"));
+    let arg: serde_json::Value = serde_json::from_str(requests[0].header("Dropbox-API-Arg").unwrap()).unwrap();
+    assert_eq!("update", arg["doc_update_policy"][".tag"]);
+    assert_eq!(2, arg["paper_revision"]);
+    let arg: serde_json::Value = serde_json::from_str(requests[1].header("Dropbox-API-Arg").unwrap()).unwrap();
+    assert_eq!("append", arg["doc_update_policy"][".tag"]);
+    assert!(arg.get("paper_revision").is_none());
+}
+
+#[test]
+fn test_export_paper_doc() {
+    let client = MockClient::new([Response::download(r#"{
+        "export_metadata": {"name": "Plan.md", "size": 6, "paper_revision": 5},
+        "file_metadata": {
+            "name": "Plan.paper",
+            "id": "id:abc",
+            "client_modified": "2024-01-01T00:00:00Z",
+            "server_modified": "2024-01-01T00:00:00Z",
+            "rev": "0123456789abcdef",
+            "size": 0
+        }
+    }"#, b"# Plan")]);
+    let export = paper_helpers::export_paper_doc(&client, "/Notes/Plan.paper", PaperExportFormat::Markdown)
+        .now_or_never().unwrap().unwrap();
+    assert_eq!("# Plan", export.content);
+    assert_eq!(Some(5), export.revision);
+    assert_eq!("id:abc", export.metadata.id);
+
+    let request = &client.requests()[0];
+    assert!(request.url.ends_with("/files/export"));
+    let arg: serde_json::Value = serde_json::from_str(request.header("Dropbox-API-Arg").unwrap()).unwrap();
+    assert_eq!("markdown", arg["export_format"]);
+}
@@ -0,0 +1,19 @@
+#![warn(rust_2018_idioms)]
+
+//! Checks that the preludes have what's needed to call routes, without any other imports.
+
+use dropbox_sdk::prelude::*;
+use dropbox_sdk::files::prelude::*;
+
+#[allow(dead_code)]
+fn list(client: &impl UserAuthClient) -> Result<ListFolderResult, Error<ListFolderError>> {
+    dropbox_sdk::files::list_folder(client, &ListFolderArg::new(String::new()))
+}
+
+#[test]
+fn test_prelude() {
+    let client = UserAuthDefaultClient::new(
+        Authorization::from_refresh_token("client".to_owned(), "refresh".to_owned()));
+    let _ = || list(&client);
+    assert_eq!(Endpoint::Api.url(), "https://api.dropboxapi.com/2/");
+}
@@ -0,0 +1,64 @@
+#![warn(rust_2018_idioms)]
+
+use dropbox_sdk::client_trait_common::ContentRange;
+use dropbox_sdk::files;
+use dropbox_sdk::Error;
+
+mod mock;
+use mock::{FILE_METADATA, MockClient, Response};
+
+#[test]
+fn test_parse_content_range() {
+    let range = ContentRange::parse("bytes 10-19/1000").unwrap();
+    assert_eq!(ContentRange { start: 10, end: 19, total: Some(1000) }, range);
+    assert_eq!(10, range.len());
+    assert_eq!(Some(ContentRange { start: 0, end: 0, total: None }),
+        ContentRange::parse("bytes 0-0/*"));
+
+    for invalid in ["10-19/1000", "bytes 19-10/1000", "bytes 10-1000/1000", "bytes */1000",
+        "bytes 10-19", "items 10-19/1000"]
+    {
+        assert_eq!(None, ContentRange::parse(invalid), "{}", invalid);
+    }
+
+    assert!(range.satisfies(Some(10), Some(19)));
+    assert!(range.satisfies(Some(10), Some(500)));
+    assert!(range.satisfies(Some(10), None));
+    assert!(range.satisfies(None, Some(10)));
+    assert!(!range.satisfies(Some(0), Some(19)));
+    assert!(!range.satisfies(None, Some(5)));
+}
+
+#[test]
+fn test_partial_download() {
+    let metadata = FILE_METADATA.replace(r#""size": 5"#, r#""size": 1000"#);
+    let client = MockClient::new([
+        Response::download(&metadata, b"0123456789").with_content_range("bytes 10-19/1000"),
+        Response::download(&metadata, b"0123456789").with_content_range("bytes 0-9/1000"),
+        Response::download(&metadata, b"0123456789").with_content_range("nonsense"),
+        Response::download(&metadata, b"0123456789"),
+    ]);
+    let arg = files::DownloadArg::new("/a.txt".to_owned());
+
+    let result = files::download(&client, &arg, Some(10), Some(19)).unwrap();
+    assert_eq!(Some(10), result.content_length);
+    assert_eq!(Some(ContentRange { start: 10, end: 19, total: Some(1000) }), result.content_range);
+    assert_eq!(Some("bytes=10-19"), client.requests()[0].header("Range"));
+
+    match files::download(&client, &arg, Some(10), Some(19)) {
+        Err(Error::UnexpectedResponse(msg)) => assert!(msg.contains("doesn't match"), "{}", msg),
+        other => panic!("wrong result: {:?}", other.map(|r| r.result)),
+    }
+    match files::download(&client, &arg, Some(10), None) {
+        Err(Error::UnexpectedResponse(msg)) => assert!(msg.contains("invalid"), "{}", msg),
+        other => panic!("wrong result: {:?}", other.map(|r| r.result)),
+    }
+
+    // A server may send the whole file instead.
+    let result = files::download(&client, &arg, Some(10), None).unwrap();
+    assert_eq!(None, result.content_range);
+
+    // An invalid range isn't sent at all.
+    assert!(matches!(files::download(&client, &arg, Some(10), Some(9)), Err(Error::BadRequest(_))));
+    assert_eq!(4, client.requests().len());
+}
@@ -0,0 +1,142 @@
+#![warn(rust_2018_idioms)]
+
+use std::sync::Arc;
+use futures::FutureExt;
+use dropbox_sdk::clock::FakeClock;
+use dropbox_sdk::reports::{self, ReportFormat, ReportOptions};
+use dropbox_sdk::retry::RetryBudget;
+
+mod mock;
+use mock::{MockClient, Response};
+
+const PERMISSIONS: &str = r#"{
+    "can_revoke": true,
+    "visibility_policies": [],
+    "can_set_expiry": true,
+    "can_remove_expiry": true,
+    "allow_download": true,
+    "can_allow_download": true,
+    "can_disallow_download": true,
+    "allow_comments": true,
+    "team_restricts_comments": false,
+    "resolved_visibility": {".tag": "public"}
+}"#;
+
+fn file_link(name: &str) -> String {
+    format!(r#"{{
+        ".tag": "file",
+        "url": "https://www.dropbox.com/s/abc/{name}",
+        "name": "{name}",
+        "link_permissions": {PERMISSIONS},
+        "client_modified": "2020-01-01T00:00:00Z",
+        "server_modified": "2020-01-01T00:00:00Z",
+        "rev": "0123456789abcdef",
+        "size": 5,
+        "path_lower": "/{name}",
+        "id": "id:{name}"
+    }}"#)
+}
+
+fn options(format: ReportFormat) -> ReportOptions {
+    let budget = RetryBudget::new(1).with_clock(Arc::new(FakeClock::new()));
+    ReportOptions::default().with_format(format).with_retry_budget(budget)
+}
+
+#[test]
+fn test_shared_links_report() {
+    let client = MockClient::new([
+        Response::json(&format!(r#"{{"links": [{}], "has_more": true, "cursor": "c1"}}"#,
+            file_link("a.txt"))),
+        rate_limited(),
+        Response::json(&format!(r#"{{"links": [{}], "has_more": false}}"#,
+            file_link(r#"b, \"c\".txt"#))),
+    ]);
+    let mut out = vec![];
+    let rows = reports::shared_links_report(&client, &mut out, &options(ReportFormat::Csv))
+        .now_or_never().unwrap().unwrap();
+    assert_eq!(2, rows);
+    assert_eq!(
+        "url,kind,name,path,id,visibility,expires,allow_download\r\n\
+        https://www.dropbox.com/s/abc/a.txt,file,a.txt,/a.txt,id:a.txt,public,,true\r\n\
+        \"https://www.dropbox.com/s/abc/b, \"\"c\"\".txt\",file,\"b, \"\"c\"\".txt\",\
+        \"/b, \"\"c\"\".txt\",\"id:b, \"\"c\"\".txt\",public,,true\r\n",
+        String::from_utf8(out).unwrap());
+
+    let requests = client.requests();
+    assert_eq!(3, requests.len());
+    let arg: serde_json::Value = serde_json::from_slice(&requests[2].body).unwrap();
+    assert_eq!("c1", arg["cursor"]);
+}
+
+fn rate_limited() -> Response {
+    Response::status(429, r#"{
+        "error_summary": "too_many_requests/",
+        "error": {"reason": {".tag": "too_many_requests"}, "retry_after": 1}
+    }"#)
+}
+
+#[test]
+fn test_file_locks_report() {
+    let client = MockClient::new([rate_limited(), Response::json(r#"{"entries": [
+        {".tag": "file", "name": "a.txt", "path_display": "/A.txt", "id": "id:a",
+            "client_modified": "2020-01-01T00:00:00Z", "server_modified": "2020-01-01T00:00:00Z",
+            "rev": "0123456789abcdef", "size": 5,
+            "file_lock_info": {"is_lockholder": false, "lockholder_name": "Someone",
+                "lockholder_account_id": "dbid:x", "created": "2024-01-01T00:00:00Z"}},
+        {".tag": "file", "name": "b.txt", "path_display": "/b.txt", "id": "id:b",
+            "client_modified": "2020-01-01T00:00:00Z", "server_modified": "2020-01-01T00:00:00Z",
+            "rev": "0123456789abcdef", "size": 5},
+        {".tag": "folder", "name": "c", "id": "id:c"}
+    ], "cursor": "c1", "has_more": false}"#)]);
+    let mut out = vec![];
+    let rows = reports::file_locks_report(&client, "", &mut out, &options(ReportFormat::JsonLines))
+        .now_or_never().unwrap().unwrap();
+    assert_eq!(1, rows);
+    let row: serde_json::Value = serde_json::from_slice(&out).unwrap();
+    assert_eq!(serde_json::json!({
+        "path": "/A.txt",
+        "id": "id:a",
+        "lock_holder_name": "Someone",
+        "lock_holder_account_id": "dbid:x",
+        "created": "2024-01-01T00:00:00Z",
+    }), row);
+    assert!(out.ends_with(b"}\n"));
+    assert_eq!(2, client.requests().len());
+}
+
+#[test]
+fn test_members_report() {
+    let member = |id: &str, status: &str| format!(r#"{{
+        "profile": {{
+            "team_member_id": "{id}",
+            "email": "{id}@example.com",
+            "email_verified": true,
+            "status": {{".tag": "{status}"}},
+            "name": {{"given_name": "", "surname": "", "familiar_name": "", "display_name": "{id}",
+                "abbreviated_name": ""}},
+            "membership_type": {{".tag": "full"}},
+            "groups": [],
+            "member_folder_id": "1"
+        }}
+    }}"#);
+    let client = MockClient::new([
+        Response::json(&format!(r#"{{"members": [{}], "cursor": "c1", "has_more": true}}"#,
+            member("dbmid:a", "active"))),
+        Response::json(&format!(r#"{{"members": [{}], "cursor": "c2", "has_more": false}}"#,
+            member("dbmid:b", "suspended"))),
+    ]);
+    let mut out = vec![];
+    let rows = reports::members_report(&client, &mut out, &options(ReportFormat::JsonLines))
+        .now_or_never().unwrap().unwrap();
+    assert_eq!(2, rows);
+    let rows = String::from_utf8(out).unwrap().lines()
+        .map(|line| serde_json::from_str::<serde_json::Value>(line).unwrap())
+        .collect::<Vec<_>>();
+    assert_eq!("dbmid:a@example.com", rows[0]["email"]);
+    assert_eq!("active", rows[0]["status"]);
+    assert_eq!("suspended", rows[1]["status"]);
+    assert_eq!(serde_json::Value::Null, rows[1]["joined_on"]);
+
+    let arg: serde_json::Value = serde_json::from_slice(&client.requests()[0].body).unwrap();
+    assert_eq!(Some(true), arg["include_removed"].as_bool());
+}
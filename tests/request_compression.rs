@@ -0,0 +1,51 @@
+#![warn(rust_2018_idioms)]
+
+use std::io::Read;
+use dropbox_sdk::files;
+
+mod mock;
+use mock::{MockClient, Response};
+
+const ASYNC_JOB: &str = r#"{".tag": "async_job_id", "async_job_id": "job"}"#;
+
+fn delete_batch_arg(count: usize) -> files::DeleteBatchArg {
+    files::DeleteBatchArg::new(
+        (0..count)
+            .map(|i| files::DeleteArg::new(format!("/some/folder/file {i}.txt")))
+            .collect(),
+    )
+}
+
+#[test]
+fn test_small_request_not_compressed() {
+    let client = MockClient::new([Response::json(ASYNC_JOB)]).with_gzip_threshold(1024);
+    files::delete_batch(&client, &delete_batch_arg(2)).unwrap();
+    let req = &client.requests()[0];
+    assert_eq!(None, req.header("Content-Encoding"));
+    assert_eq!(Some("application/json"), req.header("Content-Type"));
+    serde_json::from_slice::<serde_json::Value>(&req.body).unwrap();
+}
+
+#[test]
+fn test_large_request_compressed() {
+    let arg = delete_batch_arg(1000);
+    let client = MockClient::new([Response::json(ASYNC_JOB)]).with_gzip_threshold(1024);
+    files::delete_batch(&client, &arg).unwrap();
+    let req = &client.requests()[0];
+    assert_eq!(Some("gzip"), req.header("Content-Encoding"));
+    assert_eq!(Some("application/json"), req.header("Content-Type"));
+
+    let mut json = String::new();
+    flate2::read::GzDecoder::new(req.body.as_slice()).read_to_string(&mut json).unwrap();
+    assert!(req.body.len() < json.len() / 4, "{} bytes compressed to {}", json.len(), req.body.len());
+    let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+    assert_eq!(1000, value["entries"].as_array().unwrap().len());
+    assert_eq!("/some/folder/file 999.txt", value["entries"][999]["path"]);
+}
+
+#[test]
+fn test_no_threshold_not_compressed() {
+    let client = MockClient::new([Response::json(ASYNC_JOB)]);
+    files::delete_batch(&client, &delete_batch_arg(1000)).unwrap();
+    assert_eq!(None, client.requests()[0].header("Content-Encoding"));
+}
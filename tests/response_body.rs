@@ -0,0 +1,126 @@
+#![warn(rust_2018_idioms)]
+
+use std::io::Read;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use futures::executor::block_on;
+use std::time::Duration;
+use dropbox_sdk::client_trait_common::{Stalled, CLOSE_DRAIN_LIMIT};
+use dropbox_sdk::files_helpers::TransferError;
+
+/// A body of the given length, which counts how much of it has been read.
+struct CountingBody {
+    remaining: u64,
+    read: Arc<AtomicU64>,
+}
+
+impl CountingBody {
+    fn new(len: u64) -> (Self, Arc<AtomicU64>) {
+        let read = Arc::new(AtomicU64::new(0));
+        (Self { remaining: len, read: Arc::clone(&read) }, read)
+    }
+}
+
+impl Read for CountingBody {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        let n = (buf.len() as u64).min(self.remaining) as usize;
+        buf[..n].fill(0);
+        self.remaining -= n as u64;
+        self.read.fetch_add(n as u64, Ordering::SeqCst);
+        Ok(n)
+    }
+}
+
+fn sync_result(body: CountingBody) -> dropbox_sdk::client_trait::HttpRequestResult<()> {
+    dropbox_sdk::client_trait::HttpRequestResult::new((), Some(Box::new(body)))
+}
+
+fn async_result(body: CountingBody) -> dropbox_sdk::async_client_trait::HttpRequestResult<()> {
+    dropbox_sdk::async_client_trait::HttpRequestResult::new(
+        (), Some(Box::new(futures::io::AllowStdIo::new(body))))
+}
+
+#[test]
+fn test_close_drains_small_body() {
+    let (body, read) = CountingBody::new(1000);
+    let mut result = sync_result(body);
+    result.body.as_mut().unwrap().read_exact(&mut [0; 10]).unwrap();
+    result.close().unwrap();
+    assert!(result.body.is_none());
+    assert_eq!(1000, read.load(Ordering::SeqCst));
+
+    let (body, read) = CountingBody::new(1000);
+    let mut result = async_result(body);
+    block_on(result.close()).unwrap();
+    assert!(result.body.is_none());
+    assert_eq!(1000, read.load(Ordering::SeqCst));
+}
+
+#[test]
+fn test_close_aborts_large_body() {
+    let (body, read) = CountingBody::new(CLOSE_DRAIN_LIMIT * 10);
+    let mut result = sync_result(body);
+    result.close().unwrap();
+    assert!(result.body.is_none());
+    assert_eq!(CLOSE_DRAIN_LIMIT + 1, read.load(Ordering::SeqCst));
+
+    let (body, read) = CountingBody::new(CLOSE_DRAIN_LIMIT * 10);
+    let mut result = async_result(body);
+    block_on(result.close()).unwrap();
+    assert_eq!(CLOSE_DRAIN_LIMIT + 1, read.load(Ordering::SeqCst));
+}
+
+#[test]
+fn test_abort() {
+    let (body, read) = CountingBody::new(1000);
+    let mut result = sync_result(body);
+    result.abort();
+    assert!(result.body.is_none());
+    assert_eq!(0, read.load(Ordering::SeqCst));
+
+    let (body, read) = CountingBody::new(1000);
+    let mut result = async_result(body);
+    result.abort();
+    assert!(result.body.is_none());
+    assert_eq!(0, read.load(Ordering::SeqCst));
+}
+
+#[test]
+fn test_stalled_error() {
+    let stalled = Stalled { timeout: Duration::from_secs(30) };
+    let e = std::io::Error::from(stalled);
+    assert_eq!(std::io::ErrorKind::TimedOut, e.kind());
+    assert_eq!(Some(&stalled), Stalled::from_io_error(&e));
+    assert!(matches!(TransferError::from(e), TransferError::Stalled(s) if s == stalled));
+
+    let e = std::io::Error::new(std::io::ErrorKind::TimedOut, "deadline passed");
+    assert_eq!(None, Stalled::from_io_error(&e));
+    assert!(matches!(TransferError::from(e), TransferError::Io(_)));
+}
+
+#[cfg(feature = "sync_routes")]
+#[test]
+fn test_read_adapters() {
+    use dropbox_sdk::async_client_trait::{ReadyReadAdapter, SyncReadAdapter};
+    use futures::AsyncRead;
+
+    let (body, read) = CountingBody::new(1000);
+    let mut reader = ReadyReadAdapter::new(SyncReadAdapter::new(body));
+    let mut buf = Vec::new();
+    reader.read_to_end(&mut buf).unwrap();
+    assert_eq!(1000, buf.len());
+    assert_eq!(1000, read.load(Ordering::SeqCst));
+
+    struct NeverReady;
+    impl AsyncRead for NeverReady {
+        fn poll_read(
+            self: std::pin::Pin<&mut Self>,
+            _cx: &mut std::task::Context<'_>,
+            _buf: &mut [u8],
+        ) -> std::task::Poll<std::io::Result<usize>> {
+            std::task::Poll::Pending
+        }
+    }
+    let err = ReadyReadAdapter::new(NeverReady).read(&mut [0; 10]).unwrap_err();
+    assert_eq!(std::io::ErrorKind::WouldBlock, err.kind());
+}
@@ -0,0 +1,294 @@
+#![warn(rust_2018_idioms)]
+
+use std::sync::Arc;
+use std::time::Duration;
+use futures::executor::block_on;
+use futures::FutureExt;
+use dropbox_sdk::async_routes::{files, users};
+use dropbox_sdk::client_trait_common::DeadlineExceeded;
+use dropbox_sdk::clock::{Clock, FakeClock};
+use dropbox_sdk::files_helpers::{self, UploadOptions};
+use dropbox_sdk::retry::{self, RetryBudget};
+use dropbox_sdk::{BackoffScope, RateLimitCategory};
+
+mod mock;
+use mock::{FILE_METADATA, MockClient, Response, file_entry};
+
+const SPACE_USAGE: &str = r#"{"used": 1, "allocation": {".tag": "individual", "allocated": 2}}"#;
+
+fn rate_limited() -> Response {
+    Response::status(429, r#"{
+        "error_summary": "too_many_requests/",
+        "error": {"reason": {".tag": "too_many_requests"}, "retry_after": 0}
+    }"#)
+}
+
+#[test]
+fn test_budget_shared_across_requests() {
+    let client = MockClient::new([
+        rate_limited(),
+        Response::json(FILE_METADATA),
+        rate_limited(),
+        rate_limited(),
+        Response::status(400, "bad request"),
+    ]);
+    let arg = files::UploadArg::new("/a.txt".to_owned());
+    let retry = RetryBudget::new(2).start();
+
+    block_on(retry.call(|| files::upload(&client, &arg, b"hello".to_vec().into()))).unwrap();
+    assert_eq!(1, retry.retries());
+
+    // The second request gets the one retry left.
+    let err = block_on(retry.call(|| files::upload(&client, &arg, b"hello".to_vec().into())))
+        .unwrap_err();
+    assert!(matches!(err, dropbox_sdk::Error::RateLimited { .. }), "wrong error: {:?}", err);
+    assert_eq!(2, retry.retries());
+    assert_eq!(4, client.requests().len());
+
+    // Other errors aren't retried.
+    let retry = RetryBudget::new(2).start();
+    let err = block_on(retry.call(|| files::upload(&client, &arg, b"hello".to_vec().into())))
+        .unwrap_err();
+    assert!(matches!(err, dropbox_sdk::Error::BadRequest(_)), "wrong error: {:?}", err);
+    assert_eq!(0, retry.retries());
+}
+
+#[test]
+fn test_budget_max_elapsed() {
+    let client = MockClient::new([Response::status(500, "oops")]);
+    let arg = files::UploadArg::new("/a.txt".to_owned());
+
+    // Waiting a second to retry would take too long.
+    let retry = RetryBudget::new(5).with_max_elapsed(Duration::from_millis(500)).start();
+    let err = block_on(retry.call(|| files::upload(&client, &arg, b"hello".to_vec().into())))
+        .unwrap_err();
+    assert!(matches!(err, dropbox_sdk::Error::ServerError(_)), "wrong error: {:?}", err);
+    assert_eq!(0, retry.retries());
+}
+
+#[test]
+fn test_upload_retry() {
+    let client = MockClient::new([rate_limited(), Response::json(FILE_METADATA)]);
+    block_on(files_helpers::upload(
+        &client,
+        files::CommitInfo::new("/a.txt".to_owned()),
+        &b"hello"[..],
+        &UploadOptions::default().with_retry_budget(RetryBudget::new(1)),
+    )).unwrap();
+
+    let requests = client.requests();
+    assert_eq!(2, requests.len());
+    assert_eq!(requests[0].body, requests[1].body);
+}
+
+#[test]
+fn test_backoff_with_fake_clock() {
+    let client = MockClient::new(std::iter::repeat_with(|| Response::status(500, "oops")).take(6));
+    let arg = files::UploadArg::new("/a.txt".to_owned());
+    let clock = Arc::new(FakeClock::new());
+    let retry = RetryBudget::new(10)
+        .with_max_elapsed(Duration::from_secs(20))
+        .with_clock(clock.clone())
+        .start();
+
+    // No timer is needed, so this completes without waiting.
+    let err = retry.call(|| files::upload(&client, &arg, b"hello".to_vec().into()))
+        .now_or_never().unwrap().unwrap_err();
+    assert!(matches!(err, dropbox_sdk::Error::ServerError(_)), "wrong error: {:?}", err);
+
+    // The waits double each time, until waiting again would take more than 20s in total.
+    let sleeps = clock.sleeps();
+    assert_eq!([1, 2, 4, 8].map(Duration::from_secs).to_vec(), sleeps);
+    assert_eq!(Duration::from_secs(15), clock.elapsed());
+    assert_eq!(4, retry.retries());
+    assert_eq!(5, client.requests().len());
+
+    // Time passing between requests counts too.
+    let retry = RetryBudget::new(10)
+        .with_max_elapsed(Duration::from_secs(20))
+        .with_clock(clock.clone())
+        .start();
+    clock.advance(Duration::from_secs(20));
+    retry.call(|| files::upload(&client, &arg, b"hello".to_vec().into()))
+        .now_or_never().unwrap().unwrap_err();
+    assert_eq!(0, retry.retries());
+    assert_eq!(6, client.requests().len());
+}
+
+#[test]
+fn test_rate_limit_categories() {
+    let contended = Response::status(429, r#"{
+        "error_summary": "too_many_write_operations/",
+        "error": {"reason": {".tag": "too_many_write_operations"}, "retry_after": 3}
+    }"#);
+    let client = MockClient::new([
+        contended,
+        Response::json(FILE_METADATA),
+        Response::json(SPACE_USAGE),
+        Response::json(&file_entry()),
+        Response::status(429, r#"{
+            "error_summary": "too_many_requests/",
+            "error": {"reason": {".tag": "too_many_requests"}, "retry_after": 2}
+        }"#),
+        Response::json(SPACE_USAGE),
+    ]);
+    let clock = Arc::new(FakeClock::new());
+    let retry = RetryBudget::new(0).with_clock(clock.clone()).start();
+    let arg = files::UploadArg::new("/a.txt".to_owned());
+    let upload = || files::upload(&client, &arg, b"hello".to_vec().into());
+    let space_usage = || users::get_space_usage(&client);
+
+    // Write contention holds back other requests to the same namespace, even though the one
+    // which ran into it isn't retried.
+    let err = retry.call_route(files::routes::UPLOAD, upload).now_or_never().unwrap().unwrap_err();
+    assert_eq!(Some(RateLimitCategory::TooManyWriteOperations), err.rate_limit_category());
+    assert_eq!(0, retry.retries());
+    retry.call(upload).now_or_never().unwrap().unwrap();
+    retry.call_route(users::routes::GET_SPACE_USAGE, space_usage).now_or_never().unwrap().unwrap();
+    assert!(clock.sleeps().is_empty());
+    let metadata_arg = files::GetMetadataArg::new("/a.txt".to_owned());
+    retry.call_route(files::routes::GET_METADATA, || files::get_metadata(&client, &metadata_arg))
+        .now_or_never().unwrap().unwrap();
+    assert_eq!(vec![Duration::from_secs(3)], clock.sleeps());
+
+    // Too many requests holds back everything.
+    let err = retry.call(upload).now_or_never().unwrap().unwrap_err();
+    assert_eq!(Some(RateLimitCategory::TooManyRequests), err.rate_limit_category());
+    retry.call_route(users::routes::GET_SPACE_USAGE, space_usage).now_or_never().unwrap().unwrap();
+    assert_eq!(vec![Duration::from_secs(3), Duration::from_secs(2)], clock.sleeps());
+    assert_eq!(6, client.requests().len());
+
+    // Contention nested within an API error counts too.
+    let err = dropbox_sdk::Error::Api(files::UploadError::Path(files::UploadWriteFailed::new(
+        files::WriteError::TooManyWriteOperations, "session".to_owned())));
+    assert_eq!(Some(RateLimitCategory::TooManyWriteOperations), err.rate_limit_category());
+    assert_eq!(None, dropbox_sdk::Error::<files::UploadError>::BadRequest(String::new())
+        .rate_limit_category());
+
+    let scope = RateLimitCategory::TooManyWriteOperations.backoff_scope("files/upload");
+    assert_eq!(BackoffScope::Namespace("files".to_owned()), scope);
+    assert!(scope.applies_to("files/get_metadata"));
+    assert!(!scope.applies_to("filesx/get_metadata"));
+    assert!(!scope.applies_to("users/get_current_account"));
+    assert_eq!(BackoffScope::Global,
+        RateLimitCategory::TooManyRequests.backoff_scope("files/upload"));
+    assert!(BackoffScope::Global.applies_to("users/get_current_account"));
+}
+
+#[test]
+fn test_budget_deadline() {
+    let client = MockClient::new(std::iter::repeat_with(|| Response::status(500, "oops")).take(3));
+    let arg = files::UploadArg::new("/a.txt".to_owned());
+    let clock = Arc::new(FakeClock::new());
+    let retry = RetryBudget::new(10)
+        .with_deadline(clock.now() + Duration::from_secs(5))
+        .with_clock(clock.clone())
+        .start();
+
+    // Waiting 4s to retry would be past the deadline, so the last error is returned.
+    let err = retry.call(|| files::upload(&client, &arg, b"hello".to_vec().into()))
+        .now_or_never().unwrap().unwrap_err();
+    assert!(matches!(err, dropbox_sdk::Error::ServerError(_)), "wrong error: {:?}", err);
+    assert_eq!([1, 2].map(Duration::from_secs).to_vec(), clock.sleeps());
+    assert_eq!(3, client.requests().len());
+
+    // Once it has passed, no more requests are made.
+    clock.advance(Duration::from_secs(2));
+    let err = retry.call(|| files::upload(&client, &arg, b"hello".to_vec().into()))
+        .now_or_never().unwrap().unwrap_err();
+    assert_eq!(Some(&DeadlineExceeded), DeadlineExceeded::from_error(&err));
+    assert_eq!(3, client.requests().len());
+
+    // A request which doesn't complete in time is abandoned.
+    let retry = RetryBudget::new(0)
+        .with_deadline(clock.now() + Duration::from_secs(5))
+        .with_clock(clock.clone())
+        .start();
+    let err = retry.call(futures::future::pending::<Result<(), dropbox_sdk::Error>>)
+        .now_or_never().unwrap().unwrap_err();
+    assert_eq!(Some(&DeadlineExceeded), DeadlineExceeded::from_error(&err));
+    assert_eq!(Duration::from_secs(5), *clock.sleeps().last().unwrap());
+}
+
+#[test]
+fn test_client_total_timeout() {
+    let client = MockClient::new([Response::json(FILE_METADATA)])
+        .with_total_timeout(Duration::from_secs(30));
+    let arg = files::UploadArg::new("/a.txt".to_owned());
+    block_on(files::upload(&client, &arg, b"hello".to_vec().into())).unwrap();
+    let timeout = client.requests()[0].timeout.unwrap();
+    assert!(timeout <= Duration::from_secs(30) && timeout > Duration::from_secs(25),
+        "wrong timeout: {:?}", timeout);
+
+    let client = MockClient::new([]).with_total_timeout(Duration::ZERO);
+    let err = block_on(files::upload(&client, &arg, b"hello".to_vec().into())).unwrap_err();
+    assert!(DeadlineExceeded::from_error(&err).is_some(), "wrong error: {:?}", err);
+    assert!(client.requests().is_empty());
+}
+
+#[test]
+fn test_service_unavailable() {
+    let client = MockClient::new([
+        Response::status(503, "down for maintenance").with_retry_after("120"),
+        Response::json(FILE_METADATA),
+        Response::status(503, "oops"),
+    ]);
+    let arg = files::UploadArg::new("/a.txt".to_owned());
+    let clock = Arc::new(FakeClock::new());
+    let retry = RetryBudget::new(1).with_clock(clock.clone()).start();
+
+    // It waits as long as the server said.
+    retry.call(|| files::upload(&client, &arg, b"hello".to_vec().into()))
+        .now_or_never().unwrap().unwrap();
+    assert_eq!(vec![Duration::from_secs(120)], clock.sleeps());
+
+    // Without a Retry-After header, it's a plain server error.
+    let err = files::upload(&client, &arg, b"hello".to_vec().into()).now_or_never().unwrap()
+        .unwrap_err();
+    assert!(matches!(err, dropbox_sdk::Error::ServerError(_)), "wrong error: {:?}", err);
+}
+
+#[test]
+fn test_retry_after_helper() {
+    let rate_limited = || Response::status(429, r#"{
+        "error_summary": "too_many_requests/",
+        "error": {"reason": {".tag": "too_many_requests"}, "retry_after": 2}
+    }"#);
+    let client = MockClient::new([
+        rate_limited(),
+        Response::json(FILE_METADATA),
+        rate_limited(),
+        rate_limited(),
+        rate_limited(),
+        Response::status(400, "bad request"),
+    ]);
+    let arg = files::UploadArg::new("/a.txt".to_owned());
+    let clock = FakeClock::new();
+    let upload = || block_on(async {
+        let mut attempts = 0;
+        loop {
+            attempts += 1;
+            match files::upload(&client, &arg, b"hello".to_vec().into()).await {
+                Err(e) if attempts < 3 && retry::retry_after(&e, &clock).await => continue,
+                result => break result,
+            }
+        }
+    });
+
+    assert_eq!("a.txt", upload().unwrap().name);
+    // It waits on the clock as long as the server said, plus up to a quarter as long again.
+    let sleeps = clock.sleeps();
+    assert_eq!(1, sleeps.len());
+    assert!(sleeps[0] >= Duration::from_secs(2) && sleeps[0] <= Duration::from_millis(2500),
+        "{:?}", sleeps);
+
+    // The loop gives up after its last attempt.
+    assert!(upload().is_err());
+    assert_eq!(3, clock.sleeps().len());
+
+    // Errors which shouldn't be retried are returned without waiting.
+    let err = files::upload(&client, &arg, b"hello".to_vec().into()).now_or_never().unwrap()
+        .unwrap_err();
+    assert!(!retry::retry_after(&err, &clock).now_or_never().unwrap());
+    assert_eq!(3, clock.sleeps().len());
+}
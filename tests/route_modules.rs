@@ -0,0 +1,31 @@
+#![warn(rust_2018_idioms)]
+
+//! The `sync` and `asynch` modules have the same routes whichever routes are in the crate root.
+
+use futures::FutureExt;
+use dropbox_sdk::asynch::{self, files};
+use dropbox_sdk::sync;
+
+mod mock;
+use mock::{MockClient, Response, file_entry};
+
+fn get_metadata<C: asynch::UserAuthClient>(client: &C) -> files::Metadata {
+    let arg = files::GetMetadataArg::new("/a.txt".to_owned());
+    asynch::files::get_metadata(client, &arg).now_or_never().unwrap().unwrap()
+}
+
+/// The sync routes take the sync client traits, and use the same types. This is only compiled,
+/// as the mock client is async.
+#[allow(dead_code)]
+fn sync_get_metadata<C: sync::UserAuthClient>(client: &C)
+    -> Result<files::Metadata, dropbox_sdk::Error<files::GetMetadataError>>
+{
+    let arg = sync::files::GetMetadataArg::new("/a.txt".to_owned());
+    sync::files::get_metadata(client, &arg)
+}
+
+#[test]
+fn test_route_modules() {
+    let client = MockClient::new([Response::json(&file_entry())]);
+    assert!(matches!(get_metadata(&client), files::Metadata::File(_)));
+}
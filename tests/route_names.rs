@@ -0,0 +1,30 @@
+#![warn(rust_2018_idioms)]
+
+use futures::executor::block_on;
+use dropbox_sdk::async_routes::{files, users};
+use dropbox_sdk::client_trait_common::Endpoint;
+
+mod mock;
+use mock::{MockClient, Response};
+
+#[test]
+fn test_route_names() {
+    assert_eq!("files/list_folder", files::routes::LIST_FOLDER);
+    assert_eq!("files/list_folder/continue", files::routes::LIST_FOLDER_CONTINUE);
+    assert_eq!("files/copy_v2", files::routes::COPY_V2);
+    assert_eq!("users/get_current_account", users::routes::GET_CURRENT_ACCOUNT);
+}
+
+#[test]
+fn test_route_from_url() {
+    let client = MockClient::new([Response::status(500, "oops")]);
+    let arg = files::ListFolderArg::new(String::new());
+    block_on(files::list_folder(&client, &arg)).unwrap_err();
+    let url = &client.requests()[0].url;
+    assert_eq!(Some((Endpoint::Api, files::routes::LIST_FOLDER)), Endpoint::route_from_url(url));
+
+    assert_eq!(Some((Endpoint::Content, files::routes::DOWNLOAD)),
+        Endpoint::route_from_url("https://content.dropboxapi.com/2/files/download?arg=%7B%7D"));
+    assert_eq!(None, Endpoint::route_from_url("https://api.dropboxapi.com/oauth2/token"));
+    assert_eq!(None, Endpoint::route_from_url("https://example.com/2/files/download"));
+}
@@ -0,0 +1,136 @@
+#![warn(rust_2018_idioms)]
+
+//! Compile-time checks that the clients can be shared between threads, and that the futures and
+//! streams returned by the routes and helpers can be used with multi-threaded executors such as
+//! `tokio::spawn`. The futures are created but never polled, so no requests are made.
+
+use dropbox_sdk::oauth2::{Authorization, TokenCache};
+
+fn assert_send<T: Send>(_: &T) {}
+fn assert_sync<T: Sync>(_: &T) {}
+fn assert_send_sync<T: Send + Sync>(_: &T) {}
+fn assert_error<T: std::error::Error + Send + Sync + 'static>() {}
+
+fn auth() -> Authorization {
+    Authorization::from_refresh_token("client".to_owned(), "refresh".to_owned())
+}
+
+#[test]
+fn test_token_cache() {
+    let cache = TokenCache::new(auth());
+    assert_send(&cache);
+    assert_sync(&cache);
+}
+
+#[test]
+fn test_errors() {
+    assert_error::<dropbox_sdk::Error>();
+    assert_error::<dropbox_sdk::BoxedError>();
+    assert_error::<dropbox_sdk::Error<dropbox_sdk::auth::AuthError>>();
+    #[cfg(feature = "dbx_files")]
+    {
+        assert_error::<dropbox_sdk::Error<dropbox_sdk::files::DownloadError>>();
+        assert_error::<dropbox_sdk::files_helpers::TransferError>();
+    }
+    #[cfg(feature = "dbx_team")]
+    assert_error::<dropbox_sdk::team_helpers::OffboardError>();
+    #[cfg(feature = "disk_cache")]
+    assert_error::<dropbox_sdk::disk_cache::DiskCacheError>();
+    #[cfg(feature = "mirror")]
+    assert_error::<dropbox_sdk::mirror::MirrorError>();
+
+    // Boxed errors can be returned from other threads, and converted with `?`.
+    fn fails() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        let error = dropbox_sdk::Error::<dropbox_sdk::auth::AuthError>::Api(
+            dropbox_sdk::auth::AuthError::ExpiredAccessToken);
+        Err(error.boxed())?;
+        Ok(())
+    }
+    let result = std::thread::spawn(fails).join().unwrap();
+    let error = result.unwrap_err();
+    let boxed = error.downcast_ref::<dropbox_sdk::BoxedError>().unwrap();
+    assert!(matches!(
+        boxed.downcast_ref_inner::<dropbox_sdk::auth::AuthError>(),
+        Some(dropbox_sdk::auth::AuthError::ExpiredAccessToken)));
+}
+
+#[cfg(feature = "default_client")]
+#[test]
+fn test_default_client() {
+    use dropbox_sdk::default_client::*;
+    use dropbox_sdk::files;
+
+    let user = UserAuthDefaultClient::new(auth());
+    assert_send_sync(&user);
+    assert_send_sync(&TeamAuthDefaultClient::new(TokenCache::new(auth())));
+    assert_send_sync(&NoauthDefaultClient::default());
+    assert_send_sync(&AppAuthDefaultClient::new("key", "secret"));
+
+    // The async helpers can be used with the sync clients too.
+    assert_send(&dropbox_sdk::files_helpers::metadata_opt(&user, "/a"));
+    let arg = files::ListFolderArg::new("/a".to_owned());
+    let options = dropbox_sdk::files_helpers::ListFolderOptions::default();
+    let stream = dropbox_sdk::files_helpers::list_folder(&user, arg, &options);
+    assert_send(&stream);
+}
+
+#[cfg(feature = "default_async_client")]
+#[test]
+fn test_default_async_client() {
+    use dropbox_sdk::async_routes::files;
+    use dropbox_sdk::default_async_client::*;
+
+    let user = UserAuthDefaultClient::new(auth());
+    let team = TeamAuthDefaultClient::new(TokenCache::new(auth()));
+    let noauth = NoauthDefaultClient::default();
+    let app = AppAuthDefaultClient::new("key", "secret");
+    assert_send_sync(&user);
+    assert_send_sync(&team);
+    assert_send_sync(&noauth);
+    assert_send_sync(&app);
+
+    // Routes
+    let arg = files::ListFolderArg::new("/a".to_owned());
+    assert_send(&files::list_folder(&user, &arg));
+    let download_arg = files::DownloadArg::new("/a".to_owned());
+    assert_send(&files::download(&user, &download_arg, None, None));
+    let upload_arg = files::UploadArg::new("/a".to_owned());
+    assert_send(&files::upload(&user, &upload_arg, bytes::Bytes::from_static(b"data")));
+
+    // Helpers
+    use dropbox_sdk::files_helpers;
+    let options = files_helpers::ListFolderOptions::default();
+    assert_send(&files_helpers::list_folder(&user, arg.clone(), &options));
+    assert_send(&files_helpers::list_folder_raw(&user, arg, &options));
+    assert_send(&files_helpers::metadata_opt(&user, "/a"));
+    assert_send(&files_helpers::ensure_folders(&user, "/a/b"));
+    assert_send(&files_helpers::get_file_locks(&user, ["/a"]));
+    assert_send(&files_helpers::longpoll(&noauth, "cursor", std::time::Duration::from_secs(30)));
+    let commit = files::CommitInfo::new("/a".to_owned());
+    let upload_options = files_helpers::UploadOptions::default();
+    assert_send(&files_helpers::upload_file(&user, "a", commit.clone(), &upload_options));
+    assert_send(&files_helpers::upload_file_if_changed(
+        &user, "a", commit.clone(), &upload_options));
+    assert_send(&files_helpers::upload(&user, commit, &b"data"[..], &upload_options));
+
+    let link = files::SharedLinkFileInfo::new("https://www.dropbox.com/s/abc".to_owned());
+    assert_send(&dropbox_sdk::sharing_helpers::shared_link_metadata(&app, &link));
+    assert_send(&dropbox_sdk::sharing_helpers::list_all_folder_members(&user, "123"));
+    let offboard_options = dropbox_sdk::team_helpers::OffboardOptions::default();
+    assert_send(&dropbox_sdk::team_helpers::offboard_member(
+        &team, "dbmid:a", None, None, &offboard_options));
+}
+
+#[cfg(all(feature = "default_async_client", feature = "disk_cache"))]
+#[test]
+fn test_disk_cache() {
+    use dropbox_sdk::default_async_client::UserAuthDefaultClient;
+
+    let dir = std::env::temp_dir().join(format!("dropbox-sdk-test-send-{}", std::process::id()));
+    let cache = dropbox_sdk::disk_cache::DiskCache::open(&dir).unwrap();
+    assert_sync(&cache);
+    let user = UserAuthDefaultClient::new(auth());
+    assert_send(&cache.get_or_download(&user, "/a"));
+    drop(cache);
+    std::fs::remove_dir_all(&dir).unwrap();
+}
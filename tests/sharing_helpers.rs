@@ -0,0 +1,310 @@
+#![warn(rust_2018_idioms)]
+
+use futures::FutureExt;
+use dropbox_sdk::files;
+use dropbox_sdk::sharing;
+use dropbox_sdk::sharing_helpers;
+
+mod mock;
+use mock::{MockClient, Response};
+
+const URL: &str = "https://www.dropbox.com/s/abc/photo.jpg";
+
+#[test]
+fn test_shared_link_thumbnail() {
+    let client = MockClient::new([Response::download(
+        &format!(r#"{{"link_metadata": {{"url": "{}", "rev": "0123456789abcdef"}}}}"#, URL),
+        b"PNG DATA",
+    )]);
+    let link = files::SharedLinkFileInfo::new(URL.to_owned()).with_password("hunter2".to_owned());
+    let (metadata, image) = sharing_helpers::shared_link_thumbnail(
+        &client, &link, files::ThumbnailSize::W128h128, files::ThumbnailFormat::Png,
+    ).now_or_never().unwrap().unwrap();
+    assert_eq!(URL, metadata.url);
+    assert_eq!(b"PNG DATA", image.as_slice());
+
+    let request = &client.requests()[0];
+    assert!(request.url.ends_with("/files/get_thumbnail_v2"));
+    let arg: serde_json::Value = serde_json::from_str(request.header("Dropbox-API-Arg").unwrap()).unwrap();
+    assert_eq!("link", arg["resource"][".tag"]);
+    assert_eq!(URL, arg["resource"]["url"]);
+    assert_eq!("hunter2", arg["resource"]["password"]);
+    assert_eq!("w128h128", arg["size"][".tag"]);
+}
+
+#[test]
+fn test_shared_link_metadata() {
+    let client = MockClient::new([Response::json(&format!(r#"{{
+        ".tag": "folder",
+        "url": "{}",
+        "name": "stuff",
+        "link_permissions": {{
+            "can_revoke": false,
+            "visibility_policies": [],
+            "can_set_expiry": false,
+            "can_remove_expiry": false,
+            "allow_download": true,
+            "can_allow_download": false,
+            "can_disallow_download": false,
+            "allow_comments": false,
+            "team_restricts_comments": false
+        }}
+    }}"#, URL))]);
+    let link = files::SharedLinkFileInfo::new(URL.to_owned()).with_path("/sub".to_owned());
+    match sharing_helpers::shared_link_metadata(&client, &link).now_or_never().unwrap() {
+        Ok(sharing::SharedLinkMetadata::Folder(folder)) => assert_eq!("stuff", folder.name),
+        other => panic!("unexpected result: {:?}", other),
+    }
+    let body: serde_json::Value = serde_json::from_slice(&client.requests()[0].body).unwrap();
+    assert_eq!("/sub", body["path"]);
+}
+
+#[test]
+fn test_list_all_folder_members() {
+    let user = r#"{
+        "access_type": {".tag": "owner"},
+        "user": {
+            "account_id": "dbid:AAH4f99T0taONIb-OurWxbNQ6ywGRopQngc",
+            "email": "justin@example.com",
+            "display_name": "Justin Bieber",
+            "same_team": false
+        },
+        "is_inherited": false
+    }"#;
+    let group = r#"{
+        "access_type": {".tag": "editor"},
+        "group": {
+            "group_name": "Test group",
+            "group_id": "g:e2db7665347abcd600000000001a2b3c",
+            "group_management_type": {".tag": "user_managed"},
+            "group_type": {".tag": "user_managed"},
+            "is_member": false,
+            "is_owner": false,
+            "same_team": true
+        },
+        "is_inherited": true
+    }"#;
+    let invitee = r#"{
+        "access_type": {".tag": "viewer"},
+        "invitee": {".tag": "email", "email": "jessica@example.com"},
+        "is_inherited": false
+    }"#;
+    let client = MockClient::new([
+        Response::json(&format!(
+            r#"{{"users": [{}], "groups": [], "invitees": [{}], "cursor": "c1"}}"#, user, invitee)),
+        Response::json(&format!(
+            r#"{{"users": [], "groups": [{}], "invitees": [{}]}}"#, group, invitee)),
+    ]);
+
+    let members = sharing_helpers::list_all_folder_members(&client, "84528192421")
+        .now_or_never().unwrap().unwrap();
+    let summary = members.iter()
+        .map(|m| {
+            let kind = match m {
+                sharing_helpers::FolderMember::User(_) => "user",
+                sharing_helpers::FolderMember::Group(_) => "group",
+                sharing_helpers::FolderMember::Invitee(_) => "invitee",
+            };
+            (kind, m.access_type().clone(), m.is_inherited())
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(vec![
+        ("user", sharing::AccessLevel::Owner, false),
+        ("group", sharing::AccessLevel::Editor, true),
+        ("invitee", sharing::AccessLevel::Viewer, false),
+        ("invitee", sharing::AccessLevel::Viewer, false),
+    ], summary);
+
+    let requests = client.requests();
+    assert!(requests[1].url.ends_with("/sharing/list_folder_members/continue"));
+    let arg: serde_json::Value = serde_json::from_slice(&requests[1].body).unwrap();
+    assert_eq!("c1", arg["cursor"]);
+}
+
+#[test]
+fn test_file_sharing_metadata() {
+    let shared_file = |i: usize| format!(r#"{{
+        "file": "/file{i}.txt",
+        "result": {{
+            ".tag": "metadata",
+            "id": "id:{i}",
+            "name": "file{i}.txt",
+            "policy": {{
+                "acl_update_policy": {{".tag": "owner"}},
+                "shared_link_policy": {{".tag": "anyone"}}
+            }},
+            "preview_url": "https://www.dropbox.com/scl/fi/{i}",
+            "permissions": [
+                {{"action": {{".tag": "edit_contents"}}, "allow": true}},
+                {{"action": {{".tag": "unshare"}}, "allow": false,
+                    "reason": {{".tag": "user_not_owner"}}}}
+            ]
+        }}
+    }}"#);
+    let first = (0 .. 100).map(shared_file).collect::<Vec<_>>().join(",");
+    let client = MockClient::new([
+        Response::json(&format!("[{first}]")),
+        Response::json(r#"[{
+            "file": "/missing.txt",
+            "result": {".tag": "access_error", "access_error": {".tag": "invalid_file"}}
+        }]"#),
+    ]);
+
+    let mut files = (0 .. 100).map(|i| format!("/file{i}.txt")).collect::<Vec<_>>();
+    files.push("/missing.txt".to_owned());
+    let actions = [sharing::FileAction::EditContents, sharing::FileAction::Unshare];
+    let results = sharing_helpers::file_sharing_metadata(&client, &files, &actions)
+        .now_or_never().unwrap().unwrap();
+    assert_eq!(101, results.len());
+
+    let metadata = results["/file42.txt"].as_ref().unwrap();
+    assert_eq!("id:42", metadata.id);
+    assert_eq!(Some(true), metadata.allows(&sharing::FileAction::EditContents));
+    assert_eq!(Some(false), metadata.allows(&sharing::FileAction::Unshare));
+    assert_eq!(None, metadata.allows(&sharing::FileAction::InviteViewer));
+    assert_eq!(Err(sharing::SharingFileAccessError::InvalidFile), results["/missing.txt"]);
+
+    let requests = client.requests();
+    assert_eq!(2, requests.len());
+    assert!(requests[0].url.ends_with("/sharing/get_file_metadata/batch"));
+    let arg: serde_json::Value = serde_json::from_slice(&requests[0].body).unwrap();
+    assert_eq!(100, arg["files"].as_array().unwrap().len());
+    assert_eq!("unshare", arg["actions"][1][".tag"]);
+    let arg: serde_json::Value = serde_json::from_slice(&requests[1].body).unwrap();
+    assert_eq!(serde_json::json!(["/missing.txt"]), arg["files"]);
+}
+
+fn shared_folder(id: &str, path: Option<&str>) -> String {
+    format!(r#"{{
+        "access_type": {{".tag": "editor"}},
+        "is_inside_team_folder": false,
+        "is_team_folder": false,
+        "name": "Folder {id}",
+        "policy": {{
+            "acl_update_policy": {{".tag": "editors"}},
+            "shared_link_policy": {{".tag": "anyone"}}
+        }},
+        "preview_url": "https://www.dropbox.com/scl/fo/{id}",
+        "shared_folder_id": "{id}",
+        "time_invited": "2024-01-02T03:04:05Z",
+        "owner_display_names": ["Owner"]
+        {}
+    }}"#, path.map(|p| format!(r#", "path_lower": "{p}""#)).unwrap_or_default())
+}
+
+#[test]
+fn test_invitations() {
+    let client = MockClient::new([
+        Response::json(&format!(r#"{{"entries": [{}, {}], "cursor": "c1"}}"#,
+            shared_folder("1", Some("/folder 1")), shared_folder("2", None))),
+        Response::json(&format!(r#"{{"entries": [{}]}}"#, shared_folder("3", None))),
+        Response::json(&shared_folder("2", Some("/folder 2"))),
+    ]);
+    let invitations = sharing_helpers::list_invitations(&client).now_or_never().unwrap().unwrap();
+    assert_eq!(
+        vec!["2", "3"],
+        invitations.iter().map(|i| i.shared_folder_id.as_str()).collect::<Vec<_>>());
+    assert_eq!("Folder 2", invitations[0].name);
+    assert_eq!(sharing::AccessLevel::Editor, invitations[0].access_type);
+    assert_eq!(vec!["Owner".to_owned()], invitations[0].owner_display_names);
+    assert_eq!("2024-01-02T03:04:05Z", invitations[0].time_invited);
+
+    let mounted = sharing_helpers::accept_invitation(&client, &invitations[0])
+        .now_or_never().unwrap().unwrap();
+    assert_eq!(Some("/folder 2"), mounted.path_lower.as_deref());
+
+    let requests = client.requests();
+    let urls = requests.iter()
+        .map(|r| r.route())
+        .collect::<Vec<_>>();
+    assert_eq!(vec![
+        "sharing/list_mountable_folders",
+        "sharing/list_mountable_folders/continue",
+        "sharing/mount_folder",
+    ], urls);
+    let arg: serde_json::Value = serde_json::from_slice(&requests[1].body).unwrap();
+    assert_eq!("c1", arg["cursor"]);
+    let arg: serde_json::Value = serde_json::from_slice(&requests[2].body).unwrap();
+    assert_eq!("2", arg["shared_folder_id"]);
+}
+
+fn folder_member(account_id: &str, team_member_id: &str, access: &str) -> String {
+    format!(r#"{{
+        "access_type": {{".tag": "{access}"}},
+        "user": {{
+            "account_id": "{account_id}",
+            "email": "{account_id}@example.com",
+            "display_name": "{account_id}",
+            "same_team": true,
+            "team_member_id": "{team_member_id}"
+        }},
+        "is_inherited": false
+    }}"#)
+}
+
+fn folder_members() -> Response {
+    Response::json(&format!(r#"{{"users": [{}, {}], "groups": [], "invitees": []}}"#,
+        folder_member("dbid:owner", "dbmid:owner", "owner"),
+        folder_member("dbid:new", "dbmid:new", "editor")))
+}
+
+#[test]
+fn test_transfer_folder_ownership() {
+    let client = MockClient::new([
+        folder_members(),
+        Response::json("null"),
+        Response::json(r#"{".tag": "async_job_id", "async_job_id": "job1"}"#),
+        Response::json(r#"{".tag": "in_progress"}"#),
+        Response::json(r#"{".tag": "complete"}"#),
+    ]);
+    let options = sharing_helpers::OwnershipTransferOptions::default()
+        .with_relinquish(true)
+        .with_poll_interval(std::time::Duration::ZERO);
+    let outcome = futures::executor::block_on(sharing_helpers::transfer_folder_ownership(
+        &client, "84528192421", "dbmid:new", &options)).unwrap();
+    assert_eq!("dbid:new", outcome.new_owner_account_id);
+    assert!(outcome.relinquished);
+    assert_eq!(Some("job1"), outcome.job_id.as_deref());
+
+    let requests = client.requests();
+    assert_eq!(5, requests.len());
+    assert!(requests[1].url.ends_with("/sharing/transfer_folder"));
+    let arg: serde_json::Value = serde_json::from_slice(&requests[1].body).unwrap();
+    assert_eq!("dbmid:new", arg["to_dropbox_id"]);
+    assert!(requests[2].url.ends_with("/sharing/relinquish_folder_membership"));
+    let arg: serde_json::Value = serde_json::from_slice(&requests[2].body).unwrap();
+    assert_eq!("84528192421", arg["shared_folder_id"]);
+    assert!(requests[4].url.ends_with("/sharing/check_job_status"));
+}
+
+#[test]
+fn test_transfer_folder_ownership_not_a_member() {
+    let client = MockClient::new([folder_members()]);
+    let err = sharing_helpers::transfer_folder_ownership(
+        &client, "84528192421", "dbid:stranger", &Default::default())
+        .now_or_never().unwrap().unwrap_err();
+    assert!(matches!(err, sharing_helpers::OwnershipTransferError::Transfer(
+        dropbox_sdk::Error::Api(sharing::TransferFolderError::NewOwnerNotAMember))),
+        "wrong error: {:?}", err);
+    assert_eq!(1, client.requests().len());
+}
+
+#[test]
+fn test_transfer_folder_ownership_job_failed() {
+    let client = MockClient::new([
+        folder_members(),
+        Response::json("null"),
+        Response::json(r#"{".tag": "async_job_id", "async_job_id": "job1"}"#),
+        Response::json(r#"{
+            ".tag": "failed",
+            "failed": {".tag": "relinquish_folder_membership_error", "relinquish_folder_membership_error": {".tag": "mounted"}}
+        }"#),
+    ]);
+    let options = sharing_helpers::OwnershipTransferOptions::default()
+        .with_relinquish(true)
+        .with_poll_interval(std::time::Duration::ZERO);
+    let err = futures::executor::block_on(sharing_helpers::transfer_folder_ownership(
+        &client, "84528192421", "dbid:new", &options)).unwrap_err();
+    assert!(matches!(err, sharing_helpers::OwnershipTransferError::JobFailed { ref job_id, .. }
+        if job_id == "job1"), "wrong error: {:?}", err);
+}
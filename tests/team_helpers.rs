@@ -0,0 +1,229 @@
+#![warn(rust_2018_idioms)]
+
+use std::time::Duration;
+use futures::executor::block_on;
+use dropbox_sdk::team::{self, MembersRemoveError};
+use dropbox_sdk::team_helpers::{
+    offboard_member, Groups, MemberResolver, OffboardError, OffboardOptions,
+};
+use dropbox_sdk::Error;
+
+mod mock;
+use mock::{MockClient, Response};
+
+#[test]
+fn test_offboard_polls_job() {
+    let client = MockClient::new([
+        Response::json(r#"{".tag": "async_job_id", "async_job_id": "job1"}"#),
+        Response::json(r#"{".tag": "in_progress"}"#),
+        Response::json(r#"{".tag": "complete"}"#),
+    ]);
+    let options = OffboardOptions::default().with_poll_interval(Duration::from_millis(1));
+    let outcome = block_on(offboard_member(&client, "dbmid:a", Some("dbmid:b"), Some("dbmid:c"), &options))
+        .unwrap();
+    assert_eq!(Some("job1"), outcome.job_id.as_deref());
+    assert_eq!(Some("dbmid:b"), outcome.transferred_to.as_deref());
+
+    let requests = client.requests();
+    assert_eq!(3, requests.len());
+    let arg: serde_json::Value = serde_json::from_slice(&requests[0].body).unwrap();
+    assert_eq!("dbmid:a", arg["user"]["team_member_id"]);
+    assert_eq!("dbmid:b", arg["transfer_dest_id"]["team_member_id"]);
+    assert_eq!("dbmid:c", arg["transfer_admin_id"]["team_member_id"]);
+    assert!(requests[2].url.ends_with("/team/members/remove/job_status/get"));
+}
+
+#[test]
+fn test_offboard_invalid_arguments() {
+    let check = |transfer_to, admin_id, options: OffboardOptions, expected: MembersRemoveError| {
+        let client = MockClient::new([]);
+        match block_on(offboard_member(&client, "dbmid:a", transfer_to, admin_id, &options)) {
+            Err(OffboardError::Remove(Error::Api(e))) => assert_eq!(expected, e),
+            other => panic!("unexpected result: {:?}", other),
+        }
+        assert!(client.requests().is_empty());
+    };
+    let keep = OffboardOptions::default().with_keep_account(true).with_wipe_data(false);
+
+    check(Some("dbmid:b"), None, OffboardOptions::default(),
+        MembersRemoveError::UnspecifiedTransferAdminId);
+    check(Some("dbmid:a"), Some("dbmid:c"), OffboardOptions::default(),
+        MembersRemoveError::RemovedAndTransferDestShouldDiffer);
+    check(Some("dbmid:b"), Some("dbmid:c"), keep.clone(),
+        MembersRemoveError::CannotKeepAccountAndTransfer);
+    check(None, None, OffboardOptions::default().with_keep_account(true),
+        MembersRemoveError::CannotKeepAccountAndDeleteData);
+    check(None, None, OffboardOptions::default().with_wipe_data(false).with_retain_team_shares(true),
+        MembersRemoveError::CannotRetainSharesWhenNoAccountKept);
+
+    // And the valid combination of those:
+    let client = MockClient::new([Response::json(r#"{".tag": "complete"}"#)]);
+    let outcome = block_on(offboard_member(
+        &client, "dbmid:a", None, None, &keep.with_retain_team_shares(true))).unwrap();
+    assert!(outcome.kept_account);
+    assert_eq!(None, outcome.job_id);
+
+    // The admin being the member removed is left for the server to decide on.
+    let client = MockClient::new([Response::json(r#"{".tag": "complete"}"#)]);
+    block_on(offboard_member(
+        &client, "dbmid:a", None, Some("dbmid:a"), &OffboardOptions::default())).unwrap();
+    assert_eq!(1, client.requests().len());
+}
+
+fn group_member(id: &str) -> String {
+    format!(r#"{{
+        "profile": {{
+            "team_member_id": "{id}",
+            "email": "{id}@example.com",
+            "email_verified": true,
+            "status": {{".tag": "active"}},
+            "name": {{"given_name": "", "surname": "", "familiar_name": "", "display_name": "",
+                "abbreviated_name": ""}},
+            "membership_type": {{".tag": "full"}}
+        }},
+        "access_type": {{".tag": "member"}}
+    }}"#)
+}
+
+const GROUP_INFO: &str = r#"{"group_name": "Eng", "group_id": "g:1",
+    "group_management_type": {".tag": "company_managed"}, "created": 0}"#;
+
+#[test]
+fn test_ensure_group_existing() {
+    let client = MockClient::new([
+        Response::json(r#"{"groups": [{"group_name": "Eng", "group_id": "g:1",
+            "group_management_type": {".tag": "company_managed"}}], "cursor": "c1", "has_more": false}"#),
+        Response::json(&format!(r#"{{"members": [{}, {}], "cursor": "c2", "has_more": false}}"#,
+            group_member("dbmid:a"), group_member("dbmid:b"))),
+        // add
+        Response::json(&format!(r#"{{"group_info": {GROUP_INFO}, "async_job_id": "job1"}}"#)),
+        Response::json(r#"{".tag": "in_progress"}"#),
+        Response::json(r#"{".tag": "complete"}"#),
+        // remove
+        Response::json(&format!(r#"{{"group_info": {GROUP_INFO}, "async_job_id": ""}}"#)),
+    ]);
+    let groups = Groups::new(&client).with_poll_interval(Duration::from_millis(1));
+    let outcome = block_on(groups.ensure_group("eng", &["dbmid:b", "dbmid:c", "dbmid:c"]))
+        .unwrap();
+    assert_eq!("g:1", outcome.group_id);
+    assert!(!outcome.created);
+    assert_eq!(vec!["dbmid:c".to_owned()], outcome.added);
+    assert_eq!(vec!["dbmid:a".to_owned()], outcome.removed);
+
+    let requests = client.requests();
+    let urls = requests.iter()
+        .map(|r| r.route())
+        .collect::<Vec<_>>();
+    assert_eq!(vec![
+        "team/groups/list",
+        "team/groups/members/list",
+        "team/groups/members/add",
+        "team/groups/job_status/get",
+        "team/groups/job_status/get",
+        "team/groups/members/remove",
+    ], urls);
+    let add: serde_json::Value = serde_json::from_slice(&requests[2].body).unwrap();
+    assert_eq!("g:1", add["group"]["group_id"]);
+    assert_eq!("dbmid:c", add["members"][0]["user"]["team_member_id"]);
+    assert_eq!("member", add["members"][0]["access_type"][".tag"]);
+    let remove: serde_json::Value = serde_json::from_slice(&requests[5].body).unwrap();
+    assert_eq!("dbmid:a", remove["users"][0]["team_member_id"]);
+}
+
+#[test]
+fn test_ensure_group_create() {
+    let client = MockClient::new([
+        Response::json(r#"{"groups": [], "cursor": "c1", "has_more": false}"#),
+        Response::json(GROUP_INFO),
+        Response::json(&format!(r#"{{"group_info": {GROUP_INFO}, "async_job_id": ""}}"#)),
+    ]);
+    let outcome = block_on(Groups::new(&client).ensure_group("Eng", &["dbmid:a"])).unwrap();
+    assert!(outcome.created);
+    assert_eq!(vec!["dbmid:a".to_owned()], outcome.added);
+    assert!(outcome.removed.is_empty());
+    assert_eq!(3, client.requests().len());
+
+    // Nothing to do.
+    let client = MockClient::new([
+        Response::json(r#"{"groups": [{"group_name": "Eng", "group_id": "g:1",
+            "group_management_type": {".tag": "company_managed"}}], "cursor": "c1", "has_more": false}"#),
+        Response::json(&format!(r#"{{"members": [{}], "cursor": "c2", "has_more": false}}"#,
+            group_member("dbmid:a"))),
+    ]);
+    let outcome = block_on(Groups::new(&client).ensure_group("Eng", &["dbmid:a"])).unwrap();
+    assert!(!outcome.created && outcome.added.is_empty() && outcome.removed.is_empty());
+    assert_eq!(2, client.requests().len());
+}
+
+fn member_info(id: &str, account_id: &str) -> String {
+    format!(r#"{{
+        "profile": {{
+            "team_member_id": "{id}",
+            "account_id": "{account_id}",
+            "email": "{id}@Example.com",
+            "email_verified": true,
+            "status": {{".tag": "active"}},
+            "name": {{"given_name": "", "surname": "", "familiar_name": "", "display_name": "{id}",
+                "abbreviated_name": ""}},
+            "membership_type": {{".tag": "full"}},
+            "groups": [],
+            "member_folder_id": "1"
+        }}
+    }}"#)
+}
+
+#[test]
+fn test_member_resolver() {
+    let client = MockClient::new([
+        Response::json(&format!(r#"{{"members_info": [
+            {},
+            {{".tag": "id_not_found", "id_not_found": "nobody@example.com"}}
+        ]}}"#, member_info("dbmid:a", "dbid:a").replacen('{', r#"{".tag": "member_info","#, 1))),
+        Response::json(&format!(r#"{{"members": [{}], "cursor": "c1", "has_more": true}}"#,
+            member_info("dbmid:b", "dbid:b"))),
+        Response::json(&format!(r#"{{"members": [{}], "cursor": "c2", "has_more": false}}"#,
+            member_info("dbmid:c", "dbid:c"))),
+    ]);
+    let resolver = MemberResolver::new(&client);
+
+    let profiles = block_on(resolver.resolve(&[
+        team::UserSelectorArg::TeamMemberId("dbmid:a".to_owned()),
+        team::UserSelectorArg::Email("nobody@example.com".to_owned()),
+        team::UserSelectorArg::TeamMemberId("dbmid:a".to_owned()),
+    ])).unwrap();
+    assert_eq!(Some("dbmid:a"), profiles[0].as_ref().map(|p| p.name.display_name.as_str()));
+    assert_eq!(None, profiles[1]);
+    assert_eq!(profiles[0], profiles[2]);
+
+    // These are all cached now.
+    let by_email = block_on(resolver.by_email("DBMID:A@example.com")).unwrap().unwrap();
+    assert_eq!("dbmid:a", by_email.team_member_id);
+    assert_eq!(None, block_on(resolver.by_email("nobody@example.com")).unwrap());
+    let by_account = block_on(resolver.by_account_id("dbid:a")).unwrap().unwrap();
+    assert_eq!("dbmid:a", by_account.team_member_id);
+    assert_eq!(1, client.requests().len());
+
+    // An unknown account ID lists all the members, once.
+    let by_account = block_on(resolver.by_account_id("dbid:c")).unwrap().unwrap();
+    assert_eq!("dbmid:c", by_account.team_member_id);
+    assert_eq!(None, block_on(resolver.by_account_id("dbid:z")).unwrap());
+    let by_id = block_on(resolver.by_member_id("dbmid:b")).unwrap().unwrap();
+    assert_eq!(Some("dbid:b"), by_id.account_id.as_deref());
+
+    let requests = client.requests();
+    let urls = requests.iter()
+        .map(|r| r.route())
+        .collect::<Vec<_>>();
+    assert_eq!(vec![
+        "team/members/get_info_v2",
+        "team/members/list_v2",
+        "team/members/list/continue_v2",
+    ], urls);
+    let get_info: serde_json::Value = serde_json::from_slice(&requests[0].body).unwrap();
+    assert_eq!(serde_json::json!([
+        {".tag": "team_member_id", "team_member_id": "dbmid:a"},
+        {".tag": "email", "email": "nobody@example.com"},
+    ]), get_info["members"]);
+    let list: serde_json::Value = serde_json::from_slice(&requests[1].body).unwrap();
+    assert_eq!(true, list["include_removed"]);
+}
@@ -0,0 +1,101 @@
+#![warn(rust_2018_idioms)]
+
+use std::time::{Duration, SystemTime};
+use futures::executor::block_on;
+use futures::TryStreamExt;
+use dropbox_sdk::team_log;
+use dropbox_sdk::team_log_helpers::{team_events, EventFilter};
+
+mod mock;
+use mock::{MockClient, Response};
+
+fn file_add_event(path: &str) -> String {
+    format!(r#"{{
+        "timestamp": "2024-01-02T03:04:05Z",
+        "event_category": {{".tag": "file_operations"}},
+        "event_type": {{".tag": "file_add", "description": "Added files and/or folders"}},
+        "details": {{".tag": "file_add_details"}},
+        "actor": {{
+            ".tag": "user",
+            "user": {{
+                ".tag": "team_member",
+                "account_id": "dbid:abc",
+                "display_name": "Alice",
+                "email": "alice@example.com"
+            }}
+        }},
+        "origin": {{
+            "access_method": {{".tag": "api", "request_id": "req1"}},
+            "geo_location": {{"ip_address": "192.0.2.1", "country": "US"}}
+        }},
+        "assets": [
+            {{
+                ".tag": "file",
+                "path": {{
+                    "contextual": "{path}",
+                    "namespace_relative": {{"ns_id": "123", "relative_path": "/ignored"}}
+                }}
+            }},
+            {{
+                ".tag": "folder",
+                "path": {{"namespace_relative": {{"ns_id": "123", "relative_path": "/Shared"}}}}
+            }},
+            {{".tag": "paper_folder", "folder_id": "p1", "folder_name": "Paper"}}
+        ]
+    }}"#)
+}
+
+#[test]
+fn test_team_events() {
+    let client = MockClient::new([
+        Response::json(&format!(r#"{{"events": [{}], "cursor": "c1", "has_more": true}}"#,
+            file_add_event("/Docs/a.txt"))),
+        Response::json(r#"{"events": [], "cursor": "c2", "has_more": true}"#),
+        Response::json(&format!(r#"{{"events": [{}], "cursor": "c3", "has_more": false}}"#,
+            file_add_event("/Docs/b.txt"))),
+    ]);
+    let filter = EventFilter::default()
+        .with_category(team_log::EventCategory::FileOperations)
+        .with_account_id("dbid:abc")
+        .with_start_time(SystemTime::UNIX_EPOCH + Duration::from_secs(1704067200));
+    let events: Vec<_> = block_on(team_events(&client, &filter).try_collect()).unwrap();
+    assert_eq!(2, events.len());
+
+    let event = &events[0];
+    assert_eq!(Some("alice@example.com"), event.actor_email());
+    assert_eq!(Some("dbid:abc"), event.actor_user().and_then(|u| u.account_id()));
+    assert_eq!(Some("Alice"), event.actor_user().and_then(|u| u.display_name()));
+    assert_eq!(Some("192.0.2.1"), event.ip_address());
+    assert_eq!(vec!["/Docs/a.txt", "/Shared"], event.involved_paths());
+    assert_eq!(vec!["/Docs/b.txt", "/Shared"], events[1].involved_paths());
+
+    let requests = client.requests();
+    assert_eq!(3, requests.len());
+    assert!(requests[0].url.ends_with("/team_log/get_events"));
+    let arg: serde_json::Value = serde_json::from_slice(&requests[0].body).unwrap();
+    assert_eq!("file_operations", arg["category"][".tag"]);
+    assert!(arg.get("event_type").is_none());
+    assert_eq!("dbid:abc", arg["account_id"]);
+    assert_eq!("2024-01-01T00:00:00Z", arg["time"]["start_time"]);
+    assert!(arg["time"].get("end_time").is_none());
+    assert!(requests[2].url.ends_with("/team_log/get_events/continue"));
+    let arg: serde_json::Value = serde_json::from_slice(&requests[2].body).unwrap();
+    assert_eq!("c2", arg["cursor"]);
+}
+
+#[test]
+fn test_team_events_error() {
+    let client = MockClient::new([
+        Response::json(r#"{"events": [], "cursor": "c1", "has_more": true}"#),
+        Response::status(409, r#"{"error": {".tag": "expired_cursor"}, "error_summary": "expired_cursor/"}"#),
+    ]);
+    let filter = EventFilter::default()
+        .with_event_type(team_log::EventTypeArg::FileAdd)
+        .with_page_size(50);
+    let results: Vec<_> = block_on(futures::StreamExt::collect(team_events(&client, &filter)));
+    assert_eq!(1, results.len());
+    let err = results[0].as_ref().unwrap_err();
+    assert!(matches!(err, dropbox_sdk::Error::Api(_)), "wrong error: {:?}", err);
+    let arg: serde_json::Value = serde_json::from_slice(&client.requests()[0].body).unwrap();
+    assert_eq!(50, arg["limit"]);
+}
@@ -0,0 +1,38 @@
+#![warn(rust_2018_idioms)]
+
+use std::time::{Duration, SystemTime};
+use dropbox_sdk::timestamp::{from_system_time, to_system_time};
+
+#[test]
+fn test_format() {
+    assert_eq!("1970-01-01T00:00:00Z", from_system_time(SystemTime::UNIX_EPOCH));
+    assert_eq!("2024-02-29T23:59:59Z",
+        from_system_time(SystemTime::UNIX_EPOCH + Duration::from_secs(1709251199)));
+    // Fractional seconds are truncated.
+    assert_eq!("2001-09-09T01:46:40Z",
+        from_system_time(SystemTime::UNIX_EPOCH + Duration::from_millis(1_000_000_000_999)));
+    assert_eq!("1969-12-31T23:59:59Z",
+        from_system_time(SystemTime::UNIX_EPOCH - Duration::from_millis(500)));
+}
+
+#[test]
+fn test_parse() {
+    assert_eq!(Ok(SystemTime::UNIX_EPOCH + Duration::from_secs(1709251199)),
+        to_system_time("2024-02-29T23:59:59Z"));
+    assert_eq!(Ok(SystemTime::UNIX_EPOCH - Duration::from_secs(86400 * 365)),
+        to_system_time("1969-01-01T00:00:00Z"));
+
+    for bad in ["", "2024-02-30T00:00:00Z", "2023-02-29T00:00:00Z", "2024-13-01T00:00:00Z",
+        "2024-01-01T24:00:00Z", "2024-01-01 00:00:00Z", "2024-01-01T00:00:00", "+024-01-01T00:00:00Z"]
+    {
+        assert!(to_system_time(bad).is_err(), "{:?} should not parse", bad);
+    }
+}
+
+#[test]
+fn test_roundtrip() {
+    for secs in [0, 951782400, 4102444800, 253402300799] {
+        let t = SystemTime::UNIX_EPOCH + Duration::from_secs(secs);
+        assert_eq!(Ok(t), to_system_time(&from_system_time(t)));
+    }
+}
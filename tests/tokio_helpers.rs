@@ -0,0 +1,45 @@
+#![warn(rust_2018_idioms)]
+
+use dropbox_sdk::async_routes::files;
+use dropbox_sdk::files_helpers::{self, UploadOptions};
+use tokio::io::AsyncReadExt;
+
+mod mock;
+use mock::{FILE_METADATA, MockClient, Response};
+
+#[tokio::test]
+async fn test_take_tokio_body() {
+    let client = MockClient::new([Response::download(FILE_METADATA, b"hello")]);
+    let mut result = files::download(&client, &files::DownloadArg::new("/a.txt".to_owned()), None, None)
+        .await
+        .unwrap();
+    let mut body = String::new();
+    result.take_tokio_body().unwrap().read_to_string(&mut body).await.unwrap();
+    assert_eq!("hello", body);
+    assert!(result.take_tokio_body().is_none());
+}
+
+#[tokio::test]
+async fn test_upload_tokio_file() {
+    let mut local = std::env::temp_dir();
+    local.push(format!("dropbox-sdk-tokio-test-{}", std::process::id()));
+    std::fs::write(&local, b"hello").unwrap();
+    let mtime = std::fs::metadata(&local).unwrap().modified().unwrap();
+
+    let client = MockClient::new([Response::json(FILE_METADATA)]);
+    let file = tokio::fs::File::open(&local).await.unwrap();
+    files_helpers::upload_tokio_file(
+        &client,
+        file,
+        files::CommitInfo::new("/a.txt".to_owned()),
+        &UploadOptions::default(),
+    ).await.unwrap();
+    std::fs::remove_file(&local).unwrap();
+
+    let requests = client.requests();
+    assert!(requests[0].url.ends_with("/files/upload"));
+    assert_eq!(b"hello", requests[0].body.as_slice());
+    let arg: serde_json::Value = serde_json::from_str(requests[0].header("Dropbox-API-Arg").unwrap())
+        .unwrap();
+    assert_eq!(dropbox_sdk::timestamp::from_system_time(mtime), arg["client_modified"]);
+}
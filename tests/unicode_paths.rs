@@ -0,0 +1,56 @@
+#![warn(rust_2018_idioms)]
+
+use std::borrow::Cow;
+use futures::FutureExt;
+use dropbox_sdk::files;
+use dropbox_sdk::files_helpers::{self, UploadOptions};
+use dropbox_sdk::unicode_paths::{names_match, normalize_path, DropboxPath};
+
+mod mock;
+use mock::{FILE_METADATA, MockClient, Response};
+
+const NFC: &str = "/Caf\u{e9}/r\u{e9}sum\u{e9}.txt";
+const NFD: &str = "/Cafe\u{301}/re\u{301}sume\u{301}.txt";
+
+#[test]
+fn test_normalize_path() {
+    assert!(matches!(normalize_path(NFC), Cow::Borrowed(NFC)));
+    assert!(matches!(normalize_path("/plain/ascii.txt"), Cow::Borrowed(_)));
+    assert_eq!(NFC, normalize_path(NFD));
+}
+
+#[test]
+fn test_names_match() {
+    assert!(names_match(NFD, NFC));
+    assert!(names_match(NFC, NFD));
+    assert!(names_match(NFD, &NFC.to_uppercase()));
+    assert!(!names_match(NFD, "/Cafe/resume.txt"));
+}
+
+#[test]
+fn test_dropbox_path() {
+    let path = DropboxPath::normalized(NFD);
+    assert_eq!(NFC, path.as_str());
+    assert!(path.matches(NFD));
+    assert_eq!(NFD, DropboxPath::new(NFD).as_str());
+    let arg = files::GetMetadataArg::new(path.into());
+    assert_eq!(NFC, arg.path);
+}
+
+#[test]
+fn test_upload_normalize_paths() {
+    let upload = |options: &UploadOptions| {
+        let client = MockClient::new([Response::json(FILE_METADATA)]);
+        files_helpers::upload(
+            &client,
+            files::CommitInfo::new(NFD.to_owned()),
+            &b"hello"[..],
+            options,
+        ).now_or_never().unwrap().unwrap();
+        let arg: serde_json::Value = serde_json::from_str(
+            client.requests()[0].header("Dropbox-API-Arg").unwrap()).unwrap();
+        arg["path"].as_str().unwrap().to_owned()
+    };
+    assert_eq!(NFD, upload(&UploadOptions::default()));
+    assert_eq!(NFC, upload(&UploadOptions::default().with_normalize_paths(true)));
+}
@@ -0,0 +1,57 @@
+#![warn(rust_2018_idioms)]
+
+use futures::FutureExt;
+use dropbox_sdk::users_helpers::{self, format_bytes};
+
+mod mock;
+use mock::{MockClient, Response};
+
+#[test]
+fn test_space_usage() {
+    let client = MockClient::new([
+        Response::json(r#"{
+            "used": 750000000,
+            "allocation": {".tag": "individual", "allocated": 1000000000}
+        }"#),
+        Response::json(r#"{
+            "used": 100,
+            "allocation": {
+                ".tag": "team",
+                "used": 1950000000000,
+                "allocated": 2000000000000,
+                "user_within_team_space_allocated": 0,
+                "user_within_team_space_limit_type": {".tag": "off"},
+                "user_within_team_space_used_cached": 100
+            }
+        }"#),
+        Response::json(r#"{"used": 100, "allocation": {".tag": "something_new"}}"#),
+    ]);
+
+    let usage = users_helpers::space_usage(&client).now_or_never().unwrap().unwrap();
+    assert_eq!(750_000_000, usage.used);
+    assert_eq!(Some(1_000_000_000), usage.allocated);
+    assert_eq!(Some(75.), usage.percent_used());
+    assert!(usage.is_over(0.75));
+    assert!(!usage.is_over(0.9));
+    assert_eq!("750.00 MB of 1.00 GB used (75.0%)", usage.to_string());
+
+    // Team members without their own quota share the team's space.
+    let usage = users_helpers::space_usage(&client).now_or_never().unwrap().unwrap();
+    assert_eq!(1_950_000_000_000, usage.used);
+    assert!(usage.is_over(0.9));
+
+    let usage = users_helpers::space_usage(&client).now_or_never().unwrap().unwrap();
+    assert_eq!(None, usage.percent_used());
+    assert!(!usage.is_over(0.));
+    assert_eq!("100 B used", usage.to_string());
+}
+
+#[test]
+fn test_format_bytes() {
+    assert_eq!("0 B", format_bytes(0));
+    assert_eq!("999 B", format_bytes(999));
+    assert_eq!("1.00 kB", format_bytes(1000));
+    assert_eq!("1.50 MB", format_bytes(1_500_000));
+    assert_eq!("1.00 MB", format_bytes(999_999));
+    assert_eq!("18.45 EB", format_bytes(u64::MAX));
+}
@@ -0,0 +1,68 @@
+#![warn(rust_2018_idioms)]
+
+use std::io::{Cursor, Read};
+use futures::executor::block_on;
+use dropbox_sdk::zip_download::{download_selection_as_zip, ZipDownloadOptions};
+
+mod mock;
+use mock::{MockClient, Response};
+
+fn file(path: &str) -> String {
+    let name = path.rsplit('/').next().unwrap();
+    format!(r#"{{
+        ".tag": "file",
+        "name": "{name}",
+        "id": "id:{name}",
+        "path_display": "{path}",
+        "path_lower": "{}",
+        "client_modified": "2024-01-01T00:00:00Z",
+        "server_modified": "2024-01-01T00:00:00Z",
+        "rev": "0123456789abcdef",
+        "size": 5
+    }}"#, path.to_lowercase())
+}
+
+fn folder(path: &str) -> String {
+    let name = path.rsplit('/').next().unwrap();
+    format!(r#"{{
+        ".tag": "folder",
+        "name": "{name}",
+        "id": "id:{name}",
+        "path_display": "{path}",
+        "path_lower": "{}"
+    }}"#, path.to_lowercase())
+}
+
+#[test]
+fn test_download_selection_as_zip() {
+    let client = MockClient::new([
+        Response::json(&file("/Photos/a.jpg")),
+        Response::json(&folder("/Photos/2024")),
+        Response::json(&format!(r#"{{"entries": [{}, {}, {}], "cursor": "c1", "has_more": false}}"#,
+            folder("/Photos/2024"), folder("/Photos/2024/Empty"), file("/Photos/2024/b.jpg"))),
+        Response::download(&file("/Photos/2024/b.jpg"), b"bbbbb"),
+        Response::download(&file("/Photos/a.jpg"), b"aaaaa"),
+    ]);
+    let options = ZipDownloadOptions::default().with_concurrency(1);
+    let dest = block_on(download_selection_as_zip(
+        &client, ["/Photos/a.jpg", "/Photos/2024"], Cursor::new(vec![]), &options,
+    )).unwrap();
+
+    let mut archive = zip::ZipArchive::new(Cursor::new(dest.into_inner())).unwrap();
+    let mut names = archive.file_names().collect::<Vec<_>>();
+    names.sort();
+    assert_eq!(vec!["2024/", "2024/Empty/", "2024/b.jpg", "a.jpg"], names);
+    for (name, expected) in [("a.jpg", "aaaaa"), ("2024/b.jpg", "bbbbb")] {
+        let mut contents = String::new();
+        archive.by_name(name).unwrap().read_to_string(&mut contents).unwrap();
+        assert_eq!(expected, contents);
+    }
+
+    let requests = client.requests();
+    assert_eq!(5, requests.len());
+    assert!(requests[2].url.ends_with("/files/list_folder"));
+    let arg: serde_json::Value = serde_json::from_slice(&requests[2].body).unwrap();
+    assert_eq!(true, arg["recursive"]);
+    let arg: serde_json::Value = serde_json::from_str(requests[3].header("Dropbox-API-Arg").unwrap()).unwrap();
+    assert_eq!("id:b.jpg", arg["path"]);
+}